@@ -0,0 +1,98 @@
+use crate::scenario::Scenario;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// 파일 수정 시각을 다시 확인하는 최소 간격이다.
+pub(crate) const FILE_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 열린 시나리오 파일과 `include`로 펼친 조각 파일이 외부에서 바뀌었는지 수정 시각으로 감시한다.
+///
+/// 알림 크레이트 없이 UI 프레임에서 주기적으로 수정 시각을 비교하는 방식이다.
+#[derive(Debug, Clone)]
+pub(crate) struct ScenarioFileWatch {
+    /// 감시 중인 시나리오 파일 경로.
+    pub(crate) path: PathBuf,
+    /// 시나리오 파일(맨 앞)과 조각 파일별로 마지막으로 불러오거나 저장했을 때의 수정 시각.
+    known_modified: Vec<(PathBuf, Option<SystemTime>)>,
+    /// 알렸지만 아직 처리하지 않은 변경. 바뀐 파일 경로와 그때의 파일별 수정 시각이다.
+    pending: Option<(PathBuf, Vec<Option<SystemTime>>)>,
+    /// 마지막으로 수정 시각을 확인한 시각.
+    last_check: Instant,
+}
+
+impl ScenarioFileWatch {
+    /// 현재 수정 시각을 기준으로 시나리오 파일과 그 시나리오가 펼친 조각 파일의 감시를 시작한다.
+    pub(crate) fn new(path: PathBuf, scenario: &Scenario) -> Self {
+        let mut files = vec![path.clone()];
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+        for step in &scenario.steps {
+            if let Some(include) = &step.included_from {
+                let fragment = base_dir.join(&include.include);
+                if !files.contains(&fragment) {
+                    files.push(fragment);
+                }
+            }
+        }
+        Self {
+            known_modified: files
+                .into_iter()
+                .map(|file| {
+                    let modified = modified_at(&file);
+                    (file, modified)
+                })
+                .collect(),
+            path,
+            pending: None,
+            last_check: Instant::now(),
+        }
+    }
+
+    /// 확인 간격이 지났으면 수정 시각을 비교해 새 외부 변경을 기록한다.
+    pub(crate) fn poll(&mut self) {
+        if self.last_check.elapsed() < FILE_WATCH_INTERVAL {
+            return;
+        }
+        self.last_check = Instant::now();
+        let current: Vec<Option<SystemTime>> = self
+            .known_modified
+            .iter()
+            .map(|(file, _)| modified_at(file))
+            .collect();
+        let changed = self
+            .known_modified
+            .iter()
+            .zip(&current)
+            .find(|((_, known), now)| now.is_some() && *now != known);
+        if let Some(((file, _), _)) = changed {
+            self.pending = Some((file.clone(), current));
+        }
+    }
+
+    /// 처리하지 않은 외부 변경이 있는지 확인한다.
+    pub(crate) fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// 처리하지 않은 외부 변경이 생긴 파일 경로를 반환한다. 조각 파일일 수도 있다.
+    pub(crate) fn pending_file(&self) -> Option<&Path> {
+        self.pending.as_ref().map(|(file, _)| file.as_path())
+    }
+
+    /// 외부 변경을 처리한 것으로 표시해 같은 변경을 다시 알리지 않는다.
+    pub(crate) fn acknowledge(&mut self) {
+        if let Some((_, modified)) = self.pending.take() {
+            for ((_, known), modified) in self.known_modified.iter_mut().zip(modified) {
+                if modified.is_some() {
+                    *known = modified;
+                }
+            }
+        }
+    }
+}
+
+/// 파일 수정 시각을 읽는다. 파일이 없거나 읽을 수 없으면 `None`이다.
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
@@ -1,4 +1,6 @@
+mod file_watch;
 mod state;
+mod step_panels;
 mod ui;
 mod widgets;
 
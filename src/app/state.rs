@@ -1,21 +1,82 @@
-use crate::editor::{ScenarioEditorState, editor_state_to_scenario, scenario_to_editor_state};
-use crate::engine::{ConfirmBridge, EngineEvent, StepRuntimeState, StepStatus, run_scenario};
-use crate::executor::{DummyExecutor, SharedExecutor};
-use crate::scenario::{ConfirmDefault, Scenario, load_scenario_from_file};
+use super::file_watch::ScenarioFileWatch;
+use super::step_panels::StepDetailPanels;
+use crate::connections::{ConnectionStore, load_connection_store, save_connection_store};
+use crate::cost_check::{CostWarning, check_scenario_cost};
+use crate::daemon::{
+    DaemonClient, DaemonClientEvent, DaemonEndpoint, DaemonMessage, DaemonRequest,
+};
+use crate::editor::model::{SchemaFetch, SchemaRequest};
+use crate::editor::{
+    EditorStepConfig, ScenarioEditorState, ShellTrialStatus, SqlPreviewStatus, StepTestStatus,
+    default_scenario_name, editor_state_to_scenario, scenario_to_editor_state,
+};
+use crate::engine::{
+    ConfirmBridge, ConfirmMode, ContextSnapshot, DbPoolUsage, EngineError, EngineEvent,
+    EngineHandleCache, EngineRuntimeConfig, ExecutionContext, GateApproval, InterruptedRun,
+    PreviewField, RehearsalPlan, RunJournal, SECRET_MASK, ShellTrialOutput, StepCancelMode,
+    StepRuntimeState, StepStatus, SummaryResult, discard_journal, fetch_schema_columns,
+    fetch_schema_tables, load_context_file, load_interrupted_run, preview_sql, preview_step,
+    run_scenario, run_shell_trial, step_test_inputs, step_test_scenario,
+};
+use crate::executor::{ColumnInfo, DummyExecutor, QueryRows, SharedExecutor};
+use crate::expanded_yaml::{expand_scenario_yaml, write_expanded_yaml};
+use crate::file_check::{check_scenario_files, missing_files_summary};
+use crate::history::{
+    RunNote, StepDurationStats, load_step_stats, parse_tags, prune_history, save_run_note,
+};
+use crate::i18n::{Language, set_language, t, tf};
+use crate::run_approval::{
+    ApprovalAction, ApprovalRecord, ApprovalRequest, RUN_APPROVAL_ID_VAR, RUN_APPROVED_AT_VAR,
+    RUN_APPROVED_BY_VAR,
+};
+use crate::run_dashboard::{DASHBOARD_PERIODS, RunDashboard};
+use crate::scenario::{
+    ConfirmDefault, ExecutionProfile, ParamViolation, Scenario, Step, StepKind,
+    load_scenario_from_file, scenario_to_yaml,
+};
+use crate::scenario_crypto::{
+    ENCRYPTED_EXTENSION, ScenarioCryptoError, encrypt, is_encrypted_path, remember_passphrase,
+    resolve_passphrase,
+};
+use crate::scenario_error::ScenarioParseError;
+use crate::scenario_search::{ScenarioSearchHit, ScenarioSearchReport, search_scenarios};
+use crate::settings::{
+    AppSettings, ScenarioViewPrefs, local_user_name, save_app_settings, scenario_view_key,
+};
+use crate::sql_changes::{SqlFileChange, changed_sql_files};
+use crate::sql_check::{SqlIssue, SqlIssueLevel, check_scenario_sql};
 use crate::theme::Theme;
+use crate::workflow_import::import_workflow_file;
 use eframe::egui;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
 use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::sync::oneshot;
 use tokio_util::sync::CancellationToken;
 
 /// 메모리에 적재할 수 있는 최대 로그 라인 수를 정의한다.
 pub(crate) const MAX_LOG_LINES: usize = 500;
 
+/// 한 프레임에서 처리할 최대 엔진 이벤트 수를 정의한다.
+///
+/// 이벤트가 몰려도 UI 스레드가 멈추지 않도록 남은 이벤트는 다음 프레임으로 넘긴다.
+const MAX_EVENTS_PER_FRAME: usize = 2_000;
+
+/// 실행 시간이 과거 평균의 이 배수를 넘으면 느린 Step으로 표시한다.
+const SLOW_STEP_RATIO: f32 = 1.5;
+
+/// 실패 요약에 보여 줄 근본 원인 Step의 최근 로그 줄 수이다.
+const FAILURE_SUMMARY_LOG_LINES: usize = 20;
+
+/// DB 오류 코드 지식베이스 URL 패턴을 지정하는 환경 변수 이름이다.
+///
+/// 값에 포함된 `{code}`는 `ORA-00942`, `42P01` 같은 오류 코드로 치환된다.
+pub(crate) const ERROR_KB_URL_ENV: &str = "RUST_AIRFLOW_ERROR_KB_URL";
+
 /// UI에 표시할 컨펌 요청 정보를 저장한다.
 #[derive(Debug, Clone)]
 pub struct PendingConfirmRequest {
@@ -37,6 +98,162 @@ pub struct PendingConfirmRequest {
     pub phase: crate::engine::ConfirmPhase,
 }
 
+/// 암호 문구 입력 후 다시 수행할 파일 작업이다.
+#[derive(Debug, Clone)]
+pub enum PassphraseAction {
+    /// 실행 탭에서 시나리오를 연다.
+    OpenRun(PathBuf),
+    /// 빌더에서 시나리오를 연다.
+    OpenEditor(PathBuf),
+    /// 빌더 내용을 지정한 경로에 저장한다.
+    Save(PathBuf),
+}
+
+/// 암호화 시나리오용 암호 문구 입력 대화상자 상태이다.
+#[derive(Debug, Clone)]
+pub struct PassphrasePrompt {
+    /// 입력 중인 암호 문구.
+    pub input: String,
+    /// 직전 시도의 오류 메시지.
+    pub error: Option<String>,
+    /// 입력 후 다시 수행할 작업.
+    pub action: PassphraseAction,
+}
+
+/// `모든 시나리오에서 찾기` 창 상태이다.
+#[derive(Debug, Clone, Default)]
+pub struct ScenarioSearchWindow {
+    /// 입력 중인 검색어.
+    pub query: String,
+    /// 마지막 검색 결과. 아직 검색하지 않았으면 `None`이다.
+    pub report: Option<ScenarioSearchReport>,
+    /// 시나리오 폴더를 읽지 못한 경우의 오류 메시지.
+    pub error: Option<String>,
+}
+
+/// 백그라운드에서 계산한 Step별 실행 통계와 마지막 성공 실행 이후 바뀐 `sql_file`이다.
+pub(crate) type StepStatsReply = (
+    HashMap<String, StepDurationStats>,
+    HashMap<String, SqlFileChange>,
+);
+
+/// 진행 중인 스키마 브라우저 조회 하나이다.
+pub(crate) struct SchemaFetchTask {
+    /// 조회한 DB 타겟 키. 그 사이 타겟을 바꿨으면 결과를 버린다.
+    target_db: String,
+    /// 조회 요청.
+    request: SchemaRequest,
+    /// 결과 수신 채널.
+    rx: oneshot::Receiver<Result<SchemaReply, String>>,
+}
+
+/// 스키마 브라우저 조회 결과이다.
+enum SchemaReply {
+    /// 테이블·뷰 이름 목록.
+    Tables(Vec<String>),
+    /// 컬럼 목록.
+    Columns(Vec<ColumnInfo>),
+}
+
+/// 대시보드 실행 목록에서 편집 중인 실행 메모이다.
+#[derive(Debug, Clone)]
+pub struct RunNoteEdit {
+    /// 대상 시나리오 이름.
+    pub scenario: String,
+    /// 대상 실행 ID.
+    pub run_id: String,
+    /// 입력 중인 메모.
+    pub note: String,
+    /// 쉼표나 공백으로 구분해 입력 중인 태그.
+    pub tags: String,
+    /// 직전 저장 시도의 오류 메시지.
+    pub error: Option<String>,
+}
+
+/// 실행 중에 창을 닫으려 할 때의 종료 처리 단계이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitShutdown {
+    /// 실행 중인 시나리오를 어떻게 할지 묻는 창을 띄운 상태.
+    Asking,
+    /// 실행을 취소하고 엔진이 이력·로그 기록을 마치기를 기다리는 상태.
+    Draining {
+        /// 취소를 요청한 시각.
+        since: std::time::Instant,
+    },
+}
+
+/// 실행 승인을 받은 뒤 다시 수행할 실행 작업이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApprovedRunAction {
+    /// 처음부터 실행한다.
+    Start,
+    /// 실패한 곳부터 다시 실행한다.
+    RetryFromFailure,
+}
+
+/// 승인이 필요한 실행 프로필로 실행을 요청했을 때 띄우는 승인 대화상자 상태이다.
+#[derive(Debug, Clone)]
+pub struct RunApprovalPrompt {
+    /// 감사 기록에 남긴 승인 요청.
+    pub request: ApprovalRequest,
+    /// 승인되면 다시 수행할 작업.
+    pub action: ApprovedRunAction,
+    /// 입력 중인 승인자 이름.
+    pub approver: String,
+    /// 입력 중인 승인 코드.
+    pub code: String,
+    /// 직전 승인 시도의 오류 메시지.
+    pub error: Option<String>,
+}
+
+/// 실행 화면 하단에 표시하는 Step 상태별 개수이다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProgressCounts {
+    /// 성공한 Step 수.
+    pub succeeded: usize,
+    /// 실패한 Step 수.
+    pub failed: usize,
+    /// 선행 실패로 건너뛴 Step 수.
+    pub blocked: usize,
+    /// 대기 중이거나 실행 중인 Step 수.
+    pub remaining: usize,
+}
+
+/// 하단 진행 패널의 동시 실행 레인 하나로, 지금 실행 중인 Step이다.
+#[derive(Debug, Clone)]
+pub struct RunningLane {
+    /// Step 이름.
+    pub name: String,
+    /// 이번 시도를 시작한 뒤 지난 시간.
+    pub elapsed: std::time::Duration,
+    /// 과거 평균 소요 시간. 이력이 없으면 `None`이다.
+    pub expected: Option<std::time::Duration>,
+}
+
+/// 실패로 끝난 실행의 근본 원인 요약이다.
+#[derive(Debug, Clone)]
+pub struct FailureSummary {
+    /// 가장 먼저 실패한 Step ID.
+    pub root_step_id: String,
+    /// 근본 원인 Step 이름.
+    pub root_step_name: String,
+    /// 근본 원인 Step의 실패 메시지.
+    pub message: String,
+    /// 근본 원인 때문에 건너뛴 하위 Step ID 목록. 시나리오 순서를 따른다.
+    pub blocked_chain: Vec<String>,
+    /// 근본 원인 Step의 마지막 로그 줄.
+    pub last_logs: Vec<String>,
+}
+
+/// 메인 DAG가 끝난 뒤 받은 결과 요약 조회 결과이다.
+#[derive(Debug, Clone)]
+pub struct RunSummaryReport {
+    /// 결과를 받은 로컬 시각(`YYYY-MM-DD HH:MM:SS`).
+    pub received_at: String,
+    /// 시나리오에 정의한 순서대로의 조회 결과.
+    pub results: Vec<SummaryResult>,
+}
+
 /// 앱 상단 탭 종류를 정의한다.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AppTab {
@@ -44,6 +261,8 @@ pub enum AppTab {
     Run,
     /// 시나리오 빌더 탭이다.
     ScenarioBuilder,
+    /// 실행 이력 통계 대시보드 탭이다.
+    Dashboard,
 }
 
 /// egui 애플리케이션의 전체 상태를 보관한다.
@@ -56,16 +275,58 @@ pub struct BatchOrchestratorApp {
     pub(crate) scenario_path: Option<PathBuf>,
     /// 선택된 Step ID.
     pub(crate) selected_step: Option<String>,
+    /// 실행 탭에서 접어 둔 스테이지 이름. 스테이지가 없는 Step 묶음은 빈 문자열이다.
+    pub(crate) collapsed_stages: BTreeSet<String>,
+    /// 로그 영역의 필터 검색어.
+    pub(crate) log_filter: String,
+    /// 실행 탭에서 키보드로 이동 중인 Step 카드 ID.
+    pub(crate) run_focus: Option<String>,
+    /// 다음 프레임에 포커스된 카드가 보이도록 스크롤할지 여부.
+    pub(crate) scroll_to_focus: bool,
     /// Step별 상태 맵.
     pub(crate) step_states: HashMap<String, StepRuntimeState>,
     /// Step별 로그 버퍼.
     pub(crate) step_logs: HashMap<String, Vec<String>>,
-    /// Tokio 런타임.
+    /// `step_logs`의 줄을 소문자로 바꿔 같은 순서로 둔 것. 로그 필터가 프레임마다 변환하지 않도록
+    /// 로그를 적재할 때 함께 채운다.
+    pub(crate) step_logs_lower: HashMap<String, Vec<String>>,
+    /// Step 상세 영역에 Step 유형별로 덧붙이는 확장 패널.
+    pub(crate) step_panels: StepDetailPanels,
+    /// 데몬 접속 등 UI 보조 작업용 Tokio 런타임.
     runtime: Runtime,
+    /// 시나리오 실행과 DB 핸들 구성 전용 Tokio 런타임.
+    engine_runtime: Runtime,
+    /// 현재 엔진 런타임에 적용된 구성.
+    pub(crate) engine_runtime_config: EngineRuntimeConfig,
+    /// 설정 메뉴에서 편집 중인 엔진 런타임 구성.
+    pub(crate) engine_runtime_draft: EngineRuntimeConfig,
     /// DB 실행기.
     pub(crate) executor: SharedExecutor,
+    /// 실행 사이에 재사용하는 DB 핸들 캐시.
+    pub(crate) handle_cache: EngineHandleCache,
+    /// 진행 중인 DB 핸들 재구성 결과 수신 채널.
+    pub(crate) db_reload_rx: Option<oneshot::Receiver<Result<(), String>>>,
+    /// 진행 중인 SQL 미리보기의 Step ID와 결과 수신 채널.
+    pub(crate) sql_preview_rx: Option<(String, oneshot::Receiver<Result<QueryRows, String>>)>,
+    /// 진행 중인 스키마 브라우저 조회.
+    pub(crate) schema_fetch: Option<SchemaFetchTask>,
+    /// 진행 중인 실행 이력 통계 계산의 시나리오 이름과 결과 수신 채널.
+    pub(crate) step_stats_rx: Option<(String, oneshot::Receiver<StepStatsReply>)>,
+    /// 진행 중인 대시보드 실행 이력 통계 계산의 결과 수신 채널.
+    pub(crate) dashboard_rx: Option<oneshot::Receiver<RunDashboard>>,
+    /// 진행 중인 모든 시나리오 검색의 결과 수신 채널.
+    pub(crate) scenario_search_rx: Option<oneshot::Receiver<Result<ScenarioSearchReport, String>>>,
+    /// 진행 중인 Shell 로컬 테스트의 Step ID와 결과 수신 채널.
+    pub(crate) shell_trial_rx:
+        Option<(String, oneshot::Receiver<Result<ShellTrialOutput, String>>)>,
+    /// 진행 중인 단일 Step 테스트의 Step ID와 이벤트 수신 채널.
+    pub(crate) step_test_rx: Option<(String, UnboundedReceiver<EngineEvent>)>,
+    /// 진행 중인 단일 Step 테스트의 취소 토큰.
+    pub(crate) step_test_cancel: Option<CancellationToken>,
     /// 엔진 이벤트 수신 채널.
     pub(crate) events_rx: Option<UnboundedReceiver<EngineEvent>>,
+    /// 로컬 엔진이 `ScenarioFinished` 없이 오류로 실행을 끝냈을 때의 메시지.
+    run_failure: Arc<std::sync::Mutex<Option<String>>>,
     /// 시나리오 취소 토큰.
     pub(crate) cancel_token: Option<CancellationToken>,
     /// 실행 중 여부.
@@ -76,99 +337,648 @@ pub struct BatchOrchestratorApp {
     pub(crate) editor_state: ScenarioEditorState,
     /// 시나리오 빌더 오류 메시지.
     pub(crate) editor_error: Option<String>,
+    /// 시나리오 빌더 안내 메시지.
+    pub(crate) editor_notice: Option<String>,
+    /// 암호 문구 입력 대화상자. `None`이면 표시하지 않는다.
+    pub(crate) passphrase_prompt: Option<PassphrasePrompt>,
+    /// 시나리오 파일 형식 오류 창에 표시할 오류. `None`이면 표시하지 않는다.
+    pub(crate) scenario_error: Option<ScenarioParseError>,
+    /// 마지막 SQL 검사 결과. `None`이면 검사 패널을 숨긴다.
+    pub(crate) sql_check_report: Option<Vec<SqlIssue>>,
+    /// 마지막 검사의 비용 점검 경고. 검사 패널에 SQL 검사 결과와 함께 표시한다.
+    pub(crate) cost_check_report: Vec<CostWarning>,
+    /// 실행 시작 때 확인하지 않은 비용 경고가 있어 띄운 창의 경고 목록. `None`이면 표시하지 않는다.
+    pub(crate) cost_warning_prompt: Option<Vec<CostWarning>>,
+    /// 비용 경고 창에서 `그래도 실행`을 골라 다음 실행 시작에 한 번 경고를 건너뛸지 여부.
+    cost_override: bool,
     /// 현재 활성 탭.
     pub(crate) active_tab: AppTab,
     /// 대기 중인 컨펌 요청 목록.
     pub(crate) pending_confirms: Vec<PendingConfirmRequest>,
     /// 엔진에 전달할 컨펌 브리지.
     pub(crate) confirm_bridge: Option<ConfirmBridge>,
+    /// DB 오류 코드 지식베이스 링크 URL 패턴.
+    pub(crate) error_kb_url_template: Option<String>,
+    /// 데몬 모드에서 사용하는 데몬 접속 핸들. `None`이면 GUI 프로세스에서 직접 실행한다.
+    pub(crate) daemon: Option<DaemonClient>,
+    /// 데몬 접속 여부.
+    pub(crate) daemon_connected: bool,
+    /// 실행 이력으로 계산한 Step별 소요 시간 통계.
+    pub(crate) step_stats: HashMap<String, StepDurationStats>,
+    /// 리허설 모드 여부. 켜져 있으면 실행 시 `rehearsal_plan`을 함께 전달한다.
+    pub(crate) rehearsal_mode: bool,
+    /// 리허설 장애 주입 계획. 시나리오 파일에는 저장하지 않는다.
+    pub(crate) rehearsal_plan: RehearsalPlan,
+    /// 다음 실행에서 컨펌 Step에 응답하는 방식(묻기·무인 실행·참관 실행).
+    pub(crate) confirm_mode: ConfirmMode,
+    /// 새 실행의 시작 값으로 넣을 이전 실행의 컨텍스트 파일.
+    pub(crate) context_in: Option<PathBuf>,
+    /// 로컬 실행이 끝난 시점의 컨텍스트 변수를 남길 파일.
+    pub(crate) context_out: Option<PathBuf>,
+    /// 다음 실행에 붙일 메모. 실행이 시작되면 실행 메모 파일에 저장하고 비운다.
+    pub(crate) run_note_draft: String,
+    /// 다음 실행에 붙일 태그(쉼표나 공백 구분).
+    pub(crate) run_tags_draft: String,
+    /// 펼친 YAML 창에 표시할 치환한 시나리오. `None`이면 창을 표시하지 않는다.
+    pub(crate) expanded_yaml: Option<String>,
+    /// 실행을 시작할 때 펼친 YAML을 실행 로그 디렉터리에 함께 남길지 여부.
+    pub(crate) expanded_yaml_in_report: bool,
+    /// 실행 ID를 받으면 첨부할 시나리오 이름과 펼친 YAML.
+    expanded_attachment: Option<(String, String)>,
+    /// 선택된 Step의 유형별 구성 미리보기. Step ID와 함께 캐시해 선택이 바뀔 때만 다시 계산한다.
+    pub(crate) step_preview: Option<(String, Vec<PreviewField>)>,
+    /// 열린 시나리오 파일의 외부 변경 감시. 파일에서 불러오거나 저장한 뒤에만 있다.
+    pub(crate) file_watch: Option<ScenarioFileWatch>,
+    /// 앱이 비정상 종료되어 끝나지 못한 이전 실행. 시작할 때 실행 저널에서 읽는다.
+    pub(crate) interrupted_run: Option<InterruptedRun>,
+    /// 마지막 실행의 결과 요약. 새 실행을 시작하거나 시나리오를 바꾸면 지운다.
+    pub(crate) run_summary: Option<RunSummaryReport>,
+    /// 실행 중 DB 키별 연결 풀 사용 현황. 연결 풀을 쓰는 DB가 없으면 비어 있다.
+    pub(crate) db_pool_usage: Vec<DbPoolUsage>,
+    /// 앱 설정 파일에 저장된 앱 전역 설정.
+    pub(crate) settings: AppSettings,
+    /// 설정 대화상자에서 편집 중인 앱 설정. `None`이면 대화상자를 표시하지 않는다.
+    pub(crate) settings_draft: Option<AppSettings>,
+    /// 대시보드 탭에 표시할 실행 이력 통계. 탭을 열거나 필터를 바꿀 때 다시 계산한다.
+    pub(crate) dashboard: Option<RunDashboard>,
+    /// 대시보드에서 집계할 시나리오 이름. `None`이면 모든 시나리오를 합친다.
+    pub(crate) dashboard_scenario: Option<String>,
+    /// 대시보드 집계 기간(일).
+    pub(crate) dashboard_days: u32,
+    /// 대시보드 실행 목록의 메모·태그 검색어.
+    pub(crate) dashboard_search: String,
+    /// 대시보드에서 편집 중인 실행 메모. `None`이면 편집 양식을 표시하지 않는다.
+    pub(crate) run_note_edit: Option<RunNoteEdit>,
+    /// 실행에 쓸 실행 프로필 이름. `None`이면 시나리오 파라미터를 그대로 쓴다.
+    pub(crate) selected_profile: Option<String>,
+    /// 고른 프로필과 가져온 컨텍스트를 합친 실행 파라미터가 `param_rules`를 어긴 항목. 프로필 옆에 표시한다.
+    pub(crate) param_violations: Vec<ParamViolation>,
+    /// 승인을 기다리는 실행 요청. `None`이면 승인 대화상자를 표시하지 않는다.
+    pub(crate) run_approval_prompt: Option<RunApprovalPrompt>,
+    /// 승인되어 다음 실행 시작에 한 번 쓰일 승인 기록.
+    granted_approval: Option<ApprovalRecord>,
+    /// 시작을 기다리는 실행의 승인 기록. 실행 ID를 받으면 감사 기록에 실행 시작을 남긴다.
+    starting_approval: Option<ApprovalRecord>,
+    /// `모든 시나리오에서 찾기` 창. `None`이면 창을 표시하지 않는다.
+    pub(crate) scenario_search: Option<ScenarioSearchWindow>,
+    /// 실행 중 창 닫기 요청의 처리 단계. `None`이면 종료를 처리하고 있지 않다.
+    pub(crate) exit_shutdown: Option<ExitShutdown>,
+    /// 종료 처리를 마쳐 다음 창 닫기 요청을 막지 않을지 여부.
+    exit_allowed: bool,
 }
 
 impl BatchOrchestratorApp {
     /// egui Context를 받아 초기 상태를 구성한다.
-    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        let theme = Theme::default();
+    ///
+    /// # 매개변수
+    /// - `cc`: eframe 생성 컨텍스트.
+    /// - `daemon_endpoint`: 지정하면 해당 주소의 데몬에 접속해 실행을 위임한다.
+    /// - `runtime_config`: 엔진 전용 런타임의 워커/블로킹 스레드 구성.
+    /// - `settings`: 앱 설정 파일에서 읽은 앱 전역 설정. 테마와 UI 배율을 바로 적용한다.
+    pub fn new(
+        cc: &eframe::CreationContext<'_>,
+        daemon_endpoint: Option<DaemonEndpoint>,
+        runtime_config: EngineRuntimeConfig,
+        settings: AppSettings,
+        confirm_mode: ConfirmMode,
+    ) -> Self {
+        let theme = Theme::from_variant(settings.theme);
         theme.apply(&cc.egui_ctx);
+        cc.egui_ctx.set_zoom_factor(settings.ui_scale);
         let runtime = Runtime::new().expect("Tokio 런타임 생성 실패");
+        let engine_runtime = runtime_config.build().expect("엔진 런타임 생성 실패");
+        let daemon =
+            daemon_endpoint.map(|endpoint| DaemonClient::connect(runtime.handle(), endpoint));
+        // 데몬 모드에서는 GUI가 죽어도 실행이 데몬에서 계속되므로 저널을 쓰지도 읽지도 않는다.
+        let interrupted_run = daemon.is_none().then(load_interrupted_run).flatten();
+        let mut editor_state = ScenarioEditorState::new();
+        let mut last_error = None;
+        match load_connection_store() {
+            Ok(store) => editor_state.shared_connections = store.connections,
+            Err(err) => last_error = Some(err.to_string()),
+        }
         Self {
             theme,
             scenario: None,
             scenario_path: None,
             selected_step: None,
+            collapsed_stages: BTreeSet::new(),
+            log_filter: String::new(),
+            run_focus: None,
+            scroll_to_focus: false,
             step_states: HashMap::new(),
             step_logs: HashMap::new(),
+            step_logs_lower: HashMap::new(),
+            step_panels: StepDetailPanels::with_builtin(),
             runtime,
+            engine_runtime,
+            engine_runtime_config: runtime_config,
+            engine_runtime_draft: runtime_config,
             executor: Arc::new(DummyExecutor::default()),
+            handle_cache: EngineHandleCache::default(),
+            db_reload_rx: None,
+            sql_preview_rx: None,
+            step_stats_rx: None,
+            schema_fetch: None,
+            dashboard_rx: None,
+            scenario_search_rx: None,
+            shell_trial_rx: None,
+            step_test_rx: None,
+            step_test_cancel: None,
             events_rx: None,
+            run_failure: Arc::default(),
             cancel_token: None,
             scenario_running: false,
-            last_error: None,
-            editor_state: ScenarioEditorState::new(),
+            last_error,
+            editor_state,
             editor_error: None,
+            editor_notice: None,
+            passphrase_prompt: None,
+            scenario_error: None,
+            sql_check_report: None,
+            cost_check_report: Vec::new(),
+            cost_warning_prompt: None,
+            cost_override: false,
             active_tab: AppTab::Run,
             pending_confirms: Vec::new(),
             confirm_bridge: None,
+            error_kb_url_template: std::env::var(ERROR_KB_URL_ENV)
+                .ok()
+                .filter(|value| !value.trim().is_empty()),
+            daemon,
+            daemon_connected: false,
+            step_stats: HashMap::new(),
+            rehearsal_mode: false,
+            rehearsal_plan: RehearsalPlan::default(),
+            confirm_mode,
+            context_in: None,
+            context_out: None,
+            run_note_draft: String::new(),
+            run_tags_draft: String::new(),
+            expanded_yaml: None,
+            expanded_yaml_in_report: false,
+            expanded_attachment: None,
+            step_preview: None,
+            file_watch: None,
+            interrupted_run,
+            run_summary: None,
+            db_pool_usage: Vec::new(),
+            settings,
+            settings_draft: None,
+            dashboard: None,
+            dashboard_scenario: None,
+            dashboard_days: DASHBOARD_PERIODS[1],
+            dashboard_search: String::new(),
+            run_note_edit: None,
+            selected_profile: None,
+            param_violations: Vec::new(),
+            run_approval_prompt: None,
+            granted_approval: None,
+            starting_approval: None,
+            scenario_search: None,
+            exit_shutdown: None,
+            exit_allowed: false,
         }
     }
 
-    /// 엔진 이벤트를 모두 소비하여 UI 상태를 동기화한다.
-    pub(super) fn drain_events(&mut self) {
+    /// 엔진 이벤트를 프레임당 최대 [`MAX_EVENTS_PER_FRAME`]개까지 소비하여 UI 상태를 동기화한다.
+    ///
+    /// 처리하지 못한 이벤트가 남아 있으면 `true`를 반환한다.
+    pub(super) fn drain_events(&mut self) -> bool {
+        self.drain_daemon_messages();
+        self.poll_db_reload();
+        self.poll_sql_preview();
+        self.poll_step_stats();
+        self.poll_schema_fetch();
+        self.poll_dashboard();
+        self.poll_scenario_search();
+        self.poll_shell_trial();
+        self.poll_step_test();
+        if let Some(watch) = &mut self.file_watch {
+            watch.poll();
+        }
+        let mut pending = false;
         if let Some(mut rx) = self.events_rx.take() {
-            while let Ok(event) = rx.try_recv() {
-                match event {
-                    EngineEvent::StepStarted { step_id } => {
-                        self.mark_step_running(&step_id);
-                    }
-                    EngineEvent::StepLog { step_id, line } => {
-                        self.push_log(&step_id, line);
-                    }
-                    EngineEvent::StepFinished { step_id, success } => {
-                        self.mark_step_finished(&step_id, success);
+            let mut processed = 0;
+            let mut closed = false;
+            loop {
+                match rx.try_recv() {
+                    Ok(event) => self.apply_engine_event(event),
+                    Err(mpsc::error::TryRecvError::Empty) => break,
+                    Err(mpsc::error::TryRecvError::Disconnected) => {
+                        closed = true;
+                        break;
                     }
-                    EngineEvent::RequestConfirm {
-                        request_id,
-                        step_id,
-                        step_name,
-                        step_kind,
-                        summary,
-                        message,
-                        default_answer,
-                        phase,
-                    } => {
-                        self.pending_confirms.push(PendingConfirmRequest {
-                            request_id,
-                            step_id,
-                            step_name,
-                            step_kind,
-                            summary,
-                            message,
-                            default_answer,
-                            phase,
-                        });
-                    }
-                    EngineEvent::ConfirmResponse {
-                        request_id,
-                        step_id,
-                        accepted,
-                    } => {
-                        self.pending_confirms
-                            .retain(|req| req.request_id != request_id);
-                        let log_line = if accepted {
-                            "컨펌 승인".to_string()
-                        } else {
-                            "컨펌 거부".to_string()
-                        };
-                        self.push_log(&step_id, log_line);
+                }
+                processed += 1;
+                if processed >= MAX_EVENTS_PER_FRAME {
+                    pending = !rx.is_empty();
+                    break;
+                }
+            }
+            // 엔진과 이벤트 중계 작업(로그 파일·저널 기록)이 모두 끝나야 채널이 닫힌다.
+            if closed {
+                self.end_closed_run();
+            } else {
+                self.events_rx = Some(rx);
+            }
+        }
+        pending
+    }
+
+    /// 이벤트 채널이 닫히면 엔진이 남긴 오류를 알리고, `ScenarioFinished`를 받지 못했으면 실행을 끝난
+    /// 것으로 정리한다.
+    ///
+    /// 엔진이 시작 준비 중 오류로 끝나면 `ScenarioFinished` 뒤에 오류가 남는다. 패닉으로 멈춰도 실행 중
+    /// 상태와 취소 토큰이 남지 않게 한다.
+    fn end_closed_run(&mut self) {
+        let failure = self
+            .run_failure
+            .lock()
+            .ok()
+            .and_then(|mut failure| failure.take());
+        if self.cancel_token.is_some() {
+            self.apply_engine_event(EngineEvent::ScenarioFinished);
+            if failure.is_none() {
+                self.last_error = Some(t("app.run_ended_unexpectedly").into());
+            }
+        }
+        if let Some(failure) = failure {
+            self.last_error = Some(failure);
+        }
+    }
+
+    /// 재시도 대기 카운트다운이 진행 중인 Step이 있는지 확인한다.
+    pub(super) fn has_retry_countdown(&self) -> bool {
+        let now = std::time::Instant::now();
+        self.step_states.values().any(|state| {
+            matches!(state.status, StepStatus::Retrying { resume_at, .. } if resume_at > now)
+        })
+    }
+
+    /// DB 핸들 재구성이 끝났으면 결과를 빌더 안내 또는 오류 메시지로 표시한다.
+    fn poll_db_reload(&mut self) {
+        let Some(rx) = &mut self.db_reload_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(Ok(())) => self.editor_notice = Some(t("app.db_reloaded").into()),
+            Ok(Err(err)) => {
+                self.editor_notice = None;
+                self.editor_error = Some(tf("app.db_reload_failed", &[("error", &err)]));
+            }
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => self.editor_notice = None,
+        }
+        self.db_reload_rx = None;
+    }
+
+    /// SQL 미리보기가 끝났으면 같은 Step의 미리보기 결과로 채운다.
+    fn poll_sql_preview(&mut self) {
+        let Some((step_id, rx)) = &mut self.sql_preview_rx else {
+            return;
+        };
+        let status = match rx.try_recv() {
+            Ok(Ok(rows)) => SqlPreviewStatus::Done(rows),
+            Ok(Err(err)) => SqlPreviewStatus::Failed(err),
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                SqlPreviewStatus::Failed(t("app.sql_preview_aborted").into())
+            }
+        };
+        if let Some(preview) = &mut self.editor_state.sql_preview
+            && preview.step_id == *step_id
+            && matches!(preview.status, SqlPreviewStatus::Running)
+        {
+            preview.status = status;
+        }
+        self.sql_preview_rx = None;
+    }
+
+    /// 스키마 조회가 끝났으면 같은 DB 타겟을 보고 있는 스키마 브라우저에 결과를 채운다.
+    fn poll_schema_fetch(&mut self) {
+        let Some(task) = &mut self.schema_fetch else {
+            return;
+        };
+        let reply = match task.rx.try_recv() {
+            Ok(reply) => reply,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => Err(t("app.schema_aborted").into()),
+        };
+        let Some(task) = self.schema_fetch.take() else {
+            return;
+        };
+        let Some(browser) = &mut self.editor_state.schema_browser else {
+            return;
+        };
+        if browser.target_db != task.target_db {
+            return;
+        }
+        match (task.request, reply) {
+            (SchemaRequest::Tables, Ok(SchemaReply::Tables(tables))) => {
+                browser.tables = SchemaFetch::Done(tables);
+            }
+            (SchemaRequest::Tables, Err(err)) => browser.tables = SchemaFetch::Failed(err),
+            (SchemaRequest::Columns(table), Ok(SchemaReply::Columns(columns))) => {
+                browser.columns.insert(table, SchemaFetch::Done(columns));
+            }
+            (SchemaRequest::Columns(table), Err(err)) => {
+                browser.columns.insert(table, SchemaFetch::Failed(err));
+            }
+            _ => {}
+        }
+    }
+
+    /// Shell 로컬 테스트가 끝났으면 같은 Step의 테스트 결과로 채운다.
+    fn poll_shell_trial(&mut self) {
+        let Some((step_id, rx)) = &mut self.shell_trial_rx else {
+            return;
+        };
+        let status = match rx.try_recv() {
+            Ok(Ok(output)) => ShellTrialStatus::Done {
+                exit_code: output.exit_code,
+                output: output.output,
+            },
+            Ok(Err(err)) => ShellTrialStatus::Failed(err),
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                ShellTrialStatus::Failed(t("app.shell_trial_aborted").into())
+            }
+        };
+        if let Some(trial) = &mut self.editor_state.shell_trial
+            && trial.step_id == *step_id
+            && trial.status == ShellTrialStatus::Running
+        {
+            trial.status = status;
+        }
+        self.shell_trial_rx = None;
+    }
+
+    /// 단일 Step 테스트의 로그를 콘솔에 옮기고, 대상 Step이 끝나면 결과를 채운다.
+    fn poll_step_test(&mut self) {
+        let Some((step_id, rx)) = &mut self.step_test_rx else {
+            return;
+        };
+        let mut outcome = None;
+        let mut closed = false;
+        loop {
+            match rx.try_recv() {
+                Ok(EngineEvent::StepLog { line, .. }) => {
+                    if let Some(test) = &mut self.editor_state.step_test
+                        && test.step_id == *step_id
+                    {
+                        test.push_log(line);
                     }
-                    EngineEvent::ScenarioFinished => {
-                        self.scenario_running = false;
-                        self.cancel_token = None;
-                        self.pending_confirms.clear();
-                        self.confirm_bridge = None;
+                }
+                Ok(EngineEvent::StepFinished {
+                    step_id: finished,
+                    success,
+                    error,
+                    ..
+                }) if finished == *step_id => {
+                    outcome = Some(if success {
+                        StepTestStatus::Passed
+                    } else {
+                        StepTestStatus::Failed(
+                            error
+                                .map(|err| err.to_string())
+                                .unwrap_or_else(|| t("app.step_test_failed").into()),
+                        )
+                    });
+                }
+                Ok(_) => {}
+                Err(mpsc::error::TryRecvError::Empty) => break,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    closed = true;
+                    break;
+                }
+            }
+        }
+        if let Some(test) = &mut self.editor_state.step_test
+            && test.step_id == *step_id
+            && test.is_active()
+        {
+            if let Some(status) = outcome {
+                test.status = status;
+            } else if closed {
+                let key = if test.status == StepTestStatus::StopRequested {
+                    "app.step_test_stopped"
+                } else {
+                    "app.step_test_aborted"
+                };
+                test.status = StepTestStatus::Failed(t(key).into());
+            }
+        }
+        if !closed {
+            return;
+        }
+        self.step_test_rx = None;
+        self.step_test_cancel = None;
+    }
+
+    /// 데몬 클라이언트가 받은 메시지를 모두 반영한다.
+    fn drain_daemon_messages(&mut self) {
+        while let Some(event) = self.daemon.as_mut().and_then(DaemonClient::try_recv) {
+            match event {
+                DaemonClientEvent::Connected => {
+                    self.daemon_connected = true;
+                    self.last_error = None;
+                }
+                DaemonClientEvent::Disconnected { reason } => {
+                    self.daemon_connected = false;
+                    self.pending_confirms.clear();
+                    self.last_error = Some(tf("app.daemon_disconnected", &[("reason", &reason)]));
+                }
+                DaemonClientEvent::Message(DaemonMessage::Resync {
+                    scenario,
+                    running,
+                    events,
+                }) => {
+                    self.apply_resync(scenario, running, events);
+                }
+                DaemonClientEvent::Message(DaemonMessage::Event { event }) => {
+                    self.apply_engine_event(event);
+                }
+                DaemonClientEvent::Message(DaemonMessage::Error { message }) => {
+                    self.last_error = Some(message);
+                    // 로컬 상태가 데몬과 어긋났을 수 있으므로 전체 상태를 다시 받는다.
+                    if let Some(daemon) = &self.daemon {
+                        daemon.send(DaemonRequest::Attach);
                     }
                 }
             }
-            self.events_rx = Some(rx);
+        }
+    }
+
+    /// 데몬이 보낸 전체 상태로 실행 탭을 다시 구성한다.
+    fn apply_resync(
+        &mut self,
+        scenario: Option<Scenario>,
+        running: bool,
+        events: Vec<EngineEvent>,
+    ) {
+        self.pending_confirms.clear();
+        if let Some(scenario) = scenario {
+            let same_scenario = self
+                .scenario
+                .as_ref()
+                .is_some_and(|current| current.name == scenario.name);
+            if !same_scenario {
+                self.scenario_path = None;
+                self.selected_step = scenario.steps.first().map(|s| s.id.clone());
+            }
+            self.reset_run_state(&scenario);
+            self.scenario = Some(scenario);
+            self.refresh_step_stats();
+            self.refresh_param_violations();
+        }
+        for event in events {
+            self.apply_engine_event(event);
+        }
+        self.scenario_running = running;
+    }
+
+    /// 엔진 이벤트 하나를 UI 상태에 반영한다.
+    fn apply_engine_event(&mut self, event: EngineEvent) {
+        match event {
+            EngineEvent::RunStarted { run_id } => {
+                if let Some(record) = self.starting_approval.take()
+                    && let Err(err) = record.record_started(&run_id)
+                {
+                    self.last_error = Some(format!("{err:#}"));
+                }
+                self.attach_expanded_yaml(&run_id);
+                self.attach_run_note(run_id);
+            }
+            EngineEvent::StepStarted { step_id } => {
+                self.mark_step_running(&step_id);
+            }
+            EngineEvent::StepWaiting { step_id, until } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.status = StepStatus::Waiting(until);
+            }
+            EngineEvent::StepRetrying {
+                step_id,
+                attempt,
+                max,
+                next_delay,
+            } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.status = StepStatus::Retrying {
+                    attempt,
+                    max,
+                    resume_at: std::time::Instant::now() + next_delay,
+                };
+            }
+            EngineEvent::StepTimeoutWarning { step_id, elapsed } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.overrun = Some(elapsed);
+            }
+            EngineEvent::StepLog { step_id, line } => {
+                self.push_log(&step_id, line);
+            }
+            EngineEvent::StepBlocked { step_id } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.status = StepStatus::Blocked;
+                state.finished_at = Some(std::time::Instant::now());
+            }
+            EngineEvent::StepAwaitingApproval { step_id, .. } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.status = StepStatus::AwaitingApproval;
+            }
+            EngineEvent::StepApproved {
+                step_id,
+                approver,
+                approved_at,
+            } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.status = StepStatus::Running;
+                state.approval = Some(GateApproval {
+                    approver,
+                    approved_at,
+                });
+            }
+            EngineEvent::LoopProgress {
+                step_id,
+                done,
+                total,
+            } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.iterations = Some((done, total));
+            }
+            EngineEvent::StepFinished {
+                step_id,
+                success,
+                error,
+                context,
+            } => {
+                self.mark_step_finished(&step_id, success, error, context);
+            }
+            EngineEvent::StepOutputs { step_id, outputs } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.outputs = outputs;
+            }
+            EngineEvent::StepVariables { step_id, vars } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.set_vars = vars;
+            }
+            EngineEvent::StepDiagnostics { step_id, results } => {
+                let state = self.step_states.entry(step_id).or_default();
+                state.diagnostics = results;
+            }
+            EngineEvent::RequestConfirm {
+                request_id,
+                step_id,
+                step_name,
+                step_kind,
+                summary,
+                message,
+                default_answer,
+                phase,
+            } => {
+                self.pending_confirms.push(PendingConfirmRequest {
+                    request_id,
+                    step_id,
+                    step_name,
+                    step_kind,
+                    summary,
+                    message,
+                    default_answer,
+                    phase,
+                });
+            }
+            EngineEvent::ConfirmResponse {
+                request_id,
+                step_id,
+                accepted,
+            } => {
+                self.pending_confirms
+                    .retain(|req| req.request_id != request_id);
+                let log_line = if accepted {
+                    t("app.confirm_accepted").to_string()
+                } else {
+                    t("app.confirm_rejected").to_string()
+                };
+                self.push_log(&step_id, log_line);
+            }
+            EngineEvent::RunSummary { results } => {
+                self.run_summary = Some(RunSummaryReport {
+                    received_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                    results,
+                });
+            }
+            EngineEvent::DbPoolUsage { pools } => {
+                self.db_pool_usage = pools;
+            }
+            EngineEvent::ScenarioFinished => {
+                self.scenario_running = false;
+                self.starting_approval = None;
+                self.db_pool_usage.clear();
+                self.cancel_token = None;
+                self.pending_confirms.clear();
+                self.confirm_bridge = None;
+                self.refresh_step_stats();
+            }
         }
     }
 
@@ -177,12 +987,33 @@ impl BatchOrchestratorApp {
         let state = self.step_states.entry(step_id.to_string()).or_default();
         state.status = StepStatus::Running;
         state.started_at = Some(std::time::Instant::now());
+        state.overrun = None;
+        state.error = None;
+        state.context_snapshot = None;
+        state.diagnostics.clear();
+        state.set_vars.clear();
+        state.outputs.clear();
     }
 
     /// Step이 종료되었음을 기록한다.
-    fn mark_step_finished(&mut self, step_id: &str, success: bool) {
+    fn mark_step_finished(
+        &mut self,
+        step_id: &str,
+        success: bool,
+        error: Option<EngineError>,
+        context: Option<ContextSnapshot>,
+    ) {
         let state = self.step_states.entry(step_id.to_string()).or_default();
         state.finished_at = Some(std::time::Instant::now());
+        state.error = error;
+        state.context_snapshot = context;
+        if self
+            .step_preview
+            .as_ref()
+            .is_some_and(|(id, _)| id == step_id)
+        {
+            self.step_preview = None;
+        }
         if success {
             state.status = StepStatus::Success;
         } else if !matches!(state.status, StepStatus::Failed(_)) {
@@ -191,13 +1022,19 @@ impl BatchOrchestratorApp {
                 .get(step_id)
                 .and_then(|logs| logs.last())
                 .cloned()
-                .unwrap_or_else(|| "실패".into());
+                .unwrap_or_else(|| t("status.failed").into());
             state.status = StepStatus::Failed(fallback);
         }
     }
 
     /// Step별 로그를 버퍼에 적재한다.
     fn push_log(&mut self, step_id: &str, line: String) {
+        let lower = self.step_logs_lower.entry(step_id.to_string()).or_default();
+        lower.push(line.to_lowercase());
+        if lower.len() > MAX_LOG_LINES {
+            let overflow = lower.len() - MAX_LOG_LINES;
+            lower.drain(0..overflow);
+        }
         let entry = self.step_logs.entry(step_id.to_string()).or_default();
         entry.push(line.clone());
         if entry.len() > MAX_LOG_LINES {
@@ -214,157 +1051,1578 @@ impl BatchOrchestratorApp {
 
     /// 파일 다이얼로그로부터 시나리오를 로드한다.
     pub(super) fn load_scenario_from_dialog(&mut self) {
-        if let Some(path) = rfd::FileDialog::new()
-            .add_filter("YAML", &["yaml", "yml"])
-            .pick_file()
-        {
+        if let Some(path) = scenario_file_dialog().pick_file() {
             self.apply_scenario_path(path.into());
         }
     }
 
+    /// 실행 탭에 끌어다 놓은 시나리오 파일을 불러온다. 여러 파일을 놓으면 첫 시나리오 파일만 쓴다.
+    pub(super) fn load_dropped_scenario(&mut self, ctx: &egui::Context) {
+        let path = ctx.input(|input| {
+            input
+                .raw
+                .dropped_files
+                .iter()
+                .filter_map(|file| file.path.clone())
+                .find(|path| {
+                    is_encrypted_path(path)
+                        || path.extension().is_some_and(|ext| {
+                            ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml")
+                        })
+                })
+        });
+        if let Some(path) = path {
+            self.apply_scenario_path(path);
+        }
+    }
+
     /// 주어진 경로의 YAML을 파싱한다.
     pub(super) fn apply_scenario_path(&mut self, path: PathBuf) {
         match load_scenario_from_file(&path) {
             Ok(scenario) => self.apply_loaded_scenario(scenario, path),
             Err(err) => {
-                self.last_error = Some(err.to_string());
+                if !self.prompt_passphrase_if_needed(&err, PassphraseAction::OpenRun(path))
+                    && !self.show_scenario_error(&err)
+                {
+                    self.last_error = Some(err.to_string());
+                }
             }
         }
     }
 
+    /// 암호 문구가 없거나 틀려서 실패한 경우 입력 대화상자를 띄우고 `true`를 반환한다.
+    fn prompt_passphrase_if_needed(
+        &mut self,
+        err: &anyhow::Error,
+        action: PassphraseAction,
+    ) -> bool {
+        let error = match err.downcast_ref::<ScenarioCryptoError>() {
+            Some(ScenarioCryptoError::PassphraseRequired) => None,
+            Some(ScenarioCryptoError::WrongPassphrase) => Some(err.to_string()),
+            _ => return false,
+        };
+        self.passphrase_prompt = Some(PassphrasePrompt {
+            input: String::new(),
+            error,
+            action,
+        });
+        true
+    }
+
+    /// 시나리오 형식 오류이면 오류 창에 띄우고 `true`를 반환한다.
+    fn show_scenario_error(&mut self, err: &anyhow::Error) -> bool {
+        let Some(parse_err) = err.downcast_ref::<ScenarioParseError>() else {
+            return false;
+        };
+        self.scenario_error = Some(parse_err.clone());
+        true
+    }
+
+    /// 입력한 암호 문구를 기억하고 보류된 파일 작업을 다시 수행한다.
+    pub(super) fn submit_passphrase(&mut self) {
+        let Some(prompt) = self.passphrase_prompt.take() else {
+            return;
+        };
+        remember_passphrase(prompt.input);
+        match prompt.action {
+            PassphraseAction::OpenRun(path) => self.apply_scenario_path(path),
+            PassphraseAction::OpenEditor(path) => self.editor_open_from_path(path),
+            PassphraseAction::Save(path) => self.editor_save_to(path),
+        }
+    }
+
     /// 로드된 시나리오를 공용 상태에 반영한다.
     fn apply_loaded_scenario(&mut self, scenario: Scenario, path: PathBuf) {
+        self.remember_scenario_view();
+        self.scenario_error = None;
         self.step_states.clear();
         self.step_logs.clear();
+        self.step_logs_lower.clear();
+        self.step_preview = None;
+        self.expanded_yaml = None;
+        self.run_summary = None;
+        self.db_pool_usage.clear();
         for step in &scenario.steps {
             self.step_states
                 .insert(step.id.clone(), StepRuntimeState::new());
             self.step_logs.insert(step.id.clone(), Vec::new());
+            self.step_logs_lower.remove(&step.id);
         }
         self.selected_step = scenario.steps.first().map(|s| s.id.clone());
         match scenario_to_editor_state(&scenario) {
             Ok(mut editor_state) => {
                 editor_state.current_file = Some(path.clone());
                 editor_state.dirty = false;
-                self.editor_state = editor_state;
+                self.replace_editor_state(editor_state);
                 self.editor_error = None;
             }
             Err(err) => {
-                self.editor_state = ScenarioEditorState::new();
-                self.editor_state.current_file = Some(path.clone());
-                self.editor_state.dirty = false;
+                let mut editor_state = ScenarioEditorState::new();
+                editor_state.current_file = Some(path.clone());
+                editor_state.dirty = false;
+                self.replace_editor_state(editor_state);
                 self.editor_error = Some(err.to_string());
             }
         }
+        self.last_error = match load_connection_store() {
+            Ok(store) => {
+                let check = store.check_scenario(&scenario);
+                self.editor_state.shared_connections = store.connections;
+                check
+                    .err()
+                    .map(|err| err.to_string())
+                    .or_else(|| missing_files_summary(&check_scenario_files(&scenario)))
+            }
+            Err(err) => Some(err.to_string()),
+        };
+        if let Some(saved) = scenario
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.profile.clone())
+        {
+            self.selected_profile = Some(saved);
+        }
+        if self
+            .selected_profile
+            .as_ref()
+            .is_some_and(|name| !scenario.profiles.contains_key(name))
+        {
+            self.selected_profile = None;
+        }
+        if self.editor_state.current_file.as_ref() == Some(&path) {
+            self.editor_state.selected_profile = self.selected_profile.clone();
+        }
+        self.file_watch = Some(ScenarioFileWatch::new(path.clone(), &scenario));
         self.scenario = Some(scenario);
         self.scenario_path = Some(path);
-        self.last_error = None;
+        self.restore_scenario_view();
+        self.refresh_step_stats();
+        self.refresh_param_violations();
     }
 
-    /// 시나리오 실행을 시작한다.
-    pub(super) fn start_scenario(&mut self) {
+    /// 열린 시나리오 파일의 화면 상태를 앱 설정에 기억한다. 바뀐 것이 있을 때만 설정 파일에 쓴다.
+    ///
+    /// 캔버스 보기는 빌더가 같은 파일의 최상위 흐름을 보고 있을 때만 갱신한다.
+    pub(super) fn remember_scenario_view(&mut self) {
+        let Some(path) = &self.scenario_path else {
+            return;
+        };
+        let key = scenario_view_key(path);
+        let previous = self.settings.scenario_views.get(&key);
+        let mut prefs = ScenarioViewPrefs {
+            selected_step: self.selected_step.clone(),
+            collapsed_stages: self.collapsed_stages.clone(),
+            log_filter: self.log_filter.trim().to_string(),
+            canvas_offset: previous.and_then(|prefs| prefs.canvas_offset),
+            canvas_zoom: previous.and_then(|prefs| prefs.canvas_zoom),
+            updated_at: previous.map_or(0, |prefs| prefs.updated_at),
+        };
+        if self.editor_state.current_file.as_ref() == Some(path)
+            && self.editor_state.loop_stack.is_empty()
+        {
+            let offset = self.editor_state.canvas_offset;
+            prefs.canvas_offset = Some([offset.x, offset.y]);
+            prefs.canvas_zoom = Some(self.editor_state.canvas_zoom);
+        }
+        if previous == Some(&prefs) {
+            return;
+        }
+        self.settings.remember_scenario_view(key, prefs);
+        if let Err(err) = save_app_settings(&self.settings) {
+            self.last_error = Some(tf("settings.save_failed", &[("error", &err)]));
+        }
+    }
+
+    /// 앱 설정에 기억한 화면 상태를 방금 연 시나리오에 되살린다.
+    ///
+    /// 시나리오에 더 이상 없는 Step은 선택하지 않으며, 빌더 캔버스 보기는 최상위 흐름일 때만 바꾼다.
+    fn restore_scenario_view(&mut self) {
+        self.collapsed_stages.clear();
+        self.log_filter.clear();
+        let Some(prefs) = self
+            .scenario_path
+            .as_deref()
+            .and_then(|path| self.settings.scenario_views.get(&scenario_view_key(path)))
+            .cloned()
+        else {
+            return;
+        };
+        if let Some(step_id) = prefs.selected_step
+            && self.step_states.contains_key(&step_id)
+        {
+            self.run_focus = Some(step_id.clone());
+            self.selected_step = Some(step_id);
+        }
+        self.collapsed_stages = prefs.collapsed_stages;
+        self.log_filter = prefs.log_filter;
+        if self.editor_state.loop_stack.is_empty() {
+            if let Some([x, y]) = prefs.canvas_offset {
+                self.editor_state.canvas_offset = egui::vec2(x, y);
+            }
+            if let Some(zoom) = prefs.canvas_zoom {
+                self.editor_state.canvas_zoom = zoom;
+            }
+        }
+    }
+
+    /// 외부에서 바뀐 시나리오 파일을 다시 불러온다.
+    ///
+    /// ID가 같은 빌더 노드는 기존 위치를 유지하고, 캔버스 이동/확대와 선택한 Step도 가능한 한
+    /// 그대로 둔다. 실행 중에는 Step 상태가 초기화되므로 다시 불러오지 않는다.
+    pub(super) fn reload_scenario_from_disk(&mut self) {
         if self.scenario_running {
             return;
         }
-        let scenario = match self.scenario.clone() {
-            Some(s) => s,
-            None => {
-                self.last_error = Some("시나리오가 로드되지 않았습니다.".into());
+        let Some(path) = self.file_watch.as_ref().map(|watch| watch.path.clone()) else {
+            return;
+        };
+        let scenario = match load_scenario_from_file(&path) {
+            Ok(scenario) => scenario,
+            Err(err) => {
+                if let Some(watch) = &mut self.file_watch {
+                    watch.acknowledge();
+                }
+                if !self.prompt_passphrase_if_needed(&err, PassphraseAction::OpenEditor(path))
+                    && !self.show_scenario_error(&err)
+                {
+                    self.last_error = Some(tf("reload.failed", &[("error", &err)]));
+                }
                 return;
             }
         };
-        self.step_logs.clear();
-        self.step_states.clear();
-        for step in &scenario.steps {
-            self.step_states
-                .insert(step.id.clone(), StepRuntimeState::new());
-            self.step_logs.insert(step.id.clone(), Vec::new());
+        self.editor_state.close_loops_to(0);
+        let positions: HashMap<String, egui::Pos2> = self
+            .editor_state
+            .nodes
+            .iter()
+            .map(|node| (node.id.clone(), node.position))
+            .collect();
+        let canvas_offset = self.editor_state.canvas_offset;
+        let canvas_zoom = self.editor_state.canvas_zoom;
+        let selected_step = self.selected_step.clone();
+        self.apply_loaded_scenario(scenario, path);
+        self.editor_state.close_loops_to(0);
+        for node in &mut self.editor_state.nodes {
+            if let Some(position) = positions.get(&node.id) {
+                node.position = *position;
+            }
+        }
+        self.editor_state.canvas_offset = canvas_offset;
+        self.editor_state.canvas_zoom = canvas_zoom;
+        if let Some(step_id) = selected_step
+            && self.step_states.contains_key(&step_id)
+        {
+            self.selected_step = Some(step_id);
         }
-        let (tx, rx) = mpsc::unbounded_channel();
-        let token = CancellationToken::new();
-        let confirm_bridge = ConfirmBridge::new();
-        self.runtime.spawn(run_scenario(
-            scenario.clone(),
-            self.executor.clone(),
-            tx,
-            token.clone(),
-            Some(confirm_bridge.clone()),
-        ));
-        self.events_rx = Some(rx);
-        self.cancel_token = Some(token);
-        self.scenario_running = true;
-        self.last_error = None;
-        self.confirm_bridge = Some(confirm_bridge);
     }
 
-    /// 현재 실행 중인 시나리오를 중단한다.
-    pub(super) fn stop_scenario(&mut self) {
-        if let Some(token) = &self.cancel_token {
-            token.cancel();
+    /// 외부 변경을 무시하고 메모리의 시나리오를 유지한다.
+    ///
+    /// 디스크와 내용이 달라졌으므로 빌더를 저장하지 않은 상태로 표시한다.
+    pub(super) fn keep_local_scenario(&mut self) {
+        if let Some(watch) = &mut self.file_watch {
+            watch.acknowledge();
         }
-        self.scenario_running = false;
-        self.pending_confirms.clear();
-        self.confirm_bridge = None;
+        self.editor_state.dirty = true;
     }
 
-    /// 새 시나리오 빌더 문서를 생성한다.
-    pub(super) fn editor_new_document(&mut self) {
-        self.editor_state = ScenarioEditorState::new();
-        self.editor_error = None;
+    /// 현재 시나리오의 실행 이력 통계를 백그라운드에서 다시 읽는다.
+    ///
+    /// 마지막 성공 실행 이후 바뀐 `sql_file`도 함께 찾는다. 이력 파일과 SQL 파일을 읽는 동안 화면이
+    /// 멈추지 않도록 블로킹 작업으로 계산하고, [`Self::poll_step_stats`]가 결과를 실행 탭과 빌더에 반영한다.
+    fn refresh_step_stats(&mut self) {
+        let Some(scenario) = self.scenario.clone() else {
+            self.step_stats_rx = None;
+            self.step_stats.clear();
+            self.editor_state.step_stats.clear();
+            self.editor_state.sql_changes.clear();
+            return;
+        };
+        let (tx, rx) = oneshot::channel();
+        let name = scenario.name.clone();
+        self.engine_runtime.spawn_blocking(move || {
+            let stats = load_step_stats(&scenario.name);
+            let changes = changed_sql_files(&scenario);
+            let _ = tx.send((stats, changes));
+        });
+        self.step_stats_rx = Some((name, rx));
     }
 
-    /// 파일 다이얼로그에서 YAML을 로드한다.
-    pub(super) fn editor_open_dialog(&mut self) {
-        if let Some(path) = rfd::FileDialog::new()
-            .add_filter("YAML", &["yaml", "yml"])
-            .pick_file()
+    /// 실행 이력 통계 계산이 끝났으면 지금 시나리오의 결과일 때만 실행 탭과 빌더 배지에 반영한다.
+    fn poll_step_stats(&mut self) {
+        let Some((name, rx)) = &mut self.step_stats_rx else {
+            return;
+        };
+        let (stats, changes) = match rx.try_recv() {
+            Ok(reply) => reply,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.step_stats_rx = None;
+                return;
+            }
+        };
+        if self
+            .scenario
+            .as_ref()
+            .is_some_and(|scenario| scenario.name == *name)
         {
-            self.editor_open_from_path(path.into());
+            self.editor_state.step_stats = stats.clone();
+            self.step_stats = stats;
+            self.editor_state.sql_changes = changes;
         }
+        self.step_stats_rx = None;
     }
 
-    /// 지정된 경로의 YAML을 에디터로 불러온다.
-    pub(super) fn editor_open_from_path(&mut self, path: PathBuf) {
-        match load_scenario_from_file(&path) {
-            Ok(scenario) => {
-                self.apply_loaded_scenario(scenario, path);
-            }
+    /// 시나리오 실행을 시작한다.
+    pub(super) fn start_scenario(&mut self) {
+        if self.scenario_running {
+            return;
+        }
+        if let Ok(edited) = editor_state_to_scenario(&self.editor_state) {
+            self.apply_db_definitions(&edited);
+        }
+        let mut scenario = match self.scenario_with_run_params() {
+            Ok(scenario) => scenario,
             Err(err) => {
-                self.editor_error = Some(err.to_string());
+                self.last_error = Some(err);
+                return;
+            }
+        };
+        self.param_violations = scenario.param_violations();
+        if let Err(err) = scenario.check_param_rules() {
+            self.last_error = Some(err.to_string());
+            return;
+        }
+        if let Err(err) = scenario.check_contracts() {
+            self.last_error = Some(err.to_string());
+            return;
+        }
+        if let Some(summary) = missing_files_summary(&check_scenario_files(&scenario)) {
+            self.last_error = Some(summary);
+            return;
+        }
+        if !self.cost_override {
+            let warnings: Vec<CostWarning> = check_scenario_cost(&scenario)
+                .into_iter()
+                .filter(|warning| !warning.acknowledged)
+                .collect();
+            if !warnings.is_empty() {
+                self.cost_warning_prompt = Some(warnings);
+                return;
+            }
+        }
+        if !self.ensure_run_approved(ApprovedRunAction::Start) {
+            return;
+        }
+        self.cost_override = false;
+        self.apply_granted_approval(&mut scenario);
+        self.reset_run_state(&scenario);
+        self.launch_scenario(scenario, Vec::new());
+    }
+
+    /// 실행 프로필이나 가져올 컨텍스트, 시나리오가 바뀌었을 때 실행 파라미터의 규칙 위반을 다시 계산한다.
+    ///
+    /// 컨텍스트 파일을 읽지 못하면 그 오류는 실행할 때 알리고 여기서는 위반 없음으로 둔다.
+    pub(super) fn refresh_param_violations(&mut self) {
+        self.param_violations = self
+            .scenario_with_run_params()
+            .map(|scenario| scenario.param_violations())
+            .unwrap_or_default();
+    }
+
+    /// 불러온 시나리오에 컨텍스트 가져오기 파일과 고른 실행 프로필의 파라미터를 합친 실행용 사본을 만든다.
+    fn scenario_with_run_params(&self) -> Result<Scenario, String> {
+        let mut scenario = self
+            .scenario
+            .clone()
+            .ok_or_else(|| t("app.no_scenario").to_string())?;
+        if let Some(path) = &self.context_in {
+            let vars = load_context_file(path).map_err(|err| format!("{err:#}"))?;
+            scenario.params.extend(vars);
+        }
+        // 이전 실행의 컨텍스트가 다른 환경의 값을 가져와도 고른 프로필의 값이 이긴다.
+        if let Some((_, profile)) = self.selected_profile_config() {
+            scenario.params.extend(profile.params.clone());
+        }
+        Ok(scenario)
+    }
+
+    /// 지금 실행하면 엔진이 볼 시나리오를 플레이스홀더를 치환한 YAML로 만들어 펼친 YAML 창을 연다.
+    pub(super) fn open_expanded_yaml(&mut self) {
+        match self
+            .scenario_with_run_params()
+            .and_then(|scenario| expand_scenario_yaml(&scenario).map_err(|err| format!("{err:#}")))
+        {
+            Ok(yaml) => self.expanded_yaml = Some(yaml),
+            Err(err) => self.last_error = Some(err),
+        }
+    }
+
+    /// 펼친 YAML 창의 내용을 파일로 저장한다.
+    pub(super) fn save_expanded_yaml(&mut self) {
+        let Some(yaml) = &self.expanded_yaml else {
+            return;
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("YAML", &["yaml", "yml"])
+            .set_file_name("expanded.yaml")
+            .save_file()
+        else {
+            return;
+        };
+        if let Err(err) = std::fs::write(&path, yaml) {
+            self.last_error = Some(tf("expanded.save_failed", &[("error", &err)]));
+        }
+    }
+
+    /// 실행 시작 때 남겨 둔 펼친 YAML을 실행 ID와 함께 실행 로그 디렉터리에 첨부한다.
+    fn attach_expanded_yaml(&mut self, run_id: &str) {
+        let Some((name, yaml)) = self.expanded_attachment.take() else {
+            return;
+        };
+        if let Err(err) = write_expanded_yaml(&name, run_id, &yaml) {
+            self.last_error = Some(format!("{err:#}"));
+        }
+    }
+
+    /// 고른 실행 프로필의 이름과 설정을 반환한다. 고르지 않았거나 시나리오에 없으면 `None`이다.
+    pub(super) fn selected_profile_config(&self) -> Option<(&str, &ExecutionProfile)> {
+        let name = self.selected_profile.as_deref()?;
+        let profile = self.scenario.as_ref()?.profiles.get(name)?;
+        Some((name, profile))
+    }
+
+    /// 고른 프로필이 승인을 요구하면 승인 요청을 감사 기록에 남기고 승인 대화상자를 띄운다.
+    ///
+    /// # 반환값
+    /// 바로 실행해도 되면 `true`. 승인을 기다려야 하면 `false`이며, 승인되면 `action`을 다시 수행한다.
+    fn ensure_run_approved(&mut self, action: ApprovedRunAction) -> bool {
+        let Some((name, profile)) = self.selected_profile_config() else {
+            return true;
+        };
+        if !profile.requires_approval || self.granted_approval.is_some() {
+            return true;
+        }
+        if self.run_approval_prompt.is_some() {
+            return false;
+        }
+        let profile_name = name.to_string();
+        let (scenario_name, yaml) = match self.loaded_scenario_yaml() {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                self.last_error = Some(format!("{err:#}"));
+                return false;
+            }
+        };
+        match ApprovalRequest::create(&scenario_name, &yaml, &profile_name, &local_user_name()) {
+            Ok(request) => {
+                self.run_approval_prompt = Some(RunApprovalPrompt {
+                    request,
+                    action,
+                    approver: String::new(),
+                    code: String::new(),
+                    error: None,
+                });
+            }
+            Err(err) => self.last_error = Some(format!("{err:#}")),
+        }
+        false
+    }
+
+    /// 승인 대화상자에 입력한 승인자와 코드로 승인하고, 승인되면 요청했던 실행을 시작한다.
+    pub(super) fn approve_run(&mut self) {
+        let Some(mut prompt) = self.run_approval_prompt.take() else {
+            return;
+        };
+        if self
+            .selected_profile_config()
+            .is_none_or(|(name, _)| name != prompt.request.profile)
+        {
+            return;
+        }
+        let approved = self.loaded_scenario_yaml().and_then(|(_, yaml)| {
+            prompt
+                .request
+                .approve(&prompt.approver, &prompt.code, &yaml)
+        });
+        match approved {
+            Ok(record) => {
+                self.granted_approval = Some(record);
+                match prompt.action {
+                    ApprovedRunAction::Start => self.start_scenario(),
+                    ApprovedRunAction::RetryFromFailure => self.retry_from_failure(),
+                }
+                // 실행 전 검사에서 멈췄으면 승인을 다음 실행으로 넘기지 않는다.
+                self.granted_approval = None;
+            }
+            Err(err) => {
+                prompt.code.clear();
+                prompt.error = Some(format!("{err:#}"));
+                self.run_approval_prompt = Some(prompt);
+            }
+        }
+    }
+
+    /// 비용 경고 창에서 `그래도 실행`을 고르면 이번 한 번만 경고를 건너뛰고 실행을 시작한다.
+    ///
+    /// 승인이 필요한 프로필이면 승인 대화상자가 닫힐 때까지 건너뛰기를 유지한다.
+    pub(super) fn override_cost_warnings(&mut self) {
+        if self.cost_warning_prompt.take().is_none() {
+            return;
+        }
+        self.cost_override = true;
+        self.start_scenario();
+        if self.run_approval_prompt.is_none() {
+            self.cost_override = false;
+        }
+    }
+
+    /// 빌더 검사 패널에서 비용 경고를 확인(`true`)하거나 확인을 취소(`false`)한다.
+    ///
+    /// 확인 목록은 시나리오의 `cost_check.acknowledged`에 남아 저장하면 다음 실행부터 묻지 않는다.
+    pub(super) fn acknowledge_cost_warning(&mut self, index: usize, acknowledged: bool) {
+        let Some(warning) = self.cost_check_report.get_mut(index) else {
+            return;
+        };
+        warning.acknowledged = acknowledged;
+        let key = warning.ack_key();
+        let config = self
+            .editor_state
+            .cost_check
+            .get_or_insert_with(Default::default);
+        if acknowledged {
+            config.acknowledged.insert(key);
+        } else {
+            config.acknowledged.remove(&key);
+        }
+        self.editor_state.dirty = true;
+    }
+
+    /// 승인 대화상자를 닫고 요청을 거절(`rejected`)하거나 거둔(`false`) 것으로 감사 기록에 남긴다.
+    pub(super) fn close_run_approval(&mut self, rejected: bool) {
+        let Some(prompt) = self.run_approval_prompt.take() else {
+            return;
+        };
+        self.cost_override = false;
+        // 거절은 코드 없이 할 수 있으므로 입력한 이름 대신 지금 창을 조작한 OS 사용자를 남긴다.
+        let (action, user) = if rejected {
+            (ApprovalAction::Rejected, local_user_name())
+        } else {
+            (ApprovalAction::Withdrawn, prompt.request.requester.clone())
+        };
+        if let Err(err) = prompt.request.close(action, &user) {
+            self.last_error = Some(format!("{err:#}"));
+        }
+    }
+
+    /// 승인 요청과 확인에 쓰는, 불러온 시나리오의 이름과 YAML을 반환한다.
+    fn loaded_scenario_yaml(&self) -> anyhow::Result<(String, String)> {
+        let scenario = self
+            .scenario
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!(t("app.no_scenario")))?;
+        Ok((scenario.name.clone(), scenario_to_yaml(scenario)?))
+    }
+
+    /// 승인된 실행이면 승인 기록을 시나리오 파라미터로 넣고 승인을 소비한다.
+    ///
+    /// 실행 ID를 받으면 감사 기록에 이 승인으로 시작한 실행을 남기도록 기록을 보관한다.
+    fn apply_granted_approval(&mut self, scenario: &mut Scenario) {
+        let Some(record) = self.granted_approval.take() else {
+            return;
+        };
+        self.starting_approval = Some(record.clone());
+        scenario
+            .params
+            .insert(RUN_APPROVAL_ID_VAR.to_string(), record.id);
+        scenario
+            .params
+            .insert(RUN_APPROVED_BY_VAR.to_string(), record.user);
+        scenario
+            .params
+            .insert(RUN_APPROVED_AT_VAR.to_string(), record.at);
+    }
+
+    /// 성공한 Step은 그대로 두고 실패·건너뜀·미실행 Step만 다시 실행한다.
+    ///
+    /// 근본 원인 Step의 실패 시점 컨텍스트 변수를 파라미터로 넘겨 상위 Step이 만든 변수를 유지한다.
+    /// 이미 성공해 빠지는 Step이 만든 비밀 변수도 계속 가리도록 원래 시나리오의 비밀 변수 이름을
+    /// 비밀 파라미터로 넘긴다.
+    pub(super) fn retry_from_failure(&mut self) {
+        if self.scenario_running {
+            return;
+        }
+        let Some(scenario) = self.scenario.clone() else {
+            return;
+        };
+        if !self.ensure_run_approved(ApprovedRunAction::RetryFromFailure) {
+            return;
+        }
+        let completed: HashSet<String> = scenario
+            .steps
+            .iter()
+            .filter(|step| {
+                self.step_states
+                    .get(&step.id)
+                    .is_some_and(|state| matches!(state.status, StepStatus::Success))
+            })
+            .map(|step| step.id.clone())
+            .collect();
+        let mut resumed = scenario;
+        let secrets = resumed.secret_vars();
+        resumed.secret_params.extend(secrets);
+        resumed.steps.retain(|step| !completed.contains(&step.id));
+        for step in &mut resumed.steps {
+            step.depends_on.retain(|dep| !completed.contains(dep));
+        }
+        if let Some(snapshot) = self
+            .failure_summary()
+            .and_then(|summary| self.step_states.get(&summary.root_step_id))
+            .and_then(|state| state.context_snapshot.clone())
+        {
+            // 가려진 비밀 값은 넘기지 않는다. 비밀 파라미터는 시나리오 값을 그대로 쓴다.
+            resumed.params.extend(
+                snapshot
+                    .into_iter()
+                    .filter(|(_, value)| value != SECRET_MASK),
+            );
+        }
+        if let Some((_, profile)) = self.selected_profile_config() {
+            resumed.params.extend(profile.params.clone());
+        }
+        self.apply_granted_approval(&mut resumed);
+        for step in &resumed.steps {
+            self.step_states
+                .insert(step.id.clone(), StepRuntimeState::new());
+            self.step_logs.insert(step.id.clone(), Vec::new());
+            self.step_logs_lower.remove(&step.id);
+        }
+        self.launch_scenario(resumed, completed.into_iter().collect());
+    }
+
+    /// 비정상 종료된 이전 실행의 시나리오 파일을 불러오고 실행 저널에 남은 Step 결과를 복원한다.
+    ///
+    /// 성공한 Step은 성공으로, 실패한 Step과 중단 시점에 실행 중이었거나 실행할 차례였던 Step은 실패로
+    /// 표시해 실패 요약의 재시도 버튼으로 이어서 실행할 수 있게 한다. 실패로 표시한 Step에는 마지막으로
+    /// 기록된 컨텍스트 변수를 붙여 상위 Step이 만든 변수를 재시도에 넘긴다. 다시 실행할 Step이 남긴
+    /// `STEP.<id>.*` 변수는 이전 시도의 값이므로 넘기지 않는다.
+    pub(super) fn restore_interrupted_run(&mut self) {
+        if self.scenario_running {
+            return;
+        }
+        let Some(run) = self.interrupted_run.clone() else {
+            return;
+        };
+        self.apply_scenario_path(run.source.clone());
+        // 암호 문구 입력 등으로 아직 불러오지 못했으면 배너를 남겨 두고 다시 시도하게 한다.
+        if self.passphrase_prompt.is_some() || self.scenario_path.as_ref() != Some(&run.source) {
+            return;
+        }
+        let Some(scenario) = self.scenario.clone() else {
+            return;
+        };
+        let now = std::time::Instant::now();
+        let mut succeeded: HashSet<&str> = HashSet::new();
+        let mut rerun: Vec<&str> = Vec::new();
+        let finished = run
+            .completed
+            .iter()
+            .map(|step_id| (step_id.as_str(), true, None))
+            .chain(
+                run.steps
+                    .iter()
+                    .map(|step| (step.step_id.as_str(), step.success, step.error.clone())),
+            );
+        for (step_id, success, error) in finished {
+            let Some(state) = self.step_states.get_mut(step_id) else {
+                continue;
+            };
+            state.finished_at = Some(now);
+            if success {
+                state.status = StepStatus::Success;
+                succeeded.insert(step_id);
+            } else {
+                state.status =
+                    StepStatus::Failed(error.unwrap_or_else(|| t("status.failed").into()));
+                rerun.push(step_id);
+            }
+        }
+        for step in &scenario.steps {
+            let Some(state) = self.step_states.get_mut(&step.id) else {
+                continue;
+            };
+            if matches!(state.status, StepStatus::Pending)
+                && step
+                    .depends_on
+                    .iter()
+                    .all(|dep| succeeded.contains(dep.as_str()))
+            {
+                state.status = StepStatus::Failed(t("journal.interrupted_step").into());
+                state.finished_at = Some(now);
+                rerun.push(&step.id);
+            }
+        }
+        let context: ContextSnapshot = run
+            .context
+            .iter()
+            .filter(|(key, _)| {
+                !key.strip_prefix("STEP.")
+                    .and_then(|rest| rest.rsplit_once('.'))
+                    .is_some_and(|(step_id, _)| !succeeded.contains(step_id))
+            })
+            .cloned()
+            .collect();
+        for step_id in rerun {
+            if let Some(state) = self.step_states.get_mut(step_id) {
+                state.context_snapshot = Some(context.clone());
+            }
+        }
+        discard_journal(&run.journal);
+        self.interrupted_run = load_interrupted_run();
+    }
+
+    /// 비정상 종료된 이전 실행을 복원하지 않고 실행 저널을 지운다. 남은 중단 실행이 있으면 이어서 안내한다.
+    pub(super) fn dismiss_interrupted_run(&mut self) {
+        if let Some(run) = self.interrupted_run.take() {
+            discard_journal(&run.journal);
+        }
+        self.interrupted_run = load_interrupted_run();
+    }
+
+    /// 상태 초기화가 끝난 시나리오를 데몬 또는 로컬 엔진에서 실행한다.
+    ///
+    /// 로컬 실행이고 시나리오 파일이 있으면 실행 저널을 남긴다. `completed`는 이전 실행에서 이미 성공해
+    /// 이번 실행에서 제외한 Step ID 목록으로, 저널에 함께 기록해 재개 후 다시 중단되어도 잃지 않게 한다.
+    fn launch_scenario(&mut self, scenario: Scenario, completed: Vec<String>) {
+        self.last_error = None;
+        self.expanded_attachment = self
+            .expanded_yaml_in_report
+            .then(|| expand_scenario_yaml(&scenario).ok())
+            .flatten()
+            .map(|yaml| (scenario.name.clone(), yaml));
+        let rehearsal = self.rehearsal_mode.then(|| self.rehearsal_plan.clone());
+        if let Some(daemon) = &self.daemon {
+            daemon.send(DaemonRequest::Start {
+                scenario,
+                rehearsal,
+                confirm_mode: self.confirm_mode,
+            });
+            self.scenario_running = true;
+            return;
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        let token = CancellationToken::new();
+        let confirm_bridge = ConfirmBridge::new();
+        let journal = self.scenario_path.clone().map(|source| RunJournal {
+            scenario: scenario.name.clone(),
+            source,
+            completed,
+        });
+        let run = run_scenario(
+            scenario,
+            self.executor.clone(),
+            self.handle_cache.clone(),
+            tx.clone(),
+            token.clone(),
+            Some(confirm_bridge.clone()),
+            self.confirm_mode,
+            rehearsal,
+            journal,
+            self.context_out.clone(),
+        );
+        let failure = self.run_failure.clone();
+        if let Ok(mut slot) = failure.lock() {
+            *slot = None;
+        }
+        self.engine_runtime.spawn(async move {
+            if let Err(err) = run.await
+                && let Ok(mut slot) = failure.lock()
+            {
+                *slot = Some(format!("{err:#}"));
+            }
+            // 오류를 남긴 뒤에 채널이 닫히도록 송신자를 마지막까지 쥐고 있는다.
+            drop(tx);
+        });
+        self.events_rx = Some(rx);
+        self.cancel_token = Some(token);
+        self.scenario_running = true;
+        self.confirm_bridge = Some(confirm_bridge);
+    }
+
+    /// 컨텍스트 가져오기를 켜면 JSON 파일을 고르게 하고, 끄면 선택을 지운다. 고르지 않으면 꺼진 채 둔다.
+    pub(super) fn toggle_context_in(&mut self, enabled: bool) {
+        self.context_in = if enabled {
+            rfd::FileDialog::new()
+                .add_filter("JSON", &["json"])
+                .pick_file()
+        } else {
+            None
+        };
+        self.refresh_param_violations();
+    }
+
+    /// 컨텍스트 내보내기를 켜면 저장할 JSON 파일을 고르게 하고, 끄면 선택을 지운다.
+    pub(super) fn toggle_context_out(&mut self, enabled: bool) {
+        self.context_out = if enabled {
+            rfd::FileDialog::new()
+                .add_filter("JSON", &["json"])
+                .set_file_name("context.json")
+                .save_file()
+        } else {
+            None
+        };
+    }
+
+    /// 현재 필터로 실행 이력 통계를 다시 계산한다.
+    ///
+    /// 이력 파일이 크면 오래 걸리므로 블로킹 작업으로 계산하고, [`Self::poll_dashboard`]가 결과를
+    /// 반영한다. 계산이 끝날 때까지는 이전 통계를 그대로 보여 주며, 진행 중인 계산의 결과는 버린다.
+    pub(super) fn reload_dashboard(&mut self) {
+        let scenario = self.dashboard_scenario.clone();
+        let days = self.dashboard_days;
+        let (tx, rx) = oneshot::channel();
+        self.engine_runtime.spawn_blocking(move || {
+            let _ = tx.send(RunDashboard::load(scenario.as_deref(), days));
+        });
+        self.dashboard_rx = Some(rx);
+    }
+
+    /// 대시보드 통계 계산이 끝났으면 결과를 반영한다. 계산이 중단되었으면 빈 통계와 오류를 남긴다.
+    fn poll_dashboard(&mut self) {
+        let Some(rx) = &mut self.dashboard_rx else {
+            return;
+        };
+        let dashboard = match rx.try_recv() {
+            Ok(dashboard) => dashboard,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.last_error = Some(t("dashboard.aborted").into());
+                RunDashboard::default()
+            }
+        };
+        self.dashboard_rx = None;
+        self.dashboard = Some(dashboard);
+    }
+
+    /// 시작 전에 입력한 메모와 태그를 막 시작한 실행에 붙이고 입력란을 비운다.
+    fn attach_run_note(&mut self, run_id: String) {
+        let Some(scenario) = &self.scenario else {
+            return;
+        };
+        let note = RunNote {
+            scenario: scenario.name.clone(),
+            run_id,
+            note: self.run_note_draft.trim().to_string(),
+            tags: parse_tags(&self.run_tags_draft),
+        };
+        if note.is_empty() {
+            return;
+        }
+        match save_run_note(&note) {
+            Ok(()) => {
+                self.run_note_draft.clear();
+                self.run_tags_draft.clear();
+            }
+            Err(err) => self.last_error = Some(tf("run_note.save_failed", &[("error", &err)])),
+        }
+    }
+
+    /// 대시보드 실행 목록에서 실행 메모 편집을 시작한다.
+    pub(super) fn begin_run_note_edit(
+        &mut self,
+        scenario: &str,
+        run_id: &str,
+        current: Option<&RunNote>,
+    ) {
+        self.run_note_edit = Some(RunNoteEdit {
+            scenario: scenario.to_string(),
+            run_id: run_id.to_string(),
+            note: current.map(|note| note.note.clone()).unwrap_or_default(),
+            tags: current.map(|note| note.tags.join(", ")).unwrap_or_default(),
+            error: None,
+        });
+    }
+
+    /// 편집 중인 실행 메모를 저장하고 대시보드를 다시 읽는다. 메모와 태그를 모두 비우면 메모를 지운다.
+    pub(super) fn save_run_note_edit(&mut self) {
+        let Some(edit) = &mut self.run_note_edit else {
+            return;
+        };
+        let note = RunNote {
+            scenario: edit.scenario.clone(),
+            run_id: edit.run_id.clone(),
+            note: edit.note.trim().to_string(),
+            tags: parse_tags(&edit.tags),
+        };
+        if let Err(err) = save_run_note(&note) {
+            edit.error = Some(tf("run_note.save_failed", &[("error", &err)]));
+            return;
+        }
+        self.run_note_edit = None;
+        self.reload_dashboard();
+    }
+
+    /// 편집 중인 구성으로 엔진 런타임을 다시 만든다.
+    ///
+    /// 실행 중이거나 데몬에 접속한 경우에는 바꾸지 않는다. 캐시된 DB 핸들은 이전 런타임에 묶여
+    /// 있으므로 함께 비우고, 이전 런타임은 UI를 막지 않도록 백그라운드에서 종료한다.
+    pub(super) fn apply_engine_runtime(&mut self) {
+        if self.scenario_running || self.daemon.is_some() {
+            return;
+        }
+        let config = self.engine_runtime_draft;
+        match config.build() {
+            Ok(runtime) => {
+                let previous = std::mem::replace(&mut self.engine_runtime, runtime);
+                previous.shutdown_background();
+                self.engine_runtime_config = config;
+                self.handle_cache = EngineHandleCache::default();
+                self.db_reload_rx = None;
+            }
+            Err(err) => {
+                self.last_error = Some(tf("settings.runtime_failed", &[("error", &err)]));
+            }
+        }
+    }
+
+    /// 앱 설정의 시나리오 폴더(없으면 현재 디렉터리)를 반환한다.
+    pub(super) fn scenario_search_dir(&self) -> PathBuf {
+        self.settings
+            .scenario_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
+
+    /// 입력한 검색어로 시나리오 폴더의 모든 시나리오를 검색한다.
+    ///
+    /// 폴더가 크면 오래 걸리므로 블로킹 작업으로 검색하고, [`Self::poll_scenario_search`]가 결과를
+    /// 검색 창에 반영한다. 진행 중인 검색이 있으면 그 결과는 버린다.
+    pub(super) fn run_scenario_search(&mut self) {
+        let dir = self.scenario_search_dir();
+        let Some(search) = self.scenario_search.as_ref() else {
+            return;
+        };
+        let query = search.query.clone();
+        let (tx, rx) = oneshot::channel();
+        self.engine_runtime.spawn_blocking(move || {
+            let result = search_scenarios(&dir, &query).map_err(|err| {
+                tf(
+                    "search.dir_failed",
+                    &[("dir", &dir.display()), ("error", &err)],
+                )
+            });
+            let _ = tx.send(result);
+        });
+        self.scenario_search_rx = Some(rx);
+    }
+
+    /// 모든 시나리오 검색이 끝났으면 검색 창에 결과나 오류를 채운다. 창을 닫았으면 버린다.
+    fn poll_scenario_search(&mut self) {
+        let Some(rx) = &mut self.scenario_search_rx else {
+            return;
+        };
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => Err(t("search.aborted").into()),
+        };
+        self.scenario_search_rx = None;
+        let Some(search) = self.scenario_search.as_mut() else {
+            return;
+        };
+        match result {
+            Ok(report) => {
+                search.report = Some(report);
+                search.error = None;
+            }
+            Err(err) => {
+                search.report = None;
+                search.error = Some(err);
+            }
+        }
+    }
+
+    /// 검색 결과의 시나리오를 열고 결과가 나온 Step을 실행 탭과 빌더에서 선택한다.
+    ///
+    /// 암호 문구가 필요하면 입력 대화상자를 띄우며, 그 경우 Step은 선택하지 않는다.
+    pub(super) fn open_search_hit(&mut self, hit: &ScenarioSearchHit) {
+        self.apply_scenario_path(hit.path.clone());
+        if self.scenario_path.as_ref() != Some(&hit.path) {
+            return;
+        }
+        if let Some(step_id) = &hit.focus_id {
+            self.selected_step = Some(step_id.clone());
+            self.run_focus = Some(step_id.clone());
+            self.scroll_to_focus = true;
+            self.editor_state.selected_node_id = Some(step_id.clone());
+        }
+        if self.active_tab == AppTab::Dashboard {
+            self.active_tab = AppTab::Run;
+        }
+    }
+
+    /// 현재 앱 설정으로 설정 대화상자를 연다.
+    pub(super) fn open_settings(&mut self) {
+        self.engine_runtime_draft = self.engine_runtime_config;
+        self.settings_draft = Some(self.settings.clone());
+    }
+
+    /// 설정 대화상자에서 편집한 앱 설정을 저장하고 테마·언어·UI 배율을 바로 적용한다.
+    ///
+    /// 엔진 런타임 설정 칸의 값은 다음 시작 때의 기본값으로 함께 저장한다. 보존 일수를 지정했으면
+    /// 지난 실행 이력을 바로 정리한다. 저장에 실패하면 대화상자를 열어 둔다.
+    pub(super) fn save_settings(&mut self, ctx: &egui::Context) {
+        let Some(mut draft) = self.settings_draft.take() else {
+            return;
+        };
+        draft.engine_workers = self.engine_runtime_draft.worker_threads;
+        draft.engine_blocking_threads = self.engine_runtime_draft.max_blocking_threads;
+        draft
+            .notify_endpoints
+            .retain(|endpoint| !endpoint.trim().is_empty());
+        // 대화상자를 연 뒤에 기억한 화면 상태를 덮어쓰지 않는다.
+        draft.scenario_views = self.settings.scenario_views.clone();
+        if let Err(err) = save_app_settings(&draft) {
+            self.last_error = Some(tf("settings.save_failed", &[("error", &err)]));
+            self.settings_draft = Some(draft);
+            return;
+        }
+        self.theme = Theme::from_variant(draft.theme);
+        ctx.set_zoom_factor(draft.ui_scale);
+        if let Some(language) = draft.language {
+            set_language(language);
+        }
+        if let Some(days) = draft.history_keep_days {
+            self.runtime.spawn(async move {
+                if let Err(err) = prune_history(days).await {
+                    tracing::warn!("실행 이력 정리 실패: {err}");
+                }
+            });
+        }
+        self.settings = draft;
+    }
+
+    /// 언어를 바꾸고 앱 설정 파일의 시작 언어로 저장한다.
+    pub(super) fn change_language(&mut self, language: Language) {
+        set_language(language);
+        self.settings.language = Some(language);
+        if let Err(err) = save_app_settings(&self.settings) {
+            self.last_error = Some(tf("settings.save_failed", &[("error", &err)]));
+        }
+    }
+
+    /// Step 상태와 로그를 주어진 시나리오 기준으로 초기화한다.
+    fn reset_run_state(&mut self, scenario: &Scenario) {
+        self.step_logs.clear();
+        self.step_logs_lower.clear();
+        self.step_states.clear();
+        self.step_preview = None;
+        self.run_summary = None;
+        for step in &scenario.steps {
+            self.step_states
+                .insert(step.id.clone(), StepRuntimeState::new());
+            self.step_logs.insert(step.id.clone(), Vec::new());
+            self.step_logs_lower.remove(&step.id);
+        }
+    }
+
+    /// 현재 실행 중인 시나리오를 중단한다.
+    pub(super) fn stop_scenario(&mut self) {
+        if let Some(daemon) = &self.daemon {
+            daemon.send(DaemonRequest::Cancel);
+        }
+        if let Some(token) = &self.cancel_token {
+            token.cancel();
+        }
+        // 중지한 뒤에도 `on_failure` 정리 Step의 컨펌과 승인 게이트에 응답할 수 있도록 브리지는
+        // `ScenarioFinished`까지 남겨 둔다.
+        self.scenario_running = false;
+        self.pending_confirms.clear();
+    }
+
+    /// 시나리오 실행이 아직 끝나지 않았는지 확인한다.
+    ///
+    /// 로컬 실행은 중지를 요청한 뒤에도 엔진이 이력을 남기고 이벤트 채널이 닫힐 때까지 진행 중으로 본다.
+    pub(super) fn run_in_flight(&self) -> bool {
+        self.scenario_running || self.cancel_token.is_some() || self.events_rx.is_some()
+    }
+
+    /// 창 닫기 요청을 가로채 실행 중이면 종료 방법을 묻고, 취소 후 종료를 기다리는 중이면 끝났을 때 창을 닫는다.
+    ///
+    /// 바로 닫히는 경우에는 열린 시나리오의 화면 상태를 앱 설정에 기억한다.
+    pub(super) fn handle_close_request(&mut self, ctx: &egui::Context) {
+        if self.exit_allowed {
+            return;
+        }
+        if ctx.input(|input| input.viewport().close_requested()) {
+            if self.run_in_flight() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+                if self.exit_shutdown.is_none() {
+                    self.exit_shutdown = Some(ExitShutdown::Asking);
+                }
+            } else {
+                self.remember_scenario_view();
+            }
+        }
+        if matches!(self.exit_shutdown, Some(ExitShutdown::Draining { .. }))
+            && !self.run_in_flight()
+        {
+            self.exit_now(ctx);
+        }
+    }
+
+    /// 실행을 취소하고 엔진이 자식 프로세스를 멈추고 이력·로그 기록을 마치면 종료하도록 기다린다.
+    pub(super) fn cancel_run_for_exit(&mut self) {
+        self.stop_scenario();
+        if let Some(cancel) = &self.step_test_cancel {
+            cancel.cancel();
+        }
+        self.exit_shutdown = Some(ExitShutdown::Draining {
+            since: std::time::Instant::now(),
+        });
+    }
+
+    /// 기다리지 않고 바로 종료한다. 엔진 런타임이 내려가며 실행 중인 자식 프로세스도 종료된다.
+    pub(super) fn force_exit(&mut self, ctx: &egui::Context) {
+        if let Some(token) = &self.cancel_token {
+            token.cancel();
+        }
+        self.exit_now(ctx);
+    }
+
+    /// 종료 처리를 마치고 창을 닫는다. 데몬에 접속해 있으면 실행은 데몬에서 계속된다.
+    pub(super) fn exit_now(&mut self, ctx: &egui::Context) {
+        self.remember_scenario_view();
+        self.exit_shutdown = None;
+        self.exit_allowed = true;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+    }
+
+    /// 새 시나리오 빌더 문서를 생성한다.
+    pub(super) fn editor_new_document(&mut self) {
+        self.replace_editor_state(ScenarioEditorState::new());
+        self.editor_error = None;
+    }
+
+    /// 공유 연결 저장소 목록은 유지한 채 에디터 상태를 교체한다.
+    fn replace_editor_state(&mut self, mut editor_state: ScenarioEditorState) {
+        editor_state.shared_connections = std::mem::take(&mut self.editor_state.shared_connections);
+        self.editor_state = editor_state;
+        self.editor_notice = None;
+    }
+
+    /// 빌더에서 공유 연결 저장소가 바뀌었으면 `connections.yaml`에 기록한다.
+    pub(super) fn persist_shared_connections(&mut self) {
+        if !self.editor_state.shared_connections_changed {
+            return;
+        }
+        self.editor_state.shared_connections_changed = false;
+        let store = ConnectionStore {
+            connections: self.editor_state.shared_connections.clone(),
+        };
+        if let Err(err) = save_connection_store(&store) {
+            self.editor_error = Some(err.to_string());
+        }
+    }
+
+    /// 파일 다이얼로그에서 YAML을 로드한다.
+    pub(super) fn editor_open_dialog(&mut self) {
+        if let Some(path) = scenario_file_dialog().pick_file() {
+            self.editor_open_from_path(path.into());
+        }
+    }
+
+    /// Airflow DAG 또는 Oozie 워크플로 파일을 골라 시나리오 골격으로 빌더에 불러온다.
+    ///
+    /// 가져온 시나리오는 파일 경로가 없는 수정된 새 문서로 열리며, 직접 옮겨야 할 항목은 안내 메시지로 보여 준다.
+    pub(super) fn editor_import_dialog(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter(t("app.import_filter"), &["py", "xml"])
+            .pick_file()
+        else {
+            return;
+        };
+        let imported = match import_workflow_file(&path) {
+            Ok(imported) => imported,
+            Err(err) => {
+                self.editor_error = Some(format!("{err:#}"));
+                return;
+            }
+        };
+        match scenario_to_editor_state(&imported.scenario) {
+            Ok(mut editor_state) => {
+                editor_state.dirty = true;
+                self.replace_editor_state(editor_state);
+                self.editor_error = None;
+                let steps = imported.scenario.steps.len();
+                let mut notice = if imported.todos.is_empty() {
+                    tf("app.import_done", &[("steps", &steps)])
+                } else {
+                    tf(
+                        "app.import_done_todos",
+                        &[("steps", &steps), ("todos", &imported.todos.len())],
+                    )
+                };
+                for todo in &imported.todos {
+                    notice.push('\n');
+                    notice.push_str(todo);
+                }
+                self.editor_notice = Some(notice);
+            }
+            Err(err) => self.editor_error = Some(err.to_string()),
+        }
+    }
+
+    /// 지정된 경로의 YAML을 에디터로 불러온다.
+    pub(super) fn editor_open_from_path(&mut self, path: PathBuf) {
+        match load_scenario_from_file(&path) {
+            Ok(scenario) => {
+                self.apply_loaded_scenario(scenario, path);
+            }
+            Err(err) => {
+                if !self.prompt_passphrase_if_needed(&err, PassphraseAction::OpenEditor(path))
+                    && !self.show_scenario_error(&err)
+                {
+                    self.editor_error = Some(err.to_string());
+                }
             }
         }
     }
 
     /// 에디터 상태를 YAML로 저장한다.
     pub(super) fn editor_save(&mut self, save_as: bool) {
+        let target_path = if save_as {
+            scenario_file_dialog().save_file()
+        } else {
+            self.editor_state
+                .current_file
+                .clone()
+                .or_else(|| scenario_file_dialog().save_file())
+        };
+        if let Some(path) = target_path {
+            self.editor_save_to(path);
+        }
+    }
+
+    /// 에디터 상태를 지정한 경로에 저장한다. `*.enc` 경로는 암호화해 저장한다.
+    ///
+    /// 시나리오 이름을 새 문서 기본값에서 바꾸지 않았으면 파일 이름을 시나리오 이름으로 쓴다.
+    fn editor_save_to(&mut self, path: PathBuf) {
+        if self.editor_state.scenario_name == default_scenario_name()
+            && let Some(stem) = path.file_stem()
+        {
+            self.editor_state.scenario_name = stem.to_string_lossy().to_string();
+        }
         match editor_state_to_scenario(&self.editor_state) {
             Ok(scenario) => {
-                let target_path = if save_as {
-                    rfd::FileDialog::new()
-                        .add_filter("YAML", &["yaml", "yml"])
-                        .save_file()
-                } else {
-                    self.editor_state.current_file.clone().or_else(|| {
-                        rfd::FileDialog::new()
-                            .add_filter("YAML", &["yaml", "yml"])
-                            .save_file()
-                    })
-                };
-                if let Some(path) = target_path {
-                    if let Err(err) = save_scenario_to_file(&scenario, &path) {
-                        self.editor_error = Some(err);
-                        return;
+                if let Err(err) = save_scenario_to_file(&scenario, &path) {
+                    if !self.prompt_passphrase_if_needed(&err, PassphraseAction::Save(path)) {
+                        self.editor_error = Some(err.to_string());
+                    }
+                    return;
+                }
+                self.editor_state.current_file = Some(path.clone());
+                self.editor_state.dirty = false;
+                self.file_watch = Some(ScenarioFileWatch::new(path.clone(), &scenario));
+                self.scenario = Some(scenario);
+                self.scenario_path = Some(path);
+                self.editor_error = None;
+                self.refresh_param_violations();
+                // 작성 중인 시나리오는 계약이 덜 갖춰져도 저장하고, 실행할 때 오류로 막는다.
+                let violations = scenario_contract_summary(self.scenario.as_ref());
+                if !violations.is_empty() {
+                    self.editor_notice =
+                        Some(tf("app.contract_warning", &[("violations", &violations)]));
+                }
+            }
+            Err(err) => {
+                self.editor_error = Some(err.to_string());
+            }
+        }
+    }
+
+    /// 에디터의 SQL Step을 대상 DB 방언으로 검사해 검사 패널에 표시한다.
+    pub(super) fn editor_check_sql(&mut self) {
+        match editor_state_to_scenario(&self.editor_state) {
+            Ok(scenario) => {
+                self.editor_error = None;
+                let mut report = check_scenario_sql(&scenario);
+                // SQL 파일은 SQL 검사가 이미 읽어 보므로 나머지 참조 파일만 덧붙인다.
+                report.extend(
+                    check_scenario_files(&scenario)
+                        .into_iter()
+                        .filter(|file| file.field != "sql_file")
+                        .map(|file| SqlIssue {
+                            message: file.message(),
+                            step_id: file.step_id,
+                            level: SqlIssueLevel::Error,
+                        }),
+                );
+                self.sql_check_report = Some(report);
+                self.cost_check_report = check_scenario_cost(&scenario);
+            }
+            Err(err) => {
+                self.editor_error = Some(err.to_string());
+            }
+        }
+    }
+
+    /// 빌더 속성 패널에서 요청한 SQL 미리보기를 편집 중인 DB 정의로 실행한다.
+    ///
+    /// 데몬 모드에서도 GUI 프로세스에서 직접 조회한다. 앞선 미리보기가 진행 중이면 그 결과는 버린다.
+    pub(super) fn start_sql_preview(&mut self) {
+        let Some(preview) = &self.editor_state.sql_preview else {
+            return;
+        };
+        if !matches!(preview.status, SqlPreviewStatus::Requested) {
+            return;
+        }
+        let step_id = preview.step_id.clone();
+        let target_db = preview.target_db.clone();
+        let sql = preview.sql.clone();
+        let edited = editor_state_to_scenario(&self.editor_state);
+        let Some(preview) = &mut self.editor_state.sql_preview else {
+            return;
+        };
+        let edited = match edited {
+            Ok(scenario) => scenario,
+            Err(err) => {
+                preview.status = SqlPreviewStatus::Failed(err.to_string());
+                return;
+            }
+        };
+        preview.status = SqlPreviewStatus::Running;
+        let executor = self.executor.clone();
+        let (tx, rx) = oneshot::channel();
+        self.engine_runtime.spawn(async move {
+            let result = preview_sql(&edited, target_db.as_deref(), &sql, executor)
+                .await
+                .map_err(|err| format!("{err:#}"));
+            let _ = tx.send(result);
+        });
+        self.sql_preview_rx = Some((step_id, rx));
+    }
+
+    /// 스키마 브라우저가 기다리는 조회 하나를 편집 중인 DB 정의로 실행한다.
+    ///
+    /// 한 번에 하나씩 조회하며, 데몬 모드에서도 SQL 미리보기처럼 GUI 프로세스에서 직접 조회한다.
+    pub(super) fn start_schema_fetch(&mut self) {
+        if self.schema_fetch.is_some() || self.editor_state.schema_browser.is_none() {
+            return;
+        }
+        let edited = editor_state_to_scenario(&self.editor_state);
+        let Some(browser) = &mut self.editor_state.schema_browser else {
+            return;
+        };
+        let Some(request) = browser.start_next_request() else {
+            return;
+        };
+        let edited = match edited {
+            Ok(scenario) => scenario,
+            Err(err) => {
+                let failed = SchemaFetch::Failed(err.to_string());
+                match request {
+                    SchemaRequest::Tables => browser.tables = failed,
+                    SchemaRequest::Columns(table) => {
+                        browser.columns.insert(table, failed);
                     }
-                    self.editor_state.current_file = Some(path.clone());
-                    self.editor_state.dirty = false;
-                    self.scenario = Some(scenario);
-                    self.scenario_path = Some(path);
-                    self.editor_error = None;
                 }
+                return;
+            }
+        };
+        let target_db = browser.target_db.clone();
+        let executor = self.executor.clone();
+        let (tx, rx) = oneshot::channel();
+        let task_request = request.clone();
+        let task_target = target_db.clone();
+        self.engine_runtime.spawn(async move {
+            let result = match &task_request {
+                SchemaRequest::Tables => fetch_schema_tables(&edited, &task_target, executor)
+                    .await
+                    .map(SchemaReply::Tables),
+                SchemaRequest::Columns(table) => {
+                    fetch_schema_columns(&edited, &task_target, table, executor)
+                        .await
+                        .map(SchemaReply::Columns)
+                }
+            };
+            let _ = tx.send(result.map_err(|err| format!("{err:#}")));
+        });
+        self.schema_fetch = Some(SchemaFetchTask {
+            target_db,
+            request,
+            rx,
+        });
+    }
+
+    /// 빌더 속성 패널에서 요청한 Shell 스크립트 로컬 테스트를 GUI 프로세스에서 실행한다.
+    pub(super) fn start_shell_trial(&mut self) {
+        let Some(trial) = &mut self.editor_state.shell_trial else {
+            return;
+        };
+        if trial.status != ShellTrialStatus::Requested {
+            return;
+        }
+        let step_id = trial.step_id.clone();
+        let vars = trial.vars.clone();
+        trial.status = ShellTrialStatus::Running;
+        let config = match self.editor_state.node(&step_id).map(|node| &node.config) {
+            Some(EditorStepConfig::Shell { config }) => config.clone(),
+            _ => {
+                if let Some(trial) = &mut self.editor_state.shell_trial {
+                    trial.status = ShellTrialStatus::Failed(t("app.shell_trial_no_step").into());
+                }
+                return;
+            }
+        };
+        let (tx, rx) = oneshot::channel();
+        self.engine_runtime.spawn(async move {
+            let result = run_shell_trial(&config, &vars)
+                .await
+                .map_err(|err| format!("{err:#}"));
+            let _ = tx.send(result);
+        });
+        self.shell_trial_rx = Some((step_id, rx));
+    }
+
+    /// 빌더 속성 패널의 단일 Step 테스트 요청을 처리한다.
+    ///
+    /// 처음 요청하면 Step이 참조하는 변수를 모으고, 입력할 변수가 있으면 입력을 기다린다. 입력이
+    /// 끝나면 편집 중인 시나리오에서 그 Step만 남긴 시나리오를 GUI 프로세스에서 실제로 실행한다.
+    /// 이력·저널·알림·컨텍스트 내보내기를 남기지 않도록 장애 주입 없는 리허설로 실행한다.
+    pub(super) fn start_step_test(&mut self) {
+        let Some(test) = &self.editor_state.step_test else {
+            return;
+        };
+        match test.status {
+            StepTestStatus::StopRequested => {
+                if let Some(token) = &self.step_test_cancel {
+                    token.cancel();
+                }
+                return;
+            }
+            StepTestStatus::Requested => {}
+            _ => return,
+        }
+        let step_id = test.step_id.clone();
+        let inputs = test.inputs.clone();
+        let edited = editor_state_to_scenario(&self.editor_state);
+        let Some(test) = &mut self.editor_state.step_test else {
+            return;
+        };
+        let edited = match edited {
+            Ok(scenario) => scenario,
+            Err(err) => {
+                test.status = StepTestStatus::Failed(err.to_string());
+                return;
             }
+        };
+        let inputs = match inputs {
+            Some(inputs) => inputs,
+            None => match step_test_inputs(&edited, &step_id) {
+                Ok(vars) if vars.is_empty() => vars,
+                Ok(vars) => {
+                    test.inputs = Some(vars);
+                    test.status = StepTestStatus::Editing;
+                    return;
+                }
+                Err(err) => {
+                    test.status = StepTestStatus::Failed(format!("{err:#}"));
+                    return;
+                }
+            },
+        };
+        let scenario = match step_test_scenario(&edited, &step_id, &inputs) {
+            Ok(scenario) => scenario,
+            Err(err) => {
+                test.status = StepTestStatus::Failed(format!("{err:#}"));
+                return;
+            }
+        };
+        if let Some(token) = self.step_test_cancel.take() {
+            token.cancel();
+        }
+        test.logs.clear();
+        test.status = StepTestStatus::Running;
+        let (tx, rx) = mpsc::unbounded_channel();
+        let token = CancellationToken::new();
+        let plan = RehearsalPlan {
+            simulate_others: false,
+            faults: HashMap::new(),
+        };
+        let executor = self.executor.clone();
+        let handle_cache = self.handle_cache.clone();
+        let run_token = token.clone();
+        let failed_step = step_id.clone();
+        self.engine_runtime.spawn(async move {
+            let error_tx = tx.clone();
+            if let Err(err) = run_scenario(
+                scenario,
+                executor,
+                handle_cache,
+                tx,
+                run_token,
+                None,
+                ConfirmMode::Prompt,
+                Some(plan),
+                None,
+                None,
+            )
+            .await
+            {
+                let _ = error_tx.send(EngineEvent::StepFinished {
+                    step_id: failed_step,
+                    success: false,
+                    error: Some(EngineError::Other(format!("{err:#}"))),
+                    context: None,
+                });
+            }
+        });
+        self.step_test_rx = Some((step_id, rx));
+        self.step_test_cancel = Some(token);
+    }
+
+    /// 빌더에서 편집한 DB·원격 호스트 정의를 실행 탭 시나리오에 반영하고 DB 핸들을 다시 구성한다.
+    ///
+    /// 실행 중인 시나리오는 시작할 때 받은 핸들을 계속 사용하고, 새 핸들은 다음 실행부터 쓰인다.
+    pub(super) fn editor_reload_db(&mut self) {
+        let edited = match editor_state_to_scenario(&self.editor_state) {
+            Ok(scenario) => scenario,
             Err(err) => {
                 self.editor_error = Some(err.to_string());
+                return;
             }
+        };
+        self.editor_error = None;
+        self.apply_db_definitions(&edited);
+        if self.daemon.is_some() {
+            self.editor_notice = Some(t("app.db_reload_daemon").into());
+            return;
+        }
+        let cache = self.handle_cache.clone();
+        let executor = self.executor.clone();
+        let (tx, rx) = oneshot::channel();
+        self.engine_runtime.spawn(async move {
+            let result = cache
+                .reload(&edited, executor)
+                .await
+                .map_err(|err| format!("{err:#}"));
+            let _ = tx.send(result);
+        });
+        self.db_reload_rx = Some(rx);
+        self.editor_notice = Some(t("app.db_reloading").into());
+    }
+
+    /// 빌더 문서가 실행 탭에 로드된 시나리오와 같은 파일이면 DB·원격 호스트 정의만 덮어쓴다.
+    fn apply_db_definitions(&mut self, edited: &Scenario) {
+        if self.editor_state.current_file.is_none()
+            || self.editor_state.current_file != self.scenario_path
+        {
+            return;
+        }
+        if let Some(scenario) = &mut self.scenario {
+            scenario.db = edited.db.clone();
+            scenario.remotes = edited.remotes.clone();
         }
     }
 
@@ -383,35 +2641,391 @@ impl BatchOrchestratorApp {
         }
     }
 
-    /// 선택된 Step의 로그 배열을 반환한다.
-    pub(super) fn selected_logs(&self) -> Vec<String> {
-        if let Some(step_id) = &self.selected_step {
-            if let Some(logs) = self.step_logs.get(step_id) {
-                return logs.clone();
+    /// 선택된 Step이 바뀌었으면 유형별 구성 미리보기를 다시 계산한다.
+    ///
+    /// 치환에는 시나리오 파라미터를 쓰고, Step이 실패했으면 실패 시점의 컨텍스트 변수를 덧씌운다.
+    pub(super) fn refresh_step_preview(&mut self) {
+        let Some(step_id) = &self.selected_step else {
+            self.step_preview = None;
+            return;
+        };
+        if self
+            .step_preview
+            .as_ref()
+            .is_some_and(|(cached, _)| cached == step_id)
+        {
+            return;
+        }
+        let Some(scenario) = &self.scenario else {
+            return;
+        };
+        let Some(step) = scenario
+            .steps
+            .iter()
+            .chain(scenario.cleanup_steps())
+            .find(|step| &step.id == step_id)
+        else {
+            self.step_preview = None;
+            return;
+        };
+        let mut ctx = ExecutionContext::new();
+        for (key, value) in &scenario.params {
+            ctx.set_var(key.clone(), value.clone());
+        }
+        for key in scenario.secret_vars() {
+            ctx.mark_secret(key);
+        }
+        if let Some(snapshot) = self
+            .step_states
+            .get(step_id)
+            .and_then(|state| state.context_snapshot.as_ref())
+        {
+            for (key, value) in snapshot {
+                ctx.set_var(key.clone(), value.clone());
             }
         }
-        Vec::new()
+        self.step_preview = Some((step_id.clone(), preview_step(step, &ctx)));
     }
 
-    /// 전체 진행률을 계산한다.
+    /// 선택된 Step의 로그 중 소문자로 바꾼 검색어 `needle`이 들어간 줄을 반환한다. `needle`이 비어
+    /// 있으면 모든 줄을 반환한다.
+    pub(super) fn selected_logs(&self, needle: &str) -> Vec<&str> {
+        let Some(step_id) = &self.selected_step else {
+            return Vec::new();
+        };
+        let Some(logs) = self.step_logs.get(step_id) else {
+            return Vec::new();
+        };
+        if needle.is_empty() {
+            return logs.iter().map(String::as_str).collect();
+        }
+        let lower = self
+            .step_logs_lower
+            .get(step_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        logs.iter()
+            .zip(lower)
+            .filter(|(_, lower)| lower.contains(needle))
+            .map(|(line, _)| line.as_str())
+            .collect()
+    }
+
+    /// 성공한 작업량의 비율로 전체 진행률을 계산한다.
+    ///
+    /// Loop Step은 반복 수가 알려지면 반복 수만큼 가중치를 두고 끝난 반복까지 진행으로 센다.
+    /// 실패한 Step은 진행으로 세지 않으며, 선행 실패로 건너뛴 Step은 분모에서 제외한다.
     pub(super) fn progress_ratio(&self) -> f32 {
+        let Some(scenario) = &self.scenario else {
+            return 0.0;
+        };
+        let mut done = 0.0;
+        let mut total = 0.0;
+        for step in &scenario.steps {
+            let state = self.step_states.get(&step.id);
+            let iterations = state.and_then(|state| state.iterations);
+            let weight = iterations.map_or(1, |(_, count)| count.max(1)) as f32;
+            match state.map(|state| &state.status) {
+                Some(StepStatus::Blocked) => continue,
+                Some(StepStatus::Success) => done += weight,
+                Some(StepStatus::Failed(_)) => {}
+                _ => done += iterations.map_or(0, |(finished, _)| finished) as f32,
+            }
+            total += weight;
+        }
+        if total > 0.0 { done / total } else { 0.0 }
+    }
+
+    /// 실행이 실패로 끝났을 때 근본 원인 요약을 만든다. 실행 중이거나 실패가 없으면 `None`이다.
+    ///
+    /// 가장 먼저 실패한 Step을 근본 원인으로 보고, 그 Step에 직간접적으로 의존해 건너뛴 Step을
+    /// 시나리오 순서대로 모은다.
+    pub(super) fn failure_summary(&self) -> Option<FailureSummary> {
+        if self.scenario_running {
+            return None;
+        }
+        let scenario = self.scenario.as_ref()?;
+        let (root, state) = scenario
+            .steps
+            .iter()
+            .filter_map(|step| {
+                let state = self.step_states.get(&step.id)?;
+                matches!(state.status, StepStatus::Failed(_)).then_some((step, state))
+            })
+            .min_by_key(|(_, state)| state.finished_at)?;
+        let mut affected: HashSet<&str> = HashSet::from([root.id.as_str()]);
+        let mut blocked_chain = Vec::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for step in &scenario.steps {
+                if affected.contains(step.id.as_str())
+                    || !step
+                        .depends_on
+                        .iter()
+                        .any(|dep| affected.contains(dep.as_str()))
+                {
+                    continue;
+                }
+                affected.insert(step.id.as_str());
+                changed = true;
+                if self
+                    .step_states
+                    .get(&step.id)
+                    .is_some_and(|state| matches!(state.status, StepStatus::Blocked))
+                {
+                    blocked_chain.push(step.id.clone());
+                }
+            }
+        }
+        let order: HashMap<&str, usize> = scenario
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| (step.id.as_str(), idx))
+            .collect();
+        blocked_chain.sort_by_key(|id| order.get(id.as_str()).copied());
+        let logs = self
+            .step_logs
+            .get(&root.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let message = match &state.status {
+            StepStatus::Failed(message) => message.clone(),
+            _ => String::new(),
+        };
+        Some(FailureSummary {
+            root_step_id: root.id.clone(),
+            root_step_name: root.name.clone(),
+            message,
+            blocked_chain,
+            last_logs: logs[logs.len().saturating_sub(FAILURE_SUMMARY_LOG_LINES)..].to_vec(),
+        })
+    }
+
+    /// 하단 패널에 표시할 Step 상태별 개수를 집계한다.
+    pub(super) fn progress_counts(&self) -> ProgressCounts {
+        match &self.scenario {
+            Some(scenario) => self.counts_for(&scenario.steps),
+            None => ProgressCounts::default(),
+        }
+    }
+
+    /// 지정한 Step들의 상태별 개수를 센다. 스테이지별 진행률에 쓰인다.
+    pub(super) fn counts_for<'s>(
+        &self,
+        steps: impl IntoIterator<Item = &'s Step>,
+    ) -> ProgressCounts {
+        let mut counts = ProgressCounts::default();
+        for step in steps {
+            match self.step_states.get(&step.id).map(|state| &state.status) {
+                Some(StepStatus::Success) => counts.succeeded += 1,
+                Some(StepStatus::Failed(_)) => counts.failed += 1,
+                Some(StepStatus::Blocked) => counts.blocked += 1,
+                _ => counts.remaining += 1,
+            }
+        }
+        counts
+    }
+
+    /// 과거 평균 소요 시간으로 남은 실행 시간을 추정한다.
+    ///
+    /// Step을 순차 실행한다고 가정하며, 남은 Step 중 이력이 있는 Step이 없으면 `None`을 반환한다.
+    pub(super) fn estimated_remaining(&self) -> Option<std::time::Duration> {
+        let scenario = self.scenario.as_ref()?;
+        if !self.scenario_running {
+            return None;
+        }
+        let mut total = std::time::Duration::ZERO;
+        let mut known = false;
+        for step in &scenario.steps {
+            let state = self.step_states.get(&step.id);
+            let status = state.map(|state| &state.status);
+            if matches!(
+                status,
+                Some(StepStatus::Success | StepStatus::Failed(_) | StepStatus::Blocked)
+            ) {
+                continue;
+            }
+            let Some(stats) = self.step_stats.get(&step.id) else {
+                continue;
+            };
+            known = true;
+            let elapsed = match status {
+                Some(StepStatus::Running | StepStatus::Retrying { .. }) => state
+                    .and_then(|state| state.started_at)
+                    .map(|started| started.elapsed())
+                    .unwrap_or_default(),
+                _ => std::time::Duration::ZERO,
+            };
+            total += stats.avg.saturating_sub(elapsed);
+        }
+        known.then_some(total)
+    }
+
+    /// 지금 실행 중인 Step을 먼저 시작한 순서로 모은다.
+    ///
+    /// 시간 창, 승인, 재시도 간격을 기다리는 Step은 엔진이 실제로 일하고 있지 않으므로 넣지 않는다.
+    pub(super) fn running_lanes(&self) -> Vec<RunningLane> {
+        let Some(scenario) = &self.scenario else {
+            return Vec::new();
+        };
+        let now = std::time::Instant::now();
+        let mut lanes: Vec<(std::time::Instant, RunningLane)> = scenario
+            .steps
+            .iter()
+            .filter_map(|step| {
+                let state = self.step_states.get(&step.id)?;
+                let executing = match &state.status {
+                    StepStatus::Running => true,
+                    StepStatus::Retrying { resume_at, .. } => *resume_at <= now,
+                    _ => false,
+                };
+                let started = state.started_at.filter(|_| executing)?;
+                Some((
+                    started,
+                    RunningLane {
+                        name: step.name.clone(),
+                        elapsed: started.elapsed(),
+                        expected: self.step_stats.get(&step.id).map(|stats| stats.avg),
+                    },
+                ))
+            })
+            .collect();
+        lanes.sort_by_key(|(started, _)| *started);
+        lanes.into_iter().map(|(_, lane)| lane).collect()
+    }
+
+    /// 실행 중인 Step이 과거 평균보다 크게 오래 걸리고 있으면 평균 소요 시간을 반환한다.
+    pub(super) fn slow_step_average(&self, step_id: &str) -> Option<std::time::Duration> {
+        let state = self.step_states.get(step_id)?;
+        if !matches!(
+            state.status,
+            StepStatus::Running | StepStatus::Retrying { .. }
+        ) {
+            return None;
+        }
+        let stats = self.step_stats.get(step_id)?;
+        let elapsed = state.started_at?.elapsed();
+        (!stats.avg.is_zero() && elapsed > stats.avg.mul_f32(SLOW_STEP_RATIO)).then_some(stats.avg)
+    }
+
+    /// 오류 코드에 대한 지식베이스 링크를 생성한다.
+    pub(super) fn error_kb_url(&self, code: &str) -> Option<String> {
+        self.error_kb_url_template
+            .as_ref()
+            .map(|template| template.replace("{code}", code))
+    }
+
+    /// 승인을 기다리는 게이트 Step 목록을 시나리오 순서대로 반환한다.
+    ///
+    /// Loop 하위 Step과 `on_failure` 정리 Step 안의 게이트도 포함한다.
+    pub(super) fn awaiting_gates(&self) -> Vec<&Step> {
+        fn collect<'a>(
+            steps: &'a [Step],
+            step_states: &HashMap<String, StepRuntimeState>,
+            gates: &mut Vec<&'a Step>,
+        ) {
+            for step in steps {
+                match &step.kind {
+                    StepKind::ManualGate { .. }
+                        if step_states.get(&step.id).is_some_and(|state| {
+                            matches!(state.status, StepStatus::AwaitingApproval)
+                        }) =>
+                    {
+                        gates.push(step);
+                    }
+                    StepKind::Loop { config } => collect(&config.steps, step_states, gates),
+                    _ => {}
+                }
+            }
+        }
+        let mut gates = Vec::new();
         if let Some(scenario) = &self.scenario {
-            if scenario.steps.is_empty() {
-                return 0.0;
-            }
-            let completed = self
-                .step_states
-                .values()
-                .filter(|state| matches!(state.status, StepStatus::Success | StepStatus::Failed(_)))
-                .count();
-            completed as f32 / scenario.steps.len() as f32
-        } else {
-            0.0
+            collect(&scenario.steps, &self.step_states, &mut gates);
+            collect(scenario.cleanup_steps(), &self.step_states, &mut gates);
+        }
+        gates
+    }
+
+    /// 대기 중인 Step이 승인을 기다리는 게이트 뒤에 있는지 확인한다.
+    ///
+    /// 직간접 상위 Step 중 하나라도 승인 대기 중이면 `true`이다.
+    pub(super) fn behind_awaiting_gate(&self, step_id: &str) -> bool {
+        let Some(scenario) = &self.scenario else {
+            return false;
+        };
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = vec![step_id];
+        while let Some(id) = stack.pop() {
+            let Some(step) = scenario.steps.iter().find(|step| step.id == id) else {
+                continue;
+            };
+            for dep in &step.depends_on {
+                if !visited.insert(dep.as_str()) {
+                    continue;
+                }
+                if self
+                    .step_states
+                    .get(dep)
+                    .is_some_and(|state| matches!(state.status, StepStatus::AwaitingApproval))
+                {
+                    return true;
+                }
+                stack.push(dep.as_str());
+            }
+        }
+        false
+    }
+
+    /// 승인 대기 중인 게이트를 현재 OS 사용자 이름으로 승인한다.
+    ///
+    /// 데몬에 붙어 있으면 데몬이 자기 OS 사용자 이름으로 기록한다.
+    pub(super) fn approve_gate(&mut self, step_id: &str) {
+        if let Some(daemon) = &self.daemon {
+            daemon.send(DaemonRequest::Approve {
+                step_id: step_id.to_string(),
+            });
+            return;
+        }
+        let approved = self
+            .confirm_bridge
+            .as_ref()
+            .is_some_and(|bridge| bridge.approve_gate(step_id, &local_user_name()));
+        if !approved {
+            self.last_error = Some(tf("app.gate_not_waiting", &[("step", &step_id)]));
+        }
+    }
+
+    /// 실행 중인 Step 하나만 취소하고 `mode`에 따라 실패 또는 성공으로 처리하게 한다.
+    pub(super) fn cancel_step(&mut self, step_id: &str, mode: StepCancelMode) {
+        if let Some(daemon) = &self.daemon {
+            daemon.send(DaemonRequest::CancelStep {
+                step_id: step_id.to_string(),
+                mode,
+            });
+            return;
+        }
+        let cancelled = self
+            .confirm_bridge
+            .as_ref()
+            .is_some_and(|bridge| bridge.cancel_step(step_id, mode));
+        if !cancelled {
+            self.last_error = Some(tf("app.step_not_running", &[("step", &step_id)]));
         }
     }
 
     /// 지정한 컨펌 요청에 응답한다.
     pub(super) fn respond_confirm(&mut self, request_id: u64, accepted: bool) {
+        if let Some(daemon) = &self.daemon {
+            daemon.send(DaemonRequest::Confirm {
+                request_id,
+                accepted,
+            });
+            self.pending_confirms
+                .retain(|req| req.request_id != request_id);
+            return;
+        }
         if let Some(bridge) = &self.confirm_bridge {
             if bridge.respond(request_id, accepted) {
                 self.pending_confirms
@@ -421,10 +3035,33 @@ impl BatchOrchestratorApp {
     }
 }
 
+/// 시나리오의 변수 계약 위반을 한 줄에 하나씩 모은다. 없으면 빈 문자열이다.
+fn scenario_contract_summary(scenario: Option<&Scenario>) -> String {
+    scenario
+        .map(Scenario::contract_violations)
+        .unwrap_or_default()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Scenario 구조체를 파일로 저장한다.
-fn save_scenario_to_file(scenario: &Scenario, path: &PathBuf) -> Result<(), String> {
-    let mut file = File::create(path).map_err(|e| e.to_string())?;
-    let yaml = serde_yaml::to_string(scenario).map_err(|e| e.to_string())?;
-    file.write_all(yaml.as_bytes()).map_err(|e| e.to_string())?;
+fn save_scenario_to_file(scenario: &Scenario, path: &PathBuf) -> anyhow::Result<()> {
+    let yaml = scenario_to_yaml(scenario)?;
+    let bytes = if is_encrypted_path(path) {
+        encrypt(yaml.as_bytes(), &resolve_passphrase()?)?
+    } else {
+        yaml.into_bytes()
+    };
+    let mut file = File::create(path)?;
+    file.write_all(&bytes)?;
     Ok(())
 }
+
+/// YAML과 암호화 YAML 필터를 가진 파일 대화상자를 만든다.
+fn scenario_file_dialog() -> rfd::FileDialog {
+    rfd::FileDialog::new()
+        .add_filter("YAML", &["yaml", "yml"])
+        .add_filter(t("app.encrypted_filter"), &[ENCRYPTED_EXTENSION])
+}
@@ -0,0 +1,65 @@
+use crate::engine::StepRuntimeState;
+use crate::scenario::Step;
+use crate::theme::{StepVisualKind, ThemePalette};
+use eframe::egui;
+use std::collections::HashMap;
+
+mod sqlldr;
+
+/// 실행 탭 Step 상세 영역에 Step 유형별로 덧붙이는 확장 패널이다.
+///
+/// 새 Step 유형은 이 트레이트를 구현해 [`builtin_panels`]에 등록하면 `app/ui` 코드를 고치지 않고
+/// 자기 실행 결과를 보여 줄 수 있다. 패널은 매 프레임 다시 그리므로 무거운 계산은 피한다.
+pub(crate) trait StepDetailPanel {
+    /// 접이식 제목으로 표시할 패널 이름을 반환한다.
+    fn title(&self) -> String;
+
+    /// 선택한 Step의 정의와 실행 상태로 패널 본문을 그린다.
+    fn ui(&self, ui: &mut egui::Ui, view: &StepDetailView<'_>);
+}
+
+/// 확장 패널에 넘기는 선택 Step 정보이다.
+pub(crate) struct StepDetailView<'a> {
+    /// 시나리오의 Step 정의.
+    pub step: &'a Step,
+    /// 현재 실행 상태와 메모리에 남은 로그.
+    pub state: &'a StepRuntimeState,
+    /// 현재 테마 팔레트.
+    pub palette: &'a ThemePalette,
+}
+
+/// Step 시각 분류별로 등록된 확장 패널 목록이다.
+#[derive(Default)]
+pub(crate) struct StepDetailPanels {
+    /// 시각 분류별 패널. 등록한 순서대로 그린다.
+    panels: HashMap<StepVisualKind, Vec<Box<dyn StepDetailPanel>>>,
+}
+
+impl StepDetailPanels {
+    /// 기본 제공 패널을 모두 등록한 목록을 만든다.
+    pub(crate) fn with_builtin() -> Self {
+        let mut panels = Self::default();
+        builtin_panels(&mut panels);
+        panels
+    }
+
+    /// 시각 분류에 패널을 등록한다.
+    pub(crate) fn register(&mut self, kind: StepVisualKind, panel: Box<dyn StepDetailPanel>) {
+        self.panels.entry(kind).or_default().push(panel);
+    }
+
+    /// Step에 해당하는 패널 목록을 반환한다.
+    pub(crate) fn for_step(&self, step: &Step) -> &[Box<dyn StepDetailPanel>] {
+        self.panels
+            .get(&StepVisualKind::from_step_kind(&step.kind))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// 기본 제공 확장 패널을 등록한다. 새 Step 유형의 패널은 여기에 한 줄을 추가한다.
+fn builtin_panels(panels: &mut StepDetailPanels) {
+    panels.register(
+        StepVisualKind::SqlLoader,
+        Box::new(sqlldr::SqlLoaderCountsPanel),
+    );
+}
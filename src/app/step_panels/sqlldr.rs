@@ -0,0 +1,134 @@
+use super::{StepDetailPanel, StepDetailView};
+use crate::i18n::{t, tf};
+use eframe::egui::{self, RichText};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// 처리한 논리 레코드 수를 알리는 진행·완료 줄이다.
+static RECORD_COUNT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:Commit point reached|Load completed) - logical record count (\d+)")
+        .expect("레코드 수 정규식")
+});
+
+/// 요약의 테이블 제목 줄이다.
+static TABLE: Lazy<Regex> = Lazy::new(|| Regex::new(r"Table ([^\s:]+):").expect("테이블 정규식"));
+
+/// 테이블별 적재 행 수 줄이다.
+static LOADED: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\d+) Rows? successfully loaded").expect("적재 행 정규식"));
+
+/// 로그 파일 요약의 전체 읽기·거부·버림 레코드 수 줄이다.
+static TOTAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"Total logical records (read|rejected|discarded):\s+(\d+)")
+        .expect("전체 레코드 수 정규식")
+});
+
+/// sqlldr 출력에서 읽은 행 수 집계이다. 출력에 없던 값은 `None`이다.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SqlLoaderCounts {
+    /// 읽은 논리 레코드 수.
+    read: Option<u64>,
+    /// 거부한 레코드 수.
+    rejected: Option<u64>,
+    /// WHEN 조건으로 버린 레코드 수.
+    discarded: Option<u64>,
+    /// 테이블별 적재 행 수(출력 순서).
+    tables: Vec<(String, u64)>,
+}
+
+impl SqlLoaderCounts {
+    /// Step 로그 줄에서 행 수를 모은다. 같은 값이 여러 번 나오면 마지막 값을 쓴다.
+    fn parse(logs: &[String]) -> Self {
+        let mut counts = Self::default();
+        let mut table: Option<String> = None;
+        for line in logs {
+            if let Some(caps) = RECORD_COUNT.captures(line) {
+                counts.read = caps[1].parse().ok();
+            }
+            if let Some(caps) = TOTAL.captures(line) {
+                let value = caps[2].parse().ok();
+                match &caps[1] {
+                    "read" => counts.read = value,
+                    "rejected" => counts.rejected = value,
+                    _ => counts.discarded = value,
+                }
+            }
+            if let Some(caps) = TABLE.captures(line) {
+                table = Some(caps[1].to_string());
+            }
+            if let Some(caps) = LOADED.captures(line)
+                && let Ok(rows) = caps[1].parse()
+            {
+                let name = table.take().unwrap_or_else(|| "-".to_string());
+                match counts
+                    .tables
+                    .iter_mut()
+                    .find(|(existing, _)| *existing == name)
+                {
+                    Some(entry) => entry.1 = rows,
+                    None => counts.tables.push((name, rows)),
+                }
+            }
+        }
+        counts
+    }
+
+    /// 아무 값도 읽지 못했는지 여부를 반환한다.
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// sqlldr Step 출력에서 읽은 행 수와 테이블별 적재 행 수를 보여 주는 패널이다.
+pub(super) struct SqlLoaderCountsPanel;
+
+impl StepDetailPanel for SqlLoaderCountsPanel {
+    fn title(&self) -> String {
+        t("detail.sqlldr_title").to_string()
+    }
+
+    fn ui(&self, ui: &mut egui::Ui, view: &StepDetailView<'_>) {
+        let counts = SqlLoaderCounts::parse(&view.state.logs);
+        if counts.is_empty() {
+            ui.label(RichText::new(t("detail.sqlldr_empty")).color(view.palette.fg_text_secondary));
+            return;
+        }
+        egui::Grid::new("sqlldr_counts_grid")
+            .num_columns(2)
+            .spacing([12.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                let totals = [
+                    (
+                        "detail.sqlldr_read",
+                        counts.read,
+                        view.palette.fg_text_primary,
+                    ),
+                    (
+                        "detail.sqlldr_rejected",
+                        counts.rejected,
+                        view.palette.accent_error,
+                    ),
+                    (
+                        "detail.sqlldr_discarded",
+                        counts.discarded,
+                        view.palette.accent_warning,
+                    ),
+                ];
+                for (key, value, color) in totals {
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    ui.label(t(key));
+                    let text = RichText::new(format!(": {value}"));
+                    ui.label(if value > 0 { text.color(color) } else { text });
+                    ui.end_row();
+                }
+                for (table, rows) in &counts.tables {
+                    ui.label(tf("detail.sqlldr_loaded", &[("table", table)]));
+                    ui.label(RichText::new(format!(": {rows}")).color(view.palette.accent_success));
+                    ui.end_row();
+                }
+            });
+    }
+}
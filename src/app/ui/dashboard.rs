@@ -0,0 +1,498 @@
+use super::*;
+use crate::run_dashboard::{DASHBOARD_PERIODS, DailyRunStats, RunDashboard, RunEntry, StepRanking};
+use crate::theme::ThemePalette;
+use chrono::NaiveDate;
+
+/// 일별 막대 그래프 높이이다.
+const CHART_HEIGHT: f32 = 120.0;
+
+/// 막대 아래 날짜 라벨 영역 높이이다.
+const CHART_LABEL_HEIGHT: f32 = 16.0;
+
+/// 실행 목록에 표시할 최대 행 수이다.
+const RUN_LIST_ROWS: usize = 50;
+
+impl BatchOrchestratorApp {
+    /// 실행 이력 통계 대시보드 탭을 렌더링한다.
+    pub(super) fn render_dashboard_view(&mut self, ctx: &egui::Context) {
+        let palette = *self.theme.palette();
+        let decorations = *self.theme.decorations();
+        if self.dashboard.is_none() && self.dashboard_rx.is_none() {
+            self.reload_dashboard();
+        }
+        let central_frame = egui::Frame {
+            fill: palette.bg_main,
+            stroke: egui::Stroke::new(1.0, palette.border_soft),
+            rounding: egui::Rounding::same(decorations.container_rounding),
+            inner_margin: decorations.card_inner_margin,
+            ..Default::default()
+        };
+        egui::CentralPanel::default()
+            .frame(central_frame)
+            .show(ctx, |ui| {
+                self.render_dashboard_filters(ui);
+                ui.add_space(12.0);
+                let Some(dashboard) = self.dashboard.clone() else {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(
+                            RichText::new(t("dashboard.loading")).color(palette.fg_text_secondary),
+                        );
+                    });
+                    return;
+                };
+                if dashboard.total_runs() == 0 {
+                    ui.label(RichText::new(t("dashboard.empty")).color(palette.fg_text_secondary));
+                    return;
+                }
+                let card = egui::Frame::none()
+                    .fill(palette.bg_panel)
+                    .stroke(egui::Stroke::new(1.0, palette.border_soft))
+                    .rounding(egui::Rounding::same(decorations.card_rounding))
+                    .inner_margin(decorations.card_inner_margin);
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false; 2])
+                    .show(ui, |ui| {
+                        ui.spacing_mut().item_spacing.y = 12.0;
+                        card.show(ui, |ui| render_dashboard_totals(ui, &palette, &dashboard));
+                        let runs: Vec<(NaiveDate, Option<f32>, String)> = dashboard
+                            .days
+                            .iter()
+                            .map(|day| (day.date, Some(day.runs as f32), day.runs.to_string()))
+                            .collect();
+                        let max_runs = dashboard.days.iter().map(|day| day.runs).max();
+                        card.show(ui, |ui| {
+                            ui.label(RichText::new(t("dashboard.runs_per_day")).strong());
+                            draw_bar_chart(
+                                ui,
+                                &palette,
+                                &runs,
+                                max_runs.unwrap_or(0) as f32,
+                                palette.accent_primary,
+                            );
+                        });
+                        let rates: Vec<(NaiveDate, Option<f32>, String)> = dashboard
+                            .days
+                            .iter()
+                            .map(|day| {
+                                let rate = day.success_rate();
+                                let label = rate
+                                    .map(|rate| format!("{:.0}%", rate * 100.0))
+                                    .unwrap_or_else(|| t("dashboard.no_runs").to_string());
+                                (day.date, rate, label)
+                            })
+                            .collect();
+                        card.show(ui, |ui| {
+                            ui.label(RichText::new(t("dashboard.success_trend")).strong());
+                            draw_bar_chart(ui, &palette, &rates, 1.0, palette.accent_success);
+                        });
+                        let durations: Vec<(NaiveDate, Option<f32>, String)> = dashboard
+                            .days
+                            .iter()
+                            .map(|day| {
+                                let avg = day.avg_duration();
+                                let label = avg
+                                    .map(format_duration)
+                                    .unwrap_or_else(|| t("dashboard.no_runs").to_string());
+                                (day.date, avg.map(|avg| avg.as_secs_f32()), label)
+                            })
+                            .collect();
+                        let max_secs = durations
+                            .iter()
+                            .filter_map(|(_, value, _)| *value)
+                            .fold(0.0, f32::max);
+                        card.show(ui, |ui| {
+                            ui.label(RichText::new(t("dashboard.duration_trend")).strong());
+                            draw_bar_chart(
+                                ui,
+                                &palette,
+                                &durations,
+                                max_secs,
+                                palette.accent_warning,
+                            );
+                        });
+                        ui.columns(2, |columns| {
+                            card.show(&mut columns[0], |ui| {
+                                render_failing_steps(ui, &palette, &dashboard.failing_steps);
+                            });
+                            card.show(&mut columns[1], |ui| {
+                                render_longest_steps(ui, &palette, &dashboard.longest_steps);
+                            });
+                        });
+                        card.show(ui, |ui| {
+                            self.render_dashboard_runs(ui, &palette, &dashboard.runs)
+                        });
+                    });
+            });
+    }
+
+    /// 기간 안의 실행 목록을 메모·태그와 함께 그리고, 검색어로 거르거나 메모를 편집하게 한다.
+    fn render_dashboard_runs(
+        &mut self,
+        ui: &mut egui::Ui,
+        palette: &ThemePalette,
+        runs: &[RunEntry],
+    ) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(t("dashboard.runs")).strong());
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut self.dashboard_search)
+                    .hint_text(t("dashboard.search_hint"))
+                    .desired_width(240.0),
+            );
+        });
+        let matched: Vec<&RunEntry> = runs
+            .iter()
+            .filter(|run| run.matches(&self.dashboard_search))
+            .collect();
+        if matched.is_empty() {
+            ui.label(
+                RichText::new(t("dashboard.no_matching_runs")).color(palette.fg_text_secondary),
+            );
+            return;
+        }
+        let mut edit = None;
+        egui::Grid::new("dashboard_runs")
+            .num_columns(6)
+            .spacing([12.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(RichText::new(t("dashboard.col_finished")).strong());
+                ui.label(RichText::new(t("dashboard.col_scenario")).strong());
+                ui.label(RichText::new(t("dashboard.col_result")).strong());
+                ui.label(RichText::new(t("dashboard.col_duration")).strong());
+                ui.label(RichText::new(t("dashboard.col_note")).strong());
+                ui.label("");
+                ui.end_row();
+                for run in matched.iter().take(RUN_LIST_ROWS) {
+                    ui.label(run.finished_at.format("%Y-%m-%d %H:%M").to_string());
+                    ui.label(&run.scenario);
+                    let (result, color) = if run.success {
+                        (t("dashboard.run_success"), palette.accent_success)
+                    } else {
+                        (t("dashboard.run_failed"), palette.accent_error)
+                    };
+                    ui.label(RichText::new(result).color(color));
+                    ui.label(format_duration(run.duration));
+                    ui.horizontal_wrapped(|ui| {
+                        if let Some(note) = &run.note {
+                            for tag in &note.tags {
+                                ui.label(
+                                    RichText::new(format!("#{tag}")).color(palette.accent_primary),
+                                );
+                            }
+                            ui.label(&note.note);
+                        }
+                    });
+                    match &run.run_id {
+                        Some(run_id) => {
+                            if ui
+                                .small_button("✏")
+                                .on_hover_text(t("dashboard.edit_note"))
+                                .clicked()
+                            {
+                                edit =
+                                    Some((run.scenario.clone(), run_id.clone(), run.note.clone()));
+                            }
+                        }
+                        None => {
+                            ui.label("");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        if matched.len() > RUN_LIST_ROWS {
+            ui.label(
+                RichText::new(tf(
+                    "dashboard.more_runs",
+                    &[("count", &(matched.len() - RUN_LIST_ROWS))],
+                ))
+                .color(palette.fg_text_secondary),
+            );
+        }
+        if let Some((scenario, run_id, note)) = edit {
+            self.begin_run_note_edit(&scenario, &run_id, note.as_ref());
+        }
+        self.render_run_note_editor(ui, palette);
+    }
+
+    /// 편집 중인 실행 메모 양식을 그린다.
+    fn render_run_note_editor(&mut self, ui: &mut egui::Ui, palette: &ThemePalette) {
+        let Some(edit) = &mut self.run_note_edit else {
+            return;
+        };
+        let mut save = false;
+        let mut cancel = false;
+        ui.separator();
+        ui.label(
+            RichText::new(tf(
+                "dashboard.note_for",
+                &[("scenario", &edit.scenario), ("run", &edit.run_id)],
+            ))
+            .strong(),
+        );
+        ui.add(
+            egui::TextEdit::multiline(&mut edit.note)
+                .hint_text(t("toolbar.run_note_hint"))
+                .desired_rows(2)
+                .desired_width(f32::INFINITY),
+        );
+        ui.horizontal(|ui| {
+            ui.label(t("toolbar.run_tags"));
+            ui.add(
+                egui::TextEdit::singleline(&mut edit.tags)
+                    .hint_text(t("toolbar.run_tags_hint"))
+                    .desired_width(240.0),
+            );
+            save = ui.button(t("common.save")).clicked();
+            cancel = ui.button(t("common.cancel")).clicked();
+        });
+        if let Some(error) = &edit.error {
+            ui.label(RichText::new(error).color(palette.accent_error));
+        }
+        if save {
+            self.save_run_note_edit();
+        } else if cancel {
+            self.run_note_edit = None;
+        }
+    }
+
+    /// 대시보드 위쪽의 시나리오·기간 필터와 새로고침·복사 버튼을 그린다.
+    fn render_dashboard_filters(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(t("dashboard.title")).strong());
+            ui.separator();
+            let scenarios = self
+                .dashboard
+                .as_ref()
+                .map(|dashboard| dashboard.scenarios.clone())
+                .unwrap_or_default();
+            let all = t("dashboard.all_scenarios");
+            egui::ComboBox::from_id_source("dashboard_scenario")
+                .selected_text(self.dashboard_scenario.as_deref().unwrap_or(all))
+                .show_ui(ui, |ui| {
+                    changed |= ui
+                        .selectable_value(&mut self.dashboard_scenario, None, all)
+                        .changed();
+                    for name in scenarios {
+                        let label = name.clone();
+                        changed |= ui
+                            .selectable_value(&mut self.dashboard_scenario, Some(name), label)
+                            .changed();
+                    }
+                });
+            for days in DASHBOARD_PERIODS {
+                let label = tf("dashboard.period", &[("days", &days.to_string())]);
+                changed |= ui
+                    .selectable_value(&mut self.dashboard_days, days, label)
+                    .changed();
+            }
+            ui.separator();
+            if ui.button(t("dashboard.refresh")).clicked() {
+                changed = true;
+            }
+            if let Some(dashboard) = &self.dashboard
+                && ui
+                    .button(t("dashboard.copy"))
+                    .on_hover_text(t("dashboard.copy_hint"))
+                    .clicked()
+            {
+                let text = daily_table_text(dashboard);
+                ui.output_mut(|o| o.copied_text = text);
+            }
+        });
+        if changed {
+            self.reload_dashboard();
+        }
+    }
+}
+
+/// 기간 전체의 실행 수·성공률·평균 소요 시간을 한 줄로 그린다.
+fn render_dashboard_totals(ui: &mut egui::Ui, palette: &ThemePalette, dashboard: &RunDashboard) {
+    let Some(total) = dashboard.total() else {
+        return;
+    };
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 32.0;
+        let rate = total
+            .success_rate()
+            .map(|rate| format!("{:.1}%", rate * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        let avg = total
+            .avg_duration()
+            .map(format_duration)
+            .unwrap_or_else(|| "-".to_string());
+        for (label, value) in [
+            (t("dashboard.total_runs"), total.runs.to_string()),
+            (t("dashboard.success_rate"), rate),
+            (t("dashboard.avg_duration"), avg),
+        ] {
+            ui.vertical(|ui| {
+                ui.label(RichText::new(label).color(palette.fg_text_secondary));
+                ui.label(RichText::new(value).size(22.0).strong());
+            });
+        }
+    });
+}
+
+/// 날짜별 막대 그래프를 그린다. 값이 없는 날은 비워 두고, 막대에 마우스를 올리면 날짜와 값을 보여 준다.
+///
+/// # 매개변수
+/// - `bars`: `(날짜, 값, 표시 문자열)` 목록. 오래된 날부터.
+/// - `max`: 그래프 맨 위에 해당하는 값. 0 이하이면 막대를 그리지 않는다.
+fn draw_bar_chart(
+    ui: &mut egui::Ui,
+    palette: &ThemePalette,
+    bars: &[(NaiveDate, Option<f32>, String)],
+    max: f32,
+    color: egui::Color32,
+) {
+    let size = egui::vec2(ui.available_width(), CHART_HEIGHT + CHART_LABEL_HEIGHT);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    if bars.is_empty() {
+        return;
+    }
+    let painter = ui.painter_at(rect);
+    let plot = egui::Rect::from_min_size(rect.min, egui::vec2(rect.width(), CHART_HEIGHT));
+    let slot = plot.width() / bars.len() as f32;
+    let hovered = response
+        .hover_pos()
+        .map(|pos| (((pos.x - plot.min.x) / slot) as usize).min(bars.len() - 1));
+    for (idx, (_, value, _)) in bars.iter().enumerate() {
+        let left = plot.min.x + slot * idx as f32;
+        if hovered == Some(idx) {
+            let column = egui::Rect::from_min_max(
+                egui::pos2(left, plot.min.y),
+                egui::pos2(left + slot, plot.max.y),
+            );
+            painter.rect_filled(column, 2.0, blend_color(color, palette.bg_panel, 0.85));
+        }
+        let Some(value) = value.filter(|_| max > 0.0) else {
+            continue;
+        };
+        let height = (plot.height() * (value / max).clamp(0.0, 1.0)).max(1.0);
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(left + slot * 0.15, plot.max.y - height),
+            egui::pos2(left + slot * 0.85, plot.max.y),
+        );
+        painter.rect_filled(bar, 2.0, color);
+    }
+    painter.line_segment(
+        [plot.left_bottom(), plot.right_bottom()],
+        egui::Stroke::new(1.0, palette.border_soft),
+    );
+    let font = egui::FontId::proportional(11.0);
+    let first = bars[0].0.format("%m-%d").to_string();
+    let last = bars[bars.len() - 1].0.format("%m-%d").to_string();
+    let label_y = plot.max.y + 2.0;
+    painter.text(
+        egui::pos2(plot.min.x, label_y),
+        egui::Align2::LEFT_TOP,
+        first,
+        font.clone(),
+        palette.fg_text_secondary,
+    );
+    painter.text(
+        egui::pos2(plot.max.x, label_y),
+        egui::Align2::RIGHT_TOP,
+        last,
+        font,
+        palette.fg_text_secondary,
+    );
+    if let Some(idx) = hovered {
+        let (date, _, label) = &bars[idx];
+        response.on_hover_text(format!("{date} · {label}"));
+    }
+}
+
+/// 실패가 많은 Step 순위를 표로 그린다.
+fn render_failing_steps(ui: &mut egui::Ui, palette: &ThemePalette, steps: &[StepRanking]) {
+    ui.label(RichText::new(t("dashboard.failing_steps")).strong());
+    if steps.is_empty() {
+        ui.label(RichText::new(t("dashboard.no_failures")).color(palette.fg_text_secondary));
+        return;
+    }
+    egui::Grid::new("dashboard_failing_steps")
+        .num_columns(3)
+        .spacing([12.0, 6.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label(RichText::new(t("dashboard.step")).strong());
+            ui.label(RichText::new(t("dashboard.failures")).strong());
+            ui.label(RichText::new(t("dashboard.failure_rate")).strong());
+            ui.end_row();
+            for step in steps {
+                ui.label(format!("{} / {}", step.scenario, step.step_id));
+                ui.label(format!("{} / {}", step.failures, step.runs));
+                ui.label(
+                    RichText::new(format!(
+                        "{:.0}%",
+                        step.failures as f32 / step.runs.max(1) as f32 * 100.0
+                    ))
+                    .color(palette.accent_error),
+                );
+                ui.end_row();
+            }
+        });
+}
+
+/// 평균 소요 시간이 긴 Step 순위를 표로 그린다.
+fn render_longest_steps(ui: &mut egui::Ui, palette: &ThemePalette, steps: &[StepRanking]) {
+    ui.label(RichText::new(t("dashboard.longest_steps")).strong());
+    egui::Grid::new("dashboard_longest_steps")
+        .num_columns(3)
+        .spacing([12.0, 6.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label(RichText::new(t("dashboard.step")).strong());
+            ui.label(RichText::new(t("dashboard.avg")).strong());
+            ui.label(RichText::new(t("dashboard.max")).strong());
+            ui.end_row();
+            for step in steps {
+                ui.label(format!("{} / {}", step.scenario, step.step_id));
+                ui.label(format_duration(step.avg));
+                ui.label(RichText::new(format_duration(step.max)).color(palette.fg_text_secondary));
+                ui.end_row();
+            }
+        });
+}
+
+/// 일별 집계를 스프레드시트에 붙여 넣을 수 있는 탭 구분 표로 만든다.
+fn daily_table_text(dashboard: &RunDashboard) -> String {
+    let mut text = [
+        t("dashboard.col_date"),
+        t("dashboard.total_runs"),
+        t("dashboard.col_successes"),
+        t("dashboard.success_rate"),
+        t("dashboard.col_avg_secs"),
+    ]
+    .join("\t");
+    text.push('\n');
+    let total = dashboard.total();
+    let rows = dashboard.days.iter().chain(total.as_ref());
+    for (idx, day) in rows.enumerate() {
+        let date = if idx == dashboard.days.len() {
+            t("dashboard.col_total").to_string()
+        } else {
+            day.date.to_string()
+        };
+        text.push_str(&daily_row(&date, day));
+    }
+    text
+}
+
+/// 일별 집계 한 줄을 탭 구분 문자열로 만든다.
+fn daily_row(date: &str, day: &DailyRunStats) -> String {
+    let rate = day
+        .success_rate()
+        .map(|rate| format!("{:.1}", rate * 100.0))
+        .unwrap_or_default();
+    let avg = day
+        .avg_duration()
+        .map(|avg| avg.as_secs().to_string())
+        .unwrap_or_default();
+    format!("{date}\t{}\t{}\t{rate}\t{avg}\n", day.runs, day.successes)
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+impl BatchOrchestratorApp {
+    /// 실행 탭의 키보드 조작을 처리한다.
+    ///
+    /// `↑`/`↓`/`Home`/`End`로 Step 카드 포커스를 옮기고 `Enter`로 포커스된 Step의 상세를 연다.
+    /// `F5`는 실행, `Shift+F5`는 정지, `A`는 포커스된 승인 게이트 승인이다. 텍스트 입력 중이거나
+    /// 컨펌·암호 문구 대화상자가 열려 있으면 무시한다.
+    pub(super) fn handle_run_keyboard(&mut self, ctx: &egui::Context) {
+        if ctx.wants_keyboard_input()
+            || !self.pending_confirms.is_empty()
+            || self.passphrase_prompt.is_some()
+        {
+            return;
+        }
+        let pressed = |key: egui::Key| ctx.input(|input| input.key_pressed(key));
+        let shift = ctx.input(|input| input.modifiers.shift);
+        if pressed(egui::Key::F5) {
+            if shift {
+                if self.scenario_running {
+                    self.stop_scenario();
+                }
+            } else if self.daemon.is_none() || self.daemon_connected {
+                self.start_scenario();
+            }
+        }
+        let order = self.run_card_order();
+        if order.is_empty() {
+            return;
+        }
+        let current = self
+            .run_focus
+            .as_ref()
+            .or(self.selected_step.as_ref())
+            .and_then(|id| order.iter().position(|step_id| step_id == id));
+        let last = order.len() - 1;
+        let target = if pressed(egui::Key::ArrowDown) {
+            Some(current.map_or(0, |idx| (idx + 1).min(last)))
+        } else if pressed(egui::Key::ArrowUp) {
+            Some(current.map_or(0, |idx| idx.saturating_sub(1)))
+        } else if pressed(egui::Key::Home) {
+            Some(0)
+        } else if pressed(egui::Key::End) {
+            Some(last)
+        } else {
+            None
+        };
+        if let Some(idx) = target {
+            self.run_focus = Some(order[idx].clone());
+            self.scroll_to_focus = true;
+        }
+        let Some(focus) = self.run_focus.clone() else {
+            return;
+        };
+        if pressed(egui::Key::Enter) {
+            self.selected_step = Some(focus.clone());
+        }
+        let awaiting = self
+            .step_states
+            .get(&focus)
+            .is_some_and(|state| matches!(state.status, StepStatus::AwaitingApproval));
+        if awaiting && pressed(egui::Key::A) {
+            self.approve_gate(&focus);
+        }
+    }
+
+    /// Step 패널에 그려지는 순서대로 카드의 Step ID를 반환한다.
+    fn run_card_order(&self) -> Vec<String> {
+        let Some(scenario) = &self.scenario else {
+            return Vec::new();
+        };
+        scenario
+            .stage_groups()
+            .into_iter()
+            .flat_map(|(_, steps)| steps)
+            .chain(scenario.cleanup_steps())
+            .map(|step| step.id.clone())
+            .collect()
+    }
+}
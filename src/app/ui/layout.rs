@@ -19,19 +19,123 @@ impl BatchOrchestratorApp {
                 ui.horizontal(|ui| {
                     ui.spacing_mut().item_spacing.x = 10.0;
                     let tabs = [
-                        (AppTab::Run, "실행"),
+                        (AppTab::Run, t("tab.run")),
                         (AppTab::ScenarioBuilder, "Scenario Builder"),
+                        (AppTab::Dashboard, t("tab.dashboard")),
                     ];
                     for (tab, label) in tabs {
                         let selected = self.active_tab == tab;
                         if ui.selectable_label(selected, label).clicked() {
+                            if !selected && tab == AppTab::Dashboard {
+                                self.reload_dashboard();
+                            }
                             self.active_tab = tab;
                         }
                     }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let current = language();
+                        egui::ComboBox::from_id_source("language_select")
+                            .selected_text(format!("🌐 {}", current.label()))
+                            .show_ui(ui, |ui| {
+                                for option in Language::ALL {
+                                    if ui
+                                        .selectable_label(option == current, option.label())
+                                        .clicked()
+                                    {
+                                        self.change_language(option);
+                                    }
+                                }
+                            });
+                        if ui.button(t("settings.menu")).clicked() {
+                            self.open_settings();
+                        }
+                        if ui.button(t("search.menu")).clicked() {
+                            self.scenario_search.get_or_insert_with(Default::default);
+                        }
+                    });
                 });
             });
     }
 
+    /// 엔진 전용 런타임의 워커 수와 블로킹 스레드 상한 설정을 그린다. 0은 자동(기본값)이다.
+    pub(super) fn render_runtime_settings(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.theme.palette();
+        ui.set_min_width(260.0);
+        ui.label(RichText::new(t("settings.runtime_title")).strong());
+        let auto = t("settings.auto");
+        let mut workers = self.engine_runtime_draft.worker_threads.unwrap_or(0);
+        let mut blocking = self.engine_runtime_draft.max_blocking_threads.unwrap_or(0);
+        egui::Grid::new("engine_runtime_settings")
+            .num_columns(2)
+            .spacing([12.0, 6.0])
+            .show(ui, |ui| {
+                ui.label(t("settings.workers"));
+                ui.add(
+                    egui::DragValue::new(&mut workers)
+                        .clamp_range(0..=256)
+                        .custom_formatter(|value, _| {
+                            if value == 0.0 {
+                                auto.to_string()
+                            } else {
+                                format!("{value}")
+                            }
+                        }),
+                );
+                ui.end_row();
+                ui.label(t("settings.blocking_threads"));
+                ui.add(
+                    egui::DragValue::new(&mut blocking)
+                        .clamp_range(0..=4096)
+                        .custom_formatter(|value, _| {
+                            if value == 0.0 {
+                                auto.to_string()
+                            } else {
+                                format!("{value}")
+                            }
+                        }),
+                );
+                ui.end_row();
+            });
+        self.engine_runtime_draft.worker_threads = (workers > 0).then_some(workers);
+        self.engine_runtime_draft.max_blocking_threads = (blocking > 0).then_some(blocking);
+        let format_value = |value: Option<usize>| value.map_or(auto.to_string(), |v| v.to_string());
+        ui.label(
+            RichText::new(tf(
+                "settings.runtime_current",
+                &[
+                    (
+                        "workers",
+                        &format_value(self.engine_runtime_config.worker_threads),
+                    ),
+                    (
+                        "blocking",
+                        &format_value(self.engine_runtime_config.max_blocking_threads),
+                    ),
+                ],
+            ))
+            .color(palette.fg_text_secondary),
+        );
+        let changed = self.engine_runtime_draft != self.engine_runtime_config;
+        let blocked_reason = if self.daemon.is_some() {
+            Some(t("settings.runtime_daemon"))
+        } else if self.scenario_running {
+            Some(t("settings.runtime_running"))
+        } else {
+            None
+        };
+        let response = ui.add_enabled(
+            changed && blocked_reason.is_none(),
+            egui::Button::new(t("settings.apply")),
+        );
+        if response.clicked() {
+            self.apply_engine_runtime();
+            ui.close_menu();
+        }
+        if let Some(reason) = blocked_reason {
+            response.on_disabled_hover_text(reason);
+        }
+    }
+
     /// 실행 탭 전체 레이아웃을 렌더링한다.
     fn render_run_view(&mut self, ctx: &egui::Context) {
         let palette = *self.theme.palette();
@@ -63,6 +167,15 @@ impl BatchOrchestratorApp {
             .show(ctx, |ui| {
                 self.render_step_panel(ui);
             });
+        if self.rehearsal_mode {
+            egui::SidePanel::right("rehearsal")
+                .resizable(false)
+                .default_width(300.0)
+                .frame(sidebar_frame)
+                .show(ctx, |ui| {
+                    self.render_rehearsal_panel(ui);
+                });
+        }
         let central_frame = egui::Frame {
             fill: palette.bg_main,
             stroke: egui::Stroke::new(1.0, palette.border_soft),
@@ -75,6 +188,46 @@ impl BatchOrchestratorApp {
             .show(ctx, |ui| {
                 ui.vertical(|ui| {
                     ui.spacing_mut().item_spacing.y = 18.0;
+                    if let Some(run) = self.interrupted_run.clone() {
+                        egui::Frame::none()
+                            .fill(blend_color(palette.accent_warning, palette.bg_panel, 0.9))
+                            .stroke(egui::Stroke::new(1.0, palette.accent_warning))
+                            .rounding(egui::Rounding::same(decorations.card_rounding))
+                            .inner_margin(decorations.card_inner_margin)
+                            .show(ui, |ui| {
+                                self.render_interrupted_run(ui, &run);
+                            });
+                    }
+                    if let Some(summary) = self.failure_summary() {
+                        egui::Frame::none()
+                            .fill(blend_color(palette.accent_error, palette.bg_panel, 0.9))
+                            .stroke(egui::Stroke::new(1.0, palette.accent_error))
+                            .rounding(egui::Rounding::same(decorations.card_rounding))
+                            .inner_margin(decorations.card_inner_margin)
+                            .show(ui, |ui| {
+                                self.render_failure_summary(ui, &summary);
+                            });
+                    }
+                    if self.scenario_running && !self.db_pool_usage.is_empty() {
+                        egui::Frame::none()
+                            .fill(palette.bg_panel)
+                            .stroke(egui::Stroke::new(1.0, palette.border_soft))
+                            .rounding(egui::Rounding::same(decorations.card_rounding))
+                            .inner_margin(decorations.card_inner_margin)
+                            .show(ui, |ui| {
+                                self.render_db_pool_panel(ui);
+                            });
+                    }
+                    if let Some(report) = self.run_summary.clone() {
+                        egui::Frame::none()
+                            .fill(palette.bg_panel)
+                            .stroke(egui::Stroke::new(1.0, palette.accent_primary))
+                            .rounding(egui::Rounding::same(decorations.card_rounding))
+                            .inner_margin(decorations.card_inner_margin)
+                            .show(ui, |ui| {
+                                self.render_run_summary(ui, &report);
+                            });
+                    }
                     egui::Frame::none()
                         .fill(palette.bg_panel)
                         .stroke(egui::Stroke::new(1.0, palette.border_soft))
@@ -82,6 +235,7 @@ impl BatchOrchestratorApp {
                         .inner_margin(decorations.card_inner_margin)
                         .show(ui, |ui| {
                             self.render_step_detail(ui);
+                            self.render_step_cancel(ui);
                         });
                     egui::ScrollArea::vertical()
                         .auto_shrink([false; 2])
@@ -111,20 +265,100 @@ impl BatchOrchestratorApp {
                 let ratio = self.progress_ratio();
                 ui.vertical(|ui| {
                     ui.label(
-                        RichText::new("📈 전체 진행률")
+                        RichText::new(t("progress.title"))
                             .color(palette.fg_text_primary)
                             .strong(),
                     );
                     ui.add_space(6.0);
+                    let percent = format!("{:.0}", ratio * 100.0);
+                    let text = match self.estimated_remaining() {
+                        Some(remaining) => tf(
+                            "progress.with_eta",
+                            &[("percent", &percent), ("eta", &format_duration(remaining))],
+                        ),
+                        None => tf("progress.percent", &[("percent", &percent)]),
+                    };
                     ui.add(
                         egui::ProgressBar::new(ratio)
                             .fill(palette.accent_primary)
-                            .text(format!("진행률: {:.0}%", ratio * 100.0)),
+                            .text(text),
                     );
+                    let counts = self.progress_counts();
+                    ui.horizontal(|ui| {
+                        for (label, count, color) in [
+                            (
+                                t("progress.succeeded"),
+                                counts.succeeded,
+                                palette.accent_success,
+                            ),
+                            (t("progress.failed"), counts.failed, palette.accent_error),
+                            (
+                                t("progress.blocked"),
+                                counts.blocked,
+                                palette.fg_text_secondary,
+                            ),
+                            (
+                                t("progress.remaining"),
+                                counts.remaining,
+                                palette.accent_pending,
+                            ),
+                        ] {
+                            ui.label(RichText::new(format!("{label} {count}")).color(color));
+                        }
+                    });
+                    if self.scenario_running {
+                        self.render_running_lanes(ui);
+                    }
                 });
             });
     }
 
+    /// 지금 실행 중인 Step마다 경과 시간 막대를 한 줄씩 그린다.
+    ///
+    /// 평균 소요 시간 이력이 있으면 평균 대비 진행 정도를, 평균을 넘기면 경고색으로 표시한다.
+    fn render_running_lanes(&self, ui: &mut egui::Ui) {
+        let palette = *self.theme.palette();
+        let lanes = self.running_lanes();
+        ui.add_space(6.0);
+        let count = lanes.len().to_string();
+        ui.label(
+            RichText::new(tf("progress.lanes", &[("count", &count)]))
+                .color(palette.fg_text_secondary),
+        );
+        if lanes.is_empty() {
+            ui.label(RichText::new(t("progress.lanes_idle")).color(palette.accent_pending));
+            return;
+        }
+        for lane in lanes {
+            ui.horizontal(|ui| {
+                ui.add_sized(
+                    [180.0, 14.0],
+                    egui::Label::new(RichText::new(&lane.name).monospace()).truncate(true),
+                );
+                let (ratio, fill) = match lane.expected.filter(|avg| !avg.is_zero()) {
+                    Some(avg) if lane.elapsed > avg => (1.0, palette.accent_warning),
+                    Some(avg) => (
+                        lane.elapsed.as_secs_f32() / avg.as_secs_f32(),
+                        palette.accent_primary,
+                    ),
+                    None => (1.0, palette.accent_pending),
+                };
+                ui.add(
+                    egui::ProgressBar::new(ratio)
+                        .fill(fill)
+                        .desired_width(220.0)
+                        .desired_height(8.0),
+                );
+                let elapsed = format_duration(lane.elapsed);
+                let text = match lane.expected {
+                    Some(avg) => format!("{elapsed} / {}", format_duration(avg)),
+                    None => elapsed,
+                };
+                ui.label(RichText::new(text).color(palette.fg_text_secondary));
+            });
+        }
+    }
+
     /// 시나리오 빌더 탭 전체 레이아웃을 렌더링한다.
     fn render_builder_view(&mut self, ctx: &egui::Context) {
         let palette = *self.theme.palette();
@@ -142,21 +376,75 @@ impl BatchOrchestratorApp {
             .show(ctx, |ui| {
                 self.render_builder_toolbar(ui);
             });
+        if self.sql_check_report.is_some() {
+            let validation_frame = egui::Frame {
+                fill: palette.bg_panel,
+                stroke: egui::Stroke::new(1.0, palette.border_soft),
+                rounding: egui::Rounding::same(decorations.card_rounding),
+                inner_margin: egui::Margin::symmetric(20.0, 12.0),
+                ..Default::default()
+            };
+            egui::TopBottomPanel::bottom("builder_validation")
+                .frame(validation_frame)
+                .resizable(true)
+                .show(ctx, |ui| {
+                    self.render_sql_check_panel(ui);
+                });
+        }
         let mut builder_ui = ScenarioBuilderUi::new(&self.theme, &mut self.editor_state);
         builder_ui.show(ctx);
+        self.persist_shared_connections();
+        self.start_sql_preview();
+        self.start_schema_fetch();
+        self.start_shell_trial();
+        self.start_step_test();
     }
 }
 
 impl eframe::App for BatchOrchestratorApp {
     /// egui 메인 루프에서 호출되어 UI를 갱신한다.
     fn update(&mut self, ctx: &egui::Context, _: &mut eframe::Frame) {
-        self.drain_events();
+        if self.drain_events() {
+            ctx.request_repaint();
+        } else if self.has_retry_countdown()
+            || self.exit_shutdown.is_some()
+            || self.db_reload_rx.is_some()
+            || self.sql_preview_rx.is_some()
+            || self.step_stats_rx.is_some()
+            || self.schema_fetch.is_some()
+            || self.dashboard_rx.is_some()
+            || self.scenario_search_rx.is_some()
+            || self.shell_trial_rx.is_some()
+            || self.step_test_rx.is_some()
+        {
+            ctx.request_repaint_after(std::time::Duration::from_millis(250));
+        } else if self.scenario_running {
+            ctx.request_repaint_after(std::time::Duration::from_secs(1));
+        } else if self.file_watch.is_some() {
+            ctx.request_repaint_after(FILE_WATCH_INTERVAL);
+        }
         self.theme.apply(ctx);
         self.render_tab_selector(ctx);
         match self.active_tab {
-            AppTab::Run => self.render_run_view(ctx),
+            AppTab::Run => {
+                self.handle_run_keyboard(ctx);
+                self.load_dropped_scenario(ctx);
+                self.refresh_step_preview();
+                self.render_run_view(ctx);
+            }
             AppTab::ScenarioBuilder => self.render_builder_view(ctx),
+            AppTab::Dashboard => self.render_dashboard_view(ctx),
         }
+        self.render_reload_toast(ctx);
         self.render_confirm_modal(ctx);
+        self.render_passphrase_modal(ctx);
+        self.render_scenario_error_modal(ctx);
+        self.render_cost_warning_modal(ctx);
+        self.render_run_approval_modal(ctx);
+        self.render_settings_modal(ctx);
+        self.render_scenario_search_window(ctx);
+        self.render_expanded_yaml_window(ctx);
+        self.render_exit_modal(ctx);
+        self.handle_close_request(ctx);
     }
 }
@@ -1,11 +1,29 @@
 use crate::editor::ScenarioBuilderUi;
-use crate::engine::{ConfirmPhase, StepStatus};
-use crate::scenario::ConfirmDefault;
+use crate::engine::{
+    ConfirmMode, ConfirmPhase, DEFAULT_LOG_DIR, DiagnosticResult, EngineError, ExpectedOutput,
+    InterruptedRun, PreviewField, PreviewValue, StepCancelMode, StepStatus, SummaryResult,
+    VarChange,
+};
+use crate::history::format_duration;
+use crate::i18n::{Language, language, t, tf};
+use crate::scenario::{ConfirmDefault, RetryOn, Step, StepKind, TimeWindowPolicy, TimeoutPolicy};
+use crate::scenario_crypto::is_encrypted_path;
+use crate::scenario_search::ScenarioSearchHit;
+use crate::settings::settings_path;
+use crate::sql_check::SqlIssueLevel;
+use crate::theme::{StepVisualKind, ThemeVariant, blend_color};
 use eframe::egui::{self, RichText};
+use std::collections::HashMap;
 
-use super::state::{AppTab, BatchOrchestratorApp};
+use super::file_watch::FILE_WATCH_INTERVAL;
+use super::state::{
+    AppTab, BatchOrchestratorApp, ExitShutdown, FailureSummary, PassphraseAction, RunSummaryReport,
+};
+use super::step_panels::StepDetailView;
 use super::widgets::{PrimaryButton, StepCard, solid_section_header};
 
+mod dashboard;
+mod keyboard;
 mod layout;
 mod modal;
 mod panels;
@@ -5,7 +5,7 @@ impl BatchOrchestratorApp {
     pub(super) fn render_confirm_modal(&mut self, ctx: &egui::Context) {
         if let Some(request) = self.pending_confirms.first().cloned() {
             let palette = *self.theme.palette();
-            egui::Window::new("Step 실행 확인")
+            egui::Window::new(t("confirm.window"))
                 .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
                 .collapsible(false)
                 .resizable(false)
@@ -14,7 +14,7 @@ impl BatchOrchestratorApp {
                     ui.set_width(420.0);
                     ui.vertical(|ui| {
                         ui.label(
-                            RichText::new("사용자 컨펌이 필요합니다")
+                            RichText::new(t("confirm.title"))
                                 .size(20.0)
                                 .color(palette.fg_text_primary)
                                 .strong(),
@@ -29,16 +29,16 @@ impl BatchOrchestratorApp {
                             .strong(),
                         );
                         let phase_label = match request.phase {
-                            ConfirmPhase::Before => "실행 전",
-                            ConfirmPhase::After => "실행 후",
+                            ConfirmPhase::Before => t("confirm.phase_before"),
+                            ConfirmPhase::After => t("confirm.phase_after"),
                         };
-                        ui.label(format!(
-                            "종류: {} · 단계: {}",
-                            request.step_kind, phase_label
+                        ui.label(tf(
+                            "confirm.kind_phase",
+                            &[("kind", &request.step_kind), ("phase", &phase_label)],
                         ));
                         if let Some(summary) = &request.summary {
                             ui.add_space(6.0);
-                            ui.label("요약");
+                            ui.label(t("confirm.summary"));
                             let mut summary_buf = summary.clone();
                             ui.add(
                                 egui::TextEdit::multiline(&mut summary_buf)
@@ -53,23 +53,25 @@ impl BatchOrchestratorApp {
                             ui.label(RichText::new(message).strong());
                         }
                         ui.add_space(6.0);
-                        ui.label(format!(
-                            "기본 응답: {}",
-                            match request.default_answer {
-                                ConfirmDefault::Yes => "예",
-                                ConfirmDefault::No => "아니오",
-                            }
-                        ));
+                        let answer = match request.default_answer {
+                            ConfirmDefault::Yes => t("common.yes"),
+                            ConfirmDefault::No => t("common.no"),
+                        };
+                        ui.label(tf("confirm.default_answer", &[("answer", &answer)]));
                         ui.add_space(10.0);
                         ui.horizontal(|ui| {
                             if ui
-                                .add(PrimaryButton::new(&self.theme, "예 · 계속").icon("✅"))
+                                .add(
+                                    PrimaryButton::new(&self.theme, t("confirm.accept")).icon("✅"),
+                                )
                                 .clicked()
                             {
                                 self.respond_confirm(request.request_id, true);
                             }
                             if ui
-                                .add(PrimaryButton::new(&self.theme, "아니오 · 중단").icon("🛑"))
+                                .add(
+                                    PrimaryButton::new(&self.theme, t("confirm.reject")).icon("🛑"),
+                                )
                                 .clicked()
                             {
                                 self.respond_confirm(request.request_id, false);
@@ -79,4 +81,782 @@ impl BatchOrchestratorApp {
                 });
         }
     }
+
+    /// 암호화 시나리오의 암호 문구 입력 대화상자를 렌더링한다.
+    pub(super) fn render_passphrase_modal(&mut self, ctx: &egui::Context) {
+        let Some(mut prompt) = self.passphrase_prompt.take() else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let mut submit = false;
+        let mut cancel = false;
+        egui::Window::new(t("passphrase.window"))
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .show(ctx, |ui| {
+                ui.set_width(380.0);
+                ui.label(
+                    RichText::new(t("passphrase.title"))
+                        .size(20.0)
+                        .color(palette.fg_text_primary)
+                        .strong(),
+                );
+                ui.add_space(6.0);
+                let path = match &prompt.action {
+                    PassphraseAction::OpenRun(path)
+                    | PassphraseAction::OpenEditor(path)
+                    | PassphraseAction::Save(path) => path,
+                };
+                ui.label(
+                    RichText::new(path.display().to_string()).color(palette.fg_text_secondary),
+                );
+                if let Some(error) = &prompt.error {
+                    ui.label(RichText::new(error).color(palette.accent_error));
+                }
+                ui.add_space(6.0);
+                let response = ui.add(egui::TextEdit::singleline(&mut prompt.input).password(true));
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    submit = true;
+                }
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(PrimaryButton::new(&self.theme, t("common.ok")).icon("🔓"))
+                        .clicked()
+                    {
+                        submit = true;
+                    }
+                    if ui.button(t("common.cancel")).clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+        if cancel {
+            return;
+        }
+        self.passphrase_prompt = Some(prompt);
+        if submit {
+            self.submit_passphrase();
+        }
+    }
+
+    /// 승인이 필요한 실행 프로필의 실행 승인 창을 렌더링한다.
+    ///
+    /// 요청자가 아닌 사용자가 이름(프로필에 승인 코드가 있으면 코드도)을 입력해 승인해야 실행이 시작된다.
+    pub(super) fn render_run_approval_modal(&mut self, ctx: &egui::Context) {
+        let Some(prompt) = self.run_approval_prompt.as_mut() else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let mut approve = false;
+        let mut reject = false;
+        let mut withdraw = false;
+        egui::Window::new(t("approval.window"))
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .show(ctx, |ui| {
+                ui.set_width(420.0);
+                ui.label(
+                    RichText::new(t("approval.title"))
+                        .size(20.0)
+                        .color(palette.fg_text_primary)
+                        .strong(),
+                );
+                ui.add_space(6.0);
+                let request = &prompt.request;
+                egui::Grid::new("run_approval_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        for (key, value) in [
+                            ("approval.scenario", &request.scenario),
+                            ("approval.profile", &request.profile),
+                            ("approval.requester", &request.requester),
+                            ("approval.requested_at", &request.requested_at),
+                            ("approval.id", &request.id),
+                        ] {
+                            ui.label(RichText::new(t(key)).color(palette.fg_text_secondary));
+                            ui.label(RichText::new(value).color(palette.fg_text_primary));
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(6.0);
+                ui.label(RichText::new(t("approval.hint_code")).color(palette.fg_text_secondary));
+                if let Some(error) = &prompt.error {
+                    ui.label(RichText::new(error).color(palette.accent_error));
+                }
+                ui.add_space(6.0);
+                ui.label(t("approval.approver"));
+                ui.text_edit_singleline(&mut prompt.approver);
+                ui.label(t("approval.code"));
+                let response = ui.add(egui::TextEdit::singleline(&mut prompt.code).password(true));
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    approve = true;
+                }
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(PrimaryButton::new(&self.theme, t("approval.approve")).icon("✅"))
+                        .clicked()
+                    {
+                        approve = true;
+                    }
+                    if ui.button(t("approval.reject")).clicked() {
+                        reject = true;
+                    }
+                    if ui.button(t("approval.withdraw")).clicked() {
+                        withdraw = true;
+                    }
+                });
+            });
+        if approve {
+            self.approve_run();
+        } else if reject || withdraw {
+            self.close_run_approval(reject);
+        }
+    }
+
+    /// 실행 중에 창을 닫으려 하면 종료 방법을 묻고, 취소 후 종료를 기다리는 동안 진행 상황을 보여 준다.
+    ///
+    /// 데몬 모드에서는 실행이 데몬 프로세스에 있으므로 창만 닫고 실행을 맡길 수 있다.
+    pub(super) fn render_exit_modal(&mut self, ctx: &egui::Context) {
+        let Some(shutdown) = self.exit_shutdown else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let attached = self.daemon.is_some();
+        let mut cancel_run = false;
+        let mut detach = false;
+        let mut force = false;
+        let mut keep = false;
+        egui::Window::new(t("exit.window"))
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .show(ctx, |ui| {
+                ui.set_width(440.0);
+                ui.label(
+                    RichText::new(t("exit.title"))
+                        .size(20.0)
+                        .color(palette.fg_text_primary)
+                        .strong(),
+                );
+                ui.add_space(6.0);
+                match shutdown {
+                    ExitShutdown::Asking => {
+                        ui.label(RichText::new(t("exit.message")).color(palette.fg_text_secondary));
+                        ui.add_space(10.0);
+                        if ui
+                            .add(PrimaryButton::new(&self.theme, t("exit.cancel_wait")).icon("⏹"))
+                            .on_hover_text(t("exit.cancel_wait_hint"))
+                            .clicked()
+                        {
+                            cancel_run = true;
+                        }
+                        let hint = if attached {
+                            t("exit.detach_hint")
+                        } else {
+                            t("exit.detach_unavailable")
+                        };
+                        if ui
+                            .add_enabled(attached, egui::Button::new(t("exit.detach")))
+                            .on_hover_text(hint)
+                            .on_disabled_hover_text(hint)
+                            .clicked()
+                        {
+                            detach = true;
+                        }
+                        if ui
+                            .button(RichText::new(t("exit.force")).color(palette.accent_error))
+                            .on_hover_text(t("exit.force_hint"))
+                            .clicked()
+                        {
+                            force = true;
+                        }
+                        ui.add_space(6.0);
+                        if ui.button(t("exit.keep_running")).clicked() {
+                            keep = true;
+                        }
+                    }
+                    ExitShutdown::Draining { since } => {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(tf("exit.draining", &[("secs", &since.elapsed().as_secs())]));
+                        });
+                        ui.add_space(10.0);
+                        if ui
+                            .button(RichText::new(t("exit.force")).color(palette.accent_error))
+                            .on_hover_text(t("exit.force_hint"))
+                            .clicked()
+                        {
+                            force = true;
+                        }
+                    }
+                }
+            });
+        if cancel_run {
+            self.cancel_run_for_exit();
+        } else if detach {
+            self.exit_now(ctx);
+        } else if force {
+            self.force_exit(ctx);
+        } else if keep {
+            self.exit_shutdown = None;
+        }
+    }
+
+    /// 실행 시작 때 확인하지 않은 비용 경고가 있으면 그래도 실행할지 묻는 창을 렌더링한다.
+    pub(super) fn render_cost_warning_modal(&mut self, ctx: &egui::Context) {
+        let Some(warnings) = &self.cost_warning_prompt else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let mut run = false;
+        let mut cancel = false;
+        egui::Window::new(t("cost_check.window"))
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .show(ctx, |ui| {
+                ui.set_width(480.0);
+                ui.label(
+                    RichText::new(t("cost_check.prompt_title"))
+                        .size(20.0)
+                        .color(palette.fg_text_primary)
+                        .strong(),
+                );
+                ui.add_space(6.0);
+                egui::ScrollArea::vertical()
+                    .max_height(220.0)
+                    .show(ui, |ui| {
+                        for warning in warnings {
+                            ui.label(
+                                RichText::new(format!("💸 {warning}"))
+                                    .color(palette.accent_warning),
+                            );
+                        }
+                    });
+                ui.add_space(6.0);
+                ui.label(
+                    RichText::new(t("cost_check.prompt_hint")).color(palette.fg_text_secondary),
+                );
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(PrimaryButton::new(&self.theme, t("cost_check.run_anyway")).icon("▶"))
+                        .clicked()
+                    {
+                        run = true;
+                    }
+                    if ui.button(t("common.cancel")).clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+        if run {
+            self.override_cost_warnings();
+        } else if cancel {
+            self.cost_warning_prompt = None;
+        }
+    }
+
+    /// 시나리오 파일 형식 오류 창을 렌더링한다.
+    ///
+    /// 파일 위치, Step, 원문 줄과 힌트를 나눠 보여 주고, 파일을 고친 뒤 바로 다시 불러올 수 있게 한다.
+    pub(super) fn render_scenario_error_modal(&mut self, ctx: &egui::Context) {
+        let Some(error) = &self.scenario_error else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let mut close = false;
+        let mut retry = None;
+        egui::Window::new(t("parse_error.window"))
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .show(ctx, |ui| {
+                ui.set_width(520.0);
+                ui.label(
+                    RichText::new(t("parse_error.title"))
+                        .size(20.0)
+                        .color(palette.accent_error)
+                        .strong(),
+                );
+                ui.add_space(6.0);
+                egui::Grid::new("scenario_error_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        if let Some(file) = &error.file {
+                            ui.label(
+                                RichText::new(t("parse_error.file"))
+                                    .color(palette.fg_text_secondary),
+                            );
+                            ui.label(file.display().to_string());
+                            ui.end_row();
+                        }
+                        if let Some(location) = error.location_label() {
+                            ui.label(
+                                RichText::new(t("parse_error.location"))
+                                    .color(palette.fg_text_secondary),
+                            );
+                            ui.label(location);
+                            ui.end_row();
+                        }
+                        if let Some(step_id) = &error.step_id {
+                            ui.label(
+                                RichText::new(t("parse_error.step"))
+                                    .color(palette.fg_text_secondary),
+                            );
+                            ui.label(RichText::new(step_id).strong());
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(6.0);
+                ui.label(RichText::new(&error.message).color(palette.fg_text_primary));
+                if let (Some(line), Some(text)) = (error.line, &error.source_line) {
+                    ui.add_space(6.0);
+                    let mut snippet = format!("{line} | {text}");
+                    if let Some(column) = error.column {
+                        let gutter = line.to_string().len() + 3;
+                        snippet.push('\n');
+                        snippet.push_str(&" ".repeat(gutter + column.saturating_sub(1)));
+                        snippet.push('^');
+                    }
+                    egui::Frame::none()
+                        .fill(palette.bg_log)
+                        .rounding(egui::Rounding::same(6.0))
+                        .inner_margin(egui::Margin::same(8.0))
+                        .show(ui, |ui| {
+                            ui.label(RichText::new(snippet).monospace());
+                        });
+                }
+                if let Some(hint) = &error.hint {
+                    ui.add_space(6.0);
+                    ui.label(
+                        RichText::new(tf("parse_error.hint", &[("hint", hint)]))
+                            .color(palette.accent_warning),
+                    );
+                }
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if let Some(file) = &error.file
+                        && ui
+                            .add(
+                                PrimaryButton::new(&self.theme, t("parse_error.reload")).icon("🔄"),
+                            )
+                            .clicked()
+                    {
+                        retry = Some(file.clone());
+                    }
+                    if ui.button(format!("📋 {}", t("common.copy"))).clicked() {
+                        ui.output_mut(|o| o.copied_text = error.to_string());
+                    }
+                    if ui.button(t("common.close")).clicked() {
+                        close = true;
+                    }
+                });
+            });
+        if close {
+            self.scenario_error = None;
+        }
+        if let Some(path) = retry {
+            self.scenario_error = None;
+            self.apply_scenario_path(path);
+        }
+    }
+
+    /// 앱 설정 대화상자를 렌더링한다.
+    pub(super) fn render_settings_modal(&mut self, ctx: &egui::Context) {
+        let Some(mut draft) = self.settings_draft.take() else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let mut save = false;
+        let mut cancel = false;
+        egui::Window::new(t("settings.window"))
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .show(ctx, |ui| {
+                ui.set_width(420.0);
+                ui.label(
+                    RichText::new(t("settings.window"))
+                        .size(20.0)
+                        .color(palette.fg_text_primary)
+                        .strong(),
+                );
+                ui.label(
+                    RichText::new(tf(
+                        "settings.file_hint",
+                        &[("path", &settings_path().display())],
+                    ))
+                    .color(palette.fg_text_secondary),
+                );
+                ui.add_space(6.0);
+                egui::Grid::new("app_settings")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(t("settings.language"));
+                        let selected = draft
+                            .language
+                            .map_or(t("settings.language_default"), |lang| lang.label());
+                        egui::ComboBox::from_id_source("settings_language")
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(
+                                    &mut draft.language,
+                                    None,
+                                    t("settings.language_default"),
+                                );
+                                for option in Language::ALL {
+                                    ui.selectable_value(
+                                        &mut draft.language,
+                                        Some(option),
+                                        option.label(),
+                                    );
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(t("settings.theme"));
+                        ui.horizontal(|ui| {
+                            for variant in ThemeVariant::ALL {
+                                let label = match variant {
+                                    ThemeVariant::Light => t("settings.theme_light"),
+                                    ThemeVariant::Dark => t("settings.theme_dark"),
+                                };
+                                ui.radio_value(&mut draft.theme, variant, label);
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(t("settings.ui_scale"));
+                        ui.add(
+                            egui::Slider::new(&mut draft.ui_scale, 0.75..=2.0)
+                                .step_by(0.05)
+                                .fixed_decimals(2),
+                        );
+                        ui.end_row();
+
+                        ui.label(t("settings.log_dir"));
+                        let mut log_dir = draft
+                            .log_dir
+                            .as_ref()
+                            .map(|dir| dir.display().to_string())
+                            .unwrap_or_default();
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(&mut log_dir).hint_text(DEFAULT_LOG_DIR),
+                            )
+                            .changed()
+                        {
+                            draft.log_dir =
+                                (!log_dir.trim().is_empty()).then(|| log_dir.trim().into());
+                        }
+                        ui.end_row();
+
+                        ui.label(t("settings.scenario_dir"));
+                        let mut scenario_dir = draft
+                            .scenario_dir
+                            .as_ref()
+                            .map(|dir| dir.display().to_string())
+                            .unwrap_or_default();
+                        if ui
+                            .add(egui::TextEdit::singleline(&mut scenario_dir).hint_text("."))
+                            .changed()
+                        {
+                            draft.scenario_dir = (!scenario_dir.trim().is_empty())
+                                .then(|| scenario_dir.trim().into());
+                        }
+                        ui.end_row();
+
+                        ui.label(t("settings.history_keep_days"));
+                        let mut days = draft.history_keep_days.unwrap_or(0);
+                        let keep_all = t("settings.history_keep_all");
+                        ui.add(
+                            egui::DragValue::new(&mut days)
+                                .clamp_range(0..=3650)
+                                .custom_formatter(|value, _| {
+                                    if value == 0.0 {
+                                        keep_all.to_string()
+                                    } else {
+                                        format!("{value}")
+                                    }
+                                }),
+                        );
+                        draft.history_keep_days = (days > 0).then_some(days);
+                        ui.end_row();
+                    });
+                ui.separator();
+                self.render_runtime_settings(ui);
+                ui.separator();
+                ui.label(RichText::new(t("settings.notify")).strong());
+                ui.label(RichText::new(t("settings.notify_hint")).color(palette.fg_text_secondary));
+                let mut remove = None;
+                for (idx, endpoint) in draft.notify_endpoints.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(endpoint)
+                                .hint_text("http://host:port/path")
+                                .desired_width(340.0),
+                        );
+                        if ui.button("🗑").clicked() {
+                            remove = Some(idx);
+                        }
+                    });
+                }
+                if let Some(idx) = remove {
+                    draft.notify_endpoints.remove(idx);
+                }
+                if ui.button(t("settings.notify_add")).clicked() {
+                    draft.notify_endpoints.push(String::new());
+                }
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(PrimaryButton::new(&self.theme, t("settings.save")).icon("💾"))
+                        .clicked()
+                    {
+                        save = true;
+                    }
+                    if ui.button(t("common.cancel")).clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+        if cancel {
+            return;
+        }
+        self.settings_draft = Some(draft);
+        if save {
+            self.save_settings(ctx);
+        }
+    }
+
+    /// 플레이스홀더를 치환한 시나리오를 읽기 전용으로 보여 주는 펼친 YAML 창을 그린다.
+    pub(super) fn render_expanded_yaml_window(&mut self, ctx: &egui::Context) {
+        let Some(yaml) = self.expanded_yaml.take() else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let mut open = true;
+        let mut save = false;
+        egui::Window::new(t("expanded.window"))
+            .open(&mut open)
+            .default_width(720.0)
+            .show(ctx, |ui| {
+                ui.label(RichText::new(t("expanded.note")).color(palette.fg_text_secondary));
+                ui.horizontal(|ui| {
+                    if ui.button(t("expanded.copy")).clicked() {
+                        ui.output_mut(|output| output.copied_text = yaml.clone());
+                    }
+                    if ui.button(t("expanded.save")).clicked() {
+                        save = true;
+                    }
+                });
+                egui::ScrollArea::vertical()
+                    .max_height(520.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(&mut yaml.as_str())
+                                .code_editor()
+                                .desired_width(f32::INFINITY),
+                        );
+                    });
+            });
+        if !open {
+            return;
+        }
+        self.expanded_yaml = Some(yaml);
+        if save {
+            self.save_expanded_yaml();
+        }
+    }
+
+    /// `모든 시나리오에서 찾기` 창을 그린다.
+    ///
+    /// 결과는 파일별로 묶어 항목 종류, Step ID, 검색어가 나온 줄을 보여 주며 `열기`를 누르면 그 시나리오를
+    /// 열고 Step을 선택한다.
+    pub(super) fn render_scenario_search_window(&mut self, ctx: &egui::Context) {
+        let Some(mut search) = self.scenario_search.take() else {
+            return;
+        };
+        let palette = *self.theme.palette();
+        let dir = self.scenario_search_dir();
+        let searching = self.scenario_search_rx.is_some();
+        let mut open = true;
+        let mut submit = false;
+        let mut target: Option<ScenarioSearchHit> = None;
+        egui::Window::new(t("search.window"))
+            .open(&mut open)
+            .default_width(640.0)
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(tf("search.dir", &[("dir", &dir.display())]))
+                        .color(palette.fg_text_secondary),
+                );
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut search.query)
+                            .hint_text(t("search.hint"))
+                            .desired_width(420.0),
+                    );
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        submit = true;
+                    }
+                    if ui
+                        .add_enabled(
+                            !searching && !search.query.trim().is_empty(),
+                            egui::Button::new(t("search.run")),
+                        )
+                        .clicked()
+                    {
+                        submit = true;
+                    }
+                    if searching {
+                        ui.spinner();
+                        ui.label(t("search.running"));
+                    }
+                });
+                if let Some(error) = &search.error {
+                    ui.label(RichText::new(error).color(palette.accent_error));
+                }
+                let Some(report) = &search.report else {
+                    return;
+                };
+                ui.label(
+                    RichText::new(tf(
+                        "search.summary",
+                        &[("hits", &report.hits.len()), ("files", &report.scanned)],
+                    ))
+                    .color(palette.fg_text_secondary),
+                );
+                egui::ScrollArea::vertical()
+                    .max_height(420.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        let mut current: Option<&std::path::Path> = None;
+                        for hit in &report.hits {
+                            if current != Some(hit.path.as_path()) {
+                                current = Some(hit.path.as_path());
+                                ui.add_space(6.0);
+                                ui.label(
+                                    RichText::new(format!("📄 {}", hit.path.display())).strong(),
+                                );
+                            }
+                            ui.horizontal(|ui| {
+                                if ui.small_button(t("search.open")).clicked() {
+                                    target = Some(hit.clone());
+                                }
+                                ui.label(
+                                    RichText::new(hit.field.label()).color(palette.accent_primary),
+                                );
+                                let step = hit.step_id.as_deref().unwrap_or("params");
+                                ui.label(RichText::new(step).color(palette.fg_text_secondary));
+                                ui.label(RichText::new(&hit.snippet).monospace());
+                            });
+                        }
+                        if !report.skipped.is_empty() {
+                            ui.add_space(6.0);
+                            egui::CollapsingHeader::new(tf(
+                                "search.skipped",
+                                &[("count", &report.skipped.len())],
+                            ))
+                            .show(ui, |ui| {
+                                for (path, error) in &report.skipped {
+                                    ui.label(
+                                        RichText::new(format!("{}: {error}", path.display()))
+                                            .color(palette.fg_text_secondary),
+                                    );
+                                }
+                            });
+                        }
+                    });
+            });
+        if !open {
+            self.scenario_search_rx = None;
+            return;
+        }
+        self.scenario_search = Some(search);
+        if submit && !searching {
+            self.run_scenario_search();
+        }
+        if let Some(hit) = target {
+            self.open_search_hit(&hit);
+        }
+    }
+
+    /// 열린 시나리오 파일이 외부에서 바뀌면 화면 오른쪽 아래에 다시 불러올지 묻는 알림을 띄운다.
+    pub(super) fn render_reload_toast(&mut self, ctx: &egui::Context) {
+        let Some(watch) = &self.file_watch else {
+            return;
+        };
+        if !watch.has_pending() {
+            return;
+        }
+        let file_name = watch
+            .pending_file()
+            .unwrap_or(&watch.path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let palette = *self.theme.palette();
+        let mut reload = false;
+        let mut keep = false;
+        egui::Area::new(egui::Id::new("scenario_reload_toast"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-16.0, -16.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style())
+                    .stroke(egui::Stroke::new(1.0, palette.accent_warning))
+                    .inner_margin(egui::Margin::symmetric(14.0, 12.0))
+                    .show(ui, |ui| {
+                        ui.set_max_width(360.0);
+                        ui.label(
+                            RichText::new(tf("reload.title", &[("file", &file_name)]))
+                                .color(palette.fg_text_primary)
+                                .strong(),
+                        );
+                        ui.label(
+                            RichText::new(t("reload.message")).color(palette.fg_text_secondary),
+                        );
+                        if self.editor_state.dirty {
+                            ui.label(
+                                RichText::new(t("reload.dirty_warning"))
+                                    .color(palette.accent_warning),
+                            );
+                        }
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            let response = ui.add_enabled(
+                                !self.scenario_running,
+                                egui::Button::new(t("reload.reload")),
+                            );
+                            if response.clicked() {
+                                reload = true;
+                            }
+                            response.on_disabled_hover_text(t("reload.running"));
+                            if ui.button(t("reload.keep")).clicked() {
+                                keep = true;
+                            }
+                        });
+                    });
+            });
+        if reload {
+            self.reload_scenario_from_disk();
+        } else if keep {
+            self.keep_local_scenario();
+        }
+    }
 }
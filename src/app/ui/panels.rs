@@ -4,50 +4,345 @@ impl BatchOrchestratorApp {
     /// 좌측 Step 리스트 패널을 그린다.
     pub(super) fn render_step_panel(&mut self, ui: &mut egui::Ui) {
         let palette = *self.theme.palette();
-        solid_section_header(ui, &self.theme, "🧭", "작업 단계");
+        solid_section_header(ui, &self.theme, "🧭", t("panel.steps"));
+        ui.label(RichText::new(t("panel.keyboard_hint")).color(palette.fg_text_secondary));
         ui.add_space(12.0);
         ui.spacing_mut().item_spacing.y = 12.0;
+        let slow_steps: HashMap<String, std::time::Duration> = self
+            .scenario
+            .iter()
+            .flat_map(|scenario| scenario.steps.iter().chain(scenario.cleanup_steps()))
+            .filter_map(|step| {
+                self.slow_step_average(&step.id)
+                    .map(|avg| (step.id.clone(), avg))
+            })
+            .collect();
+        self.render_gate_approvals(ui);
 
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
             .show(ui, |ui| {
                 if let Some(scenario) = &self.scenario {
-                    for step in &scenario.steps {
-                        let state = self.step_states.get(&step.id).cloned().unwrap_or_default();
-                        let status_color = self.theme.status_color(&state.status);
-                        let (status_icon, status_text) = status::status_indicator(&state.status);
-                        let is_selected = self.selected_step.as_deref() == Some(step.id.as_str());
-
-                        let response = ui.add(
-                            StepCard::new(&self.theme, step.name.as_str(), step.id.as_str())
-                                .status(status_icon, status_text, status_color)
-                                .selected(is_selected),
+                    let selected = self.selected_step.as_deref();
+                    let mut clicked: Option<String> = None;
+                    let mut toggled: Option<(String, bool)> = None;
+                    let groups = scenario.stage_groups();
+                    let staged = groups.iter().any(|(stage, _)| stage.is_some());
+                    for (stage, steps) in groups {
+                        if !staged {
+                            for step in steps {
+                                if self.render_step_card(ui, step, &slow_steps, selected) {
+                                    clicked = Some(step.id.clone());
+                                }
+                            }
+                            continue;
+                        }
+                        let counts = self.counts_for(steps.iter().copied());
+                        let title = tf(
+                            "panel.stage_header",
+                            &[
+                                ("stage", &stage.unwrap_or(t("panel.no_stage"))),
+                                ("done", &counts.succeeded),
+                                ("total", &steps.len()),
+                            ],
                         );
-
-                        if response.clicked() {
-                            self.selected_step = Some(step.id.clone());
+                        // 키보드 포커스가 접힌 스테이지로 옮겨 가면 펼친다.
+                        let reveal_focus = self.scroll_to_focus
+                            && steps
+                                .iter()
+                                .any(|step| self.run_focus.as_deref() == Some(step.id.as_str()));
+                        let stage_key = stage.unwrap_or_default().to_string();
+                        let open = reveal_focus || !self.collapsed_stages.contains(&stage_key);
+                        if reveal_focus {
+                            toggled = Some((stage_key.clone(), false));
+                        }
+                        let response = egui::CollapsingHeader::new(RichText::new(title).strong())
+                            .id_source(("run_stage", stage))
+                            .open(Some(open))
+                            .show(ui, |ui| {
+                                let ratio = counts.succeeded as f32 / steps.len().max(1) as f32;
+                                let fill = if counts.failed > 0 {
+                                    palette.accent_error
+                                } else {
+                                    palette.accent_success
+                                };
+                                ui.add(
+                                    egui::ProgressBar::new(ratio).fill(fill).desired_height(6.0),
+                                );
+                                for step in steps {
+                                    if self.render_step_card(ui, step, &slow_steps, selected) {
+                                        clicked = Some(step.id.clone());
+                                    }
+                                }
+                            });
+                        if response.header_response.clicked() {
+                            toggled = Some((stage_key, open));
+                        }
+                    }
+                    let cleanup_steps = scenario.cleanup_steps();
+                    if !cleanup_steps.is_empty() {
+                        ui.label(
+                            RichText::new(format!("🧹 {}", t("panel.cleanup_steps")))
+                                .color(palette.fg_text_secondary)
+                                .strong(),
+                        );
+                    }
+                    for step in cleanup_steps {
+                        if self.render_step_card(ui, step, &slow_steps, selected) {
+                            clicked = Some(step.id.clone());
+                        }
+                    }
+                    if let Some(id) = clicked {
+                        self.run_focus = Some(id.clone());
+                        self.selected_step = Some(id);
+                    }
+                    if let Some((stage, collapse)) = toggled {
+                        if collapse {
+                            self.collapsed_stages.insert(stage);
+                        } else {
+                            self.collapsed_stages.remove(&stage);
                         }
                     }
                 } else {
-                    let info = egui::RichText::new("시나리오를 먼저 불러오세요.")
+                    let info = egui::RichText::new(t("panel.no_scenario"))
                         .color(palette.fg_text_secondary)
                         .italics();
                     ui.label(info);
                 }
             });
+        self.scroll_to_focus = false;
+    }
+
+    /// Step 카드 하나를 그리고 클릭되었는지 반환한다.
+    ///
+    /// # 매개변수
+    /// - `slow_steps`: 느린 Step으로 표시할 Step별 과거 평균 소요 시간.
+    /// - `selected`: 현재 선택된 Step ID.
+    fn render_step_card(
+        &self,
+        ui: &mut egui::Ui,
+        step: &Step,
+        slow_steps: &HashMap<String, std::time::Duration>,
+        selected: Option<&str>,
+    ) -> bool {
+        let palette = *self.theme.palette();
+        let state = self.step_states.get(&step.id).cloned().unwrap_or_default();
+        let slow_avg = slow_steps.get(&step.id).copied();
+        // 승인 대기 중인 게이트 뒤의 Step도 승인 대기로 보여 준다.
+        let shown_status =
+            if matches!(state.status, StepStatus::Pending) && self.behind_awaiting_gate(&step.id) {
+                StepStatus::AwaitingApproval
+            } else {
+                state.status.clone()
+            };
+        let overrun = state
+            .overrun
+            .filter(|_| matches!(state.status, StepStatus::Running));
+        let status_color = if slow_avg.is_some() {
+            palette.accent_error
+        } else if overrun.is_some() {
+            palette.accent_warning
+        } else {
+            self.theme.status_color(&shown_status)
+        };
+        let (status_icon, status_text) = status::status_indicator(&shown_status);
+        let retry_text = status::retry_progress(&state.status)
+            .or_else(|| {
+                overrun.map(|elapsed| {
+                    tf(
+                        "panel.overrun_card",
+                        &[("elapsed", &format_duration(elapsed))],
+                    )
+                })
+            })
+            .or_else(|| {
+                slow_avg.map(|avg| tf("panel.slow_card", &[("avg", &format_duration(avg))]))
+            })
+            .or_else(|| {
+                state
+                    .iterations
+                    .filter(|_| matches!(state.status, StepStatus::Running))
+                    .map(|(done, total)| {
+                        tf("panel.iterations", &[("done", &done), ("total", &total)])
+                    })
+            });
+        let status_text = retry_text.as_deref().unwrap_or(status_text);
+        let focused = self.run_focus.as_deref() == Some(step.id.as_str());
+        let response = ui.add(
+            StepCard::new(&self.theme, step.name.as_str(), step.id.as_str())
+                .visual(self.theme.step_visual_with(
+                    StepVisualKind::from_step_kind(&step.kind),
+                    step.icon.as_deref(),
+                    step.label.as_deref(),
+                ))
+                .status(status_icon, status_text, status_color)
+                .selected(selected == Some(step.id.as_str()))
+                .focused(focused)
+                .compact(matches!(step.kind, StepKind::Marker)),
+        );
+        if focused && self.scroll_to_focus {
+            response.scroll_to_me(Some(egui::Align::Center));
+        }
+        response.clicked()
+    }
+
+    /// 리허설 장애 주입 설정 패널을 그린다. 실행 중에는 설정을 바꿀 수 없다.
+    pub(super) fn render_rehearsal_panel(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.theme.palette();
+        solid_section_header(ui, &self.theme, "🎭", t("rehearsal.title"));
+        ui.add_space(8.0);
+        ui.label(RichText::new(t("rehearsal.hint")).color(palette.fg_text_secondary));
+        let Some(scenario) = &self.scenario else {
+            return;
+        };
+        let steps: Vec<(String, String)> = scenario
+            .steps
+            .iter()
+            .chain(scenario.cleanup_steps())
+            .map(|step| (step.id.clone(), step.name.clone()))
+            .collect();
+        let plan = &mut self.rehearsal_plan;
+        ui.add_enabled_ui(!self.scenario_running, |ui| {
+            ui.checkbox(&mut plan.simulate_others, t("rehearsal.simulate_others"));
+            ui.separator();
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    egui::Grid::new("rehearsal_grid")
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label(RichText::new(t("rehearsal.step")).strong());
+                            ui.label(RichText::new(t("rehearsal.fail")).strong());
+                            ui.label(RichText::new(t("rehearsal.delay")).strong());
+                            ui.end_row();
+                            for (id, name) in &steps {
+                                let mut fault = plan.faults.get(id).cloned().unwrap_or_default();
+                                ui.label(name).on_hover_text(id);
+                                let mut changed = ui.checkbox(&mut fault.fail, "").changed();
+                                changed |= ui
+                                    .add(
+                                        egui::DragValue::new(&mut fault.delay_sec)
+                                            .clamp_range(0..=3600)
+                                            .suffix(t("rehearsal.sec_suffix")),
+                                    )
+                                    .changed();
+                                ui.end_row();
+                                if !changed {
+                                    continue;
+                                }
+                                if fault.is_empty() {
+                                    plan.faults.remove(id);
+                                } else {
+                                    plan.faults.insert(id.clone(), fault);
+                                }
+                            }
+                        });
+                });
+        });
+    }
+
+    /// 승인을 기다리는 게이트마다 안내 문구와 승인 버튼을 표시한다.
+    fn render_gate_approvals(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.theme.palette();
+        let mut approved: Option<String> = None;
+        for step in self.awaiting_gates() {
+            egui::Frame::none()
+                .fill(blend_color(palette.accent_primary, palette.bg_panel, 0.9))
+                .stroke(egui::Stroke::new(1.0, palette.accent_primary))
+                .inner_margin(egui::Margin::symmetric(12.0, 10.0))
+                .show(ui, |ui| {
+                    ui.set_width(ui.available_width());
+                    ui.label(
+                        RichText::new(tf("panel.gate_waiting", &[("step", &step.name)]))
+                            .color(palette.fg_text_primary)
+                            .strong(),
+                    );
+                    if let StepKind::ManualGate { config } = &step.kind
+                        && let Some(message) = &config.message
+                    {
+                        ui.label(RichText::new(message).color(palette.fg_text_secondary));
+                    }
+                    if ui
+                        .add(PrimaryButton::new(&self.theme, t("panel.gate_approve")).icon("✋"))
+                        .clicked()
+                    {
+                        approved = Some(step.id.clone());
+                    }
+                });
+        }
+        if let Some(step_id) = approved {
+            self.approve_gate(&step_id);
+        }
+    }
+
+    /// 선택한 메인 Step이 실행 중이면 그 Step만 취소하는 버튼을 표시한다.
+    ///
+    /// 실패로 취소하면 그 Step에 의존하는 Step은 건너뛰고, 건너뛰면 성공으로 처리해 나머지 흐름을 이어 간다.
+    pub(super) fn render_step_cancel(&mut self, ui: &mut egui::Ui) {
+        if !self.scenario_running {
+            return;
+        }
+        let Some(step_id) = self.selected_step.clone() else {
+            return;
+        };
+        let is_main_step = self
+            .scenario
+            .as_ref()
+            .is_some_and(|scenario| scenario.steps.iter().any(|step| step.id == step_id));
+        let in_progress = self.step_states.get(&step_id).is_some_and(|state| {
+            matches!(
+                state.status,
+                StepStatus::Running
+                    | StepStatus::Waiting(_)
+                    | StepStatus::Retrying { .. }
+                    | StepStatus::AwaitingApproval
+            )
+        });
+        if !is_main_step || !in_progress {
+            return;
+        }
+        let mut requested: Option<StepCancelMode> = None;
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui
+                .button(format!("⏹ {}", t("panel.step_cancel_fail")))
+                .on_hover_text(t("panel.step_cancel_fail_hint"))
+                .clicked()
+            {
+                requested = Some(StepCancelMode::Fail);
+            }
+            if ui
+                .button(format!("⏭ {}", t("panel.step_cancel_skip")))
+                .on_hover_text(t("panel.step_cancel_skip_hint"))
+                .clicked()
+            {
+                requested = Some(StepCancelMode::Skip);
+            }
+        });
+        if let Some(mode) = requested {
+            self.cancel_step(&step_id, mode);
+        }
     }
 
     /// Step 상세 정보를 표시한다.
     pub(super) fn render_step_detail(&self, ui: &mut egui::Ui) {
-        solid_section_header(ui, &self.theme, "🧩", "Step 정보");
+        solid_section_header(ui, &self.theme, "🧩", t("detail.title"));
         ui.add_space(10.0);
         let palette = *self.theme.palette();
         if let Some(step_id) = &self.selected_step {
             if let Some(scenario) = &self.scenario {
-                if let Some(step) = scenario.steps.iter().find(|s| &s.id == step_id) {
+                if let Some(step) = scenario
+                    .steps
+                    .iter()
+                    .chain(scenario.cleanup_steps())
+                    .find(|s| &s.id == step_id)
+                {
                     let state = self.step_states.get(step_id).cloned().unwrap_or_default();
                     let status_color = self.theme.status_color(&state.status);
                     let (_, status_text) = status::status_indicator(&state.status);
+                    let retry_text = status::retry_progress(&state.status);
+                    let status_text = retry_text.as_deref().unwrap_or(status_text);
                     ui.label(
                         RichText::new(step.name.clone())
                             .size(20.0)
@@ -57,10 +352,45 @@ impl BatchOrchestratorApp {
                     ui.add_space(6.0);
                     ui.horizontal(|ui| {
                         ui.label(
-                            RichText::new(format!("상태 · {}", status_text))
+                            RichText::new(tf("detail.status", &[("status", &status_text)]))
                                 .color(status_color)
                                 .strong(),
                         );
+                        if let StepStatus::Waiting(until) = &state.status {
+                            ui.label(
+                                RichText::new(tf("detail.waiting_until", &[("until", until)]))
+                                    .color(palette.fg_text_secondary),
+                            );
+                        }
+                        if let Some(approval) = &state.approval {
+                            ui.label(
+                                RichText::new(tf(
+                                    "detail.approved",
+                                    &[
+                                        ("approver", &approval.approver),
+                                        ("at", &approval.approved_at),
+                                    ],
+                                ))
+                                .color(palette.fg_text_secondary),
+                            );
+                        }
+                        if let Some(elapsed) = state.overrun
+                            && matches!(state.status, StepStatus::Running)
+                        {
+                            ui.label(
+                                RichText::new(tf(
+                                    "detail.overrun",
+                                    &[("elapsed", &format_duration(elapsed))],
+                                ))
+                                .color(palette.accent_warning),
+                            );
+                        }
+                        if let Some(avg) = self.slow_step_average(step_id) {
+                            ui.label(
+                                RichText::new(tf("detail.slow", &[("avg", &format_duration(avg))]))
+                                    .color(palette.accent_error),
+                            );
+                        }
                     });
                     ui.add_space(10.0);
                     egui::Grid::new("step_detail_grid")
@@ -71,43 +401,734 @@ impl BatchOrchestratorApp {
                             ui.label("ID");
                             ui.label(format!(": {}", step.id));
                             ui.end_row();
-                            ui.label("병렬 허용");
+                            ui.label(t("detail.parallel"));
                             ui.label(format!(": {}", step.allow_parallel));
                             ui.end_row();
-                            ui.label("재시도");
-                            ui.label(format!(": {}회", step.retry));
+                            ui.label(t("detail.retry"));
+                            let retry = tf("detail.retry_value", &[("count", &step.retry)]);
+                            ui.label(match step.retry_on {
+                                _ if step.retry == 0 => retry,
+                                RetryOn::Transient => {
+                                    tf("detail.retry_transient", &[("value", &retry)])
+                                }
+                                RetryOn::Any => tf("detail.retry_any", &[("value", &retry)]),
+                            });
                             ui.end_row();
-                            ui.label("타임아웃");
-                            ui.label(format!(": {}초", step.timeout_sec));
+                            ui.label(t("detail.timeout"));
+                            let timeout =
+                                tf("detail.timeout_value", &[("secs", &step.timeout_sec)]);
+                            ui.label(match step.on_timeout {
+                                TimeoutPolicy::KillRetry => timeout,
+                                TimeoutPolicy::WarnContinue => {
+                                    tf("detail.timeout_warn_continue", &[("value", &timeout)])
+                                }
+                                TimeoutPolicy::Fail => {
+                                    tf("detail.timeout_fail", &[("value", &timeout)])
+                                }
+                            });
                             ui.end_row();
-                            ui.label("의존성");
+                            if let Some(stats) = self.step_stats.get(&step.id) {
+                                ui.label(t("detail.past_duration"));
+                                ui.label(tf(
+                                    "detail.duration_value",
+                                    &[
+                                        ("min", &format_duration(stats.min)),
+                                        ("avg", &format_duration(stats.avg)),
+                                        ("max", &format_duration(stats.max)),
+                                    ],
+                                ));
+                                ui.end_row();
+                                ui.label(t("detail.success_rate"));
+                                ui.label(tf(
+                                    "detail.success_rate_value",
+                                    &[
+                                        (
+                                            "percent",
+                                            &format!("{:.0}", stats.success_rate() * 100.0),
+                                        ),
+                                        ("successes", &stats.successes),
+                                        ("runs", &stats.runs),
+                                    ],
+                                ));
+                                ui.end_row();
+                            }
+                            if let Some(window) = &step.time_window {
+                                ui.label(t("detail.time_window"));
+                                ui.label(format!(
+                                    ": {} ~ {} ({})",
+                                    window.not_before.as_deref().unwrap_or("-"),
+                                    window.not_after.as_deref().unwrap_or("-"),
+                                    match window.policy {
+                                        TimeWindowPolicy::Wait => t("detail.window_wait"),
+                                        TimeWindowPolicy::Fail => t("detail.window_fail"),
+                                    }
+                                ));
+                                ui.end_row();
+                            }
+                            if let Some(skip_if) = &step.skip_if {
+                                let mut conditions = Vec::new();
+                                if let Some(path) = &skip_if.file_exists {
+                                    conditions.push(tf("detail.skip_if_file", &[("path", path)]));
+                                }
+                                if skip_if.sql_returns_rows.is_some() {
+                                    conditions.push(t("detail.skip_if_sql").to_string());
+                                }
+                                ui.label(t("detail.skip_if"));
+                                ui.label(format!(": {}", conditions.join(" & ")));
+                                ui.end_row();
+                            }
+                            ui.label(t("detail.depends_on"));
                             let deps = if step.depends_on.is_empty() {
-                                "없음".to_string()
+                                t("common.none").to_string()
                             } else {
                                 step.depends_on.join(", ")
                             };
                             ui.label(format!(": {}", deps));
                             ui.end_row();
                         });
+                    if !step.expects_outputs.is_empty() {
+                        ui.add_space(10.0);
+                        self.render_expected_outputs(ui, step, &state.outputs);
+                    }
+                    if let Some((_, fields)) = self
+                        .step_preview
+                        .as_ref()
+                        .filter(|(id, fields)| id == step_id && !fields.is_empty())
+                    {
+                        ui.add_space(10.0);
+                        self.render_step_preview(ui, fields);
+                    }
+                    if let Some(EngineError::DbError { code, message }) = &state.error {
+                        ui.add_space(10.0);
+                        self.render_db_error(ui, code.as_deref(), message);
+                    }
+                    if !state.set_vars.is_empty() {
+                        ui.add_space(10.0);
+                        self.render_step_set_vars(ui, &state.set_vars);
+                    }
+                    if let Some(snapshot) = &state.context_snapshot {
+                        ui.add_space(10.0);
+                        self.render_context_snapshot(ui, snapshot);
+                    }
+                    if !state.diagnostics.is_empty() {
+                        ui.add_space(10.0);
+                        self.render_failure_diagnostics(ui, &state.diagnostics);
+                    }
+                    let view = StepDetailView {
+                        step,
+                        state: &state,
+                        palette: &palette,
+                    };
+                    for (idx, panel) in self.step_panels.for_step(step).iter().enumerate() {
+                        ui.add_space(10.0);
+                        egui::CollapsingHeader::new(RichText::new(panel.title()).strong())
+                            .id_source(("step_detail_panel", idx))
+                            .default_open(true)
+                            .show(ui, |ui| panel.ui(ui, &view));
+                    }
                 }
             }
         } else {
-            ui.label(RichText::new("선택된 Step이 없습니다.").color(palette.fg_text_secondary));
+            ui.label(RichText::new(t("detail.none_selected")).color(palette.fg_text_secondary));
         }
     }
 
-    /// 로그 영역을 렌더링한다.
-    pub(super) fn render_log_panel(&self, ui: &mut egui::Ui) {
-        solid_section_header(ui, &self.theme, "📝", "로그");
+    /// DB 벤더 오류 코드와 메시지를 강조해 보여준다.
+    fn render_db_error(&self, ui: &mut egui::Ui, code: Option<&str>, message: &str) {
+        let palette = *self.theme.palette();
+        egui::Frame::none()
+            .fill(blend_color(palette.accent_error, palette.bg_panel, 0.9))
+            .stroke(egui::Stroke::new(1.0, palette.accent_error))
+            .inner_margin(egui::Margin::symmetric(12.0, 10.0))
+            .show(ui, |ui| {
+                ui.set_width(ui.available_width());
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(tf(
+                            "detail.db_error",
+                            &[("code", &code.unwrap_or(t("detail.no_code")))],
+                        ))
+                        .size(17.0)
+                        .color(palette.accent_error)
+                        .strong(),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if let Some(url) = code.and_then(|code| self.error_kb_url(code)) {
+                            ui.hyperlink_to(t("detail.knowledge_base"), url);
+                        }
+                        if ui.button(t("common.copy")).clicked() {
+                            let text = match code {
+                                Some(code) => format!("{code}: {message}"),
+                                None => message.to_string(),
+                            };
+                            ui.output_mut(|o| o.copied_text = text);
+                        }
+                    });
+                });
+                ui.label(RichText::new(message).color(palette.fg_text_primary));
+            });
+    }
+
+    /// Step 유형별 구성을 치환된 값으로 보여준다.
+    ///
+    /// SQL·스크립트·명령줄은 고정폭으로, 로컬 경로는 존재 여부 표시와 함께 그린다.
+    fn render_step_preview(&self, ui: &mut egui::Ui, fields: &[PreviewField]) {
+        let palette = *self.theme.palette();
+        egui::CollapsingHeader::new(
+            RichText::new(t("detail.preview"))
+                .color(palette.fg_text_primary)
+                .strong(),
+        )
+        .default_open(true)
+        .show(ui, |ui| {
+            for field in fields {
+                ui.label(RichText::new(t(field.label)).color(palette.fg_text_secondary));
+                match &field.value {
+                    PreviewValue::Text(text) => {
+                        ui.label(RichText::new(text).color(palette.fg_text_primary));
+                    }
+                    PreviewValue::Code(code) => {
+                        egui::Frame::none()
+                            .fill(palette.bg_log)
+                            .stroke(egui::Stroke::new(1.0, palette.border_soft))
+                            .inner_margin(egui::Margin::symmetric(8.0, 6.0))
+                            .show(ui, |ui| {
+                                ui.set_width(ui.available_width());
+                                ui.label(RichText::new(code).monospace());
+                            });
+                    }
+                    PreviewValue::Path { path, exists } => {
+                        ui.horizontal(|ui| {
+                            match exists {
+                                Some(true) => {
+                                    ui.label(RichText::new("✅").color(palette.accent_success))
+                                        .on_hover_text(t("preview.exists"));
+                                }
+                                Some(false) => {
+                                    ui.label(RichText::new("❌").color(palette.accent_error))
+                                        .on_hover_text(t("preview.missing"));
+                                }
+                                None => {}
+                            }
+                            ui.label(RichText::new(path).monospace());
+                        });
+                    }
+                }
+                ui.add_space(4.0);
+            }
+        });
+    }
+
+    /// Step의 기대 산출물을 보여준다. 확인 전이면 선언한 경로를, 확인 뒤면 항목별 ✓/✗와 크기를 표시한다.
+    fn render_expected_outputs(&self, ui: &mut egui::Ui, step: &Step, outputs: &[ExpectedOutput]) {
+        let palette = *self.theme.palette();
+        egui::CollapsingHeader::new(
+            RichText::new(tf(
+                "detail.outputs",
+                &[("count", &step.expects_outputs.len())],
+            ))
+            .color(palette.fg_text_primary)
+            .strong(),
+        )
+        .default_open(true)
+        .show(ui, |ui| {
+            if outputs.is_empty() {
+                for path in &step.expects_outputs {
+                    ui.label(
+                        RichText::new(format!("⋯ {path}"))
+                            .monospace()
+                            .color(palette.fg_text_secondary),
+                    );
+                }
+                return;
+            }
+            for output in outputs {
+                let (text, color) = match output.size {
+                    Some(size) if size > 0 => (
+                        format!("✓ {} ({size} B)", output.path),
+                        palette.accent_success,
+                    ),
+                    Some(_) => (
+                        tf("detail.output_empty", &[("path", &output.path)]),
+                        palette.accent_error,
+                    ),
+                    None => (
+                        tf("detail.output_missing", &[("path", &output.path)]),
+                        palette.accent_error,
+                    ),
+                };
+                ui.label(RichText::new(text).monospace().color(color));
+            }
+        });
+    }
+
+    /// Step이 실행 중에 설정한 변수를 설정한 순서대로 이름·값·시각 표로 보여준다.
+    fn render_step_set_vars(&self, ui: &mut egui::Ui, vars: &[VarChange]) {
+        let palette = *self.theme.palette();
+        egui::CollapsingHeader::new(
+            RichText::new(tf("detail.set_vars", &[("count", &vars.len())]))
+                .color(palette.fg_text_primary)
+                .strong(),
+        )
+        .default_open(true)
+        .show(ui, |ui| {
+            egui::Grid::new("step_set_vars_grid")
+                .num_columns(3)
+                .spacing([12.0, 6.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for change in vars {
+                        ui.label(
+                            RichText::new(&change.name)
+                                .monospace()
+                                .color(palette.accent_primary),
+                        );
+                        ui.label(RichText::new(&change.value).monospace());
+                        ui.label(RichText::new(&change.at).color(palette.fg_text_secondary));
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    /// 실패 시점에 캡처한 컨텍스트 변수를 표로 보여준다.
+    fn render_context_snapshot(&self, ui: &mut egui::Ui, snapshot: &[(String, String)]) {
+        let palette = *self.theme.palette();
+        egui::CollapsingHeader::new(
+            RichText::new(tf("detail.context", &[("count", &snapshot.len())]))
+                .color(palette.fg_text_primary)
+                .strong(),
+        )
+        .default_open(true)
+        .show(ui, |ui| {
+            if snapshot.is_empty() {
+                ui.label(RichText::new(t("detail.context_empty")).color(palette.fg_text_secondary));
+                return;
+            }
+            if ui.button(t("common.copy")).clicked() {
+                let text = snapshot
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                ui.output_mut(|o| o.copied_text = text);
+            }
+            egui::Grid::new("step_context_snapshot_grid")
+                .num_columns(2)
+                .spacing([12.0, 6.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for (key, value) in snapshot {
+                        ui.label(RichText::new(key).monospace().color(palette.accent_primary));
+                        ui.label(RichText::new(value).monospace());
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    /// 실패 직후 실행한 진단 명령의 출력을 항목별로 보여준다.
+    fn render_failure_diagnostics(&self, ui: &mut egui::Ui, results: &[DiagnosticResult]) {
+        let palette = *self.theme.palette();
+        egui::CollapsingHeader::new(
+            RichText::new(tf("detail.diagnostics", &[("count", &results.len())]))
+                .color(palette.fg_text_primary)
+                .strong(),
+        )
+        .default_open(true)
+        .show(ui, |ui| {
+            if ui.button(t("common.copy")).clicked() {
+                let text = results
+                    .iter()
+                    .map(|result| {
+                        let body = result
+                            .error
+                            .as_deref()
+                            .or(result.output.as_deref())
+                            .unwrap_or_default();
+                        format!("## {}\n{body}", result.label)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n");
+                ui.output_mut(|o| o.copied_text = text);
+            }
+            for result in results {
+                ui.label(
+                    RichText::new(&result.label)
+                        .color(palette.accent_primary)
+                        .strong(),
+                );
+                if let Some(error) = &result.error {
+                    ui.label(
+                        RichText::new(tf("detail.diagnostic_failed", &[("error", error)]))
+                            .color(palette.accent_error),
+                    );
+                    continue;
+                }
+                match &result.output {
+                    Some(output) => {
+                        egui::Frame::none()
+                            .fill(palette.bg_log)
+                            .stroke(egui::Stroke::new(1.0, palette.border_soft))
+                            .inner_margin(egui::Margin::symmetric(8.0, 6.0))
+                            .show(ui, |ui| {
+                                ui.set_width(ui.available_width());
+                                ui.label(RichText::new(output).monospace());
+                            });
+                    }
+                    None => {
+                        ui.label(
+                            RichText::new(t("detail.diagnostic_empty"))
+                                .color(palette.fg_text_secondary),
+                        );
+                    }
+                }
+                ui.add_space(4.0);
+            }
+        });
+    }
+
+    /// 로그 영역을 렌더링한다. 필터 검색어가 있으면 검색어가 들어간 줄만 보여 준다.
+    pub(super) fn render_log_panel(&mut self, ui: &mut egui::Ui) {
+        solid_section_header(ui, &self.theme, "📝", t("panel.logs"));
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.log_filter)
+                    .hint_text(t("panel.log_filter_hint"))
+                    .desired_width(240.0),
+            );
+            if !self.log_filter.is_empty() && ui.small_button("✖").clicked() {
+                self.log_filter.clear();
+            }
+        });
         ui.add_space(8.0);
+        let needle = self.log_filter.trim().to_lowercase();
         egui::ScrollArea::vertical()
             .stick_to_bottom(true)
             .show(ui, |ui| {
                 ui.spacing_mut().item_spacing.y = 6.0;
                 let text_color = self.theme.palette().fg_text_secondary;
-                for line in self.selected_logs() {
+                for line in self.selected_logs(&needle) {
                     ui.label(RichText::new(line).color(text_color));
                 }
             });
     }
+
+    /// 실패한 실행의 근본 원인 Step, 연쇄로 건너뛴 Step, 최근 로그와 조치 버튼을 표시한다.
+    pub(super) fn render_failure_summary(&mut self, ui: &mut egui::Ui, summary: &FailureSummary) {
+        let palette = *self.theme.palette();
+        ui.set_width(ui.available_width());
+        ui.label(
+            RichText::new(t("failure.title"))
+                .size(17.0)
+                .color(palette.accent_error)
+                .strong(),
+        );
+        ui.add_space(6.0);
+        ui.label(
+            RichText::new(tf(
+                "failure.root_cause",
+                &[
+                    ("name", &summary.root_step_name),
+                    ("id", &summary.root_step_id),
+                ],
+            ))
+            .color(palette.fg_text_primary)
+            .strong(),
+        );
+        if !summary.message.is_empty() {
+            ui.label(RichText::new(&summary.message).color(palette.accent_error));
+        }
+        let chain = if summary.blocked_chain.is_empty() {
+            t("common.none").to_string()
+        } else {
+            summary.blocked_chain.join(" → ")
+        };
+        ui.label(
+            RichText::new(tf("failure.blocked_chain", &[("chain", &chain)]))
+                .color(palette.fg_text_secondary),
+        );
+        egui::CollapsingHeader::new(tf(
+            "failure.recent_logs",
+            &[("count", &summary.last_logs.len())],
+        ))
+        .id_source("failure_summary_logs")
+        .default_open(true)
+        .show(ui, |ui| {
+            for line in &summary.last_logs {
+                ui.label(
+                    RichText::new(line)
+                        .monospace()
+                        .color(palette.fg_text_secondary),
+                );
+            }
+        });
+        ui.horizontal(|ui| {
+            if ui.button(t("failure.retry")).clicked() {
+                self.retry_from_failure();
+            }
+            if ui.button(t("failure.open_logs")).clicked() {
+                self.selected_step = Some(summary.root_step_id.clone());
+            }
+        });
+    }
+
+    /// 결과 요약 조회 결과를 항목별로 보여 준다. 복사 버튼은 시나리오 이름과 조회 시각을 붙인 텍스트
+    /// 보고서를 클립보드에 넣는다.
+    pub(super) fn render_run_summary(&mut self, ui: &mut egui::Ui, report: &RunSummaryReport) {
+        let palette = *self.theme.palette();
+        ui.set_width(ui.available_width());
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(t("summary.title"))
+                    .size(17.0)
+                    .color(palette.accent_primary)
+                    .strong(),
+            );
+            ui.label(
+                RichText::new(tf("summary.received_at", &[("time", &report.received_at)]))
+                    .color(palette.fg_text_secondary),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(t("common.copy")).clicked() {
+                    let name = self
+                        .scenario
+                        .as_ref()
+                        .map(|scenario| scenario.name.as_str())
+                        .unwrap_or_default();
+                    let mut text = tf(
+                        "summary.report_header",
+                        &[("name", &name), ("time", &report.received_at)],
+                    );
+                    for result in &report.results {
+                        text.push_str(&format!(
+                            "\n{}: {}",
+                            result.label,
+                            summary_value_text(result)
+                        ));
+                    }
+                    ui.output_mut(|o| o.copied_text = text);
+                }
+            });
+        });
+        ui.add_space(6.0);
+        egui::Grid::new("run_summary_grid")
+            .num_columns(2)
+            .spacing([16.0, 6.0])
+            .show(ui, |ui| {
+                for result in &report.results {
+                    ui.label(RichText::new(&result.label).color(palette.fg_text_primary));
+                    let color = if result.error.is_some() {
+                        palette.accent_error
+                    } else {
+                        palette.fg_text_primary
+                    };
+                    ui.label(
+                        RichText::new(summary_value_text(result))
+                            .monospace()
+                            .color(color)
+                            .strong(),
+                    );
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 실행 중 DB 키별 연결 풀 사용 현황을 표로 보여 준다. 대기 중인 요청이 있으면 경고색으로
+    /// 표시한다.
+    pub(super) fn render_db_pool_panel(&self, ui: &mut egui::Ui) {
+        let palette = *self.theme.palette();
+        ui.set_width(ui.available_width());
+        ui.label(
+            RichText::new(t("pool.title"))
+                .size(17.0)
+                .color(palette.accent_primary)
+                .strong(),
+        );
+        ui.add_space(6.0);
+        egui::Grid::new("db_pool_grid")
+            .num_columns(5)
+            .spacing([16.0, 6.0])
+            .show(ui, |ui| {
+                for key in [
+                    "pool.db_key",
+                    "pool.in_use",
+                    "pool.idle",
+                    "pool.waiting",
+                    "pool.avg_checkout",
+                ] {
+                    ui.label(RichText::new(t(key)).color(palette.fg_text_secondary));
+                }
+                ui.end_row();
+                for usage in &self.db_pool_usage {
+                    let stats = &usage.stats;
+                    ui.label(RichText::new(&usage.db_key).color(palette.fg_text_primary));
+                    ui.label(
+                        RichText::new(format!("{} / {}", stats.in_use, stats.max_size))
+                            .monospace()
+                            .strong(),
+                    );
+                    ui.label(RichText::new(stats.idle.to_string()).monospace());
+                    let waiting_color = if stats.waiting > 0 {
+                        palette.accent_warning
+                    } else {
+                        palette.fg_text_primary
+                    };
+                    ui.label(
+                        RichText::new(stats.waiting.to_string())
+                            .monospace()
+                            .color(waiting_color),
+                    );
+                    let avg = stats.avg_checkout.map_or_else(
+                        || "-".to_string(),
+                        |avg| format!("{:.1} ms", avg.as_secs_f64() * 1000.0),
+                    );
+                    ui.label(RichText::new(avg).monospace());
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 앱이 비정상 종료되어 끝나지 못한 이전 실행을 알리고 복원 여부를 묻는다.
+    pub(super) fn render_interrupted_run(&mut self, ui: &mut egui::Ui, run: &InterruptedRun) {
+        let palette = *self.theme.palette();
+        ui.set_width(ui.available_width());
+        ui.label(
+            RichText::new(t("journal.title"))
+                .size(17.0)
+                .color(palette.accent_warning)
+                .strong(),
+        );
+        ui.add_space(6.0);
+        ui.label(
+            RichText::new(tf(
+                "journal.summary",
+                &[
+                    ("name", &run.scenario),
+                    ("started_at", &run.started_at),
+                    ("count", &run.succeeded_count()),
+                ],
+            ))
+            .color(palette.fg_text_primary),
+        );
+        ui.label(
+            RichText::new(run.source.display().to_string())
+                .monospace()
+                .color(palette.fg_text_secondary),
+        );
+        ui.label(RichText::new(t("journal.message")).color(palette.fg_text_secondary));
+        ui.horizontal(|ui| {
+            let response = ui.add_enabled(
+                !self.scenario_running,
+                egui::Button::new(t("journal.restore")),
+            );
+            if response.clicked() {
+                self.restore_interrupted_run();
+            }
+            if ui.button(t("journal.dismiss")).clicked() {
+                self.dismiss_interrupted_run();
+            }
+        });
+    }
+
+    /// 빌더 하단에 SQL 검사 결과와 비용 점검 경고를 표시한다.
+    pub(super) fn render_sql_check_panel(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.theme.palette();
+        let mut close = false;
+        ui.horizontal(|ui| {
+            solid_section_header(ui, &self.theme, "🔍", t("toolbar.check_sql"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(t("common.close")).clicked() {
+                    close = true;
+                }
+            });
+        });
+        ui.add_space(6.0);
+        let issues = self.sql_check_report.as_deref().unwrap_or_default();
+        if issues.is_empty() {
+            ui.label(RichText::new(t("sql_check.all_passed")).color(palette.accent_success));
+        } else {
+            egui::ScrollArea::vertical()
+                .max_height(180.0)
+                .show(ui, |ui| {
+                    for issue in issues {
+                        let (icon, color) = match issue.level {
+                            SqlIssueLevel::Error => ("❌", palette.accent_error),
+                            SqlIssueLevel::Warning => ("⚠", palette.accent_warning),
+                        };
+                        ui.label(RichText::new(format!("{icon} {issue}")).color(color));
+                    }
+                });
+        }
+        let mut acknowledge = None;
+        if !self.cost_check_report.is_empty() {
+            ui.add_space(8.0);
+            ui.label(RichText::new(t("cost_check.title")).strong());
+            ui.label(RichText::new(t("cost_check.hint")).color(palette.fg_text_secondary));
+            egui::ScrollArea::vertical()
+                .id_source("cost_check_scroll")
+                .max_height(140.0)
+                .show(ui, |ui| {
+                    for (index, warning) in self.cost_check_report.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            if warning.acknowledged {
+                                ui.label(
+                                    RichText::new(format!("✔ {warning}"))
+                                        .color(palette.fg_text_secondary),
+                                );
+                                if ui.small_button(t("cost_check.unacknowledge")).clicked() {
+                                    acknowledge = Some((index, false));
+                                }
+                            } else {
+                                ui.label(
+                                    RichText::new(format!("💸 {warning}"))
+                                        .color(palette.accent_warning),
+                                );
+                                if ui.small_button(t("cost_check.acknowledge")).clicked() {
+                                    acknowledge = Some((index, true));
+                                }
+                            }
+                        });
+                    }
+                });
+        }
+        if let Some((index, acknowledged)) = acknowledge {
+            self.acknowledge_cost_warning(index, acknowledged);
+        }
+        if close {
+            self.sql_check_report = None;
+            self.cost_check_report.clear();
+        }
+    }
+}
+
+/// 결과 요약 항목 값을 표시용 문자열로 만든다. 정수 값은 세 자리마다 쉼표를 넣는다.
+fn summary_value_text(result: &SummaryResult) -> String {
+    if let Some(error) = &result.error {
+        return tf("summary.failed", &[("error", error)]);
+    }
+    match &result.value {
+        Some(value) => group_digits(value),
+        None => t("summary.no_rows").to_string(),
+    }
+}
+
+/// 정수 문자열에 천 단위 구분 쉼표를 넣는다. 정수가 아니면 그대로 반환한다.
+fn group_digits(value: &str) -> String {
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", value),
+    };
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return value.to_string();
+    }
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (idx, ch) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    format!("{sign}{grouped}")
 }
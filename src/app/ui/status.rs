@@ -3,9 +3,38 @@ use super::*;
 /// StepStatus를 기반으로 직관적인 아이콘과 텍스트를 반환한다.
 pub(super) fn status_indicator(status: &StepStatus) -> (&'static str, &'static str) {
     match status {
-        StepStatus::Pending => ("⏳", "대기 중"),
-        StepStatus::Running => ("⚙️", "실행 중"),
-        StepStatus::Success => ("✅", "성공"),
-        StepStatus::Failed(_) => ("❌", "실패"),
+        StepStatus::Pending => ("⏳", t("status.pending")),
+        StepStatus::Waiting(_) => ("🕐", t("status.waiting")),
+        StepStatus::Running => ("⚙️", t("status.running")),
+        StepStatus::Retrying { .. } => ("🔁", t("status.retrying")),
+        StepStatus::Success => ("✅", t("status.success")),
+        StepStatus::Failed(_) => ("❌", t("status.failed")),
+        StepStatus::Blocked => ("⛔", t("status.blocked")),
+        StepStatus::AwaitingApproval => ("✋", t("status.awaiting_approval")),
     }
 }
+
+/// 재시도 중인 Step의 진행 문구(`재시도 2/5 · 4초 후`)를 만든다. 재시도 중이 아니면 `None`이다.
+pub(super) fn retry_progress(status: &StepStatus) -> Option<String> {
+    let StepStatus::Retrying {
+        attempt,
+        max,
+        resume_at,
+    } = status
+    else {
+        return None;
+    };
+    let remaining = resume_at.saturating_duration_since(std::time::Instant::now());
+    let phase = if remaining.is_zero() {
+        t("status.running").to_string()
+    } else {
+        tf(
+            "status.retry_in",
+            &[("secs", &(remaining.as_secs_f32().ceil() as u64))],
+        )
+    };
+    Some(tf(
+        "status.retry_progress",
+        &[("attempt", &attempt), ("max", &max), ("phase", &phase)],
+    ))
+}
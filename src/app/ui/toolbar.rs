@@ -14,15 +14,31 @@ impl BatchOrchestratorApp {
             );
             if let Some(path) = &self.scenario_path {
                 ui.label(
-                    RichText::new(format!("로드됨 · {}", path.display()))
-                        .color(palette.fg_text_secondary),
+                    RichText::new(tf(
+                        "toolbar.loaded",
+                        &[("lock", &lock_prefix(path)), ("path", &path.display())],
+                    ))
+                    .color(palette.fg_text_secondary),
                 );
             } else {
                 ui.label(
-                    RichText::new("시나리오 파일을 선택해 시작하세요.")
-                        .color(palette.fg_text_secondary),
+                    RichText::new(t("toolbar.pick_scenario")).color(palette.fg_text_secondary),
                 );
             }
+            if let Some(daemon) = &self.daemon {
+                let (text, color) = if self.daemon_connected {
+                    (
+                        tf("toolbar.daemon_connected", &[("addr", &daemon.addr())]),
+                        palette.fg_text_secondary,
+                    )
+                } else {
+                    (
+                        tf("toolbar.daemon_waiting", &[("addr", &daemon.addr())]),
+                        palette.accent_error,
+                    )
+                };
+                ui.label(RichText::new(text).color(color));
+            }
             if let Some(err) = &self.last_error {
                 ui.label(RichText::new(err).color(palette.accent_error).strong());
                 ui.add_space(10.0);
@@ -32,15 +48,22 @@ impl BatchOrchestratorApp {
                 ui.spacing_mut().item_spacing.x = decorations.button_gap;
 
                 if ui
-                    .add(PrimaryButton::new(&self.theme, "열기").icon("📂"))
+                    .add(PrimaryButton::new(&self.theme, t("toolbar.open")).icon("📂"))
                     .clicked()
                 {
                     self.load_scenario_from_dialog();
                 }
 
-                let can_run = self.scenario.is_some() && !self.scenario_running;
+                self.render_profile_picker(ui);
+
+                let daemon_ready = self.daemon.is_none() || self.daemon_connected;
+                let can_run = self.scenario.is_some() && !self.scenario_running && daemon_ready;
                 if ui
-                    .add_enabled(can_run, PrimaryButton::new(&self.theme, "실행").icon("▶"))
+                    .add_enabled(
+                        can_run,
+                        PrimaryButton::new(&self.theme, t("toolbar.run")).icon("▶"),
+                    )
+                    .on_hover_text("F5")
                     .clicked()
                 {
                     self.start_scenario();
@@ -48,15 +71,168 @@ impl BatchOrchestratorApp {
 
                 let can_stop = self.scenario_running;
                 if ui
-                    .add_enabled(can_stop, PrimaryButton::new(&self.theme, "정지").icon("⏹"))
+                    .add_enabled(
+                        can_stop,
+                        PrimaryButton::new(&self.theme, t("toolbar.stop")).icon("⏹"),
+                    )
+                    .on_hover_text("Shift+F5")
                     .clicked()
                 {
                     self.stop_scenario();
                 }
+
+                ui.add_enabled(
+                    !self.scenario_running,
+                    egui::Checkbox::new(&mut self.rehearsal_mode, t("toolbar.rehearsal")),
+                )
+                .on_hover_text(t("toolbar.rehearsal_hint"));
+
+                ui.add_enabled_ui(!self.scenario_running, |ui| {
+                    egui::ComboBox::from_id_source("confirm_mode")
+                        .selected_text(confirm_mode_label(self.confirm_mode))
+                        .show_ui(ui, |ui| {
+                            for mode in ConfirmMode::ALL {
+                                ui.selectable_value(
+                                    &mut self.confirm_mode,
+                                    mode,
+                                    confirm_mode_label(mode),
+                                );
+                            }
+                        })
+                        .response
+                        .on_hover_text(t("toolbar.confirm_mode_hint"));
+                });
+
+                let mut context_in = self.context_in.is_some();
+                let hint = match &self.context_in {
+                    Some(path) => path.display().to_string(),
+                    None => t("toolbar.context_in_hint").into(),
+                };
+                if ui
+                    .add_enabled(
+                        !self.scenario_running,
+                        egui::Checkbox::new(&mut context_in, t("toolbar.context_in")),
+                    )
+                    .on_hover_text(hint)
+                    .changed()
+                {
+                    self.toggle_context_in(context_in);
+                }
+
+                let mut context_out = self.context_out.is_some();
+                let hint = match &self.context_out {
+                    Some(path) => path.display().to_string(),
+                    None => t("toolbar.context_out_hint").into(),
+                };
+                if ui
+                    .add_enabled(
+                        !self.scenario_running && self.daemon.is_none(),
+                        egui::Checkbox::new(&mut context_out, t("toolbar.context_out")),
+                    )
+                    .on_hover_text(hint)
+                    .changed()
+                {
+                    self.toggle_context_out(context_out);
+                }
+
+                if ui
+                    .add_enabled(
+                        self.scenario.is_some(),
+                        egui::Button::new(t("toolbar.expanded_yaml")),
+                    )
+                    .on_hover_text(t("toolbar.expanded_yaml_hint"))
+                    .clicked()
+                {
+                    self.open_expanded_yaml();
+                }
+                ui.add_enabled(
+                    !self.scenario_running,
+                    egui::Checkbox::new(
+                        &mut self.expanded_yaml_in_report,
+                        t("toolbar.expanded_yaml_attach"),
+                    ),
+                )
+                .on_hover_text(t("toolbar.expanded_yaml_attach_hint"));
+            });
+            self.render_param_violations(ui);
+            ui.horizontal(|ui| {
+                let editable = !self.scenario_running;
+                ui.label(RichText::new(t("toolbar.run_note")).color(palette.fg_text_secondary));
+                ui.add_enabled(
+                    editable,
+                    egui::TextEdit::singleline(&mut self.run_note_draft)
+                        .hint_text(t("toolbar.run_note_hint"))
+                        .desired_width(320.0),
+                );
+                ui.label(RichText::new(t("toolbar.run_tags")).color(palette.fg_text_secondary));
+                ui.add_enabled(
+                    editable,
+                    egui::TextEdit::singleline(&mut self.run_tags_draft)
+                        .hint_text(t("toolbar.run_tags_hint"))
+                        .desired_width(180.0),
+                );
             });
         });
     }
 
+    /// 시나리오에 실행 프로필이 있으면 실행할 프로필을 고르는 콤보박스를 그린다.
+    ///
+    /// 승인이 필요한 프로필에는 🔒를 붙인다.
+    fn render_profile_picker(&mut self, ui: &mut egui::Ui) {
+        let Some(scenario) = &self.scenario else {
+            return;
+        };
+        if scenario.profiles.is_empty() {
+            return;
+        }
+        let label = |name: &str, requires_approval: bool| {
+            if requires_approval {
+                format!("🔒 {name}")
+            } else {
+                name.to_string()
+            }
+        };
+        let selected_text = match self.selected_profile_config() {
+            Some((name, profile)) => label(name, profile.requires_approval),
+            None => t("toolbar.profile_none").to_string(),
+        };
+        let mut selected = self.selected_profile.clone();
+        ui.add_enabled_ui(!self.scenario_running, |ui| {
+            egui::ComboBox::from_id_source("run_profile")
+                .selected_text(tf("toolbar.profile", &[("name", &selected_text)]))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut selected, None, t("toolbar.profile_none"));
+                    for (name, profile) in &scenario.profiles {
+                        ui.selectable_value(
+                            &mut selected,
+                            Some(name.clone()),
+                            label(name, profile.requires_approval),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text(t("toolbar.profile_hint"));
+        });
+        if selected != self.selected_profile {
+            // 빌더가 같은 파일을 열고 있으면 다음 저장 때 선택이 `metadata`에 남는다.
+            if self.editor_state.current_file.is_some()
+                && self.editor_state.current_file == self.scenario_path
+            {
+                self.editor_state.selected_profile = selected.clone();
+            }
+            self.selected_profile = selected;
+            self.refresh_param_violations();
+        }
+    }
+
+    /// 실행 파라미터가 `param_rules`를 어긴 항목을 프로필 선택 아래에 한 줄씩 표시한다.
+    fn render_param_violations(&self, ui: &mut egui::Ui) {
+        let palette = self.theme.palette();
+        for violation in &self.param_violations {
+            ui.label(RichText::new(format!("⚠ {violation}")).color(palette.accent_error));
+        }
+    }
+
     /// 시나리오 빌더 전용 툴바를 렌더링한다.
     pub(super) fn render_builder_toolbar(&mut self, ui: &mut egui::Ui) {
         let palette = *self.theme.palette();
@@ -69,53 +245,85 @@ impl BatchOrchestratorApp {
             );
             if let Some(path) = &self.editor_state.current_file {
                 let dirty = if self.editor_state.dirty {
-                    " (수정됨)"
+                    t("toolbar.modified_suffix")
                 } else {
                     ""
                 };
                 ui.label(
-                    RichText::new(format!("파일 · {}{}", path.display(), dirty))
-                        .color(palette.fg_text_secondary),
+                    RichText::new(tf(
+                        "toolbar.file",
+                        &[
+                            ("lock", &lock_prefix(path)),
+                            ("path", &path.display()),
+                            ("dirty", &dirty),
+                        ],
+                    ))
+                    .color(palette.fg_text_secondary),
                 );
             } else {
                 let dirty = if self.editor_state.dirty {
-                    " · 수정됨"
+                    t("toolbar.modified_tag")
                 } else {
                     ""
                 };
                 ui.label(
-                    RichText::new(format!("새 시나리오{}", dirty)).color(palette.fg_text_secondary),
+                    RichText::new(tf("toolbar.new_scenario", &[("dirty", &dirty)]))
+                        .color(palette.fg_text_secondary),
                 );
             }
             if let Some(err) = &self.editor_error {
                 ui.label(RichText::new(err).color(palette.accent_error).strong());
+            } else if let Some(notice) = &self.editor_notice {
+                ui.label(RichText::new(notice).color(palette.accent_success));
             }
             ui.add_space(8.0);
             ui.horizontal(|ui| {
                 if ui
-                    .add(PrimaryButton::new(&self.theme, "새 시나리오").icon("🆕"))
+                    .add(PrimaryButton::new(&self.theme, t("toolbar.new")).icon("🆕"))
                     .clicked()
                 {
                     self.editor_new_document();
                 }
                 if ui
-                    .add(PrimaryButton::new(&self.theme, "열기...").icon("📂"))
+                    .add(PrimaryButton::new(&self.theme, t("toolbar.open_ellipsis")).icon("📂"))
                     .clicked()
                 {
                     self.editor_open_dialog();
                 }
                 if ui
-                    .add(PrimaryButton::new(&self.theme, "저장").icon("💾"))
+                    .add(PrimaryButton::new(&self.theme, t("toolbar.import")).icon("📥"))
+                    .on_hover_text(t("toolbar.import_hint"))
+                    .clicked()
+                {
+                    self.editor_import_dialog();
+                }
+                if ui
+                    .add(PrimaryButton::new(&self.theme, t("toolbar.save")).icon("💾"))
                     .clicked()
                 {
                     self.editor_save(false);
                 }
                 if ui
-                    .add(PrimaryButton::new(&self.theme, "다른 이름으로").icon("📝"))
+                    .add(PrimaryButton::new(&self.theme, t("toolbar.save_as")).icon("📝"))
                     .clicked()
                 {
                     self.editor_save(true);
                 }
+                if ui
+                    .add(PrimaryButton::new(&self.theme, t("toolbar.check_sql")).icon("🔍"))
+                    .clicked()
+                {
+                    self.editor_check_sql();
+                }
+                if ui
+                    .add_enabled(
+                        self.db_reload_rx.is_none(),
+                        PrimaryButton::new(&self.theme, t("toolbar.reload_db")).icon("🔌"),
+                    )
+                    .clicked()
+                {
+                    self.editor_reload_db();
+                }
                 // 시나리오 빌더에 실행 제거
                 // if ui
                 //     .add(PrimaryButton::new(&self.theme, "실행").icon("🚀"))
@@ -127,3 +335,17 @@ impl BatchOrchestratorApp {
         });
     }
 }
+
+/// 컨펌 응답 방식의 표시 이름을 반환한다.
+fn confirm_mode_label(mode: ConfirmMode) -> &'static str {
+    match mode {
+        ConfirmMode::Prompt => t("toolbar.confirm_prompt"),
+        ConfirmMode::Unattended => t("toolbar.confirm_unattended"),
+        ConfirmMode::Attended => t("toolbar.confirm_attended"),
+    }
+}
+
+/// 암호화된 시나리오 경로이면 자물쇠 아이콘 접두어를 반환한다.
+fn lock_prefix(path: &std::path::Path) -> &'static str {
+    if is_encrypted_path(path) { "🔒 " } else { "" }
+}
@@ -1,4 +1,4 @@
-use crate::theme::{Theme, blend_color};
+use crate::theme::{StepVisualStyle, Theme, blend_color};
 use eframe::egui::{self, RichText, Widget};
 
 /// StepCard는 좌측 Step 리스트에서 재사용할 수 있는 공통 카드 레이아웃을 제공한다.
@@ -6,10 +6,13 @@ pub(super) struct StepCard<'a> {
     theme: &'a Theme,
     name: &'a str,
     step_id: &'a str,
+    visual: Option<StepVisualStyle<'a>>,
     status_icon: &'a str,
     status_text: &'a str,
     status_color: egui::Color32,
     is_selected: bool,
+    is_focused: bool,
+    is_compact: bool,
     height: f32,
 }
 
@@ -21,10 +24,13 @@ impl<'a> StepCard<'a> {
             theme,
             name,
             step_id,
+            visual: None,
             status_icon: "",
             status_text: "",
             status_color: fallback_color,
             is_selected: false,
+            is_focused: false,
+            is_compact: false,
             height: 74.0,
         }
     }
@@ -37,12 +43,33 @@ impl<'a> StepCard<'a> {
         self
     }
 
+    /// Step 유형 아이콘과 라벨을 ID 앞에 표시한다.
+    pub(super) fn visual(mut self, visual: StepVisualStyle<'a>) -> Self {
+        self.visual = Some(visual);
+        self
+    }
+
     /// 현재 카드가 선택되었는지 여부를 지정한다.
     pub(super) fn selected(mut self, selected: bool) -> Self {
         self.is_selected = selected;
         self
     }
 
+    /// 키보드 포커스가 카드에 있는지 여부를 지정한다. 포커스된 카드에는 강조 윤곽선을 그린다.
+    pub(super) fn focused(mut self, focused: bool) -> Self {
+        self.is_focused = focused;
+        self
+    }
+
+    /// 한 줄짜리 낮은 카드로 그릴지 지정한다. 하는 일이 없는 표식 Step에 쓴다.
+    pub(super) fn compact(mut self, compact: bool) -> Self {
+        if compact {
+            self.is_compact = true;
+            self.height = 40.0;
+        }
+        self
+    }
+
     /// 카드 높이를 조정해 다양한 레이아웃 요구를 맞춘다.
     pub(super) fn height(mut self, height: f32) -> Self {
         self.height = height;
@@ -86,6 +113,13 @@ impl<'a> Widget for StepCard<'a> {
             fill,
             egui::Stroke::new(1.5, stroke_color),
         );
+        if self.is_focused {
+            ui.painter().rect_stroke(
+                rect.expand(3.0),
+                egui::Rounding::same(decorations.card_rounding + 3.0),
+                egui::Stroke::new(2.5, palette.accent_primary),
+            );
+        }
 
         // 좌측 상태 인디케이터
         let indicator =
@@ -112,22 +146,42 @@ impl<'a> Widget for StepCard<'a> {
         if !self.status_icon.is_empty() {
             content_ui.label(
                 RichText::new(self.status_icon)
-                    .size(26.0)
+                    .size(if self.is_compact { 18.0 } else { 26.0 })
                     .color(self.status_color),
             );
         }
 
-        content_ui.vertical(|ui| {
-            ui.label(
+        if self.is_compact {
+            if let Some(visual) = self.visual {
+                content_ui.label(RichText::new(visual.icon).color(visual.color));
+            }
+            content_ui.label(
                 RichText::new(self.name)
-                    .size(17.0)
-                    .color(palette.fg_text_primary)
-                    .strong(),
+                    .size(15.0)
+                    .color(palette.fg_text_primary),
             );
-            ui.label(
-                RichText::new(format!("ID: {}", self.step_id)).color(palette.fg_text_secondary),
-            );
-        });
+        } else {
+            content_ui.vertical(|ui| {
+                ui.label(
+                    RichText::new(self.name)
+                        .size(17.0)
+                        .color(palette.fg_text_primary)
+                        .strong(),
+                );
+                ui.horizontal(|ui| {
+                    if let Some(visual) = self.visual {
+                        ui.label(
+                            RichText::new(format!("{} {}", visual.icon, visual.label))
+                                .color(visual.color),
+                        );
+                    }
+                    ui.label(
+                        RichText::new(format!("ID: {}", self.step_id))
+                            .color(palette.fg_text_secondary),
+                    );
+                });
+            });
+        }
 
         content_ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
             if !self.status_text.is_empty() {
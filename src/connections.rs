@@ -0,0 +1,84 @@
+use crate::i18n::tf;
+use crate::scenario::{DbConnectionConfig, Scenario};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+/// 공유 연결 저장소 파일 경로를 지정하는 환경 변수 이름이다.
+pub const CONNECTIONS_FILE_ENV: &str = "RUST_AIRFLOW_CONNECTIONS_FILE";
+
+/// 환경 변수가 없을 때 사용하는 공유 연결 저장소 경로이다.
+pub const DEFAULT_CONNECTIONS_FILE: &str = "connections.yaml";
+
+/// 여러 시나리오가 `db_ref`로 참조하는 앱 공용 DB 연결 저장소이다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConnectionStore {
+    /// 이름별 연결 정의. 저장 시 이름 순으로 기록한다.
+    #[serde(default)]
+    pub connections: BTreeMap<String, DbConnectionConfig>,
+}
+
+impl ConnectionStore {
+    /// 시나리오 DB 맵의 `db_ref`를 저장소 정의로 치환한 맵을 반환한다.
+    ///
+    /// # 매개변수
+    /// - `db`: 시나리오의 DB 연결 맵.
+    ///
+    /// # 오류
+    /// 참조한 이름이 저장소에 없거나 저장소 항목이 다시 참조를 가지면 오류를 반환한다.
+    pub fn resolve(
+        &self,
+        db: &HashMap<String, DbConnectionConfig>,
+    ) -> anyhow::Result<HashMap<String, DbConnectionConfig>> {
+        db.iter()
+            .map(|(key, config)| {
+                let Some(name) = &config.db_ref else {
+                    return Ok((key.clone(), config.clone()));
+                };
+                let shared = self.connections.get(name).with_context(|| {
+                    tf(
+                        "connections.error.missing_shared",
+                        &[("key", key), ("name", name)],
+                    )
+                })?;
+                if shared.db_ref.is_some() {
+                    anyhow::bail!(tf("connections.error.nested_ref", &[("name", name)]));
+                }
+                Ok((key.clone(), shared.clone()))
+            })
+            .collect()
+    }
+
+    /// 시나리오의 모든 `db_ref`가 해석되는지 확인한다.
+    pub fn check_scenario(&self, scenario: &Scenario) -> anyhow::Result<()> {
+        self.resolve(&scenario.db).map(|_| ())
+    }
+}
+
+/// 공유 연결 저장소 파일 경로를 반환한다.
+pub fn connections_path() -> PathBuf {
+    std::env::var(CONNECTIONS_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_CONNECTIONS_FILE))
+}
+
+/// 공유 연결 저장소를 읽는다. 파일이 없으면 빈 저장소를 반환한다.
+pub fn load_connection_store() -> anyhow::Result<ConnectionStore> {
+    let path = connections_path();
+    if !path.exists() {
+        return Ok(ConnectionStore::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| tf("connections.error.read", &[("path", &path.display())]))?;
+    serde_yaml::from_str(&content)
+        .with_context(|| tf("connections.error.parse", &[("path", &path.display())]))
+}
+
+/// 공유 연결 저장소를 파일에 기록한다.
+pub fn save_connection_store(store: &ConnectionStore) -> anyhow::Result<()> {
+    let path = connections_path();
+    let yaml = serde_yaml::to_string(store)?;
+    std::fs::write(&path, yaml)
+        .with_context(|| tf("connections.error.save", &[("path", &path.display())]))
+}
@@ -0,0 +1,224 @@
+use crate::engine::ExecutionContext;
+use crate::i18n::tf;
+use crate::scenario::{CostCheckConfig, Scenario, Step, StepKind};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::fmt;
+
+/// 한 줄 주석과 블록 주석을 찾는 정규식이다.
+static SQL_COMMENT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)--[^\n]*|/\*.*?\*/").expect("정규식 오류"));
+
+/// 문장 안의 `WHERE` 키워드를 찾는 정규식이다.
+static WHERE_CLAUSE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\bWHERE\b").expect("정규식 오류"));
+
+/// 테이블 전체를 읽거나 바꿀 수 있는 문장의 첫 키워드이다.
+const DATA_STATEMENTS: [&str; 6] = ["SELECT", "WITH", "INSERT", "UPDATE", "DELETE", "MERGE"];
+
+/// 비용 점검 규칙이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostRule {
+    /// 큰 테이블을 `WHERE` 없이 읽거나 바꾸는 SQL.
+    FullTableScan,
+    /// 큰 데이터 파일을 direct path 없이 적재하는 sqlldr.
+    ConventionalLoad,
+    /// 너무 많은 파일과 일치하는 Loop glob.
+    LargeGlob,
+}
+
+impl CostRule {
+    /// 확인 키와 설정 파일에 쓰는 규칙 이름을 반환한다.
+    pub fn key(self) -> &'static str {
+        match self {
+            CostRule::FullTableScan => "full_table_scan",
+            CostRule::ConventionalLoad => "conventional_load",
+            CostRule::LargeGlob => "large_glob",
+        }
+    }
+}
+
+/// 실행 전에 발견한 비용이 큰 패턴이다. 실행을 막지 않는 권고이다.
+#[derive(Debug, Clone)]
+pub struct CostWarning {
+    /// 대상 Step ID.
+    pub step_id: String,
+    /// 해당 규칙.
+    pub rule: CostRule,
+    /// 경고 설명.
+    pub message: String,
+    /// 시나리오의 `cost_check.acknowledged`에 있어 이미 확인한 경고인지 여부.
+    pub acknowledged: bool,
+}
+
+impl CostWarning {
+    /// `cost_check.acknowledged`에 남길 확인 키(`<step_id>/<규칙>`)를 반환한다.
+    pub fn ack_key(&self) -> String {
+        format!("{}/{}", self.step_id, self.rule.key())
+    }
+}
+
+impl fmt::Display for CostWarning {
+    /// `[step_id] 메시지` 형태로 출력한다.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.step_id, self.message)
+    }
+}
+
+/// 시나리오에서 실행 비용이 클 수 있는 패턴을 찾는다.
+///
+/// `cost_check.large_tables`의 테이블을 `WHERE` 없이 다루는 SQL 문장, `large_file_mb`보다 큰 로컬
+/// 데이터 파일을 direct path 없이 적재하는 sqlldr, `max_glob_files`보다 많은 파일과 일치하는 Loop를
+/// 경고한다. 경로와 패턴은 시나리오 파라미터로 치환하며, 치환되지 않는 변수가 남으면 건너뛴다.
+/// Loop 내부와 `on_failure` Step도 함께 점검한다.
+///
+/// # 반환값
+/// 발견한 경고 목록. 확인한 경고도 [`CostWarning::acknowledged`]를 켠 채 포함한다.
+pub fn check_scenario_cost(scenario: &Scenario) -> Vec<CostWarning> {
+    let config = scenario.cost_check.clone().unwrap_or_default();
+    let mut ctx = ExecutionContext::new();
+    for (key, value) in &scenario.params {
+        ctx.set_var(key.clone(), value.clone());
+    }
+    let tables: Vec<(String, Regex)> = config
+        .large_tables
+        .iter()
+        .filter(|table| !table.trim().is_empty())
+        .filter_map(|table| {
+            let pattern = format!(
+                r"(?i)(^|[^\w.$#]){}($|[^\w$#])",
+                regex::escape(table.trim())
+            );
+            Some((table.trim().to_string(), Regex::new(&pattern).ok()?))
+        })
+        .collect();
+    let checker = CostChecker {
+        config: &config,
+        ctx: &ctx,
+        tables,
+    };
+    let mut warnings = Vec::new();
+    checker.collect(&scenario.steps, &mut warnings);
+    checker.collect(scenario.cleanup_steps(), &mut warnings);
+    for warning in &mut warnings {
+        warning.acknowledged = config.acknowledged.contains(&warning.ack_key());
+    }
+    warnings
+}
+
+/// 점검 기준과 치환용 컨텍스트를 묶는다.
+struct CostChecker<'a> {
+    /// 시나리오의 비용 점검 설정.
+    config: &'a CostCheckConfig,
+    /// 시나리오 파라미터를 넣은 치환용 컨텍스트.
+    ctx: &'a ExecutionContext,
+    /// 큰 테이블 이름과 그 이름을 식별자로 찾는 정규식.
+    tables: Vec<(String, Regex)>,
+}
+
+impl CostChecker<'_> {
+    /// Step 목록을 재귀적으로 점검해 경고를 모은다.
+    fn collect(&self, steps: &[Step], warnings: &mut Vec<CostWarning>) {
+        for step in steps {
+            let mut warn = |rule: CostRule, message: String| {
+                warnings.push(CostWarning {
+                    step_id: step.id.clone(),
+                    rule,
+                    message,
+                    acknowledged: false,
+                });
+            };
+            match &step.kind {
+                StepKind::Sql { sql, .. } => {
+                    if let Some(table) = self.unfiltered_table(sql) {
+                        warn(
+                            CostRule::FullTableScan,
+                            tf("cost_check.full_table_scan", &[("table", &table)]),
+                        );
+                    }
+                }
+                StepKind::SqlFile { path, .. } => {
+                    let path = self.ctx.expand_lenient(&path.to_string_lossy());
+                    if !path.contains("${")
+                        && let Ok(sql) = std::fs::read_to_string(&path)
+                        && let Some(table) = self.unfiltered_table(&sql)
+                    {
+                        warn(
+                            CostRule::FullTableScan,
+                            tf("cost_check.full_table_scan", &[("table", &table)]),
+                        );
+                    }
+                }
+                StepKind::SqlLoaderPar { config }
+                    if config.remote.is_none() && !config.options.direct =>
+                {
+                    let Some(data) = &config.data_file else {
+                        continue;
+                    };
+                    let path = self.ctx.expand_lenient(&data.to_string_lossy());
+                    if path.contains("${") {
+                        continue;
+                    }
+                    let Ok(meta) = std::fs::metadata(&path) else {
+                        continue;
+                    };
+                    let size_mb = meta.len() / (1024 * 1024);
+                    if size_mb > self.config.large_file_mb {
+                        warn(
+                            CostRule::ConventionalLoad,
+                            tf(
+                                "cost_check.conventional_load",
+                                &[("path", &path), ("size", &size_mb)],
+                            ),
+                        );
+                    }
+                }
+                StepKind::Loop { config } => {
+                    let pattern = self.ctx.expand_lenient(&config.for_each_glob);
+                    if !pattern.contains("${")
+                        && let Ok(paths) = glob::glob(&pattern)
+                    {
+                        let limit = self.config.max_glob_files;
+                        let count = paths.filter_map(Result::ok).take(limit + 1).count();
+                        if count > limit {
+                            warn(
+                                CostRule::LargeGlob,
+                                tf(
+                                    "cost_check.large_glob",
+                                    &[("pattern", &pattern), ("limit", &limit)],
+                                ),
+                            );
+                        }
+                    }
+                    self.collect(&config.steps, warnings);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// SQL에서 큰 테이블을 `WHERE` 없이 다루는 첫 문장의 테이블 이름을 반환한다.
+    ///
+    /// 주석을 지우고 `;`로 문장을 나눈 뒤, 데이터를 읽거나 바꾸는 문장만 본다. 하위 질의에 `WHERE`가
+    /// 있어도 문장 안에 `WHERE`가 하나라도 있으면 조건이 있는 것으로 본다.
+    fn unfiltered_table(&self, sql: &str) -> Option<String> {
+        if self.tables.is_empty() {
+            return None;
+        }
+        let sql = SQL_COMMENT.replace_all(sql, " ");
+        for statement in sql.split(';') {
+            let head = statement
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .trim_start_matches('(')
+                .to_uppercase();
+            if !DATA_STATEMENTS.contains(&head.as_str()) || WHERE_CLAUSE.is_match(statement) {
+                continue;
+            }
+            if let Some((table, _)) = self.tables.iter().find(|(_, re)| re.is_match(statement)) {
+                return Some(table.clone());
+            }
+        }
+        None
+    }
+}
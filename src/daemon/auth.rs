@@ -0,0 +1,93 @@
+use crate::i18n::tf;
+use aes_gcm::aead::OsRng;
+use aes_gcm::aead::rand_core::RngCore;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::path::Path;
+
+/// 접속 토큰의 바이트 수이다. 파일에는 16진수 문자열로 남긴다.
+const TOKEN_BYTES: usize = 32;
+
+/// 새 접속 토큰을 만들어 현재 사용자만 읽을 수 있는 파일에 쓴다.
+///
+/// 기존 파일은 지우고 새로 만들어 권한이 항상 새로 적용되게 한다. Unix에서는 `0600`으로 만들고,
+/// Windows에서는 사용자 프로필 아래 데이터 디렉터리의 기본 ACL을 따른다.
+///
+/// # 반환값
+/// 파일에 쓴 토큰.
+pub(super) fn create_token_file(path: &Path) -> anyhow::Result<String> {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    let token: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    match std::fs::remove_file(path) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(token.as_bytes())?;
+    Ok(token)
+}
+
+/// 토큰 파일을 읽는다. 앞뒤 공백은 무시한다.
+pub(super) fn read_token_file(path: &Path) -> anyhow::Result<String> {
+    let token = std::fs::read_to_string(path).map_err(|err| {
+        anyhow::anyhow!(tf(
+            "daemon.error.read_token",
+            &[("path", &path.display()), ("error", &err)],
+        ))
+    })?;
+    let token = token.trim().to_string();
+    if token.is_empty() {
+        anyhow::bail!(tf("daemon.error.empty_token", &[("path", &path.display())]));
+    }
+    Ok(token)
+}
+
+/// 요청에 담긴 토큰이 데몬 토큰과 같은지 비교한다. 길이가 같으면 일치 여부와 관계없이 같은 시간이 걸린다.
+pub(crate) fn token_matches(expected: &str, actual: &str) -> bool {
+    let (expected, actual) = (expected.as_bytes(), actual.as_bytes());
+    expected.len() == actual.len()
+        && expected
+            .iter()
+            .zip(actual)
+            .fold(0u8, |diff, (left, right)| diff | (left ^ right))
+            == 0
+}
+
+/// 데몬 주소를 해석하고 루프백 주소만 허용한다.
+///
+/// 데몬은 시나리오(shell Step 포함) 실행과 게이트 승인을 받으므로 다른 호스트에서 닿는 주소로는 띄우지
+/// 않는다.
+///
+/// # 반환값
+/// 바인딩할 루프백 주소 목록. 루프백이 아닌 주소가 하나라도 있으면 오류이다.
+pub(super) async fn loopback_addrs(addr: &str) -> anyhow::Result<Vec<SocketAddr>> {
+    let resolved: Vec<SocketAddr> = tokio::net::lookup_host(addr).await?.collect();
+    if resolved.is_empty() {
+        anyhow::bail!(tf("daemon.error.resolve", &[("addr", &addr)]));
+    }
+    if let Some(remote) = resolved
+        .iter()
+        .find(|resolved| !resolved.ip().is_loopback())
+    {
+        anyhow::bail!(tf(
+            "daemon.error.not_loopback",
+            &[("addr", &addr), ("remote", remote)],
+        ));
+    }
+    Ok(resolved)
+}
@@ -0,0 +1,158 @@
+use super::DaemonEndpoint;
+use super::auth::read_token_file;
+use super::protocol::{AuthedRequest, DaemonMessage, DaemonRequest, encode_line};
+use crate::i18n::{t, tf};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::runtime::Handle;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// 연결이 끊겼을 때 재접속을 시도하는 간격이다.
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 데몬 클라이언트가 GUI에 전달하는 알림이다.
+#[derive(Debug, Clone)]
+pub enum DaemonClientEvent {
+    /// 데몬에 접속했다. 곧이어 `Resync` 메시지가 도착한다.
+    Connected,
+    /// 데몬과의 연결이 끊겼다. 백그라운드에서 재접속을 계속 시도한다.
+    Disconnected {
+        /// 끊긴 원인.
+        reason: String,
+    },
+    /// 데몬이 보낸 메시지이다.
+    Message(DaemonMessage),
+}
+
+/// 데몬에 접속해 요청을 보내고 메시지를 받는 GUI 측 핸들이다.
+///
+/// 핸들을 버려도 데몬의 실행은 영향을 받지 않는다.
+pub struct DaemonClient {
+    /// 데몬 주소와 토큰 파일.
+    endpoint: DaemonEndpoint,
+    /// 요청 송신 채널.
+    requests_tx: UnboundedSender<DaemonRequest>,
+    /// 알림 수신 채널.
+    events_rx: UnboundedReceiver<DaemonClientEvent>,
+}
+
+impl DaemonClient {
+    /// 백그라운드 접속 루프를 시작한다. 접속될 때마다 자동으로 `Attach`를 보낸다.
+    ///
+    /// # 매개변수
+    /// - `runtime`: 접속 루프를 실행할 Tokio 런타임 핸들.
+    /// - `endpoint`: 데몬 주소와 접속 토큰 파일.
+    pub fn connect(runtime: &Handle, endpoint: DaemonEndpoint) -> Self {
+        let (requests_tx, requests_rx) = mpsc::unbounded_channel();
+        let (events_tx, events_rx) = mpsc::unbounded_channel();
+        runtime.spawn(connection_loop(endpoint.clone(), requests_rx, events_tx));
+        Self {
+            endpoint,
+            requests_tx,
+            events_rx,
+        }
+    }
+
+    /// 데몬 주소를 반환한다.
+    pub fn addr(&self) -> &str {
+        &self.endpoint.addr
+    }
+
+    /// 데몬에 요청을 보낸다. 연결이 끊긴 동안 보낸 요청은 오류 메시지로 돌아온다.
+    pub fn send(&self, request: DaemonRequest) {
+        let _ = self.requests_tx.send(request);
+    }
+
+    /// 대기 중인 알림을 하나 꺼낸다.
+    pub fn try_recv(&mut self) -> Option<DaemonClientEvent> {
+        self.events_rx.try_recv().ok()
+    }
+}
+
+/// 접속과 재접속을 반복하며 요청/메시지를 중계한다.
+///
+/// 데몬이 다시 시작되면 토큰이 바뀌므로 접속할 때마다 토큰 파일을 새로 읽는다.
+async fn connection_loop(
+    endpoint: DaemonEndpoint,
+    mut requests_rx: UnboundedReceiver<DaemonRequest>,
+    events_tx: UnboundedSender<DaemonClientEvent>,
+) {
+    let addr = &endpoint.addr;
+    let mut connected = false;
+    loop {
+        let reason = match read_token_file(&endpoint.token_file) {
+            Ok(token) => match TcpStream::connect(addr).await {
+                Ok(stream) => {
+                    connected = true;
+                    if events_tx.send(DaemonClientEvent::Connected).is_err() {
+                        return;
+                    }
+                    match relay(stream, &token, &mut requests_rx, &events_tx).await {
+                        Ok(()) => t("daemon.client.closed").to_string(),
+                        Err(err) => err.to_string(),
+                    }
+                }
+                Err(err) => tf(
+                    "daemon.client.connect_failed",
+                    &[("addr", &addr), ("error", &err)],
+                ),
+            },
+            Err(err) => err.to_string(),
+        };
+        if events_tx.is_closed() {
+            return;
+        }
+        if connected {
+            let _ = events_tx.send(DaemonClientEvent::Disconnected { reason });
+            connected = false;
+        }
+        // 끊긴 동안 쌓인 요청은 실행하지 않고 거절한다.
+        while requests_rx.try_recv().is_ok() {
+            let _ = events_tx.send(DaemonClientEvent::Message(DaemonMessage::Error {
+                message: t("daemon.client.not_connected").to_string(),
+            }));
+        }
+        tokio::time::sleep(RECONNECT_INTERVAL).await;
+    }
+}
+
+/// 연결 하나가 유지되는 동안 요청에 토큰을 붙여 보내고 메시지를 받는다.
+async fn relay(
+    stream: TcpStream,
+    token: &str,
+    requests_rx: &mut UnboundedReceiver<DaemonRequest>,
+    events_tx: &UnboundedSender<DaemonClientEvent>,
+) -> anyhow::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    let authed = |request: DaemonRequest| AuthedRequest {
+        token: token.to_string(),
+        request,
+    };
+    writer
+        .write_all(&encode_line(&authed(DaemonRequest::Attach))?)
+        .await?;
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let Some(line) = line? else {
+                    return Ok(());
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let message: DaemonMessage = serde_json::from_str(&line)?;
+                if events_tx.send(DaemonClientEvent::Message(message)).is_err() {
+                    return Ok(());
+                }
+            }
+            request = requests_rx.recv() => {
+                let Some(request) = request else {
+                    return Ok(());
+                };
+                writer.write_all(&encode_line(&authed(request))?).await?;
+            }
+        }
+    }
+}
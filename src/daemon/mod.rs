@@ -0,0 +1,60 @@
+//! 엔진 실행을 GUI 프로세스와 분리하는 데몬 모드이다.
+//!
+//! 데몬은 루프백 TCP 소켓에서 JSON 라인 프로토콜로 요청을 받아 시나리오를 실행하고,
+//! GUI는 언제든 접속/분리하며 지금까지의 이벤트를 다시 받아 상태를 복원한다. `--trigger`로 지정한
+//! 시나리오는 외부 시스템이 놓는 트리거 파일을 감시해 데몬이 직접 시작한다.
+//!
+//! 데몬은 시작할 때 현재 사용자만 읽을 수 있는 토큰 파일을 만들고, 모든 요청에 그 토큰이 있어야
+//! 처리한다.
+
+mod auth;
+mod client;
+mod protocol;
+mod server;
+mod trigger;
+
+use crate::settings::user_data_dir;
+use std::path::PathBuf;
+
+pub(crate) use auth::token_matches;
+pub use client::{DaemonClient, DaemonClientEvent};
+pub use protocol::{DaemonMessage, DaemonRequest};
+pub use server::run_daemon;
+
+/// 데몬 주소를 지정하는 환경 변수 이름이다.
+pub const DAEMON_ADDR_ENV: &str = "RUST_AIRFLOW_DAEMON_ADDR";
+
+/// 환경 변수가 없을 때 사용하는 기본 데몬 주소이다.
+pub const DEFAULT_DAEMON_ADDR: &str = "127.0.0.1:47800";
+
+/// 데몬 접속 토큰 파일 경로를 지정하는 환경 변수 이름이다.
+pub const DAEMON_TOKEN_FILE_ENV: &str = "RUST_AIRFLOW_DAEMON_TOKEN_FILE";
+
+/// 환경 변수가 없을 때 사용자 데이터 디렉터리 아래에 두는 토큰 파일 이름이다.
+const DEFAULT_DAEMON_TOKEN_FILE: &str = "daemon.token";
+
+/// 데몬 주소와 접속 토큰 파일 경로이다. 데몬과 GUI가 같은 값을 써야 한다.
+#[derive(Debug, Clone)]
+pub struct DaemonEndpoint {
+    /// 데몬이 대기하는 루프백 주소.
+    pub addr: String,
+    /// 데몬이 쓰고 GUI가 읽는 접속 토큰 파일.
+    pub token_file: PathBuf,
+}
+
+/// 환경 변수 또는 기본값에서 데몬 접속 토큰 파일 경로를 결정한다.
+pub fn daemon_token_path() -> PathBuf {
+    std::env::var(DAEMON_TOKEN_FILE_ENV)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| user_data_dir().join(DEFAULT_DAEMON_TOKEN_FILE))
+}
+
+/// 환경 변수 또는 기본값에서 데몬 주소를 결정한다.
+pub fn daemon_addr() -> String {
+    std::env::var(DAEMON_ADDR_ENV)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_DAEMON_ADDR.to_string())
+}
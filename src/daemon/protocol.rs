@@ -0,0 +1,87 @@
+use crate::engine::{ConfirmMode, EngineEvent, RehearsalPlan, StepCancelMode};
+use crate::scenario::Scenario;
+use serde::{Deserialize, Serialize};
+
+/// GUI가 데몬에 보내는 요청이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DaemonRequest {
+    /// 현재 실행 상태를 재동기화하고 이후 이벤트 구독을 시작한다.
+    Attach,
+    /// 시나리오 실행을 시작한다.
+    Start {
+        /// 실행할 시나리오 정의.
+        scenario: Scenario,
+        /// 리허설 실행이면 주입할 장애 계획.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rehearsal: Option<RehearsalPlan>,
+        /// 컨펌 Step에 응답하는 방식.
+        #[serde(default)]
+        confirm_mode: ConfirmMode,
+    },
+    /// 실행 중인 시나리오를 중단한다.
+    Cancel,
+    /// 컨펌 요청에 응답한다.
+    Confirm {
+        /// 컨펌 요청 ID.
+        request_id: u64,
+        /// 수락 여부.
+        accepted: bool,
+    },
+    /// 승인을 기다리는 수동 승인 게이트를 승인한다.
+    ///
+    /// 승인자는 요청에 적지 않고 데몬 프로세스의 OS 사용자 이름으로 기록한다. 토큰 파일을 읽을 수 있는
+    /// 사용자만 요청할 수 있으므로 데몬 사용자를 대신해 승인하는 것으로 본다.
+    Approve {
+        /// 승인할 게이트 Step ID.
+        step_id: String,
+    },
+    /// 실행 중인 Step 하나만 취소한다.
+    CancelStep {
+        /// 취소할 Step ID.
+        step_id: String,
+        /// 취소한 Step의 처리 방식.
+        mode: StepCancelMode,
+    },
+}
+
+/// 접속 토큰을 붙인 요청이다. 데몬은 토큰이 맞지 않는 요청을 처리하지 않고 연결을 끊는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthedRequest {
+    /// 데몬 토큰 파일의 내용.
+    pub token: String,
+    /// 처리할 요청.
+    pub request: DaemonRequest,
+}
+
+/// 데몬이 GUI에 보내는 메시지이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DaemonMessage {
+    /// 현재(또는 마지막) 실행의 전체 상태이다.
+    Resync {
+        /// 실행 중이거나 마지막으로 실행한 시나리오.
+        scenario: Option<Scenario>,
+        /// 실행 중 여부.
+        running: bool,
+        /// 실행 시작 후 발생한 이벤트 목록. 로그는 Step별 최근 라인만 남는다.
+        events: Vec<EngineEvent>,
+    },
+    /// 실시간 엔진 이벤트이다.
+    Event {
+        /// 전달할 엔진 이벤트.
+        event: EngineEvent,
+    },
+    /// 요청을 처리하지 못했다.
+    Error {
+        /// 사용자에게 보여줄 메시지.
+        message: String,
+    },
+}
+
+/// 메시지를 줄바꿈으로 끝나는 JSON 한 줄로 직렬화한다.
+pub(super) fn encode_line<T: Serialize>(value: &T) -> anyhow::Result<Vec<u8>> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    Ok(line)
+}
@@ -0,0 +1,433 @@
+use super::DaemonEndpoint;
+use super::auth::{create_token_file, loopback_addrs, token_matches};
+use super::protocol::{AuthedRequest, DaemonMessage, DaemonRequest, encode_line};
+use super::trigger::watch_trigger;
+use crate::engine::{
+    ConfirmBridge, ConfirmMode, EngineEvent, EngineHandleCache, RehearsalPlan, run_scenario,
+};
+use crate::executor::SharedExecutor;
+use crate::i18n::{t, tf};
+use crate::scenario::Scenario;
+use crate::settings::local_user_name;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+
+/// 재동기화용으로 Step별 보관하는 최대 로그 라인 수이다.
+const HISTORY_LOG_LINES: usize = 500;
+
+/// 접속한 GUI에 실시간 이벤트를 전달하는 브로드캐스트 채널 용량이다.
+const BROADCAST_CAPACITY: usize = 1024;
+
+/// 데몬이 소유한 실행 상태이다.
+#[derive(Default)]
+struct DaemonState {
+    /// 실행 중이거나 마지막으로 실행한 시나리오.
+    scenario: Option<Scenario>,
+    /// 실행 중 여부.
+    running: bool,
+    /// 로그를 제외한 이벤트 기록.
+    events: Vec<EngineEvent>,
+    /// Step별 최근 로그 라인.
+    logs: HashMap<String, VecDeque<String>>,
+    /// 실행 취소 토큰.
+    cancel_token: Option<CancellationToken>,
+    /// 실행 중인 시나리오의 컨펌 브리지.
+    confirm_bridge: Option<ConfirmBridge>,
+}
+
+impl DaemonState {
+    /// 새 실행을 위해 기록을 초기화한다.
+    fn reset(&mut self, scenario: Scenario) {
+        self.scenario = Some(scenario);
+        self.running = true;
+        self.events.clear();
+        self.logs.clear();
+    }
+
+    /// 엔진 이벤트를 기록에 반영한다.
+    fn record(&mut self, event: &EngineEvent) {
+        match event {
+            EngineEvent::StepLog { step_id, line } => {
+                let logs = self.logs.entry(step_id.clone()).or_default();
+                logs.push_back(line.clone());
+                if logs.len() > HISTORY_LOG_LINES {
+                    logs.pop_front();
+                }
+            }
+            EngineEvent::ScenarioFinished => {
+                self.running = false;
+                self.cancel_token = None;
+                self.confirm_bridge = None;
+                self.events.push(event.clone());
+            }
+            _ => self.events.push(event.clone()),
+        }
+    }
+
+    /// 현재 상태를 재동기화 메시지로 만든다.
+    ///
+    /// Step 로그는 해당 Step의 첫 시작 이벤트 바로 뒤에 배치해 실패 시점의 마지막 로그가 유지되도록 한다.
+    fn resync_message(&self) -> DaemonMessage {
+        let mut events = Vec::with_capacity(self.events.len());
+        let mut emitted: HashSet<&str> = HashSet::new();
+        for event in &self.events {
+            events.push(event.clone());
+            if let EngineEvent::StepStarted { step_id } = event
+                && emitted.insert(step_id.as_str())
+            {
+                push_logs(&mut events, step_id, self.logs.get(step_id));
+            }
+        }
+        for (step_id, logs) in &self.logs {
+            if !emitted.contains(step_id.as_str()) {
+                push_logs(&mut events, step_id, Some(logs));
+            }
+        }
+        DaemonMessage::Resync {
+            scenario: self.scenario.clone(),
+            running: self.running,
+            events,
+        }
+    }
+}
+
+/// 데몬이 시작한 실행 하나의 결과를 모아 시작한 쪽에 알린다.
+struct RunOutcome {
+    /// 메인 Step ID 목록.
+    steps: Vec<String>,
+    /// 성공으로 끝난 Step ID.
+    succeeded: HashSet<String>,
+    /// 이번 실행의 취소 토큰.
+    cancel: CancellationToken,
+    /// 결과를 받을 채널.
+    tx: oneshot::Sender<bool>,
+}
+
+impl RunOutcome {
+    /// 이번 실행의 이벤트에서 성공한 Step을 모은다.
+    fn record(&mut self, event: &EngineEvent) {
+        if let EngineEvent::StepFinished {
+            step_id,
+            success: true,
+            ..
+        } = event
+        {
+            self.succeeded.insert(step_id.clone());
+        }
+    }
+
+    /// 중지되지 않았고 모든 메인 Step이 성공했는지 알린다.
+    fn finish(self) {
+        let success =
+            !self.cancel.is_cancelled() && self.steps.iter().all(|id| self.succeeded.contains(id));
+        let _ = self.tx.send(success);
+    }
+}
+
+/// Step 로그를 이벤트 목록에 덧붙인다.
+fn push_logs(events: &mut Vec<EngineEvent>, step_id: &str, logs: Option<&VecDeque<String>>) {
+    for line in logs.into_iter().flatten() {
+        events.push(EngineEvent::StepLog {
+            step_id: step_id.to_string(),
+            line: line.clone(),
+        });
+    }
+}
+
+/// 데몬 공유 상태와 이벤트 브로드캐스트 채널 묶음이다.
+#[derive(Clone)]
+pub(super) struct DaemonShared {
+    /// 실행 상태.
+    state: Arc<Mutex<DaemonState>>,
+    /// 실시간 이벤트 송신기.
+    broadcast: broadcast::Sender<EngineEvent>,
+    /// DB 실행기.
+    executor: SharedExecutor,
+    /// 실행 사이에 재사용하는 DB 핸들 캐시.
+    handle_cache: EngineHandleCache,
+}
+
+impl DaemonShared {
+    /// 실행 중인 시나리오가 있는지 확인한다.
+    pub(super) fn is_running(&self) -> bool {
+        self.state
+            .lock()
+            .expect("DaemonState mutex poisoned")
+            .running
+    }
+
+    /// 시나리오 실행을 시작한다. 이미 실행 중이면 오류를 반환한다.
+    ///
+    /// # 반환값
+    /// 이번 실행이 끝나면 모든 메인 Step이 성공하고 중지되지 않았는지를 받는 채널. 엔진이
+    /// `ScenarioFinished` 없이 멈추면 값 없이 닫힌다.
+    pub(super) fn start(&self, scenario: Scenario) -> anyhow::Result<oneshot::Receiver<bool>> {
+        let mut state = self.state.lock().expect("DaemonState mutex poisoned");
+        start_run(&mut state, self, scenario, None, ConfirmMode::Prompt)
+    }
+}
+
+/// 지정한 주소에서 데몬을 실행한다. GUI 접속 여부와 관계없이 실행은 계속된다.
+///
+/// 루프백이 아닌 주소는 거부하고, 대기하기 전에 토큰 파일에 새 접속 토큰을 쓴다.
+///
+/// # 매개변수
+/// - `endpoint`: 바인딩할 루프백 주소와 접속 토큰 파일.
+/// - `executor`: 시나리오 실행에 사용할 DB 실행기.
+/// - `triggers`: 트리거 파일을 감시할 시나리오 파일 목록.
+pub async fn run_daemon(
+    endpoint: &DaemonEndpoint,
+    executor: SharedExecutor,
+    triggers: Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let addr = &endpoint.addr;
+    let addrs = loopback_addrs(addr).await?;
+    let listener = TcpListener::bind(addrs.as_slice()).await?;
+    let token = Arc::new(create_token_file(&endpoint.token_file)?);
+    tracing::info!(
+        "데몬 대기 중: {addr} (토큰 파일: {})",
+        endpoint.token_file.display()
+    );
+    let (broadcast, _) = broadcast::channel(BROADCAST_CAPACITY);
+    let shared = DaemonShared {
+        state: Arc::new(Mutex::new(DaemonState::default())),
+        broadcast,
+        executor,
+        handle_cache: EngineHandleCache::default(),
+    };
+    for path in triggers {
+        tokio::spawn(watch_trigger(path, shared.clone()));
+    }
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        tracing::info!("GUI 접속: {peer}");
+        let shared = shared.clone();
+        let token = token.clone();
+        tokio::spawn(async move {
+            if let Err(err) = serve_client(stream, shared, &token).await {
+                tracing::warn!("GUI 연결 처리 실패({peer}): {err}");
+            }
+            tracing::info!("GUI 분리: {peer}");
+        });
+    }
+}
+
+/// 접속한 GUI 하나의 요청을 처리하고 이벤트를 전달한다.
+///
+/// 토큰이 맞지 않는 요청을 받으면 오류를 알리고 연결을 끊는다.
+async fn serve_client(stream: TcpStream, shared: DaemonShared, token: &str) -> anyhow::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    let (reply_tx, mut reply_rx) = mpsc::unbounded_channel::<DaemonMessage>();
+    let mut events_rx: Option<broadcast::Receiver<EngineEvent>> = None;
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let Some(line) = line? else {
+                    return Ok(());
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let request = match serde_json::from_str::<AuthedRequest>(&line) {
+                    Ok(authed) if token_matches(token, &authed.token) => Ok(authed.request),
+                    Ok(_) => {
+                        tracing::warn!("데몬 토큰이 맞지 않는 요청을 거부했습니다.");
+                        let message = DaemonMessage::Error {
+                            message: t("daemon.error.bad_token").to_string(),
+                        };
+                        writer.write_all(&encode_line(&message)?).await?;
+                        return Ok(());
+                    }
+                    Err(err) => Err(err),
+                };
+                match request {
+                    Ok(DaemonRequest::Attach) => {
+                        let (rx, message) = attach(&shared);
+                        events_rx = Some(rx);
+                        writer.write_all(&encode_line(&message)?).await?;
+                    }
+                    Ok(request) => {
+                        if let Err(err) = handle_request(request, &shared) {
+                            let _ = reply_tx.send(DaemonMessage::Error {
+                                message: err.to_string(),
+                            });
+                        }
+                    }
+                    Err(err) => {
+                        let _ = reply_tx.send(DaemonMessage::Error {
+                            message: tf("daemon.error.bad_request", &[("error", &err)]),
+                        });
+                    }
+                }
+            }
+            Some(message) = reply_rx.recv() => {
+                writer.write_all(&encode_line(&message)?).await?;
+            }
+            event = recv_event(&mut events_rx) => {
+                match event {
+                    Ok(event) => {
+                        writer.write_all(&encode_line(&DaemonMessage::Event { event })?).await?;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        // 밀린 이벤트는 버리고 새로 구독해 전체 상태로 다시 맞춘다.
+                        tracing::warn!("GUI 이벤트 {skipped}건 누락, 재동기화");
+                        let (rx, message) = attach(&shared);
+                        events_rx = Some(rx);
+                        writer.write_all(&encode_line(&message)?).await?;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// 이벤트 구독을 시작하고 현재 상태를 재동기화 메시지로 만든다.
+///
+/// 이벤트 기록과 같은 잠금 안에서 구독해야 상태 사본과 이후 이벤트 사이에 누락이나 중복이 없다.
+fn attach(shared: &DaemonShared) -> (broadcast::Receiver<EngineEvent>, DaemonMessage) {
+    let state = shared.state.lock().expect("DaemonState mutex poisoned");
+    (shared.broadcast.subscribe(), state.resync_message())
+}
+
+/// 구독 중이면 다음 이벤트를, 아니면 영원히 대기한다.
+async fn recv_event(
+    rx: &mut Option<broadcast::Receiver<EngineEvent>>,
+) -> Result<EngineEvent, broadcast::error::RecvError> {
+    match rx {
+        Some(rx) => rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// 시나리오 실행을 시작하고 이번 실행의 결과를 받을 채널을 반환한다.
+///
+/// 이미 실행 중이거나 파라미터가 `param_rules`를 어기면 시작하지 않고 오류를 반환한다.
+fn start_run(
+    state: &mut DaemonState,
+    shared: &DaemonShared,
+    scenario: Scenario,
+    rehearsal: Option<RehearsalPlan>,
+    confirm_mode: ConfirmMode,
+) -> anyhow::Result<oneshot::Receiver<bool>> {
+    if state.running {
+        anyhow::bail!(t("daemon.error.already_running"));
+    }
+    // 엔진도 검사하지만, 시작 전에 거절해야 요청한 쪽이 이유를 오류 응답으로 받는다.
+    scenario.check_param_rules()?;
+    scenario.check_contracts()?;
+    state.reset(scenario.clone());
+    let token = CancellationToken::new();
+    let confirm_bridge = ConfirmBridge::new();
+    state.cancel_token = Some(token.clone());
+    state.confirm_bridge = Some(confirm_bridge.clone());
+    let (tx, rx) = mpsc::unbounded_channel();
+    let (outcome_tx, outcome_rx) = oneshot::channel();
+    let outcome = RunOutcome {
+        steps: scenario.steps.iter().map(|step| step.id.clone()).collect(),
+        succeeded: HashSet::new(),
+        cancel: token.clone(),
+        tx: outcome_tx,
+    };
+    tokio::spawn(forward_events(rx, shared.clone(), outcome));
+    let executor = shared.executor.clone();
+    let handle_cache = shared.handle_cache.clone();
+    tokio::spawn(async move {
+        if let Err(err) = run_scenario(
+            scenario,
+            executor,
+            handle_cache,
+            tx,
+            token,
+            Some(confirm_bridge),
+            confirm_mode,
+            rehearsal,
+            None,
+            None,
+        )
+        .await
+        {
+            // 엔진이 오류로 끝나도 ScenarioFinished는 엔진이 보낸다.
+            tracing::error!("시나리오 실행 실패: {err}");
+        }
+    });
+    Ok(outcome_rx)
+}
+
+/// 실행 제어 요청을 처리한다.
+fn handle_request(request: DaemonRequest, shared: &DaemonShared) -> anyhow::Result<()> {
+    let mut state = shared.state.lock().expect("DaemonState mutex poisoned");
+    match request {
+        DaemonRequest::Attach => {}
+        DaemonRequest::Start {
+            scenario,
+            rehearsal,
+            confirm_mode,
+        } => {
+            start_run(&mut state, shared, scenario, rehearsal, confirm_mode)?;
+        }
+        DaemonRequest::Cancel => {
+            if let Some(token) = &state.cancel_token {
+                token.cancel();
+            }
+        }
+        DaemonRequest::Confirm {
+            request_id,
+            accepted,
+        } => {
+            let responded = state
+                .confirm_bridge
+                .as_ref()
+                .is_some_and(|bridge| bridge.respond(request_id, accepted));
+            if !responded {
+                anyhow::bail!(tf("daemon.error.unknown_confirm", &[("id", &request_id)],));
+            }
+        }
+        DaemonRequest::Approve { step_id } => {
+            let approved = state
+                .confirm_bridge
+                .as_ref()
+                .is_some_and(|bridge| bridge.approve_gate(&step_id, &local_user_name()));
+            if !approved {
+                anyhow::bail!(tf("app.gate_not_waiting", &[("step", &step_id)]));
+            }
+        }
+        DaemonRequest::CancelStep { step_id, mode } => {
+            let cancelled = state
+                .confirm_bridge
+                .as_ref()
+                .is_some_and(|bridge| bridge.cancel_step(&step_id, mode));
+            if !cancelled {
+                anyhow::bail!(tf("app.step_not_running", &[("step", &step_id)]));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 엔진 이벤트를 기록하고 접속 중인 GUI에 전파한다.
+async fn forward_events(
+    mut rx: mpsc::UnboundedReceiver<EngineEvent>,
+    shared: DaemonShared,
+    mut outcome: RunOutcome,
+) {
+    while let Some(event) = rx.recv().await {
+        let finished = matches!(event, EngineEvent::ScenarioFinished);
+        outcome.record(&event);
+        {
+            let mut state = shared.state.lock().expect("DaemonState mutex poisoned");
+            state.record(&event);
+            let _ = shared.broadcast.send(event);
+        }
+        if finished {
+            outcome.finish();
+            break;
+        }
+    }
+}
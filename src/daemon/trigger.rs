@@ -0,0 +1,289 @@
+use super::server::DaemonShared;
+use crate::i18n::{t, tf};
+use crate::scenario::{
+    Scenario, TRIGGER_FILE_VAR, TriggerCleanup, TriggerConfig, TriggerMode, load_scenario_from_file,
+};
+use anyhow::Context;
+use notify::{EventKind, RecursiveMode, Watcher};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use tokio::time::{Instant, sleep, timeout_at};
+
+/// 실패하거나 중지된 실행의 트리거 파일을 옮겨 두는 `watch_dir` 아래 디렉터리 이름이다.
+const FAILED_DIR: &str = "failed";
+
+/// 트리거 감시에 쓰는 시나리오와 검증한 트리거 설정이다.
+struct TriggerWatch {
+    /// 트리거로 실행할 시나리오.
+    scenario: Scenario,
+    /// 시나리오의 `trigger` 설정.
+    config: TriggerConfig,
+    /// 파일 이름 전체와 비교하도록 감싼 `pattern` 정규식.
+    pattern: Regex,
+}
+
+impl TriggerWatch {
+    /// 시나리오 파일을 읽고 `trigger` 설정과 정규식을 검증한다.
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        let scenario = load_scenario_from_file(path)?;
+        let config = scenario
+            .trigger
+            .clone()
+            .context(t("trigger.error.no_trigger"))?;
+        let pattern = Regex::new(&format!("^(?:{})$", config.pattern))
+            .with_context(|| tf("trigger.error.bad_pattern", &[("pattern", &config.pattern)]))?;
+        Ok(Self {
+            scenario,
+            config,
+            pattern,
+        })
+    }
+}
+
+/// 운영체제 파일 알림을 받는 감시자와 알림 채널이다.
+struct FileEvents {
+    /// 살아 있는 동안만 알림을 보내므로 함께 보관한다.
+    _watcher: notify::RecommendedWatcher,
+    /// 감시 디렉터리에서 생기거나 바뀐 파일 경로.
+    rx: UnboundedReceiver<Vec<PathBuf>>,
+}
+
+impl FileEvents {
+    /// 디렉터리 안 파일의 생성·변경(이름 변경 포함) 알림을 받기 시작한다.
+    fn start(dir: &Path) -> notify::Result<Self> {
+        let (tx, rx) = unbounded_channel();
+        let mut watcher = notify::recommended_watcher(
+            move |result: notify::Result<notify::Event>| match result {
+                Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) => {
+                    let _ = tx.send(event.paths);
+                }
+                Ok(_) => {}
+                Err(err) => tracing::warn!("파일 알림 오류: {err}"),
+            },
+        )?;
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+        })
+    }
+
+    /// 이름이 `pattern`과 일치하는 파일 알림을 기다린 뒤, 그런 알림이 `debounce` 동안 더 오지 않을
+    /// 때까지 기다린다.
+    ///
+    /// `limit` 안에 일치하는 알림이 없어도 돌아오므로 호출한 쪽은 디렉터리를 다시 확인한다. 알림
+    /// 채널이 닫혔으면 `false`를 반환한다.
+    async fn wait(&mut self, pattern: &Regex, limit: Duration, debounce: Duration) -> bool {
+        let deadline = Instant::now() + limit;
+        loop {
+            match timeout_at(deadline, self.rx.recv()).await {
+                Err(_) => return true,
+                Ok(None) => return false,
+                Ok(Some(paths)) if matches_any(&paths, pattern) => break,
+                Ok(Some(_)) => {}
+            }
+        }
+        let mut quiet_until = Instant::now() + debounce;
+        loop {
+            match timeout_at(quiet_until, self.rx.recv()).await {
+                Err(_) => return true,
+                Ok(None) => return false,
+                Ok(Some(paths)) if matches_any(&paths, pattern) => {
+                    quiet_until = Instant::now() + debounce;
+                }
+                Ok(Some(_)) => {}
+            }
+        }
+    }
+}
+
+/// 경로 중 파일 이름이 패턴과 일치하는 것이 있는지 확인한다.
+fn matches_any(paths: &[PathBuf], pattern: &Regex) -> bool {
+    paths.iter().any(|path| {
+        path.file_name()
+            .is_some_and(|name| pattern.is_match(&name.to_string_lossy()))
+    })
+}
+
+/// 시나리오 파일의 `trigger` 설정대로 트리거 파일을 감시하고, 파일이 나타나면 실행을 시작한다.
+///
+/// `mode: notify`이면 운영체제 파일 알림을 받아 `debounce_ms`만큼 조용해지면 바로 확인하고, 알림을
+/// 쓸 수 없거나 끊기면 `poll_sec` 주기 확인으로 돌아간다. 감시 방식과 디렉터리는 감시를 시작할 때의
+/// 설정을 계속 쓴다. 일치하는 파일이 여럿이면 이름 순으로 하나씩 처리하며, 데몬이 다른 실행 중이면 다음 확인 때 다시
+/// 시도한다. 시나리오 파일은 트리거를 찾을 때마다 다시 읽어 감시 중에 고친 내용을 반영하고, 읽을 수
+/// 없으면 마지막으로 읽은 내용으로 실행한다. 트리거 파일을 정리하지 못하면 같은 파일로 다시 실행하지
+/// 않도록 감시를 멈춘다.
+pub(super) async fn watch_trigger(scenario_path: PathBuf, shared: DaemonShared) {
+    let mut watch = match TriggerWatch::load(&scenario_path) {
+        Ok(watch) => watch,
+        Err(err) => {
+            tracing::error!(
+                "트리거 감시 시작 실패({}): {err:#}",
+                scenario_path.display()
+            );
+            return;
+        }
+    };
+    tracing::info!(
+        "트리거 감시 시작: {} ({})",
+        watch.config.watch_dir.display(),
+        watch.scenario.name
+    );
+    let mut events = match watch.config.mode {
+        TriggerMode::Poll => None,
+        TriggerMode::Notify => match FileEvents::start(&watch.config.watch_dir) {
+            Ok(events) => Some(events),
+            Err(err) => {
+                tracing::warn!("파일 알림을 쓸 수 없어 주기 확인으로 감시합니다: {err}");
+                None
+            }
+        },
+    };
+    loop {
+        if !shared.is_running()
+            && let Some(file) = find_trigger_file(&watch.config.watch_dir, &watch.pattern)
+        {
+            match TriggerWatch::load(&scenario_path) {
+                Ok(latest) => watch = latest,
+                Err(err) => {
+                    tracing::warn!("트리거 시나리오 다시 읽기 실패, 이전 내용으로 실행: {err:#}")
+                }
+            }
+            if let Err(err) = run_triggered(&watch, &file, &shared).await {
+                tracing::error!(
+                    "트리거 파일 정리 실패로 감시를 멈춥니다({}): {err:#}",
+                    file.display()
+                );
+                return;
+            }
+            continue;
+        }
+        let poll = Duration::from_secs(watch.config.poll_sec.max(1));
+        let closed = match &mut events {
+            Some(file_events) => {
+                let debounce = Duration::from_millis(watch.config.debounce_ms);
+                !file_events.wait(&watch.pattern, poll, debounce).await
+            }
+            None => {
+                sleep(poll).await;
+                false
+            }
+        };
+        if closed {
+            tracing::warn!("파일 알림이 끊겨 주기 확인으로 감시합니다.");
+            events = None;
+        }
+    }
+}
+
+/// 감시 디렉터리에서 패턴과 일치하는 파일 중 이름이 가장 앞선 것을 찾는다.
+fn find_trigger_file(dir: &Path, pattern: &Regex) -> Option<PathBuf> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            tracing::warn!("트리거 디렉터리 읽기 실패({}): {err}", dir.display());
+            return None;
+        }
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| pattern.is_match(&name.to_string_lossy()))
+        })
+        .min()
+}
+
+/// 트리거 파일 이름의 이름 있는 그룹을 파라미터로 넣어 실행하고, 끝나면 트리거 파일을 정리한다.
+///
+/// 다른 실행이 먼저 시작해 시작하지 못하면 파일을 그대로 두고 `Ok`를 반환해 다음 확인 때 다시 시도한다.
+/// 파라미터가 `param_rules`를 어기거나 변수 계약을 어긴 Step이 있으면 실행하지 않고 실패한 트리거로 옮긴다.
+async fn run_triggered(
+    watch: &TriggerWatch,
+    file: &Path,
+    shared: &DaemonShared,
+) -> anyhow::Result<()> {
+    let name = file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let Some(captures) = watch.pattern.captures(&name) else {
+        return Ok(());
+    };
+    let mut scenario = watch.scenario.clone();
+    for group in watch.pattern.capture_names().flatten() {
+        if let Some(value) = captures.name(group) {
+            scenario
+                .params
+                .insert(group.to_string(), value.as_str().to_string());
+        }
+    }
+    scenario.params.insert(
+        TRIGGER_FILE_VAR.to_string(),
+        file.to_string_lossy().to_string(),
+    );
+    tracing::info!("트리거 감지: {} → {}", file.display(), scenario.name);
+    // 파일 이름에서 뽑은 값이 규칙을 어기거나 변수 계약이 깨져 있으면 다시 시도해도 같으므로 실패한
+    // 트리거로 옮긴다.
+    if let Err(err) = scenario
+        .check_param_rules()
+        .and_then(|()| scenario.check_contracts())
+    {
+        tracing::warn!(
+            "트리거 실행 조건(파라미터 규칙, 변수 계약)에 맞지 않아 {FAILED_DIR}로 옮깁니다: {}\n{err}",
+            file.display()
+        );
+        move_with_timestamp(file, &watch.config.watch_dir.join(FAILED_DIR))?;
+        return Ok(());
+    }
+    let outcome = match shared.start(scenario) {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            tracing::warn!("트리거 실행 시작 실패, 다음 확인 때 다시 시도: {err}");
+            return Ok(());
+        }
+    };
+    // 같은 데몬의 다른 실행 결과와 섞이지 않도록 이번 실행의 결과만 기다린다.
+    let succeeded = outcome.await.unwrap_or(false);
+    if succeeded {
+        tracing::info!("트리거 실행 성공: {}", file.display());
+        match watch.config.after {
+            TriggerCleanup::Delete => std::fs::remove_file(file)?,
+            TriggerCleanup::Archive => {
+                let dir = watch
+                    .config
+                    .archive_dir
+                    .clone()
+                    .unwrap_or_else(|| watch.config.watch_dir.join("archive"));
+                move_with_timestamp(file, &dir)?;
+            }
+        }
+    } else {
+        tracing::warn!(
+            "트리거 실행 실패, 트리거 파일을 {FAILED_DIR}로 옮깁니다: {}",
+            file.display()
+        );
+        move_with_timestamp(file, &watch.config.watch_dir.join(FAILED_DIR))?;
+    }
+    Ok(())
+}
+
+/// 파일을 `dir`로 옮기며 이름 끝에 처리 시각을 붙인다. 다른 파일 시스템이면 복사 후 지운다.
+fn move_with_timestamp(file: &Path, dir: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let name = file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let stamp = chrono::Local::now().format("%Y%m%d%H%M%S");
+    let target = dir.join(format!("{name}.{stamp}"));
+    if std::fs::rename(file, &target).is_err() {
+        std::fs::copy(file, &target)?;
+        std::fs::remove_file(file)?;
+    }
+    Ok(())
+}
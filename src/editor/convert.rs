@@ -1,34 +1,39 @@
 use super::model::{
-    DbConnectionEditor, EditorConnection, EditorError, EditorStepConfig, EditorStepNode,
-    LoopEditorConfig, ScenarioEditorState,
+    BuilderViewMode, DbConnectionEditor, EditorConnection, EditorError, EditorStepConfig,
+    EditorStepNode, LoopEditorConfig, ScenarioEditorState, execution_order,
 };
-use crate::scenario::{DbConnectionConfig, DbKind, Scenario};
+use crate::scenario::{DbConnectionConfig, DbKind, EditorMetadata, Scenario};
 use eframe::egui;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
 /// Scenario를 에디터 상태로 변환한다.
+///
+/// `metadata` 블록이 있으면 저장해 둔 노드 위치, 선택, 열어 둔 Loop와 캔버스 보기, 실행 프로필을
+/// 되살리고, 위치가 없는 Step만 의존성 레벨에 따라 자동 배치한다.
 pub fn scenario_to_editor_state(scenario: &Scenario) -> Result<ScenarioEditorState, EditorError> {
     let mut state = ScenarioEditorState::new();
+    state.scenario_name = scenario.name.clone();
+    state.remotes = scenario.remotes.clone();
+    state.params = scenario.params.clone();
+    state.secret_params = scenario.secret_params.clone();
+    state.param_rules = scenario.param_rules.clone();
+    state.step_defaults = scenario.defaults.clone();
+    state.log_lines_per_sec = scenario.log_lines_per_sec;
+    state.log_line_max_bytes = scenario.log_line_max_bytes;
+    state.explain_slow_sql_sec = scenario.explain_slow_sql_sec;
+    state.run_tmpdir = scenario.run_tmpdir.clone();
+    state.on_failure = scenario.on_failure.clone();
+    state.summary = scenario.summary.clone();
+    state.failure_diagnostics = scenario.failure_diagnostics.clone();
+    state.profiles = scenario.profiles.clone();
+    state.trigger = scenario.trigger.clone();
+    state.cost_check = scenario.cost_check.clone();
     let mut db_entries: Vec<_> = scenario.db.iter().collect();
     db_entries.sort_by(|a, b| a.0.cmp(b.0));
     for (key, config) in db_entries {
-        match &config.kind {
-            DbKind::Oracle | DbKind::Postgres => {
-                state.db_connections.push(DbConnectionEditor {
-                    key: key.clone(),
-                    kind: config.kind.clone(),
-                    dsn: config.dsn.clone().unwrap_or_default(),
-                    user: config.user.clone().unwrap_or_default(),
-                    password: config.password.clone().unwrap_or_default(),
-                });
-            }
-            other => {
-                return Err(EditorError::UnsupportedDbKind {
-                    key: key.clone(),
-                    kind: format!("{:?}", other),
-                });
-            }
-        }
+        state
+            .db_connections
+            .push(DbConnectionEditor::from_config(key, config)?);
     }
     let mut levels: HashMap<String, usize> = HashMap::new();
     for step in &scenario.steps {
@@ -66,9 +71,105 @@ pub fn scenario_to_editor_state(scenario: &Scenario) -> Result<ScenarioEditorSta
             }
         }
     }
+    if let Some(metadata) = &scenario.metadata {
+        apply_metadata(&mut state, metadata);
+    }
     Ok(state)
 }
 
+/// 저장해 둔 편집 상태를 에디터에 되살린다.
+fn apply_metadata(state: &mut ScenarioEditorState, metadata: &EditorMetadata) {
+    apply_node_metadata(&mut state.nodes, metadata);
+    if let Some(id) = &metadata.selected
+        && state.node(id).is_some()
+    {
+        state.select_node(Some(id.clone()));
+    }
+    for loop_id in &metadata.open_loops {
+        if !state.open_loop(loop_id) {
+            break;
+        }
+    }
+    if let Some([x, y]) = metadata.canvas_offset {
+        state.canvas_offset = egui::vec2(x, y);
+    }
+    if let Some(zoom) = metadata.canvas_zoom {
+        state.canvas_zoom = zoom;
+    }
+    if metadata.list_view {
+        state.view_mode = BuilderViewMode::List;
+    }
+    state.color_by_stage = metadata.color_by_stage;
+    state.snap_to_grid = metadata.snap_to_grid;
+    state.selected_profile = metadata
+        .profile
+        .clone()
+        .filter(|name| state.profiles.contains_key(name));
+}
+
+/// 노드 위치와 Loop 하위 선택을 Loop 안쪽까지 되살린다.
+fn apply_node_metadata(nodes: &mut [EditorStepNode], metadata: &EditorMetadata) {
+    for node in nodes {
+        if let Some([x, y]) = metadata.positions.get(&node.id) {
+            node.position = egui::pos2(*x, *y);
+        }
+        if let EditorStepConfig::Loop { config } = &mut node.config {
+            config.selected_node_id = metadata
+                .loop_selected
+                .get(&node.id)
+                .filter(|id| config.node(id).is_some())
+                .cloned();
+            apply_node_metadata(&mut config.nodes, metadata);
+        }
+    }
+}
+
+/// 에디터 전용 상태를 `metadata` 블록으로 모은다. 기본 상태와 같으면 `None`이다.
+///
+/// # 매개변수
+/// - `current`: Loop가 열려 있을 수 있는 현재 에디터 상태. 열린 Loop 경로와 캔버스 보기를 읽는다.
+/// - `root`: 열린 Loop를 모두 닫은 상태. 노드 위치와 선택을 읽는다.
+fn collect_metadata(
+    current: &ScenarioEditorState,
+    root: &ScenarioEditorState,
+) -> Option<EditorMetadata> {
+    let mut metadata = EditorMetadata {
+        open_loops: current
+            .loop_stack
+            .iter()
+            .map(|frame| frame.loop_id.clone())
+            .collect(),
+        selected: root.selected_node_id.clone(),
+        canvas_offset: (current.canvas_offset != egui::Vec2::ZERO)
+            .then(|| [current.canvas_offset.x, current.canvas_offset.y]),
+        canvas_zoom: (current.canvas_zoom != 1.0).then_some(current.canvas_zoom),
+        list_view: current.view_mode == BuilderViewMode::List,
+        color_by_stage: current.color_by_stage,
+        snap_to_grid: current.snap_to_grid,
+        profile: root.selected_profile.clone(),
+        ..EditorMetadata::default()
+    };
+    collect_node_metadata(&root.nodes, &mut metadata);
+    (metadata != EditorMetadata::default()).then_some(metadata)
+}
+
+/// 노드 위치와 Loop 하위 선택을 Loop 안쪽까지 모은다.
+fn collect_node_metadata(nodes: &[EditorStepNode], metadata: &mut EditorMetadata) {
+    for node in nodes {
+        metadata
+            .positions
+            .insert(node.id.clone(), [node.position.x, node.position.y]);
+        if let EditorStepConfig::Loop { config } = &node.config {
+            if let Some(selected) = &config.selected_node_id {
+                metadata
+                    .loop_selected
+                    .insert(node.id.clone(), selected.clone());
+            }
+            collect_node_metadata(&config.nodes, metadata);
+        }
+    }
+}
+
 /// 재귀적으로 노드 레벨을 계산한다.
 fn assign_level(step_id: &str, scenario: &Scenario, memo: &mut HashMap<String, usize>) -> usize {
     if let Some(level) = memo.get(step_id) {
@@ -92,8 +193,13 @@ fn assign_level(step_id: &str, scenario: &Scenario, memo: &mut HashMap<String, u
     max_dep
 }
 
-/// 에디터 상태를 Scenario로 변환한다.
+/// 에디터 상태를 Scenario로 변환한다. Loop 하위 캔버스가 열려 있어도 최상위 흐름 전체를 변환한다.
+///
+/// 노드 위치 등 에디터 전용 상태는 [`scenario_to_editor_state`]가 되살릴 수 있도록 `metadata`에 담는다.
 pub fn editor_state_to_scenario(state: &ScenarioEditorState) -> Result<Scenario, EditorError> {
+    let current = state;
+    let root = state.at_root();
+    let state = root.as_ref();
     let mut ids = HashSet::new();
     for node in &state.nodes {
         collect_ids_from_node(node, &mut ids)?;
@@ -112,18 +218,30 @@ pub fn editor_state_to_scenario(state: &ScenarioEditorState) -> Result<Scenario,
     if has_cycle(state) {
         return Err(EditorError::CyclicDependency);
     }
-    let scenario_name = state
-        .current_file
-        .as_ref()
-        .and_then(|path| {
-            path.file_stem()
-                .map(|stem| stem.to_string_lossy().to_string())
-        })
-        .unwrap_or_else(|| "Scenario Builder".into());
+    let scenario_name = state.scenario_name.trim();
+    if scenario_name.is_empty() {
+        return Err(EditorError::EmptyScenarioName);
+    }
     let mut scenario = Scenario {
-        name: scenario_name,
+        name: scenario_name.to_string(),
         db: serialize_db_connections(state)?,
+        remotes: state.remotes.clone(),
+        params: state.params.clone(),
+        secret_params: state.secret_params.clone(),
+        param_rules: state.param_rules.clone(),
+        defaults: state.step_defaults.clone(),
+        log_lines_per_sec: state.log_lines_per_sec,
+        log_line_max_bytes: state.log_line_max_bytes,
+        explain_slow_sql_sec: state.explain_slow_sql_sec,
+        run_tmpdir: state.run_tmpdir.clone(),
+        on_failure: state.on_failure.clone(),
+        summary: state.summary.clone(),
+        failure_diagnostics: state.failure_diagnostics.clone(),
+        profiles: state.profiles.clone(),
+        trigger: state.trigger.clone(),
+        cost_check: state.cost_check.clone(),
         steps: Vec::new(),
+        metadata: collect_metadata(current, state),
     };
     for node in &state.nodes {
         let deps = state.dependencies_of(&node.id);
@@ -147,70 +265,24 @@ fn serialize_db_connections(
         if !keys.insert(key.clone()) {
             return Err(EditorError::DuplicateDbKey(key));
         }
-        match conn.kind {
-            DbKind::Oracle | DbKind::Postgres => {}
-            ref other => {
-                return Err(EditorError::UnsupportedDbKind {
-                    key: conn.key.clone(),
-                    kind: format!("{:?}", other),
-                });
+        if let Some(name) = conn.db_ref.as_deref().map(str::trim) {
+            if name.is_empty() {
+                return Err(EditorError::EmptyDbRef(key));
             }
+        } else if !matches!(conn.kind, DbKind::Oracle | DbKind::Postgres) {
+            return Err(EditorError::UnsupportedDbKind {
+                key: conn.key.clone(),
+                kind: format!("{:?}", conn.kind),
+            });
         }
-        let config = DbConnectionConfig {
-            kind: conn.kind.clone(),
-            dsn: optional_string(&conn.dsn),
-            user: optional_string(&conn.user),
-            password: optional_string(&conn.password),
-        };
-        result.insert(key, config);
+        result.insert(key, conn.to_config());
     }
     Ok(result)
 }
 
-/// 공백 문자열을 None으로 변환한다.
-fn optional_string(value: &str) -> Option<String> {
-    if value.trim().is_empty() {
-        None
-    } else {
-        Some(value.to_string())
-    }
-}
-
-/// 위상 정렬을 사용해 사이클 여부를 판별한다.
+/// 실행 스케줄러로 순서를 정할 수 없는 순환 연결이 있는지 판별한다.
 fn has_cycle(state: &ScenarioEditorState) -> bool {
-    let mut indegree: HashMap<&str, usize> = HashMap::new();
-    let mut adj: HashMap<&str, Vec<&str>> = HashMap::new();
-    for node in &state.nodes {
-        indegree.insert(node.id.as_str(), 0);
-        adj.insert(node.id.as_str(), Vec::new());
-    }
-    for conn in &state.connections {
-        if let Some(entry) = indegree.get_mut(conn.to_id.as_str()) {
-            *entry += 1;
-        }
-        if let Some(list) = adj.get_mut(conn.from_id.as_str()) {
-            list.push(conn.to_id.as_str());
-        }
-    }
-    let mut queue: VecDeque<&str> = indegree
-        .iter()
-        .filter_map(|(id, &deg)| if deg == 0 { Some(*id) } else { None })
-        .collect();
-    let mut visited = 0;
-    while let Some(id) = queue.pop_front() {
-        visited += 1;
-        if let Some(children) = adj.get(id) {
-            for child in children {
-                if let Some(entry) = indegree.get_mut(child) {
-                    *entry -= 1;
-                    if *entry == 0 {
-                        queue.push_back(child);
-                    }
-                }
-            }
-        }
-    }
-    visited != state.nodes.len()
+    execution_order(&state.nodes, &state.connections).is_none()
 }
 
 /// Loop 내부에서 중복 ID를 검사한다.
@@ -252,39 +324,7 @@ fn validate_loop_connections(config: &LoopEditorConfig) -> Result<(), EditorErro
 
 /// Loop 내부에 사이클이 있는지 검사한다.
 fn has_cycle_in_loop(config: &LoopEditorConfig) -> bool {
-    let mut indegree: HashMap<&str, usize> = HashMap::new();
-    let mut adj: HashMap<&str, Vec<&str>> = HashMap::new();
-    for node in &config.nodes {
-        indegree.insert(node.id.as_str(), 0);
-        adj.insert(node.id.as_str(), Vec::new());
-    }
-    for conn in &config.connections {
-        if let Some(entry) = indegree.get_mut(conn.to_id.as_str()) {
-            *entry += 1;
-        }
-        if let Some(list) = adj.get_mut(conn.from_id.as_str()) {
-            list.push(conn.to_id.as_str());
-        }
-    }
-    let mut queue: VecDeque<&str> = indegree
-        .iter()
-        .filter_map(|(id, &deg)| if deg == 0 { Some(*id) } else { None })
-        .collect();
-    let mut visited = 0;
-    while let Some(id) = queue.pop_front() {
-        visited += 1;
-        if let Some(children) = adj.get(id) {
-            for child in children {
-                if let Some(entry) = indegree.get_mut(child) {
-                    *entry -= 1;
-                    if *entry == 0 {
-                        queue.push_back(child);
-                    }
-                }
-            }
-        }
-    }
-    if visited != config.nodes.len() {
+    if execution_order(&config.nodes, &config.connections).is_none() {
         return true;
     }
     for node in &config.nodes {
@@ -296,3 +336,144 @@ fn has_cycle_in_loop(config: &LoopEditorConfig) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scenario::load_scenario_from_reader;
+
+    const SCENARIO: &str = r#"
+name: round_trip
+profiles:
+  dev:
+    params:
+      ENV: dev
+  prod:
+    params:
+      ENV: prod
+steps:
+  - id: prepare
+    name: prepare
+    kind: shell
+    shell:
+      script: echo prepare
+  - id: each_file
+    name: each file
+    kind: loop
+    loop:
+      for_each_glob: "data/*.txt"
+      as_var: FILE
+      steps:
+        - id: load
+          name: load
+          kind: shell
+          shell:
+            script: echo ${FILE}
+        - id: archive
+          name: archive
+          kind: shell
+          shell:
+            script: echo archive
+          depends_on: [load]
+    depends_on: [prepare]
+"#;
+
+    /// 에디터 상태를 시나리오로 바꿔 YAML로 저장했다가 다시 읽어 에디터 상태로 되돌린다.
+    fn round_trip(state: &ScenarioEditorState) -> ScenarioEditorState {
+        let scenario = editor_state_to_scenario(state).expect("에디터 상태 변환");
+        let yaml = serde_yaml::to_string(&scenario).expect("YAML 직렬화");
+        let reloaded = load_scenario_from_reader(&mut yaml.as_bytes()).expect("YAML 다시 읽기");
+        scenario_to_editor_state(&reloaded).expect("시나리오 변환")
+    }
+
+    fn load_state() -> ScenarioEditorState {
+        let scenario = load_scenario_from_reader(&mut SCENARIO.as_bytes()).expect("시나리오 읽기");
+        scenario_to_editor_state(&scenario).expect("시나리오 변환")
+    }
+
+    fn position(nodes: &[EditorStepNode], id: &str) -> egui::Pos2 {
+        nodes
+            .iter()
+            .find(|node| node.id == id)
+            .map(|node| node.position)
+            .expect("노드")
+    }
+
+    #[test]
+    fn keeps_positions_inside_and_outside_loops() {
+        let mut state = load_state();
+        state.nodes[0].position = egui::pos2(512.0, 64.0);
+        let EditorStepConfig::Loop { config } = &mut state.nodes[1].config else {
+            panic!("Loop 노드여야 한다");
+        };
+        config.nodes[1].position = egui::pos2(-40.0, 300.5);
+
+        let restored = round_trip(&state);
+        assert_eq!(
+            position(&restored.nodes, "prepare"),
+            egui::pos2(512.0, 64.0)
+        );
+        assert_eq!(
+            position(&restored.nodes, "each_file"),
+            position(&state.nodes, "each_file")
+        );
+        let EditorStepConfig::Loop { config } = &restored.nodes[1].config else {
+            panic!("Loop 노드여야 한다");
+        };
+        assert_eq!(position(&config.nodes, "archive"), egui::pos2(-40.0, 300.5));
+    }
+
+    #[test]
+    fn keeps_open_loop_selection_and_view() {
+        let mut state = load_state();
+        state.select_node(Some("each_file".to_string()));
+        assert!(state.open_loop("each_file"));
+        state.select_node(Some("archive".to_string()));
+        state.canvas_offset = egui::vec2(15.0, -30.0);
+        state.canvas_zoom = 1.5;
+        state.snap_to_grid = true;
+
+        let restored = round_trip(&state);
+        let open: Vec<&str> = restored
+            .loop_stack
+            .iter()
+            .map(|frame| frame.loop_id.as_str())
+            .collect();
+        assert_eq!(open, ["each_file"]);
+        assert_eq!(restored.selected_node_id.as_deref(), Some("archive"));
+        assert_eq!(restored.canvas_offset, egui::vec2(15.0, -30.0));
+        assert_eq!(restored.canvas_zoom, 1.5);
+        assert!(restored.snap_to_grid);
+        let root = restored.at_root();
+        assert_eq!(root.selected_node_id.as_deref(), Some("each_file"));
+    }
+
+    #[test]
+    fn keeps_selected_profile() {
+        let mut state = load_state();
+        state.selected_profile = Some("prod".to_string());
+
+        let restored = round_trip(&state);
+        assert_eq!(restored.selected_profile.as_deref(), Some("prod"));
+        assert_eq!(
+            round_trip(&restored).selected_profile.as_deref(),
+            Some("prod")
+        );
+    }
+
+    #[test]
+    fn ignores_metadata_for_missing_profile_and_steps() {
+        let mut state = load_state();
+        state.selected_profile = Some("prod".to_string());
+        let mut scenario = editor_state_to_scenario(&state).expect("에디터 상태 변환");
+        scenario.profiles.remove("prod");
+        let metadata = scenario.metadata.as_mut().expect("metadata");
+        metadata.selected = Some("gone".to_string());
+        metadata.positions.insert("gone".to_string(), [1.0, 2.0]);
+
+        let restored = scenario_to_editor_state(&scenario).expect("시나리오 변환");
+        assert_eq!(restored.selected_profile, None);
+        assert_eq!(restored.selected_node_id, None);
+        assert!(restored.node("gone").is_none());
+    }
+}
@@ -4,6 +4,8 @@ pub mod view;
 
 pub use convert::{editor_state_to_scenario, scenario_to_editor_state};
 pub use model::{
-    EditorConnection, EditorError, EditorStepConfig, EditorStepNode, ScenarioEditorState, StepKind,
+    EditorConnection, EditorError, EditorStepConfig, EditorStepNode, ScenarioEditorState,
+    ShellTrial, ShellTrialStatus, SqlPreview, SqlPreviewStatus, StepKind, StepTest, StepTestStatus,
+    default_scenario_name,
 };
 pub use view::ScenarioBuilderUi;
@@ -0,0 +1,207 @@
+use eframe::egui;
+
+use super::state::ScenarioEditorState;
+
+/// 그리드 맞춤을 켰을 때 노드 왼쪽 위 모서리를 맞추는 간격(캔버스 좌표)이다.
+pub const GRID_SPACING: f32 = 20.0;
+
+/// 다른 노드의 가장자리나 중심에 붙는 최대 거리이다.
+const ALIGN_SNAP_DISTANCE: f32 = 8.0;
+
+/// 노드를 끄는 동안 캔버스에 그리는 정렬 가이드 선이다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlignGuide {
+    /// 캔버스 x 좌표를 지나는 세로선.
+    Vertical(f32),
+    /// 캔버스 y 좌표를 지나는 가로선.
+    Horizontal(f32),
+}
+
+/// 선택한 노드의 간격을 고르게 맞출 방향이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistributeAxis {
+    /// 왼쪽에서 오른쪽으로.
+    Horizontal,
+    /// 위에서 아래로.
+    Vertical,
+}
+
+impl ScenarioEditorState {
+    /// 선택된 노드 수를 반환한다.
+    pub fn selected_count(&self) -> usize {
+        self.nodes.iter().filter(|node| node.selected).count()
+    }
+
+    /// 다른 선택은 유지한 채 노드 하나의 선택 여부를 뒤집는다. Shift 클릭으로 여러 노드를 고를 때 쓴다.
+    ///
+    /// 새로 고른 노드가 속성 패널에 표시되고, 표시 중인 노드를 빼면 남은 선택 노드 중 첫 번째를 표시한다.
+    pub fn toggle_node_selection(&mut self, id: &str) {
+        let Some(node) = self.node_mut(id) else {
+            return;
+        };
+        node.selected = !node.selected;
+        let primary = if node.selected {
+            Some(id.to_string())
+        } else if self.selected_node_id.as_deref() == Some(id) {
+            self.nodes
+                .iter()
+                .find(|node| node.selected)
+                .map(|node| node.id.clone())
+        } else {
+            self.selected_node_id.clone()
+        };
+        if self.selected_node_id != primary {
+            self.id_draft = None;
+        }
+        self.selected_node_id = primary;
+    }
+
+    /// 노드 끌기를 시작한다. 맞춤 전 위치를 따로 누적해야 맞춤 지점에서 벗어날 수 있다.
+    pub fn begin_node_drag(&mut self, id: &str) {
+        self.node_drag = self.node(id).map(|node| (node.id.clone(), node.position));
+    }
+
+    /// 끌고 있는 노드를 `delta`만큼 옮기고 그리드·정렬 가이드에 맞춘다.
+    ///
+    /// 끌고 있는 노드가 선택된 노드이면 다른 선택 노드도 같은 거리만큼 함께 옮긴다. 정렬 가이드는
+    /// 함께 움직이지 않는 노드의 왼쪽·가운데·오른쪽(위·가운데·아래)을 기준으로 하며, 축마다 가이드에
+    /// 붙지 않았을 때만 그리드에 맞춘다.
+    ///
+    /// # 반환값
+    /// 이번 위치에서 붙은 정렬 가이드 목록.
+    pub fn drag_node(&mut self, id: &str, delta: egui::Vec2) -> Vec<AlignGuide> {
+        let Some((drag_id, raw)) = &mut self.node_drag else {
+            return Vec::new();
+        };
+        if drag_id.as_str() != id {
+            return Vec::new();
+        }
+        *raw += delta;
+        let raw = *raw;
+        let Some(node) = self.node(id) else {
+            return Vec::new();
+        };
+        let (size, current, group) = (node.size, node.position, node.selected);
+        let others: Vec<egui::Rect> = self
+            .nodes
+            .iter()
+            .filter(|other| other.id != id && !(group && other.selected))
+            .map(|other| egui::Rect::from_min_size(other.position, other.size))
+            .collect();
+        let mut guides = Vec::new();
+        let mut target = raw;
+        let aligned_x = self
+            .align_guides
+            .then(|| snap_to_edges(raw.x, size.x, others.iter().map(|r| (r.min.x, r.max.x))))
+            .flatten();
+        match aligned_x {
+            Some((x, line)) => {
+                target.x = x;
+                guides.push(AlignGuide::Vertical(line));
+            }
+            None if self.snap_to_grid => target.x = snap_to_grid(raw.x),
+            None => {}
+        }
+        let aligned_y = self
+            .align_guides
+            .then(|| snap_to_edges(raw.y, size.y, others.iter().map(|r| (r.min.y, r.max.y))))
+            .flatten();
+        match aligned_y {
+            Some((y, line)) => {
+                target.y = y;
+                guides.push(AlignGuide::Horizontal(line));
+            }
+            None if self.snap_to_grid => target.y = snap_to_grid(raw.y),
+            None => {}
+        }
+        let offset = target - current;
+        if offset != egui::Vec2::ZERO {
+            for node in &mut self.nodes {
+                if node.id == id || (group && node.selected) {
+                    node.position += offset;
+                }
+            }
+            self.dirty = true;
+        }
+        guides
+    }
+
+    /// 노드 끌기를 마친다.
+    pub fn end_node_drag(&mut self) {
+        self.node_drag = None;
+    }
+
+    /// 선택한 노드 사이의 간격을 고르게 맞춘다. 양 끝 노드는 그대로 두고 가운데 노드만 옮긴다.
+    ///
+    /// 간격은 노드 가장자리 사이 거리이므로 크기가 다른 노드도 빈 공간이 같아진다. 세 개 미만이면
+    /// 아무것도 하지 않는다.
+    pub fn distribute_selected(&mut self, axis: DistributeAxis) {
+        let along = |pos: egui::Pos2| match axis {
+            DistributeAxis::Horizontal => pos.x,
+            DistributeAxis::Vertical => pos.y,
+        };
+        let extent = |size: egui::Vec2| match axis {
+            DistributeAxis::Horizontal => size.x,
+            DistributeAxis::Vertical => size.y,
+        };
+        let mut selected: Vec<usize> = (0..self.nodes.len())
+            .filter(|idx| self.nodes[*idx].selected)
+            .collect();
+        if selected.len() < 3 {
+            return;
+        }
+        selected.sort_by(|a, b| {
+            along(self.nodes[*a].position).total_cmp(&along(self.nodes[*b].position))
+        });
+        let first = &self.nodes[selected[0]];
+        let last = &self.nodes[selected[selected.len() - 1]];
+        let start = along(first.position);
+        let span = along(last.position) + extent(last.size) - start;
+        let occupied: f32 = selected
+            .iter()
+            .map(|idx| extent(self.nodes[*idx].size))
+            .sum();
+        let gap = (span - occupied) / (selected.len() - 1) as f32;
+        let mut cursor = start;
+        for idx in selected {
+            let node = &mut self.nodes[idx];
+            match axis {
+                DistributeAxis::Horizontal => node.position.x = cursor,
+                DistributeAxis::Vertical => node.position.y = cursor,
+            }
+            cursor += extent(node.size) + gap;
+        }
+        self.dirty = true;
+    }
+}
+
+/// 한 축에서 시작 좌표가 `start`이고 길이가 `length`인 노드를 다른 노드 가장자리·중심에 맞춘다.
+///
+/// # 반환값
+/// 붙을 곳이 있으면 `(맞춘 시작 좌표, 가이드 선 좌표)`.
+fn snap_to_edges(
+    start: f32,
+    length: f32,
+    others: impl Iterator<Item = (f32, f32)>,
+) -> Option<(f32, f32)> {
+    let anchors = [0.0, length / 2.0, length];
+    let mut best: Option<(f32, f32, f32)> = None;
+    for (min, max) in others {
+        for line in [min, (min + max) / 2.0, max] {
+            for anchor in anchors {
+                let distance = (start + anchor - line).abs();
+                if distance <= ALIGN_SNAP_DISTANCE
+                    && best.is_none_or(|(closest, _, _)| distance < closest)
+                {
+                    best = Some((distance, line - anchor, line));
+                }
+            }
+        }
+    }
+    best.map(|(_, snapped, line)| (snapped, line))
+}
+
+/// 좌표를 가장 가까운 그리드 선에 맞춘다.
+fn snap_to_grid(value: f32) -> f32 {
+    (value / GRID_SPACING).round() * GRID_SPACING
+}
@@ -0,0 +1,293 @@
+use crate::i18n::tf;
+use crate::scenario::{
+    DB_VAR_FIELDS, DbConnectionConfig, RUN_TMPDIR_VAR, STEP_TMPDIR_VAR, Step, StepKind, db_var,
+};
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use super::connection::EditorConnection;
+use super::db::DbConnectionEditor;
+use super::identity::COPY_OFFSET;
+use super::state::ScenarioEditorState;
+use super::step::{EditorStepConfig, EditorStepNode};
+
+/// 시스템 클립보드로 주고받는 Step 묶음이다.
+///
+/// 다른 시나리오에 붙여 넣어도 바로 실행할 수 있도록 Step이 쓰는 DB 연결 정의를 함께 담는다. 클립보드
+/// 기록에 남지 않도록 비밀번호는 뺀다.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StepClipboard {
+    /// 복사한 Step. `depends_on`은 복사한 Step끼리의 의존만 남긴다.
+    steps: Vec<Step>,
+    /// Step이 쓰는 DB 연결 정의(비밀번호 제외).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    db: BTreeMap<String, DbConnectionConfig>,
+}
+
+/// Step 붙여넣기 결과이다.
+#[derive(Debug, Clone, Default)]
+pub struct StepPasteReport {
+    /// 추가한 노드 ID 목록.
+    pub added: Vec<String>,
+    /// 함께 옮겨 새로 추가한 DB 연결 키. 공유 연결 참조가 아니면 비밀번호를 다시 입력해야 한다.
+    pub added_db: Vec<String>,
+    /// 같은 키가 이미 있지만 정의가 달라 현재 시나리오의 정의를 유지한 DB 연결 키.
+    pub conflicting_db: Vec<String>,
+    /// 빌더가 편집할 수 없는 종류(`dummy` 등)라 옮기지 못한 DB 연결별 오류 메시지.
+    pub skipped_db: Vec<String>,
+    /// 붙여 넣은 Step이 읽지만(`consumes`) 시나리오 어디에서도 제공하지 않는 변수.
+    pub missing_vars: Vec<String>,
+}
+
+impl StepPasteReport {
+    /// 빌더에 보여 줄 안내 문구를 만든다. 알릴 내용이 없으면 `None`이다.
+    pub fn notice(&self) -> Option<String> {
+        let mut lines = Vec::new();
+        if !self.added_db.is_empty() {
+            lines.push(tf(
+                "builder.paste.added_db",
+                &[("names", &self.added_db.join(", "))],
+            ));
+        }
+        if !self.conflicting_db.is_empty() {
+            lines.push(tf(
+                "builder.paste.conflicting_db",
+                &[("names", &self.conflicting_db.join(", "))],
+            ));
+        }
+        if !self.skipped_db.is_empty() {
+            lines.push(tf(
+                "builder.paste.skipped_db",
+                &[("reasons", &self.skipped_db.join("; "))],
+            ));
+        }
+        if !self.missing_vars.is_empty() {
+            lines.push(tf(
+                "builder.paste.missing_vars",
+                &[("names", &self.missing_vars.join(", "))],
+            ));
+        }
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+}
+
+impl ScenarioEditorState {
+    /// 선택한 노드(Shift 클릭으로 여러 개 고른 경우 모두)를 클립보드에 넣을 YAML로 만든다.
+    ///
+    /// 선택한 노드끼리의 연결은 `depends_on`으로 남기고, 노드가 쓰는 DB 연결 정의를 비밀번호를 빼고 함께
+    /// 담는다. 선택한 노드가 없거나 Step으로 바꿀 수 없는 노드가 있으면 `None`이다.
+    pub fn copy_selected_steps_yaml(&self) -> Option<String> {
+        let selected: Vec<&EditorStepNode> = self
+            .nodes
+            .iter()
+            .filter(|node| node.selected || self.selected_node_id.as_deref() == Some(&node.id))
+            .collect();
+        if selected.is_empty() {
+            return None;
+        }
+        let ids: HashSet<&str> = selected.iter().map(|node| node.id.as_str()).collect();
+        let steps = selected
+            .iter()
+            .map(|node| {
+                let depends_on = self
+                    .dependencies_of(&node.id)
+                    .into_iter()
+                    .filter(|dep| ids.contains(dep.as_str()))
+                    .collect();
+                node.to_scenario_step(depends_on)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        let mut keys = BTreeSet::new();
+        collect_db_keys(&steps, &mut keys);
+        let db = self
+            .db_connections
+            .iter()
+            .filter(|conn| keys.contains(conn.key.trim()))
+            .map(|conn| {
+                let mut config = conn.to_config();
+                config.password = None;
+                (conn.key.trim().to_string(), config)
+            })
+            .collect();
+        serde_yaml::to_string(&StepClipboard { steps, db }).ok()
+    }
+
+    /// 클립보드 텍스트의 Step을 노드로 붙여 넣고 마지막 노드를 선택한다.
+    ///
+    /// 빌더에서 복사한 Step 묶음뿐 아니라 시나리오 파일에서 복사한 Step 정의(하나 또는 목록)도 받는다.
+    /// 붙여 넣은 Step끼리의 `depends_on`만 연결로 옮기며, ID가 겹치면 접미사를 붙인다. 묶음에 담긴 DB
+    /// 연결은 현재 시나리오에 없는 키만 추가하며, 빌더가 편집할 수 없는 종류라 추가하지 못한 연결은
+    /// 결과에 남긴다. Step으로 해석할 수 없는 텍스트면 아무것도 하지 않는다.
+    pub fn paste_steps_yaml(&mut self, text: &str) -> StepPasteReport {
+        let clipboard = match serde_yaml::from_str::<StepClipboard>(text) {
+            Ok(clipboard) => clipboard,
+            Err(_) => match serde_yaml::from_str::<Step>(text) {
+                Ok(step) => StepClipboard {
+                    steps: vec![step],
+                    db: BTreeMap::new(),
+                },
+                Err(_) => match serde_yaml::from_str::<Vec<Step>>(text) {
+                    Ok(steps) => StepClipboard {
+                        steps,
+                        db: BTreeMap::new(),
+                    },
+                    Err(_) => return StepPasteReport::default(),
+                },
+            },
+        };
+        let mut report = StepPasteReport::default();
+        if clipboard.steps.is_empty() {
+            return report;
+        }
+        for (key, config) in &clipboard.db {
+            match self
+                .db_connections
+                .iter()
+                .find(|conn| conn.key.trim() == key)
+            {
+                Some(existing) => {
+                    let mut current = existing.to_config();
+                    current.password = None;
+                    if current != *config {
+                        report.conflicting_db.push(key.clone());
+                    }
+                }
+                None => match DbConnectionEditor::from_config(key, config) {
+                    Ok(conn) => {
+                        self.db_connections.push(conn);
+                        report.added_db.push(key.clone());
+                    }
+                    Err(err) => report.skipped_db.push(err.to_string()),
+                },
+            }
+        }
+        report.missing_vars = self.missing_vars_for(&clipboard.steps);
+        let steps = clipboard.steps;
+        let origin = self
+            .selected_node_id
+            .as_deref()
+            .and_then(|id| self.node(id))
+            .map(|node| node.position)
+            .unwrap_or(egui::pos2(80.0, 80.0));
+        let mut connections = Vec::new();
+        let nodes = steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| {
+                for dep in &step.depends_on {
+                    if steps.iter().any(|other| other.id == *dep) {
+                        connections.push(EditorConnection {
+                            from_id: dep.clone(),
+                            to_id: step.id.clone(),
+                        });
+                    }
+                }
+                let mut node = EditorStepNode::from_scenario_step(step);
+                node.position = origin + COPY_OFFSET * (idx + 1) as f32;
+                node
+            })
+            .collect();
+        report.added = self.insert_node_copies(nodes, connections);
+        report
+    }
+
+    /// 붙여 넣을 Step이 읽는 변수 중 시나리오 파라미터, DB 연결 변수, 실행 기본 변수, 기존 노드나 붙여 넣을
+    /// Step이 제공하는 변수 어디에도 없는 것을 모은다.
+    ///
+    /// 붙여 넣은 노드는 아직 연결이 없으므로 상위 Step 여부는 따지지 않는다.
+    fn missing_vars_for(&self, steps: &[Step]) -> Vec<String> {
+        let mut available: HashSet<String> = self.params.keys().cloned().collect();
+        available.insert(RUN_TMPDIR_VAR.to_string());
+        available.insert(STEP_TMPDIR_VAR.to_string());
+        for conn in &self.db_connections {
+            let key = conn.key.trim();
+            available.extend(DB_VAR_FIELDS.iter().map(|field| db_var(key, field)));
+        }
+        let outer = self.loop_stack.iter().flat_map(|frame| frame.nodes());
+        for node in self.nodes.iter().chain(outer) {
+            collect_node_vars(node, &mut available);
+        }
+        // Loop 캔버스에 붙여 넣으면 열린 Loop들의 변수도 쓸 수 있다.
+        for frame in &self.loop_stack {
+            if let Some(EditorStepConfig::Loop { config }) = frame
+                .nodes()
+                .iter()
+                .find(|node| node.id == frame.loop_id)
+                .map(|node| &node.config)
+            {
+                available.insert(config.as_var.clone());
+            }
+        }
+        collect_step_vars(steps, &mut available);
+        let mut missing = BTreeSet::new();
+        collect_missing_vars(steps, &available, &mut missing);
+        missing.into_iter().collect()
+    }
+}
+
+/// Step(Loop 내부 포함)이 쓰는 DB 키를 모은다. 대상 DB를 적지 않은 SQL은 `default`를 쓴다.
+fn collect_db_keys(steps: &[Step], keys: &mut BTreeSet<String>) {
+    for step in steps {
+        if matches!(step.kind, StepKind::Sql { .. } | StepKind::SqlFile { .. }) {
+            keys.insert(step.kind.target_db().unwrap_or("default").to_string());
+        }
+        if let Some(skip_if) = &step.skip_if
+            && skip_if.sql_returns_rows.is_some()
+        {
+            keys.insert(
+                skip_if
+                    .target_db
+                    .as_deref()
+                    .unwrap_or("default")
+                    .to_string(),
+            );
+        }
+        if let StepKind::Loop { config } = &step.kind {
+            collect_db_keys(&config.steps, keys);
+        }
+    }
+}
+
+/// 노드(Loop 하위 포함)가 제공하는 변수를 모은다.
+fn collect_node_vars(node: &EditorStepNode, vars: &mut HashSet<String>) {
+    vars.extend(node.produced_vars().into_iter().map(str::to_string));
+    if let EditorStepConfig::Loop { config } = &node.config {
+        for child in &config.nodes {
+            collect_node_vars(child, vars);
+        }
+    }
+}
+
+/// Step(Loop 내부 포함)이 제공하는 변수를 모은다.
+fn collect_step_vars(steps: &[Step], vars: &mut HashSet<String>) {
+    for step in steps {
+        vars.extend(step.produced_vars().into_iter().map(str::to_string));
+        if let StepKind::Loop { config } = &step.kind {
+            collect_step_vars(&config.steps, vars);
+        }
+    }
+}
+
+/// Step(Loop 내부 포함)의 `consumes` 중 `available`에 없는 변수를 모은다. Loop 내부는 Loop 변수도 쓸 수 있다.
+fn collect_missing_vars(
+    steps: &[Step],
+    available: &HashSet<String>,
+    missing: &mut BTreeSet<String>,
+) {
+    for step in steps {
+        missing.extend(
+            step.consumes
+                .iter()
+                .filter(|var| !available.contains(var.as_str()))
+                .cloned(),
+        );
+        if let StepKind::Loop { config } = &step.kind {
+            let mut inner = available.clone();
+            inner.insert(config.as_var.clone());
+            collect_missing_vars(&config.steps, &inner, missing);
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use crate::engine::DagScheduler;
+use std::collections::HashSet;
+
+use super::step::EditorStepNode;
+
 /// 노드 간의 방향성 연결을 표현한다.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EditorConnection {
@@ -6,3 +11,43 @@ pub struct EditorConnection {
     /// 의존성을 갖는 노드 ID.
     pub to_id: String,
 }
+
+/// 노드·연결 목록을 실행과 같은 [`DagScheduler`]로 옮긴다.
+///
+/// 노드 순서가 Step 순서이며, 목록에 없는 노드를 잇는 연결은 무시한다.
+pub(super) fn node_scheduler(
+    nodes: &[EditorStepNode],
+    connections: &[EditorConnection],
+) -> DagScheduler {
+    let ids: HashSet<&str> = nodes.iter().map(|node| node.id.as_str()).collect();
+    DagScheduler::from_dependencies(nodes.iter().map(|node| {
+        let deps = connections
+            .iter()
+            .filter(|conn| conn.to_id == node.id && ids.contains(conn.from_id.as_str()))
+            .map(|conn| conn.from_id.clone())
+            .collect();
+        (node.id.clone(), deps)
+    }))
+}
+
+/// 모든 노드가 성공한다고 가정하고 실행과 같은 규칙으로 시작하는 순서대로 노드 ID를 나열한다.
+///
+/// 연결이 순환해 끝까지 시작하지 못하는 노드가 있으면 `None`을 반환한다.
+pub(crate) fn execution_order<'n>(
+    nodes: &'n [EditorStepNode],
+    connections: &[EditorConnection],
+) -> Option<Vec<&'n str>> {
+    let mut scheduler = node_scheduler(nodes, connections);
+    let mut order = Vec::with_capacity(nodes.len());
+    loop {
+        let ready = scheduler.take_ready();
+        if ready.is_empty() {
+            break;
+        }
+        for idx in ready {
+            scheduler.mark_succeeded(&nodes[idx].id);
+            order.push(nodes[idx].id.as_str());
+        }
+    }
+    scheduler.take_unreachable().is_empty().then_some(order)
+}
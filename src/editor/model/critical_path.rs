@@ -0,0 +1,124 @@
+use crate::history::StepDurationStats;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::connection::{EditorConnection, execution_order};
+use super::step::EditorStepNode;
+
+/// 단축 효과를 보여 줄 최대 Step 수이다.
+pub const CRITICAL_PATH_TOP_STEPS: usize = 5;
+
+/// 과거 평균 소요 시간으로 계산한 DAG 크리티컬 패스 분석 결과이다.
+#[derive(Debug, Clone, Default)]
+pub struct CriticalPathAnalysis {
+    /// 시작부터 끝까지 가장 오래 걸리는 경로의 Step ID 목록(실행 순서).
+    pub path: Vec<String>,
+    /// 크리티컬 패스 길이로 계산한 예상 총 소요 시간.
+    pub total: Duration,
+    /// 소요 시간을 0으로 줄였을 때 총 소요 시간이 가장 많이 줄어드는 Step과 그 단축 시간(큰 순).
+    pub bottlenecks: Vec<(String, Duration)>,
+    /// 실행 이력이 없어 0초로 계산한 Step ID 목록.
+    pub unmeasured: Vec<String>,
+}
+
+impl CriticalPathAnalysis {
+    /// 노드·연결 목록과 Step별 소요 시간 통계로 크리티컬 패스를 계산한다.
+    ///
+    /// 연결이 순환하면 경로를 정할 수 없으므로 `None`을 반환한다.
+    ///
+    /// # 매개변수
+    /// - `nodes`: 캔버스 노드 목록.
+    /// - `connections`: 노드 사이 의존 연결 목록.
+    /// - `stats`: 실행 이력으로 계산한 Step별 소요 시간 통계.
+    pub fn new(
+        nodes: &[EditorStepNode],
+        connections: &[EditorConnection],
+        stats: &HashMap<String, StepDurationStats>,
+    ) -> Option<Self> {
+        let order = execution_order(nodes, connections)?;
+        let mut durations: HashMap<&str, Duration> = HashMap::new();
+        let mut unmeasured = Vec::new();
+        for node in nodes {
+            match stats.get(&node.id) {
+                Some(stat) => {
+                    durations.insert(node.id.as_str(), stat.avg);
+                }
+                None => {
+                    durations.insert(node.id.as_str(), Duration::ZERO);
+                    unmeasured.push(node.id.clone());
+                }
+            }
+        }
+        let (total, path) = longest_path(&order, connections, &durations);
+        let mut bottlenecks: Vec<(String, Duration)> = path
+            .iter()
+            .filter_map(|id| {
+                let saved = std::mem::take(durations.get_mut(id.as_str())?);
+                let (shortened, _) = longest_path(&order, connections, &durations);
+                if let Some(slot) = durations.get_mut(id.as_str()) {
+                    *slot = saved;
+                }
+                let saving = total.saturating_sub(shortened);
+                (!saving.is_zero()).then(|| (id.clone(), saving))
+            })
+            .collect();
+        bottlenecks.sort_by(|a, b| b.1.cmp(&a.1));
+        bottlenecks.truncate(CRITICAL_PATH_TOP_STEPS);
+        Some(Self {
+            path,
+            total,
+            bottlenecks,
+            unmeasured,
+        })
+    }
+
+    /// 노드가 크리티컬 패스에 있는지 확인한다.
+    pub fn contains_node(&self, id: &str) -> bool {
+        self.path.iter().any(|step| step == id)
+    }
+
+    /// 연결이 크리티컬 패스를 이루는 간선인지 확인한다.
+    pub fn contains_edge(&self, conn: &EditorConnection) -> bool {
+        self.path
+            .windows(2)
+            .any(|pair| pair[0] == conn.from_id && pair[1] == conn.to_id)
+    }
+}
+
+/// 위상 순서대로 각 노드의 가장 늦은 종료 시각을 구해 가장 긴 경로와 그 길이를 반환한다.
+fn longest_path(
+    order: &[&str],
+    connections: &[EditorConnection],
+    durations: &HashMap<&str, Duration>,
+) -> (Duration, Vec<String>) {
+    let mut finish: HashMap<&str, Duration> = HashMap::new();
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    for &id in order {
+        let mut start = Duration::ZERO;
+        for conn in connections.iter().filter(|conn| conn.to_id == id) {
+            if let Some(&end) = finish.get(conn.from_id.as_str())
+                && (end > start || !previous.contains_key(id))
+            {
+                start = end;
+                previous.insert(id, conn.from_id.as_str());
+            }
+        }
+        let duration = durations.get(id).copied().unwrap_or_default();
+        finish.insert(id, start + duration);
+    }
+    let Some((&last, &total)) = order
+        .iter()
+        .filter_map(|id| finish.get_key_value(id))
+        .max_by_key(|(_, end)| **end)
+    else {
+        return (Duration::ZERO, Vec::new());
+    };
+    let mut path = vec![last.to_string()];
+    let mut current = last;
+    while let Some(&prev) = previous.get(current) {
+        path.push(prev.to_string());
+        current = prev;
+    }
+    path.reverse();
+    (total, path)
+}
@@ -1,4 +1,6 @@
-use crate::scenario::DbKind;
+use crate::scenario::{DbConnectionConfig, DbKind};
+
+use super::error::EditorError;
 
 /// Scenario Builder에서 편집 가능한 DB 연결 정보를 저장한다.
 #[derive(Debug, Clone)]
@@ -13,6 +15,8 @@ pub struct DbConnectionEditor {
     pub user: String,
     /// 접속 비밀번호이다.
     pub password: String,
+    /// 참조하는 공유 연결 이름이다. 지정하면 나머지 접속 정보는 사용하지 않는다.
+    pub db_ref: Option<String>,
 }
 
 impl DbConnectionEditor {
@@ -24,6 +28,55 @@ impl DbConnectionEditor {
             dsn: String::new(),
             user: String::new(),
             password: String::new(),
+            db_ref: None,
+        }
+    }
+
+    /// 시나리오 YAML의 DB 정의로 편집 항목을 만든다.
+    ///
+    /// 공유 연결 참조가 아니면서 빌더가 편집할 수 없는 종류(oracle/postgres 외)면 오류를 반환한다.
+    pub fn from_config(key: &str, config: &DbConnectionConfig) -> Result<Self, EditorError> {
+        if let Some(name) = &config.db_ref {
+            let mut conn = Self::new(key.to_string(), DbKind::Oracle);
+            conn.db_ref = Some(name.clone());
+            return Ok(conn);
+        }
+        match &config.kind {
+            DbKind::Oracle | DbKind::Postgres => Ok(Self {
+                key: key.to_string(),
+                kind: config.kind.clone(),
+                dsn: config.dsn.clone().unwrap_or_default(),
+                user: config.user.clone().unwrap_or_default(),
+                password: config.password.clone().unwrap_or_default(),
+                db_ref: None,
+            }),
+            other => Err(EditorError::UnsupportedDbKind {
+                key: key.to_string(),
+                kind: format!("{:?}", other),
+            }),
+        }
+    }
+
+    /// 편집 항목을 시나리오 YAML의 DB 정의로 바꾼다. 공백뿐인 항목은 생략한다.
+    pub fn to_config(&self) -> DbConnectionConfig {
+        if let Some(name) = self.db_ref.as_deref() {
+            return DbConnectionConfig::reference(name.trim());
+        }
+        DbConnectionConfig {
+            kind: self.kind.clone(),
+            dsn: optional_string(&self.dsn),
+            user: optional_string(&self.user),
+            password: optional_string(&self.password),
+            db_ref: None,
         }
     }
 }
+
+/// 공백 문자열을 None으로 변환한다.
+fn optional_string(value: &str) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
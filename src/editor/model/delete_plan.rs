@@ -0,0 +1,68 @@
+use super::connection::EditorConnection;
+
+/// 노드를 삭제하기 전에 연결에 미치는 영향을 계산한 결과이다.
+#[derive(Debug, Clone)]
+pub struct NodeDeletePlan {
+    /// 삭제할 노드 ID.
+    pub node_id: String,
+    /// 삭제할 노드가 의존하던 선행 노드 ID 목록.
+    pub predecessors: Vec<String>,
+    /// 삭제할 노드에 의존하던 후행 노드 ID 목록.
+    pub successors: Vec<String>,
+    /// 연결을 잇지 않고 삭제하면 선행 의존성이 모두 사라지는 후행 노드 ID 목록.
+    pub orphaned: Vec<String>,
+}
+
+impl NodeDeletePlan {
+    /// 연결 목록을 기준으로 삭제 영향도를 계산한다.
+    ///
+    /// # 매개변수
+    /// - `node_id`: 삭제할 노드 ID.
+    /// - `connections`: 노드가 속한 캔버스의 연결 목록.
+    pub fn new(node_id: &str, connections: &[EditorConnection]) -> Self {
+        let predecessors: Vec<String> = connections
+            .iter()
+            .filter(|conn| conn.to_id == node_id)
+            .map(|conn| conn.from_id.clone())
+            .collect();
+        let successors: Vec<String> = connections
+            .iter()
+            .filter(|conn| conn.from_id == node_id)
+            .map(|conn| conn.to_id.clone())
+            .collect();
+        let orphaned = successors
+            .iter()
+            .filter(|succ| {
+                connections
+                    .iter()
+                    .filter(|conn| &conn.to_id == *succ)
+                    .all(|conn| conn.from_id == node_id)
+            })
+            .cloned()
+            .collect();
+        Self {
+            node_id: node_id.to_string(),
+            predecessors,
+            successors,
+            orphaned,
+        }
+    }
+
+    /// 선행 노드와 후행 노드를 이어 줄 수 있는지 여부를 반환한다.
+    pub fn can_bridge(&self) -> bool {
+        !self.predecessors.is_empty() && !self.successors.is_empty()
+    }
+
+    /// 각 선행 노드를 각 후행 노드에 잇는 연결 목록을 반환한다.
+    pub fn bridge_connections(&self) -> Vec<EditorConnection> {
+        self.predecessors
+            .iter()
+            .flat_map(|from| {
+                self.successors.iter().map(|to| EditorConnection {
+                    from_id: from.clone(),
+                    to_id: to.clone(),
+                })
+            })
+            .collect()
+    }
+}
@@ -1,22 +1,59 @@
+use crate::i18n::{t, tf};
+use std::fmt;
+
 /// 에디터 작업 중 발생 가능한 오류를 표현한다.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug)]
 pub enum EditorError {
+    /// 시나리오 이름이 비어 있는 경우이다.
+    EmptyScenarioName,
+    /// Step ID가 비어 있는 경우이다.
+    EmptyStepId,
     /// Step ID가 중복된 경우이다.
-    #[error("중복된 Step ID가 존재합니다: {0}")]
     DuplicateStepId(String),
     /// 존재하지 않는 노드를 참조하는 연결이다.
-    #[error("존재하지 않는 노드를 참조하는 연결입니다: {from_id} -> {to_id}")]
     MissingNode { from_id: String, to_id: String },
     /// 순환 의존성이 감지된 경우이다.
-    #[error("순환 의존성이 감지되었습니다. 연결 구성을 확인하세요.")]
     CyclicDependency,
     /// 지원하지 않는 DB 종류가 사용된 경우이다.
-    #[error("지원하지 않는 DB 종류입니다: {kind} (키: {key})")]
     UnsupportedDbKind { key: String, kind: String },
     /// DB 키 이름이 비어 있는 경우이다.
-    #[error("DB 키 이름이 비어 있습니다.")]
     EmptyDbKey,
     /// DB 키가 중복된 경우이다.
-    #[error("DB 키 이름이 중복되었습니다: {0}")]
     DuplicateDbKey(String),
+    /// 공유 연결 참조 이름이 비어 있는 경우이다.
+    EmptyDbRef(String),
+    /// 실행 시간 창 시각 형식이 잘못된 경우이다.
+    InvalidTimeWindow { step_id: String, message: String },
 }
+
+impl fmt::Display for EditorError {
+    /// 현재 언어로 검증 오류를 출력한다.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            EditorError::EmptyScenarioName => t("editor.error.empty_scenario_name").to_string(),
+            EditorError::EmptyStepId => t("editor.error.empty_step_id").to_string(),
+            EditorError::DuplicateStepId(id) => tf("editor.error.duplicate_step_id", &[("id", id)]),
+            EditorError::MissingNode { from_id, to_id } => tf(
+                "editor.error.missing_node",
+                &[("from", from_id), ("to", to_id)],
+            ),
+            EditorError::CyclicDependency => t("editor.error.cyclic").to_string(),
+            EditorError::UnsupportedDbKind { key, kind } => tf(
+                "editor.error.unsupported_db_kind",
+                &[("kind", kind), ("key", key)],
+            ),
+            EditorError::EmptyDbKey => t("editor.error.empty_db_key").to_string(),
+            EditorError::DuplicateDbKey(key) => {
+                tf("editor.error.duplicate_db_key", &[("key", key)])
+            }
+            EditorError::EmptyDbRef(key) => tf("editor.error.empty_db_ref", &[("key", key)]),
+            EditorError::InvalidTimeWindow { step_id, message } => tf(
+                "editor.error.invalid_time_window",
+                &[("step", step_id), ("message", message)],
+            ),
+        };
+        f.write_str(&text)
+    }
+}
+
+impl std::error::Error for EditorError {}
@@ -0,0 +1,149 @@
+use crate::i18n::tf;
+use eframe::egui;
+use std::collections::{HashMap, HashSet};
+
+use super::connection::EditorConnection;
+use super::error::EditorError;
+use super::state::ScenarioEditorState;
+use super::step::{EditorStepConfig, EditorStepNode};
+
+/// 복제하거나 붙여 넣은 노드를 원본에서 띄워 놓는 거리이다.
+pub(super) const COPY_OFFSET: egui::Vec2 = egui::vec2(30.0, 30.0);
+
+impl ScenarioEditorState {
+    /// Loop 하위 노드를 포함한 모든 Step ID 집합을 반환한다.
+    ///
+    /// 실행 컨텍스트의 Step 결과가 ID로 구분되므로 Loop 안팎을 통틀어 ID가 겹치면 안 된다.
+    /// 캔버스로 연 Loop가 있으면 보관해 둔 상위 흐름의 노드도 포함한다.
+    pub fn all_step_ids(&self) -> HashSet<String> {
+        let mut ids = HashSet::new();
+        let outer = self.loop_stack.iter().flat_map(|frame| frame.nodes());
+        for node in self.nodes.iter().chain(outer) {
+            collect_node_ids(node, &mut ids);
+        }
+        ids
+    }
+
+    /// `current` 노드의 ID를 `candidate`로 바꿀 수 있는지 검사한다.
+    pub fn validate_step_id(&self, current: &str, candidate: &str) -> Result<(), EditorError> {
+        if candidate.trim().is_empty() {
+            return Err(EditorError::EmptyStepId);
+        }
+        if candidate != current && self.all_step_ids().contains(candidate) {
+            return Err(EditorError::DuplicateStepId(candidate.to_string()));
+        }
+        Ok(())
+    }
+
+    /// 노드 ID를 바꾸고 그 노드를 참조하는 연결과 선택 상태를 한 번에 갱신한다.
+    ///
+    /// 새 ID가 비었거나 다른 Step과 겹치면 아무것도 바꾸지 않고 오류를 반환한다.
+    pub fn rename_node(&mut self, old: &str, new: &str) -> Result<(), EditorError> {
+        if old == new {
+            self.id_draft = None;
+            return Ok(());
+        }
+        self.validate_step_id(old, new)?;
+        let Some(node) = self.node_mut(old) else {
+            return Err(EditorError::MissingNode {
+                from_id: old.to_string(),
+                to_id: new.to_string(),
+            });
+        };
+        node.id = new.to_string();
+        for conn in &mut self.connections {
+            if conn.from_id == old {
+                conn.from_id = new.to_string();
+            }
+            if conn.to_id == old {
+                conn.to_id = new.to_string();
+            }
+        }
+        if self.selected_node_id.as_deref() == Some(old) {
+            self.selected_node_id = Some(new.to_string());
+        }
+        if self.pending_connection.as_deref() == Some(old) {
+            self.pending_connection = Some(new.to_string());
+        }
+        self.id_draft = None;
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// 노드를 복제해 원본 옆에 추가하고 선택한다. 연결은 복제하지 않는다.
+    ///
+    /// # 반환값
+    /// 복제한 노드의 ID. 원본이 없으면 `None`이다.
+    pub fn duplicate_node(&mut self, id: &str) -> Option<String> {
+        let mut copy = self.node(id)?.clone();
+        copy.name = tf("builder.copy_name", &[("name", &copy.name)]);
+        copy.position += COPY_OFFSET;
+        Some(self.insert_node_copies(vec![copy], Vec::new()).remove(0))
+    }
+
+    /// 노드 사본을 ID가 겹치지 않게 바꿔 추가하고 마지막 노드를 선택한다.
+    ///
+    /// Loop 하위 노드 ID도 함께 바꾸며, `connections`는 바뀐 ID로 옮겨 추가한다.
+    pub(super) fn insert_node_copies(
+        &mut self,
+        nodes: Vec<EditorStepNode>,
+        connections: Vec<EditorConnection>,
+    ) -> Vec<String> {
+        let mut taken = self.all_step_ids();
+        let mut renamed: HashMap<String, String> = HashMap::new();
+        let mut added = Vec::with_capacity(nodes.len());
+        for mut node in nodes {
+            // 사본은 조각 파일과 무관한 일반 Step이 된다.
+            node.included_from = None;
+            let id = unique_step_id(&node.id, &taken);
+            taken.insert(id.clone());
+            renamed.insert(node.id.clone(), id.clone());
+            node.id = id.clone();
+            node.selected = false;
+            if let EditorStepConfig::Loop { config } = &mut node.config {
+                config.reassign_child_ids(&mut taken);
+            }
+            self.nodes.push(node);
+            added.push(id);
+        }
+        for conn in connections {
+            if let (Some(from), Some(to)) = (renamed.get(&conn.from_id), renamed.get(&conn.to_id)) {
+                self.add_connection(from, to);
+            }
+        }
+        self.select_node(added.last().cloned());
+        self.dirty = true;
+        added
+    }
+}
+
+/// `base`가 `taken`에 없으면 그대로, 있으면 끝의 `_숫자`를 떼고 가장 작은 `_2`, `_3`... 접미사를 붙인다.
+pub fn unique_step_id(base: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(base) {
+        return base.to_string();
+    }
+    let stem = match base.rsplit_once('_') {
+        Some((stem, suffix))
+            if !stem.is_empty()
+                && !suffix.is_empty()
+                && suffix.chars().all(|ch| ch.is_ascii_digit()) =>
+        {
+            stem
+        }
+        _ => base,
+    };
+    (2..)
+        .map(|idx| format!("{stem}_{idx}"))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or_default()
+}
+
+/// 노드와 Loop 하위 노드의 ID를 모은다.
+fn collect_node_ids(node: &EditorStepNode, ids: &mut HashSet<String>) {
+    ids.insert(node.id.clone());
+    if let EditorStepConfig::Loop { config } = &node.config {
+        for child in &config.nodes {
+            collect_node_ids(child, ids);
+        }
+    }
+}
@@ -3,8 +3,11 @@ use eframe::egui;
 use std::collections::HashSet;
 
 use super::connection::EditorConnection;
+use super::delete_plan::NodeDeletePlan;
 use super::error::EditorError;
-use super::step::EditorStepNode;
+use super::identity::unique_step_id;
+use super::step::{EditorStepConfig, EditorStepNode};
+use super::trash::DeletedStep;
 
 /// Loop 하위 흐름을 표현하는 구성체이다.
 #[derive(Debug, Clone)]
@@ -60,18 +63,47 @@ impl LoopEditorConfig {
     }
 
     /// 하위 노드용 고유 ID를 생성한다.
-    pub fn generate_child_id(&self) -> String {
+    ///
+    /// `taken`은 Loop 바깥을 포함한 시나리오 전체의 Step ID 집합이다.
+    pub fn generate_child_id(&self, taken: &HashSet<String>) -> String {
         let mut idx = 1;
         let ids: HashSet<&str> = self.nodes.iter().map(|n| n.id.as_str()).collect();
         loop {
             let candidate = format!("loop_step_{idx}");
-            if !ids.contains(candidate.as_str()) {
+            if !ids.contains(candidate.as_str()) && !taken.contains(&candidate) {
                 return candidate;
             }
             idx += 1;
         }
     }
 
+    /// 하위 노드 ID를 `taken`과 겹치지 않게 바꾸고 하위 연결과 선택 상태도 함께 옮긴다.
+    ///
+    /// 새로 쓴 ID는 `taken`에 추가한다. 중첩 Loop도 같은 방식으로 바꾼다.
+    pub fn reassign_child_ids(&mut self, taken: &mut HashSet<String>) {
+        for child in &mut self.nodes {
+            let id = unique_step_id(&child.id, taken);
+            taken.insert(id.clone());
+            if id != child.id {
+                for conn in &mut self.connections {
+                    if conn.from_id == child.id {
+                        conn.from_id = id.clone();
+                    }
+                    if conn.to_id == child.id {
+                        conn.to_id = id.clone();
+                    }
+                }
+                if self.selected_node_id.as_deref() == Some(child.id.as_str()) {
+                    self.selected_node_id = Some(id.clone());
+                }
+                child.id = id;
+            }
+            if let EditorStepConfig::Loop { config } = &mut child.config {
+                config.reassign_child_ids(taken);
+            }
+        }
+    }
+
     /// 하위 노드를 조회한다.
     pub fn node_mut(&mut self, id: &str) -> Option<&mut EditorStepNode> {
         self.nodes.iter_mut().find(|node| node.id == id)
@@ -112,13 +144,27 @@ impl LoopEditorConfig {
     }
 
     /// 노드를 제거하고 연결을 정리한다.
-    pub fn remove_node(&mut self, id: &str) {
-        self.nodes.retain(|node| node.id != id);
-        self.connections
-            .retain(|conn| conn.from_id != id && conn.to_id != id);
+    ///
+    /// # 반환값
+    /// 휴지통에 넣을 삭제한 노드와 연결. Loop 경로는 비어 있다.
+    pub fn remove_node(&mut self, id: &str) -> Option<DeletedStep> {
         if self.selected_node_id.as_deref() == Some(id) {
             self.selected_node_id = None;
         }
+        DeletedStep::take(&mut self.nodes, &mut self.connections, id)
+    }
+
+    /// 하위 노드를 삭제했을 때의 연결 영향도를 계산한다.
+    pub fn delete_plan(&self, id: &str) -> NodeDeletePlan {
+        NodeDeletePlan::new(id, &self.connections)
+    }
+
+    /// 선행 노드와 후행 노드를 서로 이은 뒤 하위 노드를 제거한다.
+    pub fn remove_node_bridged(&mut self, id: &str) -> Option<DeletedStep> {
+        for conn in self.delete_plan(id).bridge_connections() {
+            self.add_connection(&conn.from_id, &conn.to_id);
+        }
+        self.remove_node(id)
     }
 
     /// Loop 구성을 Scenario 구조로 변환한다.
@@ -0,0 +1,117 @@
+use eframe::egui;
+use std::borrow::Cow;
+
+use super::connection::EditorConnection;
+use super::state::ScenarioEditorState;
+use super::step::{EditorStepConfig, EditorStepNode};
+
+/// Loop 하위 캔버스로 들어갈 때 보관해 두는 상위 흐름이다.
+#[derive(Debug, Clone)]
+pub struct LoopFrame {
+    /// 연 Loop 노드 ID.
+    pub loop_id: String,
+    /// 연 Loop 노드 이름. 경로 표시에 쓴다.
+    pub loop_name: String,
+    /// 상위 흐름의 노드 목록. 연 Loop 노드의 하위 노드는 캔버스로 옮겨 비어 있다.
+    nodes: Vec<EditorStepNode>,
+    /// 상위 흐름의 연결 목록.
+    connections: Vec<EditorConnection>,
+    /// 상위 흐름에서 선택했던 노드 ID.
+    selected_node_id: Option<String>,
+    /// 상위 흐름의 캔버스 오프셋.
+    canvas_offset: egui::Vec2,
+    /// 상위 흐름의 캔버스 줌 비율.
+    canvas_zoom: f32,
+}
+
+impl LoopFrame {
+    /// 보관한 상위 흐름의 노드 목록을 반환한다.
+    pub fn nodes(&self) -> &[EditorStepNode] {
+        &self.nodes
+    }
+}
+
+impl ScenarioEditorState {
+    /// Loop 노드의 하위 흐름을 캔버스로 열고 현재 흐름은 경로에 보관한다.
+    ///
+    /// 캔버스·목록·속성 패널은 열린 Loop의 하위 노드를 그대로 편집하며, [`Self::close_loop`]로
+    /// 돌아갈 때 Loop 구성에 되돌려 놓는다.
+    ///
+    /// # 반환값
+    /// Loop 노드가 아니어서 열지 못하면 `false`.
+    pub fn open_loop(&mut self, id: &str) -> bool {
+        let Some(node) = self.nodes.iter_mut().find(|node| node.id == id) else {
+            return false;
+        };
+        let loop_name = node.name.clone();
+        let EditorStepConfig::Loop { config } = &mut node.config else {
+            return false;
+        };
+        let children = std::mem::take(&mut config.nodes);
+        let child_connections = std::mem::take(&mut config.connections);
+        let child_selected = config.selected_node_id.take();
+        self.loop_stack.push(LoopFrame {
+            loop_id: id.to_string(),
+            loop_name,
+            nodes: std::mem::replace(&mut self.nodes, children),
+            connections: std::mem::replace(&mut self.connections, child_connections),
+            selected_node_id: self.selected_node_id.take(),
+            canvas_offset: self.canvas_offset,
+            canvas_zoom: self.canvas_zoom,
+        });
+        self.reset_level_view();
+        self.select_node(child_selected);
+        true
+    }
+
+    /// 열린 Loop 하위 흐름을 Loop 구성에 되돌려 놓고 상위 흐름으로 돌아간다.
+    ///
+    /// # 반환값
+    /// 열린 Loop가 없으면 `false`.
+    pub fn close_loop(&mut self) -> bool {
+        let Some(frame) = self.loop_stack.pop() else {
+            return false;
+        };
+        let children = std::mem::replace(&mut self.nodes, frame.nodes);
+        let child_connections = std::mem::replace(&mut self.connections, frame.connections);
+        let child_selected = self.selected_node_id.take();
+        if let Some(node) = self.node_mut(&frame.loop_id)
+            && let EditorStepConfig::Loop { config } = &mut node.config
+        {
+            config.nodes = children;
+            config.connections = child_connections;
+            config.selected_node_id = child_selected;
+        }
+        self.reset_level_view();
+        self.canvas_offset = frame.canvas_offset;
+        self.canvas_zoom = frame.canvas_zoom;
+        self.select_node(frame.selected_node_id);
+        true
+    }
+
+    /// 열린 Loop 경로가 `depth` 단계가 될 때까지 상위 흐름으로 돌아간다. 0이면 최상위 흐름이다.
+    pub fn close_loops_to(&mut self, depth: usize) {
+        while self.loop_stack.len() > depth && self.close_loop() {}
+    }
+
+    /// 열린 Loop를 모두 닫은 최상위 상태를 반환한다. 열린 Loop가 없으면 복사하지 않는다.
+    pub fn at_root(&self) -> Cow<'_, Self> {
+        if self.loop_stack.is_empty() {
+            return Cow::Borrowed(self);
+        }
+        let mut root = self.clone();
+        root.close_loops_to(0);
+        Cow::Owned(root)
+    }
+
+    /// 흐름을 바꿀 때 이전 흐름에만 의미 있는 캔버스·목록 상태를 초기화한다.
+    fn reset_level_view(&mut self) {
+        self.canvas_offset = egui::vec2(0.0, 0.0);
+        self.canvas_zoom = 1.0;
+        self.pending_connection = None;
+        self.node_drag = None;
+        self.canvas_menu_pos = None;
+        self.list_sort = None;
+        self.list_drag = None;
+    }
+}
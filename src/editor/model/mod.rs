@@ -1,13 +1,39 @@
+mod arrange;
+mod clipboard;
 mod connection;
+mod critical_path;
 mod db;
+mod delete_plan;
 mod error;
+mod identity;
 mod loop_config;
+mod loop_nav;
+mod order_simulation;
+mod schema_browser;
+mod shell_trial;
+mod sql_preview;
 mod state;
 mod step;
+mod step_test;
+mod trash;
 
+pub use arrange::{AlignGuide, DistributeAxis, GRID_SPACING};
 pub use connection::EditorConnection;
+pub(crate) use connection::execution_order;
+pub use critical_path::{CRITICAL_PATH_TOP_STEPS, CriticalPathAnalysis};
 pub use db::DbConnectionEditor;
+pub use delete_plan::NodeDeletePlan;
 pub use error::EditorError;
 pub use loop_config::LoopEditorConfig;
-pub use state::ScenarioEditorState;
+pub use order_simulation::{
+    ORDER_SIM_DEFAULT_STEP, OrderSimEntry, OrderSimPlayback, OrderSimulation,
+};
+pub use schema_browser::{SchemaBrowser, SchemaFetch, SchemaRequest};
+pub use shell_trial::{ShellTrial, ShellTrialStatus};
+pub use sql_preview::{SqlPreview, SqlPreviewStatus};
+pub use state::{
+    BuilderViewMode, DataFlowHint, ScenarioEditorState, StepListSort, default_scenario_name,
+};
 pub use step::{EditorStepConfig, EditorStepNode, StepKind};
+pub use step_test::{STEP_TEST_MAX_LOG_LINES, StepTest, StepTestStatus};
+pub use trash::{DeletedStep, TRASH_CAPACITY};
@@ -0,0 +1,339 @@
+use crate::engine::DagScheduler;
+use crate::history::StepDurationStats;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use super::connection::{EditorConnection, node_scheduler};
+use super::step::EditorStepNode;
+
+/// 실행 이력이 없는 Step에 가정하는 소요 시간이다.
+pub const ORDER_SIM_DEFAULT_STEP: Duration = Duration::from_secs(1);
+
+/// 엔진이 Step을 시작하는 순서를 실제 실행 없이 재현한 결과이다.
+///
+/// 엔진의 [`DagScheduler`]로 선행 Step이 모두 성공한 Step을 노드 순서대로 한 번에 꺼내, `allow_parallel`이 아닌
+/// Step을 먼저 하나씩 끝까지 실행한 뒤 병렬 Step을 함께 시작한다. 꺼낼 Step이 없으면 먼저 끝나는
+/// 병렬 Step을 기다린다. 소요 시간은 실행 이력 평균이며, 이력이 없으면 [`ORDER_SIM_DEFAULT_STEP`]이다.
+#[derive(Debug, Clone, Default)]
+pub struct OrderSimulation {
+    /// Step ID별 시뮬레이션 결과. 순환 연결 등으로 끝까지 도달하지 못한 Step은 없다.
+    pub entries: HashMap<String, OrderSimEntry>,
+    /// 시작하거나 건너뛰는 시점의 수. 재생 위치는 `0..ticks` 범위의 시점 번호이다.
+    pub ticks: usize,
+    /// 마지막 Step이 끝나는 예상 시각.
+    pub total: Duration,
+    /// 실행 이력이 없어 기본 소요 시간으로 계산한 Step 수.
+    pub unmeasured: usize,
+    /// 끝까지 시작하지 못한 Step ID 목록(노드 순서).
+    pub unreached: Vec<String>,
+}
+
+/// Step 하나의 시뮬레이션 결과이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderSimEntry {
+    /// 시작 순번(1부터). 건너뛴 Step은 `None`이다.
+    pub order: Option<usize>,
+    /// 시작하거나 건너뛰는 시점 번호.
+    pub tick: usize,
+    /// 시작하거나 건너뛰는 예상 시각.
+    pub at: Duration,
+    /// 시작할 때 실행 중인 다른 Step이 있거나 다른 Step과 함께 시작했는지 여부.
+    pub parallel: bool,
+    /// 실패를 가정한 Step인지 여부.
+    pub failed: bool,
+    /// 선행 Step 실패로 건너뛰는지 여부.
+    pub blocked: bool,
+}
+
+/// 빌더 캔버스의 실행 순서 시뮬레이션 재생 상태이다. 저장하지 않는다.
+#[derive(Debug, Clone, Default)]
+pub struct OrderSimPlayback {
+    /// 재생 위치(시점 번호). 소수 부분은 다음 시점까지의 진행률이다.
+    pub playhead: f32,
+    /// 자동 재생 중인지 여부.
+    pub playing: bool,
+    /// 실패한다고 가정한 Step ID.
+    pub failures: HashSet<String>,
+}
+
+impl OrderSimPlayback {
+    /// 처음부터 자동 재생하는 상태를 만든다.
+    pub fn new() -> Self {
+        Self {
+            playing: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl OrderSimulation {
+    /// 노드·연결 목록과 Step별 소요 시간 통계로 시작 순서를 시뮬레이션한다.
+    ///
+    /// # 매개변수
+    /// - `nodes`: 캔버스 노드 목록. 이 순서가 시나리오의 Step 순서이다.
+    /// - `connections`: 노드 사이 의존 연결 목록.
+    /// - `stats`: 실행 이력으로 계산한 Step별 소요 시간 통계.
+    /// - `failures`: 실패한다고 가정할 Step ID. 그 하위 Step은 건너뛴다.
+    pub fn new(
+        nodes: &[EditorStepNode],
+        connections: &[EditorConnection],
+        stats: &HashMap<String, StepDurationStats>,
+        failures: &HashSet<String>,
+    ) -> Self {
+        let durations: Vec<Duration> = nodes
+            .iter()
+            .map(|node| {
+                stats
+                    .get(&node.id)
+                    .map_or(ORDER_SIM_DEFAULT_STEP, |stat| stat.avg)
+            })
+            .collect();
+        let unmeasured = nodes
+            .iter()
+            .filter(|node| !stats.contains_key(&node.id))
+            .count();
+
+        let mut sim = Simulator {
+            nodes,
+            scheduler: node_scheduler(nodes, connections),
+            failures,
+            entries: HashMap::new(),
+            next_order: 1,
+        };
+        let mut now = Duration::ZERO;
+        let mut total = Duration::ZERO;
+        let mut running: Vec<(Duration, usize)> = Vec::new();
+        loop {
+            let batch = sim.scheduler.take_ready();
+            if batch.is_empty() {
+                let Some(pos) = (0..running.len()).min_by_key(|&pos| running[pos]) else {
+                    break;
+                };
+                let (end, idx) = running.remove(pos);
+                now = now.max(end);
+                sim.finish(idx, now);
+                continue;
+            }
+            let (parallel, sequential): (Vec<usize>, Vec<usize>) = batch
+                .into_iter()
+                .partition(|&idx| nodes[idx].allow_parallel);
+            for idx in sequential {
+                let overlaps = running.iter().any(|&(end, _)| end > now);
+                sim.start(idx, now, overlaps);
+                now += durations[idx];
+                total = total.max(now);
+                sim.finish(idx, now);
+            }
+            let concurrent = parallel.len() > 1 || running.iter().any(|&(end, _)| end > now);
+            for idx in parallel {
+                sim.start(idx, now, concurrent);
+                let end = now + durations[idx];
+                total = total.max(end);
+                running.push((end, idx));
+            }
+        }
+
+        let mut moments: Vec<Duration> = sim.entries.values().map(|entry| entry.at).collect();
+        moments.sort();
+        moments.dedup();
+        for entry in sim.entries.values_mut() {
+            entry.tick = moments.binary_search(&entry.at).unwrap_or_default();
+        }
+        let unreached = sim.scheduler.take_unreachable();
+        Self {
+            entries: sim.entries,
+            ticks: moments.len(),
+            total,
+            unmeasured,
+            unreached,
+        }
+    }
+
+    /// 시작하는 Step 수를 반환한다.
+    pub fn started_count(&self) -> usize {
+        self.entries
+            .values()
+            .filter(|entry| entry.order.is_some())
+            .count()
+    }
+
+    /// 다른 Step과 실행 시간이 겹치는 Step 수를 반환한다.
+    pub fn parallel_count(&self) -> usize {
+        self.entries.values().filter(|entry| entry.parallel).count()
+    }
+
+    /// 선행 Step 실패로 건너뛰는 Step 수를 반환한다.
+    pub fn blocked_count(&self) -> usize {
+        self.entries.values().filter(|entry| entry.blocked).count()
+    }
+}
+
+/// 엔진의 [`DagScheduler`]로 다음 Step을 고르며 시뮬레이션 결과를 모은다.
+struct Simulator<'n> {
+    /// 캔버스 노드 목록.
+    nodes: &'n [EditorStepNode],
+    /// 실제 실행과 같은 규칙으로 준비된 노드를 고르는 스케줄러.
+    scheduler: DagScheduler,
+    /// 실패를 가정한 Step ID.
+    failures: &'n HashSet<String>,
+    /// Step ID별 결과.
+    entries: HashMap<String, OrderSimEntry>,
+    /// 다음 시작 순번.
+    next_order: usize,
+}
+
+impl Simulator<'_> {
+    /// 노드 시작을 기록한다.
+    fn start(&mut self, idx: usize, at: Duration, parallel: bool) {
+        let id = &self.nodes[idx].id;
+        self.entries.insert(
+            id.clone(),
+            OrderSimEntry {
+                order: Some(self.next_order),
+                tick: 0,
+                at,
+                parallel,
+                failed: self.failures.contains(id),
+                blocked: false,
+            },
+        );
+        self.next_order += 1;
+    }
+
+    /// 노드 종료를 스케줄러에 반영한다. 실패를 가정했으면 스케줄러가 돌려준 하위 노드를 건너뜀으로
+    /// 기록한다.
+    fn finish(&mut self, idx: usize, at: Duration) {
+        let id = &self.nodes[idx].id;
+        if !self.failures.contains(id) {
+            self.scheduler.mark_succeeded(id);
+            return;
+        }
+        for blocked in self.scheduler.mark_failed(id) {
+            self.entries.insert(
+                blocked,
+                OrderSimEntry {
+                    order: None,
+                    tick: 0,
+                    at,
+                    parallel: false,
+                    failed: false,
+                    blocked: true,
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::step::StepKind;
+    use super::*;
+
+    fn node(id: &str, allow_parallel: bool) -> EditorStepNode {
+        let mut node = EditorStepNode::new(id.to_string(), id.to_string(), StepKind::Shell);
+        node.allow_parallel = allow_parallel;
+        node
+    }
+
+    fn conn(from: &str, to: &str) -> EditorConnection {
+        EditorConnection {
+            from_id: from.to_string(),
+            to_id: to.to_string(),
+        }
+    }
+
+    fn secs(durations: &[(&str, u64)]) -> HashMap<String, StepDurationStats> {
+        durations
+            .iter()
+            .map(|&(id, secs)| {
+                let avg = Duration::from_secs(secs);
+                (
+                    id.to_string(),
+                    StepDurationStats {
+                        runs: 1,
+                        successes: 1,
+                        min: avg,
+                        max: avg,
+                        avg,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn order(sim: &OrderSimulation, id: &str) -> Option<usize> {
+        sim.entries[id].order
+    }
+
+    #[test]
+    fn sequential_steps_follow_dependencies_then_node_order() {
+        let nodes = [node("c", false), node("a", false), node("b", false)];
+        let sim = OrderSimulation::new(
+            &nodes,
+            &[conn("a", "c")],
+            &secs(&[("a", 2), ("b", 3), ("c", 4)]),
+            &HashSet::new(),
+        );
+        assert_eq!(order(&sim, "a"), Some(1));
+        assert_eq!(order(&sim, "b"), Some(2));
+        assert_eq!(order(&sim, "c"), Some(3));
+        assert_eq!(sim.entries["c"].at, Duration::from_secs(5));
+        assert_eq!(sim.total, Duration::from_secs(9));
+        assert_eq!(sim.parallel_count(), 0);
+        assert_eq!(sim.unmeasured, 0);
+    }
+
+    #[test]
+    fn parallel_steps_start_together_and_wait_for_the_first_to_finish() {
+        let nodes = [node("p1", true), node("p2", true), node("after", false)];
+        let sim = OrderSimulation::new(
+            &nodes,
+            &[conn("p1", "after")],
+            &secs(&[("p1", 2), ("p2", 5), ("after", 1)]),
+            &HashSet::new(),
+        );
+        assert_eq!(sim.entries["p1"].at, Duration::ZERO);
+        assert_eq!(sim.entries["p2"].at, Duration::ZERO);
+        assert!(sim.entries["p1"].parallel && sim.entries["p2"].parallel);
+        assert_eq!(sim.entries["after"].at, Duration::from_secs(2));
+        assert!(sim.entries["after"].parallel);
+        assert_eq!(sim.total, Duration::from_secs(5));
+        assert_eq!(sim.ticks, 2);
+    }
+
+    #[test]
+    fn failure_blocks_transitive_dependents_only() {
+        let nodes = [
+            node("a", false),
+            node("b", false),
+            node("c", false),
+            node("d", false),
+        ];
+        let failures = HashSet::from(["a".to_string()]);
+        let sim = OrderSimulation::new(
+            &nodes,
+            &[conn("a", "b"), conn("b", "c")],
+            &HashMap::new(),
+            &failures,
+        );
+        assert!(sim.entries["a"].failed);
+        assert!(sim.entries["b"].blocked && sim.entries["c"].blocked);
+        assert_eq!(order(&sim, "d"), Some(2));
+        assert_eq!(sim.blocked_count(), 2);
+        assert_eq!(sim.started_count(), 2);
+        assert_eq!(sim.unmeasured, 4);
+    }
+
+    #[test]
+    fn cycles_are_reported_as_unreached() {
+        let nodes = [node("a", false), node("b", false), node("c", false)];
+        let sim = OrderSimulation::new(
+            &nodes,
+            &[conn("a", "b"), conn("b", "a"), conn("a", "b")],
+            &HashMap::new(),
+            &HashSet::new(),
+        );
+        assert_eq!(sim.unreached, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(order(&sim, "c"), Some(1));
+    }
+}
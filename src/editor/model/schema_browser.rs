@@ -0,0 +1,92 @@
+use crate::executor::ColumnInfo;
+use std::collections::HashMap;
+
+/// 빌더 스키마 브라우저 상태이다. 앱이 요청을 실행하고 결과를 채우며 저장하지 않는다.
+///
+/// 테이블 목록은 창을 열거나 DB 타겟을 바꿀 때, 컬럼 목록은 테이블을 펼칠 때 처음 한 번만 조회한다.
+#[derive(Debug, Clone)]
+pub struct SchemaBrowser {
+    /// 조회할 DB 타겟 키.
+    pub target_db: String,
+    /// 테이블 이름 필터(대소문자 구분 없음).
+    pub filter: String,
+    /// 테이블·뷰 이름 목록.
+    pub tables: SchemaFetch<Vec<String>>,
+    /// 펼친 테이블별 컬럼 목록.
+    pub columns: HashMap<String, SchemaFetch<Vec<ColumnInfo>>>,
+}
+
+/// 스키마 메타데이터 조회의 진행 상태이다.
+#[derive(Debug, Clone)]
+pub enum SchemaFetch<T> {
+    /// 조회가 필요하고 아직 시작하지 않았다.
+    Requested,
+    /// 조회 중이다.
+    Running,
+    /// 조회가 끝났다.
+    Done(T),
+    /// 조회에 실패했다.
+    Failed(String),
+}
+
+/// 앱이 실행할 스키마 조회 요청이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaRequest {
+    /// 테이블 목록.
+    Tables,
+    /// 테이블 하나의 컬럼 목록.
+    Columns(String),
+}
+
+impl SchemaBrowser {
+    /// DB 타겟의 테이블 목록 조회를 요청한 상태로 만든다.
+    pub fn new(target_db: impl Into<String>) -> Self {
+        Self {
+            target_db: target_db.into(),
+            filter: String::new(),
+            tables: SchemaFetch::Requested,
+            columns: HashMap::new(),
+        }
+    }
+
+    /// DB 타겟을 바꾸거나 같은 타겟을 다시 읽도록 조회 결과를 모두 버린다.
+    pub fn reload(&mut self, target_db: impl Into<String>) {
+        self.target_db = target_db.into();
+        self.tables = SchemaFetch::Requested;
+        self.columns.clear();
+    }
+
+    /// 테이블 컬럼 목록을 아직 요청하지 않았으면 요청한다.
+    pub fn request_columns(&mut self, table: &str) {
+        self.columns
+            .entry(table.to_string())
+            .or_insert(SchemaFetch::Requested);
+    }
+
+    /// 아직 시작하지 않은 요청 하나를 진행 중으로 바꾸고 반환한다. 테이블 목록을 먼저 조회한다.
+    pub fn start_next_request(&mut self) -> Option<SchemaRequest> {
+        if matches!(self.tables, SchemaFetch::Requested) {
+            self.tables = SchemaFetch::Running;
+            return Some(SchemaRequest::Tables);
+        }
+        let (table, fetch) = self
+            .columns
+            .iter_mut()
+            .find(|(_, fetch)| matches!(fetch, SchemaFetch::Requested))?;
+        *fetch = SchemaFetch::Running;
+        Some(SchemaRequest::Columns(table.clone()))
+    }
+
+    /// 필터에 맞는 테이블 이름을 반환한다. 목록을 아직 받지 못했으면 비어 있다.
+    pub fn filtered_tables(&self) -> Vec<&str> {
+        let SchemaFetch::Done(tables) = &self.tables else {
+            return Vec::new();
+        };
+        let filter = self.filter.trim().to_lowercase();
+        tables
+            .iter()
+            .filter(|table| filter.is_empty() || table.to_lowercase().contains(&filter))
+            .map(String::as_str)
+            .collect()
+    }
+}
@@ -0,0 +1,67 @@
+/// 속성 패널에서 요청한 Shell Step 스크립트의 로컬 테스트 실행이다.
+#[derive(Debug, Clone)]
+pub struct ShellTrial {
+    /// 테스트할 Step ID.
+    pub step_id: String,
+    /// 스크립트가 참조하는 변수와 치환할 더미 값.
+    pub vars: Vec<(String, String)>,
+    /// 진행 상태.
+    pub status: ShellTrialStatus,
+}
+
+/// Shell 로컬 테스트의 진행 상태이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShellTrialStatus {
+    /// 더미 값을 입력받는 중이다.
+    Idle,
+    /// 버튼을 눌러 요청했고 아직 실행을 시작하지 않았다.
+    Requested,
+    /// 실행 중이다.
+    Running,
+    /// 프로세스가 끝났다.
+    Done {
+        /// 종료 코드. 시그널로 끝나 코드가 없으면 `None`이다.
+        exit_code: Option<i32>,
+        /// 표준 출력과 표준 오류.
+        output: String,
+    },
+    /// 실행하지 못했다.
+    Failed(String),
+}
+
+impl ShellTrial {
+    /// 새 로컬 테스트 상태를 만든다.
+    pub fn new(step_id: &str) -> Self {
+        Self {
+            step_id: step_id.to_string(),
+            vars: Vec::new(),
+            status: ShellTrialStatus::Idle,
+        }
+    }
+
+    /// 더미 값 목록을 스크립트가 참조하는 변수 목록에 맞춘다.
+    ///
+    /// 이미 입력한 값은 유지하고, 새 변수는 `fallback`이 주는 값(없으면 `dummy`)으로 채운다.
+    pub fn sync_vars(&mut self, names: &[String], fallback: impl Fn(&str) -> Option<String>) {
+        let mut vars = Vec::with_capacity(names.len());
+        for name in names {
+            let value = self
+                .vars
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .or_else(|| fallback(name))
+                .unwrap_or_else(|| "dummy".to_string());
+            vars.push((name.clone(), value));
+        }
+        self.vars = vars;
+    }
+
+    /// 실행을 요청했거나 실행 중인지 확인한다.
+    pub fn is_running(&self) -> bool {
+        matches!(
+            self.status,
+            ShellTrialStatus::Requested | ShellTrialStatus::Running
+        )
+    }
+}
@@ -0,0 +1,39 @@
+use crate::executor::QueryRows;
+
+/// 속성 패널에서 요청한 SQL Step 조회 결과 미리보기이다.
+#[derive(Debug, Clone)]
+pub struct SqlPreview {
+    /// 미리보기를 요청한 Step ID.
+    pub step_id: String,
+    /// 조회할 DB 타겟. `None`이면 기본 DB이다.
+    pub target_db: Option<String>,
+    /// 조회할 SQL.
+    pub sql: String,
+    /// 진행 상태.
+    pub status: SqlPreviewStatus,
+}
+
+/// SQL 미리보기의 진행 상태이다.
+#[derive(Debug, Clone)]
+pub enum SqlPreviewStatus {
+    /// 버튼을 눌러 요청했고 아직 실행을 시작하지 않았다.
+    Requested,
+    /// 조회 중이다.
+    Running,
+    /// 조회가 끝났다.
+    Done(QueryRows),
+    /// 조회에 실패했다.
+    Failed(String),
+}
+
+impl SqlPreview {
+    /// 새 미리보기 요청을 만든다.
+    pub fn request(step_id: &str, target_db: Option<String>, sql: &str) -> Self {
+        Self {
+            step_id: step_id.to_string(),
+            target_db,
+            sql: sql.to_string(),
+            status: SqlPreviewStatus::Requested,
+        }
+    }
+}
@@ -1,21 +1,116 @@
+use crate::history::StepDurationStats;
+use crate::i18n::{t, tf};
+use crate::scenario::{
+    CostCheckConfig, DbConnectionConfig, ExecutionProfile, FailureDiagnostic, OnFailureConfig,
+    ParamRule, RemoteHostConfig, RunTmpdirConfig, StepDefaults, StepInclude, SummaryQuery,
+    TriggerConfig,
+};
+use crate::sql_changes::{DiffLine, SqlFileChange};
 use eframe::egui;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
 
 use super::connection::EditorConnection;
 use super::db::DbConnectionEditor;
-use super::step::{EditorStepNode, StepKind};
+use super::delete_plan::NodeDeletePlan;
+use super::loop_nav::LoopFrame;
+use super::order_simulation::OrderSimPlayback;
+use super::schema_browser::SchemaBrowser;
+use super::shell_trial::ShellTrial;
+use super::sql_preview::SqlPreview;
+use super::step::{EditorStepConfig, EditorStepNode, StepKind};
+use super::step_test::StepTest;
+use super::trash::DeletedStep;
+
+/// 새 시나리오의 기본 이름을 현재 언어로 반환한다.
+pub fn default_scenario_name() -> &'static str {
+    t("editor.default_scenario_name")
+}
+
+/// 빌더 중앙 영역의 보기 방식이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderViewMode {
+    /// 노드를 자유롭게 배치하는 그래프 캔버스.
+    Canvas,
+    /// 정렬과 순서 변경이 가능한 Step 목록.
+    List,
+}
+
+impl Default for BuilderViewMode {
+    /// 기본 보기는 캔버스이다.
+    fn default() -> Self {
+        BuilderViewMode::Canvas
+    }
+}
+
+/// Step 목록 보기의 정렬 기준이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepListSort {
+    /// Step ID 순.
+    Id,
+    /// Step 이름 순.
+    Name,
+    /// Step 유형 순. 팔레트 순서를 따른다.
+    Kind,
+}
 
 /// 시나리오 에디터 전체 상태를 저장한다.
 #[derive(Debug, Clone)]
 pub struct ScenarioEditorState {
+    /// 시나리오 이름. 비어 있으면 저장할 수 없다.
+    pub scenario_name: String,
     /// 노드 목록.
     pub nodes: Vec<EditorStepNode>,
     /// 연결 목록.
     pub connections: Vec<EditorConnection>,
     /// 전역 DB 연결 구성 목록.
     pub db_connections: Vec<DbConnectionEditor>,
+    /// 앱 공용 연결 저장소(`connections.yaml`)의 항목. 시나리오 파일에는 저장하지 않는다.
+    pub shared_connections: BTreeMap<String, DbConnectionConfig>,
+    /// 공유 연결 저장소를 파일에 다시 기록해야 하는지 여부.
+    pub shared_connections_changed: bool,
+    /// 원격 실행 호스트 정의. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub remotes: HashMap<String, RemoteHostConfig>,
+    /// 시나리오 파라미터. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub params: HashMap<String, String>,
+    /// 비밀 시나리오 파라미터 이름. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub secret_params: BTreeSet<String>,
+    /// 파라미터 검증 규칙. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub param_rules: BTreeMap<String, ParamRule>,
+    /// 시나리오 공통 Step 기본값. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub step_defaults: Option<StepDefaults>,
+    /// Step별 로그 제한값. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub log_lines_per_sec: Option<u32>,
+    /// 로그 한 줄 길이 제한값. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub log_line_max_bytes: Option<usize>,
+    /// 느린 SQL의 실행 계획을 남길 기준 시간(초). 빌더에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub explain_slow_sql_sec: Option<u64>,
+    /// 실행별 임시 디렉터리 설정. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub run_tmpdir: Option<RunTmpdirConfig>,
+    /// 실패 시 정리 Step 구성. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub on_failure: Option<OnFailureConfig>,
+    /// 결과 요약 조회 목록. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub summary: Vec<SummaryQuery>,
+    /// 실패 진단 명령 목록. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub failure_diagnostics: Vec<FailureDiagnostic>,
+    /// 실행 프로필. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub profiles: BTreeMap<String, ExecutionProfile>,
+    /// 실행 탭에서 고른 실행 프로필 이름. 저장할 때 `metadata`에 남겨 다시 열면 같은 프로필을 고른다.
+    pub selected_profile: Option<String>,
+    /// 외부 트리거 파일 설정. 에디터에서는 편집하지 않고 저장 시 그대로 유지한다.
+    pub trigger: Option<TriggerConfig>,
+    /// 실행 전 비용 점검 설정. 검사 패널에서 경고를 확인하면 확인 목록이 바뀐다.
+    pub cost_check: Option<CostCheckConfig>,
+    /// 실행 이력으로 계산한 Step별 소요 시간 통계. 툴팁 표시용이며 저장하지 않는다.
+    pub step_stats: HashMap<String, StepDurationStats>,
+    /// 마지막 성공 실행 이후 내용이 바뀐 `sql_file` Step(Step ID별). 캔버스 배지 표시용이며 저장하지 않는다.
+    pub sql_changes: HashMap<String, SqlFileChange>,
+    /// 변경 내용 창을 연 `sql_file` Step ID와 계산해 둔 줄 단위 차이.
+    pub sql_diff: Option<(String, Vec<DiffLine>)>,
     /// 선택된 노드 ID.
     pub selected_node_id: Option<String>,
+    /// 속성 패널 ID 입력란에 입력했지만 비었거나 중복이라 적용하지 않은 `(노드 ID, 입력값)`.
+    pub id_draft: Option<(String, String)>,
     /// 현재 파일 경로.
     pub current_file: Option<std::path::PathBuf>,
     /// 캔버스 오프셋.
@@ -24,6 +119,45 @@ pub struct ScenarioEditorState {
     pub canvas_zoom: f32,
     /// 미완성 연결 시작 노드.
     pub pending_connection: Option<String>,
+    /// 캔버스에서 끌고 있는 노드 ID와 그리드·정렬 가이드에 맞추기 전 위치.
+    pub node_drag: Option<(String, egui::Pos2)>,
+    /// 캔버스에서 끄는 노드를 그리드에 맞출지 여부.
+    pub snap_to_grid: bool,
+    /// 캔버스에서 끄는 노드를 다른 노드의 가장자리·중심에 맞추고 가이드 선을 보일지 여부.
+    pub align_guides: bool,
+    /// 캔버스 우클릭 메뉴를 연 위치(캔버스 좌표). 메뉴에서 만든 노드를 이 위치에 놓는다.
+    pub canvas_menu_pos: Option<egui::Pos2>,
+    /// 다음 프레임에 속성 패널의 이름 입력란에 포커스를 줄지 여부.
+    pub focus_name_pending: bool,
+    /// 중앙 영역 보기 방식.
+    pub view_mode: BuilderViewMode,
+    /// 캔버스 노드를 스테이지별 색으로 칠할지 여부.
+    pub color_by_stage: bool,
+    /// 실행 이력 평균 소요 시간으로 크리티컬 패스를 분석해 캔버스에 강조할지 여부.
+    pub critical_path_mode: bool,
+    /// 캔버스에 FPS와 그리기 시간 오버레이를 표시할지 여부. 디버그용이며 저장하지 않는다.
+    pub perf_overlay: bool,
+    /// 캔버스에서 실행 순서 시뮬레이션을 재생 중이면 그 재생 상태. 저장하지 않는다.
+    pub order_sim: Option<OrderSimPlayback>,
+    /// 목록 보기의 마지막 정렬 기준과 역순 여부.
+    pub list_sort: Option<(StepListSort, bool)>,
+    /// 목록 보기에서 끌고 있는 행 인덱스.
+    pub list_drag: Option<usize>,
+    /// 캔버스로 연 Loop 경로(바깥부터). 비어 있으면 `nodes`가 최상위 흐름이고, 아니면 마지막 Loop의
+    /// 하위 흐름이다.
+    pub loop_stack: Vec<LoopFrame>,
+    /// 속성 패널에서 요청한 SQL Step 미리보기. 앱이 요청을 실행하고 결과를 채우며 저장하지 않는다.
+    pub sql_preview: Option<SqlPreview>,
+    /// 열려 있으면 SQL 편집기 옆 스키마 브라우저 상태. 앱이 조회를 실행하고 결과를 채우며 저장하지 않는다.
+    pub schema_browser: Option<SchemaBrowser>,
+    /// 속성 패널의 Shell 스크립트 로컬 테스트. 앱이 요청을 실행하고 결과를 채우며 저장하지 않는다.
+    pub shell_trial: Option<ShellTrial>,
+    /// 속성 패널에서 요청한 단일 Step 테스트. 앱이 실행하고 로그와 결과를 채우며 저장하지 않는다.
+    pub step_test: Option<StepTest>,
+    /// 마지막 Step 붙여넣기에서 알릴 내용(함께 추가한 DB 연결, 제공되지 않는 변수). 닫으면 지운다.
+    pub paste_notice: Option<String>,
+    /// 이 시나리오를 편집하면서 삭제한 Step(오래된 것부터). 저장하지 않는다.
+    pub trash: Vec<DeletedStep>,
     /// 저장되지 않은 변경 여부.
     pub dirty: bool,
 }
@@ -32,25 +166,69 @@ impl ScenarioEditorState {
     /// 빈 에디터 상태를 생성한다.
     pub fn new() -> Self {
         Self {
+            scenario_name: default_scenario_name().to_string(),
             nodes: Vec::new(),
             connections: Vec::new(),
             db_connections: Vec::new(),
+            shared_connections: BTreeMap::new(),
+            shared_connections_changed: false,
+            remotes: HashMap::new(),
+            params: HashMap::new(),
+            secret_params: BTreeSet::new(),
+            param_rules: BTreeMap::new(),
+            step_defaults: None,
+            log_lines_per_sec: None,
+            log_line_max_bytes: None,
+            explain_slow_sql_sec: None,
+            run_tmpdir: None,
+            on_failure: None,
+            summary: Vec::new(),
+            failure_diagnostics: Vec::new(),
+            profiles: BTreeMap::new(),
+            selected_profile: None,
+            trigger: None,
+            cost_check: None,
+            step_stats: HashMap::new(),
+            sql_changes: HashMap::new(),
+            sql_diff: None,
             selected_node_id: None,
+            id_draft: None,
             current_file: None,
             canvas_offset: egui::vec2(0.0, 0.0),
             canvas_zoom: 1.0,
             pending_connection: None,
+            node_drag: None,
+            snap_to_grid: false,
+            align_guides: true,
+            canvas_menu_pos: None,
+            focus_name_pending: false,
+            view_mode: BuilderViewMode::default(),
+            color_by_stage: false,
+            critical_path_mode: false,
+            perf_overlay: false,
+            order_sim: None,
+            list_sort: None,
+            list_drag: None,
+            loop_stack: Vec::new(),
+            sql_preview: None,
+            schema_browser: None,
+            shell_trial: None,
+            step_test: None,
+            paste_notice: None,
+            trash: Vec::new(),
             dirty: false,
         }
     }
 
     /// 고유한 Step ID를 생성한다.
+    ///
+    /// Loop 하위 노드를 포함한 모든 Step ID와 겹치지 않는다.
     pub fn generate_id(&self, prefix: &str) -> String {
         let mut idx = 1;
-        let ids: HashSet<&str> = self.nodes.iter().map(|n| n.id.as_str()).collect();
+        let ids = self.all_step_ids();
         loop {
             let candidate = format!("{prefix}_{idx}");
-            if !ids.contains(candidate.as_str()) {
+            if !ids.contains(&candidate) {
                 return candidate;
             }
             idx += 1;
@@ -59,28 +237,169 @@ impl ScenarioEditorState {
 
     /// 새 노드를 추가하고 선택한다.
     pub fn add_node(&mut self, kind: StepKind) -> String {
+        self.add_node_at(kind, egui::pos2(80.0, 80.0))
+    }
+
+    /// 새 노드를 지정한 캔버스 좌표에 추가하고 선택한다.
+    pub fn add_node_at(&mut self, kind: StepKind, position: egui::Pos2) -> String {
         let id = self.generate_id("step");
-        let mut node = EditorStepNode::new(id.clone(), format!("새 Step {id}"), kind);
-        node.position = egui::pos2(80.0, 80.0);
+        let mut node = EditorStepNode::new(
+            id.clone(),
+            tf("builder.new_step_name", &[("id", &id)]),
+            kind,
+        );
+        node.position = position;
+        if let Some(defaults) = &self.step_defaults {
+            node.apply_defaults(defaults);
+        }
         self.nodes.push(node);
         self.select_node(Some(id.clone()));
         self.dirty = true;
         id
     }
 
-    /// 지정된 노드를 제거한다.
+    /// 끌어다 놓은 파일로 Step을 만들어 지정한 캔버스 좌표에 추가하고 선택한다.
+    ///
+    /// `.sql`은 그 파일을 실행하는 SQL 파일 Step으로, `.ctl`은 그 파일을 컨트롤 파일로 쓰는
+    /// SQL*Loader Step으로 만들며, Step 이름은 파일 이름으로 한다.
+    ///
+    /// # 반환값
+    /// 추가한 Step ID. 지원하지 않는 확장자이면 아무것도 추가하지 않고 `None`을 반환한다.
+    pub fn add_file_node_at(&mut self, path: &Path, position: egui::Pos2) -> Option<String> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        let kind = match extension.as_str() {
+            "sql" => StepKind::SqlFile,
+            "ctl" => StepKind::SqlLoaderPar,
+            _ => return None,
+        };
+        let id = self.add_node_at(kind, position);
+        let node = self.node_mut(&id)?;
+        if let Some(name) = path.file_name() {
+            node.name = name.to_string_lossy().into_owned();
+        }
+        match &mut node.config {
+            EditorStepConfig::SqlFile { path: file, .. } => *file = path.to_path_buf(),
+            EditorStepConfig::SqlLoaderPar { config } => config.control_file = path.to_path_buf(),
+            _ => {}
+        }
+        Some(id)
+    }
+
+    /// 노드를 목록의 다른 위치로 옮긴다. 노드 순서가 저장되는 Step 순서이다.
+    pub fn move_node(&mut self, from: usize, to: usize) {
+        if from >= self.nodes.len() || from == to {
+            return;
+        }
+        let node = self.nodes.remove(from);
+        let to = to.min(self.nodes.len());
+        self.nodes.insert(to, node);
+        self.list_sort = None;
+        self.dirty = true;
+    }
+
+    /// 노드 순서를 지정한 기준으로 정렬한다. 같은 기준을 다시 고르면 역순으로 바꾼다.
+    pub fn sort_nodes(&mut self, key: StepListSort) {
+        let descending = self.list_sort == Some((key, false));
+        let kind_rank = |kind: StepKind| StepKind::ALL.iter().position(|item| *item == kind);
+        self.nodes.sort_by(|a, b| {
+            let ordering = match key {
+                StepListSort::Id => a.id.cmp(&b.id),
+                StepListSort::Name => a.name.cmp(&b.name),
+                StepListSort::Kind => kind_rank(a.kind).cmp(&kind_rank(b.kind)),
+            };
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        self.list_sort = Some((key, descending));
+        self.dirty = true;
+    }
+
+    /// 현재 노드 순서대로 앞 노드 → 다음 노드 의존성을 만들고 기존 연결은 모두 지운다.
+    ///
+    /// 캔버스에서도 순서가 드러나도록 노드를 세로 한 줄로 다시 배치한다.
+    pub fn chain_in_order(&mut self) {
+        self.connections = self
+            .nodes
+            .windows(2)
+            .map(|pair| EditorConnection {
+                from_id: pair[0].id.clone(),
+                to_id: pair[1].id.clone(),
+            })
+            .collect();
+        for (idx, node) in self.nodes.iter_mut().enumerate() {
+            node.position = egui::pos2(80.0, 80.0 + idx as f32 * 150.0);
+        }
+        self.dirty = true;
+    }
+
+    /// 지정된 노드를 제거하고 설정과 연결을 휴지통에 보관한다.
+    ///
+    /// 조각 파일에서 펼친 노드이면 [`Self::remove_include`]로 같은 `include` 항목의 노드를 모두 제거한다.
     pub fn remove_node(&mut self, id: &str) {
-        self.nodes.retain(|node| node.id != id);
-        self.connections
-            .retain(|conn| conn.from_id != id && conn.to_id != id);
+        if let Some(include) = self.node(id).and_then(|node| node.included_from.clone()) {
+            self.remove_include(&include);
+            return;
+        }
+        if let Some(mut deleted) = DeletedStep::take(&mut self.nodes, &mut self.connections, id) {
+            deleted.loop_path = self.loop_path();
+            self.keep_deleted(deleted);
+        }
         if self.selected_node_id.as_deref() == Some(id) {
             self.selected_node_id = None;
         }
         self.dirty = true;
     }
 
+    /// `include` 항목에서 펼친 노드를 모두 제거해 저장할 때 그 `include` 항목이 빠지게 한다.
+    ///
+    /// 하나만 지우면 저장할 때 `include` 항목이 다시 쓰여 다음에 열 때 되살아나므로 항목째 지운다.
+    /// 휴지통에는 조각 파일과 연결을 끊은 일반 Step으로 보관해, 되살리면 그 Step만 시나리오에 남는다.
+    pub fn remove_include(&mut self, include: &StepInclude) {
+        let ids: Vec<String> = self
+            .nodes
+            .iter()
+            .filter(|node| node.included_from.as_ref() == Some(include))
+            .map(|node| node.id.clone())
+            .collect();
+        for id in &ids {
+            if let Some(mut deleted) = DeletedStep::take(&mut self.nodes, &mut self.connections, id)
+            {
+                deleted.node.included_from = None;
+                deleted.loop_path = self.loop_path();
+                self.keep_deleted(deleted);
+            }
+        }
+        if self
+            .selected_node_id
+            .as_ref()
+            .is_some_and(|selected| ids.contains(selected))
+        {
+            self.selected_node_id = None;
+        }
+        self.dirty = true;
+    }
+
+    /// 노드를 삭제했을 때의 연결 영향도를 계산한다.
+    pub fn delete_plan(&self, id: &str) -> NodeDeletePlan {
+        NodeDeletePlan::new(id, &self.connections)
+    }
+
+    /// 선행 노드와 후행 노드를 서로 이은 뒤 지정된 노드를 제거한다.
+    pub fn remove_node_bridged(&mut self, id: &str) {
+        for conn in self.delete_plan(id).bridge_connections() {
+            self.add_connection(&conn.from_id, &conn.to_id);
+        }
+        self.remove_node(id);
+    }
+
     /// 노드 선택 상태를 갱신한다.
     pub fn select_node(&mut self, id: Option<String>) {
+        if self.selected_node_id != id {
+            self.id_draft = None;
+        }
         self.selected_node_id = id.clone();
         for node in &mut self.nodes {
             node.selected = Some(node.id.as_str()) == id.as_deref();
@@ -106,6 +425,69 @@ impl ScenarioEditorState {
             .collect()
     }
 
+    /// 지정 노드가 직간접적으로 의존하는 상위 노드 ID 집합을 반환한다.
+    pub fn upstream_of(&self, id: &str) -> HashSet<String> {
+        self.reachable_from(id, |conn| (&conn.to_id, &conn.from_id))
+    }
+
+    /// 지정 노드에 직간접적으로 의존하는 하위 노드 ID 집합을 반환한다.
+    pub fn downstream_of(&self, id: &str) -> HashSet<String> {
+        self.reachable_from(id, |conn| (&conn.from_id, &conn.to_id))
+    }
+
+    /// `edge`가 돌려주는 (출발, 도착) 방향으로 연결을 따라가며 도달 가능한 노드를 모은다.
+    fn reachable_from<F>(&self, id: &str, edge: F) -> HashSet<String>
+    where
+        F: Fn(&EditorConnection) -> (&String, &String),
+    {
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut stack = vec![id.to_string()];
+        while let Some(current) = stack.pop() {
+            for conn in &self.connections {
+                let (from, to) = edge(conn);
+                if *from == current && to != id && visited.insert(to.clone()) {
+                    stack.push(to.clone());
+                }
+            }
+        }
+        visited
+    }
+
+    /// 노드 간 변수 흐름 힌트를 계산한다.
+    ///
+    /// 각 노드의 `consumes` 변수를 제공하는 다른 노드마다 힌트를 하나씩 만든다.
+    /// 제공 노드가 소비 노드의 상위에 있지 않으면 `upstream`이 `false`이다.
+    pub fn data_flow_hints(&self) -> Vec<DataFlowHint> {
+        let mut hints = Vec::new();
+        for consumer in &self.nodes {
+            if consumer.consumes.is_empty() {
+                continue;
+            }
+            let upstream = self.upstream_of(&consumer.id);
+            for producer in &self.nodes {
+                if producer.id == consumer.id {
+                    continue;
+                }
+                let produced = producer.produced_vars();
+                let vars: Vec<String> = consumer
+                    .consumes
+                    .iter()
+                    .filter(|var| produced.contains(&var.as_str()))
+                    .cloned()
+                    .collect();
+                if !vars.is_empty() {
+                    hints.push(DataFlowHint {
+                        from_id: producer.id.clone(),
+                        to_id: consumer.id.clone(),
+                        vars,
+                        upstream: upstream.contains(&producer.id),
+                    });
+                }
+            }
+        }
+        hints
+    }
+
     /// 연결을 추가한다.
     pub fn add_connection(&mut self, from_id: &str, to_id: &str) {
         if from_id == to_id {
@@ -176,3 +558,16 @@ impl Default for ScenarioEditorState {
         Self::new()
     }
 }
+
+/// 변수를 제공하는 노드에서 소비하는 노드로 향하는 데이터 흐름 힌트이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFlowHint {
+    /// 변수를 제공하는 노드 ID.
+    pub from_id: String,
+    /// 변수를 읽는 노드 ID.
+    pub to_id: String,
+    /// 전달되는 변수명 목록.
+    pub vars: Vec<String>,
+    /// 제공 노드가 소비 노드의 상위 노드인지 여부.
+    pub upstream: bool,
+}
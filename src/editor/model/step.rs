@@ -1,6 +1,9 @@
+use crate::i18n::t;
 use crate::scenario::{
-    ExtractVarFromFileConfig, LoopStepConfig, ShellConfig, SqlLoaderParConfig, Step,
-    StepConfirmConfig, StepKind as ScenarioStepKind,
+    ExtractVarFromFileConfig, LoopStepConfig, ManualGateConfig, MissingOutputPolicy, RetryOn,
+    ShellConfig, SqlGuardConfig, SqlLoaderParConfig, Step, StepConfirmConfig, StepDefaults,
+    StepInclude, StepKind as ScenarioStepKind, StepLogLevel, StepSkipIf, StepTimeWindow,
+    TimeoutPolicy, VerifyConfig, WaitConfig, WaitForRunConfig,
 };
 use eframe::egui;
 use std::path::PathBuf;
@@ -23,6 +26,58 @@ pub enum StepKind {
     Extract,
     /// Loop 컨테이너 Step이다.
     Loop,
+    /// 수동 승인 게이트 Step이다.
+    ManualGate,
+    /// 지정한 시간만큼 기다리는 Step이다.
+    Wait,
+    /// 다른 시나리오의 성공한 실행을 기다리는 Step이다.
+    WaitForRun,
+    /// 파일 체크섬이나 줄 수를 검증하는 Step이다.
+    Verify,
+    /// 아무 작업 없이 바로 성공하는 합류 표식 Step이다.
+    Marker,
+}
+
+impl StepKind {
+    /// 에디터에서 선택 가능한 전체 Step 유형 목록이다.
+    pub const ALL: [StepKind; 11] = [
+        StepKind::Sql,
+        StepKind::SqlFile,
+        StepKind::SqlLoaderPar,
+        StepKind::Shell,
+        StepKind::Extract,
+        StepKind::Loop,
+        StepKind::ManualGate,
+        StepKind::Wait,
+        StepKind::WaitForRun,
+        StepKind::Verify,
+        StepKind::Marker,
+    ];
+
+    /// 속성 패널에 표시할 유형 이름을 반환한다.
+    pub fn label(self) -> &'static str {
+        match self {
+            StepKind::Sql => "SQL",
+            StepKind::SqlFile => t("builder.kind.sql_file"),
+            StepKind::SqlLoaderPar => "SQL*Loader",
+            StepKind::Shell => "Shell",
+            StepKind::Extract => "Extract",
+            StepKind::Loop => "Loop",
+            StepKind::ManualGate => t("builder.kind.manual_gate"),
+            StepKind::Wait => t("builder.kind.wait"),
+            StepKind::WaitForRun => t("builder.kind.wait_for_run"),
+            StepKind::Verify => t("builder.kind.verify"),
+            StepKind::Marker => t("builder.kind.marker"),
+        }
+    }
+
+    /// 캔버스에 새로 놓을 때의 노드 크기를 반환한다. 표식 Step은 작게 그린다.
+    pub fn node_size(self) -> egui::Vec2 {
+        match self {
+            StepKind::Marker => egui::vec2(180.0, 44.0),
+            _ => egui::vec2(220.0, 110.0),
+        }
+    }
 }
 
 /// Step별 상세 구성을 저장한다.
@@ -34,6 +89,8 @@ pub enum EditorStepConfig {
         sql: String,
         /// 대상 DB 명칭.
         target_db: Option<String>,
+        /// 문장 제한 시간/변경 행 수 안전장치.
+        guard: SqlGuardConfig,
     },
     /// SQL 파일 Step 구성이다.
     SqlFile {
@@ -41,6 +98,8 @@ pub enum EditorStepConfig {
         path: PathBuf,
         /// 대상 DB 명칭.
         target_db: Option<String>,
+        /// 문장 제한 시간/변경 행 수 안전장치.
+        guard: SqlGuardConfig,
     },
     /// SQL*Loader Step 구성이다.
     SqlLoaderPar {
@@ -62,6 +121,28 @@ pub enum EditorStepConfig {
         /// Loop 실행 설정.
         config: LoopEditorConfig,
     },
+    /// 수동 승인 게이트 구성이다.
+    ManualGate {
+        /// 승인 게이트 설정.
+        config: ManualGateConfig,
+    },
+    /// 대기 Step 구성이다.
+    Wait {
+        /// 대기 설정.
+        config: WaitConfig,
+    },
+    /// 실행 대기 Step 구성이다.
+    WaitForRun {
+        /// 실행 대기 설정.
+        config: WaitForRunConfig,
+    },
+    /// 검증 Step 구성이다.
+    Verify {
+        /// 검증 설정.
+        config: VerifyConfig,
+    },
+    /// 표식 Step 구성이다. 설정할 값이 없다.
+    Marker,
 }
 
 impl EditorStepConfig {
@@ -71,10 +152,12 @@ impl EditorStepConfig {
             StepKind::Sql => EditorStepConfig::Sql {
                 sql: String::new(),
                 target_db: None,
+                guard: SqlGuardConfig::default(),
             },
             StepKind::SqlFile => EditorStepConfig::SqlFile {
                 path: PathBuf::new(),
                 target_db: None,
+                guard: SqlGuardConfig::default(),
             },
             StepKind::SqlLoaderPar => EditorStepConfig::SqlLoaderPar {
                 config: SqlLoaderParConfig {
@@ -84,6 +167,10 @@ impl EditorStepConfig {
                     bad_file: None,
                     discard_file: None,
                     conn: None,
+                    remote: None,
+                    options: Default::default(),
+                    extra_args: Vec::new(),
+                    priority: None,
                 },
             },
             StepKind::Shell => EditorStepConfig::Shell {
@@ -92,9 +179,12 @@ impl EditorStepConfig {
                     shell_program: None,
                     shell_args: Vec::new(),
                     env: Default::default(),
+                    inherit_env: true,
                     working_dir: None,
                     run_as: None,
                     error_policy: Default::default(),
+                    remote: None,
+                    priority: None,
                 },
             },
             StepKind::Extract => EditorStepConfig::Extract {
@@ -104,29 +194,219 @@ impl EditorStepConfig {
                     pattern: String::new(),
                     group: 1,
                     var_name: String::new(),
+                    secret: false,
                 },
             },
             StepKind::Loop => EditorStepConfig::Loop {
                 config: LoopEditorConfig::new(),
             },
+            StepKind::ManualGate => EditorStepConfig::ManualGate {
+                config: ManualGateConfig::default(),
+            },
+            StepKind::Wait => EditorStepConfig::Wait {
+                config: WaitConfig {
+                    seconds: Some(60),
+                    until_time: None,
+                },
+            },
+            StepKind::WaitForRun => EditorStepConfig::WaitForRun {
+                config: WaitForRunConfig::default(),
+            },
+            StepKind::Verify => EditorStepConfig::Verify {
+                config: VerifyConfig::default(),
+            },
+            StepKind::Marker => EditorStepConfig::Marker,
+        }
+    }
+
+    /// 다른 StepKind의 기본 구성으로 변환하되 호환되는 target_db/안전장치 값은 유지한다.
+    pub fn converted_to(&self, kind: StepKind) -> Self {
+        let mut converted = Self::default_for(kind);
+        let current_guard = self.sql_guard().cloned();
+        if let EditorStepConfig::Sql {
+            target_db, guard, ..
+        }
+        | EditorStepConfig::SqlFile {
+            target_db, guard, ..
+        } = &mut converted
+        {
+            *target_db = self.target_db().map(str::to_string);
+            *guard = current_guard.unwrap_or_default();
+        }
+        converted
+    }
+
+    /// 지정한 StepKind로 변환할 때 사라지는 입력 필드 이름 목록을 반환한다.
+    ///
+    /// 비어 있는 필드는 잃을 값이 없으므로 목록에 포함하지 않는다.
+    pub fn fields_lost_on_convert(&self, kind: StepKind) -> Vec<&'static str> {
+        let mut lost = Vec::new();
+        let keeps_target_db = matches!(kind, StepKind::Sql | StepKind::SqlFile);
+        match self {
+            EditorStepConfig::Sql {
+                sql,
+                target_db,
+                guard,
+            } => {
+                if kind != StepKind::Sql && !sql.trim().is_empty() {
+                    lost.push("SQL");
+                }
+                if !keeps_target_db && target_db.is_some() {
+                    lost.push(t("builder.lost.target_db"));
+                }
+                if !keeps_target_db && !guard.is_empty() {
+                    lost.push(t("builder.lost.guard"));
+                }
+            }
+            EditorStepConfig::SqlFile {
+                path,
+                target_db,
+                guard,
+            } => {
+                if kind != StepKind::SqlFile && !path.as_os_str().is_empty() {
+                    lost.push(t("builder.lost.sql_file_path"));
+                }
+                if !keeps_target_db && target_db.is_some() {
+                    lost.push(t("builder.lost.target_db"));
+                }
+                if !keeps_target_db && !guard.is_empty() {
+                    lost.push(t("builder.lost.guard"));
+                }
+            }
+            EditorStepConfig::SqlLoaderPar { config } => {
+                if kind != StepKind::SqlLoaderPar {
+                    if !config.control_file.as_os_str().is_empty() {
+                        lost.push(t("builder.lost.control_file"));
+                    }
+                    if config.data_file.is_some()
+                        || config.log_file.is_some()
+                        || config.bad_file.is_some()
+                        || config.discard_file.is_some()
+                    {
+                        lost.push(t("builder.lost.sqlldr_files"));
+                    }
+                    if config.conn.is_some() {
+                        lost.push(t("builder.lost.conn"));
+                    }
+                    if config.remote.is_some() {
+                        lost.push(t("builder.lost.remote"));
+                    }
+                    if !config.options.is_empty() || !config.extra_args.is_empty() {
+                        lost.push(t("builder.lost.sqlldr_options"));
+                    }
+                }
+            }
+            EditorStepConfig::Shell { config } => {
+                if kind != StepKind::Shell {
+                    if !config.script.trim().is_empty() {
+                        lost.push(t("builder.lost.script"));
+                    }
+                    if config.shell_program.is_some() || !config.shell_args.is_empty() {
+                        lost.push(t("builder.lost.shell_program"));
+                    }
+                    if !config.env.is_empty() {
+                        lost.push(t("builder.lost.env"));
+                    }
+                    if config.working_dir.is_some() || config.run_as.is_some() {
+                        lost.push(t("builder.lost.working_dir"));
+                    }
+                    if config.remote.is_some() {
+                        lost.push(t("builder.lost.remote"));
+                    }
+                }
+            }
+            EditorStepConfig::Extract { config } => {
+                if kind != StepKind::Extract
+                    && (!config.file_path.is_empty()
+                        || !config.pattern.is_empty()
+                        || !config.var_name.is_empty())
+                {
+                    lost.push(t("builder.lost.extract"));
+                }
+            }
+            EditorStepConfig::Loop { config } => {
+                if kind != StepKind::Loop {
+                    if !config.for_each_glob.is_empty() {
+                        lost.push("for_each_glob");
+                    }
+                    if !config.nodes.is_empty() {
+                        lost.push(t("builder.lost.loop_steps"));
+                    }
+                }
+            }
+            EditorStepConfig::ManualGate { config } => {
+                if kind != StepKind::ManualGate && config.message.is_some() {
+                    lost.push(t("builder.lost.gate_message"));
+                }
+            }
+            EditorStepConfig::Wait { .. } => {
+                if kind != StepKind::Wait {
+                    lost.push(t("builder.lost.wait"));
+                }
+            }
+            EditorStepConfig::WaitForRun { config } => {
+                if kind != StepKind::WaitForRun && !config.scenario.is_empty() {
+                    lost.push(t("builder.lost.wait_for_run"));
+                }
+            }
+            EditorStepConfig::Verify { config } => {
+                if kind != StepKind::Verify
+                    && (!config.file.is_empty()
+                        || config.expected.is_some()
+                        || config.expected_file.is_some())
+                {
+                    lost.push(t("builder.lost.verify"));
+                }
+            }
+            EditorStepConfig::Marker => {}
+        }
+        lost
+    }
+
+    /// SQL 계열 구성의 target_db 값을 반환한다.
+    pub fn target_db(&self) -> Option<&str> {
+        match self {
+            EditorStepConfig::Sql { target_db, .. }
+            | EditorStepConfig::SqlFile { target_db, .. } => target_db.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// SQL 계열 구성의 안전장치 설정을 반환한다.
+    pub fn sql_guard(&self) -> Option<&SqlGuardConfig> {
+        match self {
+            EditorStepConfig::Sql { guard, .. } | EditorStepConfig::SqlFile { guard, .. } => {
+                Some(guard)
+            }
+            _ => None,
         }
     }
 
     /// Scenario StepKind를 에디터 구성으로 변환한다.
     pub fn from_scenario_kind(kind: &ScenarioStepKind) -> (StepKind, Self) {
         match kind {
-            ScenarioStepKind::Sql { sql, target_db } => (
+            ScenarioStepKind::Sql {
+                sql,
+                target_db,
+                guard,
+            } => (
                 StepKind::Sql,
                 EditorStepConfig::Sql {
                     sql: sql.clone(),
                     target_db: target_db.clone(),
+                    guard: guard.clone(),
                 },
             ),
-            ScenarioStepKind::SqlFile { path, target_db } => (
+            ScenarioStepKind::SqlFile {
+                path,
+                target_db,
+                guard,
+            } => (
                 StepKind::SqlFile,
                 EditorStepConfig::SqlFile {
                     path: path.clone(),
                     target_db: target_db.clone(),
+                    guard: guard.clone(),
                 },
             ),
             ScenarioStepKind::SqlLoaderPar { config } => (
@@ -153,6 +433,31 @@ impl EditorStepConfig {
                     config: LoopEditorConfig::from_scenario_config(config),
                 },
             ),
+            ScenarioStepKind::ManualGate { config } => (
+                StepKind::ManualGate,
+                EditorStepConfig::ManualGate {
+                    config: config.clone(),
+                },
+            ),
+            ScenarioStepKind::Wait { config } => (
+                StepKind::Wait,
+                EditorStepConfig::Wait {
+                    config: config.clone(),
+                },
+            ),
+            ScenarioStepKind::WaitForRun { config } => (
+                StepKind::WaitForRun,
+                EditorStepConfig::WaitForRun {
+                    config: config.clone(),
+                },
+            ),
+            ScenarioStepKind::Verify { config } => (
+                StepKind::Verify,
+                EditorStepConfig::Verify {
+                    config: config.clone(),
+                },
+            ),
+            ScenarioStepKind::Marker => (StepKind::Marker, EditorStepConfig::Marker),
         }
     }
 }
@@ -164,6 +469,12 @@ pub struct EditorStepNode {
     pub id: String,
     /// 사용자 친화적인 이름.
     pub name: String,
+    /// 표시 아이콘. 없으면 유형별 기본 아이콘을 쓴다.
+    pub icon: Option<String>,
+    /// 표시 라벨. 없으면 유형 이름을 쓴다.
+    pub label: Option<String>,
+    /// 구성용 스테이지 이름.
+    pub stage: Option<String>,
     /// Step 유형.
     pub kind: StepKind,
     /// 노드 배치 좌표.
@@ -176,12 +487,36 @@ pub struct EditorStepNode {
     pub config: EditorStepConfig,
     /// 병렬 실행 허용 여부.
     pub allow_parallel: bool,
+    /// 같은 구성의 실행 결과 재사용 여부.
+    pub memoize: bool,
     /// 재시도 횟수.
     pub retry: u8,
+    /// 재시도할 오류 범위.
+    pub retry_on: RetryOn,
     /// 타임아웃(초).
     pub timeout_sec: u64,
+    /// 타임아웃 처리 정책.
+    pub on_timeout: TimeoutPolicy,
+    /// 엔진 로그 최소 수준. 없으면 감싼 Loop 또는 `info`를 따른다.
+    pub log_level: Option<StepLogLevel>,
     /// 컨펌 설정.
     pub confirm: Option<StepConfirmConfig>,
+    /// 실행 시간 창 설정.
+    pub time_window: Option<StepTimeWindow>,
+    /// 건너뛰기 조건.
+    pub skip_if: Option<StepSkipIf>,
+    /// 성공한 뒤 있어야 하는 산출물 경로 목록.
+    pub expects_outputs: Vec<String>,
+    /// 기대 산출물이 없을 때의 처리 정책.
+    pub on_missing_output: MissingOutputPolicy,
+    /// 읽는 컨텍스트 변수 목록.
+    pub consumes: Vec<String>,
+    /// 제공하는 컨텍스트 변수 목록.
+    pub produces: Vec<String>,
+    /// Step 시작·종료를 알릴 웹훅 주소.
+    pub notify_url: Option<String>,
+    /// 조각 파일에서 펼친 Step이면 그 `include` 항목. 빌더에서는 읽기 전용으로 표시한다.
+    pub included_from: Option<StepInclude>,
 }
 
 impl EditorStepNode {
@@ -191,31 +526,107 @@ impl EditorStepNode {
             config: EditorStepConfig::default_for(kind),
             id,
             name,
+            icon: None,
+            label: None,
+            stage: None,
             kind,
             position: egui::pos2(40.0, 40.0),
-            size: egui::vec2(220.0, 110.0),
+            size: kind.node_size(),
             selected: false,
             allow_parallel: false,
+            memoize: false,
             retry: 0,
+            retry_on: RetryOn::default(),
             timeout_sec: 60,
+            on_timeout: TimeoutPolicy::default(),
+            log_level: None,
             confirm: None,
+            time_window: None,
+            skip_if: None,
+            expects_outputs: Vec::new(),
+            on_missing_output: MissingOutputPolicy::default(),
+            consumes: Vec::new(),
+            produces: Vec::new(),
+            notify_url: None,
+            included_from: None,
+        }
+    }
+
+    /// 시나리오 공통 기본값으로 재시도/타임아웃/컨펌과 유형별 기본값을 채운다.
+    pub fn apply_defaults(&mut self, defaults: &StepDefaults) {
+        if let Some(retry) = defaults.retry {
+            self.retry = retry;
+        }
+        if let Some(timeout_sec) = defaults.timeout_sec {
+            self.timeout_sec = timeout_sec;
+        }
+        if defaults.confirm.is_some() {
+            self.confirm = defaults.confirm.clone();
+        }
+        match &mut self.config {
+            EditorStepConfig::Sql { target_db, .. }
+            | EditorStepConfig::SqlFile { target_db, .. } => {
+                if defaults.target_db.is_some() {
+                    *target_db = defaults.target_db.clone();
+                }
+            }
+            EditorStepConfig::Shell { config } => {
+                if let Some(policy) = &defaults.error_policy {
+                    config.error_policy = policy.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 노드의 Step 유형을 변경한다.
+    ///
+    /// ID, 이름, 위치, 재시도/타임아웃/컨펌 설정은 노드에 그대로 남으므로 연결도 유지된다.
+    pub fn convert_kind(&mut self, kind: StepKind) {
+        if self.kind == kind {
+            return;
         }
+        self.config = self.config.converted_to(kind);
+        if self.size == self.kind.node_size() {
+            self.size = kind.node_size();
+        }
+        self.kind = kind;
     }
 
     /// Scenario Step으로 변환한다.
     pub fn to_scenario_step(&self, depends_on: Vec<String>) -> Result<Step, EditorError> {
+        if let Some(window) = &self.time_window
+            && let Err(err) = window.parse()
+        {
+            return Err(EditorError::InvalidTimeWindow {
+                step_id: self.id.clone(),
+                message: err.to_string(),
+            });
+        }
         let kind = match &self.config {
-            EditorStepConfig::Sql { sql, target_db } => ScenarioStepKind::Sql {
+            EditorStepConfig::Sql {
+                sql,
+                target_db,
+                guard,
+            } => ScenarioStepKind::Sql {
                 sql: sql.clone(),
                 target_db: target_db.clone(),
+                guard: guard.clone(),
             },
-            EditorStepConfig::SqlFile { path, target_db } => ScenarioStepKind::SqlFile {
+            EditorStepConfig::SqlFile {
+                path,
+                target_db,
+                guard,
+            } => ScenarioStepKind::SqlFile {
                 path: path.clone(),
                 target_db: target_db.clone(),
+                guard: guard.clone(),
             },
-            EditorStepConfig::SqlLoaderPar { config } => ScenarioStepKind::SqlLoaderPar {
-                config: config.clone(),
-            },
+            EditorStepConfig::SqlLoaderPar { config } => {
+                let mut config = config.clone();
+                config.extra_args.retain(|arg| !arg.trim().is_empty());
+                ScenarioStepKind::SqlLoaderPar { config }
+            }
             EditorStepConfig::Shell { config } => ScenarioStepKind::Shell {
                 config: config.clone(),
             },
@@ -225,16 +636,49 @@ impl EditorStepNode {
             EditorStepConfig::Loop { config } => ScenarioStepKind::Loop {
                 config: config.to_loop_step_config()?,
             },
+            EditorStepConfig::ManualGate { config } => ScenarioStepKind::ManualGate {
+                config: config.clone(),
+            },
+            EditorStepConfig::Wait { config } => ScenarioStepKind::Wait {
+                config: config.clone(),
+            },
+            EditorStepConfig::WaitForRun { config } => ScenarioStepKind::WaitForRun {
+                config: config.clone(),
+            },
+            EditorStepConfig::Verify { config } => ScenarioStepKind::Verify {
+                config: config.clone(),
+            },
+            EditorStepConfig::Marker => ScenarioStepKind::Marker,
         };
         Ok(Step {
             id: self.id.clone(),
             name: self.name.clone(),
+            icon: non_empty(&self.icon),
+            label: non_empty(&self.label),
+            stage: non_empty(&self.stage),
             kind,
             depends_on,
             allow_parallel: self.allow_parallel,
+            memoize: self.memoize,
             retry: self.retry,
+            retry_on: self.retry_on,
             timeout_sec: self.timeout_sec,
+            on_timeout: self.on_timeout,
+            log_level: self.log_level,
             confirm: self.confirm.clone(),
+            time_window: self.time_window.clone(),
+            skip_if: self.skip_if.clone().filter(|skip_if| !skip_if.is_empty()),
+            expects_outputs: self
+                .expects_outputs
+                .iter()
+                .map(|path| path.trim().to_string())
+                .filter(|path| !path.is_empty())
+                .collect(),
+            on_missing_output: self.on_missing_output,
+            consumes: self.consumes.clone(),
+            produces: self.produces.clone(),
+            notify_url: non_empty(&self.notify_url),
+            included_from: self.included_from.clone(),
         })
     }
 
@@ -244,15 +688,51 @@ impl EditorStepNode {
         Self {
             id: step.id.clone(),
             name: step.name.clone(),
+            icon: step.icon.clone(),
+            label: step.label.clone(),
+            stage: step.stage.clone(),
             kind,
             config,
             position: egui::pos2(40.0, 40.0),
-            size: egui::vec2(220.0, 110.0),
+            size: kind.node_size(),
             selected: false,
             allow_parallel: step.allow_parallel,
+            memoize: step.memoize,
             retry: step.retry,
+            retry_on: step.retry_on,
             timeout_sec: step.timeout_sec,
+            on_timeout: step.on_timeout,
+            log_level: step.log_level,
             confirm: step.confirm.clone(),
+            time_window: step.time_window.clone(),
+            skip_if: step.skip_if.clone(),
+            expects_outputs: step.expects_outputs.clone(),
+            on_missing_output: step.on_missing_output,
+            consumes: step.consumes.clone(),
+            produces: step.produces.clone(),
+            notify_url: step.notify_url.clone(),
+            included_from: step.included_from.clone(),
+        }
+    }
+
+    /// 노드가 제공하는 변수 목록을 반환한다. Extract 노드는 `var_name`도 포함한다.
+    pub fn produced_vars(&self) -> Vec<&str> {
+        let mut vars: Vec<&str> = self.produces.iter().map(String::as_str).collect();
+        if let EditorStepConfig::Extract { config } = &self.config
+            && !config.var_name.is_empty()
+            && !vars.contains(&config.var_name.as_str())
+        {
+            vars.push(config.var_name.as_str());
         }
+        vars
     }
 }
+
+/// 공백뿐인 표시 항목은 저장하지 않도록 `None`으로 바꾼다.
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
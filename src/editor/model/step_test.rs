@@ -0,0 +1,64 @@
+/// Step 테스트 콘솔에 보관하는 최대 로그 줄 수이다. 넘으면 오래된 줄부터 버린다.
+pub const STEP_TEST_MAX_LOG_LINES: usize = 300;
+
+/// 속성 패널에서 요청한 단일 Step 테스트 실행이다.
+///
+/// 시나리오의 다른 Step과 실행 컨텍스트를 공유하지 않고, 시나리오 파라미터와 여기서 입력한 변수만으로
+/// 선택한 Step 하나를 실행한다.
+#[derive(Debug, Clone)]
+pub struct StepTest {
+    /// 테스트할 Step ID.
+    pub step_id: String,
+    /// Step이 참조하는 `${VAR}`와 입력한 값. `None`이면 앱이 아직 참조 변수를 모으지 않았다.
+    pub inputs: Option<Vec<(String, String)>>,
+    /// 실행 중 받은 Step 로그.
+    pub logs: Vec<String>,
+    /// 진행 상태.
+    pub status: StepTestStatus,
+}
+
+/// 단일 Step 테스트의 진행 상태이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepTestStatus {
+    /// 참조 변수 값을 입력받는 중이다.
+    Editing,
+    /// 실행을 요청했고 아직 시작하지 않았다.
+    Requested,
+    /// 실행 중이다.
+    Running,
+    /// 실행 중에 중지를 요청했다.
+    StopRequested,
+    /// Step이 성공했다.
+    Passed,
+    /// Step이 실패했거나 실행을 시작하지 못했다.
+    Failed(String),
+}
+
+impl StepTest {
+    /// 새 테스트 요청을 만든다. 참조 변수는 앱이 모아 채운다.
+    pub fn request(step_id: &str) -> Self {
+        Self {
+            step_id: step_id.to_string(),
+            inputs: None,
+            logs: Vec::new(),
+            status: StepTestStatus::Requested,
+        }
+    }
+
+    /// 실행을 시작했거나 기다리는 중인지 확인한다.
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self.status,
+            StepTestStatus::Requested | StepTestStatus::Running | StepTestStatus::StopRequested
+        )
+    }
+
+    /// 로그 한 줄을 콘솔에 추가한다.
+    pub fn push_log(&mut self, line: String) {
+        self.logs.push(line);
+        if self.logs.len() > STEP_TEST_MAX_LOG_LINES {
+            let overflow = self.logs.len() - STEP_TEST_MAX_LOG_LINES;
+            self.logs.drain(..overflow);
+        }
+    }
+}
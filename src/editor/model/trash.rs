@@ -0,0 +1,148 @@
+use chrono::{DateTime, Local};
+
+use super::connection::EditorConnection;
+use super::identity::unique_step_id;
+use super::state::ScenarioEditorState;
+use super::step::{EditorStepConfig, EditorStepNode};
+
+/// 휴지통에 보관하는 최대 Step 수이다. 넘치면 가장 오래전에 삭제한 것부터 버린다.
+pub const TRASH_CAPACITY: usize = 50;
+
+/// 빌더에서 삭제한 Step이다. 편집 중인 시나리오에만 보관하며 저장하지 않는다.
+#[derive(Debug, Clone)]
+pub struct DeletedStep {
+    /// 삭제한 노드. 설정과 Loop 하위 노드를 그대로 담는다.
+    pub node: EditorStepNode,
+    /// 삭제할 때 노드에 닿아 있던 연결.
+    pub connections: Vec<EditorConnection>,
+    /// 노드가 있던 Loop 경로(바깥부터 Loop 노드 ID). 비어 있으면 최상위 흐름이다.
+    pub loop_path: Vec<String>,
+    /// 삭제한 시각.
+    pub deleted_at: DateTime<Local>,
+}
+
+impl DeletedStep {
+    /// 노드 목록에서 노드를 빼고 닿아 있던 연결도 함께 떼어 낸다. 노드가 없으면 `None`이다.
+    ///
+    /// `loop_path`는 비워 두므로 보관하는 쪽에서 채운다.
+    pub(super) fn take(
+        nodes: &mut Vec<EditorStepNode>,
+        connections: &mut Vec<EditorConnection>,
+        id: &str,
+    ) -> Option<Self> {
+        let idx = nodes.iter().position(|node| node.id == id)?;
+        let mut node = nodes.remove(idx);
+        node.selected = false;
+        let (touching, rest): (Vec<_>, Vec<_>) = std::mem::take(connections)
+            .into_iter()
+            .partition(|conn| conn.from_id == id || conn.to_id == id);
+        *connections = rest;
+        Some(Self {
+            node,
+            connections: touching,
+            loop_path: Vec::new(),
+            deleted_at: Local::now(),
+        })
+    }
+}
+
+impl ScenarioEditorState {
+    /// 캔버스로 연 Loop 경로의 Loop 노드 ID를 바깥부터 반환한다.
+    pub fn loop_path(&self) -> Vec<String> {
+        self.loop_stack
+            .iter()
+            .map(|frame| frame.loop_id.clone())
+            .collect()
+    }
+
+    /// 삭제한 Step을 휴지통에 넣는다. [`TRASH_CAPACITY`]를 넘으면 가장 오래된 것을 버린다.
+    pub(super) fn keep_deleted(&mut self, deleted: DeletedStep) {
+        self.trash.push(deleted);
+        if self.trash.len() > TRASH_CAPACITY {
+            self.trash.remove(0);
+        }
+    }
+
+    /// 속성 패널의 Loop 설정에서 삭제한 하위 Step을 휴지통에 넣는다.
+    ///
+    /// `loop_id`는 현재 흐름에 있는 Loop 노드 ID이다.
+    pub fn keep_deleted_loop_child(&mut self, loop_id: &str, mut deleted: DeletedStep) {
+        deleted.loop_path = self.loop_path();
+        deleted.loop_path.push(loop_id.to_string());
+        self.keep_deleted(deleted);
+    }
+
+    /// 휴지통의 Step을 지금 되살릴 수 있는지 여부를 반환한다.
+    ///
+    /// 삭제한 흐름이 캔버스에 열려 있거나, 현재 흐름에 있는 Loop 노드의 하위 흐름이어야 한다.
+    pub fn can_restore_deleted(&self, deleted: &DeletedStep) -> bool {
+        let current = self.loop_path();
+        if deleted.loop_path == current {
+            return true;
+        }
+        deleted.loop_path.len() == current.len() + 1
+            && deleted.loop_path.starts_with(&current)
+            && matches!(
+                self.node(&deleted.loop_path[current.len()])
+                    .map(|node| &node.config),
+                Some(EditorStepConfig::Loop { .. })
+            )
+    }
+
+    /// 휴지통의 `index`번째 Step을 삭제 전 흐름에 되살리고 휴지통에서 뺀다.
+    ///
+    /// ID가 그사이 다른 Step과 겹치면 접미사를 붙인다. 삭제 전 연결은 상대 Step이 아직 있는 것만 다시
+    /// 잇는다. 되살린 노드가 현재 흐름에 있으면 선택한다.
+    ///
+    /// # 반환값
+    /// 되살린 노드 ID. 삭제한 흐름을 지금 열 수 없으면 `None`이고 휴지통은 그대로이다.
+    pub fn restore_deleted(&mut self, index: usize) -> Option<String> {
+        let deleted = self.trash.get(index)?;
+        if !self.can_restore_deleted(deleted) {
+            return None;
+        }
+        let DeletedStep {
+            mut node,
+            connections,
+            loop_path,
+            ..
+        } = self.trash.remove(index);
+        let mut taken = self.all_step_ids();
+        let old_id = std::mem::take(&mut node.id);
+        node.id = unique_step_id(&old_id, &taken);
+        taken.insert(node.id.clone());
+        if let EditorStepConfig::Loop { config } = &mut node.config {
+            config.reassign_child_ids(&mut taken);
+        }
+        let id = node.id.clone();
+        let into_current = loop_path.len() == self.loop_stack.len();
+        let (nodes, links) = if into_current {
+            (&mut self.nodes, &mut self.connections)
+        } else {
+            let loop_id = loop_path.last()?;
+            let EditorStepConfig::Loop { config } = &mut self.node_mut(loop_id)?.config else {
+                return None;
+            };
+            (&mut config.nodes, &mut config.connections)
+        };
+        nodes.push(node);
+        for conn in connections {
+            let rename = |end: String| if end == old_id { id.clone() } else { end };
+            let conn = EditorConnection {
+                from_id: rename(conn.from_id),
+                to_id: rename(conn.to_id),
+            };
+            let linked = [&conn.from_id, &conn.to_id]
+                .iter()
+                .all(|end| nodes.iter().any(|node| &node.id == *end));
+            if linked && !links.contains(&conn) {
+                links.push(conn);
+            }
+        }
+        if into_current {
+            self.select_node(Some(id.clone()));
+        }
+        self.dirty = true;
+        Some(id)
+    }
+}
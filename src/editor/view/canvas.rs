@@ -1,21 +1,60 @@
 use super::*;
+use std::collections::HashSet;
 
 impl<'a> ScenarioBuilderUi<'a> {
     /// 캔버스를 렌더링하고 노드/연결 상호작용을 처리한다.
-    pub(super) fn render_canvas(&mut self, ui: &mut egui::Ui, colors: BuilderColors) {
+    ///
+    /// Loop 노드를 더블 클릭하면 그 하위 흐름을 캔버스로 연다.
+    /// `critical`이 있으면 영향 미리보기 대신 크리티컬 패스를 강조한다.
+    /// 스크롤 영역 밖의 노드와 연결은 상호작용과 그리기를 건너뛰고(끄는 중인 노드 제외), 노드 글자
+    /// 배치와 데이터 흐름 힌트는 바뀐 경우에만 다시 계산한다. `F12`로 성능 오버레이를 켜고 끈다.
+    /// `order_sim`이 있으면 노드 위에 실행 순서 시뮬레이션의 현재 재생 상태를 덧그린다.
+    pub(super) fn render_canvas(
+        &mut self,
+        ui: &mut egui::Ui,
+        colors: BuilderColors,
+        critical: Option<&CriticalPathAnalysis>,
+        order_sim: Option<&OrderSimulation>,
+    ) {
+        if ui.input_mut(|input| input.consume_key(egui::Modifiers::NONE, egui::Key::F12)) {
+            let state = self.get_state_mut();
+            state.perf_overlay = !state.perf_overlay;
+        }
+        let build_started = std::time::Instant::now();
+        let mut stats = CanvasPaintStats::default();
+        let mut cache = CanvasCache::take(ui);
         let desired_size = egui::vec2(2400.0, 1600.0);
-        egui::ScrollArea::both()
+        let viewport = egui::ScrollArea::both()
             .auto_shrink([false; 2])
             .show(ui, |ui| {
                 let (rect, response) =
                     ui.allocate_exact_size(desired_size, egui::Sense::click_and_drag());
                 let painter = ui.painter_at(rect);
+                let visible = ui.clip_rect().expand(CULL_MARGIN);
                 let mut pending_selection: Option<String> = None;
+                let mut open_loop: Option<String> = None;
                 if response.clicked() && !response.dragged() {
                     self.clear_selection();
                 }
+                if response.secondary_clicked() {
+                    self.get_state_mut().canvas_menu_pos = response
+                        .interact_pointer_pos()
+                        .map(|pos| (pos - rect.min).to_pos2());
+                }
+                response.context_menu(|ui| self.render_canvas_menu(ui));
                 let origin = rect.min.to_vec2();
-                self.draw_connections(&painter, colors, origin);
+                if self.get_state().snap_to_grid {
+                    Self::draw_grid(&painter, rect, colors);
+                }
+                let toggle_modifier = ui.input(|input| input.modifiers.shift);
+                let dragging = self
+                    .get_state()
+                    .node_drag
+                    .as_ref()
+                    .map(|(id, _)| id.clone());
+                let mut hovered: Option<String> = None;
+                let mut guides: Vec<AlignGuide> = Vec::new();
+                let mut drawn: Vec<usize> = Vec::new();
                 for idx in 0..self.get_state().nodes.len() {
                     let (node_id, node_rect) = {
                         let node = &self.get_state().nodes[idx];
@@ -25,120 +64,613 @@ impl<'a> ScenarioBuilderUi<'a> {
                         );
                         (node.id.clone(), shape)
                     };
+                    if !visible.intersects(node_rect) && dragging.as_ref() != Some(&node_id) {
+                        continue;
+                    }
                     let response_id = egui::Id::new(("builder_node", node_id.clone()));
-                    let node_response =
+                    let mut node_response =
                         ui.interact(node_rect, response_id, egui::Sense::click_and_drag());
+                    if let Some(stats) = self.get_state().step_stats.get(&node_id) {
+                        node_response = node_response.on_hover_text(tf(
+                            "builder.canvas.history",
+                            &[("summary", &stats.summary())],
+                        ));
+                    }
+                    if self.get_state().sql_changes.contains_key(&node_id) {
+                        node_response =
+                            node_response.on_hover_text(t("builder.canvas.sql_changed_hover"));
+                    }
+                    if node_response.drag_started() {
+                        self.get_state_mut().begin_node_drag(&node_id);
+                    }
                     if node_response.dragged() {
-                        if let Some(node) = self.get_state_mut().node_mut(&node_id) {
-                            node.position += node_response.drag_delta();
-                        }
-                        self.get_state_mut().dirty = true;
+                        guides = self
+                            .get_state_mut()
+                            .drag_node(&node_id, node_response.drag_delta());
+                    }
+                    if node_response.drag_released() {
+                        self.get_state_mut().end_node_drag();
                     }
                     if node_response.clicked() {
-                        pending_selection = Some(node_id.clone());
+                        if toggle_modifier {
+                            self.get_state_mut().toggle_node_selection(&node_id);
+                        } else {
+                            pending_selection = Some(node_id.clone());
+                        }
+                    }
+                    if node_response.double_clicked()
+                        && self
+                            .get_state()
+                            .node(&node_id)
+                            .is_some_and(|node| node.kind == StepKind::Loop)
+                    {
+                        open_loop = Some(node_id.clone());
+                    }
+                    if node_response.hovered() {
+                        hovered = Some(node_id.clone());
                     }
-                    if let Some(node) = self.get_state().node(&node_id) {
-                        self.draw_node(&painter, node_rect, node, colors);
+                    drawn.push(idx);
+                }
+                let impact = hovered
+                    .or_else(|| self.get_state().selected_node_id.clone())
+                    .filter(|_| critical.is_none())
+                    .map(|focus| ImpactPreview::new(self.get_state(), focus));
+                let hints = cache.data_flow_hints(self.get_state());
+                self.draw_data_flow_hints(
+                    &painter,
+                    colors,
+                    origin,
+                    visible,
+                    &hints,
+                    impact.as_ref(),
+                );
+                stats.connections_drawn = self.draw_connections(
+                    &painter,
+                    colors,
+                    origin,
+                    visible,
+                    impact.as_ref(),
+                    critical,
+                );
+                for &idx in &drawn {
+                    if let Some(node) = self.get_state().nodes.get(idx) {
+                        let node_rect = egui::Rect::from_min_size(
+                            rect.min + node.position.to_vec2(),
+                            node.size,
+                        );
+                        self.draw_node(
+                            &painter,
+                            &mut cache,
+                            node_rect,
+                            node,
+                            colors,
+                            impact.as_ref(),
+                            critical,
+                        );
+                    }
+                }
+                let playhead = self
+                    .get_state()
+                    .order_sim
+                    .as_ref()
+                    .map(|playback| playback.playhead);
+                if let (Some(sim), Some(playhead)) = (order_sim, playhead) {
+                    for &idx in &drawn {
+                        if let Some(node) = self.get_state().nodes.get(idx) {
+                            let node_rect = egui::Rect::from_min_size(
+                                rect.min + node.position.to_vec2(),
+                                node.size,
+                            );
+                            self.draw_order_sim_overlay(
+                                &painter,
+                                node_rect,
+                                sim.entries.get(&node.id),
+                                playhead,
+                                colors,
+                            );
+                        }
                     }
                 }
+                stats.nodes_drawn = drawn.len();
+                stats.nodes_total = self.get_state().nodes.len();
+                stats.connections_total = self.get_state().connections.len();
+                Self::draw_align_guides(&painter, rect, colors, &guides);
                 if let Some(id) = pending_selection {
                     self.get_state_mut().select_node(Some(id));
                 }
-            });
+                if let Some(id) = open_loop {
+                    self.get_state_mut().open_loop(&id);
+                }
+                self.accept_dropped_files(ui, rect);
+            })
+            .inner_rect;
+        cache.prune(self.get_state());
+        cache.store(ui);
+        if self.get_state().perf_overlay {
+            let palette = *self.get_theme().palette();
+            draw_perf_overlay(ui, viewport, stats, build_started.elapsed(), palette);
+        }
     }
 
-    /// 연결 선을 그린다.
+    /// 캔버스에 끌어다 놓은 `.sql`/`.ctl` 파일을 놓은 위치에 SQL 파일·SQL*Loader Step으로 추가한다.
+    ///
+    /// 여러 파일을 함께 놓으면 조금씩 어긋나게 쌓는다. 포인터가 캔버스 밖에 있으면 무시하고, 운영체제가
+    /// 포인터 위치를 알려 주지 않으면 보이는 영역 가운데에 놓는다.
+    fn accept_dropped_files(&mut self, ui: &egui::Ui, rect: egui::Rect) {
+        let (paths, pointer) = ui.input(|input| {
+            let paths: Vec<_> = input
+                .raw
+                .dropped_files
+                .iter()
+                .filter_map(|file| file.path.clone())
+                .collect();
+            (paths, input.pointer.hover_pos())
+        });
+        if paths.is_empty() {
+            return;
+        }
+        let visible = ui.clip_rect();
+        let drop_pos = match pointer {
+            Some(pos) if visible.contains(pos) => pos,
+            Some(_) => return,
+            None => visible.center(),
+        };
+        let mut position = (drop_pos - rect.min).to_pos2();
+        for path in &paths {
+            if self
+                .get_state_mut()
+                .add_file_node_at(path, position)
+                .is_some()
+            {
+                position += egui::vec2(24.0, 24.0);
+            }
+        }
+    }
+
+    /// 그리드 맞춤이 켜져 있을 때 캔버스에 옅은 그리드 선을 그린다.
+    fn draw_grid(painter: &egui::Painter, rect: egui::Rect, colors: BuilderColors) {
+        let stroke = Stroke::new(
+            1.0,
+            blend_color(colors.node_border, colors.canvas_fill, GRID_LINE_RATIO),
+        );
+        let mut x = rect.min.x;
+        while x <= rect.max.x {
+            painter.line_segment(
+                [egui::pos2(x, rect.min.y), egui::pos2(x, rect.max.y)],
+                stroke,
+            );
+            x += GRID_SPACING;
+        }
+        let mut y = rect.min.y;
+        while y <= rect.max.y {
+            painter.line_segment(
+                [egui::pos2(rect.min.x, y), egui::pos2(rect.max.x, y)],
+                stroke,
+            );
+            y += GRID_SPACING;
+        }
+    }
+
+    /// 노드를 끄는 동안 붙은 정렬 가이드를 캔버스 끝까지 점선으로 그린다.
+    fn draw_align_guides(
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        colors: BuilderColors,
+        guides: &[AlignGuide],
+    ) {
+        let stroke = Stroke::new(1.0, colors.downstream_highlight);
+        for guide in guides {
+            let points = match *guide {
+                AlignGuide::Vertical(x) => [
+                    egui::pos2(rect.min.x + x, rect.min.y),
+                    egui::pos2(rect.min.x + x, rect.max.y),
+                ],
+                AlignGuide::Horizontal(y) => [
+                    egui::pos2(rect.min.x, rect.min.y + y),
+                    egui::pos2(rect.max.x, rect.min.y + y),
+                ],
+            };
+            painter.extend(egui::Shape::dashed_line(&points, stroke, 6.0, 4.0));
+        }
+    }
+
+    /// 빈 캔버스 우클릭 메뉴에 Step 유형 목록을 그린다.
+    ///
+    /// 고른 유형의 노드를 우클릭한 위치에 만들고 선택한 뒤 이름 입력란에 포커스를 준다.
+    fn render_canvas_menu(&mut self, ui: &mut egui::Ui) {
+        ui.label(t("builder.canvas.add_here"));
+        ui.separator();
+        for kind in StepKind::ALL {
+            let visual = self.get_theme().step_visual(Self::visual_kind_for(kind));
+            if ui
+                .button(format!("{} {}", visual.icon, kind.label()))
+                .clicked()
+            {
+                let state = self.get_state_mut();
+                let position = state
+                    .canvas_menu_pos
+                    .take()
+                    .unwrap_or(egui::pos2(80.0, 80.0));
+                state.add_node_at(kind, position);
+                state.focus_name_pending = true;
+                ui.close_menu();
+            }
+        }
+    }
+
+    /// 연결 선을 그린다. 크리티컬 패스 분석 중이면 경로 연결을 굵게, 나머지를 흐리게 그린다.
+    ///
+    /// 곡선을 감싸는 사각형이 `visible`과 겹치지 않는 연결은 건너뛰며, 그린 연결 수를 반환한다.
     fn draw_connections(
         &mut self,
         painter: &egui::Painter,
         colors: BuilderColors,
         origin: egui::Vec2,
-    ) {
+        visible: egui::Rect,
+        impact: Option<&ImpactPreview>,
+        critical: Option<&CriticalPathAnalysis>,
+    ) -> usize {
+        let nodes: HashMap<&str, &EditorStepNode> = self
+            .get_state()
+            .nodes
+            .iter()
+            .map(|node| (node.id.as_str(), node))
+            .collect();
+        let mut drawn = 0;
         for conn in &self.get_state().connections {
             if let (Some(from), Some(to)) = (
-                self.get_state().node(&conn.from_id),
-                self.get_state().node(&conn.to_id),
+                nodes.get(conn.from_id.as_str()),
+                nodes.get(conn.to_id.as_str()),
             ) {
                 let start = from.position + egui::vec2(from.size.x / 2.0, from.size.y);
                 let end = to.position + egui::vec2(to.size.x / 2.0, 0.0);
                 let start = egui::pos2(start.x + origin.x, start.y + origin.y);
                 let end = egui::pos2(end.x + origin.x, end.y + origin.y);
+                let points = [
+                    start,
+                    start + egui::vec2(0.0, 60.0),
+                    end - egui::vec2(0.0, 60.0),
+                    end,
+                ];
+                if !visible.intersects(egui::Rect::from_points(&points)) {
+                    continue;
+                }
+                let on_path = critical.map(|analysis| analysis.contains_edge(conn));
+                let stroke = match (on_path, impact.map(|preview| preview.edge_role(conn))) {
+                    (Some(true), _) => Stroke::new(3.5, colors.critical_path),
+                    (_, Some(ImpactRole::Upstream)) => Stroke::new(3.0, colors.upstream_highlight),
+                    (_, Some(ImpactRole::Downstream)) => {
+                        Stroke::new(3.0, colors.downstream_highlight)
+                    }
+                    (Some(false), _) | (_, Some(_)) => Stroke::new(
+                        2.0,
+                        blend_color(colors.connection_stroke, colors.canvas_fill, DIM_RATIO),
+                    ),
+                    (None, None) => Stroke::new(2.0, colors.connection_stroke),
+                };
 
                 painter.add(CubicBezierShape::from_points_stroke(
-                    [
-                        start,
-                        start + egui::vec2(0.0, 60.0),
-                        end - egui::vec2(0.0, 60.0),
-                        end,
-                    ],
+                    points,
                     false,
                     egui::Color32::TRANSPARENT,
-                    Stroke::new(2.0, colors.connection_stroke),
+                    stroke,
                 ));
+                drawn += 1;
             }
         }
+        drawn
+    }
+
+    /// `consumes`/`produces` 선언에서 계산한 변수 흐름 힌트를 점선으로 그린다.
+    ///
+    /// 제어 흐름 연결과 구분되도록 노드 옆면끼리 직선으로 잇고, 가운데에 변수명을 표시한다.
+    /// 제공 노드가 상위 노드가 아니면 실행 시점에 값이 없을 수 있으므로 경고 색으로 그린다.
+    /// 두 노드를 잇는 선분이 `visible` 밖에 있으면 건너뛴다.
+    fn draw_data_flow_hints(
+        &self,
+        painter: &egui::Painter,
+        colors: BuilderColors,
+        origin: egui::Vec2,
+        visible: egui::Rect,
+        hints: &[DataFlowHint],
+        impact: Option<&ImpactPreview>,
+    ) {
+        for hint in hints {
+            let (Some(from), Some(to)) = (
+                self.get_state().node(&hint.from_id),
+                self.get_state().node(&hint.to_id),
+            ) else {
+                continue;
+            };
+            let base = if hint.upstream {
+                colors.data_flow_hint
+            } else {
+                colors.data_flow_conflict
+            };
+            let related = impact.is_none_or(|preview| {
+                preview.node_role(&hint.from_id) != ImpactRole::Unrelated
+                    && preview.node_role(&hint.to_id) != ImpactRole::Unrelated
+            });
+            let color = if related {
+                base
+            } else {
+                blend_color(base, colors.canvas_fill, DIM_RATIO)
+            };
+            let from_left = from.position.x > to.position.x;
+            let start_x = if from_left { 0.0 } else { from.size.x };
+            let end_x = if from_left { to.size.x } else { 0.0 };
+            let start = from.position + egui::vec2(start_x, from.size.y / 2.0) + origin;
+            let end = to.position + egui::vec2(end_x, to.size.y / 2.0) + origin;
+            if !visible.intersects(egui::Rect::from_two_pos(start, end)) {
+                continue;
+            }
+            painter.extend(egui::Shape::dashed_line(
+                &[start, end],
+                Stroke::new(1.5, color),
+                6.0,
+                4.0,
+            ));
+            let label = hint
+                .vars
+                .iter()
+                .map(|var| format!("${var}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            painter.text(
+                start + (end - start) / 2.0,
+                egui::Align2::CENTER_BOTTOM,
+                label,
+                egui::FontId::proportional(11.0),
+                color,
+            );
+        }
     }
 
     /// 개별 노드를 드로잉한다.
+    ///
+    /// 영향 미리보기가 활성화되어 있으면 역할에 따라 테두리를 강조하거나 노드를 흐리게 그린다.
+    /// 크리티컬 패스 분석 중이면 경로 노드의 테두리를 강조하고 나머지 노드를 흐리게 그린다.
     fn draw_node(
         &self,
         painter: &egui::Painter,
+        cache: &mut CanvasCache,
         rect: egui::Rect,
         node: &EditorStepNode,
         colors: BuilderColors,
+        impact: Option<&ImpactPreview>,
+        critical: Option<&CriticalPathAnalysis>,
     ) {
-        let bg = if node.selected {
+        let role = impact.map(|preview| preview.node_role(&node.id));
+        let on_path = critical.map(|analysis| analysis.contains_node(&node.id));
+        let dimmed = role == Some(ImpactRole::Unrelated) || on_path == Some(false);
+        let tint = |color: egui::Color32| {
+            if dimmed {
+                blend_color(color, colors.canvas_fill, DIM_RATIO)
+            } else {
+                color
+            }
+        };
+        let stage_color = node
+            .stage
+            .as_deref()
+            .filter(|_| self.get_state().color_by_stage)
+            .map(|stage| self.get_theme().stage_color(stage));
+        let mut bg = if node.selected {
             colors.node_selected
         } else {
             colors.node_fill
         };
-        painter.rect_filled(rect, 10.0, bg);
-        painter.rect_stroke(rect, 10.0, egui::Stroke::new(1.6, colors.node_border));
-        let title_pos = rect.min + egui::vec2(10.0, 8.0);
-        painter.text(
-            title_pos,
-            egui::Align2::LEFT_TOP,
-            &node.name,
-            egui::FontId::proportional(16.0),
-            colors.text_primary,
-        );
-        let id_pos = rect.min + egui::vec2(10.0, 30.0);
-        painter.text(
-            id_pos,
-            egui::Align2::LEFT_TOP,
-            format!("ID: {}", node.id),
-            egui::FontId::proportional(12.0),
-            colors.text_secondary,
+        if let Some(color) = stage_color {
+            bg = blend_color(color, bg, STAGE_FILL_RATIO);
+        }
+        let border = match role {
+            _ if on_path == Some(true) => egui::Stroke::new(3.0, colors.critical_path),
+            Some(ImpactRole::Upstream) => egui::Stroke::new(2.6, colors.upstream_highlight),
+            Some(ImpactRole::Downstream) => egui::Stroke::new(2.6, colors.downstream_highlight),
+            _ => egui::Stroke::new(1.6, tint(stage_color.unwrap_or(colors.node_border))),
+        };
+        // 표식 Step은 하는 일이 없으므로 알약 모양의 작은 노드로 그린다.
+        let compact = node.kind == StepKind::Marker;
+        let rounding = if compact { rect.height() / 2.0 } else { 10.0 };
+        painter.rect_filled(rect, rounding, tint(bg));
+        painter.rect_stroke(rect, rounding, border);
+        let visual = self.get_theme().step_visual_with(
+            Self::visual_kind_for(node.kind),
+            node.icon.as_deref(),
+            node.label.as_deref(),
         );
-        let visual = self
-            .get_theme()
-            .step_visual(Self::visual_kind_for(node.kind));
         let mut subtitle = visual.label.to_string();
-        if let EditorStepConfig::Extract { config } = &node.config {
+        if compact {
+            subtitle = String::new();
+        } else if let EditorStepConfig::Extract { config } = &node.config {
             if config.var_name.is_empty() {
-                subtitle = format!("{} → 변수 미지정", visual.label);
+                subtitle = tf("builder.canvas.no_var", &[("label", &visual.label)]);
             } else {
                 subtitle = format!("{} → ${}", visual.label, config.var_name);
             }
         } else if let EditorStepConfig::Loop { config } = &node.config {
             subtitle = format!("{} · {} steps", visual.label, config.nodes.len());
         }
-        let type_pos = rect.min + egui::vec2(10.0, 48.0);
-        painter.text(
-            type_pos,
-            egui::Align2::LEFT_TOP,
-            format!("{} {}", visual.icon, subtitle),
-            egui::FontId::proportional(14.0),
-            visual.color,
+        if node.included_from.is_some() {
+            subtitle = format!("🔗 {subtitle}");
+        }
+        let text_colors = [
+            tint(colors.text_primary),
+            tint(colors.text_secondary),
+            tint(visual.color),
+        ];
+        let labels = cache.node_labels(
+            painter,
+            node,
+            format!("{} {}", visual.icon, subtitle).trim_end(),
+            text_colors,
         );
+        if compact {
+            let icon_width = labels.subtitle.size().x;
+            let left = rect.left_center() + egui::vec2(14.0, 0.0);
+            painter.galley(
+                left - egui::vec2(0.0, labels.subtitle.size().y / 2.0),
+                labels.subtitle,
+                text_colors[2],
+            );
+            painter.galley(
+                left + egui::vec2(icon_width + 4.0, -labels.title.size().y / 2.0),
+                labels.title,
+                text_colors[0],
+            );
+        } else {
+            painter.galley(
+                rect.min + egui::vec2(10.0, 8.0),
+                labels.title,
+                text_colors[0],
+            );
+            painter.galley(rect.min + egui::vec2(10.0, 30.0), labels.id, text_colors[1]);
+            painter.galley(
+                rect.min + egui::vec2(10.0, 48.0),
+                labels.subtitle,
+                text_colors[2],
+            );
+        }
         let input_center = rect.center_top() - egui::vec2(0.0, 6.0);
         let output_center = rect.center_bottom() + egui::vec2(0.0, 6.0);
-        painter.circle_filled(input_center, 5.0, colors.handle_fill);
-        painter.circle_filled(output_center, 5.0, colors.handle_fill);
+        painter.circle_filled(input_center, 5.0, tint(colors.handle_fill));
+        painter.circle_filled(output_center, 5.0, tint(colors.handle_fill));
+        if let (Some(ImpactRole::Focus), Some(preview)) = (role, impact) {
+            Self::draw_downstream_badge(painter, rect, preview.downstream.len(), colors);
+        }
+        if self.get_state().sql_changes.contains_key(&node.id) {
+            Self::draw_sql_changed_badge(
+                painter,
+                rect,
+                tint(self.get_theme().palette().accent_warning),
+            );
+        }
+        if on_path == Some(true)
+            && let Some(stats) = self.get_state().step_stats.get(&node.id)
+        {
+            painter.text(
+                rect.right_top() + egui::vec2(-10.0, 8.0),
+                egui::Align2::RIGHT_TOP,
+                format!("⏱ {}", format_duration(stats.avg)),
+                egui::FontId::proportional(12.0),
+                colors.critical_path,
+            );
+        }
+    }
+
+    /// 크리티컬 패스 분석 요약을 그린다.
+    ///
+    /// 예상 총 소요 시간과 경로, 줄였을 때 전체 실행이 가장 많이 짧아지는 Step 목록을 보여 주며,
+    /// Step을 누르면 캔버스에서 선택한다.
+    pub(super) fn render_critical_path_summary(
+        &mut self,
+        ui: &mut egui::Ui,
+        critical: Option<&CriticalPathAnalysis>,
+    ) {
+        let palette = *self.get_theme().palette();
+        let Some(analysis) = critical else {
+            ui.colored_label(palette.accent_warning, t("builder.critical.cycle"));
+            return;
+        };
+        if analysis.path.is_empty() {
+            ui.label(
+                egui::RichText::new(t("builder.critical.no_steps"))
+                    .color(palette.fg_text_secondary),
+            );
+            return;
+        }
+        ui.horizontal_wrapped(|ui| {
+            ui.label(
+                egui::RichText::new(tf(
+                    "builder.critical.total",
+                    &[("total", &format_duration(analysis.total))],
+                ))
+                .strong(),
+            );
+            ui.label(
+                egui::RichText::new(analysis.path.join(" → ")).color(palette.fg_text_secondary),
+            );
+        });
+        let mut selected = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.label(tf(
+                "builder.critical.top",
+                &[("CRITICAL_PATH_TOP_STEPS", &CRITICAL_PATH_TOP_STEPS)],
+            ));
+            if analysis.bottlenecks.is_empty() {
+                ui.label(
+                    egui::RichText::new(t("builder.critical.no_history"))
+                        .color(palette.fg_text_secondary),
+                );
+            }
+            for (rank, (step_id, saving)) in analysis.bottlenecks.iter().enumerate() {
+                if ui
+                    .link(format!(
+                        "{}. {step_id} (−{})",
+                        rank + 1,
+                        format_duration(*saving)
+                    ))
+                    .on_hover_text(t("builder.critical.saving_hover"))
+                    .clicked()
+                {
+                    selected = Some(step_id.clone());
+                }
+            }
+        });
+        if !analysis.unmeasured.is_empty() {
+            ui.label(
+                egui::RichText::new(tf(
+                    "builder.critical.unmeasured",
+                    &[("count", &analysis.unmeasured.len())],
+                ))
+                .color(palette.fg_text_secondary),
+            );
+        }
+        if selected.is_some() {
+            self.select_node(&selected);
+        }
+    }
+
+    /// 포커스 노드 우측 상단에 하위 의존 노드 수 배지를 그린다.
+    fn draw_downstream_badge(
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        count: usize,
+        colors: BuilderColors,
+    ) {
+        let text = format!("↓ {count}");
+        let galley =
+            painter.layout_no_wrap(text, egui::FontId::proportional(12.0), egui::Color32::WHITE);
+        let size = galley.size() + egui::vec2(12.0, 4.0);
+        let badge = egui::Rect::from_center_size(rect.right_top(), size);
+        painter.rect_filled(badge, size.y / 2.0, colors.downstream_highlight);
+        painter.galley(
+            badge.center() - galley.size() / 2.0,
+            galley,
+            egui::Color32::WHITE,
+        );
+    }
+
+    /// 마지막 성공 실행 이후 SQL 파일이 바뀐 노드의 왼쪽 위 모서리에 `변경됨` 배지를 그린다.
+    fn draw_sql_changed_badge(painter: &egui::Painter, rect: egui::Rect, fill: egui::Color32) {
+        let galley = painter.layout_no_wrap(
+            t("builder.canvas.changed").to_string(),
+            egui::FontId::proportional(11.0),
+            egui::Color32::WHITE,
+        );
+        let size = galley.size() + egui::vec2(12.0, 4.0);
+        let badge =
+            egui::Rect::from_center_size(rect.left_top() + egui::vec2(size.x / 2.0, 0.0), size);
+        painter.rect_filled(badge, size.y / 2.0, fill);
+        painter.galley(
+            badge.center() - galley.size() / 2.0,
+            galley,
+            egui::Color32::WHITE,
+        );
     }
 
     /// StepKind를 시각 스타일 분류로 매핑한다.
-    fn visual_kind_for(kind: StepKind) -> StepVisualKind {
+    pub(super) fn visual_kind_for(kind: StepKind) -> StepVisualKind {
         match kind {
             StepKind::Sql => StepVisualKind::Sql,
             StepKind::SqlFile => StepVisualKind::SqlFile,
@@ -146,6 +678,82 @@ impl<'a> ScenarioBuilderUi<'a> {
             StepKind::Shell => StepVisualKind::Shell,
             StepKind::Extract => StepVisualKind::Extract,
             StepKind::Loop => StepVisualKind::Loop,
+            StepKind::ManualGate => StepVisualKind::ManualGate,
+            StepKind::Wait => StepVisualKind::Wait,
+            StepKind::WaitForRun => StepVisualKind::WaitForRun,
+            StepKind::Verify => StepVisualKind::Verify,
+            StepKind::Marker => StepVisualKind::Marker,
+        }
+    }
+}
+
+/// 스테이지별 색상으로 칠할 때 노드 배경색과 섞는 비율이다.
+const STAGE_FILL_RATIO: f32 = 0.8;
+
+/// 영향 미리보기에서 흐리게 그릴 때 캔버스 배경색과 섞는 비율이다.
+const DIM_RATIO: f32 = 0.65;
+
+/// 그리드 선을 캔버스 배경색에 섞는 비율이다. 노드보다 눈에 띄지 않도록 높게 잡는다.
+const GRID_LINE_RATIO: f32 = 0.85;
+
+/// 영향 미리보기에서 노드나 연결이 갖는 역할이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImpactRole {
+    /// 호버 또는 선택된 기준 노드.
+    Focus,
+    /// 기준 노드가 의존하는 상위 노드.
+    Upstream,
+    /// 기준 노드에 의존하는 하위 노드.
+    Downstream,
+    /// 기준 노드와 관계없는 노드.
+    Unrelated,
+}
+
+/// 호버 또는 선택된 노드를 기준으로 계산한 상·하위 의존 관계이다.
+struct ImpactPreview {
+    /// 기준 노드 ID.
+    focus: String,
+    /// 직간접 상위 노드 ID 집합.
+    upstream: HashSet<String>,
+    /// 직간접 하위 노드 ID 집합.
+    downstream: HashSet<String>,
+}
+
+impl ImpactPreview {
+    /// 에디터 상태에서 기준 노드의 영향 범위를 계산한다.
+    fn new(state: &ScenarioEditorState, focus: String) -> Self {
+        Self {
+            upstream: state.upstream_of(&focus),
+            downstream: state.downstream_of(&focus),
+            focus,
+        }
+    }
+
+    /// 노드의 역할을 반환한다.
+    fn node_role(&self, id: &str) -> ImpactRole {
+        if id == self.focus {
+            ImpactRole::Focus
+        } else if self.upstream.contains(id) {
+            ImpactRole::Upstream
+        } else if self.downstream.contains(id) {
+            ImpactRole::Downstream
+        } else {
+            ImpactRole::Unrelated
+        }
+    }
+
+    /// 연결이 상위 경로인지 하위 경로인지 판별한다.
+    fn edge_role(&self, conn: &EditorConnection) -> ImpactRole {
+        let from = self.node_role(&conn.from_id);
+        let to = self.node_role(&conn.to_id);
+        match (from, to) {
+            (ImpactRole::Upstream, ImpactRole::Upstream | ImpactRole::Focus) => {
+                ImpactRole::Upstream
+            }
+            (ImpactRole::Focus | ImpactRole::Downstream, ImpactRole::Downstream) => {
+                ImpactRole::Downstream
+            }
+            _ => ImpactRole::Unrelated,
         }
     }
 }
@@ -0,0 +1,178 @@
+use super::*;
+use eframe::epaint::Galley;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 화면 밖 노드를 건너뛸 때 보이는 영역 바깥으로 더 그리는 여유 폭이다. 끄는 중인 노드의 그림자나
+/// 배지가 가장자리에서 잘리지 않게 한다.
+pub(super) const CULL_MARGIN: f32 = 48.0;
+
+/// 프레임 사이에 재사용하는 캔버스 계산 결과이다.
+///
+/// 에디터 상태가 아니라 egui 임시 메모리에 두며, 입력 해시가 바뀐 항목만 다시 계산한다.
+#[derive(Clone, Default)]
+pub(super) struct CanvasCache {
+    /// 데이터 흐름 힌트를 계산한 노드·연결 구성의 해시.
+    hints_key: Option<u64>,
+    /// 마지막으로 계산한 데이터 흐름 힌트.
+    hints: Arc<Vec<DataFlowHint>>,
+    /// 노드 ID별 텍스트 레이아웃과 그 입력의 해시.
+    labels: HashMap<String, (u64, NodeLabels)>,
+}
+
+/// 노드 카드에 그리는 텍스트 레이아웃이다.
+#[derive(Clone)]
+pub(super) struct NodeLabels {
+    /// 노드 이름.
+    pub title: Arc<Galley>,
+    /// `ID: ...` 줄.
+    pub id: Arc<Galley>,
+    /// 유형 아이콘과 부제.
+    pub subtitle: Arc<Galley>,
+}
+
+impl CanvasCache {
+    /// egui 임시 메모리에서 캐시를 꺼낸다. 다 쓰면 [`CanvasCache::store`]로 되돌려 놓는다.
+    pub(super) fn take(ui: &egui::Ui) -> Self {
+        ui.data_mut(|d| std::mem::take(d.get_temp_mut_or_default::<Self>(Self::id())))
+    }
+
+    /// 캐시를 egui 임시 메모리에 되돌려 놓는다.
+    pub(super) fn store(self, ui: &egui::Ui) {
+        ui.data_mut(|d| d.insert_temp(Self::id(), self));
+    }
+
+    /// 캐시를 저장하는 egui 메모리 ID이다.
+    fn id() -> egui::Id {
+        egui::Id::new("builder_canvas_cache")
+    }
+
+    /// 데이터 흐름 힌트를 반환한다. 노드의 변수 선언이나 연결이 바뀐 경우에만 다시 계산한다.
+    pub(super) fn data_flow_hints(
+        &mut self,
+        state: &ScenarioEditorState,
+    ) -> Arc<Vec<DataFlowHint>> {
+        let mut hasher = DefaultHasher::new();
+        for node in &state.nodes {
+            node.id.hash(&mut hasher);
+            node.consumes.hash(&mut hasher);
+            node.produced_vars().hash(&mut hasher);
+        }
+        for conn in &state.connections {
+            conn.from_id.hash(&mut hasher);
+            conn.to_id.hash(&mut hasher);
+        }
+        let key = hasher.finish();
+        if self.hints_key != Some(key) {
+            self.hints = Arc::new(state.data_flow_hints());
+            self.hints_key = Some(key);
+        }
+        self.hints.clone()
+    }
+
+    /// 노드 카드 텍스트 레이아웃을 반환한다. 글자나 색이 바뀐 노드만 다시 배치한다.
+    ///
+    /// `colors`는 제목, ID, 부제 순서의 글자색이다.
+    pub(super) fn node_labels(
+        &mut self,
+        painter: &egui::Painter,
+        node: &EditorStepNode,
+        subtitle: &str,
+        colors: [egui::Color32; 3],
+    ) -> NodeLabels {
+        let mut hasher = DefaultHasher::new();
+        node.name.hash(&mut hasher);
+        node.id.hash(&mut hasher);
+        subtitle.hash(&mut hasher);
+        colors.hash(&mut hasher);
+        let key = hasher.finish();
+        if let Some((cached_key, labels)) = self.labels.get(&node.id)
+            && *cached_key == key
+        {
+            return labels.clone();
+        }
+        let [title_color, id_color, subtitle_color] = colors;
+        let labels = NodeLabels {
+            title: painter.layout_no_wrap(
+                node.name.clone(),
+                egui::FontId::proportional(16.0),
+                title_color,
+            ),
+            id: painter.layout_no_wrap(
+                format!("ID: {}", node.id),
+                egui::FontId::proportional(12.0),
+                id_color,
+            ),
+            subtitle: painter.layout_no_wrap(
+                subtitle.to_string(),
+                egui::FontId::proportional(14.0),
+                subtitle_color,
+            ),
+        };
+        self.labels.insert(node.id.clone(), (key, labels.clone()));
+        labels
+    }
+
+    /// 지워진 노드의 텍스트 레이아웃을 버린다.
+    pub(super) fn prune(&mut self, state: &ScenarioEditorState) {
+        if self.labels.len() <= state.nodes.len() {
+            return;
+        }
+        let ids: HashSet<&str> = state.nodes.iter().map(|node| node.id.as_str()).collect();
+        self.labels.retain(|id, _| ids.contains(id.as_str()));
+    }
+}
+
+/// 한 프레임의 캔버스 그리기 통계이다. 성능 오버레이에 표시한다.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct CanvasPaintStats {
+    /// 그린 노드 수.
+    pub nodes_drawn: usize,
+    /// 전체 노드 수.
+    pub nodes_total: usize,
+    /// 그린 연결 수.
+    pub connections_drawn: usize,
+    /// 전체 연결 수.
+    pub connections_total: usize,
+}
+
+/// 캔버스 보이는 영역 오른쪽 위에 FPS와 캔버스 구성 시간, 그린 노드·연결 수를 표시한다.
+///
+/// 구성 시간은 캔버스 도형을 만드는 데 걸린 시간이며 GPU 렌더링은 포함하지 않는다.
+pub(super) fn draw_perf_overlay(
+    ui: &egui::Ui,
+    viewport: egui::Rect,
+    stats: CanvasPaintStats,
+    build_time: Duration,
+    palette: ThemePalette,
+) {
+    let dt = ui.input(|input| input.stable_dt).max(f32::EPSILON);
+    let text = tf(
+        "builder.perf.overlay",
+        &[
+            ("fps", &format!("{:.0}", 1.0 / dt)),
+            (
+                "build_ms",
+                &format!("{:.2}", build_time.as_secs_f64() * 1000.0),
+            ),
+            ("nodes", &stats.nodes_drawn),
+            ("nodes_total", &stats.nodes_total),
+            ("connections", &stats.connections_drawn),
+            ("connections_total", &stats.connections_total),
+        ],
+    );
+    let painter = ui.painter_at(viewport);
+    let galley =
+        painter.layout_no_wrap(text, egui::FontId::monospace(12.0), palette.fg_text_primary);
+    let size = galley.size() + egui::vec2(16.0, 12.0);
+    let frame =
+        egui::Rect::from_min_size(viewport.right_top() + egui::vec2(-size.x - 8.0, 8.0), size);
+    painter.rect_filled(frame, 6.0, palette.bg_panel.gamma_multiply(0.92));
+    painter.rect_stroke(frame, 6.0, Stroke::new(1.0, palette.border_soft));
+    painter.galley(
+        frame.min + egui::vec2(8.0, 6.0),
+        galley,
+        palette.fg_text_primary,
+    );
+}
@@ -72,6 +72,22 @@ impl<'a> ScenarioBuilderUi<'a> {
             .show(ctx, |ui| {
                 self.render_properties(ui);
             });
+        if self.get_state().schema_browser.is_some() {
+            let schema_frame = egui::Frame {
+                fill: palette.bg_sidebar,
+                stroke: egui::Stroke::new(1.0, palette.border_soft),
+                rounding: egui::Rounding::same(decorations.container_rounding),
+                inner_margin: decorations.card_inner_margin,
+                ..Default::default()
+            };
+            egui::SidePanel::right("builder_schema")
+                .frame(schema_frame)
+                .resizable(true)
+                .default_width(240.0)
+                .show(ctx, |ui| {
+                    self.render_schema_browser(ui);
+                });
+        }
         let canvas_frame = egui::Frame {
             fill: builder_colors.canvas_fill,
             stroke: egui::Stroke::new(1.0, palette.border_soft),
@@ -82,7 +98,202 @@ impl<'a> ScenarioBuilderUi<'a> {
         egui::CentralPanel::default()
             .frame(canvas_frame)
             .show(ctx, |ui| {
-                self.render_canvas(ui, builder_colors);
+                if !self.get_state().loop_stack.is_empty() {
+                    self.render_loop_breadcrumb(ui);
+                    ui.add_space(4.0);
+                }
+                ui.horizontal(|ui| {
+                    let mode = &mut self.get_state_mut().view_mode;
+                    ui.selectable_value(mode, BuilderViewMode::Canvas, t("builder.layout.canvas"));
+                    ui.selectable_value(mode, BuilderViewMode::List, t("builder.layout.list"));
+                    ui.separator();
+                    ui.checkbox(
+                        &mut self.get_state_mut().color_by_stage,
+                        t("builder.layout.stage_colors"),
+                    );
+                    ui.checkbox(
+                        &mut self.get_state_mut().critical_path_mode,
+                        t("builder.layout.critical_path"),
+                    )
+                    .on_hover_text(t("builder.layout.critical_path_hover"));
+                    let mut order_sim = self.get_state().order_sim.is_some();
+                    if ui
+                        .checkbox(&mut order_sim, t("builder.layout.order_sim"))
+                        .on_hover_text(t("builder.layout.order_sim_hover"))
+                        .changed()
+                    {
+                        self.get_state_mut().order_sim = order_sim.then(OrderSimPlayback::new);
+                    }
+                    let mut schema = self.get_state().schema_browser.is_some();
+                    if ui
+                        .checkbox(&mut schema, t("builder.layout.schema"))
+                        .on_hover_text(t("builder.layout.schema_hover"))
+                        .changed()
+                    {
+                        let target = schema_target_hint(self.get_state());
+                        self.get_state_mut().schema_browser =
+                            schema.then(|| SchemaBrowser::new(target));
+                    }
+                    if self.get_state().view_mode == BuilderViewMode::Canvas {
+                        ui.separator();
+                        self.render_arrange_controls(ui);
+                    }
+                });
+                ui.add_space(6.0);
+                self.render_paste_notice(ui);
+                let critical = self.get_state().critical_path_mode.then(|| {
+                    let state = self.get_state();
+                    CriticalPathAnalysis::new(&state.nodes, &state.connections, &state.step_stats)
+                });
+                if let Some(analysis) = &critical {
+                    self.render_critical_path_summary(ui, analysis.as_ref());
+                    ui.add_space(6.0);
+                }
+                let order_sim = self.get_state().order_sim.as_ref().map(|playback| {
+                    let state = self.get_state();
+                    OrderSimulation::new(
+                        &state.nodes,
+                        &state.connections,
+                        &state.step_stats,
+                        &playback.failures,
+                    )
+                });
+                if let Some(sim) = &order_sim {
+                    self.render_order_sim_summary(ui, sim);
+                    ui.add_space(6.0);
+                }
+                match self.get_state().view_mode {
+                    BuilderViewMode::Canvas => self.render_canvas(
+                        ui,
+                        builder_colors,
+                        critical.flatten().as_ref(),
+                        order_sim.as_ref(),
+                    ),
+                    BuilderViewMode::List => self.render_step_list(ui),
+                }
             });
+        self.render_sql_diff_window(ctx);
+        self.handle_step_clipboard(ctx);
+    }
+
+    /// 캔버스 그리드 맞춤·정렬 가이드 설정과 선택 노드 간격 맞춤 버튼을 그린다.
+    fn render_arrange_controls(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(
+            &mut self.get_state_mut().snap_to_grid,
+            t("builder.layout.snap"),
+        );
+        ui.checkbox(
+            &mut self.get_state_mut().align_guides,
+            t("builder.layout.guides"),
+        )
+        .on_hover_text(t("builder.layout.guides_hover"));
+        let hint = t("builder.layout.distribute_hint");
+        let enabled = self.get_state().selected_count() >= 3;
+        for (axis, label) in [
+            (DistributeAxis::Horizontal, t("builder.layout.distribute_h")),
+            (DistributeAxis::Vertical, t("builder.layout.distribute_v")),
+        ] {
+            if ui
+                .add_enabled(enabled, egui::Button::new(label))
+                .on_hover_text(hint)
+                .on_disabled_hover_text(hint)
+                .clicked()
+            {
+                self.get_state_mut().distribute_selected(axis);
+            }
+        }
+    }
+
+    /// 캔버스로 연 Loop 경로를 그린다. 상위 단계를 누르면 그 흐름으로 돌아간다.
+    fn render_loop_breadcrumb(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.get_theme().palette();
+        let mut target: Option<usize> = None;
+        ui.horizontal_wrapped(|ui| {
+            if ui
+                .button(t("builder.layout.up"))
+                .on_hover_text(t("builder.layout.up_hover"))
+                .clicked()
+            {
+                target = Some(self.get_state().loop_stack.len() - 1);
+            }
+            ui.separator();
+            let state = self.get_state();
+            if ui.link(state.scenario_name.as_str()).clicked() {
+                target = Some(0);
+            }
+            let depth = state.loop_stack.len();
+            for (idx, frame) in state.loop_stack.iter().enumerate() {
+                ui.label("›");
+                let label = format!("🔁 {}", frame.loop_name);
+                if idx + 1 == depth {
+                    ui.label(egui::RichText::new(label).strong());
+                } else if ui.link(label).clicked() {
+                    target = Some(idx + 1);
+                }
+            }
+            ui.label(
+                egui::RichText::new(t("builder.layout.editing_loop"))
+                    .color(palette.fg_text_secondary),
+            );
+        });
+        if let Some(depth) = target {
+            self.get_state_mut().close_loops_to(depth);
+        }
+    }
+
+    /// 마지막 Step 붙여넣기에서 알릴 내용이 있으면 닫기 버튼과 함께 보여 준다.
+    fn render_paste_notice(&mut self, ui: &mut egui::Ui) {
+        let Some(notice) = self.get_state().paste_notice.clone() else {
+            return;
+        };
+        let palette = *self.get_theme().palette();
+        let mut close = false;
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(notice).color(palette.accent_warning));
+            close = ui
+                .small_button("✕")
+                .on_hover_text(t("builder.layout.close_notice"))
+                .clicked();
+        });
+        if close {
+            self.get_state_mut().paste_notice = None;
+        }
+        ui.add_space(6.0);
+    }
+
+    /// 입력란에 포커스가 없을 때 Step 복사(Ctrl+C)·붙여넣기(Ctrl+V)·복제(Ctrl+D)를 처리한다.
+    ///
+    /// 선택한 노드(여러 개 고른 경우 모두)를 쓰는 DB 연결 정의와 함께 YAML로 시스템 클립보드에 넣으므로,
+    /// 다른 시나리오를 열어 그대로 붙여 넣을 수 있고 시나리오 파일에서 복사한 Step 정의도 붙여 넣을 수 있다.
+    /// 붙여 넣거나 복제한 Step의 ID가 겹치면 접미사를 붙인다.
+    fn handle_step_clipboard(&mut self, ctx: &egui::Context) {
+        if ctx.memory(|mem| mem.focused().is_some()) {
+            return;
+        }
+        let (copy, paste, duplicate) = ctx.input_mut(|input| {
+            let copy = input
+                .events
+                .iter()
+                .any(|event| matches!(event, egui::Event::Copy));
+            let paste = input.events.iter().find_map(|event| match event {
+                egui::Event::Paste(text) => Some(text.clone()),
+                _ => None,
+            });
+            let duplicate = input.consume_key(egui::Modifiers::COMMAND, egui::Key::D);
+            (copy, paste, duplicate)
+        });
+        let selected = self.get_state().selected_node_id.clone();
+        if copy && let Some(yaml) = self.get_state().copy_selected_steps_yaml() {
+            ctx.output_mut(|output| output.copied_text = yaml);
+        }
+        if let Some(text) = paste {
+            let report = self.get_state_mut().paste_steps_yaml(&text);
+            if !report.added.is_empty() {
+                self.get_state_mut().paste_notice = report.notice();
+            }
+        }
+        if duplicate && let Some(id) = selected {
+            self.get_state_mut().duplicate_node(&id);
+        }
     }
 }
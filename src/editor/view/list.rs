@@ -0,0 +1,154 @@
+use super::*;
+
+/// 목록 보기 한 행의 높이이다.
+const ROW_HEIGHT: f32 = 30.0;
+
+impl<'a> ScenarioBuilderUi<'a> {
+    /// 캔버스 대신 Step을 표 형태로 보여 주는 목록 보기를 렌더링한다.
+    ///
+    /// 머리글을 누르면 해당 기준으로 정렬하고, `☰` 손잡이를 끌어 순서를 바꾼다. 목록 순서가 저장되는
+    /// Step 순서이며, `체인으로 연결`은 이 순서대로 순차 의존성을 만든다.
+    pub(super) fn render_step_list(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.get_theme().palette();
+        ui.horizontal(|ui| {
+            let chain = ui
+                .add_enabled(
+                    self.get_state().nodes.len() > 1,
+                    egui::Button::new(t("builder.list.chain")),
+                )
+                .on_hover_text(t("builder.list.chain_hint"));
+            if chain.clicked() {
+                self.get_state_mut().chain_in_order();
+            }
+            ui.label(
+                egui::RichText::new(t("builder.list.drag_hint")).color(palette.fg_text_secondary),
+            );
+        });
+        ui.add_space(6.0);
+        let sort = self.get_state().list_sort;
+        ui.horizontal(|ui| {
+            ui.add_sized([28.0, ROW_HEIGHT], egui::Label::new(""));
+            ui.add_sized(
+                [28.0, ROW_HEIGHT],
+                egui::Label::new(egui::RichText::new("#").strong()),
+            );
+            for (label, key, width) in [
+                (t("builder.col.kind"), StepListSort::Kind, 140.0),
+                ("ID", StepListSort::Id, 160.0),
+                (t("builder.col.name"), StepListSort::Name, 240.0),
+            ] {
+                let arrow = match sort {
+                    Some((current, false)) if current == key => " ▲",
+                    Some((current, true)) if current == key => " ▼",
+                    _ => "",
+                };
+                if ui
+                    .add_sized(
+                        [width, ROW_HEIGHT],
+                        egui::Button::new(egui::RichText::new(format!("{label}{arrow}")).strong())
+                            .frame(false),
+                    )
+                    .clicked()
+                {
+                    self.get_state_mut().sort_nodes(key);
+                }
+            }
+            ui.label(egui::RichText::new(t("builder.col.predecessors")).strong());
+        });
+        ui.separator();
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                let mut row_rects: Vec<egui::Rect> =
+                    Vec::with_capacity(self.get_state().nodes.len());
+                let mut clicked: Option<String> = None;
+                for idx in 0..self.get_state().nodes.len() {
+                    let node = &self.get_state().nodes[idx];
+                    let visual = self.get_theme().step_visual_with(
+                        Self::visual_kind_for(node.kind),
+                        node.icon.as_deref(),
+                        node.label.as_deref(),
+                    );
+                    let deps = self.get_state().dependencies_of(&node.id).join(", ");
+                    let row = ui.horizontal(|ui| {
+                        let handle = ui
+                            .add_sized(
+                                [28.0, ROW_HEIGHT],
+                                egui::Label::new("☰").sense(egui::Sense::drag()),
+                            )
+                            .on_hover_cursor(egui::CursorIcon::Grab);
+                        ui.add_sized([28.0, ROW_HEIGHT], egui::Label::new((idx + 1).to_string()));
+                        ui.add_sized(
+                            [140.0, ROW_HEIGHT],
+                            egui::Label::new(
+                                egui::RichText::new(format!("{} {}", visual.icon, visual.label))
+                                    .color(visual.color),
+                            ),
+                        );
+                        let id_label = ui.add_sized(
+                            [160.0, ROW_HEIGHT],
+                            egui::SelectableLabel::new(node.selected, node.id.as_str()),
+                        );
+                        let name_label = ui.add_sized(
+                            [240.0, ROW_HEIGHT],
+                            egui::SelectableLabel::new(node.selected, node.name.as_str()),
+                        );
+                        ui.label(egui::RichText::new(deps).color(palette.fg_text_secondary));
+                        (
+                            handle.drag_started(),
+                            id_label.clicked() || name_label.clicked(),
+                        )
+                    });
+                    let (drag_started, row_clicked) = row.inner;
+                    if drag_started {
+                        self.get_state_mut().list_drag = Some(idx);
+                    }
+                    if row_clicked {
+                        clicked = Some(self.get_state().nodes[idx].id.clone());
+                    }
+                    row_rects.push(row.response.rect);
+                }
+                if let Some(id) = clicked {
+                    self.get_state_mut().select_node(Some(id));
+                }
+                self.handle_list_drag(ui, &row_rects, palette.accent_primary);
+            });
+    }
+
+    /// 끌고 있는 행의 삽입 위치를 표시하고, 놓으면 노드 순서를 바꾼다.
+    fn handle_list_drag(
+        &mut self,
+        ui: &mut egui::Ui,
+        row_rects: &[egui::Rect],
+        color: egui::Color32,
+    ) {
+        let Some(from) = self.get_state().list_drag else {
+            return;
+        };
+        let Some(pointer) = ui.ctx().pointer_interact_pos() else {
+            return;
+        };
+        let target = row_rects
+            .iter()
+            .filter(|rect| rect.center().y < pointer.y)
+            .count();
+        if let Some(first) = row_rects.first() {
+            let y = row_rects
+                .get(target)
+                .map(|rect| rect.top())
+                .or_else(|| row_rects.last().map(|rect| rect.bottom()))
+                .unwrap_or(first.top());
+            ui.painter().hline(
+                first.left()..=first.right(),
+                y,
+                egui::Stroke::new(2.0, color),
+            );
+        }
+        if ui.input(|input| input.pointer.any_released()) {
+            let state = self.get_state_mut();
+            state.list_drag = None;
+            let to = if target > from { target - 1 } else { target };
+            state.move_node(from, to);
+        }
+    }
+}
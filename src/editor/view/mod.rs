@@ -1,15 +1,37 @@
 use super::model::{
-    DbConnectionEditor, EditorStepConfig, EditorStepNode, ScenarioEditorState, StepKind,
+    AlignGuide, BuilderViewMode, CRITICAL_PATH_TOP_STEPS, CriticalPathAnalysis, DataFlowHint,
+    DbConnectionEditor, DeletedStep, DistributeAxis, EditorConnection, EditorError,
+    EditorStepConfig, EditorStepNode, GRID_SPACING, NodeDeletePlan, ORDER_SIM_DEFAULT_STEP,
+    OrderSimEntry, OrderSimPlayback, OrderSimulation, ScenarioEditorState, SchemaBrowser,
+    SchemaFetch, ShellTrial, ShellTrialStatus, SqlPreview, SqlPreviewStatus, StepKind,
+    StepListSort, StepTest, StepTestStatus, TRASH_CAPACITY,
 };
-use crate::scenario::{ConfirmDefault, DbKind, ExtractVarFromFileConfig, LoopIterationFailure};
-use crate::theme::{BuilderColors, StepVisualKind, Theme, ThemeDecorations, ThemePalette};
+use crate::history::format_duration;
+use crate::i18n::{t, tf};
+use crate::scenario::{
+    ConfirmDefault, DbConnectionConfig, DbKind, ExtractVarFromFileConfig, LoopIterationFailure,
+    MissingOutputPolicy, RetryOn, StepDefaults, StepInclude, StepLogLevel, StepSkipIf,
+    StepTimeWindow, TimeWindowPolicy, TimeoutPolicy,
+};
+use crate::sql_changes::{DiffLine, SqlFileChange};
+use crate::theme::{
+    BuilderColors, StepVisualKind, Theme, ThemeDecorations, ThemePalette, blend_color,
+};
+use canvas_cache::{CULL_MARGIN, CanvasCache, CanvasPaintStats, draw_perf_overlay};
 use eframe::egui;
 use eframe::epaint::{CubicBezierShape, Stroke};
-use std::collections::HashMap;
+use schema_browser::{accept_schema_drop, schema_target_hint};
+use std::collections::{HashMap, HashSet};
 
 mod canvas;
+mod canvas_cache;
 mod layout;
+mod list;
+mod order_sim;
 mod palette;
 mod properties;
+mod schema_browser;
+mod sql_diff;
+mod trash;
 
 pub use layout::ScenarioBuilderUi;
@@ -0,0 +1,191 @@
+use super::*;
+
+/// 재생할 때 시점 하나를 보여 주는 시간(초)이다.
+const SECONDS_PER_TICK: f32 = 0.8;
+
+/// 아직 시작하지 않은 노드를 캔버스 배경색으로 덮는 불투명도이다.
+const PENDING_VEIL_ALPHA: f32 = 0.6;
+
+impl<'a> ScenarioBuilderUi<'a> {
+    /// 실행 순서 시뮬레이션 요약과 재생 조작을 그리고, 재생 중이면 재생 위치를 진행한다.
+    ///
+    /// 선택한 Step의 실패를 가정하면 그 하위 Step이 건너뛰어지는 모습도 재생한다.
+    pub(super) fn render_order_sim_summary(&mut self, ui: &mut egui::Ui, sim: &OrderSimulation) {
+        let palette = *self.get_theme().palette();
+        let last_tick = sim.ticks.saturating_sub(1) as f32;
+        let dt = ui.input(|input| input.stable_dt);
+        let selected = self.get_state().selected_node_id.clone();
+        let Some(playback) = self.get_state_mut().order_sim.as_mut() else {
+            return;
+        };
+        if playback.playing {
+            playback.playhead += dt / SECONDS_PER_TICK;
+            if playback.playhead >= last_tick {
+                playback.playhead = last_tick;
+                playback.playing = false;
+            }
+            ui.ctx().request_repaint();
+        }
+        playback.playhead = playback.playhead.min(last_tick);
+
+        ui.horizontal_wrapped(|ui| {
+            ui.label(
+                egui::RichText::new(tf(
+                    "builder.sim.total",
+                    &[("total", &format_duration(sim.total))],
+                ))
+                .strong(),
+            );
+            ui.label(
+                egui::RichText::new(tf(
+                    "builder.sim.counts",
+                    &[
+                        ("started", &sim.started_count()),
+                        ("parallel", &sim.parallel_count()),
+                        ("blocked", &sim.blocked_count()),
+                    ],
+                ))
+                .color(palette.fg_text_secondary),
+            );
+        });
+        ui.horizontal_wrapped(|ui| {
+            let (icon, hint) = if playback.playing {
+                ("⏸", t("builder.sim.pause"))
+            } else {
+                ("▶", t("builder.sim.play"))
+            };
+            if ui.button(icon).on_hover_text(hint).clicked() {
+                if !playback.playing && playback.playhead >= last_tick {
+                    playback.playhead = 0.0;
+                }
+                playback.playing = !playback.playing;
+            }
+            if ui
+                .button("⏮")
+                .on_hover_text(t("builder.sim.rewind"))
+                .clicked()
+            {
+                playback.playhead = 0.0;
+            }
+            let mut tick = playback.playhead.floor() as usize;
+            if ui
+                .add_enabled(
+                    sim.ticks > 1,
+                    egui::Slider::new(&mut tick, 0..=sim.ticks.saturating_sub(1))
+                        .text(t("builder.sim.tick")),
+                )
+                .changed()
+            {
+                playback.playhead = tick as f32;
+                playback.playing = false;
+            }
+            ui.separator();
+            let assumed = selected
+                .as_ref()
+                .is_some_and(|id| playback.failures.contains(id));
+            let label = if assumed {
+                t("builder.sim.assume_success")
+            } else {
+                t("builder.sim.assume_failure")
+            };
+            if ui
+                .add_enabled(selected.is_some(), egui::Button::new(label))
+                .on_hover_text(t("builder.sim.failure_hover"))
+                .clicked()
+                && let Some(id) = &selected
+            {
+                if !playback.failures.remove(id) {
+                    playback.failures.insert(id.clone());
+                }
+                playback.playhead = 0.0;
+                playback.playing = true;
+            }
+            if !playback.failures.is_empty() {
+                let mut failures: Vec<&str> =
+                    playback.failures.iter().map(String::as_str).collect();
+                failures.sort_unstable();
+                ui.label(
+                    egui::RichText::new(tf(
+                        "builder.sim.failures",
+                        &[("steps", &failures.join(", "))],
+                    ))
+                    .color(palette.accent_error),
+                );
+                if ui.small_button(t("builder.sim.clear_failures")).clicked() {
+                    playback.failures.clear();
+                }
+            }
+        });
+        if sim.unmeasured > 0 {
+            ui.label(
+                egui::RichText::new(tf(
+                    "builder.sim.unmeasured",
+                    &[
+                        ("count", &sim.unmeasured),
+                        ("secs", &ORDER_SIM_DEFAULT_STEP.as_secs()),
+                    ],
+                ))
+                .color(palette.fg_text_secondary),
+            );
+        }
+        if !sim.unreached.is_empty() {
+            ui.colored_label(
+                palette.accent_warning,
+                tf(
+                    "builder.sim.unreached",
+                    &[("steps", &sim.unreached.join(", "))],
+                ),
+            );
+        }
+    }
+
+    /// 노드에 시뮬레이션 재생 상태를 덧그린다.
+    ///
+    /// 재생 위치에 아직 이르지 않은 노드는 흐리게 덮고, 이른 노드에는 시작 순번(병렬이면 `∥`)이나
+    /// 건너뜀 배지를 붙이며, 지금 시점에 시작하는 노드는 테두리를 강조한다.
+    pub(super) fn draw_order_sim_overlay(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        entry: Option<&OrderSimEntry>,
+        playhead: f32,
+        colors: BuilderColors,
+    ) {
+        let palette = *self.get_theme().palette();
+        let current = playhead.floor() as usize;
+        let veil = colors.canvas_fill.gamma_multiply(PENDING_VEIL_ALPHA);
+        let Some(entry) = entry.filter(|entry| entry.tick <= current) else {
+            painter.rect_filled(rect, 8.0, veil);
+            return;
+        };
+        let (text, fill) = match entry.order {
+            _ if entry.blocked => ("⛔".to_string(), palette.accent_error),
+            Some(order) if entry.failed => (format!("{order} 💥"), palette.accent_error),
+            Some(order) if entry.parallel => (format!("{order} ∥"), palette.accent_primary),
+            Some(order) => (order.to_string(), palette.accent_primary),
+            None => ("?".to_string(), palette.fg_text_secondary),
+        };
+        if entry.blocked {
+            painter.rect_filled(rect, 8.0, veil);
+        }
+        if entry.tick == current {
+            let pulse = 1.0 - playhead.fract() * 0.6;
+            painter.rect_stroke(
+                rect.expand(3.0),
+                10.0,
+                Stroke::new(3.0, fill.gamma_multiply(pulse)),
+            );
+        }
+        let galley =
+            painter.layout_no_wrap(text, egui::FontId::proportional(13.0), egui::Color32::WHITE);
+        let size = galley.size() + egui::vec2(12.0, 6.0);
+        let size = egui::vec2(size.x.max(size.y), size.y);
+        let badge = egui::Rect::from_center_size(rect.left_top(), size);
+        painter.rect_filled(badge, size.y / 2.0, fill);
+        painter.galley(
+            badge.center() - galley.size() / 2.0,
+            galley,
+            egui::Color32::WHITE,
+        );
+    }
+}
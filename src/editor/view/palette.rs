@@ -3,21 +3,28 @@ use super::*;
 impl<'a> ScenarioBuilderUi<'a> {
     /// Step 팔레트를 렌더링한다.
     pub(super) fn render_palette(&mut self, ui: &mut egui::Ui) {
-        ui.heading("🧱 Step 팔레트");
+        ui.heading(t("builder.palette.title"));
         ui.separator();
-        ui.label("추가할 Step 유형을 선택하세요.");
+        ui.label(t("builder.palette.hint"));
         ui.add_space(10.0);
         for (label, kind) in [
             ("SQL", StepKind::Sql),
-            ("SQL 파일", StepKind::SqlFile),
+            (t("builder.kind.sql_file"), StepKind::SqlFile),
             ("SQL*Loader", StepKind::SqlLoaderPar),
             ("Shell", StepKind::Shell),
-            ("Extract (값 추출)", StepKind::Extract),
-            ("Loop (반복)", StepKind::Loop),
+            (t("builder.palette.extract"), StepKind::Extract),
+            (t("builder.palette.loop"), StepKind::Loop),
+            (t("builder.kind.manual_gate"), StepKind::ManualGate),
+            (t("builder.kind.wait"), StepKind::Wait),
+            (t("builder.palette.wait_for_run"), StepKind::WaitForRun),
+            (t("builder.kind.verify"), StepKind::Verify),
+            (t("builder.palette.marker"), StepKind::Marker),
         ] {
             if ui.button(label).clicked() {
                 self.get_state_mut().add_node(kind);
             }
         }
+        ui.add_space(10.0);
+        self.render_trash(ui);
     }
 }
@@ -7,7 +7,7 @@ pub(super) fn render_confirm_section(
     confirm: &mut Option<crate::scenario::StepConfirmConfig>,
     mark_dirty: &mut bool,
 ) {
-    egui::CollapsingHeader::new("실행 컨펌")
+    egui::CollapsingHeader::new(t("builder.confirm.title"))
         .default_open(false)
         .show(ui, |ui| {
             let cfg = confirm.get_or_insert_with(|| crate::scenario::StepConfirmConfig {
@@ -17,13 +17,19 @@ pub(super) fn render_confirm_section(
                 message_after: None,
                 default_answer: ConfirmDefault::Yes,
             });
-            if ui.checkbox(&mut cfg.before, "실행 전 확인").changed() {
+            if ui
+                .checkbox(&mut cfg.before, t("builder.confirm.before"))
+                .changed()
+            {
                 *mark_dirty = true;
             }
-            if ui.checkbox(&mut cfg.after, "실행 후 확인").changed() {
+            if ui
+                .checkbox(&mut cfg.after, t("builder.confirm.after"))
+                .changed()
+            {
                 *mark_dirty = true;
             }
-            ui.label("메시지 (실행 전)");
+            ui.label(t("builder.confirm.message_before"));
             let mut before_msg = cfg.message_before.clone().unwrap_or_default();
             if ui.text_edit_singleline(&mut before_msg).changed() {
                 cfg.message_before = if before_msg.trim().is_empty() {
@@ -33,7 +39,7 @@ pub(super) fn render_confirm_section(
                 };
                 *mark_dirty = true;
             }
-            ui.label("메시지 (실행 후)");
+            ui.label(t("builder.confirm.message_after"));
             let mut after_msg = cfg.message_after.clone().unwrap_or_default();
             if ui.text_edit_singleline(&mut after_msg).changed() {
                 cfg.message_after = if after_msg.trim().is_empty() {
@@ -43,14 +49,17 @@ pub(super) fn render_confirm_section(
                 };
                 *mark_dirty = true;
             }
-            egui::ComboBox::from_label("기본 응답")
+            egui::ComboBox::from_label(t("builder.confirm.default_answer"))
                 .selected_text(match cfg.default_answer {
-                    ConfirmDefault::Yes => "예",
-                    ConfirmDefault::No => "아니오",
+                    ConfirmDefault::Yes => t("common.yes"),
+                    ConfirmDefault::No => t("common.no"),
                 })
                 .show_ui(ui, |ui| {
                     if ui
-                        .selectable_label(matches!(cfg.default_answer, ConfirmDefault::Yes), "예")
+                        .selectable_label(
+                            matches!(cfg.default_answer, ConfirmDefault::Yes),
+                            t("common.yes"),
+                        )
                         .clicked()
                     {
                         cfg.default_answer = ConfirmDefault::Yes;
@@ -59,7 +68,7 @@ pub(super) fn render_confirm_section(
                     if ui
                         .selectable_label(
                             matches!(cfg.default_answer, ConfirmDefault::No),
-                            "아니오",
+                            t("common.no"),
                         )
                         .clicked()
                     {
@@ -0,0 +1,44 @@
+use super::super::*;
+use super::*;
+
+/// 입력/출력 변수 계약(`consumes`/`produces`) 편집 UI를 그린다.
+///
+/// 변수명은 쉼표로 구분해 입력하며, 공백과 빈 항목은 저장 시 제거된다.
+pub(super) fn render_contract_section(
+    ui: &mut egui::Ui,
+    node: &mut EditorStepNode,
+    mark_dirty: &mut bool,
+) {
+    egui::CollapsingHeader::new(t("builder.contracts.title"))
+        .default_open(!node.consumes.is_empty() || !node.produces.is_empty())
+        .show(ui, |ui| {
+            ui.push_id(("contract", node.id.clone()), |ui| {
+                ui.label(t("builder.contracts.consumes"));
+                if edit_var_list(ui, &mut node.consumes) {
+                    *mark_dirty = true;
+                }
+                ui.label(t("builder.contracts.produces"));
+                if edit_var_list(ui, &mut node.produces) {
+                    *mark_dirty = true;
+                }
+            });
+        });
+}
+
+/// 쉼표로 구분된 변수 목록을 편집하고 변경 여부를 반환한다.
+fn edit_var_list(ui: &mut egui::Ui, vars: &mut Vec<String>) -> bool {
+    let mut buf = vars.join(", ");
+    if ui
+        .add(egui::TextEdit::singleline(&mut buf).hint_text(t("builder.contracts.placeholder")))
+        .changed()
+    {
+        *vars = buf
+            .split(',')
+            .map(str::trim)
+            .filter(|var| !var.is_empty())
+            .map(str::to_string)
+            .collect();
+        return true;
+    }
+    false
+}
@@ -9,18 +9,17 @@ pub(super) fn render_db_section(
     palette: ThemePalette,
     decorations: ThemeDecorations,
 ) {
-    ui.heading("🗄 DB 설정");
-    ui.label("SQL/SQL 파일 Step에서 사용할 DB 접속 정보를 정의합니다.");
+    ui.heading(t("builder.db.title"));
+    ui.label(t("builder.db.hint"));
     if !state.has_default_db() {
-        ui.colored_label(
-            palette.accent_warning,
-            "default 키가 없으면 target_db 미지정 Step이 실패합니다.",
-        );
+        ui.colored_label(palette.accent_warning, t("builder.db.no_default"));
     }
     if state.db_connections.is_empty() {
-        ui.label("등록된 DB 연결이 없습니다. 'DB 연결 추가' 버튼으로 새 항목을 만드세요.");
+        ui.label(t("builder.db.empty"));
     }
     let mut remove_idx: Option<usize> = None;
+    let mut export_idx: Option<usize> = None;
+    let shared_names: Vec<String> = state.shared_connections.keys().cloned().collect();
     for (idx, conn) in state.db_connections.iter_mut().enumerate() {
         ui.add_space(6.0);
         ui.push_id(idx, |ui| {
@@ -31,22 +30,51 @@ pub(super) fn render_db_section(
                 .inner_margin(egui::Margin::symmetric(8.0, 6.0))
                 .show(ui, |ui| {
                     ui.horizontal(|ui| {
-                        ui.label("키");
+                        ui.label(t("builder.db.key"));
                         if ui.text_edit_singleline(&mut conn.key).changed() {
                             *mark_dirty = true;
                         }
-                        if ui.button("삭제").clicked() {
+                        if ui.button(t("builder.delete.apply")).clicked() {
                             remove_idx = Some(idx);
                         }
                     });
                     if conn.key.trim() == "default" {
-                        ui.small("default는 target_db 미지정 시 사용됩니다.");
+                        ui.small(t("builder.db.default_hint"));
                     }
-                    egui::ComboBox::from_label("종류")
+                    egui::ComboBox::from_label(t("builder.db.shared"))
+                        .selected_text(conn.db_ref.as_deref().unwrap_or(t("builder.db.inline")))
+                        .show_ui(ui, |ui| {
+                            if ui
+                                .selectable_label(conn.db_ref.is_none(), t("builder.db.inline"))
+                                .clicked()
+                            {
+                                conn.db_ref = None;
+                                *mark_dirty = true;
+                            }
+                            for name in &shared_names {
+                                let selected = conn.db_ref.as_deref() == Some(name.as_str());
+                                if ui.selectable_label(selected, name).clicked() {
+                                    conn.db_ref = Some(name.clone());
+                                    *mark_dirty = true;
+                                }
+                            }
+                        });
+                    if let Some(name) = &conn.db_ref {
+                        if shared_names.contains(name) {
+                            ui.small(tf("builder.db.uses_shared", &[("name", &name)]));
+                        } else {
+                            ui.colored_label(
+                                palette.accent_error,
+                                tf("builder.db.shared_missing", &[("name", &name)]),
+                            );
+                        }
+                        return;
+                    }
+                    egui::ComboBox::from_label(t("builder.db.kind"))
                         .selected_text(match conn.kind {
                             DbKind::Oracle => "Oracle",
                             DbKind::Postgres => "PostgreSQL",
-                            DbKind::Dummy => "(미지원)",
+                            DbKind::Dummy => t("builder.db.unsupported"),
                         })
                         .show_ui(ui, |ui| {
                             if ui
@@ -67,26 +95,39 @@ pub(super) fn render_db_section(
                                 *mark_dirty = true;
                             }
                         });
-                    ui.label("DSN / 접속 문자열");
+                    ui.label(t("builder.db.dsn"));
                     if ui.text_edit_singleline(&mut conn.dsn).changed() {
                         *mark_dirty = true;
                     }
-                    ui.label("사용자");
+                    ui.label(t("builder.db.user"));
                     if ui.text_edit_singleline(&mut conn.user).changed() {
                         *mark_dirty = true;
                     }
-                    ui.label("비밀번호");
+                    ui.label(t("builder.db.password"));
                     if ui.text_edit_singleline(&mut conn.password).changed() {
                         *mark_dirty = true;
                     }
+                    if ui
+                        .add_enabled(
+                            !conn.key.trim().is_empty(),
+                            egui::Button::new(t("builder.db.export")),
+                        )
+                        .clicked()
+                    {
+                        export_idx = Some(idx);
+                    }
                 });
         });
     }
+    if let Some(idx) = export_idx {
+        export_to_shared(state, idx);
+        *mark_dirty = true;
+    }
     if let Some(idx) = remove_idx {
         state.db_connections.remove(idx);
         *mark_dirty = true;
     }
-    if ui.button("DB 연결 추가").clicked() {
+    if ui.button(t("builder.db.add")).clicked() {
         let new_key = state.generate_db_key();
         state
             .db_connections
@@ -94,4 +135,80 @@ pub(super) fn render_db_section(
         *mark_dirty = true;
     }
     ui.add_space(8.0);
+    render_shared_store_section(ui, state, mark_dirty, palette);
+}
+
+/// 앱 공용 연결 저장소 목록과 가져오기/삭제 버튼을 렌더링한다.
+fn render_shared_store_section(
+    ui: &mut egui::Ui,
+    state: &mut ScenarioEditorState,
+    mark_dirty: &mut bool,
+    palette: ThemePalette,
+) {
+    egui::CollapsingHeader::new(t("builder.db.store_title"))
+        .default_open(false)
+        .show(ui, |ui| {
+            ui.small(t("builder.db.store_hint"));
+            if state.shared_connections.is_empty() {
+                ui.label(t("builder.db.store_empty"));
+                return;
+            }
+            let mut import_name: Option<String> = None;
+            let mut remove_name: Option<String> = None;
+            for (name, config) in &state.shared_connections {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(name).strong());
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{:?} · {}",
+                            config.kind,
+                            config.dsn.as_deref().unwrap_or("-")
+                        ))
+                        .color(palette.fg_text_secondary),
+                    );
+                    if ui.button(t("builder.db.import")).clicked() {
+                        import_name = Some(name.clone());
+                    }
+                    if ui.button(t("builder.delete.apply")).clicked() {
+                        remove_name = Some(name.clone());
+                    }
+                });
+            }
+            if let Some(name) = import_name {
+                let key = if state.db_connections.iter().any(|conn| conn.key == name) {
+                    state.generate_db_key()
+                } else {
+                    name.clone()
+                };
+                let mut conn = DbConnectionEditor::new(key, DbKind::Oracle);
+                conn.db_ref = Some(name);
+                state.db_connections.push(conn);
+                *mark_dirty = true;
+            }
+            if let Some(name) = remove_name {
+                state.shared_connections.remove(&name);
+                state.shared_connections_changed = true;
+            }
+        });
+}
+
+/// 직접 입력한 DB 연결을 같은 이름의 공유 연결로 옮기고 참조로 바꾼다.
+fn export_to_shared(state: &mut ScenarioEditorState, idx: usize) {
+    let Some(conn) = state.db_connections.get_mut(idx) else {
+        return;
+    };
+    let name = conn.key.trim().to_string();
+    let config = DbConnectionConfig {
+        kind: conn.kind.clone(),
+        dsn: Some(conn.dsn.clone()).filter(|value| !value.trim().is_empty()),
+        user: Some(conn.user.clone()).filter(|value| !value.trim().is_empty()),
+        password: Some(conn.password.clone()).filter(|value| !value.trim().is_empty()),
+        db_ref: None,
+    };
+    conn.db_ref = Some(name.clone());
+    conn.dsn.clear();
+    conn.user.clear();
+    conn.password.clear();
+    state.shared_connections.insert(name, config);
+    state.shared_connections_changed = true;
 }
@@ -0,0 +1,87 @@
+use super::super::*;
+
+/// 삭제 확인 영역에서 사용자가 고른 삭제 방식이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum DeleteChoice {
+    /// 선행 노드와 후행 노드를 이은 뒤 삭제한다.
+    Bridge,
+    /// 연결을 잇지 않고 삭제한다.
+    DeleteOnly,
+}
+
+/// 삭제 버튼과 연결 영향도 확인 영역을 렌더링한다.
+///
+/// 버튼을 누르면 바로 삭제하지 않고 선행/후행 노드와 고아가 될 노드를 보여준 뒤,
+/// 사용자가 삭제 방식을 고른 프레임에서만 결과를 반환한다.
+pub(super) fn render_delete_section(
+    ui: &mut egui::Ui,
+    button_label: &str,
+    plan: &NodeDeletePlan,
+    palette: ThemePalette,
+) -> Option<DeleteChoice> {
+    let pending_id = egui::Id::new(("node_delete_pending", plan.node_id.clone()));
+    let mut pending: bool = ui.data(|d| d.get_temp(pending_id)).unwrap_or(false);
+    let mut choice = None;
+
+    if ui.button(button_label).clicked() {
+        pending = true;
+    }
+
+    if pending {
+        egui::Frame::none()
+            .stroke(egui::Stroke::new(1.0, palette.accent_error))
+            .inner_margin(egui::Margin::symmetric(8.0, 6.0))
+            .show(ui, |ui| {
+                ui.label(tf("builder.delete.title", &[("step", &plan.node_id)]));
+                ui.small(tf(
+                    "builder.delete.predecessors",
+                    &[("steps", &join_or_none(&plan.predecessors))],
+                ));
+                ui.small(tf(
+                    "builder.delete.successors",
+                    &[("steps", &join_or_none(&plan.successors))],
+                ));
+                if !plan.orphaned.is_empty() {
+                    ui.colored_label(
+                        palette.accent_warning,
+                        tf(
+                            "builder.delete.orphaned",
+                            &[("steps", &plan.orphaned.join(", "))],
+                        ),
+                    );
+                }
+                ui.horizontal(|ui| {
+                    if plan.can_bridge() && ui.button(t("builder.delete.bridge")).clicked() {
+                        choice = Some(DeleteChoice::Bridge);
+                    }
+                    if ui.button(t("builder.delete.apply")).clicked() {
+                        choice = Some(DeleteChoice::DeleteOnly);
+                    }
+                    if ui.button(t("common.cancel")).clicked() {
+                        pending = false;
+                    }
+                });
+            });
+    }
+
+    if choice.is_some() {
+        pending = false;
+    }
+    ui.data_mut(|d| {
+        if pending {
+            d.insert_temp(pending_id, true);
+        } else {
+            d.remove::<bool>(pending_id);
+        }
+    });
+    choice
+}
+
+/// 목록을 쉼표로 잇고 비어 있으면 `없음`을 반환한다.
+fn join_or_none(ids: &[String]) -> String {
+    if ids.is_empty() {
+        t("common.none").to_string()
+    } else {
+        ids.join(", ")
+    }
+}
@@ -0,0 +1,53 @@
+use super::super::*;
+
+/// 기대 산출물(`expects_outputs`)과 산출물이 없을 때의 처리 정책을 편집하는 UI를 그린다.
+///
+/// 한 줄에 경로 하나를 적으며, 빈 줄은 저장할 때 버린다.
+pub(super) fn render_expected_outputs_section(
+    ui: &mut egui::Ui,
+    selected: &mut EditorStepNode,
+    mark_dirty: &mut bool,
+) {
+    egui::CollapsingHeader::new(t("builder.outputs.title"))
+        .default_open(!selected.expects_outputs.is_empty())
+        .show(ui, |ui| {
+            ui.label(t("builder.outputs.files"));
+            let mut buf = selected.expects_outputs.join("\n");
+            if ui
+                .add(
+                    egui::TextEdit::multiline(&mut buf)
+                        .code_editor()
+                        .desired_rows(2)
+                        .hint_text("${STEP_TMPDIR}/out_${LOAD_DT}.csv"),
+                )
+                .changed()
+            {
+                selected.expects_outputs = buf.split('\n').map(str::to_string).collect();
+                *mark_dirty = true;
+            }
+            let label = |policy: MissingOutputPolicy| match policy {
+                MissingOutputPolicy::Fail => t("builder.outputs.fail"),
+                MissingOutputPolicy::Warn => t("builder.outputs.warn"),
+            };
+            ui.horizontal(|ui| {
+                ui.label(t("builder.outputs.policy"));
+                egui::ComboBox::from_id_source(("on_missing_output_combo", &selected.id))
+                    .selected_text(label(selected.on_missing_output))
+                    .show_ui(ui, |ui| {
+                        for option in [MissingOutputPolicy::Fail, MissingOutputPolicy::Warn] {
+                            if ui
+                                .selectable_label(
+                                    selected.on_missing_output == option,
+                                    label(option),
+                                )
+                                .clicked()
+                            {
+                                selected.on_missing_output = option;
+                                *mark_dirty = true;
+                            }
+                        }
+                    });
+            });
+            ui.small(t("builder.outputs.hint"));
+        });
+}
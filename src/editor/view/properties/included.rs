@@ -0,0 +1,63 @@
+use super::super::*;
+
+/// 조각 파일에서 펼친 Step임을 알리고 조각 파일 경로를 보여 준다.
+///
+/// 펼친 Step은 저장할 때 `include` 항목으로 되돌아가 빌더에서 바꾼 값이 남지 않으므로, 호출한 쪽은
+/// 이 안내 아래의 속성 편집을 막는다. 삭제는 `include` 항목 단위로만 할 수 있다.
+///
+/// # 매개변수
+/// - `include`: Step을 펼친 `include` 항목.
+/// - `count`: 같은 `include` 항목에서 펼친 Step 수.
+/// - `scenario_file`: 열린 시나리오 파일. 조각 파일 상대 경로의 기준 디렉터리를 찾는 데 쓴다.
+///
+/// # 반환값
+/// `include` 항목 삭제를 눌렀으면 `true`.
+pub(super) fn render_included_notice(
+    ui: &mut egui::Ui,
+    include: &StepInclude,
+    count: usize,
+    scenario_file: Option<&std::path::Path>,
+    palette: ThemePalette,
+) -> bool {
+    let mut remove = false;
+    let path = scenario_file
+        .and_then(|file| file.parent())
+        .map(|dir| dir.join(&include.include))
+        .unwrap_or_else(|| include.include.clone());
+    egui::Frame::none()
+        .stroke(egui::Stroke::new(1.0, palette.accent_primary))
+        .rounding(egui::Rounding::same(6.0))
+        .inner_margin(egui::Margin::same(8.0))
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(t("builder.included.title"))
+                    .color(palette.accent_primary)
+                    .strong(),
+            );
+            ui.label(
+                egui::RichText::new(tf(
+                    "builder.included.prefix",
+                    &[("prefix", &include.id_prefix())],
+                ))
+                .color(palette.fg_text_secondary),
+            );
+            ui.horizontal_wrapped(|ui| {
+                ui.label(egui::RichText::new(path.display().to_string()).monospace());
+                if ui
+                    .small_button(t("builder.included.copy_path"))
+                    .on_hover_text(t("builder.included.copy_path_hover"))
+                    .clicked()
+                {
+                    ui.output_mut(|o| o.copied_text = path.display().to_string());
+                }
+            });
+            if ui
+                .button(tf("builder.included.remove", &[("count", &count)]))
+                .on_hover_text(t("builder.included.remove_hover"))
+                .clicked()
+            {
+                remove = true;
+            }
+        });
+    remove
+}
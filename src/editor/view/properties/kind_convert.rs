@@ -0,0 +1,66 @@
+use super::super::*;
+use super::*;
+
+/// Step 유형 변경 콤보와 손실 필드 경고를 렌더링한다.
+///
+/// 콤보에서 다른 유형을 고르면 바로 변환하지 않고, 사라질 필드 목록을 보여준 뒤
+/// `변환` 버튼을 눌렀을 때만 노드 구성을 교체한다.
+pub(super) fn render_kind_convert_section(
+    ui: &mut egui::Ui,
+    node: &mut EditorStepNode,
+    mark_dirty: &mut bool,
+    palette: ThemePalette,
+) {
+    let pending_id = egui::Id::new(("kind_convert_pending", node.id.clone()));
+    let mut pending: Option<StepKind> = ui.data(|d| d.get_temp(pending_id));
+
+    ui.push_id(("kind_convert", node.id.clone()), |ui| {
+        egui::ComboBox::from_label(t("builder.col.kind"))
+            .selected_text(node.kind.label())
+            .show_ui(ui, |ui| {
+                for kind in StepKind::ALL {
+                    let selected = pending.unwrap_or(node.kind) == kind;
+                    if ui.selectable_label(selected, kind.label()).clicked() {
+                        pending = if kind == node.kind { None } else { Some(kind) };
+                    }
+                }
+            });
+    });
+
+    if let Some(target) = pending {
+        let lost = node.config.fields_lost_on_convert(target);
+        egui::Frame::none()
+            .stroke(egui::Stroke::new(1.0, palette.accent_warning))
+            .inner_margin(egui::Margin::symmetric(8.0, 6.0))
+            .show(ui, |ui| {
+                ui.label(tf(
+                    "builder.convert.title",
+                    &[("from", &node.kind.label()), ("to", &target.label())],
+                ));
+                if lost.is_empty() {
+                    ui.small(t("builder.convert.nothing_lost"));
+                } else {
+                    ui.colored_label(
+                        palette.accent_warning,
+                        tf("builder.convert.lost", &[("fields", &lost.join(", "))]),
+                    );
+                    ui.small(t("builder.convert.kept"));
+                }
+                ui.horizontal(|ui| {
+                    if ui.button(t("builder.convert.apply")).clicked() {
+                        node.convert_kind(target);
+                        pending = None;
+                        *mark_dirty = true;
+                    }
+                    if ui.button(t("common.cancel")).clicked() {
+                        pending = None;
+                    }
+                });
+            });
+    }
+
+    ui.data_mut(|d| match pending {
+        Some(kind) => d.insert_temp(pending_id, kind),
+        None => d.remove::<StepKind>(pending_id),
+    });
+}
@@ -9,6 +9,9 @@ pub(super) fn render_loop_section(
     palette: ThemePalette,
     decorations: ThemeDecorations,
     db_keys: &[String],
+    defaults: &StepDefaults,
+    taken_ids: &HashSet<String>,
+    deleted: &mut Option<DeletedStep>,
 ) {
     let EditorStepConfig::Loop { config } = &mut node.config else {
         return;
@@ -20,17 +23,17 @@ pub(super) fn render_loop_section(
         .inner_margin(egui::Margin::symmetric(16.0, 12.0))
         .show(ui, |ui| {
             ui.set_width(ui.available_width());
-            ui.heading("Loop 설정");
+            ui.heading(t("builder.loop.title"));
             ui.add_space(6.0);
             ui.label("for_each_glob");
             if ui.text_edit_singleline(&mut config.for_each_glob).changed() {
                 *mark_dirty = true;
             }
-            ui.label("as 변수명");
+            ui.label(t("builder.loop.as_var"));
             if ui.text_edit_singleline(&mut config.as_var).changed() {
                 *mark_dirty = true;
             }
-            egui::ComboBox::from_label("실패 시 동작")
+            egui::ComboBox::from_label(t("builder.loop.on_failure"))
                 .selected_text(match config.on_iteration_failure {
                     LoopIterationFailure::StopAll => "Stop All",
                     LoopIterationFailure::Continue => "Continue",
@@ -59,18 +62,23 @@ pub(super) fn render_loop_section(
                 });
             ui.separator();
             ui.horizontal(|ui| {
-                ui.label("하위 Step");
-                ui.menu_button("추가", |ui| {
+                ui.label(t("builder.loop.children"));
+                ui.menu_button(t("builder.loop.add"), |ui| {
                     for (label, kind) in [
                         ("SQL", StepKind::Sql),
-                        ("SQL 파일", StepKind::SqlFile),
+                        (t("builder.kind.sql_file"), StepKind::SqlFile),
                         ("SQL*Loader", StepKind::SqlLoaderPar),
                         ("Shell", StepKind::Shell),
                         ("Extract", StepKind::Extract),
                         ("Loop", StepKind::Loop),
+                        (t("builder.kind.manual_gate"), StepKind::ManualGate),
+                        (t("builder.kind.wait"), StepKind::Wait),
+                        (t("builder.kind.wait_for_run"), StepKind::WaitForRun),
+                        (t("builder.kind.verify"), StepKind::Verify),
+                        (t("builder.kind.marker"), StepKind::Marker),
                     ] {
                         if ui.button(label).clicked() {
-                            let new_id = config.generate_child_id();
+                            let new_id = config.generate_child_id(taken_ids);
                             let mut child = EditorStepNode::new(
                                 new_id.clone(),
                                 format!("Loop Step {new_id}"),
@@ -84,13 +92,26 @@ pub(super) fn render_loop_section(
                         }
                     }
                 });
-                if let Some(selected_id) = config.selected_node_id.clone() {
-                    if ui.button("선택 Step 삭제").clicked() {
-                        config.remove_node(&selected_id);
+            });
+            if let Some(selected_id) = config.selected_node_id.clone() {
+                let plan = config.delete_plan(&selected_id);
+                match super::delete_dialog::render_delete_section(
+                    ui,
+                    t("builder.loop.delete_selected"),
+                    &plan,
+                    palette,
+                ) {
+                    Some(super::delete_dialog::DeleteChoice::Bridge) => {
+                        *deleted = config.remove_node_bridged(&selected_id);
+                        *mark_dirty = true;
+                    }
+                    Some(super::delete_dialog::DeleteChoice::DeleteOnly) => {
+                        *deleted = config.remove_node(&selected_id);
                         *mark_dirty = true;
                     }
+                    None => {}
                 }
-            });
+            }
             for child in &config.nodes {
                 let selected = config.selected_node_id.as_deref() == Some(child.id.as_str());
                 if ui
@@ -115,8 +136,11 @@ pub(super) fn render_loop_section(
 
                 if let Some(child) = config.node_mut(&selected_id) {
                     ui.separator();
-                    ui.heading("선택된 하위 Step");
+                    ui.heading(t("builder.loop.selected_child"));
                     ui.label(format!("ID: {}", child.id));
+                    super::kind_convert::render_kind_convert_section(
+                        ui, child, mark_dirty, palette,
+                    );
 
                     let mut name_buf = child.name.clone();
                     if ui.text_edit_singleline(&mut name_buf).changed() {
@@ -125,33 +149,46 @@ pub(super) fn render_loop_section(
                     }
 
                     if ui
-                        .checkbox(&mut child.allow_parallel, "병렬 허용")
+                        .checkbox(&mut child.allow_parallel, t("builder.allow_parallel"))
                         .changed()
                     {
                         *mark_dirty = true;
                     }
+                    super::render_memoize_checkbox(ui, &mut child.memoize, mark_dirty);
 
                     let mut retry = child.retry;
-                    if ui
-                        .add(egui::Slider::new(&mut retry, 0..=5).text("재시도"))
-                        .changed()
+                    let inherited = defaults.retry == Some(child.retry);
+                    if inherited_style(ui, inherited, palette, |ui| {
+                        ui.add(egui::Slider::new(&mut retry, 0..=5).text(t("builder.retry")))
+                    })
+                    .changed()
                     {
                         child.retry = retry;
                         *mark_dirty = true;
                     }
+                    super::render_retry_on_checkbox(ui, &mut child.retry_on, mark_dirty);
 
                     let mut timeout = child.timeout_sec as i32;
-                    if ui
-                        .add(
+                    let inherited = defaults.timeout_sec == Some(child.timeout_sec);
+                    if inherited_style(ui, inherited, palette, |ui| {
+                        ui.add(
                             egui::DragValue::new(&mut timeout)
-                                .prefix("타임아웃 ")
-                                .suffix("초"),
+                                .prefix(t("builder.timeout_prefix"))
+                                .suffix(t("builder.seconds_suffix")),
                         )
-                        .changed()
+                    })
+                    .changed()
                     {
                         child.timeout_sec = timeout.max(1) as u64;
                         *mark_dirty = true;
                     }
+                    super::render_on_timeout_combo(
+                        ui,
+                        &child.id,
+                        &mut child.on_timeout,
+                        mark_dirty,
+                    );
+                    super::render_log_level_combo(ui, &child.id, &mut child.log_level, mark_dirty);
 
                     ui.separator();
                     super::step_config::render_step_config_ui(
@@ -160,6 +197,8 @@ pub(super) fn render_loop_section(
                         mark_dirty,
                         db_keys,
                         child.id.as_str(),
+                        defaults.target_db.as_deref(),
+                        palette,
                     );
                     super::confirm::render_confirm_section(ui, &mut child.confirm, mark_dirty);
 
@@ -170,7 +209,7 @@ pub(super) fn render_loop_section(
                                 let dep_id = dep.clone();
                                 ui.horizontal(|ui| {
                                     ui.label(&dep_id);
-                                    if ui.button("삭제").clicked() {
+                                    if ui.button(t("builder.delete.apply")).clicked() {
                                         deps_to_remove.push(dep_id.clone());
                                         *mark_dirty = true;
                                     }
@@ -178,8 +217,8 @@ pub(super) fn render_loop_section(
                             }
                         });
 
-                    egui::ComboBox::from_label("의존성 추가")
-                        .selected_text("노드 선택")
+                    egui::ComboBox::from_label(t("builder.deps.add"))
+                        .selected_text(t("builder.deps.select_node"))
                         .show_ui(ui, |ui| {
                             for option in &options {
                                 if ui.selectable_label(false, option).clicked() {
@@ -1,9 +1,22 @@
 use super::*;
 
 mod confirm;
+mod contracts;
 mod db;
+mod delete_dialog;
+mod expected_outputs;
+mod included;
+mod kind_convert;
 mod loop_panel;
+mod notify;
+mod shell_editor;
+mod shell_trial;
+mod skip_if;
+mod sql_change;
+mod sql_preview;
 mod step_config;
+mod step_test;
+mod time_window;
 
 impl<'a> ScenarioBuilderUi<'a> {
     /// 우측 속성 패널을 렌더링한다.
@@ -12,66 +25,185 @@ impl<'a> ScenarioBuilderUi<'a> {
         let palette = *self.get_theme().palette();
         let decorations = *self.get_theme().decorations();
         let state = self.get_state_mut();
+        let mut preview = state.sql_preview.take();
+        let mut test = state.step_test.take();
+        let mut trial = state.shell_trial.take();
 
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
             .show(ui, |ui| {
                 ui.set_width(320.0);
                 let mut selected_runtime_id: Option<String> = None;
+                render_scenario_name_field(ui, state, &mut mark_dirty, palette);
+                ui.separator();
                 db::render_db_section(ui, state, &mut mark_dirty, palette, decorations);
                 ui.separator();
-                ui.heading("⚙️ Step 속성");
+                ui.heading(t("builder.props.title"));
                 let db_keys = state.db_key_list();
+                let defaults = state.step_defaults.clone().unwrap_or_default();
+                let taken_ids = state.all_step_ids();
+                let params = state.params.clone();
 
                 if let Some(selected_id) = state.selected_node_id.clone() {
-                    if let Some(selected) = state.node_mut(&selected_id) {
-                        selected_runtime_id = Some(selected.id.clone());
-
-                        let mut id_buf = selected.id.clone();
-                        ui.label("ID");
-                        if ui.text_edit_singleline(&mut id_buf).changed() {
-                            selected.id = id_buf.clone();
-                            selected_runtime_id = Some(id_buf);
+                    let focus_name = std::mem::take(&mut state.focus_name_pending);
+                    let included = state
+                        .node(&selected_id)
+                        .and_then(|node| node.included_from.clone());
+                    if let Some(include) = &included {
+                        let count = state
+                            .nodes
+                            .iter()
+                            .filter(|node| node.included_from.as_ref() == Some(include))
+                            .count();
+                        if included::render_included_notice(
+                            ui,
+                            include,
+                            count,
+                            state.current_file.as_deref(),
+                            palette,
+                        ) {
+                            state.remove_include(include);
                             mark_dirty = true;
                         }
+                        // 저장하면 include 항목으로 되돌아가므로 아래 속성과 의존성 편집을 막는다.
+                        ui.set_enabled(false);
+                    }
+                    if let Some(kind) = state.node(&selected_id).map(|node| node.kind) {
+                        render_id_field(ui, state, &selected_id, &mut mark_dirty, palette);
+                        ui.horizontal(|ui| {
+                            if ui
+                                .button(t("builder.props.duplicate"))
+                                .on_hover_text(t("builder.props.duplicate_hover"))
+                                .clicked()
+                            {
+                                state.duplicate_node(&selected_id);
+                                mark_dirty = true;
+                            }
+                            if kind == StepKind::Loop
+                                && ui
+                                    .button(t("builder.props.open_loop"))
+                                    .on_hover_text(t("builder.props.open_loop_hover"))
+                                    .clicked()
+                            {
+                                state.open_loop(&selected_id);
+                            }
+                        });
+                    }
+                    let selected_id = state.selected_node_id.clone().unwrap_or(selected_id);
+                    let mut deleted_child = None;
+                    let sql_changed_at = state
+                        .sql_changes
+                        .get(&selected_id)
+                        .map(SqlFileChange::last_success_label);
+                    let mut open_sql_diff = false;
+                    if let Some(selected) = state.node_mut(&selected_id) {
+                        selected_runtime_id = Some(selected.id.clone());
 
                         let mut name_buf = selected.name.clone();
-                        ui.label("이름");
-                        if ui.text_edit_singleline(&mut name_buf).changed() {
+                        ui.label(t("builder.col.name"));
+                        let name_response = ui.text_edit_singleline(&mut name_buf);
+                        if focus_name {
+                            name_response.request_focus();
+                        }
+                        if name_response.changed() {
                             selected.name = name_buf;
                             mark_dirty = true;
                         }
 
-                        ui.label(format!("유형: {:?}", selected.kind));
+                        ui.horizontal(|ui| {
+                            let mut icon_buf = selected.icon.clone().unwrap_or_default();
+                            ui.label(t("builder.props.icon"));
+                            if ui
+                                .add(
+                                    egui::TextEdit::singleline(&mut icon_buf)
+                                        .desired_width(40.0)
+                                        .hint_text("🧮"),
+                                )
+                                .changed()
+                            {
+                                selected.icon = Some(icon_buf).filter(|value| !value.is_empty());
+                                mark_dirty = true;
+                            }
+                            let mut label_buf = selected.label.clone().unwrap_or_default();
+                            ui.label(t("builder.props.label"));
+                            if ui
+                                .add(
+                                    egui::TextEdit::singleline(&mut label_buf)
+                                        .hint_text(selected.kind.label()),
+                                )
+                                .changed()
+                            {
+                                selected.label = Some(label_buf).filter(|value| !value.is_empty());
+                                mark_dirty = true;
+                            }
+                        });
 
+                        let mut stage_buf = selected.stage.clone().unwrap_or_default();
+                        ui.label(t("builder.props.stage"));
                         if ui
-                            .checkbox(&mut selected.allow_parallel, "병렬 허용")
+                            .add(
+                                egui::TextEdit::singleline(&mut stage_buf)
+                                    .hint_text("extract, load, verify ..."),
+                            )
                             .changed()
                         {
+                            selected.stage = Some(stage_buf).filter(|value| !value.is_empty());
                             mark_dirty = true;
                         }
 
-                        let mut retry = selected.retry;
+                        kind_convert::render_kind_convert_section(
+                            ui,
+                            selected,
+                            &mut mark_dirty,
+                            palette,
+                        );
+
                         if ui
-                            .add(egui::Slider::new(&mut retry, 0..=5).text("재시도"))
+                            .checkbox(&mut selected.allow_parallel, t("builder.allow_parallel"))
                             .changed()
+                        {
+                            mark_dirty = true;
+                        }
+                        render_memoize_checkbox(ui, &mut selected.memoize, &mut mark_dirty);
+
+                        let mut retry = selected.retry;
+                        let inherited = defaults.retry == Some(selected.retry);
+                        if inherited_style(ui, inherited, palette, |ui| {
+                            ui.add(egui::Slider::new(&mut retry, 0..=5).text(t("builder.retry")))
+                        })
+                        .changed()
                         {
                             selected.retry = retry;
                             mark_dirty = true;
                         }
+                        render_retry_on_checkbox(ui, &mut selected.retry_on, &mut mark_dirty);
 
                         let mut timeout = selected.timeout_sec as i32;
-                        if ui
-                            .add(
+                        let inherited = defaults.timeout_sec == Some(selected.timeout_sec);
+                        if inherited_style(ui, inherited, palette, |ui| {
+                            ui.add(
                                 egui::DragValue::new(&mut timeout)
-                                    .prefix("타임아웃 ")
-                                    .suffix("초"),
+                                    .prefix(t("builder.timeout_prefix"))
+                                    .suffix(t("builder.seconds_suffix")),
                             )
-                            .changed()
+                        })
+                        .changed()
                         {
                             selected.timeout_sec = timeout.max(1) as u64;
                             mark_dirty = true;
                         }
+                        render_on_timeout_combo(
+                            ui,
+                            &selected.id,
+                            &mut selected.on_timeout,
+                            &mut mark_dirty,
+                        );
+                        render_log_level_combo(
+                            ui,
+                            &selected.id,
+                            &mut selected.log_level,
+                            &mut mark_dirty,
+                        );
 
                         ui.separator();
 
@@ -81,8 +213,41 @@ impl<'a> ScenarioBuilderUi<'a> {
                             &mut mark_dirty,
                             &db_keys,
                             selected.id.as_str(),
+                            defaults.target_db.as_deref(),
+                            palette,
+                        );
+                        sql_change::render_sql_change_section(
+                            ui,
+                            selected,
+                            sql_changed_at.as_deref(),
+                            &mut open_sql_diff,
+                            palette,
+                        );
+                        sql_preview::render_sql_preview_section(
+                            ui,
+                            selected,
+                            &mut preview,
+                            palette,
                         );
+                        shell_trial::render_shell_trial_section(
+                            ui, selected, &mut trial, &params, palette,
+                        );
+                        step_test::render_step_test_section(ui, selected, &mut test, palette);
                         confirm::render_confirm_section(ui, &mut selected.confirm, &mut mark_dirty);
+                        time_window::render_time_window_section(
+                            ui,
+                            &mut selected.time_window,
+                            &mut mark_dirty,
+                            palette,
+                        );
+                        skip_if::render_skip_if_section(ui, &mut selected.skip_if, &mut mark_dirty);
+                        expected_outputs::render_expected_outputs_section(
+                            ui,
+                            selected,
+                            &mut mark_dirty,
+                        );
+                        contracts::render_contract_section(ui, selected, &mut mark_dirty);
+                        notify::render_notify_section(ui, selected, &mut mark_dirty);
                         if selected.kind == StepKind::Loop {
                             loop_panel::render_loop_section(
                                 ui,
@@ -91,18 +256,27 @@ impl<'a> ScenarioBuilderUi<'a> {
                                 palette,
                                 decorations,
                                 &db_keys,
+                                &defaults,
+                                &taken_ids,
+                                &mut deleted_child,
                             );
                         }
                     } else {
-                        ui.label("선택된 Step 정보를 찾을 수 없습니다.");
+                        ui.label(t("builder.props.not_found"));
+                    }
+                    if let Some(deleted) = deleted_child {
+                        state.keep_deleted_loop_child(&selected_id, deleted);
+                    }
+                    if open_sql_diff && let Some(change) = state.sql_changes.get(&selected_id) {
+                        state.sql_diff = Some((selected_id.clone(), change.diff()));
                     }
                 } else {
-                    ui.label("선택된 Step이 없습니다.");
+                    ui.label(t("builder.props.no_selection"));
                 }
 
                 if let Some(selected_id) = selected_runtime_id.clone() {
                     ui.separator();
-                    ui.label("의존성");
+                    ui.label(t("builder.deps.title"));
 
                     if !state.nodes.is_empty() {
                         egui::ScrollArea::vertical()
@@ -113,7 +287,7 @@ impl<'a> ScenarioBuilderUi<'a> {
                                     let dep_id = dep.clone();
                                     ui.horizontal(|ui| {
                                         ui.label(&dep_id);
-                                        if ui.button("삭제").clicked() {
+                                        if ui.button(t("builder.delete.apply")).clicked() {
                                             state.remove_connection(&dep_id, &selected_id);
                                             mark_dirty = true;
                                         }
@@ -132,8 +306,8 @@ impl<'a> ScenarioBuilderUi<'a> {
                         .collect();
                     options.sort();
 
-                    egui::ComboBox::from_label("의존성 추가")
-                        .selected_text("노드 선택")
+                    egui::ComboBox::from_label(t("builder.deps.add"))
+                        .selected_text(t("builder.deps.select_node"))
                         .show_ui(ui, |ui| {
                             for option in &options {
                                 if ui.selectable_label(false, option).clicked() {
@@ -144,12 +318,193 @@ impl<'a> ScenarioBuilderUi<'a> {
                         });
 
                     ui.separator();
-                    if ui.button("이 Step 삭제").clicked() {
-                        state.remove_node(&selected_id);
-                        mark_dirty = true;
+                    let plan = state.delete_plan(&selected_id);
+                    match delete_dialog::render_delete_section(
+                        ui,
+                        t("builder.props.delete_step"),
+                        &plan,
+                        palette,
+                    ) {
+                        Some(delete_dialog::DeleteChoice::Bridge) => {
+                            state.remove_node_bridged(&selected_id);
+                            mark_dirty = true;
+                        }
+                        Some(delete_dialog::DeleteChoice::DeleteOnly) => {
+                            state.remove_node(&selected_id);
+                            mark_dirty = true;
+                        }
+                        None => {}
                     }
                 }
             });
+        state.sql_preview = preview;
+        state.step_test = test;
+        state.shell_trial = trial;
         state.dirty = mark_dirty;
     }
 }
+
+/// 시나리오 이름 입력란을 그린다. 비어 있으면 저장할 수 없으므로 바로 아래에 오류를 표시한다.
+fn render_scenario_name_field(
+    ui: &mut egui::Ui,
+    state: &mut ScenarioEditorState,
+    mark_dirty: &mut bool,
+    palette: ThemePalette,
+) {
+    ui.label(t("builder.props.scenario_name"));
+    if ui.text_edit_singleline(&mut state.scenario_name).changed() {
+        *mark_dirty = true;
+    }
+    if state.scenario_name.trim().is_empty() {
+        ui.colored_label(
+            palette.accent_error,
+            EditorError::EmptyScenarioName.to_string(),
+        );
+    }
+}
+
+/// Step ID 입력란을 그린다.
+///
+/// 입력값이 유효하면 연결과 함께 바로 이름을 바꾸고, 비었거나 다른 Step과 겹치면 적용하지 않은 채
+/// 입력값을 `id_draft`에 남겨 두고 오류를 표시한다.
+fn render_id_field(
+    ui: &mut egui::Ui,
+    state: &mut ScenarioEditorState,
+    selected_id: &str,
+    mark_dirty: &mut bool,
+    palette: ThemePalette,
+) {
+    let mut buf = match &state.id_draft {
+        Some((owner, text)) if owner == selected_id => text.clone(),
+        _ => selected_id.to_string(),
+    };
+    ui.label("ID");
+    if ui.text_edit_singleline(&mut buf).changed() {
+        match state.rename_node(selected_id, &buf) {
+            Ok(()) => *mark_dirty = true,
+            Err(_) => state.id_draft = Some((selected_id.to_string(), buf)),
+        }
+    }
+    if let Some((owner, text)) = &state.id_draft
+        && owner == selected_id
+        && let Err(err) = state.validate_step_id(selected_id, text)
+    {
+        ui.colored_label(palette.accent_error, err.to_string());
+    }
+}
+
+/// 같은 구성의 실행 결과를 재사용할지 고르는 체크박스를 그린다.
+fn render_memoize_checkbox(ui: &mut egui::Ui, memoize: &mut bool, mark_dirty: &mut bool) {
+    if ui
+        .checkbox(memoize, t("builder.props.memoize"))
+        .on_hover_text(t("builder.props.memoize_hover"))
+        .changed()
+    {
+        *mark_dirty = true;
+    }
+}
+
+/// 모든 오류를 재시도할지 고르는 체크박스를 그린다. 끄면 일시적 오류만 재시도한다.
+fn render_retry_on_checkbox(ui: &mut egui::Ui, retry_on: &mut RetryOn, mark_dirty: &mut bool) {
+    let mut any = *retry_on == RetryOn::Any;
+    if ui
+        .checkbox(&mut any, t("builder.props.retry_all"))
+        .on_hover_text(t("builder.props.retry_all_hover"))
+        .changed()
+    {
+        *retry_on = if any {
+            RetryOn::Any
+        } else {
+            RetryOn::Transient
+        };
+        *mark_dirty = true;
+    }
+}
+
+/// 타임아웃 처리 정책 콤보를 그린다.
+fn render_on_timeout_combo(
+    ui: &mut egui::Ui,
+    step_id: &str,
+    policy: &mut TimeoutPolicy,
+    mark_dirty: &mut bool,
+) {
+    let label = |policy: TimeoutPolicy| match policy {
+        TimeoutPolicy::Fail => t("builder.timeout.fail"),
+        TimeoutPolicy::WarnContinue => t("builder.timeout.warn_continue"),
+        TimeoutPolicy::KillRetry => t("builder.timeout.kill_retry"),
+    };
+    ui.horizontal(|ui| {
+        ui.label(t("builder.timeout.policy"));
+        egui::ComboBox::from_id_source(("on_timeout_combo", step_id))
+            .selected_text(label(*policy))
+            .show_ui(ui, |ui| {
+                for option in TimeoutPolicy::ALL {
+                    if ui
+                        .selectable_label(*policy == option, label(option))
+                        .clicked()
+                    {
+                        *policy = option;
+                        *mark_dirty = true;
+                    }
+                }
+            });
+    })
+    .response
+    .on_hover_text(t("builder.timeout.warn_continue_hover"));
+}
+
+/// 엔진 로그 수준 콤보를 그린다. `기본`을 고르면 감싼 Loop의 수준(최상위면 info)을 따른다.
+fn render_log_level_combo(
+    ui: &mut egui::Ui,
+    step_id: &str,
+    level: &mut Option<StepLogLevel>,
+    mark_dirty: &mut bool,
+) {
+    let label = |level: Option<StepLogLevel>| match level {
+        None => t("builder.log_level.default"),
+        Some(StepLogLevel::Debug) => t("builder.log_level.debug"),
+        Some(StepLogLevel::Info) => "info",
+        Some(StepLogLevel::Warn) => t("builder.log_level.warn"),
+    };
+    ui.horizontal(|ui| {
+        ui.label(t("builder.log_level.title"));
+        egui::ComboBox::from_id_source(("log_level_combo", step_id))
+            .selected_text(label(*level))
+            .show_ui(ui, |ui| {
+                let options = std::iter::once(None).chain(StepLogLevel::ALL.map(Some));
+                for option in options {
+                    if ui
+                        .selectable_label(*level == option, label(option))
+                        .clicked()
+                    {
+                        *level = option;
+                        *mark_dirty = true;
+                    }
+                }
+            });
+    })
+    .response
+    .on_hover_text(t("builder.log_level.hover"));
+}
+
+/// 시나리오 `defaults`에서 상속된 값이면 흐린 글자색으로 그리고 안내 툴팁을 붙인다.
+fn inherited_style(
+    ui: &mut egui::Ui,
+    inherited: bool,
+    palette: ThemePalette,
+    add: impl FnOnce(&mut egui::Ui) -> egui::Response,
+) -> egui::Response {
+    let response = ui
+        .scope(|ui| {
+            if inherited {
+                ui.visuals_mut().override_text_color = Some(palette.fg_text_secondary);
+            }
+            add(ui)
+        })
+        .inner;
+    if inherited {
+        response.on_hover_text(t("builder.props.inherited"))
+    } else {
+        response
+    }
+}
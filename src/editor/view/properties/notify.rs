@@ -0,0 +1,30 @@
+use super::super::*;
+use super::*;
+
+/// Step 알림 웹훅(`notify_url`) 편집 UI를 그린다.
+///
+/// 비워 두면 알림을 보내지 않는다.
+pub(super) fn render_notify_section(
+    ui: &mut egui::Ui,
+    node: &mut EditorStepNode,
+    mark_dirty: &mut bool,
+) {
+    egui::CollapsingHeader::new(t("builder.notify.title"))
+        .default_open(node.notify_url.is_some())
+        .show(ui, |ui| {
+            ui.push_id(("notify", node.id.clone()), |ui| {
+                ui.label(t("builder.notify.hint"));
+                let mut buf = node.notify_url.clone().unwrap_or_default();
+                if ui
+                    .add(
+                        egui::TextEdit::singleline(&mut buf)
+                            .hint_text(t("builder.notify.placeholder")),
+                    )
+                    .changed()
+                {
+                    node.notify_url = Some(buf).filter(|url| !url.trim().is_empty());
+                    *mark_dirty = true;
+                }
+            });
+        });
+}
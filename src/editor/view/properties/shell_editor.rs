@@ -0,0 +1,130 @@
+use super::super::*;
+use crate::engine::placeholder_names;
+use eframe::egui::text::{LayoutJob, TextFormat};
+
+/// 셸 스크립트에서 강조할 예약어이다.
+const SHELL_KEYWORDS: &[&str] = &[
+    "if", "then", "elif", "else", "fi", "for", "while", "until", "do", "done", "case", "esac",
+    "in", "function", "return", "exit", "export", "local", "set", "echo",
+];
+
+/// 변수 목록 열의 너비이다.
+const VARS_COLUMN_WIDTH: f32 = 110.0;
+
+/// Shell 스크립트를 구문 강조 편집기로 그리고, 오른쪽에 스크립트가 참조하는 `${...}` 변수 목록을
+/// 보여 준다.
+pub(super) fn render_script_editor(
+    ui: &mut egui::Ui,
+    script: &mut String,
+    mark_dirty: &mut bool,
+    palette: ThemePalette,
+) {
+    ui.horizontal_top(|ui| {
+        let editor_width = (ui.available_width() - VARS_COLUMN_WIDTH).max(120.0);
+        let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+            let mut job = highlight_shell(ui, text, palette);
+            job.wrap.max_width = wrap_width;
+            ui.fonts(|fonts| fonts.layout_job(job))
+        };
+        if ui
+            .add(
+                egui::TextEdit::multiline(script)
+                    .code_editor()
+                    .desired_rows(8)
+                    .desired_width(editor_width)
+                    .layouter(&mut layouter),
+            )
+            .changed()
+        {
+            *mark_dirty = true;
+        }
+        ui.vertical(|ui| {
+            ui.set_width(VARS_COLUMN_WIDTH - 8.0);
+            ui.label(egui::RichText::new(t("builder.shell.vars")).strong());
+            let names = placeholder_names(script);
+            if names.is_empty() {
+                ui.colored_label(palette.fg_text_secondary, t("builder.none"));
+            }
+            for name in names {
+                ui.label(
+                    egui::RichText::new(format!("${{{name}}}"))
+                        .monospace()
+                        .color(palette.accent_primary),
+                );
+            }
+        });
+    });
+}
+
+/// 셸 스크립트를 주석, 문자열, 변수, 예약어로 나눠 색을 입힌 레이아웃을 만든다.
+///
+/// 실제 셸 문법 분석이 아니라 가벼운 토큰 구분이며, 문자열 안의 변수는 따로 칠하지 않는다.
+fn highlight_shell(ui: &egui::Ui, text: &str, palette: ThemePalette) -> LayoutJob {
+    let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+    let format = |color: egui::Color32| TextFormat::simple(font_id.clone(), color);
+    let mut job = LayoutJob::default();
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let at_word_start = pos == 0 || bytes[pos - 1].is_ascii_whitespace();
+        let (len, color) = if rest.starts_with('#') && at_word_start {
+            (
+                rest.find('\n').unwrap_or(rest.len()),
+                palette.fg_text_secondary,
+            )
+        } else if rest.starts_with('\'') || rest.starts_with('"') {
+            (quoted_len(rest), palette.accent_success)
+        } else if rest.starts_with("${") {
+            (
+                rest.find('}').map_or(rest.len(), |end| end + 1),
+                palette.accent_primary,
+            )
+        } else if let Some(after) = rest.strip_prefix('$') {
+            let name_len = match after.find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_')) {
+                Some(0) => after.chars().next().map_or(0, char::len_utf8),
+                Some(len) => len,
+                None => after.len(),
+            };
+            (1 + name_len, palette.accent_primary)
+        } else if rest.starts_with(|ch: char| ch.is_ascii_alphanumeric() || ch == '_') {
+            let word_len = rest
+                .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+                .unwrap_or(rest.len());
+            let color = if SHELL_KEYWORDS.contains(&&rest[..word_len]) {
+                palette.icon_emphasis
+            } else {
+                palette.fg_text_primary
+            };
+            (word_len, color)
+        } else {
+            (
+                rest.chars().next().map_or(1, char::len_utf8),
+                palette.fg_text_primary,
+            )
+        };
+        job.append(&rest[..len], 0.0, format(color));
+        pos += len;
+    }
+    if job.sections.is_empty() {
+        job.append("", 0.0, format(palette.fg_text_primary));
+    }
+    job
+}
+
+/// 따옴표로 시작하는 문자열의 길이를 닫는 따옴표까지 센다. 큰따옴표 안에서는 `\`로 이스케이프한
+/// 따옴표를 건너뛰고, 닫히지 않으면 끝까지이다.
+fn quoted_len(rest: &str) -> usize {
+    let quote = rest.as_bytes()[0];
+    let mut escaped = false;
+    for (idx, byte) in rest.bytes().enumerate().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if byte == b'\\' && quote == b'"' {
+            escaped = true;
+        } else if byte == quote {
+            return idx + 1;
+        }
+    }
+    rest.len()
+}
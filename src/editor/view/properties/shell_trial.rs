@@ -0,0 +1,126 @@
+use super::super::*;
+use crate::engine::placeholder_names;
+use crate::scenario::{RUN_TMPDIR_VAR, STEP_TMPDIR_VAR};
+
+/// Shell Step 스크립트를 더미 변수 값으로 로컬에서 실행해 보는 버튼과 결과를 렌더링한다.
+///
+/// 스크립트·인자·환경 변수가 참조하는 변수마다 더미 값 입력란을 보여 주고, 버튼을 누르면 `trial`을
+/// 요청 상태로 바꾼다. 실행과 결과 채우기는 앱이 맡으며, 다른 Step의 테스트 상태는 새로 만든다.
+pub(super) fn render_shell_trial_section(
+    ui: &mut egui::Ui,
+    node: &EditorStepNode,
+    trial: &mut Option<ShellTrial>,
+    params: &HashMap<String, String>,
+    palette: ThemePalette,
+) {
+    let EditorStepConfig::Shell { config } = &node.config else {
+        return;
+    };
+    let current = match trial {
+        Some(current) if current.step_id == node.id => current,
+        _ => trial.insert(ShellTrial::new(&node.id)),
+    };
+    let mut sources = vec![config.script.as_str()];
+    sources.extend(config.shell_args.iter().map(String::as_str));
+    sources.extend(config.env.values().map(String::as_str));
+    let mut names: Vec<String> = Vec::new();
+    for name in sources.into_iter().flat_map(placeholder_names) {
+        if name != RUN_TMPDIR_VAR && name != STEP_TMPDIR_VAR && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    current.sync_vars(&names, |name| {
+        params
+            .get(name)
+            .cloned()
+            .or_else(|| std::env::var(name).ok())
+    });
+
+    ui.add_space(4.0);
+    egui::CollapsingHeader::new(t("builder.shell_trial.title"))
+        .id_source(("shell_trial", node.id.as_str()))
+        .show(ui, |ui| {
+            if current.vars.is_empty() {
+                ui.colored_label(palette.fg_text_secondary, t("builder.shell_trial.no_vars"));
+            } else {
+                ui.colored_label(
+                    palette.fg_text_secondary,
+                    t("builder.shell_trial.dummy_values"),
+                );
+                egui::Grid::new(("shell_trial_vars", node.id.as_str()))
+                    .num_columns(2)
+                    .spacing([8.0, 4.0])
+                    .show(ui, |ui| {
+                        for (name, value) in current.vars.iter_mut() {
+                            ui.monospace(format!("${{{name}}}"));
+                            ui.text_edit_singleline(value);
+                            ui.end_row();
+                        }
+                    });
+            }
+            if ui
+                .add_enabled(
+                    !current.is_running() && !config.script.trim().is_empty(),
+                    egui::Button::new(t("builder.shell_trial.run")),
+                )
+                .on_hover_text(t("builder.shell_trial.hover"))
+                .clicked()
+            {
+                current.status = ShellTrialStatus::Requested;
+            }
+            match &current.status {
+                ShellTrialStatus::Idle => {}
+                ShellTrialStatus::Requested | ShellTrialStatus::Running => {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(t("builder.running"));
+                    });
+                }
+                ShellTrialStatus::Failed(error) => {
+                    ui.colored_label(palette.accent_error, error);
+                }
+                ShellTrialStatus::Done { exit_code, output } => {
+                    let (color, text) = match exit_code {
+                        Some(0) => (
+                            palette.accent_success,
+                            tf("builder.shell_trial.exit_code", &[("code", &0)]),
+                        ),
+                        Some(code) => (
+                            palette.accent_error,
+                            tf("builder.shell_trial.exit_code", &[("code", &code)]),
+                        ),
+                        None => (
+                            palette.accent_error,
+                            t("builder.shell_trial.signaled").to_string(),
+                        ),
+                    };
+                    ui.colored_label(color, text);
+                    render_output(ui, &node.id, output, palette);
+                }
+            }
+        });
+}
+
+/// 로컬 테스트 출력을 고정폭 읽기 전용 영역으로 그린다.
+fn render_output(ui: &mut egui::Ui, step_id: &str, output: &str, palette: ThemePalette) {
+    if output.is_empty() {
+        ui.colored_label(
+            palette.fg_text_secondary,
+            t("builder.shell_trial.no_output"),
+        );
+        return;
+    }
+    egui::Frame::none()
+        .fill(palette.bg_log)
+        .stroke(egui::Stroke::new(1.0, palette.border_soft))
+        .inner_margin(egui::Margin::same(6.0))
+        .show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_source(("shell_trial_output", step_id))
+                .max_height(180.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new(output).monospace().size(12.0));
+                });
+        });
+}
@@ -0,0 +1,66 @@
+use super::super::*;
+
+/// 실행 전 건너뛰기 조건(`skip_if`) 설정 UI를 그린다.
+///
+/// 파일 경로와 조회 SQL이 모두 비면 설정 자체를 제거한다.
+pub(super) fn render_skip_if_section(
+    ui: &mut egui::Ui,
+    skip_if: &mut Option<StepSkipIf>,
+    mark_dirty: &mut bool,
+) {
+    egui::CollapsingHeader::new(t("builder.skip_if.title"))
+        .default_open(skip_if.is_some())
+        .show(ui, |ui| {
+            let config = skip_if.get_or_insert_with(StepSkipIf::default);
+            ui.label(t("builder.skip_if.file_exists"));
+            if edit_optional(
+                ui,
+                &mut config.file_exists,
+                false,
+                "/data/out/${LOAD_DT}.csv",
+            ) {
+                *mark_dirty = true;
+            }
+            ui.label(t("builder.skip_if.sql_returns_rows"));
+            if edit_optional(
+                ui,
+                &mut config.sql_returns_rows,
+                true,
+                "SELECT 1 FROM load_log WHERE load_dt = '${LOAD_DT}'",
+            ) {
+                *mark_dirty = true;
+            }
+            if config.sql_returns_rows.is_some() {
+                ui.label(t("builder.skip_if.db"));
+                if edit_optional(ui, &mut config.target_db, false, "default") {
+                    *mark_dirty = true;
+                }
+            }
+            ui.small(t("builder.skip_if.hint"));
+        });
+    if skip_if.as_ref().is_some_and(StepSkipIf::is_empty) {
+        *skip_if = None;
+    }
+}
+
+/// 선택 문자열을 편집하고 변경 여부를 반환한다. 비우면 `None`으로 둔다.
+fn edit_optional(
+    ui: &mut egui::Ui,
+    value: &mut Option<String>,
+    multiline: bool,
+    hint: &str,
+) -> bool {
+    let mut buf = value.clone().unwrap_or_default();
+    let edit = if multiline {
+        egui::TextEdit::multiline(&mut buf)
+            .code_editor()
+            .desired_rows(2)
+    } else {
+        egui::TextEdit::singleline(&mut buf)
+    };
+    if ui.add(edit.hint_text(hint)).changed() {
+        *value = Some(buf).filter(|text| !text.trim().is_empty());
+        return true;
+    }
+    false
+}
@@ -0,0 +1,29 @@
+use super::super::*;
+
+/// 마지막 성공 실행 이후 SQL 파일이 바뀐 `sql_file` Step에 알림과 변경 내용 보기 버튼을 그린다.
+///
+/// `changed_at`은 비교한 마지막 성공 실행 시각이며 `None`이면 아무것도 그리지 않는다. 버튼을 누르면
+/// `open_diff`를 켠다.
+pub(super) fn render_sql_change_section(
+    ui: &mut egui::Ui,
+    node: &EditorStepNode,
+    changed_at: Option<&str>,
+    open_diff: &mut bool,
+    palette: ThemePalette,
+) {
+    let (EditorStepConfig::SqlFile { .. }, Some(changed_at)) = (&node.config, changed_at) else {
+        return;
+    };
+    ui.add_space(4.0);
+    ui.colored_label(
+        palette.accent_warning,
+        tf("builder.sql_change.changed", &[("changed_at", &changed_at)]),
+    );
+    if ui
+        .button(t("builder.sql_change.show"))
+        .on_hover_text(t("builder.sql_change.show_hover"))
+        .clicked()
+    {
+        *open_diff = true;
+    }
+}
@@ -0,0 +1,106 @@
+use super::super::*;
+use crate::engine::SQL_PREVIEW_MAX_ROWS;
+use crate::executor::QueryRows;
+
+/// SQL Step의 조회 결과 미리보기 버튼과 결과 표를 렌더링한다.
+///
+/// 버튼을 누르면 `preview`를 새 요청으로 바꾸며, 요청 실행과 결과 채우기는 앱이 맡는다.
+/// 다른 Step의 미리보기 결과는 표시하지 않는다.
+pub(super) fn render_sql_preview_section(
+    ui: &mut egui::Ui,
+    node: &EditorStepNode,
+    preview: &mut Option<SqlPreview>,
+    palette: ThemePalette,
+) {
+    let EditorStepConfig::Sql { sql, target_db, .. } = &node.config else {
+        return;
+    };
+    let running = preview.as_ref().is_some_and(|current| {
+        current.step_id == node.id
+            && matches!(
+                current.status,
+                SqlPreviewStatus::Requested | SqlPreviewStatus::Running
+            )
+    });
+    ui.add_space(4.0);
+    if ui
+        .add_enabled(
+            !running && !sql.trim().is_empty(),
+            egui::Button::new(tf(
+                "builder.preview.button",
+                &[("SQL_PREVIEW_MAX_ROWS", &SQL_PREVIEW_MAX_ROWS)],
+            )),
+        )
+        .on_hover_text(t("builder.preview.hover"))
+        .clicked()
+    {
+        *preview = Some(SqlPreview::request(&node.id, target_db.clone(), sql));
+        return;
+    }
+    let Some(current) = preview
+        .as_ref()
+        .filter(|current| current.step_id == node.id)
+    else {
+        return;
+    };
+    match &current.status {
+        SqlPreviewStatus::Requested | SqlPreviewStatus::Running => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(t("builder.preview.running"));
+            });
+        }
+        SqlPreviewStatus::Failed(error) => {
+            ui.colored_label(palette.accent_error, error);
+        }
+        SqlPreviewStatus::Done(rows) => render_result_grid(ui, &node.id, rows, palette),
+    }
+}
+
+/// 미리보기 결과를 읽기 전용 표로 그린다. NULL 값은 흐리게 표시한다.
+fn render_result_grid(ui: &mut egui::Ui, step_id: &str, rows: &QueryRows, palette: ThemePalette) {
+    if rows.rows.is_empty() {
+        ui.colored_label(palette.fg_text_secondary, t("builder.preview.no_rows"));
+        return;
+    }
+    let summary = if rows.truncated {
+        tf(
+            "builder.preview.truncated",
+            &[
+                ("more", &(rows.rows.len() + 1)),
+                ("shown", &rows.rows.len()),
+            ],
+        )
+    } else {
+        tf("builder.preview.rows", &[("rows", &rows.rows.len())])
+    };
+    ui.colored_label(palette.fg_text_secondary, summary);
+    egui::ScrollArea::both()
+        .id_source(("sql_preview", step_id))
+        .max_height(240.0)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            egui::Grid::new(("sql_preview_grid", step_id))
+                .striped(true)
+                .spacing([12.0, 4.0])
+                .show(ui, |ui| {
+                    for column in &rows.columns {
+                        ui.label(egui::RichText::new(column).strong());
+                    }
+                    ui.end_row();
+                    for row in &rows.rows {
+                        for value in row {
+                            match value {
+                                Some(value) => ui.label(value),
+                                None => ui.label(
+                                    egui::RichText::new("NULL")
+                                        .italics()
+                                        .color(palette.fg_text_secondary),
+                                ),
+                            };
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
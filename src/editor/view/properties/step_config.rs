@@ -1,5 +1,6 @@
 use super::super::*;
 use super::*;
+use crate::scenario::{IoPriorityClass, ProcessPriority, VerifyMethod};
 use std::collections::HashMap;
 
 /// Step 구성 UI를 노출한다.
@@ -9,34 +10,261 @@ pub(super) fn render_step_config_ui(
     mark_dirty: &mut bool,
     db_keys: &[String],
     id_hint: &str,
+    default_target_db: Option<&str>,
+    palette: ThemePalette,
 ) {
+    let target_inherited = |target_db: &Option<String>| {
+        default_target_db.is_some() && target_db.as_deref() == default_target_db
+    };
     match config {
-        EditorStepConfig::Sql { sql, target_db } => {
-            render_target_db_picker(ui, target_db, db_keys, mark_dirty, id_hint);
+        EditorStepConfig::Sql {
+            sql,
+            target_db,
+            guard,
+        } => {
+            let inherited = target_inherited(target_db);
+            inherited_style(ui, inherited, palette, |ui| {
+                render_target_db_picker(ui, target_db, db_keys, mark_dirty, id_hint)
+            });
             ui.label("SQL");
-            if ui.text_edit_multiline(sql).changed() {
+            let output = egui::TextEdit::multiline(sql).show(ui);
+            if output.response.changed() || accept_schema_drop(ui, &output, sql) {
                 *mark_dirty = true;
             }
+            render_sql_guard(ui, guard, mark_dirty);
         }
-        EditorStepConfig::SqlFile { path, target_db } => {
-            render_target_db_picker(ui, target_db, db_keys, mark_dirty, id_hint);
-            ui.label("SQL 파일 경로");
+        EditorStepConfig::SqlFile {
+            path,
+            target_db,
+            guard,
+        } => {
+            let inherited = target_inherited(target_db);
+            inherited_style(ui, inherited, palette, |ui| {
+                render_target_db_picker(ui, target_db, db_keys, mark_dirty, id_hint)
+            });
+            ui.label(t("builder.cfg.sql_file_path"));
             let mut path_buf = path.display().to_string();
             if ui.text_edit_singleline(&mut path_buf).changed() {
                 *path = std::path::PathBuf::from(path_buf);
                 *mark_dirty = true;
             }
+            render_sql_guard(ui, guard, mark_dirty);
         }
         EditorStepConfig::SqlLoaderPar { config } => {
             render_sqlldr(ui, config, mark_dirty);
         }
         EditorStepConfig::Shell { config } => {
-            render_shell(ui, config, mark_dirty);
+            render_shell(ui, config, mark_dirty, palette);
         }
         EditorStepConfig::Extract { config } => {
             render_extract(ui, config, mark_dirty);
         }
         EditorStepConfig::Loop { .. } => {}
+        EditorStepConfig::ManualGate { config } => {
+            ui.label(t("builder.cfg.gate_message"));
+            let mut message = config.message.clone().unwrap_or_default();
+            if ui
+                .add(
+                    egui::TextEdit::multiline(&mut message)
+                        .hint_text(t("builder.cfg.gate_message_hint")),
+                )
+                .changed()
+            {
+                config.message = (!message.trim().is_empty()).then_some(message);
+                *mark_dirty = true;
+            }
+        }
+        EditorStepConfig::Wait { config } => {
+            render_wait(ui, config, mark_dirty);
+        }
+        EditorStepConfig::WaitForRun { config } => {
+            render_wait_for_run(ui, config, mark_dirty);
+        }
+        EditorStepConfig::Verify { config } => {
+            render_verify(ui, config, mark_dirty);
+        }
+        EditorStepConfig::Marker => {
+            ui.label(
+                "표식 Step은 아무 작업 없이 바로 성공합니다. 여러 Step을 이 Step으로 모은 뒤 \
+                 다음 Step을 하나만 연결하면 의존 연결이 단순해집니다.",
+            );
+        }
+    }
+}
+
+/// 검증 Step의 대상 파일, 계산 방식, 기대값 출처를 입력받는다.
+fn render_verify(
+    ui: &mut egui::Ui,
+    config: &mut crate::scenario::VerifyConfig,
+    mark_dirty: &mut bool,
+) {
+    ui.label(t("builder.cfg.verify_file"));
+    if ui.text_edit_singleline(&mut config.file).changed() {
+        *mark_dirty = true;
+    }
+    ui.label(t("builder.cfg.verify_method"));
+    ui.horizontal(|ui| {
+        for method in VerifyMethod::ALL {
+            if ui
+                .radio_value(&mut config.method, method, method.as_str())
+                .changed()
+            {
+                // 줄 수는 사이드카 파일이 없으므로 기대값을 직접 받는다.
+                if method == VerifyMethod::Lines
+                    && config.expected.is_none()
+                    && config.expected_file.is_none()
+                {
+                    config.expected = Some(String::new());
+                }
+                *mark_dirty = true;
+            }
+        }
+    });
+    // 0: 값/변수, 1: 기대값 파일, 2: 사이드카 파일(체크섬만)
+    let mut source = match (&config.expected, &config.expected_file) {
+        (Some(_), _) => 0,
+        (None, Some(_)) => 1,
+        (None, None) => 2,
+    };
+    ui.label(t("builder.cfg.expected_source"));
+    ui.horizontal(|ui| {
+        let mut changed = ui
+            .radio_value(&mut source, 0, t("builder.cfg.expected_value"))
+            .changed();
+        changed |= ui
+            .radio_value(&mut source, 1, t("builder.cfg.expected_file"))
+            .changed();
+        if config.method != VerifyMethod::Lines {
+            changed |= ui
+                .radio_value(&mut source, 2, t("builder.cfg.sidecar"))
+                .changed();
+        }
+        if changed {
+            config.expected = (source == 0).then(String::new);
+            config.expected_file = (source == 1).then(String::new);
+            *mark_dirty = true;
+        }
+    });
+    if let Some(expected) = &mut config.expected {
+        if ui
+            .add(egui::TextEdit::singleline(expected).hint_text(t("builder.cfg.expected_hint")))
+            .changed()
+        {
+            *mark_dirty = true;
+        }
+    } else if let Some(path) = &mut config.expected_file {
+        if ui
+            .add(egui::TextEdit::singleline(path).hint_text(t("builder.cfg.expected_file_hint")))
+            .changed()
+        {
+            *mark_dirty = true;
+        }
+    } else {
+        let sidecar = format!("{}.{}", config.file, config.method.as_str());
+        ui.label(
+            egui::RichText::new(tf("builder.cfg.sidecar_hint", &[("sidecar", &sidecar)]))
+                .small()
+                .weak(),
+        );
+    }
+}
+
+/// 실행 대기 Step의 대상 시나리오, 기준 시각, 확인 간격, 최대 대기 시간을 입력받는다.
+fn render_wait_for_run(
+    ui: &mut egui::Ui,
+    config: &mut crate::scenario::WaitForRunConfig,
+    mark_dirty: &mut bool,
+) {
+    ui.label(t("builder.cfg.wait_scenario"));
+    if ui
+        .add(
+            egui::TextEdit::singleline(&mut config.scenario)
+                .hint_text(t("builder.cfg.wait_scenario_hint")),
+        )
+        .changed()
+    {
+        *mark_dirty = true;
+    }
+    ui.label(t("builder.cfg.wait_since"));
+    let mut since = config.since.clone().unwrap_or_default();
+    if ui
+        .add(egui::TextEdit::singleline(&mut since).hint_text(t("builder.cfg.wait_since_hint")))
+        .changed()
+    {
+        config.since = (!since.trim().is_empty()).then_some(since);
+        *mark_dirty = true;
+    }
+    ui.label(t("builder.cfg.poll_sec"));
+    if ui
+        .add(
+            egui::DragValue::new(&mut config.poll_sec)
+                .clamp_range(1..=3_600)
+                .suffix(t("builder.seconds_suffix")),
+        )
+        .changed()
+    {
+        *mark_dirty = true;
+    }
+    let mut limited = config.max_wait_sec.is_some();
+    if ui
+        .checkbox(&mut limited, t("builder.cfg.max_wait"))
+        .changed()
+    {
+        config.max_wait_sec = limited.then_some(3_600);
+        *mark_dirty = true;
+    }
+    if let Some(max_wait) = &mut config.max_wait_sec
+        && ui
+            .add(
+                egui::DragValue::new(max_wait)
+                    .clamp_range(1..=86_400 * 7)
+                    .suffix(t("builder.seconds_suffix")),
+            )
+            .changed()
+    {
+        *mark_dirty = true;
+    }
+}
+
+/// 대기 Step의 대기 방식(초 단위/지정 시각까지)과 값을 입력받는다.
+fn render_wait(ui: &mut egui::Ui, config: &mut crate::scenario::WaitConfig, mark_dirty: &mut bool) {
+    let mut until_mode = config.until_time.is_some();
+    ui.horizontal(|ui| {
+        let by_seconds = ui.radio_value(&mut until_mode, false, t("builder.cfg.wait_seconds_mode"));
+        let by_time = ui.radio_value(&mut until_mode, true, t("builder.cfg.wait_until_mode"));
+        if by_seconds.changed() || by_time.changed() {
+            if until_mode {
+                config.seconds = None;
+                config.until_time = Some(String::new());
+            } else {
+                config.until_time = None;
+                config.seconds = Some(60);
+            }
+            *mark_dirty = true;
+        }
+    });
+    if let Some(until) = &mut config.until_time {
+        ui.label(t("builder.cfg.until_time"));
+        if ui
+            .add(egui::TextEdit::singleline(until).hint_text(t("builder.cfg.until_time_hint")))
+            .changed()
+        {
+            *mark_dirty = true;
+        }
+    } else {
+        let mut seconds = config.seconds.unwrap_or_default();
+        ui.label(t("builder.cfg.wait_seconds"));
+        if ui
+            .add(
+                egui::DragValue::new(&mut seconds)
+                    .clamp_range(0..=86_400)
+                    .suffix(t("builder.seconds_suffix")),
+            )
+            .changed()
+        {
+            config.seconds = Some(seconds);
+            *mark_dirty = true;
+        }
     }
 }
 
@@ -47,18 +275,18 @@ fn render_target_db_picker(
     db_keys: &[String],
     mark_dirty: &mut bool,
     id_hint: &str,
-) {
-    ui.label("DB 타겟(target_db)");
+) -> egui::Response {
+    ui.label(t("builder.cfg.target_db"));
     let selected_text = target_db
         .as_deref()
         .map(|s| s.to_string())
-        .unwrap_or_else(|| "(기본 DB 사용)".to_string());
+        .unwrap_or_else(|| t("builder.cfg.default_db").to_string());
     ui.push_id(format!("target_db_{id_hint}"), |ui| {
         egui::ComboBox::from_id_source("target_db_combo")
             .selected_text(selected_text)
             .show_ui(ui, |ui| {
                 if ui
-                    .selectable_label(target_db.is_none(), "(기본 DB 사용)")
+                    .selectable_label(target_db.is_none(), t("builder.cfg.default_db"))
                     .clicked()
                 {
                     if target_db.is_some() {
@@ -73,7 +301,70 @@ fn render_target_db_picker(
                         *mark_dirty = true;
                     }
                 }
-            });
+            })
+            .response
+    })
+    .inner
+}
+
+/// SQL 안전장치(문장 제한 시간, 최대 변경 행 수) 입력을 렌더링한다.
+fn render_sql_guard(
+    ui: &mut egui::Ui,
+    guard: &mut crate::scenario::SqlGuardConfig,
+    mark_dirty: &mut bool,
+) {
+    ui.label(t("builder.cfg.guard"));
+    optional_u64_field_ui(
+        ui,
+        t("builder.cfg.statement_timeout"),
+        t("builder.cfg.suffix_seconds"),
+        &mut guard.statement_timeout_sec,
+        60,
+        1,
+        mark_dirty,
+    );
+    optional_u64_field_ui(
+        ui,
+        t("builder.cfg.max_affected_rows"),
+        t("builder.cfg.suffix_count"),
+        &mut guard.max_affected_rows,
+        1000,
+        1,
+        mark_dirty,
+    );
+    if guard.max_affected_rows.is_some() {
+        ui.small(t("builder.cfg.max_affected_rows_hint"));
+    }
+}
+
+/// 체크박스로 켜고 끄는 선택적 정수 필드를 렌더링한다. 값은 `min` 이상으로 제한한다.
+fn optional_u64_field_ui(
+    ui: &mut egui::Ui,
+    label: &str,
+    suffix: &str,
+    value: &mut Option<u64>,
+    default_value: u64,
+    min: u64,
+    mark_dirty: &mut bool,
+) {
+    ui.horizontal(|ui| {
+        let mut enabled = value.is_some();
+        if ui.checkbox(&mut enabled, label).changed() {
+            *value = enabled.then_some(value.unwrap_or(default_value));
+            *mark_dirty = true;
+        }
+        if let Some(current) = value.as_mut() {
+            if ui
+                .add(
+                    egui::DragValue::new(current)
+                        .clamp_range(min..=u64::MAX)
+                        .suffix(suffix),
+                )
+                .changed()
+            {
+                *mark_dirty = true;
+            }
+        }
     });
 }
 
@@ -84,24 +375,223 @@ fn render_sqlldr(
     mark_dirty: &mut bool,
 ) {
     let mut control = config.control_file.display().to_string();
-    ui.label("control 파일");
+    ui.label(t("builder.cfg.control_file"));
     if ui.text_edit_singleline(&mut control).changed() {
         config.control_file = control.into();
         *mark_dirty = true;
     }
 
-    optional_path_field_ui(ui, "data 파일", &mut config.data_file, mark_dirty);
-    optional_path_field_ui(ui, "log 파일", &mut config.log_file, mark_dirty);
-    optional_path_field_ui(ui, "bad 파일", &mut config.bad_file, mark_dirty);
-    optional_path_field_ui(ui, "discard 파일", &mut config.discard_file, mark_dirty);
+    optional_path_field_ui(
+        ui,
+        t("builder.cfg.data_file"),
+        &mut config.data_file,
+        mark_dirty,
+    );
+    optional_path_field_ui(
+        ui,
+        t("builder.cfg.log_file"),
+        &mut config.log_file,
+        mark_dirty,
+    );
+    optional_path_field_ui(
+        ui,
+        t("builder.cfg.bad_file"),
+        &mut config.bad_file,
+        mark_dirty,
+    );
+    optional_path_field_ui(
+        ui,
+        t("builder.cfg.discard_file"),
+        &mut config.discard_file,
+        mark_dirty,
+    );
 
     let mut conn = config.conn.clone().unwrap_or_default();
-    ui.label("접속 문자열");
+    ui.label(t("builder.cfg.conn"));
     if ui.text_edit_singleline(&mut conn).changed() {
         config.conn = if conn.is_empty() { None } else { Some(conn) };
         *mark_dirty = true;
     }
-    ui.small("비워두면 SQLLDR_CONN 환경 변수를 사용합니다.");
+    ui.small(t("builder.cfg.conn_hint"));
+
+    remote_field_ui(ui, &mut config.remote, mark_dirty);
+    priority_field_ui(ui, &mut config.priority, mark_dirty);
+
+    ui.label(t("builder.cfg.sqlldr_options"));
+    let options = &mut config.options;
+    optional_u64_field_ui(
+        ui,
+        "rows",
+        t("builder.cfg.suffix_rows"),
+        &mut options.rows,
+        5000,
+        1,
+        mark_dirty,
+    );
+    optional_u64_field_ui(
+        ui,
+        "bindsize",
+        t("builder.cfg.suffix_bytes"),
+        &mut options.bindsize,
+        1_048_576,
+        1,
+        mark_dirty,
+    );
+    optional_u64_field_ui(
+        ui,
+        "errors",
+        t("builder.cfg.suffix_count"),
+        &mut options.errors,
+        50,
+        0,
+        mark_dirty,
+    );
+    optional_u64_field_ui(
+        ui,
+        "skip",
+        t("builder.cfg.suffix_rows"),
+        &mut options.skip,
+        1,
+        1,
+        mark_dirty,
+    );
+    if ui
+        .checkbox(&mut options.direct, "direct path (direct=true)")
+        .changed()
+    {
+        *mark_dirty = true;
+    }
+
+    let mut extra = config.extra_args.join("\n");
+    ui.label(t("builder.cfg.extra_args"));
+    if ui.text_edit_multiline(&mut extra).changed() {
+        // 입력 중인 빈 줄이 사라지지 않도록 그대로 두고, 시나리오로 변환할 때 빈 인자를 뺀다.
+        config.extra_args = if extra.is_empty() {
+            Vec::new()
+        } else {
+            extra
+                .split('\n')
+                .map(|line| line.trim_end_matches('\r').to_string())
+                .collect()
+        };
+        *mark_dirty = true;
+    }
+    ui.small(t("builder.cfg.extra_args_hint"));
+}
+
+/// 원격 실행 호스트 키 입력을 렌더링한다.
+fn remote_field_ui(ui: &mut egui::Ui, remote: &mut Option<String>, mark_dirty: &mut bool) {
+    let mut buf = remote.clone().unwrap_or_default();
+    ui.label(t("builder.cfg.remote"));
+    if ui.text_edit_singleline(&mut buf).changed() {
+        let trimmed = buf.trim();
+        *remote = if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed.to_string())
+        };
+        *mark_dirty = true;
+    }
+    ui.small(t("builder.cfg.remote_hint"));
+}
+
+/// 프로세스 우선순위와 CPU 지정 입력을 렌더링한다. 모두 비우면 설정을 지운다.
+fn priority_field_ui(
+    ui: &mut egui::Ui,
+    priority: &mut Option<ProcessPriority>,
+    mark_dirty: &mut bool,
+) {
+    let mut value = priority.clone().unwrap_or_default();
+    let mut changed = false;
+    egui::CollapsingHeader::new(t("builder.cfg.priority"))
+        .default_open(priority.is_some())
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let mut enabled = value.nice.is_some();
+                if ui.checkbox(&mut enabled, "nice").changed() {
+                    value.nice = enabled.then_some(10);
+                    changed = true;
+                }
+                if let Some(nice) = &mut value.nice {
+                    changed |= ui
+                        .add(egui::DragValue::new(nice).clamp_range(-20..=19))
+                        .on_hover_text(t("builder.cfg.nice_hover"))
+                        .changed();
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label(t("builder.cfg.io_class"));
+                egui::ComboBox::from_id_source("priority_io_class")
+                    .selected_text(
+                        value
+                            .io_class
+                            .map(IoPriorityClass::name)
+                            .unwrap_or(t("builder.cfg.unset")),
+                    )
+                    .show_ui(ui, |ui| {
+                        changed |= ui
+                            .selectable_value(&mut value.io_class, None, t("builder.cfg.unset"))
+                            .changed();
+                        for class in IoPriorityClass::ALL {
+                            changed |= ui
+                                .selectable_value(&mut value.io_class, Some(class), class.name())
+                                .changed();
+                        }
+                    });
+            });
+            if value.io_class == Some(IoPriorityClass::Idle) && value.io_level.is_some() {
+                value.io_level = None;
+                changed = true;
+            }
+            ui.add_enabled_ui(value.io_class != Some(IoPriorityClass::Idle), |ui| {
+                ui.horizontal(|ui| {
+                    let mut enabled = value.io_level.is_some();
+                    if ui
+                        .checkbox(&mut enabled, t("builder.cfg.io_level"))
+                        .changed()
+                    {
+                        value.io_level = enabled.then_some(7);
+                        changed = true;
+                    }
+                    if let Some(level) = &mut value.io_level {
+                        changed |= ui
+                            .add(egui::DragValue::new(level).clamp_range(0..=7))
+                            .on_hover_text(t("builder.cfg.io_level_hover"))
+                            .changed();
+                    }
+                });
+            });
+            // 입력 중인 쉼표가 사라지지 않도록 편집하는 동안은 입력 문자열을 따로 보관한다.
+            let draft_id = ui.make_persistent_id("priority_cpu_affinity");
+            let mut cpus = ui
+                .data(|data| data.get_temp::<String>(draft_id))
+                .unwrap_or_else(|| {
+                    value
+                        .cpu_affinity
+                        .iter()
+                        .map(usize::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                });
+            ui.label(t("builder.cfg.cpus"));
+            let response = ui.text_edit_singleline(&mut cpus);
+            if response.changed() {
+                value.cpu_affinity = cpus
+                    .split(',')
+                    .filter_map(|cpu| cpu.trim().parse().ok())
+                    .collect();
+                changed = true;
+                ui.data_mut(|data| data.insert_temp(draft_id, cpus));
+            }
+            if response.lost_focus() {
+                ui.data_mut(|data| data.remove::<String>(draft_id));
+            }
+            ui.small(t("builder.cfg.priority_hint"));
+        });
+    if changed {
+        *priority = Some(value).filter(|value| !value.is_empty());
+        *mark_dirty = true;
+    }
 }
 
 /// 선택적 경로 필드를 렌더링한다.
@@ -133,14 +623,13 @@ fn render_shell(
     ui: &mut egui::Ui,
     config: &mut crate::scenario::ShellConfig,
     mark_dirty: &mut bool,
+    palette: ThemePalette,
 ) {
-    ui.label("스크립트");
-    if ui.text_edit_multiline(&mut config.script).changed() {
-        *mark_dirty = true;
-    }
+    ui.label(t("builder.cfg.script"));
+    super::shell_editor::render_script_editor(ui, &mut config.script, mark_dirty, palette);
 
     let mut program = config.shell_program.clone().unwrap_or_default();
-    ui.label("셸 프로그램");
+    ui.label(t("builder.cfg.shell_program"));
     if ui.text_edit_singleline(&mut program).changed() {
         config.shell_program = if program.is_empty() {
             None
@@ -151,7 +640,7 @@ fn render_shell(
     }
 
     let mut args = config.shell_args.join(", ");
-    ui.label("인자 목록(쉼표 구분)");
+    ui.label(t("builder.cfg.shell_args"));
     if ui.text_edit_singleline(&mut args).changed() {
         config.shell_args = args
             .split(',')
@@ -166,7 +655,7 @@ fn render_shell(
         .as_ref()
         .map(|p| p.display().to_string())
         .unwrap_or_default();
-    ui.label("작업 디렉터리");
+    ui.label(t("builder.cfg.working_dir"));
     if ui.text_edit_singleline(&mut work_dir).changed() {
         config.working_dir = if work_dir.is_empty() {
             None
@@ -177,7 +666,7 @@ fn render_shell(
     }
 
     let mut run_as = config.run_as.clone().unwrap_or_default();
-    ui.label("실행 사용자");
+    ui.label(t("builder.cfg.run_as"));
     if ui.text_edit_singleline(&mut run_as).changed() {
         config.run_as = if run_as.is_empty() {
             None
@@ -187,7 +676,14 @@ fn render_shell(
         *mark_dirty = true;
     }
 
-    ui.label("환경 변수 (KEY=VALUE 한 줄씩)");
+    if ui
+        .checkbox(&mut config.inherit_env, t("builder.cfg.inherit_env"))
+        .on_hover_text(t("builder.cfg.inherit_env_hover"))
+        .changed()
+    {
+        *mark_dirty = true;
+    }
+    ui.label(t("builder.cfg.env"));
     let mut env_text = config
         .env
         .iter()
@@ -198,38 +694,47 @@ fn render_shell(
         config.env = parse_env(&env_text);
         *mark_dirty = true;
     }
+
+    remote_field_ui(ui, &mut config.remote, mark_dirty);
+    priority_field_ui(ui, &mut config.priority, mark_dirty);
 }
 
 /// Extract Step 속성 UI를 렌더링한다.
 fn render_extract(ui: &mut egui::Ui, config: &mut ExtractVarFromFileConfig, mark_dirty: &mut bool) {
-    ui.label("파일 경로");
+    ui.label(t("builder.cfg.file_path"));
     if ui.text_edit_singleline(&mut config.file_path).changed() {
         *mark_dirty = true;
     }
     let mut line = config.line as i32;
     if ui
-        .add(egui::DragValue::new(&mut line).prefix("라인 "))
+        .add(egui::DragValue::new(&mut line).prefix(t("builder.cfg.line_prefix")))
         .changed()
     {
         config.line = line.max(1) as usize;
         *mark_dirty = true;
     }
-    ui.label("정규식 패턴");
+    ui.label(t("builder.cfg.pattern"));
     if ui.text_edit_singleline(&mut config.pattern).changed() {
         *mark_dirty = true;
     }
     let mut group = config.group as i32;
     if ui
-        .add(egui::DragValue::new(&mut group).prefix("캡처 그룹 "))
+        .add(egui::DragValue::new(&mut group).prefix(t("builder.cfg.group_prefix")))
         .changed()
     {
         config.group = group.max(0) as usize;
         *mark_dirty = true;
     }
-    ui.label("저장할 변수명");
+    ui.label(t("builder.cfg.var_name"));
     if ui.text_edit_singleline(&mut config.var_name).changed() {
         *mark_dirty = true;
     }
+    if ui
+        .checkbox(&mut config.secret, t("builder.cfg.secret"))
+        .changed()
+    {
+        *mark_dirty = true;
+    }
 }
 
 /// Shell env 문자열을 파싱한다.
@@ -0,0 +1,118 @@
+use super::super::*;
+
+/// 선택한 Step만 따로 실행해 보는 테스트 버튼, 변수 입력, 로그 콘솔을 렌더링한다.
+///
+/// 버튼을 누르면 `test`를 새 요청으로 바꾸며, 참조 변수 수집과 실행, 로그 채우기는 앱이 맡는다.
+/// 다른 Step의 테스트가 진행 중이면 버튼을 막고, 그 결과는 표시하지 않는다.
+pub(super) fn render_step_test_section(
+    ui: &mut egui::Ui,
+    node: &EditorStepNode,
+    test: &mut Option<StepTest>,
+    palette: ThemePalette,
+) {
+    let busy = test
+        .as_ref()
+        .is_some_and(|current| current.is_active() || current.status == StepTestStatus::Editing);
+    ui.add_space(4.0);
+    if ui
+        .add_enabled(!busy, egui::Button::new(t("builder.step_test.button")))
+        .on_hover_text(t("builder.step_test.hover"))
+        .clicked()
+    {
+        *test = Some(StepTest::request(&node.id));
+        return;
+    }
+    let Some(current) = test.as_mut().filter(|current| current.step_id == node.id) else {
+        return;
+    };
+    match current.status.clone() {
+        StepTestStatus::Editing => {
+            if render_inputs(ui, current, palette) == Some(false) {
+                *test = None;
+                return;
+            }
+        }
+        StepTestStatus::Requested => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(t("builder.preparing"));
+            });
+        }
+        StepTestStatus::Running => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(t("builder.running"));
+                if ui.button(t("builder.stop")).clicked() {
+                    current.status = StepTestStatus::StopRequested;
+                }
+            });
+        }
+        StepTestStatus::StopRequested => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(t("builder.stopping"));
+            });
+        }
+        StepTestStatus::Passed => {
+            ui.colored_label(palette.accent_success, t("builder.step_test.passed"));
+        }
+        StepTestStatus::Failed(error) => {
+            ui.colored_label(palette.accent_error, format!("❌ {error}"));
+        }
+    }
+    if !current.logs.is_empty() {
+        render_console(ui, &current.step_id, &current.logs, palette);
+    }
+}
+
+/// 참조 변수 입력 표와 실행·취소 버튼을 그린다.
+///
+/// # 반환값
+/// 실행을 누르면 `Some(true)`, 취소를 누르면 `Some(false)`, 아무것도 누르지 않으면 `None`.
+fn render_inputs(ui: &mut egui::Ui, test: &mut StepTest, palette: ThemePalette) -> Option<bool> {
+    let inputs = test.inputs.get_or_insert_with(Vec::new);
+    ui.colored_label(palette.fg_text_secondary, t("builder.step_test.enter_vars"));
+    egui::Grid::new(("step_test_inputs", test.step_id.as_str()))
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            for (name, value) in inputs.iter_mut() {
+                ui.monospace(format!("${{{name}}}"));
+                ui.text_edit_singleline(value);
+                ui.end_row();
+            }
+        });
+    let mut decision = None;
+    ui.horizontal(|ui| {
+        if ui.button(t("builder.run")).clicked() {
+            decision = Some(true);
+        }
+        if ui.button(t("common.cancel")).clicked() {
+            decision = Some(false);
+        }
+    });
+    if decision == Some(true) {
+        test.status = StepTestStatus::Requested;
+    }
+    decision
+}
+
+/// 테스트 실행 로그를 고정폭 콘솔로 그린다. 새 줄이 오면 맨 아래를 따라간다.
+fn render_console(ui: &mut egui::Ui, step_id: &str, logs: &[String], palette: ThemePalette) {
+    egui::Frame::none()
+        .fill(palette.bg_log)
+        .stroke(egui::Stroke::new(1.0, palette.border_soft))
+        .inner_margin(egui::Margin::same(6.0))
+        .show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_source(("step_test_console", step_id))
+                .max_height(180.0)
+                .auto_shrink([false, true])
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for line in logs {
+                        ui.label(egui::RichText::new(line).monospace().size(12.0));
+                    }
+                });
+        });
+}
@@ -0,0 +1,65 @@
+use super::super::*;
+use super::*;
+
+/// 실행 시간 창(`not_before`/`not_after`) 설정 UI를 그린다.
+///
+/// 두 시각이 모두 비면 설정 자체를 제거한다.
+pub(super) fn render_time_window_section(
+    ui: &mut egui::Ui,
+    time_window: &mut Option<StepTimeWindow>,
+    mark_dirty: &mut bool,
+    palette: ThemePalette,
+) {
+    egui::CollapsingHeader::new(t("builder.window.title"))
+        .default_open(time_window.is_some())
+        .show(ui, |ui| {
+            let window = time_window.get_or_insert_with(StepTimeWindow::default);
+            ui.label(t("builder.window.not_before"));
+            if edit_time(ui, &mut window.not_before) {
+                *mark_dirty = true;
+            }
+            ui.label(t("builder.window.not_after"));
+            if edit_time(ui, &mut window.not_after) {
+                *mark_dirty = true;
+            }
+            egui::ComboBox::from_label(t("builder.window.policy"))
+                .selected_text(match window.policy {
+                    TimeWindowPolicy::Wait => t("builder.window.wait"),
+                    TimeWindowPolicy::Fail => t("builder.window.fail"),
+                })
+                .show_ui(ui, |ui| {
+                    for (policy, label) in [
+                        (TimeWindowPolicy::Wait, t("builder.window.wait")),
+                        (TimeWindowPolicy::Fail, t("builder.window.fail")),
+                    ] {
+                        if ui
+                            .selectable_label(window.policy == policy, label)
+                            .clicked()
+                        {
+                            window.policy = policy;
+                            *mark_dirty = true;
+                        }
+                    }
+                });
+            if let Err(err) = window.parse() {
+                ui.colored_label(palette.accent_error, err.to_string());
+            }
+        });
+    if time_window.as_ref().is_some_and(StepTimeWindow::is_empty) {
+        *time_window = None;
+    }
+}
+
+/// 선택 시각 문자열을 편집하고 변경 여부를 반환한다.
+fn edit_time(ui: &mut egui::Ui, value: &mut Option<String>) -> bool {
+    let mut buf = value.clone().unwrap_or_default();
+    if ui.text_edit_singleline(&mut buf).changed() {
+        *value = if buf.trim().is_empty() {
+            None
+        } else {
+            Some(buf.trim().to_string())
+        };
+        return true;
+    }
+    false
+}
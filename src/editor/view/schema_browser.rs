@@ -0,0 +1,214 @@
+use super::*;
+
+/// 스키마 브라우저에서 SQL 편집기로 끌어 놓는 테이블·컬럼 이름이다.
+#[derive(Debug, Clone)]
+pub(super) struct SchemaDragPayload(pub String);
+
+impl<'a> ScenarioBuilderUi<'a> {
+    /// SQL 편집기 옆 스키마 브라우저 패널을 그린다.
+    ///
+    /// 테이블을 펼치면 그때 컬럼을 조회하며, 테이블·컬럼 이름을 SQL 편집기로 끌어다 놓으면 커서 위치에
+    /// 삽입된다.
+    pub(super) fn render_schema_browser(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.get_theme().palette();
+        let db_keys = self.get_state().db_key_list();
+        let Some(browser) = self.get_state_mut().schema_browser.as_mut() else {
+            return;
+        };
+        let mut close = false;
+        ui.horizontal(|ui| {
+            ui.heading(t("builder.schema.title"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .small_button("✕")
+                    .on_hover_text(t("common.close"))
+                    .clicked()
+                {
+                    close = true;
+                }
+                if ui
+                    .small_button("🔄")
+                    .on_hover_text(t("builder.schema.reload"))
+                    .clicked()
+                {
+                    let target = browser.target_db.clone();
+                    browser.reload(target);
+                }
+            });
+        });
+        let mut target = browser.target_db.clone();
+        egui::ComboBox::from_id_source("schema_browser_target")
+            .selected_text(format!("DB: {target}"))
+            .show_ui(ui, |ui| {
+                let mut keys = db_keys;
+                if !keys.contains(&target) {
+                    keys.insert(0, target.clone());
+                }
+                for key in keys {
+                    let label = key.clone();
+                    ui.selectable_value(&mut target, key, label);
+                }
+            });
+        if target != browser.target_db {
+            browser.reload(target);
+        }
+        ui.add(
+            egui::TextEdit::singleline(&mut browser.filter)
+                .hint_text(t("builder.schema.filter"))
+                .desired_width(f32::INFINITY),
+        );
+        ui.add_space(4.0);
+        match &browser.tables {
+            SchemaFetch::Requested | SchemaFetch::Running => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(t("builder.schema.loading"));
+                });
+            }
+            SchemaFetch::Failed(err) => {
+                ui.colored_label(
+                    palette.accent_error,
+                    tf("builder.schema.failed", &[("err", &err)]),
+                );
+            }
+            SchemaFetch::Done(_) => {
+                let tables: Vec<String> = browser
+                    .filtered_tables()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect();
+                ui.label(
+                    egui::RichText::new(tf("builder.schema.tables", &[("count", &tables.len())]))
+                        .color(palette.fg_text_secondary),
+                );
+                let mut requested: Vec<String> = Vec::new();
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for table in &tables {
+                            let id =
+                                ui.make_persistent_id(("schema_table", &browser.target_db, table));
+                            egui::collapsing_header::CollapsingState::load_with_default_open(
+                                ui.ctx(),
+                                id,
+                                false,
+                            )
+                            .show_header(ui, |ui| {
+                                drag_name(ui, table, table, palette.fg_text_primary);
+                            })
+                            .body(|ui| {
+                                match browser.columns.get(table) {
+                                    None => requested.push(table.clone()),
+                                    Some(SchemaFetch::Requested | SchemaFetch::Running) => {
+                                        ui.spinner();
+                                    }
+                                    Some(SchemaFetch::Failed(err)) => {
+                                        ui.colored_label(palette.accent_error, err);
+                                    }
+                                    Some(SchemaFetch::Done(columns)) => {
+                                        for column in columns {
+                                            ui.horizontal(|ui| {
+                                                drag_name(
+                                                    ui,
+                                                    &format!("{table}.{}", column.name),
+                                                    &column.name,
+                                                    palette.fg_text_primary,
+                                                );
+                                                let nullable =
+                                                    if column.nullable { "" } else { " NOT NULL" };
+                                                ui.label(
+                                                    egui::RichText::new(format!(
+                                                        "{}{nullable}",
+                                                        column.data_type
+                                                    ))
+                                                    .small()
+                                                    .color(palette.fg_text_secondary),
+                                                );
+                                            });
+                                        }
+                                    }
+                                }
+                            });
+                        }
+                    });
+                for table in requested {
+                    browser.request_columns(&table);
+                }
+            }
+        }
+        if close {
+            self.get_state_mut().schema_browser = None;
+        }
+    }
+}
+
+/// 끌어서 SQL 편집기에 놓을 수 있는 이름 라벨을 그린다. `key`는 같은 이름의 컬럼을 구분한다.
+fn drag_name(ui: &mut egui::Ui, key: &str, name: &str, color: egui::Color32) {
+    ui.dnd_drag_source(
+        egui::Id::new(("schema_drag", key)),
+        SchemaDragPayload(name.to_string()),
+        |ui| {
+            ui.label(egui::RichText::new(name).monospace().color(color));
+        },
+    );
+}
+
+/// 스키마 브라우저에서 끌어 온 이름을 SQL 편집기에 놓으면 마지막 커서 위치(없으면 끝)에 삽입한다.
+///
+/// 끌기를 시작하면 편집기 포커스가 풀리므로 포커스가 있는 동안의 커서 위치를 egui 임시 메모리에 기억해
+/// 둔다. 이름을 삽입했으면 `true`를 반환한다.
+pub(super) fn accept_schema_drop(
+    ui: &egui::Ui,
+    output: &egui::text_edit::TextEditOutput,
+    text: &mut String,
+) -> bool {
+    let cursor_id = output.response.id.with("schema_drop_cursor");
+    if let Some(range) = output.cursor_range {
+        ui.data_mut(|d| d.insert_temp(cursor_id, range.primary.ccursor.index));
+    }
+    if output
+        .response
+        .dnd_hover_payload::<SchemaDragPayload>()
+        .is_some()
+    {
+        let stroke = ui.visuals().selection.stroke;
+        ui.painter().rect_stroke(output.response.rect, 2.0, stroke);
+    }
+    let Some(payload) = output.response.dnd_release_payload::<SchemaDragPayload>() else {
+        return false;
+    };
+    let at = ui
+        .data(|d| d.get_temp::<usize>(cursor_id))
+        .unwrap_or(usize::MAX);
+    let byte = text
+        .char_indices()
+        .nth(at)
+        .map_or(text.len(), |(idx, _)| idx);
+    text.insert_str(byte, &payload.0);
+    let after = text[..byte + payload.0.len()].chars().count();
+    ui.data_mut(|d| d.insert_temp(cursor_id, after));
+    true
+}
+
+/// 스키마 브라우저를 처음 열 때 볼 DB 타겟을 고른다.
+///
+/// 선택한 SQL Step의 타겟, 공통 기본값의 타겟, `default` 순으로 쓴다.
+pub(super) fn schema_target_hint(state: &ScenarioEditorState) -> String {
+    let selected = state
+        .selected_node_id
+        .as_deref()
+        .and_then(|id| state.node(id))
+        .and_then(|node| match &node.config {
+            EditorStepConfig::Sql { target_db, .. }
+            | EditorStepConfig::SqlFile { target_db, .. } => target_db.clone(),
+            _ => None,
+        });
+    selected
+        .or_else(|| {
+            state
+                .step_defaults
+                .as_ref()
+                .and_then(|defaults| defaults.target_db.clone())
+        })
+        .unwrap_or_else(|| "default".to_string())
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+impl<'a> ScenarioBuilderUi<'a> {
+    /// 속성 패널에서 연 `sql_file` 변경 내용 창을 그린다.
+    ///
+    /// 마지막 성공 실행 때 파일 내용과 지금 내용을 줄 단위로 비교해 지운 줄은 `-`, 추가한 줄은 `+`로 표시한다.
+    pub(super) fn render_sql_diff_window(&mut self, ctx: &egui::Context) {
+        let palette = *self.get_theme().palette();
+        let state = self.get_state_mut();
+        let Some((step_id, lines)) = state.sql_diff.take() else {
+            return;
+        };
+        let Some(change) = state.sql_changes.get(&step_id) else {
+            return;
+        };
+        let mut open = true;
+        egui::Window::new(tf("builder.sql_diff.title", &[("step_id", &step_id)]))
+            .id(egui::Id::new("builder_sql_diff"))
+            .open(&mut open)
+            .default_width(720.0)
+            .show(ctx, |ui| {
+                ui.label(
+                    egui::RichText::new(tf(
+                        "builder.sql_diff.since",
+                        &[("path", &change.path), ("at", &change.last_success_label())],
+                    ))
+                    .color(palette.fg_text_secondary),
+                );
+                if change.previous.is_none() {
+                    ui.colored_label(palette.accent_warning, t("builder.sql_diff.no_snapshot"));
+                    return;
+                }
+                let (removed, added) = diff_counts(&lines);
+                ui.label(tf(
+                    "builder.sql_diff.counts",
+                    &[("removed", &removed), ("added", &added)],
+                ));
+                ui.separator();
+                let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+                egui::ScrollArea::both()
+                    .max_height(520.0)
+                    .auto_shrink([false, true])
+                    .show_rows(ui, row_height, lines.len(), |ui, rows| {
+                        for line in &lines[rows] {
+                            let (text, color) = match line {
+                                DiffLine::Same(text) => {
+                                    (format!("  {text}"), palette.fg_text_secondary)
+                                }
+                                DiffLine::Removed(text) => {
+                                    (format!("- {text}"), palette.accent_error)
+                                }
+                                DiffLine::Added(text) => {
+                                    (format!("+ {text}"), palette.accent_success)
+                                }
+                            };
+                            ui.label(egui::RichText::new(text).monospace().color(color));
+                        }
+                    });
+            });
+        if open {
+            self.get_state_mut().sql_diff = Some((step_id, lines));
+        }
+    }
+}
+
+/// 지운 줄과 추가한 줄 수를 센다.
+fn diff_counts(lines: &[DiffLine]) -> (usize, usize) {
+    lines
+        .iter()
+        .fold((0, 0), |(removed, added), line| match line {
+            DiffLine::Same(_) => (removed, added),
+            DiffLine::Removed(_) => (removed + 1, added),
+            DiffLine::Added(_) => (removed, added + 1),
+        })
+}
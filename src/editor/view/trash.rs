@@ -0,0 +1,110 @@
+use super::*;
+
+impl<'a> ScenarioBuilderUi<'a> {
+    /// 이 시나리오를 편집하면서 삭제한 Step을 최근 것부터 보여 주고 되살리거나 버릴 수 있게 한다.
+    pub(super) fn render_trash(&mut self, ui: &mut egui::Ui) {
+        let palette = *self.get_theme().palette();
+        let count = self.get_state().trash.len();
+        let mut restore: Option<usize> = None;
+        let mut discard: Option<usize> = None;
+        let mut clear = false;
+        egui::CollapsingHeader::new(tf("builder.trash.title", &[("count", &count)]))
+            .id_source("builder_trash")
+            .show(ui, |ui| {
+                if count == 0 {
+                    ui.label(
+                        egui::RichText::new(t("builder.trash.empty"))
+                            .color(palette.fg_text_secondary),
+                    );
+                    return;
+                }
+                ui.label(
+                    egui::RichText::new(tf(
+                        "builder.trash.capacity",
+                        &[("capacity", &TRASH_CAPACITY)],
+                    ))
+                    .small()
+                    .color(palette.fg_text_secondary),
+                );
+                let state = self.get_state();
+                for (idx, deleted) in state.trash.iter().enumerate().rev() {
+                    ui.separator();
+                    ui.label(egui::RichText::new(&deleted.node.name).strong())
+                        .on_hover_text(trash_entry_details(deleted));
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} · {} · {}",
+                            deleted.node.id,
+                            deleted.node.kind.label(),
+                            deleted.deleted_at.format("%H:%M:%S")
+                        ))
+                        .small()
+                        .color(palette.fg_text_secondary),
+                    );
+                    ui.horizontal(|ui| {
+                        let restorable = state.can_restore_deleted(deleted);
+                        if ui
+                            .add_enabled(restorable, egui::Button::new(t("builder.trash.restore")))
+                            .on_hover_text(t("builder.trash.restore_hover"))
+                            .on_disabled_hover_text(tf(
+                                "builder.trash.open_flow",
+                                &[("flow", &loop_path_label(&deleted.loop_path))],
+                            ))
+                            .clicked()
+                        {
+                            restore = Some(idx);
+                        }
+                        if ui
+                            .small_button("✕")
+                            .on_hover_text(t("builder.trash.purge_hover"))
+                            .clicked()
+                        {
+                            discard = Some(idx);
+                        }
+                    });
+                }
+                ui.add_space(4.0);
+                if ui.button(t("builder.trash.clear")).clicked() {
+                    clear = true;
+                }
+            });
+        let state = self.get_state_mut();
+        if let Some(idx) = restore {
+            state.restore_deleted(idx);
+        }
+        if let Some(idx) = discard {
+            state.trash.remove(idx);
+        }
+        if clear {
+            state.trash.clear();
+        }
+    }
+}
+
+/// 휴지통 항목의 툴팁 문구를 만든다. 삭제 전 흐름과 연결을 보여 준다.
+fn trash_entry_details(deleted: &DeletedStep) -> String {
+    let mut lines = vec![tf(
+        "builder.trash.flow",
+        &[("flow", &loop_path_label(&deleted.loop_path))],
+    )];
+    if deleted.connections.is_empty() {
+        lines.push(t("builder.trash.no_connections").to_string());
+    } else {
+        lines.push(t("builder.trash.connections").to_string());
+        lines.extend(
+            deleted
+                .connections
+                .iter()
+                .map(|conn| format!("  {} → {}", conn.from_id, conn.to_id)),
+        );
+    }
+    lines.join("\n")
+}
+
+/// Loop 경로를 `최상위 › loop_a › loop_b` 형태로 만든다.
+fn loop_path_label(path: &[String]) -> String {
+    std::iter::once(t("builder.trash.top_level"))
+        .chain(path.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" › ")
+}
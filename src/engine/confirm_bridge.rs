@@ -1,9 +1,69 @@
+use crate::i18n::t;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
 
-/// UI와 엔진 사이에서 컨펌 응답을 중계하는 헬퍼이다.
+/// 실행 중인 Step 하나만 취소할 때 그 Step을 어떻게 처리할지 나타낸다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepCancelMode {
+    /// 실패로 처리한다. 이 Step에 의존하는 Step은 건너뛴다.
+    Fail,
+    /// 건너뛰고 성공으로 처리한다. 나머지 흐름은 그대로 이어 간다.
+    Skip,
+}
+
+/// 실행 하나에서 컨펌 Step에 응답하는 방식이다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmMode {
+    /// 응답할 화면이 있으면 묻고, 없으면(`--run` 등) 각 Step의 `default_answer`를 따른다.
+    #[default]
+    Prompt,
+    /// 묻지 않고 모든 컨펌을 각 Step의 `default_answer`로 바로 처리한다.
+    Unattended,
+    /// 기본 응답이 있어도 반드시 사람의 응답을 받는다. 응답할 화면이 없거나 응답 전에 연결이 끊기면
+    /// 기본 응답을 쓰지 않고 Step을 실패로 처리한다.
+    Attended,
+}
+
+impl ConfirmMode {
+    /// 모든 모드를 표시 순서대로 담은 목록이다.
+    pub const ALL: [ConfirmMode; 3] = [
+        ConfirmMode::Prompt,
+        ConfirmMode::Unattended,
+        ConfirmMode::Attended,
+    ];
+
+    /// 명령줄 인자에서 모드를 읽는다.
+    ///
+    /// `--unattended`이면 무인 실행, `--attended`이면 참관 실행이고, 둘 다 없으면 [`ConfirmMode::Prompt`]이다.
+    /// 둘을 함께 지정하면 오류이다.
+    pub fn from_args(args: &[String]) -> anyhow::Result<Self> {
+        let unattended = args.iter().any(|arg| arg == "--unattended");
+        let attended = args.iter().any(|arg| arg == "--attended");
+        match (unattended, attended) {
+            (true, true) => anyhow::bail!(t("cli.confirm_mode_conflict")),
+            (true, false) => Ok(ConfirmMode::Unattended),
+            (false, true) => Ok(ConfirmMode::Attended),
+            (false, false) => Ok(ConfirmMode::Prompt),
+        }
+    }
+}
+
+/// 실행 중인 Step의 개별 취소 토큰과 요청된 처리 방식이다.
+#[derive(Debug)]
+struct StepCancelSlot {
+    /// 그 Step에만 적용되는 취소 토큰이다.
+    token: CancellationToken,
+    /// 취소를 요청했으면 그 처리 방식이다.
+    mode: Option<StepCancelMode>,
+}
+
+/// UI와 엔진 사이에서 컨펌 응답, 수동 승인 게이트 승인, Step 개별 취소를 중계하는 헬퍼이다.
 #[derive(Clone, Debug)]
 pub struct ConfirmBridge {
     /// 내부 상태를 보관한다.
@@ -17,6 +77,10 @@ struct ConfirmBridgeInner {
     next_id: AtomicU64,
     /// 대기 중인 요청과 응답 채널을 매핑한다.
     pending: Mutex<HashMap<u64, oneshot::Sender<bool>>>,
+    /// 승인을 기다리는 게이트 Step ID와 승인자 전달 채널을 매핑한다.
+    gates: Mutex<HashMap<String, oneshot::Sender<String>>>,
+    /// 실행 중인 Step ID와 개별 취소 슬롯을 매핑한다.
+    steps: Mutex<HashMap<String, StepCancelSlot>>,
 }
 
 impl ConfirmBridge {
@@ -26,6 +90,8 @@ impl ConfirmBridge {
             inner: Arc::new(ConfirmBridgeInner {
                 next_id: AtomicU64::new(1),
                 pending: Mutex::new(HashMap::new()),
+                gates: Mutex::new(HashMap::new()),
+                steps: Mutex::new(HashMap::new()),
             }),
         }
     }
@@ -66,4 +132,73 @@ impl ConfirmBridge {
             .expect("ConfirmBridge mutex poisoned")
             .remove(&request_id);
     }
+
+    /// 승인 게이트 대기를 등록하고 승인자 이름을 받을 Receiver를 반환한다.
+    pub fn register_gate(&self, step_id: &str) -> oneshot::Receiver<String> {
+        let (tx, rx) = oneshot::channel();
+        self.inner
+            .gates
+            .lock()
+            .expect("ConfirmBridge mutex poisoned")
+            .insert(step_id.to_string(), tx);
+        rx
+    }
+
+    /// 대기 중인 게이트를 승인한다. 대기 중인 게이트가 없으면 `false`를 반환한다.
+    pub fn approve_gate(&self, step_id: &str, approver: &str) -> bool {
+        if let Some(sender) = self
+            .inner
+            .gates
+            .lock()
+            .expect("ConfirmBridge mutex poisoned")
+            .remove(step_id)
+        {
+            sender.send(approver.to_string()).is_ok()
+        } else {
+            false
+        }
+    }
+
+    /// 대기 중인 게이트를 취소하고 맵에서 제거한다.
+    pub fn cancel_gate(&self, step_id: &str) {
+        self.inner
+            .gates
+            .lock()
+            .expect("ConfirmBridge mutex poisoned")
+            .remove(step_id);
+    }
+
+    /// 실행을 시작한 Step의 개별 취소 토큰을 등록한다.
+    pub fn register_step(&self, step_id: &str, token: CancellationToken) {
+        self.inner
+            .steps
+            .lock()
+            .expect("ConfirmBridge mutex poisoned")
+            .insert(step_id.to_string(), StepCancelSlot { token, mode: None });
+    }
+
+    /// 실행 중인 Step 하나만 취소한다. 실행 중인 Step이 아니면 `false`를 반환한다.
+    pub fn cancel_step(&self, step_id: &str, mode: StepCancelMode) -> bool {
+        let mut steps = self
+            .inner
+            .steps
+            .lock()
+            .expect("ConfirmBridge mutex poisoned");
+        let Some(slot) = steps.get_mut(step_id) else {
+            return false;
+        };
+        slot.mode = Some(mode);
+        slot.token.cancel();
+        true
+    }
+
+    /// 끝난 Step의 등록을 지우고 실행 중에 요청된 취소 처리 방식을 반환한다.
+    pub fn finish_step(&self, step_id: &str) -> Option<StepCancelMode> {
+        self.inner
+            .steps
+            .lock()
+            .expect("ConfirmBridge mutex poisoned")
+            .remove(step_id)
+            .and_then(|slot| slot.mode)
+    }
 }
@@ -1,13 +1,77 @@
+use super::confirm_bridge::ConfirmMode;
+use super::error::EngineError;
+use super::rehearsal::RehearsalPlan;
+use super::steps::StepMemo;
+use crate::i18n::tf;
+use crate::scenario::{FailureDiagnostic, STEP_TMPDIR_VAR, StepLogLevel};
 use anyhow::Context;
+use chrono::{DateTime, Local};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// `${VAR}` 플레이스홀더 패턴이다. Step 결과 변수 `${STEP.<id>.<field>}`와 DB 연결 정보 변수
+/// `${DB.<키>.<field>}`도 함께 인식한다.
+static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\$\{([A-Z0-9_]+|(?:STEP|DB)\.[A-Za-z0-9_-]+\.[a-z_]+)\}")
+        .expect("정규식 컴파일 실패")
+});
+
+tokio::task_local! {
+    /// 실행 중인 Step의 작업 디렉터리(`${STEP_TMPDIR}`).
+    ///
+    /// 병렬 Step이 같은 컨텍스트를 나눠 쓰므로 공유 변수에 두면 서로 덮어쓴다. Step 실행 future마다
+    /// 따로 값을 두어 그 Step 안에서 치환할 때만 보이게 한다.
+    pub(super) static STEP_TMPDIR: String;
+
+    /// 실행 중인 Step ID. 변수 변경 기록에 어느 Step이 값을 설정했는지 남기는 데 쓴다.
+    pub(super) static CURRENT_STEP: String;
+
+    /// 실행 중인 Step의 엔진 로그 최소 수준. Loop 하위 Step은 자기 값이 없으면 Loop의 값을 물려받는다.
+    pub(super) static STEP_LOG_LEVEL: StepLogLevel;
+}
+
+/// Step별 변수 변경 기록에 남기는 최대 항목 수이다. 넘으면 오래된 항목부터 버린다.
+const VAR_CHANGE_LIMIT: usize = 200;
+
+/// Step이 실행 중에 설정한 변수 하나의 기록이다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VarChange {
+    /// 변수명.
+    pub name: String,
+    /// 설정한 값. 비밀 변수는 [`SECRET_MASK`]로 가린다.
+    pub value: String,
+    /// 설정한 로컬 시각(`HH:MM:SS.mmm`).
+    pub at: String,
+}
+
+/// 비밀 변수 값 대신 표시하는 문자열이다.
+pub const SECRET_MASK: &str = "****";
 
 /// 실행 중 Step 간 값을 공유하기 위한 컨텍스트이다.
 #[derive(Debug, Default)]
 pub struct ExecutionContext {
     /// 문자열 기반 변수 저장소이다.
     vars: HashMap<String, String>,
+    /// 값을 로그와 화면에서 가려야 하는 변수 이름이다.
+    secrets: HashSet<String>,
+    /// 리허설 실행이면 Step에 주입할 장애 계획이다.
+    rehearsal: Option<Arc<RehearsalPlan>>,
+    /// 컨펌 Step에 응답하는 방식이다.
+    confirm_mode: ConfirmMode,
+    /// SQL 문장 실행이 이 시간을 넘기면 실행 계획을 남긴다.
+    explain_slow_sql: Option<Duration>,
+    /// Step이 실패했을 때 실행할 진단 명령이다.
+    failure_diagnostics: Arc<[FailureDiagnostic]>,
+    /// `memoize` Step의 실행 결과를 구성 해시별로 보관하는 저장소이다.
+    step_memo: StepMemo,
+    /// Step ID별로 그 Step이 실행 중에 설정한 변수 기록이다.
+    var_changes: HashMap<String, Vec<VarChange>>,
+    /// 실행을 시작한 시각이다. Step 시간 창이 어느 날의 창인지 정하는 기준이다.
+    run_started_at: DateTime<Local>,
 }
 
 impl ExecutionContext {
@@ -15,16 +79,154 @@ impl ExecutionContext {
     pub fn new() -> Self {
         Self {
             vars: HashMap::new(),
+            secrets: HashSet::new(),
+            rehearsal: None,
+            confirm_mode: ConfirmMode::Prompt,
+            explain_slow_sql: None,
+            failure_diagnostics: Arc::from([]),
+            step_memo: StepMemo::default(),
+            var_changes: HashMap::new(),
+            run_started_at: Local::now(),
+        }
+    }
+
+    /// 변수를 비밀로 표시한다. 값이 나중에 설정되어도 가린다.
+    pub fn mark_secret(&mut self, key: impl Into<String>) {
+        self.secrets.insert(key.into());
+    }
+
+    /// 문자열 안의 비밀 변수 값을 [`SECRET_MASK`]로 바꾼다.
+    ///
+    /// 한 값이 다른 값의 일부여도 모두 가리도록 긴 값부터 바꾼다.
+    pub fn mask(&self, text: &str) -> String {
+        let mut values: Vec<&str> = self
+            .secrets
+            .iter()
+            .filter_map(|key| self.get_var(key))
+            .filter(|value| !value.is_empty())
+            .collect();
+        values.sort_by_key(|value| std::cmp::Reverse(value.len()));
+        let mut masked = text.to_string();
+        for value in values {
+            if masked.contains(value) {
+                masked = masked.replace(value, SECRET_MASK);
+            }
         }
+        masked
+    }
+
+    /// 리허설 실행 계획을 설정한다.
+    pub fn set_rehearsal(&mut self, plan: RehearsalPlan) {
+        self.rehearsal = Some(Arc::new(plan));
+    }
+
+    /// 리허설 실행이면 실행 계획을 반환한다.
+    pub fn rehearsal(&self) -> Option<Arc<RehearsalPlan>> {
+        self.rehearsal.clone()
+    }
+
+    /// 컨펌 응답 방식을 설정한다.
+    pub fn set_confirm_mode(&mut self, mode: ConfirmMode) {
+        self.confirm_mode = mode;
+    }
+
+    /// 컨펌 응답 방식을 반환한다.
+    pub fn confirm_mode(&self) -> ConfirmMode {
+        self.confirm_mode
+    }
+
+    /// 실행을 시작한 시각을 반환한다. 따로 정하지 않으면 컨텍스트를 만든 시각이다.
+    pub fn run_started_at(&self) -> DateTime<Local> {
+        self.run_started_at
+    }
+
+    /// 실행 계획을 남길 느린 SQL 기준 시간을 설정한다.
+    pub fn set_explain_slow_sql(&mut self, threshold: Option<Duration>) {
+        self.explain_slow_sql = threshold;
+    }
+
+    /// 실행 계획을 남길 느린 SQL 기준 시간을 반환한다.
+    pub fn explain_slow_sql(&self) -> Option<Duration> {
+        self.explain_slow_sql
+    }
+
+    /// Step 실패 시 실행할 진단 명령을 설정한다.
+    pub fn set_failure_diagnostics(&mut self, diagnostics: &[FailureDiagnostic]) {
+        self.failure_diagnostics = Arc::from(diagnostics);
+    }
+
+    /// Step 실패 시 실행할 진단 명령을 반환한다.
+    pub fn failure_diagnostics(&self) -> Arc<[FailureDiagnostic]> {
+        self.failure_diagnostics.clone()
+    }
+
+    /// 이 실행의 Step 메모 저장소를 반환한다.
+    pub(super) fn step_memo(&self) -> StepMemo {
+        self.step_memo.clone()
     }
 
     /// 컨텍스트 변수 값을 설정한다.
     ///
+    /// Step 실행 중이면 `STEP.<id>.<field>` 결과 변수를 뺀 변경을 그 Step의 변수 변경 기록에 남긴다.
+    ///
     /// # 매개변수
     /// - `key`: 저장할 변수명.
     /// - `value`: 저장할 문자열 값.
     pub fn set_var(&mut self, key: impl Into<String>, value: impl Into<String>) {
-        self.vars.insert(key.into(), value.into());
+        let key = key.into();
+        let value = value.into();
+        if !key.starts_with("STEP.")
+            && let Ok(step_id) = CURRENT_STEP.try_with(Clone::clone)
+        {
+            let log = self.var_changes.entry(step_id).or_default();
+            if log.len() >= VAR_CHANGE_LIMIT {
+                log.remove(0);
+            }
+            log.push(VarChange {
+                name: key.clone(),
+                value: value.clone(),
+                at: chrono::Local::now().format("%H:%M:%S%.3f").to_string(),
+            });
+        }
+        self.vars.insert(key, value);
+    }
+
+    /// Step이 실행 중에 설정한 변수를 설정한 순서대로 반환한다. 비밀 변수 값은 [`SECRET_MASK`]로 가린다.
+    pub fn step_var_changes(&self, step_id: &str) -> Vec<VarChange> {
+        let Some(log) = self.var_changes.get(step_id) else {
+            return Vec::new();
+        };
+        log.iter()
+            .map(|change| {
+                let mut change = change.clone();
+                if self.secrets.contains(&change.name) {
+                    change.value = SECRET_MASK.to_string();
+                }
+                change
+            })
+            .collect()
+    }
+
+    /// Step 실행 결과 변수 `STEP.<step_id>.<field>`를 저장한다.
+    ///
+    /// # 매개변수
+    /// - `step_id`: 결과를 남길 Step ID.
+    /// - `field`: `status`, `duration_sec`, `exit_code`, `rows_affected` 중 하나.
+    /// - `value`: 저장할 값.
+    pub fn set_step_result(&mut self, step_id: &str, field: &str, value: impl ToString) {
+        self.set_var(format!("STEP.{step_id}.{field}"), value.to_string());
+    }
+
+    /// Step이 남긴 `STEP.<step_id>.<field>` 결과를 `(field, 값)` 목록으로 반환한다.
+    pub(super) fn step_results(&self, step_id: &str) -> Vec<(String, String)> {
+        let prefix = format!("STEP.{step_id}.");
+        self.vars
+            .iter()
+            .filter_map(|(key, value)| {
+                let field = key.strip_prefix(&prefix)?;
+                Some((field.to_string(), value.clone()))
+            })
+            .collect()
     }
 
     /// 변수 값을 조회한다.
@@ -38,6 +240,27 @@ impl ExecutionContext {
         self.vars.get(key).map(|s| s.as_str())
     }
 
+    /// 현재 저장된 모든 변수를 변수명 순으로 복사한다. 비밀 변수 값은 [`SECRET_MASK`]로 가린다.
+    ///
+    /// # 반환값
+    /// 실패 분석용으로 보관할 `(변수명, 값)` 목록을 반환한다.
+    pub fn snapshot(&self) -> ContextSnapshot {
+        let mut vars: ContextSnapshot = self
+            .vars
+            .iter()
+            .map(|(key, value)| {
+                let value = if self.secrets.contains(key) {
+                    SECRET_MASK.to_string()
+                } else {
+                    value.clone()
+                };
+                (key.clone(), value)
+            })
+            .collect();
+        vars.sort();
+        vars
+    }
+
     /// 컨텍스트 또는 환경 변수에서 값을 조회한다.
     ///
     /// # 매개변수
@@ -46,6 +269,9 @@ impl ExecutionContext {
     /// # 반환값
     /// 우선 컨텍스트에서 찾고 없으면 환경 변수에서 조회한 값을 반환한다.
     pub fn get_or_env(&self, key: &str) -> Option<String> {
+        if let Some(value) = step_local_var(key) {
+            return Some(value);
+        }
         if let Some(value) = self.get_var(key) {
             return Some(value.to_string());
         }
@@ -60,31 +286,65 @@ impl ExecutionContext {
     /// # 반환값
     /// 성공 시 치환 결과 문자열을 반환한다.
     pub fn expand_placeholders(&self, template: &str) -> anyhow::Result<String> {
-        static PLACEHOLDER: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"\$\{([A-Z0-9_]+)\}").expect("정규식 컴파일 실패"));
-        let result = PLACEHOLDER.replace_all(template, |caps: &regex::Captures| {
-            let key = &caps[1];
-            if let Some(val) = self.get_var(key) {
-                return val.to_string();
-            }
-            if let Ok(env_val) = std::env::var(key) {
-                return env_val;
-            }
-            format!("${{{key}}}")
-        });
-        let result = result.to_string();
+        let result = self.expand_lenient(template);
         if PLACEHOLDER.is_match(&result) {
-            anyhow::bail!("플레이스홀더 치환 실패: {result}");
+            return Err(EngineError::ConfigError(tf(
+                "engine.error.placeholder",
+                &[("text", &result)],
+            ))
+            .into());
         }
         Ok(result)
     }
 
+    /// `${VAR}` 패턴을 치환하되 값을 찾지 못한 플레이스홀더는 그대로 남긴다.
+    ///
+    /// 실행 전 미리보기처럼 일부 변수가 아직 없을 수 있는 곳에서 쓴다.
+    pub fn expand_lenient(&self, template: &str) -> String {
+        PLACEHOLDER
+            .replace_all(template, |caps: &regex::Captures| {
+                let key = &caps[1];
+                if let Some(val) = step_local_var(key) {
+                    return val;
+                }
+                if let Some(val) = self.get_var(key) {
+                    return val.to_string();
+                }
+                if let Ok(env_val) = std::env::var(key) {
+                    return env_val;
+                }
+                format!("${{{key}}}")
+            })
+            .to_string()
+    }
+
     /// `template` 문자열을 치환하되 값이 없을 경우 명시적인 오류를 발생시킨다.
     pub fn expand_required(&self, template: &str, field: &str) -> anyhow::Result<String> {
         self.expand_placeholders(template)
-            .with_context(|| format!("{field} 필드의 플레이스홀더를 치환할 수 없습니다."))
+            .with_context(|| tf("engine.error.placeholder_field", &[("field", &field)]))
+    }
+}
+
+/// 실행 중인 Step에서만 보이는 변수 값을 조회한다. Step 밖이거나 해당 변수가 아니면 `None`이다.
+fn step_local_var(key: &str) -> Option<String> {
+    if key != STEP_TMPDIR_VAR {
+        return None;
     }
+    STEP_TMPDIR.try_with(Clone::clone).ok()
+}
+
+/// 문자열에 나타난 플레이스홀더 변수명을 처음 나타난 순서대로 중복 없이 반환한다.
+pub fn placeholder_names(template: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    PLACEHOLDER
+        .captures_iter(template)
+        .map(|caps| caps[1].to_string())
+        .filter(|name| seen.insert(name.clone()))
+        .collect()
 }
 
+/// 특정 시점의 컨텍스트 변수 사본이다. 변수명 순으로 정렬된 `(변수명, 값)` 목록이다.
+pub type ContextSnapshot = Vec<(String, String)>;
+
 /// ExecutionContext를 비동기 환경에서 공유하기 위한 타입 별칭이다.
 pub type SharedExecutionContext = std::sync::Arc<tokio::sync::RwLock<ExecutionContext>>;
@@ -0,0 +1,67 @@
+use super::context::{SECRET_MASK, SharedExecutionContext};
+use crate::i18n::tf;
+use crate::scenario::{DB_VAR_PREFIX, RUN_TMPDIR_VAR};
+use anyhow::Context;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// 실행이 끝난 시점의 컨텍스트 변수를 JSON 객체(`{"변수명": "값"}`)로 저장한다.
+///
+/// 다음 실행의 시작 값으로 쓰기 위한 파일이므로 비밀 변수, 실행이 끝나면 지워지는 `RUN_TMPDIR`, 실행마다
+/// 다시 만드는 `DB.<키>.<필드>` 변수는 넣지 않는다.
+pub(super) async fn write_context_file(
+    path: &Path,
+    ctx: &SharedExecutionContext,
+) -> anyhow::Result<()> {
+    let vars: BTreeMap<String, String> = ctx
+        .read()
+        .await
+        .snapshot()
+        .into_iter()
+        .filter(|(key, value)| {
+            value != SECRET_MASK && key != RUN_TMPDIR_VAR && !key.starts_with(DB_VAR_PREFIX)
+        })
+        .collect();
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&vars)?;
+    std::fs::write(path, json).with_context(|| {
+        tf(
+            "engine.error.context_file_write",
+            &[("path", &path.display())],
+        )
+    })
+}
+
+/// 이전 실행이 남긴 컨텍스트 파일을 읽어 시나리오 파라미터로 넣을 변수 목록을 반환한다.
+///
+/// 손으로 쓴 파일도 받을 수 있도록 문자열이 아닌 값(숫자, 불리언 등)은 JSON 표기 그대로 문자열로 바꾼다.
+pub fn load_context_file(path: &Path) -> anyhow::Result<BTreeMap<String, String>> {
+    let content = std::fs::read_to_string(path).with_context(|| {
+        tf(
+            "engine.error.context_file_read",
+            &[("path", &path.display())],
+        )
+    })?;
+    let values: BTreeMap<String, serde_json::Value> =
+        serde_json::from_str(&content).with_context(|| {
+            tf(
+                "engine.error.context_file_format",
+                &[("path", &path.display())],
+            )
+        })?;
+    Ok(values
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(text) => text,
+                other => other.to_string(),
+            };
+            (key, value)
+        })
+        .collect())
+}
@@ -0,0 +1,122 @@
+use super::context::SharedExecutionContext;
+use super::resources::EngineHandles;
+use super::shell_command::{shell_invocation, truncate_output};
+use crate::i18n::{t, tf};
+use crate::scenario::FailureDiagnostic;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::process::Command;
+
+/// 진단 명령 하나에 허용하는 최대 실행 시간이다.
+const DIAGNOSTIC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 진단 결과 하나에 남기는 최대 출력 바이트 수이다. 넘는 부분은 잘라 낸다.
+const DIAGNOSTIC_OUTPUT_MAX_BYTES: usize = 8 * 1024;
+
+/// Step 실패 시 실행한 진단 명령 하나의 결과이다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticResult {
+    /// 진단 항목 이름.
+    pub label: String,
+    /// 명령 출력이나 조회 값. 출력이 없거나 값이 NULL이면 `None`이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// 진단 명령이 실패하면 그 오류 메시지.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 실패한 Step의 진단 명령을 순서대로 실행해 결과를 반환한다.
+///
+/// 진단 하나가 실패하거나 제한 시간을 넘겨도 나머지는 계속 실행하며, 실패는 그 항목의 오류로만
+/// 남긴다. 진단은 원래 실패를 바꾸지 않는다.
+pub(super) async fn run_failure_diagnostics(
+    diagnostics: &[FailureDiagnostic],
+    handles: &EngineHandles,
+    ctx: &SharedExecutionContext,
+) -> Vec<DiagnosticResult> {
+    let mut results = Vec::with_capacity(diagnostics.len());
+    for diagnostic in diagnostics {
+        let outcome = match tokio::time::timeout(
+            DIAGNOSTIC_TIMEOUT,
+            run_diagnostic(diagnostic, handles, ctx),
+        )
+        .await
+        {
+            Ok(outcome) => outcome,
+            Err(_) => Err(anyhow::anyhow!(tf(
+                "engine.error.diagnostic_timeout",
+                &[("secs", &DIAGNOSTIC_TIMEOUT.as_secs())]
+            ))),
+        };
+        let (output, error) = match outcome {
+            Ok(output) => (
+                output.map(|text| truncate_output(&text, DIAGNOSTIC_OUTPUT_MAX_BYTES)),
+                None,
+            ),
+            Err(err) => (None, Some(format!("{err:#}"))),
+        };
+        results.push(DiagnosticResult {
+            label: diagnostic.label.clone(),
+            output,
+            error,
+        });
+    }
+    results
+}
+
+/// 진단 명령 하나를 치환해 실행한다.
+async fn run_diagnostic(
+    diagnostic: &FailureDiagnostic,
+    handles: &EngineHandles,
+    ctx: &SharedExecutionContext,
+) -> anyhow::Result<Option<String>> {
+    match (&diagnostic.shell, &diagnostic.sql) {
+        (Some(command), None) => {
+            let command = ctx
+                .read()
+                .await
+                .expand_required(command, "failure_diagnostics.shell")?;
+            run_shell(&command).await
+        }
+        (None, Some(sql)) => {
+            let sql = ctx
+                .read()
+                .await
+                .expand_required(sql, "failure_diagnostics.sql")?;
+            let executor =
+                handles.get_db_executor(diagnostic.target_db.as_deref().unwrap_or("default"))?;
+            executor.query_scalar(&sql).await
+        }
+        _ => anyhow::bail!(t("engine.error.diagnostic_kind")),
+    }
+}
+
+/// 로컬 셸에서 명령을 실행하고 표준 출력과 표준 오류를 합쳐 반환한다. 종료 코드가 0이 아니어도
+/// 출력은 그대로 남긴다.
+async fn run_shell(command: &str) -> anyhow::Result<Option<String>> {
+    let (program, script_flag) = shell_invocation(None, false);
+    let output = Command::new(program)
+        .arg(script_flag)
+        .arg(command)
+        .kill_on_drop(true)
+        .output()
+        .await?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.trim().is_empty() {
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(&stderr);
+    }
+    if !output.status.success() {
+        text.push('\n');
+        text.push_str(&tf(
+            "engine.diagnostic.exit_code",
+            &[("code", &output.status.code().unwrap_or(-1))],
+        ));
+    }
+    let text = text.trim_end().to_string();
+    Ok((!text.is_empty()).then_some(text))
+}
@@ -0,0 +1,231 @@
+use crate::i18n::{t, tf};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Step 실패 원인을 분류한 엔진 오류 모델이다.
+///
+/// 재시도 필터, 알림 라우팅, UI 표시는 문자열 대신 이 분류를 기준으로 분기한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EngineError {
+    /// DB 실행기가 반환한 오류이다. Oracle은 `ORA-xxxxx`, PostgreSQL은 SQLSTATE 코드를 담는다.
+    DbError {
+        /// 벤더 오류 코드이다.
+        code: Option<String>,
+        /// 벤더가 반환한 오류 메시지이다. 추출하지 못하면 전체 오류 문자열이다.
+        message: String,
+    },
+    /// Step 제한 시간을 초과했다.
+    Timeout,
+    /// 사용자 또는 상위 흐름에 의해 중단되었다.
+    Cancelled,
+    /// 외부 프로세스가 비정상 종료했다.
+    ProcessExit {
+        /// 프로세스 종료 코드이다. 시그널로 종료되면 `None`이다.
+        code: Option<i32>,
+        /// 사용자에게 보여줄 메시지이다.
+        message: String,
+    },
+    /// 입력 파일이나 실행 파일을 찾지 못했다.
+    FileNotFound(String),
+    /// Step 시작 허용 시간 창 밖이다.
+    OutsideTimeWindow(String),
+    /// 시나리오 설정 또는 플레이스홀더 치환이 잘못되었다.
+    ConfigError(String),
+    /// 위 분류에 속하지 않는 오류이다.
+    Other(String),
+}
+
+impl fmt::Display for EngineError {
+    /// 현재 언어로 오류 설명을 출력한다.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EngineError::DbError { code, message } => {
+                let code = code
+                    .as_deref()
+                    .map(|code| format!(" [{code}]"))
+                    .unwrap_or_default();
+                let text = tf("engine.error.db", &[("code", &code), ("message", message)]);
+                f.write_str(&text)
+            }
+            EngineError::Timeout => f.write_str(t("engine.error.timeout")),
+            EngineError::Cancelled => f.write_str(t("engine.error.cancelled")),
+            EngineError::ProcessExit { message, .. } => f.write_str(message),
+            EngineError::FileNotFound(path) => {
+                f.write_str(&tf("engine.error.file_not_found", &[("path", path)]))
+            }
+            EngineError::OutsideTimeWindow(reason) => {
+                f.write_str(&tf("engine.error.outside_window", &[("reason", reason)]))
+            }
+            EngineError::ConfigError(reason) => {
+                f.write_str(&tf("engine.error.config", &[("reason", reason)]))
+            }
+            EngineError::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for EngineError {}
+
+/// 다시 시도하면 성공할 수 있는 Oracle 오류 코드 목록이다.
+///
+/// 접속 시간 초과·리스너 연결 실패·세션 끊김(TNS/ORA-031xx)과 교착 상태, 잠금 대기 실패가 해당한다.
+pub const TRANSIENT_ORACLE_CODES: &[&str] = &[
+    "ORA-00051", // 리소스 대기 시간 초과
+    "ORA-00054", // 리소스 사용 중(NOWAIT)
+    "ORA-00060", // 교착 상태
+    "ORA-01033", // 기동/종료 진행 중
+    "ORA-01089", // 즉시 종료 진행 중
+    "ORA-03113", // 통신 채널 EOF
+    "ORA-03114", // DB 연결 끊김
+    "ORA-03135", // 연결 응답 없음
+    "ORA-12170", // 접속 시간 초과
+    "ORA-12500", // 리스너가 전용 서버 프로세스를 시작하지 못함
+    "ORA-12516", // 리스너 핸들러 부족
+    "ORA-12519", // 리스너 핸들러 부족
+    "ORA-12520", // 리스너 핸들러 부족
+    "ORA-12535", // 작업 시간 초과
+    "ORA-12537", // 연결 닫힘
+    "ORA-12541", // 리스너 없음
+    "ORA-12547", // 연결 유실
+    "ORA-12571", // 패킷 쓰기 실패
+];
+
+/// 다시 시도하면 성공할 수 있는 PostgreSQL SQLSTATE 코드 목록이다.
+///
+/// `08` 연결 예외 클래스 전체와 직렬화 실패, 교착 상태, 잠금 획득 실패, 서버 종료·기동 중 상태가 해당한다.
+pub const TRANSIENT_SQLSTATES: &[&str] = &[
+    "40001", // serialization_failure
+    "40P01", // deadlock_detected
+    "53300", // too_many_connections
+    "55P03", // lock_not_available
+    "57P01", // admin_shutdown
+    "57P02", // crash_shutdown
+    "57P03", // cannot_connect_now
+];
+
+/// 오류 코드가 없을 때 일시적 오류로 판단하는 메시지 조각 목록이다(소문자 비교).
+const TRANSIENT_MESSAGE_PATTERNS: &[&str] = &[
+    "connection reset",
+    "connection refused",
+    "connection closed",
+    "broken pipe",
+    "timed out",
+    "deadlock",
+];
+
+impl EngineError {
+    /// anyhow 오류 체인을 살펴 가장 구체적인 분류로 변환한다.
+    ///
+    /// # 매개변수
+    /// - `err`: Step 실행 중 발생한 오류.
+    ///
+    /// # 반환값
+    /// 체인 안에 이미 [`EngineError`]가 있으면 그 값을, 없으면 원인 타입과 메시지로
+    /// 추론한 분류를 반환한다.
+    pub fn classify(err: &anyhow::Error) -> Self {
+        let message = format!("{err:#}");
+        for cause in err.chain() {
+            if let Some(engine_err) = cause.downcast_ref::<EngineError>() {
+                return engine_err.clone();
+            }
+            if let Some(pg_err) = cause.downcast_ref::<tokio_postgres::Error>() {
+                return match pg_err.as_db_error() {
+                    Some(db_err) => EngineError::DbError {
+                        code: Some(db_err.code().code().to_string()),
+                        message: db_err.message().to_string(),
+                    },
+                    None => EngineError::DbError {
+                        code: pg_err.code().map(|state| state.code().to_string()),
+                        message,
+                    },
+                };
+            }
+            if cause
+                .downcast_ref::<tokio::time::error::Elapsed>()
+                .is_some()
+            {
+                return EngineError::Timeout;
+            }
+            if cause
+                .downcast_ref::<std::io::Error>()
+                .is_some_and(|io_err| io_err.kind() == std::io::ErrorKind::NotFound)
+            {
+                return EngineError::FileNotFound(message);
+            }
+        }
+        if let Some((code, vendor_message)) = oracle_error(&message) {
+            return EngineError::DbError {
+                code: Some(code),
+                message: vendor_message,
+            };
+        }
+        EngineError::Other(message)
+    }
+
+    /// 같은 Step을 다시 실행하면 성공할 가능성이 있는 일시적 오류인지 판단한다.
+    ///
+    /// DB 오류는 [`TRANSIENT_ORACLE_CODES`], [`TRANSIENT_SQLSTATES`](`08` 클래스 포함)에 있는 코드만,
+    /// 코드가 없으면 연결 끊김·교착 상태를 나타내는 메시지만 일시적으로 본다. 시간 초과는 일시적이며,
+    /// 파일·설정·시간 창 오류와 취소는 다시 실행해도 같으므로 일시적이지 않다. 원인을 알 수 없는
+    /// 프로세스 종료와 기타 오류는 네트워크 문제일 수 있으므로 일시적으로 본다.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            EngineError::DbError {
+                code: Some(code), ..
+            } => {
+                TRANSIENT_ORACLE_CODES.contains(&code.as_str())
+                    || TRANSIENT_SQLSTATES.contains(&code.as_str())
+                    || code.starts_with("08")
+            }
+            EngineError::DbError {
+                code: None,
+                message,
+            } => {
+                let message = message.to_lowercase();
+                TRANSIENT_MESSAGE_PATTERNS
+                    .iter()
+                    .any(|pattern| message.contains(pattern))
+            }
+            EngineError::Timeout | EngineError::ProcessExit { .. } | EngineError::Other(_) => true,
+            EngineError::Cancelled
+            | EngineError::FileNotFound(_)
+            | EngineError::OutsideTimeWindow(_)
+            | EngineError::ConfigError(_) => false,
+        }
+    }
+
+    /// 메시지 문자열에 `mask`를 적용한 오류를 반환한다. 분류와 오류 코드는 그대로 둔다.
+    pub fn masked(self, mask: impl Fn(&str) -> String) -> Self {
+        match self {
+            EngineError::DbError { code, message } => EngineError::DbError {
+                code,
+                message: mask(&message),
+            },
+            EngineError::ProcessExit { code, message } => EngineError::ProcessExit {
+                code,
+                message: mask(&message),
+            },
+            EngineError::FileNotFound(path) => EngineError::FileNotFound(mask(&path)),
+            EngineError::OutsideTimeWindow(reason) => EngineError::OutsideTimeWindow(mask(&reason)),
+            EngineError::ConfigError(reason) => EngineError::ConfigError(mask(&reason)),
+            EngineError::Other(message) => EngineError::Other(mask(&message)),
+            other => other,
+        }
+    }
+}
+
+/// 텍스트에서 첫 번째 `ORA-xxxxx` 코드와 해당 줄의 메시지를 찾는다.
+fn oracle_error(text: &str) -> Option<(String, String)> {
+    static ORA_LINE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(ORA-\d{5})(?::\s*([^\r\n]*))?").expect("정규식 컴파일 실패"));
+    let caps = ORA_LINE.captures(text)?;
+    let code = caps[1].to_string();
+    let message = caps
+        .get(2)
+        .map(|m| m.as_str().trim().to_string())
+        .filter(|m| !m.is_empty())
+        .unwrap_or_else(|| text.to_string());
+    Some((code, message))
+}
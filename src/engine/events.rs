@@ -1,7 +1,14 @@
+use super::context::{ContextSnapshot, VarChange};
+use super::diagnostics::DiagnosticResult;
+use super::error::EngineError;
+use super::summary::SummaryResult;
+use crate::executor::PoolStats;
 use crate::scenario::ConfirmDefault;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// 컨펌 요청이 어느 시점인지 나타내는 값이다.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConfirmPhase {
     /// 실행 전 확인 단계이다.
     Before,
@@ -9,15 +16,115 @@ pub enum ConfirmPhase {
     After,
 }
 
+/// Step이 남겨야 하는 산출물(`expects_outputs`) 하나의 확인 결과이다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedOutput {
+    /// 실행 컨텍스트로 치환한 경로이다.
+    pub path: String,
+    /// 파일이 있으면 그 크기(바이트)이다. 없거나 일반 파일이 아니면 `None`이다.
+    pub size: Option<u64>,
+}
+
+impl ExpectedOutput {
+    /// 파일이 있고 비어 있지 않은지 확인한다.
+    pub fn ok(&self) -> bool {
+        self.size.is_some_and(|size| size > 0)
+    }
+}
+
+/// DB 키 하나의 연결 풀 사용 현황이다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbPoolUsage {
+    /// 시나리오의 DB 키이다(`default` 또는 `databases`의 이름).
+    pub db_key: String,
+    /// 연결 풀 사용 현황이다.
+    pub stats: PoolStats,
+}
+
 /// 엔진에서 UI로 전달되는 주요 이벤트 모델이다.
-#[derive(Debug, Clone)]
+///
+/// 데몬 모드에서는 그대로 직렬화되어 소켓으로 전달된다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum EngineEvent {
+    /// 실행이 시작되었다. 실행 이력 레코드와 실행 메모를 잇는 실행 ID를 알린다. 리허설에서는
+    /// 이력을 남기지 않으므로 전달하지 않는다.
+    RunStarted {
+        /// 실행 ID이다.
+        run_id: String,
+    },
     /// Step 시작 알림이다.
     StepStarted { step_id: String },
+    /// Step이 시간 창이 열리기를 기다리는 중이다. 대기가 끝나면 `StepStarted`가 다시 전달된다.
+    StepWaiting {
+        /// 대상 Step ID이다.
+        step_id: String,
+        /// 실행을 재개할 로컬 시각(`HH:MM:SS`)이다.
+        until: String,
+    },
+    /// Step 실행이 실패해 재시도를 기다리는 중이다. 대기가 끝나면 같은 Step이 다시 실행된다.
+    StepRetrying {
+        /// 대상 Step ID이다.
+        step_id: String,
+        /// 이번 재시도 순번(1부터 시작)이다.
+        attempt: u8,
+        /// 허용된 최대 재시도 횟수이다.
+        max: u8,
+        /// 다음 시도까지 남은 대기 시간이다.
+        next_delay: Duration,
+    },
+    /// `on_timeout: warn_continue` Step이 제한 시간을 넘겼지만 중단하지 않고 계속 기다린다.
+    /// 제한 시간이 다시 지날 때마다 전달된다.
+    StepTimeoutWarning {
+        /// 대상 Step ID이다.
+        step_id: String,
+        /// 이번 시도를 시작한 뒤 지난 시간이다.
+        elapsed: Duration,
+    },
     /// Step별 로그 라인이다.
     StepLog { step_id: String, line: String },
+    /// 선행 Step 실패로 실행하지 않고 건너뛴 Step이다.
+    StepBlocked { step_id: String },
+    /// Loop Step의 반복 진행 상황이다. 반복 대상이 정해지면 `done: 0`으로 먼저 전달된다.
+    LoopProgress {
+        /// 대상 Loop Step ID이다.
+        step_id: String,
+        /// 끝난 반복 수이다.
+        done: usize,
+        /// 전체 반복 수이다.
+        total: usize,
+    },
     /// Step 종료 알림이다.
-    StepFinished { step_id: String, success: bool },
+    StepFinished {
+        /// 대상 Step ID이다.
+        step_id: String,
+        /// 성공 여부이다.
+        success: bool,
+        /// 실패한 경우 분류된 오류이다.
+        error: Option<EngineError>,
+        /// 실패한 경우 실패 시점의 컨텍스트 변수 사본이다.
+        context: Option<ContextSnapshot>,
+    },
+    /// Step이 성공한 뒤 확인한 기대 산출물 목록이다. 해당 `StepFinished`보다 먼저 전달된다.
+    StepOutputs {
+        /// 대상 Step ID이다.
+        step_id: String,
+        /// `expects_outputs` 순서대로의 확인 결과이다.
+        outputs: Vec<ExpectedOutput>,
+    },
+    /// Step이 실행 중에 설정한 변수 목록이다. 설정한 변수가 있으면 해당 `StepFinished`보다 먼저 전달된다.
+    StepVariables {
+        /// 대상 Step ID이다.
+        step_id: String,
+        /// 설정한 순서대로의 변수 기록이다.
+        vars: Vec<VarChange>,
+    },
+    /// 실패한 Step에 대해 실행한 진단 명령의 결과이다. 해당 `StepFinished`보다 먼저 전달된다.
+    StepDiagnostics {
+        /// 대상 Step ID이다.
+        step_id: String,
+        /// 시나리오에 정의한 순서대로의 진단 결과이다.
+        results: Vec<DiagnosticResult>,
+    },
     /// 컨펌을 위해 사용자 입력이 필요한 경우 발생한다.
     RequestConfirm {
         /// 컨펌 요청 ID이다.
@@ -46,6 +153,33 @@ pub enum EngineEvent {
         /// 수락 여부이다.
         accepted: bool,
     },
+    /// 수동 승인 게이트가 승인을 기다리기 시작했다. 승인되면 `StepApproved`가 전달된다.
+    StepAwaitingApproval {
+        /// 대상 게이트 Step ID이다.
+        step_id: String,
+        /// 승인 요청 안내 문구이다.
+        message: Option<String>,
+    },
+    /// 수동 승인 게이트가 승인되었다.
+    StepApproved {
+        /// 대상 게이트 Step ID이다.
+        step_id: String,
+        /// 승인한 사용자 이름이다.
+        approver: String,
+        /// 승인한 로컬 시각(`YYYY-MM-DD HH:MM:SS`)이다.
+        approved_at: String,
+    },
+    /// 메인 DAG가 끝난 뒤 실행한 결과 요약 조회의 결과이다. `ScenarioFinished`보다 먼저 전달된다.
+    RunSummary {
+        /// 시나리오에 정의한 순서대로의 조회 결과이다.
+        results: Vec<SummaryResult>,
+    },
+    /// 실행 중 DB 키별 연결 풀 사용 현황이다. 현황이 바뀔 때만 전달되며, 연결 풀을 쓰는 DB가
+    /// 없으면 전달되지 않는다.
+    DbPoolUsage {
+        /// DB 키 순으로 정렬한 사용 현황이다.
+        pools: Vec<DbPoolUsage>,
+    },
     /// 전체 시나리오 종료이다.
     ScenarioFinished,
 }
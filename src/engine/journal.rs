@@ -0,0 +1,267 @@
+use super::context::{ContextSnapshot, SharedExecutionContext};
+use super::events::EngineEvent;
+use super::run_tmpdir::sanitize;
+use crate::settings::user_data_dir;
+use serde::{Deserialize, Serialize};
+use std::fs::TryLockError;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+/// 실행 저널 디렉터리를 지정하는 환경 변수 이름이다.
+pub const JOURNAL_DIR_ENV: &str = "RUST_AIRFLOW_JOURNAL_DIR";
+
+/// 환경 변수가 없을 때 사용자 데이터 디렉터리 아래에 두는 실행 저널 디렉터리 이름이다.
+pub const DEFAULT_JOURNAL_DIR: &str = "journals";
+
+/// 실행 저널 머리에 남길 실행 정보이다.
+#[derive(Debug, Clone)]
+pub struct RunJournal {
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 재개할 때 다시 불러올 시나리오 파일 경로.
+    pub source: PathBuf,
+    /// 이전 실행에서 이미 성공해 이번 실행에서 제외한 Step ID 목록.
+    pub completed: Vec<String>,
+}
+
+/// 저널 파일에 JSON 한 줄씩 추가되는 레코드이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "record", rename_all = "snake_case")]
+enum JournalRecord {
+    /// 실행 시작. 저널의 첫 줄이다.
+    Started {
+        scenario: String,
+        source: PathBuf,
+        started_at: String,
+        completed: Vec<String>,
+    },
+    /// Step 종료와 그 시점의 컨텍스트 변수.
+    StepFinished {
+        step_id: String,
+        success: bool,
+        error: Option<String>,
+        context: ContextSnapshot,
+    },
+}
+
+/// 저널에서 읽은 Step 종료 기록이다.
+#[derive(Debug, Clone)]
+pub struct JournalStep {
+    /// Step ID.
+    pub step_id: String,
+    /// 성공 여부.
+    pub success: bool,
+    /// 실패한 경우 오류 메시지.
+    pub error: Option<String>,
+}
+
+/// 앱이 비정상 종료되어 끝나지 못한 실행을 저널에서 복원한 결과이다.
+#[derive(Debug, Clone)]
+pub struct InterruptedRun {
+    /// 이 실행의 저널 파일 경로.
+    pub journal: PathBuf,
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 시나리오 파일 경로.
+    pub source: PathBuf,
+    /// 실행 시작 로컬 시각(`YYYY-MM-DD HH:MM:SS`).
+    pub started_at: String,
+    /// 이전 실행에서 이미 성공해 이번 실행에서 제외했던 Step ID 목록.
+    pub completed: Vec<String>,
+    /// 저널에 기록된 순서대로의 Step 종료 기록.
+    pub steps: Vec<JournalStep>,
+    /// 마지막으로 기록된 컨텍스트 변수 사본.
+    pub context: ContextSnapshot,
+}
+
+impl InterruptedRun {
+    /// 성공으로 끝난 Step 수를 반환한다. 이전 실행에서 넘어온 Step도 포함한다.
+    pub fn succeeded_count(&self) -> usize {
+        self.completed.len() + self.steps.iter().filter(|step| step.success).count()
+    }
+}
+
+/// 실행 저널 디렉터리를 반환한다.
+///
+/// 실행마다 `<시나리오>-<시작 시각>-<프로세스 ID>.jsonl` 파일을 따로 만들므로 앱을 여러 개 띄워도
+/// 서로의 저널을 덮어쓰거나 지우지 않는다.
+pub fn journal_dir() -> PathBuf {
+    std::env::var(JOURNAL_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| user_data_dir().join(DEFAULT_JOURNAL_DIR))
+}
+
+/// 저널 디렉터리에 남은 저널 중 가장 최근에 시작한 끝나지 못한 실행을 읽어 온다.
+///
+/// 정상 종료한 실행은 저널을 지우므로 파일이 있다는 것 자체가 중단의 증거다. 실행 중인 앱은 저널
+/// 파일을 잠가 두므로, 잠금을 얻지 못한 저널은 다른 인스턴스가 아직 실행 중인 것으로 보고 건너뛴다.
+/// 비정상 종료 직전에 쓰다 만 마지막 줄처럼 해석할 수 없는 줄은 건너뛴다.
+pub fn load_interrupted_run() -> Option<InterruptedRun> {
+    std::fs::read_dir(journal_dir())
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+        .filter_map(|path| read_unlocked_journal(&path))
+        .max_by(|a, b| a.started_at.cmp(&b.started_at))
+}
+
+/// 다른 프로세스가 잠그고 있지 않은 저널 파일을 읽어 해석한다.
+fn read_unlocked_journal(path: &Path) -> Option<InterruptedRun> {
+    let mut file = std::fs::File::open(path).ok()?;
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => return None,
+        Err(TryLockError::Error(err)) => {
+            tracing::warn!("실행 저널 잠금 확인 실패({}): {err}", path.display());
+            return None;
+        }
+    }
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    let mut records = content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<JournalRecord>(line).ok());
+    let JournalRecord::Started {
+        scenario,
+        source,
+        started_at,
+        completed,
+    } = records.next()?
+    else {
+        return None;
+    };
+    let mut run = InterruptedRun {
+        journal: path.to_path_buf(),
+        scenario,
+        source,
+        started_at,
+        completed,
+        steps: Vec::new(),
+        context: Vec::new(),
+    };
+    for record in records {
+        if let JournalRecord::StepFinished {
+            step_id,
+            success,
+            error,
+            context,
+        } = record
+        {
+            run.steps.push(JournalStep {
+                step_id,
+                success,
+                error,
+            });
+            run.context = context;
+        }
+    }
+    Some(run)
+}
+
+/// 저널 파일을 지운다. 파일이 없으면 아무 일도 하지 않는다.
+pub fn discard_journal(path: &Path) {
+    if let Err(err) = std::fs::remove_file(path)
+        && err.kind() != std::io::ErrorKind::NotFound
+    {
+        tracing::warn!("실행 저널 삭제 실패: {err}");
+    }
+}
+
+/// 엔진 이벤트를 가로채 Step 종료를 저널에 기록한 뒤 `outer`로 전달하는 송신자를 만든다.
+///
+/// 기록을 마친 뒤에 이벤트를 넘기므로 UI에 성공으로 보인 Step은 항상 저널에도 남아 있다.
+/// 시나리오가 끝나거나 엔진이 송신자를 모두 놓으면 저널을 지운다. 프로세스가 죽으면 이 정리가
+/// 실행되지 않아 다음 시작 시 [`load_interrupted_run`]으로 복원할 수 있다.
+pub(super) fn spawn_run_journal(
+    outer: UnboundedSender<EngineEvent>,
+    journal: RunJournal,
+    ctx: SharedExecutionContext,
+) -> UnboundedSender<EngineEvent> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let (mut file, path) = match open_journal(journal).await {
+            Ok((file, path)) => (Some(file), Some(path)),
+            Err(err) => {
+                tracing::warn!("실행 저널 생성 실패: {err}");
+                (None, None)
+            }
+        };
+        while let Some(event) = rx.recv().await {
+            if let (
+                Some(writer),
+                EngineEvent::StepFinished {
+                    step_id,
+                    success,
+                    error,
+                    context,
+                },
+            ) = (file.as_mut(), &event)
+            {
+                let context = match context {
+                    Some(context) => context.clone(),
+                    None => ctx.read().await.snapshot(),
+                };
+                let record = JournalRecord::StepFinished {
+                    step_id: step_id.clone(),
+                    success: *success,
+                    error: error.as_ref().map(ToString::to_string),
+                    context,
+                };
+                if let Err(err) = write_record(writer, &record).await {
+                    tracing::warn!("실행 저널 기록 실패: {err}");
+                }
+            }
+            let finished = matches!(event, EngineEvent::ScenarioFinished);
+            let _ = outer.send(event);
+            if finished {
+                break;
+            }
+        }
+        // 잠금을 풀고 파일을 닫은 뒤에 지운다.
+        drop(file);
+        if let Some(path) = path {
+            discard_journal(&path);
+        }
+    });
+    tx
+}
+
+/// 이 실행의 저널 파일을 새로 만들어 잠그고 시작 레코드를 쓴다.
+///
+/// # 반환값
+/// 잠근 저널 파일과 그 경로.
+async fn open_journal(journal: RunJournal) -> anyhow::Result<(File, PathBuf)> {
+    let dir = journal_dir();
+    tokio::fs::create_dir_all(&dir).await?;
+    let now = chrono::Local::now();
+    let path = dir.join(format!(
+        "{}-{}-{}.jsonl",
+        sanitize(&journal.scenario),
+        now.format("%Y%m%d%H%M%S%3f"),
+        std::process::id()
+    ));
+    let std_file = std::fs::File::create(&path)?;
+    std_file.lock()?;
+    let mut file = File::from_std(std_file);
+    let record = JournalRecord::Started {
+        scenario: journal.scenario,
+        source: journal.source,
+        started_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        completed: journal.completed,
+    };
+    write_record(&mut file, &record).await?;
+    Ok((file, path))
+}
+
+/// 레코드 한 줄을 쓰고 디스크에 반영한다.
+async fn write_record(file: &mut File, record: &JournalRecord) -> anyhow::Result<()> {
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+    file.write_all(line.as_bytes()).await?;
+    file.sync_data().await?;
+    Ok(())
+}
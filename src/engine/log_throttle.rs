@@ -0,0 +1,187 @@
+use super::events::EngineEvent;
+use crate::i18n::tf;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// 시나리오에 값이 없을 때 사용하는 Step별 초당 최대 로그 라인 수이다.
+pub const DEFAULT_LOG_LINES_PER_SEC: u32 = 200;
+
+/// 로그 제한 구간 길이이다.
+const THROTTLE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Step 하나의 현재 구간 로그 집계이다.
+struct StepLogWindow {
+    /// 구간 시작 시각.
+    started_at: Instant,
+    /// 구간 안에서 전달한 라인 수.
+    forwarded: u32,
+    /// 구간 안에서 버린 라인 수.
+    suppressed: u64,
+}
+
+impl StepLogWindow {
+    /// 지금 시작하는 빈 구간을 만든다.
+    fn new(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            forwarded: 0,
+            suppressed: 0,
+        }
+    }
+}
+
+/// Step별 로그 폭주를 제한하는 중계기이다.
+struct LogThrottle {
+    /// Step별 초당 최대 로그 라인 수.
+    lines_per_sec: u32,
+    /// 실제 이벤트를 받을 송신자.
+    outer: UnboundedSender<EngineEvent>,
+    /// Step별 구간 집계.
+    windows: HashMap<String, StepLogWindow>,
+}
+
+impl LogThrottle {
+    /// 이벤트 하나를 처리한다. 로그가 아닌 이벤트는 해당 Step의 생략 알림을 먼저 내보낸 뒤 그대로 전달한다.
+    fn handle(&mut self, event: EngineEvent) {
+        let now = Instant::now();
+        match event {
+            EngineEvent::StepLog { step_id, line } => {
+                let expired = self
+                    .windows
+                    .get(&step_id)
+                    .is_some_and(|window| now.duration_since(window.started_at) >= THROTTLE_WINDOW);
+                if expired {
+                    self.flush(&step_id);
+                }
+                let window = self
+                    .windows
+                    .entry(step_id.clone())
+                    .or_insert_with(|| StepLogWindow::new(now));
+                if window.forwarded < self.lines_per_sec {
+                    window.forwarded += 1;
+                    let _ = self.outer.send(EngineEvent::StepLog { step_id, line });
+                } else {
+                    window.suppressed += 1;
+                }
+            }
+            other => {
+                if let Some(step_id) = event_step_id(&other) {
+                    let step_id = step_id.to_string();
+                    self.flush(&step_id);
+                }
+                if matches!(other, EngineEvent::ScenarioFinished) {
+                    self.flush_all();
+                }
+                let _ = self.outer.send(other);
+            }
+        }
+    }
+
+    /// 만료된 구간의 생략 알림을 내보낸다. 주기적으로 호출되어 로그가 멈춘 Step도 알림이 남게 한다.
+    fn flush_expired(&mut self) {
+        let now = Instant::now();
+        let expired: Vec<String> = self
+            .windows
+            .iter()
+            .filter(|(_, window)| {
+                window.suppressed > 0 && now.duration_since(window.started_at) >= THROTTLE_WINDOW
+            })
+            .map(|(step_id, _)| step_id.clone())
+            .collect();
+        for step_id in expired {
+            self.flush(&step_id);
+        }
+    }
+
+    /// 모든 Step의 생략 알림을 내보낸다.
+    fn flush_all(&mut self) {
+        let step_ids: Vec<String> = self.windows.keys().cloned().collect();
+        for step_id in step_ids {
+            self.flush(&step_id);
+        }
+    }
+
+    /// Step 구간을 닫고, 버린 라인이 있으면 생략 알림을 한 줄 내보낸다.
+    fn flush(&mut self, step_id: &str) {
+        if let Some(window) = self.windows.remove(step_id)
+            && window.suppressed > 0
+        {
+            let _ = self.outer.send(EngineEvent::StepLog {
+                step_id: step_id.to_string(),
+                line: tf(
+                    "engine.log.suppressed",
+                    &[
+                        ("count", &window.suppressed),
+                        ("limit", &self.lines_per_sec),
+                    ],
+                ),
+            });
+        }
+    }
+}
+
+/// 이벤트가 속한 Step ID를 반환한다.
+fn event_step_id(event: &EngineEvent) -> Option<&str> {
+    match event {
+        EngineEvent::StepStarted { step_id }
+        | EngineEvent::StepWaiting { step_id, .. }
+        | EngineEvent::StepRetrying { step_id, .. }
+        | EngineEvent::StepTimeoutWarning { step_id, .. }
+        | EngineEvent::StepLog { step_id, .. }
+        | EngineEvent::StepBlocked { step_id }
+        | EngineEvent::StepAwaitingApproval { step_id, .. }
+        | EngineEvent::StepApproved { step_id, .. }
+        | EngineEvent::LoopProgress { step_id, .. }
+        | EngineEvent::StepFinished { step_id, .. }
+        | EngineEvent::StepOutputs { step_id, .. }
+        | EngineEvent::StepVariables { step_id, .. }
+        | EngineEvent::StepDiagnostics { step_id, .. }
+        | EngineEvent::RequestConfirm { step_id, .. }
+        | EngineEvent::ConfirmResponse { step_id, .. } => Some(step_id),
+        EngineEvent::RunStarted { .. }
+        | EngineEvent::RunSummary { .. }
+        | EngineEvent::DbPoolUsage { .. }
+        | EngineEvent::ScenarioFinished => None,
+    }
+}
+
+/// Step별 로그 속도를 제한하는 중계 작업을 시작하고 엔진이 사용할 송신자를 반환한다.
+///
+/// 엔진 내부 채널은 중계 작업이 즉시 비우므로 로그가 폭주해도 UI 쪽 채널에는
+/// Step당 초당 `lines_per_sec`줄과 생략 알림만 쌓인다. `0`이면 제한하지 않는다.
+///
+/// # 매개변수
+/// - `outer`: UI 또는 데몬이 이벤트를 받는 송신자.
+/// - `lines_per_sec`: Step별 초당 최대 로그 라인 수.
+pub(crate) fn spawn_log_throttle(
+    outer: UnboundedSender<EngineEvent>,
+    lines_per_sec: u32,
+) -> UnboundedSender<EngineEvent> {
+    if lines_per_sec == 0 {
+        return outer;
+    }
+    let (tx, rx) = mpsc::unbounded_channel();
+    let throttle = LogThrottle {
+        lines_per_sec,
+        outer,
+        windows: HashMap::new(),
+    };
+    tokio::spawn(run_throttle(rx, throttle));
+    tx
+}
+
+/// 엔진 이벤트를 받아 제한을 적용하며 전달한다. 모든 송신자가 사라지면 남은 알림을 내보내고 끝낸다.
+async fn run_throttle(mut rx: UnboundedReceiver<EngineEvent>, mut throttle: LogThrottle) {
+    let mut ticker = tokio::time::interval(THROTTLE_WINDOW);
+    loop {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Some(event) => throttle.handle(event),
+                None => break,
+            },
+            _ = ticker.tick() => throttle.flush_expired(),
+        }
+    }
+    throttle.flush_all();
+}
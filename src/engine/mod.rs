@@ -1,14 +1,51 @@
 mod confirm_bridge;
 mod context;
+mod context_file;
+mod diagnostics;
+mod error;
 mod events;
+mod journal;
+mod log_throttle;
+mod pool_monitor;
+mod preview;
+mod rehearsal;
 mod resources;
+mod run_log;
+mod run_tmpdir;
 mod runner;
+mod runtime_config;
+mod scheduler;
+mod schema_browse;
+mod secret_mask;
+mod shell_command;
+mod shell_trial;
+mod sql_preview;
 mod state;
+mod step_notify;
+mod step_test;
 mod steps;
+mod summary;
 
-pub use confirm_bridge::ConfirmBridge;
-pub use context::{ExecutionContext, SharedExecutionContext};
-pub use events::{ConfirmPhase, EngineEvent};
-pub use resources::EngineHandles;
+pub use confirm_bridge::{ConfirmBridge, ConfirmMode, StepCancelMode};
+pub use context::{
+    ContextSnapshot, ExecutionContext, SECRET_MASK, SharedExecutionContext, VarChange,
+    placeholder_names,
+};
+pub use context_file::load_context_file;
+pub use diagnostics::DiagnosticResult;
+pub use error::EngineError;
+pub use events::{ConfirmPhase, DbPoolUsage, EngineEvent, ExpectedOutput};
+pub use journal::{InterruptedRun, RunJournal, discard_journal, load_interrupted_run};
+pub use preview::{PreviewField, PreviewValue, preview_step};
+pub use rehearsal::{RehearsalFault, RehearsalPlan};
+pub use resources::{EngineHandleCache, EngineHandles};
+pub use run_log::{DEFAULT_LOG_DIR, log_dir};
 pub use runner::run_scenario;
-pub use state::{ScenarioRuntime, StepRuntimeState, StepStatus};
+pub use runtime_config::EngineRuntimeConfig;
+pub(crate) use scheduler::DagScheduler;
+pub use schema_browse::{fetch_schema_columns, fetch_schema_tables};
+pub use shell_trial::{ShellTrialOutput, run_shell_trial};
+pub use sql_preview::{SQL_PREVIEW_MAX_ROWS, preview_sql};
+pub use state::{GateApproval, ScenarioRuntime, StepRuntimeState, StepStatus};
+pub use step_test::{step_test_inputs, step_test_scenario};
+pub use summary::SummaryResult;
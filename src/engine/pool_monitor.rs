@@ -0,0 +1,57 @@
+use super::events::{DbPoolUsage, EngineEvent};
+use super::resources::EngineHandles;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+
+/// 연결 풀 사용 현황을 다시 읽는 간격이다.
+const POOL_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 실행 중 DB 키별 연결 풀 사용 현황을 주기적으로 읽어 [`EngineEvent::DbPoolUsage`]로 보낸다.
+///
+/// 연결 풀을 쓰는 실행기가 하나도 없으면 작업을 띄우지 않는다. 현황이 바뀐 경우에만 이벤트를
+/// 보내며, `stop`이 취소되면 멈춘다. 실행기는 실행 사이에 재사용되므로 평균 획득 시간은 이 함수를
+/// 부를 때(실행 시작) 읽은 누계를 빼서 이번 실행의 값만으로 계산한다.
+pub(super) fn spawn_pool_monitor(
+    handles: &EngineHandles,
+    sender: UnboundedSender<EngineEvent>,
+    stop: CancellationToken,
+) {
+    let mut executors: Vec<_> = handles
+        .db_map
+        .iter()
+        .filter_map(|(key, executor)| {
+            let baseline = executor.pool_stats()?;
+            Some((key.clone(), executor.clone(), baseline))
+        })
+        .collect();
+    if executors.is_empty() {
+        return;
+    }
+    executors.sort_by(|a, b| a.0.cmp(&b.0));
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(POOL_SAMPLE_INTERVAL);
+        let mut last: Vec<DbPoolUsage> = Vec::new();
+        loop {
+            tokio::select! {
+                _ = stop.cancelled() => break,
+                _ = ticker.tick() => {}
+            }
+            let pools: Vec<DbPoolUsage> = executors
+                .iter()
+                .filter_map(|(key, executor, baseline)| {
+                    Some(DbPoolUsage {
+                        db_key: key.clone(),
+                        stats: executor.pool_stats()?.since(baseline),
+                    })
+                })
+                .collect();
+            if pools != last {
+                last = pools.clone();
+                if sender.send(EngineEvent::DbPoolUsage { pools }).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+}
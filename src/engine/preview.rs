@@ -0,0 +1,267 @@
+use super::context::ExecutionContext;
+use super::shell_command::shell_invocation;
+use crate::i18n::tf;
+use crate::scenario::{Step, StepKind};
+use std::path::{Path, PathBuf};
+
+/// 미리보기로 보여 줄 SQL 파일의 최대 줄 수이다.
+const MAX_PREVIEW_LINES: usize = 200;
+
+/// Step 구성 미리보기 항목의 값이다.
+#[derive(Debug, Clone)]
+pub enum PreviewValue {
+    /// 한 줄 텍스트.
+    Text(String),
+    /// SQL·스크립트·명령줄처럼 고정폭으로 보여 줄 텍스트.
+    Code(String),
+    /// 경로와 존재 여부. 원격 경로나 출력 파일처럼 확인하지 않는 경로는 `None`이다.
+    Path {
+        /// 치환된 경로.
+        path: String,
+        /// 파일 또는 디렉터리 존재 여부.
+        exists: Option<bool>,
+    },
+}
+
+/// Step 구성 미리보기 항목이다.
+#[derive(Debug, Clone)]
+pub struct PreviewField {
+    /// 항목 이름의 i18n 키.
+    pub label: &'static str,
+    /// 항목 값.
+    pub value: PreviewValue,
+}
+
+/// Step 구성을 엔진과 같은 플레이스홀더 치환 규칙으로 풀어 유형별 미리보기 항목을 만든다.
+///
+/// 아직 값이 없는 플레이스홀더는 `${VAR}` 그대로 남긴다. 로컬 경로는 존재 여부를 함께 확인하고,
+/// Loop는 현재 glob에 일치하는 항목 수를 센다. 존재 여부는 실제 값으로 확인하되, 보여 주는 값에서는
+/// `ctx`의 비밀 변수 값을 가린다.
+///
+/// # 매개변수
+/// - `step`: 미리볼 Step.
+/// - `ctx`: 치환에 쓸 컨텍스트. 시나리오 파라미터나 실패 시점 변수를 담는다.
+pub fn preview_step(step: &Step, ctx: &ExecutionContext) -> Vec<PreviewField> {
+    let mut fields = Vec::new();
+    let mut push = |label: &'static str, value: PreviewValue| {
+        fields.push(PreviewField { label, value });
+    };
+    match &step.kind {
+        StepKind::Sql { sql, target_db, .. } => {
+            push("preview.target_db", target_text(target_db.as_deref()));
+            push("preview.sql", PreviewValue::Code(ctx.expand_lenient(sql)));
+        }
+        StepKind::SqlFile {
+            path, target_db, ..
+        } => {
+            push("preview.target_db", target_text(target_db.as_deref()));
+            let expanded = ctx.expand_lenient(&path.to_string_lossy());
+            push("preview.sql_file", local_path(&expanded));
+            if let Ok(content) = std::fs::read_to_string(&expanded) {
+                push(
+                    "preview.sql",
+                    PreviewValue::Code(ctx.expand_lenient(&head_lines(&content))),
+                );
+            }
+        }
+        StepKind::SqlLoaderPar { config } => {
+            let remote = config.remote.is_some();
+            let path = |value: &PathBuf, check: bool| {
+                let expanded = ctx.expand_lenient(&value.to_string_lossy());
+                if check && !remote {
+                    local_path(&expanded)
+                } else {
+                    PreviewValue::Path {
+                        path: expanded,
+                        exists: None,
+                    }
+                }
+            };
+            if let Some(name) = &config.remote {
+                push("preview.remote", PreviewValue::Text(name.clone()));
+            }
+            push("preview.control_file", path(&config.control_file, true));
+            let mut args = vec![format!(
+                "control={}",
+                ctx.expand_lenient(&config.control_file.to_string_lossy())
+            )];
+            for (label, key, value, check) in [
+                ("preview.data_file", "data", &config.data_file, true),
+                ("preview.log_file", "log", &config.log_file, false),
+                ("preview.bad_file", "bad", &config.bad_file, false),
+                (
+                    "preview.discard_file",
+                    "discard",
+                    &config.discard_file,
+                    false,
+                ),
+            ] {
+                if let Some(value) = value {
+                    let shown = path(value, check);
+                    if let PreviewValue::Path { path, .. } = &shown {
+                        args.push(format!("{key}={path}"));
+                    }
+                    push(label, shown);
+                }
+            }
+            args.extend(config.options.to_args());
+            args.extend(config.extra_args.iter().map(|arg| ctx.expand_lenient(arg)));
+            // 접속 문자열에는 비밀번호가 들어 있으므로 가린다.
+            push(
+                "preview.command",
+                PreviewValue::Code(format!("sqlldr *** {}", args.join(" "))),
+            );
+        }
+        StepKind::Shell { config } => {
+            let remote = config.remote.is_some();
+            if let Some(name) = &config.remote {
+                push("preview.remote", PreviewValue::Text(name.clone()));
+            }
+            let (program, flag) = shell_invocation(config.shell_program.as_deref(), remote);
+            let mut command = format!("{program} {flag} <script>");
+            for arg in &config.shell_args {
+                command.push(' ');
+                command.push_str(&ctx.expand_lenient(arg));
+            }
+            push("preview.command", PreviewValue::Code(command));
+            push(
+                "preview.script",
+                PreviewValue::Code(ctx.expand_lenient(&config.script)),
+            );
+            if let Some(dir) = &config.working_dir {
+                let expanded = ctx.expand_lenient(&dir.to_string_lossy());
+                let value = if remote {
+                    PreviewValue::Path {
+                        path: expanded,
+                        exists: None,
+                    }
+                } else {
+                    local_path(&expanded)
+                };
+                push("preview.working_dir", value);
+            }
+            if let Some(user) = &config.run_as {
+                push("preview.run_as", PreviewValue::Text(user.clone()));
+            }
+            if !config.env.is_empty() {
+                // 값에는 비밀이 들어 있을 수 있으므로 변수 이름만 보여 준다.
+                let mut keys: Vec<&str> = config.env.keys().map(String::as_str).collect();
+                keys.sort();
+                push("preview.env", PreviewValue::Text(keys.join(", ")));
+            }
+        }
+        StepKind::Extract { config } => {
+            push(
+                "preview.extract_file",
+                local_path(&ctx.expand_lenient(&config.file_path)),
+            );
+            push(
+                "preview.extract_rule",
+                PreviewValue::Text(tf(
+                    "preview.extract_rule_value",
+                    &[
+                        ("line", &config.line),
+                        ("pattern", &config.pattern),
+                        ("group", &config.group),
+                        ("var", &config.var_name),
+                    ],
+                )),
+            );
+        }
+        StepKind::Loop { config } => {
+            let pattern = ctx.expand_lenient(&config.for_each_glob);
+            let matches = glob::glob(&pattern)
+                .map(|paths| paths.filter_map(Result::ok).count().to_string())
+                .unwrap_or_else(|err| err.to_string());
+            push("preview.glob", PreviewValue::Code(pattern));
+            push("preview.glob_matches", PreviewValue::Text(matches));
+            push(
+                "preview.loop_body",
+                PreviewValue::Text(tf(
+                    "preview.loop_body_value",
+                    &[("var", &config.as_var), ("count", &config.steps.len())],
+                )),
+            );
+        }
+        StepKind::ManualGate { config } => {
+            if let Some(message) = &config.message {
+                push("preview.gate_message", PreviewValue::Text(message.clone()));
+            }
+        }
+        StepKind::Wait { config } => {
+            if let Some(secs) = config.seconds {
+                push(
+                    "preview.wait_seconds",
+                    PreviewValue::Text(tf("preview.wait_seconds_value", &[("secs", &secs)])),
+                );
+            }
+            if let Some(until) = &config.until_time {
+                push("preview.wait_until", PreviewValue::Text(until.clone()));
+            }
+        }
+        StepKind::WaitForRun { config } => {
+            push(
+                "preview.wait_run_scenario",
+                PreviewValue::Text(ctx.expand_lenient(&config.scenario)),
+            );
+            if let Some(since) = &config.since {
+                push(
+                    "preview.wait_run_since",
+                    PreviewValue::Text(ctx.expand_lenient(since)),
+                );
+            }
+        }
+        StepKind::Verify { config } => {
+            let file = ctx.expand_lenient(&config.file);
+            push("preview.verify_file", local_path(&file));
+            push(
+                "preview.verify_method",
+                PreviewValue::Text(config.method.as_str().to_string()),
+            );
+            match (&config.expected, &config.expected_file) {
+                (Some(expected), _) => push(
+                    "preview.verify_expected",
+                    PreviewValue::Text(ctx.expand_lenient(expected)),
+                ),
+                (None, Some(path)) => push(
+                    "preview.verify_expected_file",
+                    local_path(&ctx.expand_lenient(path)),
+                ),
+                (None, None) => push(
+                    "preview.verify_expected_file",
+                    local_path(&format!("{file}.{}", config.method.as_str())),
+                ),
+            }
+        }
+        StepKind::Marker => {}
+    }
+    for field in &mut fields {
+        match &mut field.value {
+            PreviewValue::Text(text) | PreviewValue::Code(text) => *text = ctx.mask(text),
+            PreviewValue::Path { path, .. } => *path = ctx.mask(path),
+        }
+    }
+    fields
+}
+
+/// DB 타겟 이름을 표시용 값으로 만든다. 지정하지 않으면 `default`이다.
+fn target_text(target_db: Option<&str>) -> PreviewValue {
+    PreviewValue::Text(target_db.unwrap_or("default").to_string())
+}
+
+/// 로컬 경로와 존재 여부를 만든다.
+fn local_path(path: &str) -> PreviewValue {
+    PreviewValue::Path {
+        path: path.to_string(),
+        exists: Some(Path::new(path).exists()),
+    }
+}
+
+/// 긴 파일은 앞부분만 남긴다.
+fn head_lines(content: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().take(MAX_PREVIEW_LINES).collect();
+    if content.lines().count() > MAX_PREVIEW_LINES {
+        lines.push("...");
+    }
+    lines.join("\n")
+}
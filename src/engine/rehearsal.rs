@@ -0,0 +1,101 @@
+use super::error::EngineError;
+use super::events::EngineEvent;
+use crate::i18n::{t, tf};
+use crate::scenario::{Step, StepKind};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+
+/// 리허설 실행에서 Step 하나에 주입할 가짜 장애이다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RehearsalFault {
+    /// 실제로 실행하지 않고 실패 처리할지 여부.
+    #[serde(default)]
+    pub fail: bool,
+    /// 실행 또는 강제 실패 전에 기다릴 시간(초 단위).
+    #[serde(default)]
+    pub delay_sec: u64,
+}
+
+impl RehearsalFault {
+    /// 주입할 장애가 없는지 확인한다.
+    pub fn is_empty(&self) -> bool {
+        !self.fail && self.delay_sec == 0
+    }
+}
+
+/// 리허설 실행 계획이다.
+///
+/// 시나리오 YAML에는 저장하지 않으며 실행 요청과 함께만 엔진에 전달된다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RehearsalPlan {
+    /// 장애를 주입하지 않은 Step도 실제로 실행하지 않고 성공으로 처리할지 여부.
+    #[serde(default)]
+    pub simulate_others: bool,
+    /// Step ID별로 주입할 장애.
+    #[serde(default)]
+    pub faults: HashMap<String, RehearsalFault>,
+}
+
+impl RehearsalPlan {
+    /// Step을 실제로 실행하지 않고 성공으로 흉내만 내는지 확인한다. [`rehearse_step`]과 같은 기준이다.
+    pub(super) fn simulates(&self, step: &Step) -> bool {
+        self.simulate_others
+            && !matches!(step.kind, StepKind::ManualGate { .. })
+            && !self.faults.get(&step.id).is_some_and(|fault| fault.fail)
+    }
+}
+
+impl Default for RehearsalPlan {
+    /// 실제 데이터를 건드리지 않도록 나머지 Step도 흉내만 내는 계획을 기본값으로 한다.
+    fn default() -> Self {
+        Self {
+            simulate_others: true,
+            faults: HashMap::new(),
+        }
+    }
+}
+
+/// 리허설 계획에 따라 Step의 지연과 강제 실패를 적용한다.
+///
+/// 실제 실행을 대신 처리했으면 그 결과를, 지연만 적용하고 실제로 실행해야 하면 `None`을 반환한다.
+/// 승인 게이트는 데이터를 건드리지 않으므로 강제 실패가 없으면 리허설에서도 그대로 승인을 기다린다.
+pub(super) async fn rehearse_step(
+    plan: &RehearsalPlan,
+    step: &Step,
+    sender: &UnboundedSender<EngineEvent>,
+    cancel: &CancellationToken,
+) -> Option<Result<(), EngineError>> {
+    let fault = plan.faults.get(&step.id).cloned().unwrap_or_default();
+    if fault.delay_sec > 0 {
+        log_rehearsal(
+            sender,
+            step,
+            tf("engine.log.rehearsal_delay", &[("secs", &fault.delay_sec)]),
+        );
+        tokio::select! {
+            _ = cancel.cancelled() => return Some(Err(EngineError::Cancelled)),
+            _ = tokio::time::sleep(Duration::from_secs(fault.delay_sec)) => {}
+        }
+    }
+    if fault.fail {
+        return Some(Err(EngineError::Other(
+            t("engine.rehearsal.injected_failure").into(),
+        )));
+    }
+    if plan.simulates(step) {
+        log_rehearsal(sender, step, t("engine.log.rehearsal_simulated").into());
+        return Some(Ok(()));
+    }
+    None
+}
+
+/// 리허설 로그를 Step 로그로 전달한다.
+fn log_rehearsal(sender: &UnboundedSender<EngineEvent>, step: &Step, line: String) {
+    let _ = sender.send(EngineEvent::StepLog {
+        step_id: step.id.clone(),
+        line,
+    });
+}
@@ -1,18 +1,28 @@
-use super::context::SharedExecutionContext;
+use super::context::{ExecutionContext, SharedExecutionContext};
+use super::error::EngineError;
+use crate::connections::load_connection_store;
 use crate::executor::{
     DummyExecutor, SharedExecutor, new_oracle_db_executor, new_real_db_executor,
 };
-use crate::scenario::{DbConnectionConfig, DbKind, Scenario};
+use crate::i18n::tf;
+use crate::scenario::{DbConnectionConfig, DbKind, RemoteHostConfig, Scenario, db_var};
 use anyhow::Context;
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
 
 /// 엔진 실행 중 필요한 공용 리소스를 캡슐화한다.
-#[derive(Clone)]
+///
+/// 기본값은 DB와 원격 호스트가 하나도 없는 핸들로, 준비에 실패한 실행의 정리 Step에 쓴다.
+#[derive(Clone, Default)]
 pub struct EngineHandles {
     /// DB 이름별 실행기 맵이다.
     pub(crate) db_map: HashMap<String, SharedExecutor>,
+    /// 이름별 원격 실행 호스트 맵이다. 플레이스홀더는 이미 치환되어 있다.
+    pub(crate) remote_map: HashMap<String, RemoteHostConfig>,
+    /// DB 이름별 연결 정의이다. 플레이스홀더는 이미 치환되어 있다.
+    pub(crate) db_configs: HashMap<String, DbConnectionConfig>,
 }
 
 impl fmt::Debug for EngineHandles {
@@ -25,8 +35,10 @@ impl fmt::Debug for EngineHandles {
     /// * [`fmt::Result`] - 포매팅 성공 여부
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let keys: Vec<&String> = self.db_map.keys().collect();
+        let remote_keys: Vec<&String> = self.remote_map.keys().collect();
         f.debug_struct("EngineHandles")
             .field("db_map_keys", &keys)
+            .field("remote_map_keys", &remote_keys)
             .finish()
     }
 }
@@ -43,17 +55,152 @@ impl EngineHandles {
     /// # 오류
     /// * 존재하지 않는 타겟을 요청하면 [`anyhow::Error`]를 반환한다.
     pub fn get_db_executor(&self, name: &str) -> anyhow::Result<SharedExecutor> {
-        self.db_map
-            .get(name)
-            .cloned()
-            .ok_or_else(|| anyhow::anyhow!(format!("정의되지 않은 DB 타겟: {name}")))
+        self.db_map.get(name).cloned().ok_or_else(|| {
+            EngineError::ConfigError(tf("engine.error.unknown_db", &[("name", &name)])).into()
+        })
+    }
+
+    /// 지정한 이름의 원격 실행 호스트 정의를 반환한다.
+    ///
+    /// # 매개변수
+    /// * `name` - 조회할 원격 호스트 키
+    ///
+    /// # 반환값
+    /// * [`RemoteHostConfig`] - 플레이스홀더가 치환된 호스트 정의
+    ///
+    /// # 오류
+    /// * 존재하지 않는 호스트를 요청하면 [`anyhow::Error`]를 반환한다.
+    pub fn get_remote(&self, name: &str) -> anyhow::Result<&RemoteHostConfig> {
+        self.remote_map.get(name).ok_or_else(|| {
+            EngineError::ConfigError(tf("engine.error.unknown_remote", &[("name", &name)])).into()
+        })
+    }
+
+    /// 시나리오 DB 연결 정보를 `DB.<키>.<필드>` 컨텍스트 변수로 넣는다.
+    ///
+    /// 셸 스크립트가 자격 증명을 하드코딩하지 않고 `${DB.billing.user}`나 `${DB.billing.conn_string}`으로
+    /// 참조할 수 있게 한다. 정의에 없는 필드는 넣지 않으므로 참조하면 치환 오류가 난다. 비밀번호와 연결
+    /// 문자열은 [`Scenario::secret_vars`]에 포함되어 로그와 화면에서 가려진다.
+    ///
+    /// # 매개변수
+    /// * `ctx` - 변수를 넣을 실행 컨텍스트
+    pub(super) fn export_db_vars(&self, ctx: &mut ExecutionContext) {
+        for (key, config) in &self.db_configs {
+            let fields = [
+                ("kind", Some(config.kind.as_str().to_string())),
+                ("dsn", config.dsn.clone()),
+                ("user", config.user.clone()),
+                ("password", config.password.clone()),
+                ("conn_string", conn_string(config)),
+            ];
+            for (field, value) in fields {
+                if let Some(value) = value {
+                    ctx.set_var(db_var(key, field), value);
+                }
+            }
+        }
+    }
+}
+
+/// 핸들을 구성할 때 사용한 시나리오 정의이다. 값이 같으면 캐시된 핸들을 재사용한다.
+#[derive(Debug, Clone, PartialEq)]
+struct HandleSource {
+    /// 공유 연결 참조를 해석한 DB 정의.
+    db: HashMap<String, DbConnectionConfig>,
+    /// 원격 호스트 정의.
+    remotes: HashMap<String, RemoteHostConfig>,
+    /// 플레이스홀더 치환에 쓰이는 시나리오 파라미터.
+    params: HashMap<String, String>,
+}
+
+impl HandleSource {
+    /// 시나리오에서 핸들 구성 입력을 추출한다. `db_ref`는 공유 연결 저장소로 해석한다.
+    ///
+    /// # 오류
+    /// * 공유 연결 저장소를 읽지 못하거나 참조 대상이 없으면 [`anyhow::Error`]를 반환한다.
+    fn from_scenario(scenario: &Scenario) -> anyhow::Result<Self> {
+        let db = if scenario.db.values().any(|config| config.db_ref.is_some()) {
+            load_connection_store()?.resolve(&scenario.db)?
+        } else {
+            scenario.db.clone()
+        };
+        Ok(Self {
+            db,
+            remotes: scenario.remotes.clone(),
+            params: scenario.params.clone(),
+        })
+    }
+}
+
+/// 실행 사이에 재사용하는 [`EngineHandles`] 캐시이다.
+///
+/// 시나리오의 DB·원격 호스트 정의가 바뀌면 다음 실행을 시작할 때 핸들을 새로 만든다.
+/// 실행 중인 시나리오는 시작할 때 받은 핸들을 끝까지 사용하므로 교체의 영향을 받지 않는다.
+#[derive(Clone, Default)]
+pub struct EngineHandleCache {
+    /// 마지막으로 구성한 핸들과 그 입력 정의이다.
+    slot: Arc<Mutex<Option<(HandleSource, Arc<EngineHandles>)>>>,
+}
+
+impl EngineHandleCache {
+    /// 실행에 사용할 핸들을 반환한다. 정의가 바뀌었으면 새로 구성해 캐시를 교체한다.
+    ///
+    /// # 매개변수
+    /// * `scenario` - DB 설정 정보를 포함한 시나리오
+    /// * `default_executor` - default 키로 등록할 기본 실행기
+    /// * `ctx` - 환경 변수를 확장하기 위한 실행 컨텍스트
+    ///
+    /// # 오류
+    /// * 실행기 생성에 실패하면 [`anyhow::Error`]를 반환한다.
+    pub(crate) async fn acquire(
+        &self,
+        scenario: &Scenario,
+        default_executor: SharedExecutor,
+        ctx: SharedExecutionContext,
+    ) -> anyhow::Result<Arc<EngineHandles>> {
+        let source = HandleSource::from_scenario(scenario)?;
+        let mut slot = self.slot.lock().await;
+        if let Some((cached, handles)) = slot.as_ref()
+            && *cached == source
+        {
+            return Ok(handles.clone());
+        }
+        let handles = Arc::new(build_engine_handles(&source, default_executor, ctx).await?);
+        *slot = Some((source, handles.clone()));
+        Ok(handles)
+    }
+
+    /// 정의 변경 여부와 관계없이 핸들을 즉시 다시 구성한다.
+    ///
+    /// 플레이스홀더는 시나리오 파라미터만으로 치환한다. 실패하면 기존 캐시를 그대로 둔다.
+    ///
+    /// # 매개변수
+    /// * `scenario` - 편집된 DB 정의를 담은 시나리오
+    /// * `default_executor` - default 키로 등록할 기본 실행기
+    ///
+    /// # 오류
+    /// * 실행기 생성에 실패하면 [`anyhow::Error`]를 반환한다.
+    pub async fn reload(
+        &self,
+        scenario: &Scenario,
+        default_executor: SharedExecutor,
+    ) -> anyhow::Result<()> {
+        let source = HandleSource::from_scenario(scenario)?;
+        let mut initial_ctx = ExecutionContext::new();
+        for (key, value) in &source.params {
+            initial_ctx.set_var(key.clone(), value.clone());
+        }
+        let ctx: SharedExecutionContext = Arc::new(RwLock::new(initial_ctx));
+        let handles = build_engine_handles(&source, default_executor, ctx).await?;
+        *self.slot.lock().await = Some((source, Arc::new(handles)));
+        Ok(())
     }
 }
 
-/// Scenario 정의를 기반으로 DB 실행기 맵을 구성한다.
+/// 핸들 구성 입력을 기반으로 DB 실행기 맵과 원격 호스트 맵을 구성한다.
 ///
 /// # 매개변수
-/// * `scenario` - DB 설정 정보를 포함한 시나리오
+/// * `source` - 공유 연결 참조가 해석된 DB·원격 호스트 정의
 /// * `default_executor` - default 키로 등록할 기본 실행기
 /// * `ctx` - 환경 변수를 확장하기 위한 실행 컨텍스트
 ///
@@ -62,53 +209,243 @@ impl EngineHandles {
 ///
 /// # 오류
 /// * 실행기 생성에 실패하면 [`anyhow::Error`]를 반환한다.
-pub async fn prepare_engine_handles(
-    scenario: &Scenario,
+async fn build_engine_handles(
+    source: &HandleSource,
     default_executor: SharedExecutor,
     ctx: SharedExecutionContext,
 ) -> anyhow::Result<EngineHandles> {
     let mut db_map: HashMap<String, SharedExecutor> = HashMap::new();
+    let mut db_configs: HashMap<String, DbConnectionConfig> = HashMap::new();
     db_map.insert("default".to_string(), default_executor);
-    for (name, config) in &scenario.db {
-        let executor = build_executor_from_config(config, ctx.clone())
+    for (name, config) in &source.db {
+        let (expanded, executor) = build_db(config, ctx.clone())
             .await
-            .with_context(|| format!("DB 실행기 생성 실패: {name}"))?;
+            .with_context(|| tf("engine.error.db_executor", &[("name", name)]))?;
         db_map.insert(name.clone(), executor);
+        db_configs.insert(name.clone(), expanded);
+    }
+    let mut remote_map: HashMap<String, RemoteHostConfig> = HashMap::new();
+    for (name, config) in &source.remotes {
+        let remote = expand_remote_config(config, ctx.clone())
+            .await
+            .with_context(|| tf("engine.error.remote_expand", &[("name", name)]))?;
+        remote_map.insert(name.clone(), remote);
     }
-    Ok(EngineHandles { db_map })
+    Ok(EngineHandles {
+        db_map,
+        remote_map,
+        db_configs,
+    })
 }
 
-/// DB 연결 설정을 바탕으로 적절한 실행기를 생성한다.
+/// 캐시를 거치지 않고 시나리오의 DB 타겟 하나에 대한 실행기를 만든다.
+///
+/// 편집 중인 정의로 미리보기 조회를 할 때 쓰며, 다른 DB 정의의 오류에는 영향을 받지 않는다.
+///
+/// # 매개변수
+/// * `scenario` - DB 설정 정보를 포함한 시나리오
+/// * `target` - 실행기를 만들 DB 타겟 이름
+/// * `default_executor` - 시나리오에 `default` 정의가 없을 때 쓸 기본 실행기
+/// * `ctx` - 환경 변수를 확장하기 위한 실행 컨텍스트
+///
+/// # 오류
+/// * 정의되지 않은 타겟이거나 실행기 생성에 실패하면 [`anyhow::Error`]를 반환한다.
+pub(super) async fn build_target_executor(
+    scenario: &Scenario,
+    target: &str,
+    default_executor: SharedExecutor,
+    ctx: SharedExecutionContext,
+) -> anyhow::Result<SharedExecutor> {
+    let source = HandleSource::from_scenario(scenario)?;
+    match source.db.get(target) {
+        Some(config) => build_db(config, ctx)
+            .await
+            .map(|(_, executor)| executor)
+            .with_context(|| tf("engine.error.db_executor", &[("name", &target)])),
+        None if target == "default" => Ok(default_executor),
+        None => Err(
+            EngineError::ConfigError(tf("engine.error.unknown_db", &[("name", &target)])).into(),
+        ),
+    }
+}
+
+/// 원격 호스트 정의의 문자열 필드에 플레이스홀더를 치환한다.
+///
+/// # 매개변수
+/// * `config` - 원본 호스트 정의
+/// * `ctx` - 변수 확장을 수행할 실행 컨텍스트
+///
+/// # 반환값
+/// * [`RemoteHostConfig`] - 치환이 끝난 호스트 정의
+///
+/// # 오류
+/// * 치환할 값을 찾지 못하면 [`anyhow::Error`]를 반환한다.
+async fn expand_remote_config(
+    config: &RemoteHostConfig,
+    ctx: SharedExecutionContext,
+) -> anyhow::Result<RemoteHostConfig> {
+    let identity_file = config
+        .identity_file
+        .as_ref()
+        .map(|path| path.to_string_lossy().to_string());
+    Ok(RemoteHostConfig {
+        host: expand_required(ctx.clone(), Some(config.host.clone()), "remote.host").await?,
+        user: expand_optional(ctx.clone(), config.user.clone(), "remote.user").await?,
+        port: config.port,
+        identity_file: expand_optional(ctx.clone(), identity_file, "remote.identity_file")
+            .await?
+            .map(Into::into),
+        base_dir: expand_optional(ctx, config.base_dir.clone(), "remote.base_dir").await?,
+        ssh_args: config.ssh_args.clone(),
+    })
+}
+
+/// DB 연결 설정의 플레이스홀더를 치환하고 적절한 실행기를 생성한다.
 ///
 /// # 매개변수
 /// * `config` - 대상 DB 연결 정보
 /// * `ctx` - 변수 확장을 수행할 실행 컨텍스트
 ///
 /// # 반환값
-/// * [`SharedExecutor`] - 생성된 실행기를 감싼 스마트 포인터
+/// * `(DbConnectionConfig, SharedExecutor)` - 치환한 연결 정보와 생성된 실행기
 ///
 /// # 오류
 /// * 연결 정보가 누락되었거나 생성 중 오류가 발생하면 [`anyhow::Error`]를 반환한다.
-async fn build_executor_from_config(
+async fn build_db(
     config: &DbConnectionConfig,
     ctx: SharedExecutionContext,
-) -> anyhow::Result<SharedExecutor> {
-    match config.kind {
-        DbKind::Dummy => Ok(Arc::new(DummyExecutor::default()) as SharedExecutor),
+) -> anyhow::Result<(DbConnectionConfig, SharedExecutor)> {
+    let expanded = expand_db_config(config, ctx).await?;
+    let executor = match expanded.kind {
+        DbKind::Dummy => Arc::new(DummyExecutor::default()) as SharedExecutor,
         DbKind::Postgres => {
-            let dsn = expand_required(ctx.clone(), config.dsn.clone(), "dsn").await?;
-            let user = expand_optional(ctx.clone(), config.user.clone(), "user").await?;
-            let password =
-                expand_optional(ctx.clone(), config.password.clone(), "password").await?;
-            new_real_db_executor(dsn, user, password).await
+            let dsn = required_field(&expanded.dsn, "dsn")?;
+            new_real_db_executor(dsn, expanded.user.clone(), expanded.password.clone()).await?
         }
-        DbKind::Oracle => {
-            let dsn = expand_required(ctx.clone(), config.dsn.clone(), "dsn").await?;
-            let user = expand_required(ctx.clone(), config.user.clone(), "user").await?;
-            let password = expand_required(ctx, config.password.clone(), "password").await?;
-            Ok(new_oracle_db_executor(dsn, user, password))
+        DbKind::Oracle => new_oracle_db_executor(
+            required_field(&expanded.dsn, "dsn")?,
+            required_field(&expanded.user, "user")?,
+            required_field(&expanded.password, "password")?,
+        ),
+    };
+    Ok((expanded, executor))
+}
+
+/// DB 연결 정의의 문자열 필드에 플레이스홀더를 치환한다.
+///
+/// 더미 연결은 값을 쓰지 않으므로 치환하지 못한 플레이스홀더를 오류 없이 그대로 둔다.
+///
+/// # 매개변수
+/// * `config` - 원본 DB 연결 정보
+/// * `ctx` - 변수 확장을 수행할 실행 컨텍스트
+///
+/// # 오류
+/// * 더미가 아닌 연결에서 치환할 값을 찾지 못하면 [`anyhow::Error`]를 반환한다.
+async fn expand_db_config(
+    config: &DbConnectionConfig,
+    ctx: SharedExecutionContext,
+) -> anyhow::Result<DbConnectionConfig> {
+    if config.kind.is_dummy() {
+        let guard = ctx.read().await;
+        let lenient =
+            |value: &Option<String>| value.as_deref().map(|raw| guard.expand_lenient(raw));
+        return Ok(DbConnectionConfig {
+            kind: config.kind.clone(),
+            dsn: lenient(&config.dsn),
+            user: lenient(&config.user),
+            password: lenient(&config.password),
+            db_ref: None,
+        });
+    }
+    Ok(DbConnectionConfig {
+        kind: config.kind.clone(),
+        dsn: expand_optional(ctx.clone(), config.dsn.clone(), "dsn").await?,
+        user: expand_optional(ctx.clone(), config.user.clone(), "user").await?,
+        password: expand_optional(ctx, config.password.clone(), "password").await?,
+        db_ref: None,
+    })
+}
+
+/// 치환을 마친 필수 필드 값을 꺼낸다.
+///
+/// # 오류
+/// * 값이 없으면 [`anyhow::Error`]를 반환한다.
+fn required_field(value: &Option<String>, field: &str) -> anyhow::Result<String> {
+    value
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!(tf("engine.error.missing_field", &[("field", &field)])))
+}
+
+/// 셸에서 바로 쓸 수 있는 연결 문자열을 만든다.
+///
+/// Oracle은 sqlplus 형식 `user/password@dsn`이고, PostgreSQL은 `dsn`에 사용자와 비밀번호를 넣은 형식이다.
+/// DSN이 없으면 `None`이다.
+fn conn_string(config: &DbConnectionConfig) -> Option<String> {
+    let dsn = config.dsn.as_deref()?;
+    let user = config.user.as_deref();
+    let password = config.password.as_deref();
+    Some(match config.kind {
+        DbKind::Oracle => match (user, password) {
+            (Some(user), Some(password)) => format!("{user}/{password}@{dsn}"),
+            (Some(user), None) => format!("{user}@{dsn}"),
+            _ => dsn.to_string(),
+        },
+        DbKind::Postgres => postgres_conn_string(dsn, user, password),
+        DbKind::Dummy => dsn.to_string(),
+    })
+}
+
+/// PostgreSQL DSN에 사용자와 비밀번호를 넣는다.
+///
+/// URL(`postgres://host/db`)이면 사용자 정보를 퍼센트 인코딩해 호스트 앞에 넣고, 이미 사용자 정보가 있으면
+/// 그대로 둔다. `host=... dbname=...` 같은 키-값 형식이면 `user=`·`password=`를 덧붙인다.
+fn postgres_conn_string(dsn: &str, user: Option<&str>, password: Option<&str>) -> String {
+    let Some(user) = user else {
+        return dsn.to_string();
+    };
+    if let Some((scheme, rest)) = dsn.split_once("://") {
+        let authority = rest.split(['/', '?']).next().unwrap_or_default();
+        if authority.contains('@') {
+            return dsn.to_string();
+        }
+        let mut userinfo = percent_encode(user);
+        if let Some(password) = password {
+            userinfo.push(':');
+            userinfo.push_str(&percent_encode(password));
         }
+        return format!("{scheme}://{userinfo}@{rest}");
+    }
+    let mut parts = vec![dsn.to_string(), format!("user={}", quote_conninfo(user))];
+    if let Some(password) = password {
+        parts.push(format!("password={}", quote_conninfo(password)));
+    }
+    parts.join(" ")
+}
+
+/// URL 사용자 정보에 넣을 수 있도록 영문·숫자와 `-._~` 이외의 바이트를 퍼센트 인코딩한다.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                (byte as char).to_string()
+            } else {
+                format!("%{byte:02X}")
+            }
+        })
+        .collect()
+}
+
+/// libpq 키-값 형식의 값을 필요하면 작은따옴표로 감싼다.
+fn quote_conninfo(value: &str) -> String {
+    if !value.is_empty()
+        && !value
+            .chars()
+            .any(|ch| ch.is_whitespace() || ch == '\'' || ch == '\\')
+    {
+        return value.to_string();
     }
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
 }
 
 /// 필수 문자열 값을 컨텍스트 기반으로 확장한다.
@@ -128,7 +465,8 @@ async fn expand_required(
     value: Option<String>,
     field: &str,
 ) -> anyhow::Result<String> {
-    let raw = value.ok_or_else(|| anyhow::anyhow!(format!("{field} 값이 누락되었습니다.")))?;
+    let raw = value
+        .ok_or_else(|| anyhow::anyhow!(tf("engine.error.missing_field", &[("field", &field)])))?;
     let guard = ctx.read().await;
     guard.expand_required(&raw, field)
 }
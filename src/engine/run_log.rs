@@ -0,0 +1,141 @@
+use super::events::EngineEvent;
+use super::run_tmpdir::sanitize;
+use crate::i18n::tf;
+use crate::settings::load_app_settings;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// 실행 로그 파일 디렉터리를 지정하는 환경 변수 이름이다.
+pub const LOG_DIR_ENV: &str = "RUST_AIRFLOW_LOG_DIR";
+
+/// 환경 변수가 없을 때 사용하는 실행 로그 파일 디렉터리이다.
+pub const DEFAULT_LOG_DIR: &str = "logs";
+
+/// 시나리오에 값이 없을 때 사용하는 로그 한 줄의 최대 바이트 수이다.
+pub const DEFAULT_LOG_LINE_MAX_BYTES: usize = 8 * 1024;
+
+/// 버퍼에 쌓인 파일 로그를 디스크로 내보내는 간격이다.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 실행 로그 파일 디렉터리를 반환한다.
+///
+/// 환경 변수, 앱 설정의 `log_dir` 순으로 찾고, 둘 다 없으면 현재 디렉터리의 `logs`이다.
+pub fn log_dir() -> PathBuf {
+    std::env::var(LOG_DIR_ENV)
+        .map(PathBuf::from)
+        .ok()
+        .or_else(|| {
+            load_app_settings()
+                .ok()
+                .and_then(|settings| settings.log_dir)
+        })
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_LOG_DIR))
+}
+
+/// Step 로그 원문을 실행 로그 파일에 남기고, UI로는 긴 줄을 잘라 전달하는 중계 작업을 시작한다.
+///
+/// 파일은 `<로그 디렉터리>/<시나리오명>-<시작 시각>.log`이며 한 줄에 `시각 [step_id] 로그` 형식으로
+/// 쓴다. 수 MB짜리 한 줄이 화면을 멈추지 않도록 `max_bytes`를 넘는 줄은 UTF-8 경계에서 자르고
+/// 잘림 표시를 붙여 전달한다. `max_bytes`가 `0`이면 자르지 않는다. 파일을 만들지 못하면 경고만
+/// 남기고 자르기는 그대로 적용한다.
+///
+/// # 매개변수
+/// - `outer`: 다음 단계가 이벤트를 받는 송신자.
+/// - `scenario_name`: 파일 이름에 쓸 시나리오 이름.
+/// - `max_bytes`: UI로 전달할 로그 한 줄의 최대 바이트 수.
+pub(crate) fn spawn_run_log(
+    outer: UnboundedSender<EngineEvent>,
+    scenario_name: &str,
+    max_bytes: usize,
+) -> UnboundedSender<EngineEvent> {
+    let dir = log_dir();
+    let path = dir.join(format!(
+        "{}-{}.log",
+        sanitize(scenario_name),
+        chrono::Local::now().format("%Y%m%d-%H%M%S%3f")
+    ));
+    let file = match std::fs::create_dir_all(&dir).and_then(|_| std::fs::File::create(&path)) {
+        Ok(file) => {
+            tracing::info!("실행 로그 파일: {}", path.display());
+            Some(BufWriter::new(tokio::fs::File::from_std(file)))
+        }
+        Err(err) => {
+            tracing::warn!("실행 로그 파일 생성 실패({}): {err}", path.display());
+            None
+        }
+    };
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(run_log_relay(rx, outer, file, max_bytes));
+    tx
+}
+
+/// 엔진 이벤트를 받아 로그 원문을 파일에 쓰고 긴 줄을 잘라 전달한다. 모든 송신자가 사라지면 파일을 닫는다.
+async fn run_log_relay(
+    mut rx: UnboundedReceiver<EngineEvent>,
+    outer: UnboundedSender<EngineEvent>,
+    mut file: Option<BufWriter<tokio::fs::File>>,
+    max_bytes: usize,
+) {
+    let mut ticker = tokio::time::interval(FLUSH_INTERVAL);
+    loop {
+        let event = tokio::select! {
+            event = rx.recv() => match event {
+                Some(event) => event,
+                None => break,
+            },
+            _ = ticker.tick() => {
+                flush_file(&mut file).await;
+                continue;
+            }
+        };
+        let event = match event {
+            EngineEvent::StepLog { step_id, line } => {
+                if let Some(writer) = &mut file {
+                    let entry = format!(
+                        "{} [{step_id}] {line}\n",
+                        chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f")
+                    );
+                    if let Err(err) = writer.write_all(entry.as_bytes()).await {
+                        tracing::warn!("실행 로그 파일 기록 실패: {err}");
+                        file = None;
+                    }
+                }
+                EngineEvent::StepLog {
+                    step_id,
+                    line: truncate_line(line, max_bytes),
+                }
+            }
+            other => other,
+        };
+        let _ = outer.send(event);
+    }
+    flush_file(&mut file).await;
+}
+
+/// 버퍼에 쌓인 파일 로그를 내보낸다. 실패하면 이후 기록을 멈춘다.
+async fn flush_file(file: &mut Option<BufWriter<tokio::fs::File>>) {
+    if let Some(writer) = file
+        && let Err(err) = writer.flush().await
+    {
+        tracing::warn!("실행 로그 파일 기록 실패: {err}");
+        *file = None;
+    }
+}
+
+/// `max_bytes`를 넘는 줄을 UTF-8 문자 경계에서 자르고 원래 길이와 잘림 표시를 붙인다.
+fn truncate_line(line: String, max_bytes: usize) -> String {
+    if max_bytes == 0 || line.len() <= max_bytes {
+        return line;
+    }
+    let mut end = max_bytes;
+    while !line.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}{}",
+        &line[..end],
+        tf("engine.log.truncated", &[("bytes", &line.len())])
+    )
+}
@@ -0,0 +1,147 @@
+use crate::i18n::tf;
+use crate::scenario::{RunTmpdirConfig, Scenario, TmpdirRetention};
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Step 작업 디렉터리 이름이 겹치지 않도록 붙이는 일련번호이다.
+static STEP_TMPDIR_SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// 한 번의 시나리오 실행에 제공하는 임시 작업 디렉터리이다.
+pub(super) struct RunTmpdir {
+    /// 이번 실행의 디렉터리 경로.
+    path: PathBuf,
+    /// 실행별 디렉터리를 모아 두는 상위 경로.
+    root: PathBuf,
+    /// 같은 시나리오의 디렉터리를 구분하는 이름 접두어.
+    prefix: String,
+    /// 보존 정책.
+    config: RunTmpdirConfig,
+}
+
+impl RunTmpdir {
+    /// 시나리오 설정에 따라 이번 실행의 임시 디렉터리를 만든다.
+    ///
+    /// 디렉터리 이름은 `<시나리오명>-<시작 시각>-<PID>` 형식이라 이름 순서가 곧 생성 순서이다.
+    pub(super) fn create(scenario: &Scenario) -> anyhow::Result<Self> {
+        let config = scenario.run_tmpdir.clone().unwrap_or_default();
+        let root = config
+            .base_dir
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join("rust-airflow"));
+        let prefix = sanitize(&scenario.name);
+        let path = root.join(format!(
+            "{prefix}-{}-{}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S%3f"),
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&path).with_context(|| {
+            tf(
+                "engine.error.run_tmpdir_create",
+                &[("path", &path.display())],
+            )
+        })?;
+        Ok(Self {
+            path,
+            root,
+            prefix,
+            config,
+        })
+    }
+
+    /// 이번 실행의 디렉터리 경로를 반환한다.
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 보존 정책에 따라 디렉터리를 삭제하고, 남겨 둔 이전 디렉터리를 `keep_last`개로 정리한다.
+    ///
+    /// # 매개변수
+    /// - `success`: 모든 Step이 성공했고 중지되지 않았는지 여부.
+    pub(super) fn finish(self, success: bool) {
+        let keep = match self.config.retention {
+            TmpdirRetention::Delete => false,
+            TmpdirRetention::KeepOnFailure => !success,
+            TmpdirRetention::Keep => true,
+        };
+        if keep {
+            tracing::info!("실행 임시 디렉터리 보존: {}", self.path.display());
+        } else {
+            remove_dir(&self.path);
+        }
+        self.prune_kept();
+    }
+
+    /// 이번 실행보다 오래된 같은 시나리오의 디렉터리 중 최근 `keep_last`개만 남긴다.
+    fn prune_kept(&self) {
+        let Some(current) = self.path.file_name().and_then(|name| name.to_str()) else {
+            return;
+        };
+        let Ok(entries) = std::fs::read_dir(&self.root) else {
+            return;
+        };
+        let marker = format!("{}-", self.prefix);
+        let mut older: Vec<PathBuf> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.starts_with(&marker) && name < current)
+            })
+            .map(|entry| entry.path())
+            .collect();
+        older.sort();
+        let kept_current = usize::from(self.path.exists());
+        let allowed = self.config.keep_last.saturating_sub(kept_current);
+        let excess = older.len().saturating_sub(allowed);
+        for path in older.iter().take(excess) {
+            remove_dir(path);
+        }
+    }
+}
+
+/// 실행 임시 디렉터리 아래에 Step 실행 하나의 작업 디렉터리(`steps/<Step ID>-<일련번호>`)를 만든다.
+///
+/// Loop 하위 Step은 반복마다 새 디렉터리를 받는다. 실행 임시 디렉터리 안에 있으므로 그 보존 정책에
+/// 따라 함께 삭제된다.
+pub(super) fn create_step_tmpdir(run_tmpdir: &Path, step_id: &str) -> anyhow::Result<PathBuf> {
+    let seq = STEP_TMPDIR_SEQ.fetch_add(1, Ordering::Relaxed);
+    let path = run_tmpdir
+        .join("steps")
+        .join(format!("{}-{seq:04}", sanitize(step_id)));
+    std::fs::create_dir_all(&path).with_context(|| {
+        tf(
+            "engine.error.step_tmpdir_create",
+            &[("path", &path.display())],
+        )
+    })?;
+    Ok(path)
+}
+
+/// 디렉터리를 삭제하고 실패하면 경고만 남긴다.
+fn remove_dir(path: &Path) {
+    if let Err(err) = std::fs::remove_dir_all(path) {
+        tracing::warn!("실행 임시 디렉터리 삭제 실패({}): {err}", path.display());
+    }
+}
+
+/// 시나리오 이름을 디렉터리 이름에 쓸 수 있는 문자로 바꾼다.
+pub(super) fn sanitize(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if cleaned.is_empty() {
+        "scenario".to_string()
+    } else {
+        cleaned
+    }
+}
@@ -1,32 +1,165 @@
+use super::confirm_bridge::ConfirmMode;
 use super::context::{ExecutionContext, SharedExecutionContext};
+use super::context_file::write_context_file;
+use super::error::EngineError;
 use super::events::EngineEvent;
-use super::resources::prepare_engine_handles;
+use super::journal::{RunJournal, spawn_run_journal};
+use super::log_throttle::{DEFAULT_LOG_LINES_PER_SEC, spawn_log_throttle};
+use super::pool_monitor::spawn_pool_monitor;
+use super::rehearsal::RehearsalPlan;
+use super::resources::{EngineHandleCache, EngineHandles};
+use super::run_log::{DEFAULT_LOG_LINE_MAX_BYTES, spawn_run_log};
+use super::run_tmpdir::RunTmpdir;
+use super::scheduler::DagScheduler;
+use super::secret_mask::spawn_secret_mask;
 use super::state::{ScenarioRuntime, StepStatus};
+use super::step_notify::spawn_step_notify;
 use super::steps::{StepRunResult, run_single_step};
+use super::summary::run_summary_queries;
 use crate::executor::SharedExecutor;
-use crate::scenario::{Scenario, Step};
+use crate::history::{StepRunRecord, append_records, prune_history};
+use crate::i18n::{t, tf};
+use crate::notify::{RunNotification, send_run_notification};
+use crate::scenario::{OnFailureConfig, RUN_TMPDIR_VAR, Scenario, Step};
+use crate::settings::load_app_settings;
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc::UnboundedSender;
-use tokio::time::sleep;
 use tokio_util::sync::CancellationToken;
 
 /// Scenario 전체를 실행하고 이벤트를 송신한다.
+///
+/// DB 핸들은 `handle_cache`에서 얻으며, DB 정의가 직전 실행과 다르면 이 시점에 새로 구성한다.
+/// `rehearsal`이 있으면 계획에 따라 장애를 주입하고, 소요 시간 통계가 흐려지지 않도록 실행 이력을
+/// 남기지 않는다. `journal`이 있으면 비정상 종료 뒤 재개할 수 있도록 Step 종료를 실행 저널에 기록하며,
+/// 리허설에서는 기록하지 않는다. 비밀 변수가 있으면 밖으로 나가는 로그와 메시지에서 그 값을 가린다.
+/// 중지하지 않았으면 메인 DAG가 끝난 뒤(실패해도) `summary` 조회를 실행해 결과를 알린다.
+/// Step 로그는 가린 뒤의 원문을 실행 로그 파일에 남기고, 속도 제한과 한 줄 길이 제한은 UI로 가는
+/// 로그에만 적용한다. 리허설이 아니면 `notify_url`을 지정한 Step의 시작·종료를 그 주소로 알리고,
+/// 끝난 뒤 앱 설정에 따라 이력을 정리하고 웹훅으로 결과를 알린다.
+/// 컨펌 Step은 `confirm_mode`에 따라 묻거나, 기본 응답으로 바로 처리하거나, 반드시 응답을 받는다.
+/// `context_out`이 있으면 리허설이 아닐 때 성공 여부와 관계없이 마지막 컨텍스트 변수를 그 파일에 남긴다.
+/// 파라미터 값이 `param_rules`를 어기면 아무 Step도 실행하지 않고 오류를 반환한다. 임시 디렉터리나
+/// DB 핸들을 준비하지 못해도 `on_failure` 정리 Step은 실행한 뒤 오류를 반환하며, 어느 경우든
+/// `ScenarioFinished`는 정리 Step이 끝난 뒤 마지막으로 한 번만 보낸다.
 pub async fn run_scenario(
     scenario: Scenario,
     executor: SharedExecutor,
+    handle_cache: EngineHandleCache,
     sender: UnboundedSender<EngineEvent>,
     cancel: CancellationToken,
     confirm_bridge: Option<crate::engine::ConfirmBridge>,
+    confirm_mode: ConfirmMode,
+    rehearsal: Option<RehearsalPlan>,
+    journal: Option<RunJournal>,
+    context_out: Option<PathBuf>,
 ) -> anyhow::Result<()> {
-    let ctx: SharedExecutionContext = Arc::new(tokio::sync::RwLock::new(ExecutionContext::new()));
-    let handles = Arc::new(prepare_engine_handles(&scenario, executor, ctx.clone()).await?);
+    if let Err(err) = scenario.check_param_rules() {
+        let _ = sender.send(EngineEvent::ScenarioFinished);
+        return Err(err);
+    }
+    let sender = spawn_log_throttle(
+        sender,
+        scenario
+            .log_lines_per_sec
+            .unwrap_or(DEFAULT_LOG_LINES_PER_SEC),
+    );
+    let sender = spawn_run_log(
+        sender,
+        &scenario.name,
+        scenario
+            .log_line_max_bytes
+            .unwrap_or(DEFAULT_LOG_LINE_MAX_BYTES),
+    );
+    let tmpdir = RunTmpdir::create(&scenario);
+    let mut initial_ctx = ExecutionContext::new();
+    for (key, value) in &scenario.params {
+        initial_ctx.set_var(key.clone(), value.clone());
+    }
+    if let Ok(tmpdir) = &tmpdir {
+        initial_ctx.set_var(RUN_TMPDIR_VAR, tmpdir.path().to_string_lossy());
+    }
+    initial_ctx.set_failure_diagnostics(&scenario.failure_diagnostics);
+    initial_ctx.set_confirm_mode(confirm_mode);
+    initial_ctx.set_explain_slow_sql(scenario.explain_slow_sql_sec.map(Duration::from_secs));
+    let secrets = scenario.secret_vars();
+    let has_secrets = !secrets.is_empty();
+    for key in secrets {
+        initial_ctx.mark_secret(key);
+    }
+    let rehearsing = rehearsal.is_some();
+    if let Some(plan) = rehearsal {
+        initial_ctx.set_rehearsal(plan);
+    }
+    let ctx: SharedExecutionContext = Arc::new(tokio::sync::RwLock::new(initial_ctx));
+    let sender = match journal.filter(|_| !rehearsing) {
+        Some(journal) => spawn_run_journal(sender, journal, ctx.clone()),
+        None => sender,
+    };
+    let sender = if has_secrets {
+        spawn_secret_mask(sender, ctx.clone())
+    } else {
+        sender
+    };
+    let prepared = match tmpdir {
+        Ok(tmpdir) => match handle_cache.acquire(&scenario, executor, ctx.clone()).await {
+            Ok(handles) => Ok((tmpdir, handles)),
+            Err(err) => Err((err, Some(tmpdir))),
+        },
+        Err(err) => Err((err, None)),
+    };
+    let (tmpdir, handles) = match prepared {
+        Ok(prepared) => prepared,
+        Err((err, tmpdir)) => {
+            // 준비 단계 실패도 실패한 실행이므로 정리 Step을 실행한다. DB 핸들이 없으면 DB Step은 실패한다.
+            tracing::error!("실행 준비 실패: {err:#}");
+            if let Some(on_failure) = &scenario.on_failure {
+                run_on_failure_steps(
+                    on_failure,
+                    Arc::new(EngineHandles::default()),
+                    ctx.clone(),
+                    &sender,
+                    confirm_bridge.clone(),
+                )
+                .await;
+            }
+            if let Some(tmpdir) = tmpdir {
+                tmpdir.finish(false);
+            }
+            if !rehearsing {
+                run_post_actions(
+                    &scenario.name,
+                    false,
+                    &HashSet::new(),
+                    cancel.is_cancelled(),
+                )
+                .await;
+            }
+            let _ = sender.send(EngineEvent::ScenarioFinished);
+            return Err(err);
+        }
+    };
+    handles.export_db_vars(&mut *ctx.write().await);
+    let pool_monitor = CancellationToken::new();
+    let pool_monitor_guard = pool_monitor.clone().drop_guard();
+    spawn_pool_monitor(&handles, sender.clone(), pool_monitor);
+    let run_id = chrono::Local::now().format("%Y%m%d%H%M%S%3f").to_string();
+    let sender = if rehearsing {
+        sender
+    } else {
+        spawn_step_notify(sender, &scenario, &run_id, ctx.clone())
+    };
+    if !rehearsing {
+        let _ = sender.send(EngineEvent::RunStarted {
+            run_id: run_id.clone(),
+        });
+    }
     let mut runtime = ScenarioRuntime::new(scenario.clone());
-    let mut started: HashSet<String> = HashSet::new();
-    let mut succeeded: HashSet<String> = HashSet::new();
+    let mut scheduler = DagScheduler::new(&scenario);
     let mut failed: HashSet<String> = HashSet::new();
     type RunningHandle = tokio::task::JoinHandle<(String, StepRunResult)>;
     let mut running_tasks: FuturesUnordered<RunningHandle> = FuturesUnordered::new();
@@ -34,11 +167,37 @@ pub async fn run_scenario(
         if cancel.is_cancelled() {
             break;
         }
-        mark_blocked_steps(&scenario, &mut runtime, &mut started, &mut failed, &sender);
-        let ready_steps = collect_ready_steps(&scenario, &started, &succeeded, &failed);
+        let ready_steps = scheduler.take_ready();
+        if ready_steps.is_empty() {
+            // 준비된 Step이 없으면 병렬 Step 하나가 끝날 때까지 기다리고, 그것도 없으면 DAG가 끝난 것이다.
+            let Some(join_result) = running_tasks.next().await else {
+                break;
+            };
+            let (step_id, run_result) = match join_result {
+                Ok(value) => value,
+                Err(err) => (
+                    "unknown".to_string(),
+                    StepRunResult::Failed {
+                        error: EngineError::Other(err.to_string()),
+                        context: Vec::new(),
+                        diagnostics: Vec::new(),
+                    },
+                ),
+            };
+            apply_result(
+                run_result,
+                &mut runtime,
+                &step_id,
+                &mut scheduler,
+                &mut failed,
+                &sender,
+            );
+            continue;
+        }
         let mut sequential: Vec<Step> = Vec::new();
         let mut parallel: Vec<Step> = Vec::new();
-        for step in ready_steps {
+        for idx in ready_steps {
+            let step = scenario.steps[idx].clone();
             if step.allow_parallel {
                 parallel.push(step);
             } else {
@@ -47,7 +206,6 @@ pub async fn run_scenario(
         }
         for step in sequential {
             let step_id = step.id.clone();
-            started.insert(step_id.clone());
             mark_step_started(&mut runtime, &step_id, &sender);
             let result = run_single_step(
                 step,
@@ -62,14 +220,13 @@ pub async fn run_scenario(
                 result,
                 &mut runtime,
                 &step_id,
-                &mut succeeded,
+                &mut scheduler,
                 &mut failed,
                 &sender,
             );
         }
         for step in parallel {
             let step_id = step.id.clone();
-            started.insert(step_id.clone());
             mark_step_started(&mut runtime, &step_id, &sender);
             let confirm_bridge = confirm_bridge.clone();
             let exec_handles = handles.clone();
@@ -82,33 +239,257 @@ pub async fn run_scenario(
                 (step_id, outcome)
             }));
         }
-        if let Some(join_result) = running_tasks.next().await {
-            let (step_id, run_result) = match join_result {
-                Ok(value) => value,
-                Err(err) => (
-                    "unknown".to_string(),
-                    StepRunResult::Failed(err.to_string()),
-                ),
-            };
+    }
+    // 취소로 빠져나왔으면 병렬 Step이 취소를 반영하고 끝날 때까지 기다려 결과를 이력에 남긴다.
+    while let Some(join_result) = running_tasks.next().await {
+        if let Ok((step_id, run_result)) = join_result {
             apply_result(
                 run_result,
                 &mut runtime,
                 &step_id,
-                &mut succeeded,
+                &mut scheduler,
                 &mut failed,
                 &sender,
             );
-            continue;
         }
-        if runtime.steps_state.len() == succeeded.len() + failed.len() {
-            break;
+    }
+    if !cancel.is_cancelled() {
+        let unreachable = scheduler.take_unreachable();
+        mark_blocked_steps(&unreachable, &mut runtime, &mut failed, &sender);
+    }
+    if !rehearsing {
+        let run_success = failed.is_empty() && !cancel.is_cancelled();
+        let records = collect_history_records(&runtime, &run_id, run_success, &*ctx.read().await);
+        if let Err(err) = append_records(&records).await {
+            tracing::warn!("실행 이력 기록 실패: {err}");
         }
-        sleep(Duration::from_millis(100)).await;
     }
+    if !cancel.is_cancelled() {
+        run_summary_queries(&scenario.summary, handles.clone(), ctx.clone(), &sender).await;
+    }
+    let dag_failed = !failed.is_empty() || cancel.is_cancelled();
+    if dag_failed && let Some(on_failure) = &scenario.on_failure {
+        run_on_failure_steps(
+            on_failure,
+            handles.clone(),
+            ctx.clone(),
+            &sender,
+            confirm_bridge.clone(),
+        )
+        .await;
+    }
+    tmpdir.finish(!dag_failed);
+    if !rehearsing
+        && let Some(path) = &context_out
+        && let Err(err) = write_context_file(path, &ctx).await
+    {
+        tracing::warn!("컨텍스트 파일 기록 실패: {err:#}");
+    }
+    if !rehearsing {
+        run_post_actions(
+            &scenario.name,
+            failed.is_empty() && !cancel.is_cancelled(),
+            &failed,
+            cancel.is_cancelled(),
+        )
+        .await;
+    }
+    drop(pool_monitor_guard);
     let _ = sender.send(EngineEvent::ScenarioFinished);
     Ok(())
 }
 
+/// 앱 설정에 따라 보존 기간이 지난 실행 이력을 지우고 웹훅으로 실행 결과를 알린다.
+///
+/// 알림은 실행 종료를 늦추지 않도록 별도 작업으로 보낸다.
+async fn run_post_actions(
+    scenario_name: &str,
+    success: bool,
+    failed: &HashSet<String>,
+    cancelled: bool,
+) {
+    let settings = match load_app_settings() {
+        Ok(settings) => settings,
+        Err(err) => {
+            tracing::warn!("앱 설정 읽기 실패: {err:#}");
+            return;
+        }
+    };
+    if let Some(days) = settings.history_keep_days {
+        match prune_history(days).await {
+            Ok(0) => {}
+            Ok(removed) => tracing::info!("보존 기간이 지난 실행 이력 {removed}건 삭제"),
+            Err(err) => tracing::warn!("실행 이력 정리 실패: {err}"),
+        }
+    }
+    if settings.notify_endpoints.is_empty() {
+        return;
+    }
+    let mut failed_steps: Vec<String> = failed.iter().cloned().collect();
+    failed_steps.sort();
+    let notification = RunNotification {
+        scenario: scenario_name.to_string(),
+        success,
+        cancelled,
+        failed_steps,
+        finished_at: chrono::Local::now().to_rfc3339(),
+    };
+    let endpoints = settings.notify_endpoints;
+    tokio::spawn(async move {
+        send_run_notification(&endpoints, &notification).await;
+    });
+}
+
+/// 메인 DAG가 실패하거나 취소된 뒤 `on_failure` Step을 의존성 순서대로 실행한다.
+///
+/// 메인 실행과 분리된 취소 토큰을 쓰므로 중지된 뒤에도 실행된다. `timeout_sec`이 지나면 실행 중인
+/// Step은 시간 초과로 실패 처리하고 남은 Step은 건너뛴다. 정리 Step 하나가 실패해도 그 Step에
+/// 의존하지 않는 나머지 Step은 계속 실행한다. 정리 Step의 컨펌은 참관 실행에서도 제한 시간 뒤 기본 응답으로
+/// 처리한다. 승인 게이트는 메인 실행과 같은 브리지로 승인받으며, 승인이 없으면 `timeout_sec`에서 끝난다.
+async fn run_on_failure_steps(
+    config: &OnFailureConfig,
+    handles: Arc<EngineHandles>,
+    ctx: SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+    confirm_bridge: Option<crate::engine::ConfirmBridge>,
+) {
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(config.timeout_sec.max(1));
+    {
+        let mut guard = ctx.write().await;
+        if guard.confirm_mode() == ConfirmMode::Attended {
+            guard.set_confirm_mode(ConfirmMode::Prompt);
+        }
+    }
+    let cancel = CancellationToken::new();
+    let mut finished: HashSet<String> = HashSet::new();
+    let mut succeeded: HashSet<String> = HashSet::new();
+    loop {
+        let ready: Vec<&Step> = config
+            .steps
+            .iter()
+            .filter(|step| {
+                !finished.contains(&step.id)
+                    && step.depends_on.iter().all(|dep| finished.contains(dep))
+            })
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        for step in ready {
+            finished.insert(step.id.clone());
+            if tokio::time::Instant::now() >= deadline {
+                skip_cleanup_step(sender, &step.id, "engine.log.on_failure_expired");
+                continue;
+            }
+            if !step.depends_on.iter().all(|dep| succeeded.contains(dep)) {
+                skip_cleanup_step(sender, &step.id, "engine.log.blocked");
+                continue;
+            }
+            let _ = sender.send(EngineEvent::StepStarted {
+                step_id: step.id.clone(),
+            });
+            let run = run_single_step(
+                step.clone(),
+                handles.clone(),
+                ctx.clone(),
+                sender.clone(),
+                cancel.clone(),
+                confirm_bridge.clone(),
+            );
+            let (error, context) = match tokio::time::timeout_at(deadline, run).await {
+                Ok(StepRunResult::Success | StepRunResult::Skipped) => {
+                    succeeded.insert(step.id.clone());
+                    let _ = sender.send(EngineEvent::StepFinished {
+                        step_id: step.id.clone(),
+                        success: true,
+                        error: None,
+                        context: None,
+                    });
+                    continue;
+                }
+                Ok(StepRunResult::Failed { error, context, .. }) => (error, context),
+                Err(_) => {
+                    let _ = sender.send(EngineEvent::StepLog {
+                        step_id: step.id.clone(),
+                        line: tf(
+                            "engine.log.on_failure_timeout",
+                            &[("secs", &config.timeout_sec)],
+                        ),
+                    });
+                    (EngineError::Timeout, ctx.read().await.snapshot())
+                }
+            };
+            let _ = sender.send(EngineEvent::StepLog {
+                step_id: step.id.clone(),
+                line: error.to_string(),
+            });
+            let _ = sender.send(EngineEvent::StepFinished {
+                step_id: step.id.clone(),
+                success: false,
+                error: Some(error),
+                context: Some(context),
+            });
+        }
+    }
+    for step in &config.steps {
+        if !finished.contains(&step.id) {
+            skip_cleanup_step(sender, &step.id, "engine.log.blocked");
+        }
+    }
+}
+
+/// 실행하지 않은 정리 Step을 건너뜀으로 알린다.
+fn skip_cleanup_step(sender: &UnboundedSender<EngineEvent>, step_id: &str, reason: &'static str) {
+    let _ = sender.send(EngineEvent::StepLog {
+        step_id: step_id.to_string(),
+        line: t(reason).into(),
+    });
+    let _ = sender.send(EngineEvent::StepBlocked {
+        step_id: step_id.to_string(),
+    });
+}
+
+/// 실제로 실행되어 끝난 Step의 이력 레코드를 만든다. 취소되었거나 건너뛴 Step은 제외한다.
+///
+/// `sql_file` Step은 실행한 파일 내용의 해시(`STEP.<id>.sql_sha256`)도 함께 남긴다. 모든 레코드에
+/// 실행 전체의 결과 `run_success`를 남겨, 취소로 일부 Step만 성공한 실행을 성공으로 보지 않게 한다.
+fn collect_history_records(
+    runtime: &ScenarioRuntime,
+    run_id: &str,
+    run_success: bool,
+    ctx: &ExecutionContext,
+) -> Vec<StepRunRecord> {
+    let now = std::time::Instant::now();
+    let wall_now = chrono::Local::now();
+    runtime
+        .scenario
+        .steps
+        .iter()
+        .filter_map(|step| {
+            let state = runtime.steps_state.get(&step.id)?;
+            let (started, finished) = (state.started_at?, state.finished_at?);
+            if state.skipped || matches!(state.error, Some(EngineError::Cancelled)) {
+                return None;
+            }
+            let since_finish =
+                chrono::Duration::from_std(now.duration_since(finished)).unwrap_or_default();
+            Some(StepRunRecord {
+                scenario: runtime.scenario.name.clone(),
+                step_id: step.id.clone(),
+                success: matches!(state.status, StepStatus::Success),
+                duration_ms: finished.duration_since(started).as_millis() as u64,
+                finished_at: (wall_now - since_finish).to_rfc3339(),
+                run_id: Some(run_id.to_string()),
+                diagnostics: state.diagnostics.clone(),
+                sql_sha256: ctx
+                    .get_var(&format!("STEP.{}.sql_sha256", step.id))
+                    .map(str::to_string),
+                run_success: Some(run_success),
+            })
+        })
+        .collect()
+}
+
 /// Step이 시작될 때 상태와 이벤트를 갱신한다.
 fn mark_step_started(
     runtime: &mut ScenarioRuntime,
@@ -124,63 +505,71 @@ fn mark_step_started(
     });
 }
 
-/// 선행 Step 실패로 인해 더 이상 실행할 수 없는 Step을 실패 처리한다.
+/// 선행 Step 실패로 인해 더 이상 실행할 수 없는 Step을 건너뜀으로 처리한다.
+///
+/// 건너뛴 Step도 `failed`에 넣어 실패한 Step과 함께 실행 결과에 반영한다.
 fn mark_blocked_steps(
-    scenario: &Scenario,
+    step_ids: &[String],
     runtime: &mut ScenarioRuntime,
-    started: &mut HashSet<String>,
     failed: &mut HashSet<String>,
     sender: &UnboundedSender<EngineEvent>,
 ) {
-    for step in &scenario.steps {
-        if started.contains(&step.id) {
-            continue;
-        }
-        if step.depends_on.iter().any(|dep| failed.contains(dep)) {
-            started.insert(step.id.clone());
-            failed.insert(step.id.clone());
-            if let Some(state) = runtime.steps_state.get_mut(&step.id) {
-                state.status = StepStatus::Failed("선행 Step 실패로 건너뜀".into());
-                state.finished_at = Some(std::time::Instant::now());
-            }
-            let _ = sender.send(EngineEvent::StepLog {
-                step_id: step.id.clone(),
-                line: "선행 Step 실패로 인해 실행하지 않습니다.".into(),
-            });
-            let _ = sender.send(EngineEvent::StepFinished {
-                step_id: step.id.clone(),
-                success: false,
-            });
+    for step_id in step_ids {
+        failed.insert(step_id.clone());
+        if let Some(state) = runtime.steps_state.get_mut(step_id) {
+            state.status = StepStatus::Blocked;
+            state.finished_at = Some(std::time::Instant::now());
         }
+        let _ = sender.send(EngineEvent::StepLog {
+            step_id: step_id.clone(),
+            line: t("engine.log.blocked").into(),
+        });
+        let _ = sender.send(EngineEvent::StepBlocked {
+            step_id: step_id.clone(),
+        });
     }
 }
 
 /// Step 실행 결과를 반영하고 이벤트를 송신한다.
+///
+/// 성공하면 하위 Step을 준비 대기열에 넣고, 실패하면 그 때문에 실행할 수 없게 된 하위 Step을 바로
+/// 건너뜀으로 처리한다.
 fn apply_result(
     result: StepRunResult,
     runtime: &mut ScenarioRuntime,
     step_id: &str,
-    succeeded: &mut HashSet<String>,
+    scheduler: &mut DagScheduler,
     failed: &mut HashSet<String>,
     sender: &UnboundedSender<EngineEvent>,
 ) {
     match result {
-        StepRunResult::Success => {
-            succeeded.insert(step_id.to_string());
+        StepRunResult::Success | StepRunResult::Skipped => {
+            scheduler.mark_succeeded(step_id);
             if let Some(state) = runtime.steps_state.get_mut(step_id) {
                 state.status = StepStatus::Success;
                 state.finished_at = Some(std::time::Instant::now());
+                state.skipped = matches!(result, StepRunResult::Skipped);
             }
             let _ = sender.send(EngineEvent::StepFinished {
                 step_id: step_id.to_string(),
                 success: true,
+                error: None,
+                context: None,
             });
         }
-        StepRunResult::Failed(msg) => {
+        StepRunResult::Failed {
+            error: err,
+            context,
+            diagnostics,
+        } => {
+            let msg = err.to_string();
             failed.insert(step_id.to_string());
             if let Some(state) = runtime.steps_state.get_mut(step_id) {
                 state.status = StepStatus::Failed(msg.clone());
                 state.finished_at = Some(std::time::Instant::now());
+                state.error = Some(err.clone());
+                state.context_snapshot = Some(context.clone());
+                state.diagnostics = diagnostics;
             }
             let _ = sender.send(EngineEvent::StepLog {
                 step_id: step_id.to_string(),
@@ -189,26 +578,11 @@ fn apply_result(
             let _ = sender.send(EngineEvent::StepFinished {
                 step_id: step_id.to_string(),
                 success: false,
+                error: Some(err),
+                context: Some(context),
             });
+            let blocked = scheduler.mark_failed(step_id);
+            mark_blocked_steps(&blocked, runtime, failed, sender);
         }
     }
 }
-
-/// 의존성이 모두 충족된 Step을 추출한다.
-fn collect_ready_steps(
-    scenario: &Scenario,
-    started: &HashSet<String>,
-    succeeded: &HashSet<String>,
-    failed: &HashSet<String>,
-) -> Vec<Step> {
-    scenario
-        .steps
-        .iter()
-        .filter(|step| {
-            !started.contains(&step.id)
-                && step.depends_on.iter().all(|dep| succeeded.contains(dep))
-                && step.depends_on.iter().all(|dep| !failed.contains(dep))
-        })
-        .cloned()
-        .collect()
-}
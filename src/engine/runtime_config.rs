@@ -0,0 +1,71 @@
+use tokio::runtime::{Builder, Runtime};
+
+/// 엔진 워커 스레드 수를 지정하는 환경 변수 이름이다.
+pub const ENGINE_WORKERS_ENV: &str = "RUST_AIRFLOW_ENGINE_WORKERS";
+/// 엔진 블로킹 스레드 풀 상한을 지정하는 환경 변수 이름이다.
+pub const ENGINE_BLOCKING_ENV: &str = "RUST_AIRFLOW_ENGINE_BLOCKING_THREADS";
+
+/// 엔진 전용 Tokio 런타임 구성이다.
+///
+/// 시나리오 실행과 DB 핸들 구성은 이 런타임에서만 돌리므로, 병렬 Step이 많아도 UI 쪽 작업(데몬 접속 등)과
+/// 워커를 나눠 쓰지 않는다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EngineRuntimeConfig {
+    /// 워커 스레드 수. `None`이면 CPU 코어 수를 쓴다.
+    pub worker_threads: Option<usize>,
+    /// 블로킹 스레드 풀 상한. `None`이면 Tokio 기본값(512)을 쓴다.
+    pub max_blocking_threads: Option<usize>,
+}
+
+impl EngineRuntimeConfig {
+    /// 명령줄 인자와 환경 변수에서 구성을 읽는다.
+    ///
+    /// `--engine-workers <N>`, `--engine-blocking-threads <N>`(`=N` 형식도 허용)이 환경 변수
+    /// [`ENGINE_WORKERS_ENV`], [`ENGINE_BLOCKING_ENV`]보다 우선한다. 0이나 숫자가 아닌 값은 무시한다.
+    pub fn from_args(args: &[String]) -> Self {
+        let read = |flag: &str, env: &str| {
+            flag_value(args, flag)
+                .or_else(|| std::env::var(env).ok())
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .filter(|value| *value > 0)
+        };
+        Self {
+            worker_threads: read("--engine-workers", ENGINE_WORKERS_ENV),
+            max_blocking_threads: read("--engine-blocking-threads", ENGINE_BLOCKING_ENV),
+        }
+    }
+
+    /// 인자와 환경 변수로 지정하지 않은 값을 앱 설정의 기본값으로 채운다.
+    pub fn or_defaults(self, workers: Option<usize>, blocking: Option<usize>) -> Self {
+        Self {
+            worker_threads: self.worker_threads.or(workers.filter(|v| *v > 0)),
+            max_blocking_threads: self.max_blocking_threads.or(blocking.filter(|v| *v > 0)),
+        }
+    }
+
+    /// 구성대로 엔진 전용 멀티 스레드 런타임을 만든다.
+    pub fn build(&self) -> std::io::Result<Runtime> {
+        let mut builder = Builder::new_multi_thread();
+        builder.enable_all().thread_name("engine-worker");
+        if let Some(workers) = self.worker_threads {
+            builder.worker_threads(workers.max(1));
+        }
+        if let Some(blocking) = self.max_blocking_threads {
+            builder.max_blocking_threads(blocking.max(1));
+        }
+        builder.build()
+    }
+}
+
+/// `--flag value` 또는 `--flag=value` 형식의 인자 값을 찾는다.
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter().enumerate().find_map(|(idx, arg)| {
+        if arg == flag {
+            args.get(idx + 1).cloned()
+        } else {
+            arg.strip_prefix(flag)
+                .and_then(|rest| rest.strip_prefix('='))
+                .map(str::to_string)
+        }
+    })
+}
@@ -0,0 +1,137 @@
+use crate::scenario::Scenario;
+use std::collections::{BTreeSet, HashMap};
+
+/// 남은 선행 Step 수와 준비 대기열로 다음에 실행할 Step을 고르는 DAG 스케줄러이다.
+///
+/// Step이 끝날 때마다 그 Step에 의존하는 Step만 갱신하므로 매번 전체 Step을 다시 훑지 않는다.
+/// Step은 시나리오의 `steps` 순서 인덱스로 다루며, 준비된 Step은 그 순서대로 꺼낸다. 빌더의 실행 순서
+/// 시뮬레이션도 실제 실행과 같은 순서를 내도록 이 스케줄러를 쓴다.
+pub(crate) struct DagScheduler {
+    /// Step ID별 인덱스.
+    index: HashMap<String, usize>,
+    /// 인덱스별 Step ID.
+    ids: Vec<String>,
+    /// 아직 성공하지 않은 선행 Step 수.
+    pending_deps: Vec<usize>,
+    /// 인덱스별로 그 Step에 의존하는 Step 인덱스.
+    dependents: Vec<Vec<usize>>,
+    /// 선행 Step이 모두 성공해 실행을 기다리는 Step.
+    ready: BTreeSet<usize>,
+    /// 시작했거나 건너뜀으로 처리해 더 이상 고르지 않는 Step.
+    settled: Vec<bool>,
+}
+
+impl DagScheduler {
+    /// 시나리오의 의존성으로 스케줄러를 만든다. 선행 Step이 없는 Step은 바로 준비 대기열에 넣는다.
+    ///
+    /// 같은 선행 Step을 여러 번 적어도 한 번으로 센다.
+    pub(super) fn new(scenario: &Scenario) -> Self {
+        Self::from_dependencies(
+            scenario
+                .steps
+                .iter()
+                .map(|step| (step.id.clone(), step.depends_on.clone())),
+        )
+    }
+
+    /// `(Step ID, 선행 Step ID 목록)`을 Step 순서대로 받아 스케줄러를 만든다.
+    pub(crate) fn from_dependencies(
+        steps: impl IntoIterator<Item = (String, Vec<String>)>,
+    ) -> Self {
+        let (ids, depends_on): (Vec<String>, Vec<Vec<String>>) = steps.into_iter().unzip();
+        let index: HashMap<String, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(idx, id)| (id.clone(), idx))
+            .collect();
+        let mut pending_deps = vec![0; ids.len()];
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); ids.len()];
+        for (idx, deps) in depends_on.iter().enumerate() {
+            let mut deps: Vec<&String> = deps.iter().collect();
+            deps.sort();
+            deps.dedup();
+            pending_deps[idx] = deps.len();
+            for dep in deps {
+                // 없는 Step에 의존하면 수가 줄지 않아 끝까지 준비되지 않는다.
+                if let Some(&dep_idx) = index.get(dep) {
+                    dependents[dep_idx].push(idx);
+                }
+            }
+        }
+        let ready = (0..ids.len())
+            .filter(|&idx| pending_deps[idx] == 0)
+            .collect();
+        Self {
+            index,
+            settled: vec![false; ids.len()],
+            ids,
+            pending_deps,
+            dependents,
+            ready,
+        }
+    }
+
+    /// 준비 대기열을 비우고 꺼낸 Step 인덱스를 시나리오 순서로 반환한다. 꺼낸 Step은 시작한 것으로 본다.
+    pub(crate) fn take_ready(&mut self) -> Vec<usize> {
+        let ready: Vec<usize> = std::mem::take(&mut self.ready).into_iter().collect();
+        for &idx in &ready {
+            self.settled[idx] = true;
+        }
+        ready
+    }
+
+    /// Step 성공을 반영한다. 남은 선행 Step이 없어진 하위 Step을 준비 대기열에 넣는다.
+    pub(crate) fn mark_succeeded(&mut self, step_id: &str) {
+        let Some(&idx) = self.index.get(step_id) else {
+            return;
+        };
+        for &dependent in &self.dependents[idx] {
+            self.pending_deps[dependent] = self.pending_deps[dependent].saturating_sub(1);
+            if self.pending_deps[dependent] == 0 && !self.settled[dependent] {
+                self.ready.insert(dependent);
+            }
+        }
+    }
+
+    /// Step 실패를 반영하고, 그 때문에 더 이상 실행할 수 없게 된 하위 Step ID를 시나리오 순서로 반환한다.
+    ///
+    /// 하위 Step의 하위 Step까지 연쇄적으로 포함하며, 반환한 Step은 다시 고르지 않는다.
+    pub(crate) fn mark_failed(&mut self, step_id: &str) -> Vec<String> {
+        let Some(&idx) = self.index.get(step_id) else {
+            return Vec::new();
+        };
+        let mut blocked: Vec<usize> = Vec::new();
+        let mut stack = vec![idx];
+        while let Some(current) = stack.pop() {
+            for &dependent in &self.dependents[current] {
+                if self.settled[dependent] {
+                    continue;
+                }
+                self.settled[dependent] = true;
+                self.ready.remove(&dependent);
+                blocked.push(dependent);
+                stack.push(dependent);
+            }
+        }
+        blocked.sort_unstable();
+        blocked
+            .into_iter()
+            .map(|idx| self.ids[idx].clone())
+            .collect()
+    }
+
+    /// 한 번도 고르지 않은 Step ID를 시나리오 순서로 반환하고 더 이상 고르지 않게 한다.
+    ///
+    /// 실행 중인 Step과 준비된 Step이 모두 없는데도 남은 Step은 끝까지 실행할 수 없는 Step이다.
+    pub(crate) fn take_unreachable(&mut self) -> Vec<String> {
+        let mut rest = Vec::new();
+        for (idx, settled) in self.settled.iter_mut().enumerate() {
+            if !*settled {
+                *settled = true;
+                rest.push(self.ids[idx].clone());
+            }
+        }
+        self.ready.clear();
+        rest
+    }
+}
@@ -0,0 +1,67 @@
+use super::context::{ExecutionContext, SharedExecutionContext};
+use super::resources::build_target_executor;
+use crate::executor::{ColumnInfo, SharedExecutor};
+use crate::i18n::tf;
+use crate::scenario::Scenario;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// 스키마 조회 한 번에 허용하는 최대 실행 시간이다.
+const SCHEMA_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 편집 중인 시나리오의 DB 정의로 `target_db`의 테이블·뷰 이름 목록을 가져온다.
+///
+/// # 매개변수
+/// - `scenario`: 편집 중인 DB 정의와 파라미터를 담은 시나리오.
+/// - `target_db`: 조회할 DB 타겟 키.
+/// - `default_executor`: 시나리오에 `default` 정의가 없을 때 쓸 기본 실행기.
+pub async fn fetch_schema_tables(
+    scenario: &Scenario,
+    target_db: &str,
+    default_executor: SharedExecutor,
+) -> anyhow::Result<Vec<String>> {
+    let executor = schema_executor(scenario, target_db, default_executor).await?;
+    with_schema_timeout(executor.list_tables()).await
+}
+
+/// 편집 중인 시나리오의 DB 정의로 `target_db`에 있는 테이블 하나의 컬럼 목록을 가져온다.
+///
+/// `table`은 [`fetch_schema_tables`]가 돌려준 이름이다. 나머지 매개변수는 [`fetch_schema_tables`]와 같다.
+pub async fn fetch_schema_columns(
+    scenario: &Scenario,
+    target_db: &str,
+    table: &str,
+    default_executor: SharedExecutor,
+) -> anyhow::Result<Vec<ColumnInfo>> {
+    let executor = schema_executor(scenario, target_db, default_executor).await?;
+    with_schema_timeout(executor.list_columns(table)).await
+}
+
+/// 시나리오 파라미터만 넣은 컨텍스트로 DB 타겟 실행기를 만든다.
+async fn schema_executor(
+    scenario: &Scenario,
+    target_db: &str,
+    default_executor: SharedExecutor,
+) -> anyhow::Result<SharedExecutor> {
+    let mut initial_ctx = ExecutionContext::new();
+    for (key, value) in &scenario.params {
+        initial_ctx.set_var(key.clone(), value.clone());
+    }
+    let ctx: SharedExecutionContext = Arc::new(RwLock::new(initial_ctx));
+    build_target_executor(scenario, target_db, default_executor, ctx).await
+}
+
+/// 메타데이터 조회에 [`SCHEMA_FETCH_TIMEOUT`]을 적용한다.
+async fn with_schema_timeout<T>(
+    fetch: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    match tokio::time::timeout(SCHEMA_FETCH_TIMEOUT, fetch).await {
+        Ok(result) => result,
+        Err(_) => anyhow::bail!(tf(
+            "engine.error.schema_timeout",
+            &[("secs", &SCHEMA_FETCH_TIMEOUT.as_secs())]
+        )),
+    }
+}
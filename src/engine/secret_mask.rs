@@ -0,0 +1,85 @@
+use super::context::SharedExecutionContext;
+use super::events::EngineEvent;
+use super::summary::SummaryResult;
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+/// 엔진 이벤트의 사람이 읽는 문자열에서 비밀 변수 값을 가린 뒤 `outer`로 전달하는 송신자를 만든다.
+///
+/// Step 로그, 실패 오류 메시지, 컨펌·승인 안내 문구, 결과 요약 값이 대상이다. 실행하는 명령과 SQL에는 실제 값이
+/// 그대로 들어가고, 밖으로 나가는 이벤트만 가린다. 컨텍스트 사본은
+/// [`ExecutionContext::snapshot`](super::context::ExecutionContext::snapshot)이 이미 가린다.
+pub(super) fn spawn_secret_mask(
+    outer: UnboundedSender<EngineEvent>,
+    ctx: SharedExecutionContext,
+) -> UnboundedSender<EngineEvent> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            let event = {
+                let guard = ctx.read().await;
+                mask_event(event, |text| guard.mask(text))
+            };
+            if outer.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    tx
+}
+
+/// 이벤트 하나의 표시용 문자열에 `mask`를 적용한다.
+fn mask_event(event: EngineEvent, mask: impl Fn(&str) -> String) -> EngineEvent {
+    match event {
+        EngineEvent::StepLog { step_id, line } => EngineEvent::StepLog {
+            step_id,
+            line: mask(&line),
+        },
+        EngineEvent::StepFinished {
+            step_id,
+            success,
+            error,
+            context,
+        } => EngineEvent::StepFinished {
+            step_id,
+            success,
+            error: error.map(|error| error.masked(&mask)),
+            context,
+        },
+        EngineEvent::RequestConfirm {
+            request_id,
+            step_id,
+            step_name,
+            step_kind,
+            summary,
+            message,
+            default_answer,
+            phase,
+        } => EngineEvent::RequestConfirm {
+            request_id,
+            step_id,
+            step_name,
+            step_kind,
+            summary: summary.map(|text| mask(&text)),
+            message: message.map(|text| mask(&text)),
+            default_answer,
+            phase,
+        },
+        EngineEvent::StepAwaitingApproval { step_id, message } => {
+            EngineEvent::StepAwaitingApproval {
+                step_id,
+                message: message.map(|text| mask(&text)),
+            }
+        }
+        EngineEvent::RunSummary { results } => EngineEvent::RunSummary {
+            results: results
+                .into_iter()
+                .map(|result| SummaryResult {
+                    value: result.value.map(|text| mask(&text)),
+                    error: result.error.map(|text| mask(&text)),
+                    ..result
+                })
+                .collect(),
+        },
+        other => other,
+    }
+}
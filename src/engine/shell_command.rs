@@ -0,0 +1,32 @@
+use crate::i18n::tf;
+
+/// 셸 스크립트를 실행할 프로그램과 스크립트를 넘기는 인자를 정한다.
+///
+/// `program`이 없으면 로컬 Windows에서는 `cmd`, 그 밖(원격 실행 포함)에서는 `sh`를 쓴다. 스크립트
+/// 인자는 로컬 Windows에서 `/C`, 그 밖에서 `-c`이다.
+///
+/// # 반환값
+/// `(프로그램, 스크립트 인자)`.
+pub(super) fn shell_invocation(program: Option<&str>, remote: bool) -> (String, &'static str) {
+    let windows = cfg!(target_os = "windows") && !remote;
+    let program = program
+        .map(str::to_string)
+        .unwrap_or_else(|| if windows { "cmd" } else { "sh" }.to_string());
+    (program, if windows { "/C" } else { "-c" })
+}
+
+/// 출력이 `max_bytes`를 넘으면 문자 경계에서 잘라 잘림 표시를 붙인다.
+pub(super) fn truncate_output(text: &str, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text.to_string();
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}\n{}",
+        &text[..end],
+        tf("engine.output.truncated", &[("bytes", &text.len())])
+    )
+}
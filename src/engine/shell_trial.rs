@@ -0,0 +1,119 @@
+use super::context::ExecutionContext;
+use super::shell_command::{shell_invocation, truncate_output};
+use super::steps::ESSENTIAL_ENV_VARS;
+use crate::i18n::tf;
+use crate::scenario::{RUN_TMPDIR_VAR, STEP_TMPDIR_VAR, ShellConfig};
+use anyhow::Context;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// 로컬 셸 테스트 실행에 허용하는 최대 실행 시간이다.
+const SHELL_TRIAL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 로컬 셸 테스트 결과에 남기는 최대 출력 바이트 수이다. 넘는 부분은 잘라 낸다.
+const SHELL_TRIAL_OUTPUT_MAX_BYTES: usize = 16 * 1024;
+
+/// 로컬 셸 테스트 실행 결과이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellTrialOutput {
+    /// 종료 코드. 시그널로 끝나 코드가 없으면 `None`이다.
+    pub exit_code: Option<i32>,
+    /// 표준 출력 뒤에 표준 오류를 이어 붙인 출력.
+    pub output: String,
+}
+
+/// Shell Step 스크립트를 더미 변수 값으로 치환해 임시 샌드박스 디렉터리에서 로컬로 한 번 실행한다.
+///
+/// 원격 호스트, 실행 사용자, 작업 디렉터리, 오류 정책은 무시하고 샌드박스를 작업 디렉터리로 쓴다.
+/// `${RUN_TMPDIR}`와 `${STEP_TMPDIR}`도 샌드박스 경로로 치환하며, 샌드박스는 실행이 끝나면 지운다.
+///
+/// # 매개변수
+/// - `config`: 테스트할 Shell 구성.
+/// - `vars`: 플레이스홀더에 넣을 `(변수명, 더미 값)` 목록. 목록에 없으면 환경 변수로 치환한다.
+///
+/// # 반환값
+/// 프로세스가 끝나면 종료 코드와 출력. 치환·실행에 실패하거나 제한 시간을 넘기면 오류를 반환한다.
+pub async fn run_shell_trial(
+    config: &ShellConfig,
+    vars: &[(String, String)],
+) -> anyhow::Result<ShellTrialOutput> {
+    let sandbox = create_sandbox()?;
+    let result = run_in_sandbox(config, vars, &sandbox).await;
+    let _ = std::fs::remove_dir_all(&sandbox);
+    result
+}
+
+/// 실행마다 다른 샌드박스 디렉터리를 시스템 임시 디렉터리 아래에 만든다.
+fn create_sandbox() -> anyhow::Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!(
+        "rust-airflow-shell-trial-{}-{}",
+        std::process::id(),
+        chrono::Local::now().format("%Y%m%d%H%M%S%3f")
+    ));
+    std::fs::create_dir_all(&dir)
+        .with_context(|| tf("engine.error.sandbox_create", &[("path", &dir.display())]))?;
+    Ok(dir)
+}
+
+/// 샌드박스에서 스크립트를 실행하고 출력을 모은다.
+async fn run_in_sandbox(
+    config: &ShellConfig,
+    vars: &[(String, String)],
+    sandbox: &std::path::Path,
+) -> anyhow::Result<ShellTrialOutput> {
+    let mut ctx = ExecutionContext::new();
+    let sandbox_text = sandbox.to_string_lossy().into_owned();
+    ctx.set_var(RUN_TMPDIR_VAR, sandbox_text.clone());
+    ctx.set_var(STEP_TMPDIR_VAR, sandbox_text);
+    for (key, value) in vars {
+        ctx.set_var(key.clone(), value.clone());
+    }
+    let script = ctx.expand_required(&config.script, "shell.command")?;
+    let mut args = Vec::with_capacity(config.shell_args.len());
+    for arg in &config.shell_args {
+        args.push(ctx.expand_required(arg, "shell.arg")?);
+    }
+    let mut env = Vec::with_capacity(config.env.len());
+    for (key, value) in &config.env {
+        env.push((key.clone(), ctx.expand_required(value, "shell.env")?));
+    }
+
+    let (program, script_flag) = shell_invocation(config.shell_program.as_deref(), false);
+    let mut command = Command::new(&program);
+    command.arg(script_flag);
+    command.arg(&script);
+    command.args(&args);
+    command.current_dir(sandbox);
+    if !config.inherit_env {
+        command.env_clear();
+        command.envs(
+            ESSENTIAL_ENV_VARS
+                .iter()
+                .filter_map(|key| std::env::var(key).ok().map(|value| (*key, value))),
+        );
+    }
+    command.envs(env);
+    command.kill_on_drop(true);
+    let output = tokio::time::timeout(SHELL_TRIAL_TIMEOUT, command.output())
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(tf(
+                "engine.error.shell_trial_timeout",
+                &[("secs", &SHELL_TRIAL_TIMEOUT.as_secs())]
+            ))
+        })?
+        .with_context(|| tf("engine.error.shell_spawn", &[("program", &program)]))?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.is_empty() {
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(&stderr);
+    }
+    Ok(ShellTrialOutput {
+        exit_code: output.status.code(),
+        output: truncate_output(text.trim_end(), SHELL_TRIAL_OUTPUT_MAX_BYTES),
+    })
+}
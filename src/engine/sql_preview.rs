@@ -0,0 +1,89 @@
+use super::context::{ExecutionContext, SharedExecutionContext};
+use super::resources::build_target_executor;
+use crate::executor::{QueryRows, SharedExecutor, has_statement_separator};
+use crate::i18n::{t, tf};
+use crate::scenario::Scenario;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// SQL 미리보기로 가져오는 최대 행 수이다.
+pub const SQL_PREVIEW_MAX_ROWS: usize = 100;
+
+/// SQL 미리보기 조회에 허용하는 최대 실행 시간이다.
+const SQL_PREVIEW_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 편집 중인 시나리오의 DB 정의로 조회 SQL을 읽기 전용으로 실행해 앞부분 행을 가져온다.
+///
+/// 변경 문장을 실수로 실행하지 않도록 `SELECT`나 `WITH`로 시작하는 단일 문장만 받고, 실행기는
+/// 읽기 전용 트랜잭션에서 인라인 뷰로 감싸 [`SQL_PREVIEW_MAX_ROWS`]행까지만 조회한다. 플레이스홀더는
+/// 시나리오 파라미터로만 치환하므로 상위 Step이 만드는 변수를 쓰는 SQL은 미리 볼 수 없다.
+///
+/// # 매개변수
+/// - `scenario`: 편집 중인 DB 정의와 파라미터를 담은 시나리오.
+/// - `target_db`: 조회할 DB 타겟. `None`이면 기본 DB이다.
+/// - `sql`: 미리 볼 조회 SQL.
+/// - `default_executor`: 시나리오에 `default` 정의가 없을 때 쓸 기본 실행기.
+pub async fn preview_sql(
+    scenario: &Scenario,
+    target_db: Option<&str>,
+    sql: &str,
+    default_executor: SharedExecutor,
+) -> anyhow::Result<QueryRows> {
+    let mut initial_ctx = ExecutionContext::new();
+    for (key, value) in &scenario.params {
+        initial_ctx.set_var(key.clone(), value.clone());
+    }
+    let statement = initial_ctx.expand_required(sql, "sql")?;
+    let statement = read_only_statement(&statement)?;
+    let ctx: SharedExecutionContext = Arc::new(RwLock::new(initial_ctx));
+    let target = target_db.unwrap_or("default");
+    let executor = build_target_executor(scenario, target, default_executor, ctx).await?;
+    match tokio::time::timeout(
+        SQL_PREVIEW_TIMEOUT,
+        executor.query_rows(statement, SQL_PREVIEW_MAX_ROWS),
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(_) => anyhow::bail!(tf(
+            "engine.error.preview_timeout",
+            &[("secs", &SQL_PREVIEW_TIMEOUT.as_secs())]
+        )),
+    }
+}
+
+/// 앞쪽 주석과 끝의 `;`·`/`를 떼고, `SELECT`/`WITH`로 시작하는 단일 문장인지 확인한다.
+///
+/// 문자열·주석 안의 `;`는 문장 구분자로 보지 않는다.
+fn read_only_statement(sql: &str) -> anyhow::Result<&str> {
+    let mut rest = sql.trim();
+    loop {
+        if let Some(line) = rest.strip_prefix("--") {
+            rest = line
+                .split_once('\n')
+                .map_or("", |(_, tail)| tail)
+                .trim_start();
+        } else if let Some(block) = rest.strip_prefix("/*") {
+            rest = block
+                .split_once("*/")
+                .map_or("", |(_, tail)| tail)
+                .trim_start();
+        } else {
+            break;
+        }
+    }
+    let statement = rest.trim_end_matches(['/', ';']).trim_end();
+    let keyword: String = statement
+        .chars()
+        .take_while(|ch| ch.is_ascii_alphabetic())
+        .collect::<String>()
+        .to_ascii_uppercase();
+    if keyword != "SELECT" && keyword != "WITH" {
+        anyhow::bail!(t("engine.error.preview_read_only"));
+    }
+    if has_statement_separator(statement) {
+        anyhow::bail!(t("engine.error.preview_single"));
+    }
+    Ok(statement)
+}
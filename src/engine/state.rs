@@ -1,18 +1,46 @@
+use super::context::{ContextSnapshot, VarChange};
+use super::diagnostics::DiagnosticResult;
+use super::error::EngineError;
+use super::events::ExpectedOutput;
 use crate::scenario::Scenario;
 use std::collections::HashMap;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Step의 런타임 상태를 표현한다.
 #[derive(Debug, Clone)]
 pub enum StepStatus {
     /// 아직 실행 대기 상태.
     Pending,
+    /// 실행 시간 창이 열리기를 기다리는 중. 재개 시각을 포함한다.
+    Waiting(String),
     /// 실행 중.
     Running,
+    /// 실패 후 재시도 중. `resume_at` 이전이면 다음 시도를 기다리는 중이다.
+    Retrying {
+        /// 현재 재시도 순번.
+        attempt: u8,
+        /// 최대 재시도 횟수.
+        max: u8,
+        /// 다음 시도가 시작되는 시각.
+        resume_at: Instant,
+    },
     /// 정상 종료.
     Success,
     /// 실패와 함께 오류 메시지를 포함한다.
     Failed(String),
+    /// 선행 Step 실패로 실행하지 않고 건너뜀.
+    Blocked,
+    /// 수동 승인 게이트가 승인을 기다리는 중.
+    AwaitingApproval,
+}
+
+/// 수동 승인 게이트의 승인 기록이다.
+#[derive(Debug, Clone)]
+pub struct GateApproval {
+    /// 승인한 사용자 이름.
+    pub approver: String,
+    /// 승인한 로컬 시각.
+    pub approved_at: String,
 }
 
 /// Step의 시간 및 로그 정보를 담는다.
@@ -26,6 +54,24 @@ pub struct StepRuntimeState {
     pub finished_at: Option<Instant>,
     /// 메모리에 적재된 로그 버퍼.
     pub logs: Vec<String>,
+    /// 실패 시 분류된 오류.
+    pub error: Option<EngineError>,
+    /// 실패 시점의 컨텍스트 변수 사본.
+    pub context_snapshot: Option<ContextSnapshot>,
+    /// 실패 직후 실행한 진단 명령 결과.
+    pub diagnostics: Vec<DiagnosticResult>,
+    /// 실행 중에 이 Step이 설정한 변수 기록.
+    pub set_vars: Vec<VarChange>,
+    /// 성공한 뒤 확인한 기대 산출물 결과.
+    pub outputs: Vec<ExpectedOutput>,
+    /// Loop Step의 `(끝난 반복 수, 전체 반복 수)`. 반복 대상이 정해지기 전에는 `None`이다.
+    pub iterations: Option<(usize, usize)>,
+    /// 수동 승인 게이트의 승인 기록.
+    pub approval: Option<GateApproval>,
+    /// `on_timeout: warn_continue` Step이 제한 시간을 넘긴 경우 마지막 경고 시점까지 지난 시간.
+    pub overrun: Option<Duration>,
+    /// `skip_if` 조건이나 사용자 건너뛰기로 실행을 마치지 않고 성공 처리했는지 여부. 실행 이력에 남기지 않는다.
+    pub skipped: bool,
 }
 
 impl StepRuntimeState {
@@ -36,6 +82,15 @@ impl StepRuntimeState {
             started_at: None,
             finished_at: None,
             logs: Vec::new(),
+            error: None,
+            context_snapshot: None,
+            diagnostics: Vec::new(),
+            set_vars: Vec::new(),
+            outputs: Vec::new(),
+            iterations: None,
+            approval: None,
+            overrun: None,
+            skipped: false,
         }
     }
 }
@@ -0,0 +1,139 @@
+use super::context::SharedExecutionContext;
+use super::events::EngineEvent;
+use crate::notify::{StepNotification, StepNotifyEvent, send_step_notification};
+use crate::scenario::{Scenario, Step, StepKind};
+use std::collections::{HashMap, HashSet};
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+/// `notify_url`을 지정한 Step 하나의 알림 정보이다.
+struct NotifyTarget {
+    /// 치환 전 웹훅 주소.
+    url: String,
+    /// Step 이름.
+    name: String,
+    /// 알림에 담을 변수 이름. Step 결과 변수는 따로 붙인다.
+    vars: Vec<String>,
+}
+
+/// Step 시작·종료 이벤트를 보고 `notify_url`을 지정한 Step마다 웹훅을 보낸 뒤 `outer`로 전달하는
+/// 송신자를 만든다.
+///
+/// Loop 내부와 `on_failure` Step도 대상이다. 알림은 실행을 늦추지 않도록 전송 작업 하나의 대기열에
+/// 넣어 이벤트 순서대로 하나씩 보낸다(`started`가 `success`보다 늦게 도착하지 않는다). 전송 작업은
+/// 대기열을 모두 보낼 때까지 `outer`를 쥐고 있으므로, 이벤트 채널이 닫혔으면 남은 알림도 모두 보낸
+/// 것이다. 주소의 `${VAR}`는 알림 시점의 컨텍스트로 치환한다. 본문의 컨텍스트는 그 Step의 결과 변수와
+/// `consumes`/`produces`(Extract 변수 포함) 변수만 담고, 비밀 변수는 이름으로, 다른 변수 값에 섞인
+/// 비밀 값은 값으로 가린다. 대상 Step이 없으면 `outer`를 그대로 돌려준다.
+pub(super) fn spawn_step_notify(
+    outer: UnboundedSender<EngineEvent>,
+    scenario: &Scenario,
+    run_id: &str,
+    ctx: SharedExecutionContext,
+) -> UnboundedSender<EngineEvent> {
+    let mut targets = HashMap::new();
+    collect_targets(&scenario.steps, &mut targets);
+    collect_targets(scenario.cleanup_steps(), &mut targets);
+    if targets.is_empty() {
+        return outer;
+    }
+    let scenario_name = scenario.name.clone();
+    let run_id = run_id.to_string();
+    let (queue, mut pending) = mpsc::unbounded_channel::<(String, StepNotification)>();
+    let flush_guard = outer.clone();
+    tokio::spawn(async move {
+        while let Some((endpoint, notification)) = pending.recv().await {
+            send_step_notification(&endpoint, &notification).await;
+        }
+        drop(flush_guard);
+    });
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        // 시간 창 대기가 끝나 다시 오는 `StepStarted`는 한 번 더 알리지 않는다.
+        let mut waiting: HashSet<String> = HashSet::new();
+        while let Some(event) = rx.recv().await {
+            let notify = match &event {
+                EngineEvent::StepWaiting { step_id, .. } => {
+                    waiting.insert(step_id.clone());
+                    None
+                }
+                EngineEvent::StepStarted { step_id } if waiting.remove(step_id) => None,
+                EngineEvent::StepStarted { step_id } => {
+                    Some((step_id.clone(), StepNotifyEvent::Started, None))
+                }
+                EngineEvent::StepFinished {
+                    step_id,
+                    success,
+                    error,
+                    ..
+                } => {
+                    let kind = if *success {
+                        StepNotifyEvent::Success
+                    } else {
+                        StepNotifyEvent::Failure
+                    };
+                    Some((
+                        step_id.clone(),
+                        kind,
+                        error.as_ref().map(ToString::to_string),
+                    ))
+                }
+                _ => None,
+            };
+            if let Some((step_id, kind, error)) = notify
+                && let Some(target) = targets.get(&step_id)
+            {
+                let (endpoint, notification) = {
+                    let guard = ctx.read().await;
+                    let prefix = format!("STEP.{step_id}.");
+                    let context = guard
+                        .snapshot()
+                        .into_iter()
+                        .filter(|(key, _)| key.starts_with(&prefix) || target.vars.contains(key))
+                        .map(|(key, value)| (key, guard.mask(&value)))
+                        .collect();
+                    let notification = StepNotification {
+                        scenario: scenario_name.clone(),
+                        run_id: run_id.clone(),
+                        step_name: target.name.clone(),
+                        step_id,
+                        event: kind,
+                        error: error.map(|error| guard.mask(&error)),
+                        context,
+                        at: chrono::Local::now().to_rfc3339(),
+                    };
+                    (guard.expand_lenient(&target.url), notification)
+                };
+                let _ = queue.send((endpoint, notification));
+            }
+            if outer.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    tx
+}
+
+/// `notify_url`을 지정한 Step을 Loop 내부까지 모은다.
+fn collect_targets(steps: &[Step], targets: &mut HashMap<String, NotifyTarget>) {
+    for step in steps {
+        if let Some(url) = step
+            .notify_url
+            .as_ref()
+            .filter(|url| !url.trim().is_empty())
+        {
+            let mut vars: Vec<String> = step.consumes.clone();
+            vars.extend(step.produced_vars().into_iter().map(str::to_string));
+            targets.insert(
+                step.id.clone(),
+                NotifyTarget {
+                    url: url.trim().to_string(),
+                    name: step.name.clone(),
+                    vars,
+                },
+            );
+        }
+        if let StepKind::Loop { config } = &step.kind {
+            collect_targets(&config.steps, targets);
+        }
+    }
+}
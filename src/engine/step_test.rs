@@ -0,0 +1,81 @@
+use super::context::placeholder_names;
+use crate::i18n::tf;
+use crate::scenario::{RUN_TMPDIR_VAR, STEP_TMPDIR_VAR, Scenario, Step, StepKind};
+
+/// Step 목록에서 ID가 같은 Step을 찾는다. Loop 하위 흐름까지 찾는다.
+fn find_step<'a>(steps: &'a [Step], step_id: &str) -> Option<&'a Step> {
+    steps.iter().find_map(|step| {
+        if step.id == step_id {
+            return Some(step);
+        }
+        match &step.kind {
+            StepKind::Loop { config } => find_step(&config.steps, step_id),
+            _ => None,
+        }
+    })
+}
+
+/// 단일 Step 테스트에서 사용자에게 값을 받아야 하는 변수와 미리 채울 값을 모은다.
+///
+/// Step 설정이 참조하는 `${VAR}` 중 엔진이 채우는 작업 디렉터리, DB 연결 정보, 자기 자신의 결과
+/// 변수는 제외한다. 다른 Step의 결과 변수(`${STEP.<id>.<field>}`)는 이 테스트에서 채워지지 않으므로
+/// 입력 대상에 포함한다. 값은 시나리오 파라미터, 환경 변수 순으로 미리 채운다.
+///
+/// # 반환값
+/// 처음 나타난 순서대로의 `(변수명, 값)` 목록. Step을 찾지 못하면 오류를 반환한다.
+pub fn step_test_inputs(
+    scenario: &Scenario,
+    step_id: &str,
+) -> anyhow::Result<Vec<(String, String)>> {
+    let step = find_step(&scenario.steps, step_id)
+        .or_else(|| find_step(scenario.cleanup_steps(), step_id))
+        .ok_or_else(|| anyhow::anyhow!(tf("engine.error.step_not_found", &[("step", &step_id)])))?;
+    let own_prefix = format!("STEP.{step_id}.");
+    let text = serde_json::to_string(step)?;
+    Ok(placeholder_names(&text)
+        .into_iter()
+        .filter(|name| {
+            name != RUN_TMPDIR_VAR
+                && name != STEP_TMPDIR_VAR
+                && !name.starts_with("DB.")
+                && !name.starts_with(&own_prefix)
+        })
+        .map(|name| {
+            let value = scenario
+                .params
+                .get(&name)
+                .cloned()
+                .or_else(|| std::env::var(&name).ok())
+                .unwrap_or_default();
+            (name, value)
+        })
+        .collect())
+}
+
+/// Step 하나만 실행하는 시나리오를 만든다.
+///
+/// DB·원격 호스트 정의, 파라미터, 비밀 변수, 기본값과 실패 진단은 그대로 두고, Step은 선행 의존과
+/// 실행 전 컨펌, 시간 창, 건너뛰기 조건을 지운 대상 Step 하나만 남긴다. 실패 정리, 결과 요약, 트리거는 쓰지 않는다.
+/// `inputs`는 파라미터로 덧씌운다.
+pub fn step_test_scenario(
+    scenario: &Scenario,
+    step_id: &str,
+    inputs: &[(String, String)],
+) -> anyhow::Result<Scenario> {
+    let mut step = find_step(&scenario.steps, step_id)
+        .or_else(|| find_step(scenario.cleanup_steps(), step_id))
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!(tf("engine.error.step_not_found", &[("step", &step_id)])))?;
+    step.depends_on.clear();
+    step.confirm = None;
+    step.time_window = None;
+    step.skip_if = None;
+    let mut isolated = scenario.clone();
+    isolated.steps = vec![step];
+    isolated.on_failure = None;
+    isolated.summary.clear();
+    isolated.trigger = None;
+    isolated.metadata = None;
+    isolated.params.extend(inputs.iter().cloned());
+    Ok(isolated)
+}
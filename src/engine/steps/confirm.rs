@@ -1,6 +1,8 @@
 use super::super::context::SharedExecutionContext;
 use super::super::events::{ConfirmPhase, EngineEvent};
-use crate::engine::ConfirmBridge;
+use super::utils::log_step;
+use crate::engine::{ConfirmBridge, ConfirmMode};
+use crate::i18n::{t, tf};
 use crate::scenario::{ConfirmDefault, Step, StepConfirmConfig, StepKind};
 use anyhow::Result;
 use tokio::sync::mpsc::UnboundedSender;
@@ -8,7 +10,8 @@ use tokio::sync::mpsc::UnboundedSender;
 /// 컨펌 설정을 기반으로 실제 UI 상호작용을 수행하고 결과를 컨텍스트에 저장한다.
 ///
 /// 수락 여부는 `CONFIRM_<STEP_ID>` 형태(대문자)로 `ExecutionContext`에 "Yes" 또는
-/// "No" 값으로 기록되어 이후 Step에서 플레이스홀더로 활용할 수 있다.
+/// "No" 값으로 기록되어 이후 Step에서 플레이스홀더로 활용할 수 있다. 무인 실행이면 묻지 않고
+/// `default_answer`를 쓰며, 참관 실행에서 응답을 받을 수 없으면 기본 응답 대신 오류를 반환한다.
 pub(super) async fn evaluate_confirm(
     step: &Step,
     confirm: &StepConfirmConfig,
@@ -25,70 +28,86 @@ pub(super) async fn evaluate_confirm(
         return Ok(true);
     }
 
-    let mut accepted = matches!(confirm.default_answer, ConfirmDefault::Yes);
-    let answered = if let Some(bridge) = bridge {
-        let (request_id, rx) = bridge.register();
-        let event = EngineEvent::RequestConfirm {
-            request_id,
-            step_id: step.id.clone(),
-            step_name: step.name.clone(),
-            step_kind: step_kind_label(&step.kind),
-            summary: summarize_step(step),
-            message,
-            default_answer: confirm.default_answer.clone(),
-            phase,
-        };
-        let _ = sender.send(event);
-        match rx.await {
-            Ok(answer) => {
-                accepted = answer;
-                let _ = sender.send(EngineEvent::ConfirmResponse {
-                    request_id,
-                    step_id: step.id.clone(),
-                    accepted: answer,
-                });
-                Ok::<bool, anyhow::Error>(answer)
-            }
-            Err(_) => {
-                bridge.cancel(request_id);
-                Ok(matches!(confirm.default_answer, ConfirmDefault::Yes))
+    let default_accepted = matches!(confirm.default_answer, ConfirmDefault::Yes);
+    let mode = ctx.read().await.confirm_mode();
+    let accepted = match (mode, bridge) {
+        (ConfirmMode::Unattended, _) => {
+            let answer = if default_accepted {
+                t("common.yes")
+            } else {
+                t("common.no")
+            };
+            log_step(
+                sender,
+                &step.id,
+                &tf("engine.confirm.unattended", &[("answer", &answer)]),
+            );
+            default_accepted
+        }
+        (ConfirmMode::Attended, None) => anyhow::bail!(t("engine.confirm.attended_no_ui")),
+        (_, Some(bridge)) => {
+            let (request_id, rx) = bridge.register();
+            let event = EngineEvent::RequestConfirm {
+                request_id,
+                step_id: step.id.clone(),
+                step_name: step.name.clone(),
+                step_kind: step_kind_label(&step.kind),
+                summary: summarize_step(step),
+                message,
+                default_answer: confirm.default_answer.clone(),
+                phase,
+            };
+            let _ = sender.send(event);
+            match rx.await {
+                Ok(answer) => {
+                    let _ = sender.send(EngineEvent::ConfirmResponse {
+                        request_id,
+                        step_id: step.id.clone(),
+                        accepted: answer,
+                    });
+                    answer
+                }
+                Err(_) => {
+                    bridge.cancel(request_id);
+                    if mode == ConfirmMode::Attended {
+                        anyhow::bail!(t("engine.confirm.attended_no_answer"));
+                    }
+                    default_accepted
+                }
             }
         }
-    } else {
-        Ok(matches!(confirm.default_answer, ConfirmDefault::Yes))
-    }?;
+        (ConfirmMode::Prompt, None) => default_accepted,
+    };
 
     let mut guard = ctx.write().await;
     let key = format!("CONFIRM_{}", step.id.to_uppercase());
     guard.set_var(&key, if accepted { "Yes" } else { "No" });
-    Ok(answered)
+    Ok(accepted)
 }
 
 /// StepKind를 사용자 친화적인 문자열로 변환한다.
 fn step_kind_label(kind: &StepKind) -> String {
-    match kind {
-        StepKind::Sql { .. } => "sql",
-        StepKind::SqlFile { .. } => "sql_file",
-        StepKind::SqlLoaderPar { .. } => "sql_loader_par",
-        StepKind::Shell { .. } => "shell",
-        StepKind::Extract { .. } => "extract",
-        StepKind::Loop { .. } => "loop",
-    }
-    .into()
+    kind.name().into()
 }
 
 /// Step 내용을 간단히 요약한다.
 fn summarize_step(step: &Step) -> Option<String> {
     match &step.kind {
         StepKind::Sql { sql, .. } => Some(trim_lines(sql, 4)),
-        StepKind::SqlFile { path, .. } => Some(format!("파일: {}", path.display())),
+        StepKind::SqlFile { path, .. } => {
+            Some(tf("engine.confirm.sql_file", &[("path", &path.display())]))
+        }
         StepKind::SqlLoaderPar { config } => {
             Some(format!("control: {}", config.control_file.display()))
         }
         StepKind::Shell { config } => Some(trim_lines(&config.script, 4)),
-        StepKind::Extract { config } => Some(format!(
-            "파일: {} / 그룹: {} / 변수: {}",
-            config.file_path, config.group, config.var_name
+        StepKind::Extract { config } => Some(tf(
+            "engine.confirm.extract",
+            &[
+                ("path", &config.file_path),
+                ("group", &config.group),
+                ("var", &config.var_name),
+            ],
         )),
         StepKind::Loop { config } => Some(format!(
             "Loop {} → {} ({} steps)",
@@ -96,6 +115,30 @@ fn summarize_step(step: &Step) -> Option<String> {
             config.as_var,
             config.steps.len()
         )),
+        StepKind::ManualGate { config } => config.message.clone(),
+        StepKind::Wait { config } => match (&config.until_time, config.seconds) {
+            (Some(until), _) => Some(tf("engine.confirm.wait_until", &[("until", &until)])),
+            (None, Some(secs)) => Some(tf("engine.confirm.wait_secs", &[("secs", &secs)])),
+            (None, None) => None,
+        },
+        StepKind::WaitForRun { config } => Some(tf(
+            "engine.confirm.wait_for_run",
+            &[
+                ("scenario", &config.scenario),
+                (
+                    "since",
+                    &config
+                        .since
+                        .as_deref()
+                        .unwrap_or(t("engine.confirm.since_midnight")),
+                ),
+            ],
+        )),
+        StepKind::Verify { config } => Some(tf(
+            "engine.confirm.verify",
+            &[("file", &config.file), ("method", &config.method.as_str())],
+        )),
+        StepKind::Marker => None,
     }
 }
 
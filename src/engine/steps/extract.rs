@@ -1,13 +1,15 @@
 use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
 use super::super::events::EngineEvent;
-use crate::scenario::ExtractVarFromFileConfig;
+use crate::i18n::tf;
+use crate::scenario::{ExtractVarFromFileConfig, StepLogLevel};
 use anyhow::Context;
 use regex::Regex;
 use tokio::fs::File;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::sync::mpsc::UnboundedSender;
 
-use super::utils::log_step;
+use super::utils::log_step_at;
 
 /// Extract Step을 실행한다.
 pub(super) async fn execute_extract_step(
@@ -22,7 +24,7 @@ pub(super) async fn execute_extract_step(
     };
     let file = File::open(&file_path)
         .await
-        .with_context(|| format!("파일을 열 수 없습니다: {file_path}"))?;
+        .with_context(|| tf("engine.error.file_open", &[("path", &file_path)]))?;
     let mut reader = BufReader::new(file).lines();
     let mut current_line = None;
     for i in 1..=config.line {
@@ -32,28 +34,44 @@ pub(super) async fn execute_extract_step(
                 break;
             }
         } else {
-            anyhow::bail!("{file_path}에서 {}번째 줄을 찾을 수 없습니다.", config.line);
+            anyhow::bail!(tf(
+                "engine.error.extract_line",
+                &[("path", &file_path), ("line", &config.line)],
+            ));
         }
     }
     let content = current_line.unwrap_or_default();
-    let re = Regex::new(&config.pattern)
-        .with_context(|| format!("정규식 컴파일 실패: {}", config.pattern))?;
-    let captures = re
-        .captures(&content)
-        .ok_or_else(|| anyhow::anyhow!("패턴이 매칭되지 않았습니다: {content}"))?;
+    let re = Regex::new(&config.pattern).map_err(|err| {
+        EngineError::ConfigError(tf(
+            "engine.error.extract_regex",
+            &[("pattern", &config.pattern), ("error", &err)],
+        ))
+    })?;
+    let captures = re.captures(&content).ok_or_else(|| {
+        anyhow::anyhow!(tf("engine.error.extract_no_match", &[("line", &content)]))
+    })?;
     let value = captures
         .get(config.group)
-        .ok_or_else(|| anyhow::anyhow!("캡처 그룹 {}을 찾을 수 없습니다.", config.group))?
+        .ok_or_else(|| {
+            anyhow::anyhow!(tf(
+                "engine.error.extract_group",
+                &[("group", &config.group)]
+            ))
+        })?
         .as_str()
         .to_string();
     {
         let mut guard = ctx.write().await;
         guard.set_var(&config.var_name, &value);
     }
-    log_step(
+    log_step_at(
         sender,
         step_id,
-        &format!("변수 {} = {}", config.var_name, value),
+        StepLogLevel::Debug,
+        &tf(
+            "engine.log.extract_var",
+            &[("name", &config.var_name), ("value", &value)],
+        ),
     );
     Ok(())
 }
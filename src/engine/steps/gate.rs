@@ -0,0 +1,69 @@
+use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
+use super::super::events::EngineEvent;
+use super::utils::log_step;
+use crate::engine::ConfirmBridge;
+use crate::i18n::{t, tf};
+use crate::scenario::{ManualGateConfig, Step};
+use chrono::Local;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+
+/// 수동 승인 게이트에서 승인될 때까지 대기하고 승인 기록을 컨텍스트에 남긴다.
+///
+/// 제한 시간 없이 무기한 대기하며, 취소되면 즉시 `Cancelled`로 끝난다. 승인자와 승인 시각은
+/// `APPROVED_BY_<STEP_ID>`, `APPROVED_AT_<STEP_ID>`(대문자)로 기록되어 이후 Step에서 쓸 수 있다.
+///
+/// # 매개변수
+/// - `step`: 대상 게이트 Step.
+/// - `config`: 게이트 설정.
+/// - `ctx`: 승인 기록을 남길 실행 컨텍스트.
+/// - `sender`: 대기 상태와 로그를 전달할 이벤트 채널.
+/// - `cancel`: 대기 중 취소를 감지할 토큰.
+/// - `bridge`: 승인을 전달받을 브리지. 없으면 승인할 방법이 없으므로 실패한다.
+pub(super) async fn wait_for_approval(
+    step: &Step,
+    config: &ManualGateConfig,
+    ctx: SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+    cancel: &CancellationToken,
+    bridge: Option<ConfirmBridge>,
+) -> Result<(), EngineError> {
+    let Some(bridge) = bridge else {
+        return Err(EngineError::Other(t("engine.gate.no_bridge").into()));
+    };
+    let rx = bridge.register_gate(&step.id);
+    let _ = sender.send(EngineEvent::StepAwaitingApproval {
+        step_id: step.id.clone(),
+        message: config.message.clone(),
+    });
+    log_step(sender, &step.id, t("engine.log.gate_waiting"));
+    let approver = tokio::select! {
+        _ = cancel.cancelled() => {
+            bridge.cancel_gate(&step.id);
+            return Err(EngineError::Cancelled);
+        }
+        answer = rx => answer.map_err(|_| EngineError::Cancelled)?,
+    };
+    let approved_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    {
+        let mut guard = ctx.write().await;
+        let key = step.id.to_uppercase();
+        guard.set_var(format!("APPROVED_BY_{key}"), &approver);
+        guard.set_var(format!("APPROVED_AT_{key}"), &approved_at);
+    }
+    log_step(
+        sender,
+        &step.id,
+        &tf(
+            "engine.log.gate_approved",
+            &[("approver", &approver), ("at", &approved_at)],
+        ),
+    );
+    let _ = sender.send(EngineEvent::StepApproved {
+        step_id: step.id.clone(),
+        approver,
+        approved_at,
+    });
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
 use super::super::events::EngineEvent;
 use super::super::resources::EngineHandles;
 use super::{StepRunResult, run_single_step};
 use crate::engine::ConfirmBridge;
-use crate::scenario::{LoopIterationFailure, LoopStepConfig, Step};
-use anyhow::{Context, Result};
+use crate::i18n::{t, tf};
+use crate::scenario::{LoopIterationFailure, LoopStepConfig, Step, StepLogLevel};
+use anyhow::Result;
 use glob::glob;
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -12,7 +14,7 @@ use std::sync::Arc;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio_util::sync::CancellationToken;
 
-use super::utils::log_step;
+use super::utils::{log_enabled, log_step_at};
 
 /// Loop Step을 실행하고 각 반복에서 하위 Step 전체를 처리한다.
 pub(super) async fn execute_loop_step(
@@ -29,31 +31,47 @@ pub(super) async fn execute_loop_step(
         guard.expand_required(&config.for_each_glob, "loop.pattern")?
     };
     let mut entries: Vec<PathBuf> = Vec::new();
-    for entry in glob(&pattern).context("glob 패턴 파싱 실패")? {
+    let paths = glob(&pattern)
+        .map_err(|err| EngineError::ConfigError(tf("engine.error.glob", &[("error", &err)])))?;
+    for entry in paths {
         entries.push(entry?);
     }
     if entries.is_empty() {
-        log_step(
+        log_step_at(
             &sender,
             log_step_id,
-            &format!("Loop 패턴에 해당하는 파일이 없습니다: {pattern}"),
+            StepLogLevel::Warn,
+            &tf("engine.log.loop_no_match", &[("pattern", &pattern)]),
         );
         return Ok(());
     }
+    send_progress(&sender, log_step_id, 0, entries.len());
+    // 반복마다의 변수 로그는 반복 수만큼 쌓이므로 debug 수준에서만 만든다.
+    let log_iterations = log_enabled(StepLogLevel::Debug);
     for (idx, entry) in entries.iter().enumerate() {
         if cancel.is_cancelled() {
-            anyhow::bail!("사용자에 의해 Loop Step이 중단되었습니다.");
+            return Err(EngineError::Cancelled.into());
         }
         let value = entry.to_string_lossy().to_string();
         {
             let mut guard = ctx.write().await;
             guard.set_var(&config.as_var, &value);
         }
-        log_step(
-            &sender,
-            log_step_id,
-            &format!("[반복 {}] {} = {}", idx + 1, config.as_var, value),
-        );
+        if log_iterations {
+            log_step_at(
+                &sender,
+                log_step_id,
+                StepLogLevel::Debug,
+                &tf(
+                    "engine.log.loop_iteration",
+                    &[
+                        ("index", &(idx + 1)),
+                        ("var", &config.as_var),
+                        ("value", &value),
+                    ],
+                ),
+            );
+        }
         let iteration_result = run_iteration_steps(
             &config.steps,
             handles.clone(),
@@ -67,14 +85,29 @@ pub(super) async fn execute_loop_step(
             match config.on_iteration_failure {
                 LoopIterationFailure::StopAll => return Err(err),
                 LoopIterationFailure::Continue => {
-                    log_step(&sender, log_step_id, &format!("Loop 반복 실패 무시: {err}"));
+                    log_step_at(
+                        &sender,
+                        log_step_id,
+                        StepLogLevel::Warn,
+                        &tf("engine.log.loop_failure_ignored", &[("error", &err)]),
+                    );
                 }
             }
         }
+        send_progress(&sender, log_step_id, idx + 1, entries.len());
     }
     Ok(())
 }
 
+/// Loop 반복 진행 상황을 전달한다.
+fn send_progress(sender: &UnboundedSender<EngineEvent>, step_id: &str, done: usize, total: usize) {
+    let _ = sender.send(EngineEvent::LoopProgress {
+        step_id: step_id.to_string(),
+        done,
+        total,
+    });
+}
+
 /// 단일 Loop 반복에서 하위 Step 전체를 의존성 순으로 실행한다.
 async fn run_iteration_steps(
     steps: &[Step],
@@ -94,7 +127,7 @@ async fn run_iteration_steps(
             break;
         }
         if cancel.is_cancelled() {
-            anyhow::bail!("사용자에 의해 Loop 반복이 중단되었습니다.");
+            return Err(EngineError::Cancelled.into());
         }
         let mut progressed = false;
         for step in steps {
@@ -116,14 +149,14 @@ async fn run_iteration_steps(
             )
             .await
             {
-                StepRunResult::Success => {
+                StepRunResult::Success | StepRunResult::Skipped => {
                     completed.insert(step.id.clone());
                 }
-                StepRunResult::Failed(msg) => anyhow::bail!(msg),
+                StepRunResult::Failed { error, .. } => return Err(error.into()),
             }
         }
         if !progressed {
-            anyhow::bail!("Loop 하위 Step 실행 순서를 결정할 수 없습니다. 의존성을 확인하세요.");
+            anyhow::bail!(t("engine.error.loop_order"));
         }
     }
     Ok(())
@@ -136,11 +169,10 @@ fn ensure_dependencies(step: &Step, completed: &HashSet<String>, steps: &[Step])
             continue;
         }
         if !steps.iter().any(|s| &s.id == dep) {
-            anyhow::bail!(
-                "Loop 하위 Step '{}'에서 알 수 없는 의존성 '{}'을 참조했습니다.",
-                step.id,
-                dep
-            );
+            anyhow::bail!(tf(
+                "engine.error.loop_unknown_dependency",
+                &[("step", &step.id), ("dependency", dep)],
+            ));
         }
     }
     Ok(())
@@ -0,0 +1,107 @@
+use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
+use super::super::events::EngineEvent;
+use super::utils::log_step;
+use crate::i18n::tf;
+use crate::scenario::Step;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::OnceCell;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// 한 실행 안에서 `memoize` Step의 성공 결과를 구성 해시별로 보관하는 저장소이다.
+///
+/// 실행마다 새 컨텍스트와 함께 만들어지므로 결과는 그 실행 안에서만 재사용한다.
+#[derive(Debug, Clone, Default)]
+pub(in crate::engine) struct StepMemo {
+    /// 구성 해시별 결과 칸. 같은 해시를 동시에 요청하면 한 요청만 실행하고 나머지는 기다린다.
+    slots: Arc<Mutex<HashMap<String, Arc<OnceCell<MemoEntry>>>>>,
+}
+
+/// 처음 실행해 성공한 Step이 남긴 결과이다.
+#[derive(Debug, Clone)]
+struct MemoEntry {
+    /// 실제로 실행한 Step ID.
+    step_id: String,
+    /// 실행 직후 Step이 제공하는 변수 값.
+    vars: Vec<(String, String)>,
+    /// 실행 중에 남긴 `STEP.<id>.<field>` 결과 필드와 값.
+    results: Vec<(String, String)>,
+}
+
+impl StepMemo {
+    /// 구성 해시의 결과 칸을 반환한다. 없으면 빈 칸을 만든다.
+    fn slot(&self, key: &str) -> Arc<OnceCell<MemoEntry>> {
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        slots.entry(key.to_string()).or_default().clone()
+    }
+}
+
+/// `memoize`가 켜진 Step이면 같은 구성으로 이미 성공한 결과를 재사용하고, 아니면 `run`을 실행한다.
+///
+/// 구성 해시는 Step 유형과 플레이스홀더를 현재 값으로 치환한 구성으로 계산하므로, Loop 변수처럼
+/// 반복마다 달라지는 값을 참조하면 반복마다 실행된다. 재사용하면 처음 실행한 Step이 제공한 변수와
+/// `STEP.<id>.<field>` 결과를 이 Step 이름으로 다시 남긴다. 실패한 실행은 보관하지 않으므로 다음
+/// 요청이 다시 실행한다.
+pub(super) async fn run_memoized<F>(
+    step: &Step,
+    ctx: &SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+    run: F,
+) -> Result<(), EngineError>
+where
+    F: Future<Output = Result<(), EngineError>>,
+{
+    if !step.memoize {
+        return run.await;
+    }
+    let (memo, key) = {
+        let guard = ctx.read().await;
+        let Ok(config) = serde_json::to_string(&step.kind) else {
+            drop(guard);
+            return run.await;
+        };
+        let digest = Sha256::digest(guard.expand_lenient(&config).as_bytes());
+        (guard.step_memo(), format!("{digest:x}"))
+    };
+    let ran = AtomicBool::new(false);
+    let entry = memo
+        .slot(&key)
+        .get_or_try_init(|| async {
+            ran.store(true, Ordering::Relaxed);
+            run.await?;
+            let guard = ctx.read().await;
+            Ok::<_, EngineError>(MemoEntry {
+                step_id: step.id.clone(),
+                vars: step
+                    .produced_vars()
+                    .into_iter()
+                    .filter_map(|name| Some((name.to_string(), guard.get_var(name)?.to_string())))
+                    .collect(),
+                results: guard.step_results(&step.id),
+            })
+        })
+        .await?
+        .clone();
+    if ran.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+    {
+        let mut guard = ctx.write().await;
+        for (name, value) in &entry.vars {
+            guard.set_var(name.clone(), value.clone());
+        }
+        for (field, value) in &entry.results {
+            guard.set_step_result(&step.id, field, value);
+        }
+    }
+    log_step(
+        sender,
+        &step.id,
+        &tf("engine.log.memo_hit", &[("step", &entry.step_id)]),
+    );
+    Ok(())
+}
@@ -1,42 +1,94 @@
-use super::context::SharedExecutionContext;
+use super::context::{
+    CURRENT_STEP, ContextSnapshot, STEP_LOG_LEVEL, STEP_TMPDIR, SharedExecutionContext,
+};
+use super::diagnostics::{DiagnosticResult, run_failure_diagnostics};
+use super::error::EngineError;
 use super::events::{ConfirmPhase, EngineEvent};
+use super::rehearsal::rehearse_step;
 use super::resources::EngineHandles;
-use crate::engine::ConfirmBridge;
-use crate::scenario::{Step, StepKind};
+use super::run_tmpdir::create_step_tmpdir;
+use crate::engine::{ConfirmBridge, StepCancelMode};
+use crate::i18n::{t, tf};
+use crate::scenario::{RUN_TMPDIR_VAR, RetryOn, Step, StepKind, StepLogLevel, TimeoutPolicy};
 use std::future::Future;
+use std::path::Path;
 use std::pin::Pin;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::time::sleep;
 use tokio_util::sync::CancellationToken;
 
 mod confirm;
 mod extract;
+mod gate;
 mod loops;
+mod memo;
+mod outputs;
+mod priority;
+mod remote;
 mod shell;
+mod skip_if;
 mod sql;
 mod sqlldr;
 mod utils;
+mod verify;
+mod wait;
+mod wait_run;
+mod window;
 
 use confirm::evaluate_confirm;
 use extract::execute_extract_step;
+use gate::wait_for_approval;
 use loops::execute_loop_step;
+use memo::run_memoized;
+use outputs::check_expected_outputs;
 use shell::run_shell_command;
+use skip_if::should_skip;
 use sql::{execute_sql, load_sql_file};
 use sqlldr::run_sqlldr;
-use utils::{display_path, log_step};
+use utils::{current_log_level, display_path, log_step, log_step_at};
+
+pub(super) use memo::StepMemo;
+pub(super) use shell::ESSENTIAL_ENV_VARS;
+use verify::execute_verify_step;
+use wait::execute_wait_step;
+use wait_run::execute_wait_for_run_step;
+use window::wait_for_time_window;
 
 /// Step 실행의 결과를 표현한다.
 #[derive(Debug)]
 pub(super) enum StepRunResult {
     /// 실행 성공.
     Success,
-    /// 오류 메시지와 함께 실패.
-    Failed(String),
+    /// 실행 중에 사용자가 건너뛰었거나 `skip_if` 조건을 충족해 성공으로 처리함.
+    Skipped,
+    /// 분류된 오류와 실패 시점의 컨텍스트 변수 사본, 진단 결과를 담은 실패.
+    Failed {
+        /// 분류된 오류.
+        error: EngineError,
+        /// 실패 시점의 컨텍스트 변수 사본.
+        context: ContextSnapshot,
+        /// 실패 직후 실행한 진단 명령 결과.
+        diagnostics: Vec<DiagnosticResult>,
+    },
 }
 
 /// 단일 Step을 실행하고 결과를 반환한다.
+///
+/// 실행마다 `${RUN_TMPDIR}` 아래에 이 Step만 쓰는 작업 디렉터리를 만들어 `${STEP_TMPDIR}`로 제공하므로
+/// 병렬 Step이 같은 파일 이름을 써도 겹치지 않는다. 재시도는 같은 디렉터리를 쓴다.
+///
+/// 끝나면 `STEP.<id>.status`(`success`/`failed`/`cancelled`/`skipped`), `STEP.<id>.duration_sec`과
+/// 작업 디렉터리 경로 `STEP.<id>.tmpdir`을 컨텍스트에 남겨 이후 Step이 참조할 수 있게 한다. 실패하면 그 시점의 실행 컨텍스트를 캡처해
+/// 결과에 함께 담는다. 브리지가 있으면 전체 실행과 별도로 이 Step만 취소할 수 있도록 개별 취소
+/// 토큰을 등록하며, 사용자가 건너뛰기로 취소하면 [`StepRunResult::Skipped`]를 반환한다.
+/// `memoize` Step은 이 실행에서 같은 구성으로 이미 성공했으면 실행하지 않고 그 결과를 재사용한다.
+/// `skip_if` 조건을 모두 충족하면 작업 디렉터리도 만들지 않고 [`StepRunResult::Skipped`]를 반환한다.
+/// 실행 중에 설정한 변수가 있으면 끝날 때 [`EngineEvent::StepVariables`]로 알린다. 성공하면
+/// `expects_outputs` 산출물을 확인해 정책에 따라 실패로 바꾸거나 경고만 남긴다. 리허설로 흉내만 낸
+/// Step은 산출물을 확인하지 않는다.
+/// `log_level`보다 낮은 수준의 엔진 로그는 보내지 않으며, 값이 없으면 감싼 Loop Step의 수준을 따른다.
 pub(super) fn run_single_step(
     step: Step,
     handles: Arc<EngineHandles>,
@@ -45,106 +97,416 @@ pub(super) fn run_single_step(
     cancel: CancellationToken,
     confirm_bridge: Option<ConfirmBridge>,
 ) -> Pin<Box<dyn Future<Output = StepRunResult> + Send>> {
-    Box::pin(async move {
-        if let Some(confirm) = &step.confirm {
-            match evaluate_confirm(
+    let step_id = step.id.clone();
+    let log_level = step.log_level.unwrap_or_else(current_log_level);
+    let run = CURRENT_STEP.scope(step_id, async move {
+        if let Some(skip_if) = &step.skip_if
+            && should_skip(&step.id, skip_if, &handles, &ctx, &sender).await
+        {
+            let mut guard = ctx.write().await;
+            guard.set_step_result(&step.id, "status", "skipped");
+            guard.set_step_result(&step.id, "duration_sec", 0);
+            return StepRunResult::Skipped;
+        }
+        let started = Instant::now();
+        let step_cancel = cancel.child_token();
+        if let Some(bridge) = &confirm_bridge {
+            bridge.register_step(&step.id, step_cancel.clone());
+        }
+        let step_tmpdir = prepare_step_tmpdir(&step.id, &ctx, &sender).await;
+        let attempts = run_memoized(
+            &step,
+            &ctx,
+            &sender,
+            run_step_attempts(
                 &step,
-                confirm,
-                ConfirmPhase::Before,
+                handles.clone(),
                 ctx.clone(),
-                &sender,
+                sender.clone(),
+                step_cancel,
                 confirm_bridge.clone(),
-            )
-            .await
-            {
-                Ok(true) => {}
-                Ok(false) => {
-                    return StepRunResult::Failed(format!(
-                        "사전 컨펌에서 Step '{}' 실행이 거부되었습니다.",
-                        step.name
-                    ));
-                }
-                Err(err) => {
-                    return StepRunResult::Failed(format!("컨펌 처리 오류: {err}"));
+            ),
+        );
+        let attempts = async {
+            attempts.await?;
+            // 리허설로 흉내만 낸 Step은 산출물을 만들지 않았으므로 확인하지 않는다.
+            let simulated = ctx
+                .read()
+                .await
+                .rehearsal()
+                .is_some_and(|plan| plan.simulates(&step));
+            if simulated {
+                return Ok(());
+            }
+            check_expected_outputs(&step, &ctx, &sender).await
+        };
+        let outcome = match &step_tmpdir {
+            Some(path) => STEP_TMPDIR.scope(path.clone(), attempts).await,
+            None => attempts.await,
+        };
+        // 요청이 처리되기 전에 Step이 먼저 성공했거나 전체 실행이 중지되었으면 개별 취소는 무시한다.
+        let requested = confirm_bridge
+            .as_ref()
+            .and_then(|bridge| bridge.finish_step(&step.id))
+            .filter(|_| outcome.is_err() && !cancel.is_cancelled());
+        if let Some(mode) = requested {
+            let key = match mode {
+                StepCancelMode::Fail => "engine.log.step_cancel_fail",
+                StepCancelMode::Skip => "engine.log.step_cancel_skip",
+            };
+            log_step_at(&sender, &step.id, StepLogLevel::Warn, t(key));
+        }
+        let skipped = requested == Some(StepCancelMode::Skip);
+        {
+            let status = match &outcome {
+                _ if skipped => "skipped",
+                Ok(()) => "success",
+                Err(EngineError::Cancelled) => "cancelled",
+                Err(_) => "failed",
+            };
+            let mut guard = ctx.write().await;
+            guard.set_step_result(&step.id, "status", status);
+            guard.set_step_result(&step.id, "duration_sec", started.elapsed().as_secs());
+            if let Some(path) = &step_tmpdir {
+                guard.set_step_result(&step.id, "tmpdir", path);
+            }
+            let vars = guard.step_var_changes(&step.id);
+            if !vars.is_empty() {
+                let _ = sender.send(EngineEvent::StepVariables {
+                    step_id: step.id.clone(),
+                    vars,
+                });
+            }
+        }
+        match outcome {
+            _ if skipped => StepRunResult::Skipped,
+            Ok(()) => StepRunResult::Success,
+            Err(error) => {
+                let diagnostics = if matches!(error, EngineError::Cancelled) {
+                    Vec::new()
+                } else {
+                    capture_failure_diagnostics(&step.id, &handles, &ctx, &sender).await
+                };
+                StepRunResult::Failed {
+                    error,
+                    context: ctx.read().await.snapshot(),
+                    diagnostics,
                 }
             }
         }
+    });
+    Box::pin(STEP_LOG_LEVEL.scope(log_level, run))
+}
 
-        let timeout_duration = Duration::from_secs(step.timeout_sec.max(1));
-        let mut attempt: u8 = 0;
+/// 시나리오의 `failure_diagnostics`를 실행해 비밀 값을 가린 출력을 Step 로그에 남기고 결과를 알린다.
+///
+/// 환경 문제가 사라지기 전에 남겨야 하므로 실패 직후, 하위 Step이 건너뛰어지기 전에 실행한다.
+async fn capture_failure_diagnostics(
+    step_id: &str,
+    handles: &EngineHandles,
+    ctx: &SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+) -> Vec<DiagnosticResult> {
+    let diagnostics = ctx.read().await.failure_diagnostics();
+    if diagnostics.is_empty() {
+        return Vec::new();
+    }
+    log_step_at(
+        sender,
+        step_id,
+        StepLogLevel::Warn,
+        t("engine.log.diagnostics_start"),
+    );
+    let results = run_failure_diagnostics(&diagnostics, handles, ctx).await;
+    // 실행 이력에도 남으므로 이벤트 중계 단계가 아니라 여기서 비밀 값을 가린다.
+    let results: Vec<DiagnosticResult> = {
+        let guard = ctx.read().await;
+        results
+            .into_iter()
+            .map(|result| DiagnosticResult {
+                output: result.output.map(|text| guard.mask(&text)),
+                error: result.error.map(|text| guard.mask(&text)),
+                ..result
+            })
+            .collect()
+    };
+    for result in &results {
+        let body = match (&result.output, &result.error) {
+            (_, Some(error)) => tf("engine.log.diagnostic_failed", &[("error", error)]),
+            (Some(output), None) => output.clone(),
+            (None, None) => t("engine.log.diagnostic_empty").to_string(),
+        };
+        log_step_at(
+            sender,
+            step_id,
+            StepLogLevel::Warn,
+            &format!("[{}] {body}", result.label),
+        );
+    }
+    let _ = sender.send(EngineEvent::StepDiagnostics {
+        step_id: step_id.to_string(),
+        results: results.clone(),
+    });
+    results
+}
 
-        loop {
-            if cancel.is_cancelled() {
-                return StepRunResult::Failed("사용자에 의해 실행이 중단되었습니다.".to_string());
+/// 실행 임시 디렉터리 아래에 Step 작업 디렉터리를 만들고 경로를 반환한다.
+///
+/// 실행 임시 디렉터리가 없는 컨텍스트이거나 만들지 못하면 `None`이며, 실패는 Step 로그에 남긴다.
+async fn prepare_step_tmpdir(
+    step_id: &str,
+    ctx: &SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+) -> Option<String> {
+    let run_tmpdir = ctx.read().await.get_var(RUN_TMPDIR_VAR)?.to_string();
+    match create_step_tmpdir(Path::new(&run_tmpdir), step_id) {
+        Ok(path) => Some(path.to_string_lossy().into_owned()),
+        Err(err) => {
+            log_step_at(
+                sender,
+                step_id,
+                StepLogLevel::Warn,
+                &tf(
+                    "engine.log.step_tmpdir_failed",
+                    &[("error", &format!("{err:#}"))],
+                ),
+            );
+            None
+        }
+    }
+}
+
+/// 컨펌, 재시도, 타임아웃을 적용해 Step을 실행한다.
+async fn run_step_attempts(
+    step: &Step,
+    handles: Arc<EngineHandles>,
+    ctx: SharedExecutionContext,
+    sender: UnboundedSender<EngineEvent>,
+    cancel: CancellationToken,
+    confirm_bridge: Option<ConfirmBridge>,
+) -> Result<(), EngineError> {
+    if let Some(window) = &step.time_window {
+        wait_for_time_window(step, window, &ctx, &sender, &cancel).await?;
+    }
+
+    if let Some(confirm) = &step.confirm {
+        match evaluate_confirm(
+            step,
+            confirm,
+            ConfirmPhase::Before,
+            ctx.clone(),
+            &sender,
+            confirm_bridge.clone(),
+        )
+        .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(EngineError::Other(tf(
+                    "engine.confirm.rejected_before",
+                    &[("step", &step.name)],
+                )));
             }
+            Err(err) => {
+                return Err(EngineError::Other(tf(
+                    "engine.confirm.error",
+                    &[("error", &err)],
+                )));
+            }
+        }
+    }
 
-            let backoff = Duration::from_secs(2_u64.pow(attempt as u32));
-            let log_step_id = step.id.clone();
+    let rehearsal = ctx.read().await.rehearsal();
+    if let Some(plan) = rehearsal
+        && let Some(result) = rehearse_step(&plan, step, &sender, &cancel).await
+    {
+        return result;
+    }
 
-            let exec_future = execute_step_kind(
-                &step,
-                &log_step_id,
-                handles.clone(),
-                ctx.clone(),
-                sender.clone(),
-                cancel.clone(),
-                confirm_bridge.clone(),
-            );
+    // 승인 게이트는 무기한 대기하므로 타임아웃/재시도를 적용하지 않는다.
+    if let StepKind::ManualGate { config } = &step.kind {
+        return wait_for_approval(step, config, ctx, &sender, &cancel, confirm_bridge).await;
+    }
+
+    // 대기·실행 대기 Step은 기다리는 것 자체가 목적이므로 타임아웃/재시도를 적용하지 않는다.
+    if let StepKind::Wait { config } = &step.kind {
+        return execute_wait_step(step, config, &sender, &cancel).await;
+    }
+    if let StepKind::WaitForRun { config } = &step.kind {
+        return execute_wait_for_run_step(step, config, ctx, &sender, &cancel).await;
+    }
+
+    // 표식 Step은 선행 Step이 모두 끝났다는 것만 알리고 바로 성공한다.
+    if matches!(step.kind, StepKind::Marker) {
+        log_step(&sender, &step.id, t("engine.log.marker_reached"));
+        return Ok(());
+    }
+
+    let timeout_duration = Duration::from_secs(step.timeout_sec.max(1));
+    let mut attempt: u8 = 0;
 
-            let result = tokio::time::timeout(timeout_duration, exec_future).await;
+    loop {
+        if cancel.is_cancelled() {
+            return Err(EngineError::Cancelled);
+        }
 
-            match result {
-                Ok(Ok(())) => {
-                    if let Some(confirm) = &step.confirm {
-                        match evaluate_confirm(
-                            &step,
-                            confirm,
-                            ConfirmPhase::After,
-                            ctx.clone(),
+        let backoff = Duration::from_secs(2_u64.pow(attempt as u32));
+        let log_step_id = step.id.clone();
+
+        let exec_future = execute_step_kind(
+            step,
+            &log_step_id,
+            handles.clone(),
+            ctx.clone(),
+            sender.clone(),
+            cancel.clone(),
+            confirm_bridge.clone(),
+        );
+
+        // warn_continue 정책이면 제한 시간이 지날 때마다 경고만 보내고 같은 실행을 계속 기다린다.
+        // 취소되면 실행 중인 작업을 버려 로컬 자식 프로세스까지 바로 멈춘다.
+        let started = Instant::now();
+        let mut exec_future = std::pin::pin!(exec_future);
+        let result = loop {
+            let waited = tokio::select! {
+                _ = cancel.cancelled() => {
+                    if let Some(remote) = step.remote_host() {
+                        log_step_at(
                             &sender,
-                            confirm_bridge.clone(),
-                        )
-                        .await
-                        {
-                            Ok(true) => {}
-                            Ok(false) => {
-                                return StepRunResult::Failed(format!(
-                                    "사후 컨펌에서 Step '{}' 실행이 거부되었습니다.",
-                                    step.name
-                                ));
-                            }
-                            Err(err) => {
-                                return StepRunResult::Failed(format!("컨펌 처리 오류: {err}"));
-                            }
-                        }
+                            &step.id,
+                            StepLogLevel::Warn,
+                            &tf("engine.log.remote_cancel", &[("remote", &remote)]),
+                        );
                     }
-                    return StepRunResult::Success;
+                    return Err(EngineError::Cancelled);
                 }
-                Ok(Err(err)) => {
-                    attempt += 1;
-                    if attempt > step.retry {
-                        return StepRunResult::Failed(format!("실패: {err}"));
+                waited = tokio::time::timeout(timeout_duration, exec_future.as_mut()) => waited,
+            };
+            match waited {
+                Err(_) if step.on_timeout == TimeoutPolicy::WarnContinue => {
+                    notify_timeout_warning(step, started.elapsed(), &sender);
+                }
+                other => break other,
+            }
+        };
+
+        match result {
+            Ok(Ok(())) => {
+                if let Some(confirm) = &step.confirm {
+                    match evaluate_confirm(
+                        step,
+                        confirm,
+                        ConfirmPhase::After,
+                        ctx.clone(),
+                        &sender,
+                        confirm_bridge.clone(),
+                    )
+                    .await
+                    {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            return Err(EngineError::Other(tf(
+                                "engine.confirm.rejected_after",
+                                &[("step", &step.name)],
+                            )));
+                        }
+                        Err(err) => {
+                            return Err(EngineError::Other(tf(
+                                "engine.confirm.error",
+                                &[("error", &err)],
+                            )));
+                        }
                     }
+                }
+                return Ok(());
+            }
+            Ok(Err(err)) => {
+                let error = EngineError::classify(&err);
+                attempt += 1;
+                if attempt > step.retry {
+                    return Err(error);
+                }
+                if step.retry_on == RetryOn::Transient && !error.is_transient() {
                     let _ = sender.send(EngineEvent::StepLog {
                         step_id: step.id.clone(),
-                        line: format!("오류 발생, {}초 후 재시도", backoff.as_secs()),
+                        line: t("engine.log.retry_skipped_permanent").into(),
                     });
-                    sleep(backoff).await;
+                    return Err(error);
                 }
-                Err(_) => {
-                    attempt += 1;
-                    if attempt > step.retry {
-                        return StepRunResult::Failed("시간 초과".into());
-                    }
+                let _ = sender.send(EngineEvent::StepLog {
+                    step_id: step.id.clone(),
+                    line: tf(
+                        "engine.log.retry_after_error",
+                        &[("secs", &backoff.as_secs())],
+                    ),
+                });
+                notify_retry(step, attempt, backoff, &sender);
+                sleep_unless_cancelled(backoff, &cancel).await?;
+            }
+            Err(_) => {
+                if step.on_timeout == TimeoutPolicy::Fail {
                     let _ = sender.send(EngineEvent::StepLog {
                         step_id: step.id.clone(),
-                        line: "시간 초과 발생, 재시도 준비".into(),
+                        line: t("engine.log.timeout_fail").into(),
                     });
-                    sleep(backoff).await;
+                    return Err(EngineError::Timeout);
+                }
+                attempt += 1;
+                if attempt > step.retry {
+                    return Err(EngineError::Timeout);
                 }
+                let _ = sender.send(EngineEvent::StepLog {
+                    step_id: step.id.clone(),
+                    line: t("engine.log.retry_after_timeout").into(),
+                });
+                notify_retry(step, attempt, backoff, &sender);
+                sleep_unless_cancelled(backoff, &cancel).await?;
             }
         }
-    })
+    }
+}
+
+/// 재시도 간격만큼 기다린다. 그사이 전체 실행이나 이 Step이 취소되면 바로 [`EngineError::Cancelled`]를
+/// 반환한다.
+async fn sleep_unless_cancelled(
+    delay: Duration,
+    cancel: &CancellationToken,
+) -> Result<(), EngineError> {
+    tokio::select! {
+        _ = cancel.cancelled() => Err(EngineError::Cancelled),
+        _ = sleep(delay) => Ok(()),
+    }
+}
+
+/// 재시도 대기 시작을 UI에 알린다.
+fn notify_retry(step: &Step, attempt: u8, delay: Duration, sender: &UnboundedSender<EngineEvent>) {
+    let _ = sender.send(EngineEvent::StepRetrying {
+        step_id: step.id.clone(),
+        attempt,
+        max: step.retry,
+        next_delay: delay,
+    });
+}
+
+/// `warn_continue` Step이 제한 시간을 넘겼음을 로그와 이벤트로 알린다.
+fn notify_timeout_warning(step: &Step, elapsed: Duration, sender: &UnboundedSender<EngineEvent>) {
+    let _ = sender.send(EngineEvent::StepLog {
+        step_id: step.id.clone(),
+        line: tf(
+            "engine.log.timeout_warn",
+            &[("secs", &step.timeout_sec), ("elapsed", &elapsed.as_secs())],
+        ),
+    });
+    let _ = sender.send(EngineEvent::StepTimeoutWarning {
+        step_id: step.id.clone(),
+        elapsed,
+    });
+}
+
+/// 셸·sqlldr 프로세스 대기에 적용할 제한 시간을 반환한다. `warn_continue`이면 제한하지 않는다.
+fn process_timeout(step: &Step) -> Option<Duration> {
+    (step.on_timeout != TimeoutPolicy::WarnContinue)
+        .then(|| Duration::from_secs(step.timeout_sec.max(1)))
 }
 
 /// StepKind별 실제 수행 로직을 실행한다.
@@ -158,42 +520,81 @@ async fn execute_step_kind(
     confirm_bridge: Option<ConfirmBridge>,
 ) -> anyhow::Result<()> {
     match &step.kind {
-        StepKind::Sql { sql, target_db } => {
-            log_step(&sender, log_step_id, "SQL 실행 시작");
-            execute_sql(sql, target_db.as_deref(), handles, ctx).await?;
+        StepKind::Sql {
+            sql,
+            target_db,
+            guard,
+        } => {
+            log_step(&sender, log_step_id, t("engine.log.sql_start"));
+            execute_sql(
+                sql,
+                target_db.as_deref(),
+                guard,
+                handles,
+                ctx,
+                log_step_id,
+                &sender,
+            )
+            .await?;
         }
-        StepKind::SqlFile { path, target_db } => {
-            let file_sql = load_sql_file(path, ctx.clone()).await?;
+        StepKind::SqlFile {
+            path,
+            target_db,
+            guard,
+        } => {
+            let file_sql = load_sql_file(path, ctx.clone(), log_step_id).await?;
             log_step(
                 &sender,
                 log_step_id,
-                &format!("SQL 파일 실행: {}", display_path(path)),
+                &tf("engine.log.sql_file", &[("path", &display_path(path))]),
             );
-            execute_sql(&file_sql, target_db.as_deref(), handles, ctx).await?;
+            execute_sql(
+                &file_sql,
+                target_db.as_deref(),
+                guard,
+                handles,
+                ctx,
+                log_step_id,
+                &sender,
+            )
+            .await?;
         }
         StepKind::SqlLoaderPar { config } => {
+            let remote = match &config.remote {
+                Some(name) => Some(handles.get_remote(name)?),
+                None => None,
+            };
             run_sqlldr(
                 config,
+                remote,
                 ctx,
                 &sender,
                 log_step_id,
-                Duration::from_secs(step.timeout_sec.max(1)),
+                process_timeout(step),
             )
             .await?;
         }
         StepKind::Shell { config } => {
+            let remote = match &config.remote {
+                Some(name) => Some(handles.get_remote(name)?),
+                None => None,
+            };
             run_shell_command(
                 config,
+                remote,
                 ctx,
                 &sender,
                 log_step_id,
-                Duration::from_secs(step.timeout_sec.max(1)),
+                process_timeout(step),
             )
             .await?;
         }
         StepKind::Extract { config } => {
             execute_extract_step(config, ctx, log_step_id, &sender).await?;
         }
+        StepKind::Verify { config } => {
+            execute_verify_step(config, ctx, log_step_id, &sender).await?;
+        }
         StepKind::Loop { config } => {
             execute_loop_step(
                 config,
@@ -206,6 +607,11 @@ async fn execute_step_kind(
             )
             .await?;
         }
+        // 승인 대기, 대기, 실행 대기, 표식 Step은 run_step_attempts에서 처리하므로 여기까지 오지 않는다.
+        StepKind::ManualGate { .. }
+        | StepKind::Wait { .. }
+        | StepKind::WaitForRun { .. }
+        | StepKind::Marker => {}
     }
     Ok(())
 }
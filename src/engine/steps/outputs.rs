@@ -0,0 +1,74 @@
+use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
+use super::super::events::{EngineEvent, ExpectedOutput};
+use super::utils::log_step_at;
+use crate::i18n::tf;
+use crate::scenario::{MissingOutputPolicy, Step, StepLogLevel};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// 성공한 Step의 `expects_outputs` 경로가 있고 비어 있지 않은지 확인한다.
+///
+/// 경로는 실행 컨텍스트로 치환하며(`${STEP_TMPDIR}` 포함), 확인 결과를
+/// [`EngineEvent::StepOutputs`]로 알린다. 없거나 빈 산출물이 있으면 `on_missing_output`이 `fail`일
+/// 때 오류를 반환하고, `warn`이면 경고 로그만 남긴다. 원격 호스트에서 실행한 Step의 산출물은
+/// 이 호스트에 없으므로 확인하지 않고 그 사실만 로그에 남긴다.
+pub(super) async fn check_expected_outputs(
+    step: &Step,
+    ctx: &SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+) -> Result<(), EngineError> {
+    if step.expects_outputs.is_empty() {
+        return Ok(());
+    }
+    if let Some(remote) = step.remote_host() {
+        log_step_at(
+            sender,
+            &step.id,
+            StepLogLevel::Info,
+            &tf("engine.log.output_remote_skipped", &[("remote", &remote)]),
+        );
+        return Ok(());
+    }
+    let paths: Vec<String> = {
+        let guard = ctx.read().await;
+        step.expects_outputs
+            .iter()
+            .map(|path| guard.expand_lenient(path))
+            .collect()
+    };
+    let mut outputs = Vec::with_capacity(paths.len());
+    for path in paths {
+        let size = tokio::fs::metadata(&path)
+            .await
+            .ok()
+            .filter(|meta| meta.is_file())
+            .map(|meta| meta.len());
+        outputs.push(ExpectedOutput { path, size });
+    }
+    let missing: Vec<&ExpectedOutput> = outputs.iter().filter(|output| !output.ok()).collect();
+    for output in &missing {
+        let key = if output.size.is_some() {
+            "engine.log.output_empty"
+        } else {
+            "engine.log.output_missing"
+        };
+        log_step_at(
+            sender,
+            &step.id,
+            StepLogLevel::Warn,
+            &tf(key, &[("path", &output.path)]),
+        );
+    }
+    let result = match (missing.len(), step.on_missing_output) {
+        (0, _) | (_, MissingOutputPolicy::Warn) => Ok(()),
+        (count, MissingOutputPolicy::Fail) => Err(EngineError::Other(tf(
+            "engine.output_check_failed",
+            &[("count", &count)],
+        ))),
+    };
+    let _ = sender.send(EngineEvent::StepOutputs {
+        step_id: step.id.clone(),
+        outputs,
+    });
+    result
+}
@@ -0,0 +1,154 @@
+use super::super::error::EngineError;
+use super::super::events::EngineEvent;
+use crate::i18n::{t, tf};
+use crate::scenario::{IoPriorityClass, ProcessPriority, StepLogLevel};
+use tokio::process::Command;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::utils::log_step_at;
+
+/// 로컬에서 실행할 프로그램 명령을 만들고 우선순위·CPU 지정을 적용한다.
+///
+/// Unix에서는 `taskset`·`ionice`·`nice`를 프로그램 앞에 붙인다. 각 도구는 다음 프로그램으로 실행 이미지를
+/// 바꾸므로 실제 프로세스의 PID가 같아 타임아웃·취소로 종료할 때도 그대로 적용된다. 도구가 `PATH`에
+/// 없으면 실행 파일을 찾지 못한 프로그램이 스크립트처럼 보이지 않도록 미리 어떤 도구가 없는지 알린다.
+/// Windows에서는 `nice`를 가까운 우선순위 클래스로 바꿔 생성 플래그에 넣는다.
+///
+/// # 반환값
+/// 설정 값이 범위를 벗어나거나, 현재 운영체제에서 지원하지 않는 항목이거나, 필요한 도구가 없으면 설정
+/// 오류를 반환한다.
+pub(super) fn local_command(
+    program: &str,
+    priority: Option<&ProcessPriority>,
+) -> anyhow::Result<Command> {
+    let Some(priority) = priority.filter(|priority| !priority.is_empty()) else {
+        return Ok(Command::new(program));
+    };
+    priority
+        .validate()
+        .map_err(|err| EngineError::ConfigError(err.to_string()))?;
+    let linux_only = priority.io_class.is_some()
+        || priority.io_level.is_some()
+        || !priority.cpu_affinity.is_empty();
+    if linux_only && !cfg!(target_os = "linux") {
+        return Err(EngineError::ConfigError(t("engine.error.priority_linux_only").into()).into());
+    }
+    #[cfg(windows)]
+    {
+        let mut command = Command::new(program);
+        if let Some(nice) = priority.nice {
+            command.creation_flags(windows_priority_class(nice));
+        }
+        Ok(command)
+    }
+    #[cfg(not(windows))]
+    {
+        let wrappers = unix_wrappers(priority);
+        if let Some(missing) = wrappers
+            .iter()
+            .map(|wrapper| wrapper[0].as_str())
+            .find(|tool| !on_path(tool))
+        {
+            return Err(EngineError::ConfigError(tf(
+                "engine.error.priority_tool_missing",
+                &[("tool", &missing)],
+            ))
+            .into());
+        }
+        let mut argv: Vec<String> = wrappers.into_iter().flatten().collect();
+        argv.push(program.to_string());
+        let mut command = Command::new(&argv[0]);
+        command.args(&argv[1..]);
+        Ok(command)
+    }
+}
+
+/// 원격 호스트에서 실행할 `argv` 앞에 우선순위·CPU 지정 명령을 붙인다.
+///
+/// 원격 호스트는 POSIX 셸이 있는 Linux로 보며, 도구가 없으면 원격 명령이 실패한다.
+pub(super) fn remote_argv(
+    argv: Vec<String>,
+    priority: Option<&ProcessPriority>,
+) -> anyhow::Result<Vec<String>> {
+    let Some(priority) = priority.filter(|priority| !priority.is_empty()) else {
+        return Ok(argv);
+    };
+    priority
+        .validate()
+        .map_err(|err| EngineError::ConfigError(err.to_string()))?;
+    let mut prefixed: Vec<String> = unix_wrappers(priority).into_iter().flatten().collect();
+    prefixed.extend(argv);
+    Ok(prefixed)
+}
+
+/// 적용한 우선순위·CPU 지정을 `debug` 수준 로그로 남긴다.
+pub(super) fn log_priority(
+    sender: &UnboundedSender<EngineEvent>,
+    step_id: &str,
+    priority: Option<&ProcessPriority>,
+) {
+    if let Some(priority) = priority.filter(|priority| !priority.is_empty()) {
+        log_step_at(
+            sender,
+            step_id,
+            StepLogLevel::Debug,
+            &tf(
+                "engine.log.process_priority",
+                &[("detail", &priority.summary())],
+            ),
+        );
+    }
+}
+
+/// 프로그램 앞에 차례로 붙일 `taskset`·`ionice`·`nice` 명령을 만든다. 각 명령은 도구 이름으로 시작한다.
+fn unix_wrappers(priority: &ProcessPriority) -> Vec<Vec<String>> {
+    let mut wrappers = Vec::new();
+    if !priority.cpu_affinity.is_empty() {
+        let cpus: Vec<String> = priority.cpu_affinity.iter().map(usize::to_string).collect();
+        wrappers.push(vec![
+            "taskset".to_string(),
+            "-c".to_string(),
+            cpus.join(","),
+        ]);
+    }
+    if priority.io_class.is_some() || priority.io_level.is_some() {
+        let class = match priority.io_class.unwrap_or(IoPriorityClass::BestEffort) {
+            IoPriorityClass::Realtime => "1",
+            IoPriorityClass::BestEffort => "2",
+            IoPriorityClass::Idle => "3",
+        };
+        let mut ionice = vec!["ionice".to_string(), "-c".to_string(), class.to_string()];
+        if let Some(level) = priority.io_level {
+            ionice.extend(["-n".to_string(), level.to_string()]);
+        }
+        wrappers.push(ionice);
+    }
+    if let Some(nice) = priority.nice {
+        wrappers.push(vec!["nice".to_string(), "-n".to_string(), nice.to_string()]);
+    }
+    wrappers
+}
+
+/// `PATH`의 디렉터리에서 실행 파일을 찾을 수 있는지 확인한다.
+#[cfg(not(windows))]
+fn on_path(tool: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(tool).is_file()))
+}
+
+/// nice 값을 Windows 프로세스 우선순위 클래스 플래그로 바꾼다.
+#[cfg(windows)]
+fn windows_priority_class(nice: i8) -> u32 {
+    const IDLE_PRIORITY_CLASS: u32 = 0x0000_0040;
+    const BELOW_NORMAL_PRIORITY_CLASS: u32 = 0x0000_4000;
+    const NORMAL_PRIORITY_CLASS: u32 = 0x0000_0020;
+    const ABOVE_NORMAL_PRIORITY_CLASS: u32 = 0x0000_8000;
+    const HIGH_PRIORITY_CLASS: u32 = 0x0000_0080;
+    match nice {
+        15.. => IDLE_PRIORITY_CLASS,
+        5..=14 => BELOW_NORMAL_PRIORITY_CLASS,
+        -4..=4 => NORMAL_PRIORITY_CLASS,
+        -14..=-5 => ABOVE_NORMAL_PRIORITY_CLASS,
+        _ => HIGH_PRIORITY_CLASS,
+    }
+}
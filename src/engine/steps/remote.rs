@@ -0,0 +1,81 @@
+use crate::scenario::RemoteHostConfig;
+use std::collections::HashMap;
+use tokio::process::Command;
+
+/// 원격 호스트에서 `remote_command`를 실행하는 `ssh` 명령을 구성한다.
+///
+/// # 인자
+/// - `remote`: 접속할 원격 호스트 정의
+/// - `remote_command`: 원격 셸에서 해석될 명령 문자열
+///
+/// # 반환값
+/// 표준 출력/에러를 파이프로 연결하기 전의 `ssh` 명령 빌더
+///
+/// # 동작
+/// 비밀번호 프롬프트로 멈추지 않도록 `BatchMode=yes`를 지정하고, Step 타임아웃으로
+/// 명령이 버려지면 로컬 `ssh` 프로세스도 함께 종료되도록 한다. 원격 호스트의 프로세스는 연결이
+/// 끊겨도 계속 돌 수 있으므로 함께 멈춘다고 보장하지 않는다.
+pub(super) fn ssh_command(remote: &RemoteHostConfig, remote_command: &str) -> Command {
+    let mut command = Command::new("ssh");
+    command.arg("-o").arg("BatchMode=yes");
+    if let Some(port) = remote.port {
+        command.arg("-p").arg(port.to_string());
+    }
+    if let Some(identity) = &remote.identity_file {
+        command.arg("-i").arg(identity);
+    }
+    command.args(&remote.ssh_args);
+    let destination = match &remote.user {
+        Some(user) => format!("{user}@{}", remote.host),
+        None => remote.host.clone(),
+    };
+    command.arg(destination);
+    command.arg("--");
+    command.arg(remote_command);
+    command.kill_on_drop(true);
+    command
+}
+
+/// 원격 POSIX 셸에서 실행할 명령 줄을 조립한다.
+///
+/// # 인자
+/// - `remote`: 기본 디렉터리를 제공하는 원격 호스트 정의
+/// - `working_dir`: 기본 디렉터리 기준으로 추가 이동할 작업 디렉터리
+/// - `env`: 명령 앞에 지정할 환경 변수 목록
+/// - `clear_env`: 참이면 `env -i`로 원격 셸 환경을 비운 뒤 실행한다
+/// - `argv`: 실행할 프로그램과 인자 목록
+///
+/// # 반환값
+/// 모든 값이 작은따옴표로 감싸진 단일 명령 문자열
+pub(super) fn remote_command_line(
+    remote: &RemoteHostConfig,
+    working_dir: Option<&str>,
+    env: &HashMap<String, String>,
+    clear_env: bool,
+    argv: &[String],
+) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for dir in [remote.base_dir.as_deref(), working_dir]
+        .into_iter()
+        .flatten()
+    {
+        parts.push(format!("cd {} &&", shell_quote(dir)));
+    }
+    let mut env_pairs: Vec<_> = env.iter().collect();
+    env_pairs.sort();
+    if clear_env {
+        parts.push("env -i".to_string());
+    } else if !env_pairs.is_empty() {
+        parts.push("env".to_string());
+    }
+    for (key, value) in env_pairs {
+        parts.push(shell_quote(&format!("{key}={value}")));
+    }
+    parts.extend(argv.iter().map(|arg| shell_quote(arg)));
+    parts.join(" ")
+}
+
+/// POSIX 셸에서 문자 그대로 해석되도록 값을 작은따옴표로 감싼다.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
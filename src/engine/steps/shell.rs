@@ -1,41 +1,51 @@
 use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
 use super::super::events::EngineEvent;
-use crate::scenario::{ShellConfig, ShellErrorPolicy};
+use super::super::shell_command::shell_invocation;
+use crate::i18n::{t, tf};
+use crate::scenario::{RemoteHostConfig, ShellConfig, ShellErrorPolicy, StepLogLevel};
 use anyhow::Context;
 use std::time::Duration;
 use tokio::process::Command;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::time::sleep;
 
-use super::utils::log_step;
+use super::priority::{local_command, log_priority, remote_argv};
+use super::remote::{remote_command_line, ssh_command};
+use super::utils::log_step_at;
 use super::utils::pipe_forwarder;
 
+/// `inherit_env: false`여도 셸 자체가 동작하도록 유지하는 환경 변수이다.
+///
+/// Windows의 `cmd`는 `SystemRoot`/`ComSpec`이 없으면 기본 명령도 실패한다.
+pub(in crate::engine) const ESSENTIAL_ENV_VARS: &[&str] = if cfg!(target_os = "windows") {
+    &["SystemRoot", "ComSpec"]
+} else {
+    &[]
+};
+
 /// 쉘 명령을 실행하고 실시간 로그를 전달한다.
 ///
 /// # 인자
 /// - `config`: 쉘 프로그램, 스크립트, 환경 변수 설정을 담은 구성
-/// - `ctx`: 변수 치환에 사용되는 실행 컨텍스트 공유 포인터
+/// - `remote`: 지정되면 해당 원격 호스트에서 SSH로 실행한다. `priority`는 원격 프로세스에 적용한다
+/// - `ctx`: 변수 치환과 종료 코드 기록에 사용되는 실행 컨텍스트 공유 포인터
 /// - `sender`: 로그 및 이벤트를 내보낼 채널 송신자
 /// - `step_id`: 실행 중인 스텝의 식별자 문자열
-/// - `timeout_duration`: 명령 최대 대기 시간
+/// - `timeout_duration`: 명령 최대 대기 시간. `None`이면 끝날 때까지 기다린다
 ///
 /// # 반환값
 /// 성공 시 `Ok(())`를 반환하며, 실행 실패 또는 재시도 초과 시 에러를 반환한다.
 pub(super) async fn run_shell_command(
     config: &ShellConfig,
+    remote: Option<&RemoteHostConfig>,
     ctx: SharedExecutionContext,
     sender: &UnboundedSender<EngineEvent>,
     step_id: &str,
-    timeout_duration: Duration,
+    timeout_duration: Option<Duration>,
 ) -> anyhow::Result<()> {
-    let program = config.shell_program.clone().unwrap_or_else(|| {
-        if cfg!(target_os = "windows") {
-            "cmd"
-        } else {
-            "sh"
-        }
-        .to_string()
-    });
+    let (program, script_flag) =
+        shell_invocation(config.shell_program.as_deref(), remote.is_some());
     let script = {
         let guard = ctx.read().await;
         guard.expand_required(&config.script, "shell.command")?
@@ -63,34 +73,60 @@ pub(super) async fn run_shell_command(
         None
     };
     let run_as = config.run_as.clone();
+    if remote.is_some() && run_as.is_some() {
+        return Err(EngineError::ConfigError(t("engine.error.run_as_remote").into()).into());
+    }
+    let priority = config.priority.as_ref();
+    log_priority(sender, step_id, priority);
     let mut attempt: u32 = 0;
     loop {
         attempt += 1;
-        let mut command = Command::new(&program);
-        if cfg!(target_os = "windows") {
-            command.arg("/C");
-            command.arg(&script);
+        let mut command = if let Some(remote) = remote {
+            let mut argv = vec![program.clone(), script_flag.to_string(), script.clone()];
+            argv.extend(shell_args.iter().cloned());
+            let argv = remote_argv(argv, priority)?;
+            let line = remote_command_line(
+                remote,
+                working_dir.as_deref(),
+                &env_map,
+                !config.inherit_env,
+                &argv,
+            );
+            ssh_command(remote, &line)
         } else {
-            command.arg("-c");
+            let mut command = local_command(&program, priority)?;
+            command.arg(script_flag);
             command.arg(&script);
-        }
-        if !shell_args.is_empty() {
-            command.args(&shell_args);
-        }
-        if let Some(dir) = &working_dir {
-            command.current_dir(dir);
-        }
-        if !env_map.is_empty() {
-            command.envs(&env_map);
-        }
-        if let Some(user) = &run_as {
-            apply_user_context(&mut command, user)?;
-        }
+            if !shell_args.is_empty() {
+                command.args(&shell_args);
+            }
+            if let Some(dir) = &working_dir {
+                command.current_dir(dir);
+            }
+            if !config.inherit_env {
+                command.env_clear();
+                command.envs(
+                    ESSENTIAL_ENV_VARS
+                        .iter()
+                        .filter_map(|key| std::env::var(key).ok().map(|value| (*key, value))),
+                );
+            }
+            if !env_map.is_empty() {
+                command.envs(&env_map);
+            }
+            if let Some(user) = &run_as {
+                apply_user_context(&mut command, user)?;
+            }
+            command
+        };
         command.stdout(std::process::Stdio::piped());
         command.stderr(std::process::Stdio::piped());
+        // 실행을 취소하거나 앱을 종료해 Step future가 버려지면 프로세스도 함께 종료한다.
+        // 원격 실행이면 로컬 ssh 클라이언트만 종료되고 원격 프로세스는 남을 수 있다.
+        command.kill_on_drop(true);
         let mut child = command
             .spawn()
-            .with_context(|| format!("쉘 명령 실행 실패: {script}"))?;
+            .with_context(|| tf("engine.error.shell_spawn_script", &[("script", &script)]))?;
         if let Some(stdout) = child.stdout.take() {
             tokio::spawn(pipe_forwarder(
                 stdout,
@@ -107,19 +143,32 @@ pub(super) async fn run_shell_command(
                 "STDERR",
             ));
         }
-        let status = tokio::time::timeout(timeout_duration, child.wait()).await??;
+        let status = match timeout_duration {
+            Some(limit) => tokio::time::timeout(limit, child.wait()).await??,
+            None => child.wait().await?,
+        };
+        if let Some(code) = status.code() {
+            ctx.write()
+                .await
+                .set_step_result(step_id, "exit_code", code);
+        }
         if status.success() {
             return Ok(());
         }
         match &config.error_policy {
             ShellErrorPolicy::Fail => {
-                return Err(anyhow::anyhow!(format!("쉘 명령 종료 코드: {status}")));
+                return Err(EngineError::ProcessExit {
+                    code: status.code(),
+                    message: tf("engine.error.shell_exit", &[("status", &status)]),
+                }
+                .into());
             }
             ShellErrorPolicy::Ignore => {
-                log_step(
+                log_step_at(
                     sender,
                     step_id,
-                    &format!("비정상 종료 코드 {status}, 정책에 따라 무시"),
+                    StepLogLevel::Warn,
+                    &tf("engine.log.shell_exit_ignored", &[("status", &status)]),
                 );
                 return Ok(());
             }
@@ -128,18 +177,23 @@ pub(super) async fn run_shell_command(
                 delay_secs,
             } => {
                 if attempt > max_retries + 1 {
-                    return Err(anyhow::anyhow!(format!(
-                        "재시도 한도를 초과했습니다: {status}"
-                    )));
+                    return Err(EngineError::ProcessExit {
+                        code: status.code(),
+                        message: tf("engine.error.shell_retry_exhausted", &[("status", &status)]),
+                    }
+                    .into());
                 }
-                log_step(
+                log_step_at(
                     sender,
                     step_id,
-                    &format!(
-                        "쉘 명령 실패, {}초 후 재시도 ({}/{})",
-                        delay_secs,
-                        attempt,
-                        max_retries + 1
+                    StepLogLevel::Warn,
+                    &tf(
+                        "engine.log.shell_retry",
+                        &[
+                            ("secs", delay_secs),
+                            ("attempt", &attempt),
+                            ("max", &(max_retries + 1)),
+                        ],
                     ),
                 );
                 sleep(Duration::from_secs(*delay_secs)).await;
@@ -168,9 +222,7 @@ fn apply_user_context(command: &mut Command, user: &str) -> anyhow::Result<()> {
     #[cfg(not(unix))]
     {
         let _ = user;
-        Err(anyhow::anyhow!(
-            "run_as는 현재 운영체제에서 지원되지 않습니다."
-        ))
+        Err(anyhow::anyhow!(t("engine.error.run_as_unsupported")))
     }
 }
 
@@ -183,8 +235,8 @@ fn apply_user_context(command: &mut Command, user: &str) -> anyhow::Result<()> {
 /// `(uid, gid)` 튜플을 반환하며, 존재하지 않을 경우 에러를 반환한다.
 #[cfg(unix)]
 fn lookup_unix_user(user: &str) -> anyhow::Result<(u32, u32)> {
-    let content = std::fs::read_to_string("/etc/passwd")
-        .with_context(|| "/etc/passwd 파일을 읽을 수 없습니다.".to_string())?;
+    let content =
+        std::fs::read_to_string("/etc/passwd").with_context(|| t("engine.error.passwd_read"))?;
     for line in content.lines() {
         if line.trim().is_empty() || line.starts_with('#') {
             continue;
@@ -196,12 +248,15 @@ fn lookup_unix_user(user: &str) -> anyhow::Result<(u32, u32)> {
         if parts[0] == user {
             let uid: u32 = parts[2]
                 .parse()
-                .with_context(|| format!("UID 파싱 실패: {}", parts[2]))?;
+                .with_context(|| tf("engine.error.uid_parse", &[("value", &parts[2])]))?;
             let gid: u32 = parts[3]
                 .parse()
-                .with_context(|| format!("GID 파싱 실패: {}", parts[3]))?;
+                .with_context(|| tf("engine.error.gid_parse", &[("value", &parts[3])]))?;
             return Ok((uid, gid));
         }
     }
-    Err(anyhow::anyhow!("사용자 {user} 정보를 찾을 수 없습니다."))
+    Err(anyhow::anyhow!(tf(
+        "engine.error.user_not_found",
+        &[("user", &user)]
+    )))
 }
@@ -0,0 +1,88 @@
+use super::super::context::SharedExecutionContext;
+use super::super::events::EngineEvent;
+use super::super::resources::EngineHandles;
+use super::utils::{log_step, log_step_at};
+use crate::i18n::{t, tf};
+use crate::scenario::{StepLogLevel, StepSkipIf};
+use std::path::Path;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// 건너뛰기 조회 하나에 허용하는 최대 실행 시간이다.
+const SKIP_IF_QUERY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Step의 `skip_if` 조건을 확인해 지정한 조건을 모두 충족하면 `true`를 반환한다.
+///
+/// 충족한 조건은 Step 로그에 남긴다. 조건을 확인하지 못하면(치환 실패, 조회 오류 등) 그 사실을
+/// 로그에 남기고 `false`를 반환해 Step을 그대로 실행한다.
+pub(super) async fn should_skip(
+    step_id: &str,
+    config: &StepSkipIf,
+    handles: &EngineHandles,
+    ctx: &SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+) -> bool {
+    if config.is_empty() {
+        return false;
+    }
+    match satisfied_reasons(config, handles, ctx).await {
+        Ok(Some(reasons)) => {
+            for reason in reasons {
+                log_step(sender, step_id, &reason);
+            }
+            true
+        }
+        Ok(None) => false,
+        Err(err) => {
+            log_step_at(
+                sender,
+                step_id,
+                StepLogLevel::Warn,
+                &tf(
+                    "engine.log.skip_if_failed",
+                    &[("error", &format!("{err:#}"))],
+                ),
+            );
+            false
+        }
+    }
+}
+
+/// 조건을 차례로 확인해 모두 충족하면 조건별 로그 문구를 반환한다. 하나라도 충족하지 않으면 `None`이다.
+async fn satisfied_reasons(
+    config: &StepSkipIf,
+    handles: &EngineHandles,
+    ctx: &SharedExecutionContext,
+) -> anyhow::Result<Option<Vec<String>>> {
+    let mut reasons = Vec::new();
+    if let Some(template) = &config.file_exists {
+        let path = ctx
+            .read()
+            .await
+            .expand_required(template, "skip_if.file_exists")?;
+        if !Path::new(&path).exists() {
+            return Ok(None);
+        }
+        reasons.push(tf("engine.log.skip_if_file", &[("path", &path)]));
+    }
+    if let Some(template) = &config.sql_returns_rows {
+        let sql = ctx
+            .read()
+            .await
+            .expand_required(template, "skip_if.sql_returns_rows")?;
+        let executor = handles.get_db_executor(config.target_db.as_deref().unwrap_or("default"))?;
+        let rows = tokio::time::timeout(SKIP_IF_QUERY_TIMEOUT, executor.query_rows(&sql, 1))
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!(tf(
+                    "engine.error.query_timeout",
+                    &[("secs", &SKIP_IF_QUERY_TIMEOUT.as_secs())]
+                ))
+            })??;
+        if rows.rows.is_empty() {
+            return Ok(None);
+        }
+        reasons.push(t("engine.log.skip_if_sql").to_string());
+    }
+    Ok(Some(reasons))
+}
@@ -1,30 +1,172 @@
 use super::super::context::SharedExecutionContext;
+use super::super::events::EngineEvent;
 use super::super::resources::EngineHandles;
+use super::super::run_log::log_dir;
+use super::super::run_tmpdir::sanitize;
+use super::utils::log_step_at;
+use crate::executor::{DbExecutor, SqlGuard, has_statement_separator};
+use crate::history::save_sql_snapshot;
+use crate::i18n::tf;
+use crate::scenario::{SqlGuardConfig, StepLogLevel};
 use anyhow::Context;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::fs;
+use tokio::sync::mpsc::UnboundedSender;
 
 /// SQL 문자열을 실행한다.
+///
+/// `guard`에 안전장치가 설정되어 있으면 실행기의 안전장치 경로로 실행한다.
+/// 실행기가 변경 행 수를 알려 주면 `STEP.<step_id>.rows_affected`로 남긴다. 시나리오의
+/// `explain_slow_sql_sec`보다 오래 걸렸으면 성공 여부와 관계없이 실행 계획을 남긴다. 실패했거나
+/// 제한 시간을 넘긴 문장은 다시 실행하지 않도록 예상 계획만 받고, 성공한 조회는 원래 소요 시간의
+/// 두 배(`statement_timeout_sec`이 더 짧으면 그 시간) 안에서만 실제 실행 통계를 받는다.
 pub(super) async fn execute_sql(
     sql: &str,
     target_db: Option<&str>,
+    guard: &SqlGuardConfig,
     handles: Arc<EngineHandles>,
     ctx: SharedExecutionContext,
+    step_id: &str,
+    sender: &UnboundedSender<EngineEvent>,
 ) -> anyhow::Result<()> {
-    let expanded_sql = {
+    let (expanded_sql, explain_after) = {
         let guard = ctx.read().await;
-        guard.expand_required(sql, "sql")?
+        (guard.expand_required(sql, "sql")?, guard.explain_slow_sql())
     };
     let target = target_db.unwrap_or("default");
     let executor = handles.get_db_executor(target)?;
-    executor.execute_sql(&expanded_sql).await
+    let started = Instant::now();
+    let result = if guard.is_empty() {
+        executor.execute_sql(&expanded_sql).await
+    } else {
+        let guard = SqlGuard {
+            statement_timeout: guard.statement_timeout_sec.map(Duration::from_secs),
+            max_affected_rows: guard.max_affected_rows,
+        };
+        match executor.execute_sql_guarded(&expanded_sql, guard).await {
+            Ok(Some(rows)) => {
+                ctx.write()
+                    .await
+                    .set_step_result(step_id, "rows_affected", rows);
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        }
+    };
+    let elapsed = started.elapsed();
+    if let Some(threshold) = explain_after
+        && elapsed >= threshold
+    {
+        let analyze_timeout = result.is_ok().then(|| {
+            let limit = elapsed.saturating_mul(2);
+            guard
+                .statement_timeout_sec
+                .map_or(limit, |secs| limit.min(Duration::from_secs(secs)))
+        });
+        let plan = PlanRequest {
+            sql: &expanded_sql,
+            elapsed,
+            analyze_timeout,
+        };
+        capture_plan(&*executor, plan, &ctx, step_id, sender).await;
+    }
+    result
+}
+
+/// 실행 계획을 받을 느린 SQL 문장이다.
+struct PlanRequest<'a> {
+    /// 치환한 SQL.
+    sql: &'a str,
+    /// 문장 실행에 걸린 시간.
+    elapsed: Duration,
+    /// 조회를 다시 실행해 실제 통계를 받을 최대 시간. `None`이면 예상 계획만 받는다.
+    analyze_timeout: Option<Duration>,
+}
+
+/// 느린 SQL의 실행 계획을 받아 `<로그 디렉터리>/plans/<step_id>-<시각>.txt`에 남긴다.
+///
+/// 파일에는 소요 시간, 문장, 실행 계획을 비밀 값을 가려 쓰고 경로를 `STEP.<step_id>.plan_file`로
+/// 남긴다. 여러 문장이거나 실행 계획을 받지 못하면 경고 로그만 남기고 Step 결과에는 영향을 주지 않는다.
+async fn capture_plan(
+    executor: &dyn DbExecutor,
+    request: PlanRequest<'_>,
+    ctx: &SharedExecutionContext,
+    step_id: &str,
+    sender: &UnboundedSender<EngineEvent>,
+) {
+    let secs = request.elapsed.as_secs().to_string();
+    let statement = request.sql.trim().trim_end_matches(['/', ';']);
+    if has_statement_separator(statement) {
+        log_step_at(
+            sender,
+            step_id,
+            StepLogLevel::Warn,
+            &tf("engine.log.sql_plan_multi", &[("secs", &secs)]),
+        );
+        return;
+    }
+    let saved = match executor
+        .explain_plan(statement, request.analyze_timeout)
+        .await
+    {
+        Ok(plan) => {
+            let elapsed = tf("engine.plan.elapsed", &[("secs", &secs)]);
+            let content = ctx.read().await.mask(&format!(
+                "-- Step: {step_id}\n-- {elapsed}\n-- SQL:\n{statement}\n\n{plan}\n"
+            ));
+            let dir = log_dir().join("plans");
+            let path = dir.join(format!(
+                "{}-{}.txt",
+                sanitize(step_id),
+                chrono::Local::now().format("%Y%m%d-%H%M%S%3f")
+            ));
+            let written = match fs::create_dir_all(&dir).await {
+                Ok(()) => fs::write(&path, content).await,
+                Err(err) => Err(err),
+            };
+            written.map(|()| path).map_err(anyhow::Error::from)
+        }
+        Err(err) => Err(err),
+    };
+    match saved {
+        Ok(path) => {
+            let path = path.display().to_string();
+            ctx.write()
+                .await
+                .set_step_result(step_id, "plan_file", &path);
+            log_step_at(
+                sender,
+                step_id,
+                StepLogLevel::Warn,
+                &tf(
+                    "engine.log.sql_plan_saved",
+                    &[("secs", &secs), ("path", &path)],
+                ),
+            );
+        }
+        Err(err) => log_step_at(
+            sender,
+            step_id,
+            StepLogLevel::Warn,
+            &tf(
+                "engine.log.sql_plan_failed",
+                &[("secs", &secs), ("error", &format!("{err:#}"))],
+            ),
+        ),
+    }
 }
 
-/// SQL 파일을 읽어 문자열을 반환한다.
+/// SQL 파일을 읽어 플레이스홀더를 치환한 문자열을 반환한다.
+///
+/// 다음에 시나리오를 열 때 바뀐 SQL을 보여 줄 수 있도록 치환 전 내용을 스냅숏으로 남기고 해시를
+/// `STEP.<step_id>.sql_sha256`으로 남긴다. 스냅숏을 남기지 못해도 실행은 계속한다.
 pub(super) async fn load_sql_file(
     path: &PathBuf,
     ctx: SharedExecutionContext,
+    step_id: &str,
 ) -> anyhow::Result<String> {
     let raw = path.to_string_lossy().to_string();
     let actual_path = {
@@ -33,7 +175,14 @@ pub(super) async fn load_sql_file(
     };
     let content = fs::read_to_string(&actual_path)
         .await
-        .with_context(|| format!("SQL 파일 읽기 실패: {actual_path}"))?;
+        .with_context(|| tf("engine.error.sql_file_read", &[("path", &actual_path)]))?;
+    match save_sql_snapshot(&content).await {
+        Ok(hash) => ctx
+            .write()
+            .await
+            .set_step_result(step_id, "sql_sha256", hash),
+        Err(err) => tracing::warn!("SQL 스냅숏 저장 실패: {err}"),
+    }
     let guard = ctx.read().await;
     guard.expand_required(&content, "sql_file_content")
 }
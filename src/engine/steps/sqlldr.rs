@@ -1,20 +1,32 @@
 use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
 use super::super::events::EngineEvent;
-use crate::scenario::SqlLoaderParConfig;
+use crate::i18n::{t, tf};
+use crate::scenario::{RemoteHostConfig, SqlLoaderParConfig};
 use anyhow::Context;
+use std::collections::HashMap;
 use std::time::Duration;
-use tokio::process::Command;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc::UnboundedSender;
 
+use super::priority::{local_command, log_priority, remote_argv};
+use super::remote::{remote_command_line, ssh_command};
 use super::utils::{expand_option_path, expand_path, pipe_forwarder};
 
 /// sqlldr 프로세스를 실행한다.
+///
+/// `remote`가 지정되면 control/data 등 모든 경로를 원격 호스트 기준으로 해석해 SSH로 실행한다.
+/// 인자는 접속 문자열, 파일 경로, `options`, 치환한 `extra_args` 순으로 붙인다. 원격 실행에서는 비밀번호가
+/// 원격 호스트의 프로세스 목록에 보이지 않도록 접속 문자열을 인자 대신 sqlldr의 사용자 이름 프롬프트에
+/// 표준 입력으로 넘긴다. `priority`가 있으면
+/// 프로세스 우선순위와 CPU 지정을 적용한다.
 pub(super) async fn run_sqlldr(
     config: &SqlLoaderParConfig,
+    remote: Option<&RemoteHostConfig>,
     ctx: SharedExecutionContext,
     sender: &UnboundedSender<EngineEvent>,
     step_id: &str,
-    timeout_duration: Duration,
+    timeout_duration: Option<Duration>,
 ) -> anyhow::Result<()> {
     let conn = if let Some(conn) = &config.conn {
         let guard = ctx.read().await;
@@ -23,31 +35,61 @@ pub(super) async fn run_sqlldr(
         let guard = ctx.read().await;
         guard
             .get_or_env("SQLLDR_CONN")
-            .ok_or_else(|| anyhow::anyhow!("SQLLDR_CONN 값을 찾을 수 없습니다."))?
+            .ok_or_else(|| EngineError::ConfigError(t("engine.error.sqlldr_conn").into()))?
     };
     let control = expand_path(&config.control_file, ctx.clone(), "control").await?;
     let data = expand_option_path(config.data_file.as_ref(), ctx.clone(), "data").await?;
     let log = expand_option_path(config.log_file.as_ref(), ctx.clone(), "log").await?;
     let bad = expand_option_path(config.bad_file.as_ref(), ctx.clone(), "bad").await?;
-    let discard = expand_option_path(config.discard_file.as_ref(), ctx, "discard").await?;
-    let mut command = Command::new("sqlldr");
-    command.arg(conn);
-    command.arg(format!("control={control}"));
+    let discard = expand_option_path(config.discard_file.as_ref(), ctx.clone(), "discard").await?;
+    let mut args = vec![format!("control={control}")];
     if let Some(val) = data {
-        command.arg(format!("data={val}"));
+        args.push(format!("data={val}"));
     }
     if let Some(val) = log {
-        command.arg(format!("log={val}"));
+        args.push(format!("log={val}"));
     }
     if let Some(val) = bad {
-        command.arg(format!("bad={val}"));
+        args.push(format!("bad={val}"));
     }
     if let Some(val) = discard {
-        command.arg(format!("discard={val}"));
+        args.push(format!("discard={val}"));
     }
+    args.extend(config.options.to_args());
+    {
+        let guard = ctx.read().await;
+        for arg in &config.extra_args {
+            args.push(guard.expand_required(arg, "sqlldr.extra_args")?);
+        }
+    }
+    let priority = config.priority.as_ref();
+    log_priority(sender, step_id, priority);
+    let mut command = if let Some(remote) = remote {
+        let mut argv = vec!["sqlldr".to_string()];
+        argv.extend(args);
+        let argv = remote_argv(argv, priority)?;
+        let line = remote_command_line(remote, None, &HashMap::new(), false, &argv);
+        let mut command = ssh_command(remote, &line);
+        command.stdin(std::process::Stdio::piped());
+        command
+    } else {
+        let mut command = local_command("sqlldr", priority)?;
+        command.arg(&conn);
+        command.args(&args);
+        command
+    };
     command.stdout(std::process::Stdio::piped());
     command.stderr(std::process::Stdio::piped());
-    let mut child = command.spawn().context("sqlldr 실행 실패")?;
+    // 실행을 취소하거나 앱을 종료해 Step future가 버려지면 프로세스도 함께 종료한다.
+    // 원격 실행이면 로컬 ssh 클라이언트만 종료되고 원격 프로세스는 남을 수 있다.
+    command.kill_on_drop(true);
+    let mut child = command.spawn().context(t("engine.error.sqlldr_spawn"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(format!("{conn}\n").as_bytes())
+            .await
+            .context(t("engine.error.sqlldr_stdin"))?;
+    }
     if let Some(stdout) = child.stdout.take() {
         tokio::spawn(pipe_forwarder(
             stdout,
@@ -64,10 +106,22 @@ pub(super) async fn run_sqlldr(
             "sqlldr STDERR",
         ));
     }
-    let status = tokio::time::timeout(timeout_duration, child.wait()).await??;
+    let status = match timeout_duration {
+        Some(limit) => tokio::time::timeout(limit, child.wait()).await??,
+        None => child.wait().await?,
+    };
+    if let Some(code) = status.code() {
+        ctx.write()
+            .await
+            .set_step_result(step_id, "exit_code", code);
+    }
     if status.success() {
         Ok(())
     } else {
-        Err(anyhow::anyhow!("sqlldr 종료 코드: {status}"))
+        Err(EngineError::ProcessExit {
+            code: status.code(),
+            message: tf("engine.error.sqlldr_exit", &[("status", &status)]),
+        }
+        .into())
     }
 }
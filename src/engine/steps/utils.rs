@@ -1,5 +1,7 @@
-use super::super::context::SharedExecutionContext;
+use super::super::context::{STEP_LOG_LEVEL, SharedExecutionContext};
 use super::super::events::EngineEvent;
+use crate::i18n::tf;
+use crate::scenario::StepLogLevel;
 use encoding::DecoderTrap;
 use encoding::Encoding;
 use encoding::all::WINDOWS_949;
@@ -8,19 +10,48 @@ use std::path::PathBuf;
 use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::sync::mpsc::UnboundedSender;
 
-/// Step 로그를 전송한다.
+/// `info` 수준 Step 로그를 전송한다. 실행 중인 Step의 로그 수준이 `warn`이면 보내지 않는다.
 ///
 /// # 인자
 /// - `sender`: 로그 이벤트를 내보낼 채널 송신자
 /// - `step_id`: 로그가 속한 스텝의 식별자
 /// - `line`: 전송할 로그 문자열
 pub(super) fn log_step(sender: &UnboundedSender<EngineEvent>, step_id: &str, line: &str) {
+    log_step_at(sender, step_id, StepLogLevel::Info, line);
+}
+
+/// 지정한 수준의 Step 로그를 실행 중인 Step의 로그 수준 이상일 때만 전송한다.
+///
+/// # 인자
+/// - `sender`: 로그 이벤트를 내보낼 채널 송신자
+/// - `step_id`: 로그가 속한 스텝의 식별자
+/// - `level`: 이 메시지의 수준
+/// - `line`: 전송할 로그 문자열
+pub(super) fn log_step_at(
+    sender: &UnboundedSender<EngineEvent>,
+    step_id: &str,
+    level: StepLogLevel,
+    line: &str,
+) {
+    if !log_enabled(level) {
+        return;
+    }
     let _ = sender.send(EngineEvent::StepLog {
         step_id: step_id.to_string(),
         line: line.to_string(),
     });
 }
 
+/// 실행 중인 Step이 이 수준의 로그를 남기는지 확인한다. 메시지를 만드는 비용이 클 때 먼저 확인한다.
+pub(super) fn log_enabled(level: StepLogLevel) -> bool {
+    level >= current_log_level()
+}
+
+/// 실행 중인 Step의 엔진 로그 수준을 반환한다. Step 밖이면 기본값(`info`)이다.
+pub(super) fn current_log_level() -> StepLogLevel {
+    STEP_LOG_LEVEL.try_with(|level| *level).unwrap_or_default()
+}
+
 /// 경로 정보를 보기 좋게 변환한다.
 ///
 /// # 인자
@@ -115,7 +146,10 @@ pub(super) async fn pipe_forwarder<R>(
             Err(err) => {
                 let _ = sender.send(EngineEvent::StepLog {
                     step_id: step_id.clone(),
-                    line: format!("{tag} 읽기 오류: {err}"),
+                    line: tf(
+                        "engine.log.pipe_read_error",
+                        &[("tag", &tag), ("error", &err)],
+                    ),
                 });
                 break; // 에러 발생 시 종료
             }
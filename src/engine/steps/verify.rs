@@ -0,0 +1,159 @@
+use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
+use super::super::events::EngineEvent;
+use crate::i18n::{t, tf};
+use crate::scenario::{StepLogLevel, VerifyConfig, VerifyMethod};
+use anyhow::Context;
+use sha2::Digest;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::utils::{log_step, log_step_at};
+
+/// 파일을 읽을 때 한 번에 읽는 크기이다.
+const READ_CHUNK_BYTES: usize = 64 * 1024;
+
+/// 검증 Step을 실행한다. 계산값이 기대값과 다르면 두 값과 출처를 담은 오류로 실패한다.
+pub(super) async fn execute_verify_step(
+    config: &VerifyConfig,
+    ctx: SharedExecutionContext,
+    step_id: &str,
+    sender: &UnboundedSender<EngineEvent>,
+) -> anyhow::Result<()> {
+    let (file_path, expected) = {
+        let guard = ctx.read().await;
+        let file_path = guard.expand_required(&config.file, "verify.file")?;
+        let expected = match (&config.expected, &config.expected_file) {
+            (Some(_), Some(_)) => {
+                return Err(EngineError::ConfigError(t("engine.error.verify_both").into()).into());
+            }
+            (Some(value), None) => {
+                ExpectedSource::Value(guard.expand_required(value, "verify.expected")?)
+            }
+            (None, Some(path)) => {
+                ExpectedSource::File(guard.expand_required(path, "verify.expected_file")?)
+            }
+            (None, None) if config.method == VerifyMethod::Lines => {
+                return Err(EngineError::ConfigError(
+                    t("engine.error.verify_lines_expected").into(),
+                )
+                .into());
+            }
+            (None, None) => ExpectedSource::File(format!("{file_path}.{}", config.method.as_str())),
+        };
+        (file_path, expected)
+    };
+    let (expected_value, source) = match expected {
+        ExpectedSource::Value(value) => (value.trim().to_string(), "expected".to_string()),
+        ExpectedSource::File(path) => (read_expected_file(&path).await?, path),
+    };
+    let actual = compute(&file_path, config.method).await?;
+    let method = config.method.as_str();
+    log_step_at(
+        sender,
+        step_id,
+        StepLogLevel::Debug,
+        &format!("{method} {file_path} = {actual}"),
+    );
+    let matched = match config.method {
+        VerifyMethod::Lines => {
+            let expected: u64 = expected_value.parse().map_err(|_| {
+                EngineError::ConfigError(tf(
+                    "engine.error.verify_lines_nan",
+                    &[("expected", &expected_value), ("source", &source)],
+                ))
+            })?;
+            expected.to_string() == actual
+        }
+        VerifyMethod::Md5 | VerifyMethod::Sha256 => expected_value.eq_ignore_ascii_case(&actual),
+    };
+    if !matched {
+        let what = match config.method {
+            VerifyMethod::Lines => t("engine.verify.lines").to_string(),
+            VerifyMethod::Md5 | VerifyMethod::Sha256 => {
+                tf("engine.verify.checksum", &[("method", &method)])
+            }
+        };
+        anyhow::bail!(tf(
+            "engine.error.verify_mismatch",
+            &[
+                ("what", &what),
+                ("path", &file_path),
+                ("expected", &expected_value),
+                ("source", &source),
+                ("actual", &actual),
+            ],
+        ));
+    }
+    log_step(
+        sender,
+        step_id,
+        &tf("engine.log.verify_passed", &[("source", &source)]),
+    );
+    Ok(())
+}
+
+/// 기대값을 어디서 읽을지 나타낸다.
+enum ExpectedSource {
+    /// 치환을 마친 값.
+    Value(String),
+    /// 기대값을 담은 파일 경로.
+    File(String),
+}
+
+/// 기대값 파일에서 첫 줄의 첫 단어를 읽는다. `md5sum`/`sha256sum` 출력도 그대로 쓸 수 있다.
+async fn read_expected_file(path: &str) -> anyhow::Result<String> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| tf("engine.error.verify_expected_read", &[("path", &path)]))?;
+    content
+        .split_whitespace()
+        .next()
+        .map(str::to_string)
+        .ok_or_else(|| {
+            anyhow::anyhow!(tf("engine.error.verify_expected_empty", &[("path", &path)]))
+        })
+}
+
+/// 파일 전체를 나눠 읽으며 체크섬(소문자 16진수)이나 줄 수를 계산한다.
+async fn compute(path: &str, method: VerifyMethod) -> anyhow::Result<String> {
+    let mut file = File::open(path)
+        .await
+        .with_context(|| tf("engine.error.file_open", &[("path", &path)]))?;
+    let mut buf = vec![0u8; READ_CHUNK_BYTES];
+    let mut md5 = md5::Md5::new();
+    let mut sha256 = sha2::Sha256::new();
+    let mut lines: u64 = 0;
+    let mut last_byte = b'\n';
+    loop {
+        let read = file.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        let chunk = &buf[..read];
+        match method {
+            VerifyMethod::Md5 => md5.update(chunk),
+            VerifyMethod::Sha256 => sha256.update(chunk),
+            VerifyMethod::Lines => {
+                lines += chunk.iter().filter(|byte| **byte == b'\n').count() as u64;
+                last_byte = chunk[read - 1];
+            }
+        }
+    }
+    Ok(match method {
+        VerifyMethod::Md5 => hex(&md5.finalize()),
+        VerifyMethod::Sha256 => hex(&sha256.finalize()),
+        VerifyMethod::Lines => {
+            if last_byte != b'\n' {
+                lines += 1;
+            }
+            lines.to_string()
+        }
+    })
+}
+
+/// 바이트 목록을 소문자 16진수 문자열로 바꾼다.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,68 @@
+use super::super::error::EngineError;
+use super::super::events::EngineEvent;
+use super::utils::{log_step, log_step_at};
+use crate::i18n::{t, tf};
+use crate::scenario::{Step, StepLogLevel, WaitConfig};
+use chrono::Local;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+/// 대기 중 남은 시간을 로그로 알리는 간격이다.
+const COUNTDOWN_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 대기 Step을 실행한다. 지정한 시간만큼(또는 지정 시각까지) 기다리며 남은 시간을 주기적으로 로그에 남긴다.
+///
+/// # 매개변수
+/// - `step`: 대상 Step.
+/// - `config`: 대기 설정.
+/// - `sender`: 로그를 전달할 이벤트 채널.
+/// - `cancel`: 대기 중 취소를 감지할 토큰. 취소되면 즉시 `Cancelled`로 끝난다.
+pub(super) async fn execute_wait_step(
+    step: &Step,
+    config: &WaitConfig,
+    sender: &UnboundedSender<EngineEvent>,
+    cancel: &CancellationToken,
+) -> Result<(), EngineError> {
+    let now = Local::now();
+    let wait = config
+        .remaining(now.time())
+        .map_err(|err| EngineError::ConfigError(err.to_string()))?;
+    let until = (now + chrono::Duration::from_std(wait).unwrap_or_default())
+        .format("%H:%M:%S")
+        .to_string();
+    log_step(
+        sender,
+        &step.id,
+        &tf(
+            "engine.log.wait_start",
+            &[("secs", &wait.as_secs()), ("until", &until)],
+        ),
+    );
+    let deadline = Instant::now() + wait;
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            break;
+        }
+        tokio::select! {
+            _ = cancel.cancelled() => return Err(EngineError::Cancelled),
+            _ = tokio::time::sleep(left.min(COUNTDOWN_INTERVAL)) => {}
+        }
+        let left = deadline.saturating_duration_since(Instant::now());
+        if !left.is_zero() {
+            log_step_at(
+                sender,
+                &step.id,
+                StepLogLevel::Debug,
+                &tf(
+                    "engine.log.wait_remaining",
+                    &[("secs", &(left.as_millis() as u64).div_ceil(1000))],
+                ),
+            );
+        }
+    }
+    log_step(sender, &step.id, t("engine.log.wait_done"));
+    Ok(())
+}
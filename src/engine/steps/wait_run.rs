@@ -0,0 +1,125 @@
+use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
+use super::super::events::EngineEvent;
+use super::utils::{log_step, log_step_at};
+use crate::i18n::{t, tf};
+use crate::run_dashboard::last_successful_run;
+use crate::scenario::{Step, StepLogLevel, WaitForRunConfig};
+use chrono::Local;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+/// 다른 시나리오의 실행 완료 대기 Step을 실행한다.
+///
+/// `poll_sec`마다 실행 이력 파일을 다시 읽어 대상 시나리오가 기준 시각 뒤에 성공한 실행이 있는지
+/// 확인하며, 없으면 다음 확인까지 기다린다는 로그를 남긴다. 찾으면 그 실행의 종료 시각과 실행 ID를
+/// `STEP.<id>.finished_at`, `STEP.<id>.run_id`로 남긴다.
+///
+/// # 매개변수
+/// - `step`: 대상 Step.
+/// - `config`: 실행 대기 설정. `scenario`와 `since`는 컨텍스트로 치환한다.
+/// - `ctx`: 치환과 결과 기록에 쓸 실행 컨텍스트.
+/// - `sender`: 로그를 전달할 이벤트 채널.
+/// - `cancel`: 대기 중 취소를 감지할 토큰. 취소되면 즉시 `Cancelled`로 끝난다.
+pub(super) async fn execute_wait_for_run_step(
+    step: &Step,
+    config: &WaitForRunConfig,
+    ctx: SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+    cancel: &CancellationToken,
+) -> Result<(), EngineError> {
+    let (scenario, since) = {
+        let guard = ctx.read().await;
+        let scenario = guard
+            .expand_required(&config.scenario, "wait_for_run.scenario")
+            .map_err(|err| EngineError::ConfigError(format!("{err:#}")))?;
+        let since = config
+            .since
+            .as_deref()
+            .map(|since| guard.expand_required(since, "wait_for_run.since"))
+            .transpose()
+            .map_err(|err| EngineError::ConfigError(format!("{err:#}")))?;
+        (scenario.trim().to_string(), since)
+    };
+    if scenario.is_empty() {
+        return Err(EngineError::ConfigError(
+            t("engine.error.wait_for_run_scenario").into(),
+        ));
+    }
+    let since = WaitForRunConfig::since_time(since.as_deref(), Local::now())
+        .map_err(|err| EngineError::ConfigError(err.to_string()))?;
+    let since_text = since.format("%Y-%m-%d %H:%M:%S").to_string();
+    log_step(
+        sender,
+        &step.id,
+        &tf(
+            "engine.log.wait_run_start",
+            &[("scenario", &scenario), ("since", &since_text)],
+        ),
+    );
+    let poll = Duration::from_secs(config.poll_sec.max(1));
+    let deadline = config
+        .max_wait_sec
+        .map(|secs| Instant::now() + Duration::from_secs(secs));
+    loop {
+        let name = scenario.clone();
+        let found = tokio::task::spawn_blocking(move || last_successful_run(&name))
+            .await
+            .ok()
+            .flatten()
+            .filter(|(finished, _)| *finished > since);
+        if let Some((finished, run_id)) = found {
+            let finished_text = finished.format("%Y-%m-%d %H:%M:%S").to_string();
+            {
+                let mut guard = ctx.write().await;
+                guard.set_step_result(&step.id, "finished_at", finished.to_rfc3339());
+                if let Some(run_id) = &run_id {
+                    guard.set_step_result(&step.id, "run_id", run_id);
+                }
+            }
+            log_step(
+                sender,
+                &step.id,
+                &tf(
+                    "engine.log.wait_run_done",
+                    &[("scenario", &scenario), ("finished", &finished_text)],
+                ),
+            );
+            return Ok(());
+        }
+        let wait = match deadline {
+            Some(deadline) => {
+                let left = deadline.saturating_duration_since(Instant::now());
+                if left.is_zero() {
+                    log_step_at(
+                        sender,
+                        &step.id,
+                        StepLogLevel::Warn,
+                        &tf(
+                            "engine.log.wait_run_timeout",
+                            &[("scenario", &scenario), ("since", &since_text)],
+                        ),
+                    );
+                    return Err(EngineError::Timeout);
+                }
+                left.min(poll)
+            }
+            None => poll,
+        };
+        log_step_at(
+            sender,
+            &step.id,
+            StepLogLevel::Debug,
+            &tf(
+                "engine.log.wait_run_pending",
+                &[("scenario", &scenario), ("secs", &wait.as_secs().max(1))],
+            ),
+        );
+        tokio::select! {
+            _ = cancel.cancelled() => return Err(EngineError::Cancelled),
+            _ = tokio::time::sleep(wait) => {}
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use super::super::context::SharedExecutionContext;
+use super::super::error::EngineError;
+use super::super::events::EngineEvent;
+use super::utils::log_step;
+use crate::i18n::{t, tf};
+use crate::scenario::{Step, StepTimeWindow, TimeWindowPolicy};
+use chrono::{Duration, Local, NaiveDateTime, NaiveTime};
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+
+/// 현재 시각이 시간 창의 어디에 있는지 나타낸다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WindowState {
+    /// 지금 시작할 수 있다.
+    Open,
+    /// 지정 시각에 창이 열린다.
+    OpensAt(NaiveDateTime),
+    /// 이번 실행의 창이 이미 닫혔다.
+    Closed,
+}
+
+/// Step 시간 창을 확인하고, 정책에 따라 창이 열릴 때까지 대기하거나 실패한다.
+///
+/// 창은 실행을 시작한 시각 이후에 처음 닫히는 날의 창으로 정하므로, 저녁에 시작한 실행의
+/// `01:00~05:30` 창은 다음 날 새벽 창이 되고 자정을 넘는 `22:00~06:00` 창은 다음 날 06:00에 닫힌다.
+/// `not_before`만 있으면 실행을 시작한 뒤 처음 오는 그 시각에 열린다.
+///
+/// # 매개변수
+/// - `step`: 대상 Step.
+/// - `window`: Step 시간 창 설정.
+/// - `ctx`: 실행 시작 시각을 읽을 실행 컨텍스트.
+/// - `sender`: 대기 상태와 로그를 전달할 이벤트 채널.
+/// - `cancel`: 대기 중 취소를 감지할 토큰.
+pub(super) async fn wait_for_time_window(
+    step: &Step,
+    window: &StepTimeWindow,
+    ctx: &SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+    cancel: &CancellationToken,
+) -> Result<(), EngineError> {
+    let (not_before, not_after) = window
+        .parse()
+        .map_err(|err| EngineError::ConfigError(err.to_string()))?;
+    let run_started_at = ctx.read().await.run_started_at().naive_local();
+    let now = Local::now().naive_local();
+    match window_state(now, run_started_at, not_before, not_after) {
+        WindowState::Open => Ok(()),
+        WindowState::Closed => Err(EngineError::OutsideTimeWindow(tf(
+            "engine.error.window_closed",
+            &[("time", &window.not_after.as_deref().unwrap_or_default())],
+        ))),
+        WindowState::OpensAt(opens_at) => {
+            let until = if opens_at.date() == now.date() {
+                opens_at.format("%H:%M:%S").to_string()
+            } else {
+                opens_at.format("%Y-%m-%d %H:%M:%S").to_string()
+            };
+            if window.policy == TimeWindowPolicy::Fail {
+                return Err(EngineError::OutsideTimeWindow(tf(
+                    "engine.error.window_not_open",
+                    &[("until", &until)],
+                )));
+            }
+            let _ = sender.send(EngineEvent::StepWaiting {
+                step_id: step.id.clone(),
+                until: until.clone(),
+            });
+            log_step(
+                sender,
+                &step.id,
+                &tf("engine.log.window_wait", &[("until", &until)]),
+            );
+            let wait = (opens_at - now).to_std().unwrap_or_default();
+            tokio::select! {
+                _ = cancel.cancelled() => return Err(EngineError::Cancelled),
+                _ = tokio::time::sleep(wait) => {}
+            }
+            log_step(sender, &step.id, t("engine.log.window_open"));
+            let _ = sender.send(EngineEvent::StepStarted {
+                step_id: step.id.clone(),
+            });
+            Ok(())
+        }
+    }
+}
+
+/// 현재 시각과 시간 창 경계로 창 상태를 계산한다.
+///
+/// `not_before`가 `not_after`보다 늦거나 같으면 자정을 넘는 창으로 본다. 어느 날의 창인지는
+/// `run_started_at` 이후에 처음 닫히는 창으로 정하며, `not_after`가 없으면 `run_started_at` 이후에 처음
+/// 오는 `not_before`에 열린다.
+fn window_state(
+    now: NaiveDateTime,
+    run_started_at: NaiveDateTime,
+    not_before: Option<NaiveTime>,
+    not_after: Option<NaiveTime>,
+) -> WindowState {
+    let day = run_started_at.date();
+    let (opens_at, closes_at) = match (not_before, not_after) {
+        (None, None) => return WindowState::Open,
+        (Some(start), None) => {
+            let opens_at = day.and_time(start);
+            if opens_at < run_started_at {
+                (Some(opens_at + Duration::days(1)), None)
+            } else {
+                (Some(opens_at), None)
+            }
+        }
+        (start, Some(end)) => {
+            // 전날 창부터 차례로 보며 실행 시작 뒤에 닫히는 첫 창을 고른다.
+            let first_closing = (-1..=1)
+                .filter_map(|offset| day.checked_add_signed(Duration::days(offset)))
+                .map(|day| {
+                    let closes_at = match start {
+                        Some(start) if start >= end => day.and_time(end) + Duration::days(1),
+                        _ => day.and_time(end),
+                    };
+                    (start.map(|start| day.and_time(start)), closes_at)
+                })
+                .find(|(_, closes_at)| *closes_at > run_started_at);
+            match first_closing {
+                Some((opens_at, closes_at)) => (opens_at, Some(closes_at)),
+                None => return WindowState::Closed,
+            }
+        }
+    };
+    if let Some(opens_at) = opens_at
+        && now < opens_at
+    {
+        WindowState::OpensAt(opens_at)
+    } else if closes_at.is_some_and(|closes_at| now >= closes_at) {
+        WindowState::Closed
+    } else {
+        WindowState::Open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32, hour: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, day)
+            .and_then(|date| date.and_hms_opt(hour, min, 0))
+            .expect("유효한 시각")
+    }
+
+    fn time(hour: u32, min: u32) -> Option<NaiveTime> {
+        NaiveTime::from_hms_opt(hour, min, 0)
+    }
+
+    #[test]
+    fn not_before_only_rolls_to_next_day_after_evening_start() {
+        let started = at(1, 22, 0);
+        assert_eq!(
+            window_state(started, started, time(1, 0), None),
+            WindowState::OpensAt(at(2, 1, 0))
+        );
+        assert_eq!(
+            window_state(at(2, 1, 30), started, time(1, 0), None),
+            WindowState::Open
+        );
+    }
+
+    #[test]
+    fn not_before_only_opens_same_day_when_still_ahead() {
+        let started = at(1, 0, 30);
+        assert_eq!(
+            window_state(started, started, time(1, 0), None),
+            WindowState::OpensAt(at(1, 1, 0))
+        );
+    }
+
+    #[test]
+    fn paired_window_rolls_to_next_night() {
+        let started = at(1, 22, 0);
+        assert_eq!(
+            window_state(started, started, time(1, 0), time(5, 30)),
+            WindowState::OpensAt(at(2, 1, 0))
+        );
+        assert_eq!(
+            window_state(at(2, 6, 0), started, time(1, 0), time(5, 30)),
+            WindowState::Closed
+        );
+    }
+}
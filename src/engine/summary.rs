@@ -0,0 +1,75 @@
+use super::context::SharedExecutionContext;
+use super::events::EngineEvent;
+use super::resources::EngineHandles;
+use crate::i18n::tf;
+use crate::scenario::SummaryQuery;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// 결과 요약 조회 하나에 허용하는 최대 실행 시간이다.
+const SUMMARY_QUERY_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// 결과 요약 조회 하나의 실행 결과이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryResult {
+    /// 요약 항목 이름.
+    pub label: String,
+    /// 조회한 값. 결과 행이 없거나 값이 NULL이면 `None`이다.
+    pub value: Option<String>,
+    /// 조회에 실패하면 그 오류 메시지.
+    pub error: Option<String>,
+}
+
+/// 결과 요약 조회를 순서대로 실행하고 [`EngineEvent::RunSummary`]로 알린다.
+///
+/// 조회 하나가 실패하거나 제한 시간을 넘겨도 나머지는 계속 실행하며, 실패는 그 항목의 오류로만
+/// 남긴다. 조회가 없으면 이벤트를 보내지 않는다.
+pub(super) async fn run_summary_queries(
+    queries: &[SummaryQuery],
+    handles: Arc<EngineHandles>,
+    ctx: SharedExecutionContext,
+    sender: &UnboundedSender<EngineEvent>,
+) {
+    if queries.is_empty() {
+        return;
+    }
+    let mut results = Vec::with_capacity(queries.len());
+    for query in queries {
+        let outcome =
+            match tokio::time::timeout(SUMMARY_QUERY_TIMEOUT, run_query(query, &handles, &ctx))
+                .await
+            {
+                Ok(outcome) => outcome,
+                Err(_) => Err(anyhow::anyhow!(tf(
+                    "engine.error.query_timeout",
+                    &[("secs", &SUMMARY_QUERY_TIMEOUT.as_secs())]
+                ))),
+            };
+        let (value, error) = match outcome {
+            Ok(value) => (value, None),
+            Err(err) => (None, Some(format!("{err:#}"))),
+        };
+        results.push(SummaryResult {
+            label: query.label.clone(),
+            value,
+            error,
+        });
+    }
+    let _ = sender.send(EngineEvent::RunSummary { results });
+}
+
+/// 조회 SQL을 치환해 대상 DB에서 실행한다.
+async fn run_query(
+    query: &SummaryQuery,
+    handles: &EngineHandles,
+    ctx: &SharedExecutionContext,
+) -> anyhow::Result<Option<String>> {
+    let sql = ctx
+        .read()
+        .await
+        .expand_required(&query.sql, "summary.sql")?;
+    let executor = handles.get_db_executor(query.target_db.as_deref().unwrap_or("default"))?;
+    executor.query_scalar(&sql).await
+}
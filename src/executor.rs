@@ -1,16 +1,229 @@
+use crate::i18n::{t, tf};
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 
 mod oracle_db_executor;
 mod real_db_executor;
 pub use oracle_db_executor::{OracleDbExecutor, new_oracle_db_executor};
 pub use real_db_executor::{RealDbExecutor, new_real_db_executor};
 
+/// SQL 실행 시 DB 쪽에 적용할 안전장치이다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SqlGuard {
+    /// DB 세션에 적용할 문장 제한 시간이다.
+    pub statement_timeout: Option<Duration>,
+    /// 변경을 허용할 최대 행 수이다. 초과하면 롤백 후 오류를 반환한다.
+    pub max_affected_rows: Option<u64>,
+}
+
+/// 미리보기 조회로 가져온 결과 표이다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryRows {
+    /// 열 이름 목록.
+    pub columns: Vec<String>,
+    /// 행 목록. 값이 NULL이면 `None`이다.
+    pub rows: Vec<Vec<Option<String>>>,
+    /// 요청한 행 수보다 결과가 더 있어 잘렸는지 여부.
+    pub truncated: bool,
+}
+
+/// 연결 풀의 세션 사용 현황이다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolStats {
+    /// 풀이 열 수 있는 최대 세션 수.
+    pub max_size: usize,
+    /// Step이 쓰고 있는 세션 수.
+    pub in_use: usize,
+    /// 열려 있지만 쉬고 있는 세션 수.
+    pub idle: usize,
+    /// 세션을 얻으려고 기다리는 요청 수.
+    pub waiting: usize,
+    /// 실행기를 만든 뒤 세션을 얻은 횟수. 실행기는 실행 사이에 재사용되므로 여러 실행의 누계이다.
+    #[serde(default)]
+    pub checkouts: u64,
+    /// 실행기를 만든 뒤 세션을 얻는 데 걸린 시간의 합.
+    #[serde(default)]
+    pub checkout_time: Duration,
+    /// 이번 실행에서 세션을 얻는 데 걸린 평균 시간. 실행기는 채우지 않고 [`PoolStats::since`]로
+    /// 계산한다. 이번 실행에서 아직 얻은 적이 없으면 `None`이다.
+    pub avg_checkout: Option<Duration>,
+}
+
+impl PoolStats {
+    /// 실행을 시작할 때 읽은 `baseline` 이후의 세션 획득만으로 평균 획득 시간을 채운 현황을 반환한다.
+    pub fn since(mut self, baseline: &PoolStats) -> Self {
+        let count = self.checkouts.saturating_sub(baseline.checkouts);
+        let total = self.checkout_time.saturating_sub(baseline.checkout_time);
+        self.avg_checkout = (count > 0).then(|| total / u32::try_from(count).unwrap_or(u32::MAX));
+        self
+    }
+}
+
+/// 스키마 브라우저가 테이블·컬럼 목록으로 가져오는 최대 행 수이다.
+pub const SCHEMA_MAX_ROWS: usize = 2000;
+
+/// 스키마 브라우저에 표시할 테이블 컬럼 정보이다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnInfo {
+    /// 컬럼 이름.
+    pub name: String,
+    /// DB가 알려 준 자료형 이름.
+    pub data_type: String,
+    /// NULL을 허용하는지 여부.
+    pub nullable: bool,
+}
+
 /// DbExecutor는 SQL 실행을 위한 추상 계층을 정의한다.
 #[async_trait]
 pub trait DbExecutor: Send + Sync {
     /// SQL 문장을 실행한다.
     async fn execute_sql(&self, sql: &str) -> anyhow::Result<()>;
+
+    /// 안전장치를 적용해 SQL 문장을 실행하고, 알 수 있으면 변경 행 수를 반환한다.
+    ///
+    /// 기본 구현은 안전장치를 지원하지 않는 실행기를 위해 `guard`를 무시하고
+    /// [`DbExecutor::execute_sql`]을 호출한다.
+    async fn execute_sql_guarded(&self, sql: &str, guard: SqlGuard) -> anyhow::Result<Option<u64>> {
+        let _ = guard;
+        self.execute_sql(sql).await.map(|()| None)
+    }
+
+    /// 조회 SQL을 실행해 첫 행 첫 열 값을 문자열로 반환한다. 결과 행이 없으면 `None`이다.
+    ///
+    /// 기본 구현은 조회 결과를 받을 수 없는 실행기를 위해 오류를 반환한다.
+    async fn query_scalar(&self, sql: &str) -> anyhow::Result<Option<String>> {
+        let _ = sql;
+        anyhow::bail!(t("executor.error.no_query"))
+    }
+
+    /// 조회 SQL을 읽기 전용 트랜잭션에서 실행해 최대 `max_rows`행을 반환한다.
+    ///
+    /// 구현은 `sql`을 인라인 뷰로 감싸 행 수를 제한하고 변경 내용을 남기지 않아야 한다.
+    /// 기본 구현은 조회 결과를 받을 수 없는 실행기를 위해 오류를 반환한다.
+    async fn query_rows(&self, sql: &str, max_rows: usize) -> anyhow::Result<QueryRows> {
+        let _ = (sql, max_rows);
+        anyhow::bail!(t("executor.error.no_query"))
+    }
+
+    /// 단일 SQL 문장의 실행 계획을 사람이 읽을 수 있는 텍스트로 반환한다.
+    ///
+    /// 느린 SQL Step의 튜닝 근거로 남기는 데 쓰며, 구현은 변경 내용을 남기지 않아야 한다.
+    /// `analyze_timeout`이 있으면 조회를 실제로 실행해 통계까지 받을 수 있으며, 그 시간 안에 끝나지
+    /// 않으면 중단해야 한다. `None`이면 문장을 실행하지 않고 예상 계획만 받는다.
+    /// 기본 구현은 실행 계획을 지원하지 않는 실행기를 위해 오류를 반환한다.
+    async fn explain_plan(
+        &self,
+        sql: &str,
+        analyze_timeout: Option<Duration>,
+    ) -> anyhow::Result<String> {
+        let _ = (sql, analyze_timeout);
+        anyhow::bail!(t("executor.error.no_plan"))
+    }
+
+    /// 접속 사용자가 볼 수 있는 테이블·뷰 이름을 이름순으로 최대 [`SCHEMA_MAX_ROWS`]개 반환한다.
+    ///
+    /// 기본 구현은 메타데이터 조회를 지원하지 않는 실행기를 위해 오류를 반환한다.
+    async fn list_tables(&self) -> anyhow::Result<Vec<String>> {
+        anyhow::bail!(t("executor.error.no_schema"))
+    }
+
+    /// [`DbExecutor::list_tables`]가 돌려준 이름의 테이블 컬럼을 정의 순서로 반환한다.
+    ///
+    /// 기본 구현은 메타데이터 조회를 지원하지 않는 실행기를 위해 오류를 반환한다.
+    async fn list_columns(&self, table: &str) -> anyhow::Result<Vec<ColumnInfo>> {
+        let _ = table;
+        anyhow::bail!(t("executor.error.no_schema"))
+    }
+
+    /// 연결 풀을 쓰는 실행기면 지금의 세션 사용 현황을 반환한다.
+    ///
+    /// 기본 구현은 연결 풀이 없는 실행기를 위해 `None`을 반환한다.
+    fn pool_stats(&self) -> Option<PoolStats> {
+        None
+    }
+}
+
+/// SQL에 문자열·인용 식별자·주석 밖의 `;`가 있는지, 즉 여러 문장인지 확인한다.
+///
+/// 작은따옴표·큰따옴표, PostgreSQL 달러 인용(`$tag$ ... $tag$`), `--` 줄 주석과 `/* */` 블록 주석 안의
+/// `;`는 문장 구분자로 보지 않는다. 끝의 `;`를 떼고 호출해야 한다.
+pub fn has_statement_separator(sql: &str) -> bool {
+    let bytes = sql.as_bytes();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let rest = &bytes[idx..];
+        idx += match rest[0] {
+            b';' => return true,
+            quote @ (b'\'' | b'"') => skip_past(&rest[1..], &[quote]) + 1,
+            b'-' if rest.starts_with(b"--") => skip_past(&rest[2..], b"\n") + 2,
+            b'/' if rest.starts_with(b"/*") => skip_past(&rest[2..], b"*/") + 2,
+            b'$' => match dollar_quote_tag(rest) {
+                Some(tag) => skip_past(&rest[tag.len()..], tag) + tag.len(),
+                None => 1,
+            },
+            _ => 1,
+        };
+    }
+    false
+}
+
+/// `text`에서 `end`가 처음 나온 곳의 바로 뒤까지의 길이를 반환한다. 없으면 `text` 전체 길이이다.
+///
+/// 바이트 단위로 비교하므로 한글 같은 여러 바이트 문자가 섞여 있어도 문자 경계를 따지지 않는다.
+fn skip_past(text: &[u8], end: &[u8]) -> usize {
+    text.windows(end.len())
+        .position(|window| window == end)
+        .map_or(text.len(), |pos| pos + end.len())
+}
+
+/// `$`로 시작하는 텍스트가 달러 인용 시작(`$$`, `$tag$`)이면 그 태그를 반환한다.
+///
+/// `$1` 같은 위치 매개변수나 Oracle의 `V$SESSION` 같은 이름은 태그로 보지 않는다.
+fn dollar_quote_tag(text: &[u8]) -> Option<&[u8]> {
+    let end = text[1..].iter().position(|&byte| byte == b'$')? + 2;
+    let name = &text[1..end - 1];
+    let valid = name
+        .iter()
+        .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_')
+        && !name.first().is_some_and(u8::is_ascii_digit);
+    valid.then_some(&text[..end])
+}
+
+/// 메타데이터 조회 SQL에 넣을 문자열 리터럴을 만든다.
+fn sql_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// 조회 결과 행을 `(이름, 자료형, NULL 허용 표시)` 세 열의 컬럼 정보로 바꾼다.
+///
+/// NULL 허용 표시는 `YES`/`Y`이면 허용으로 본다.
+fn column_infos(rows: QueryRows) -> Vec<ColumnInfo> {
+    rows.rows
+        .into_iter()
+        .filter_map(|row| {
+            let mut cells = row.into_iter().map(Option::unwrap_or_default);
+            let name = cells.next().filter(|name| !name.is_empty())?;
+            let data_type = cells.next().unwrap_or_default();
+            let nullable = cells.next().is_some_and(|flag| {
+                flag.eq_ignore_ascii_case("yes") || flag.eq_ignore_ascii_case("y")
+            });
+            Some(ColumnInfo {
+                name,
+                data_type,
+                nullable,
+            })
+        })
+        .collect()
+}
+
+/// 조회 결과 행의 첫 열을 이름 목록으로 바꾼다.
+fn first_column(rows: QueryRows) -> Vec<String> {
+    rows.rows
+        .into_iter()
+        .filter_map(|row| row.into_iter().next().flatten())
+        .collect()
 }
 
 /// DummyExecutor는 실제 DB 연결 없이 로그만 출력하는 기본 구현이다.
@@ -21,9 +234,38 @@ pub struct DummyExecutor;
 impl DbExecutor for DummyExecutor {
     /// Dummy 구현으로 SQL을 stdout으로 출력한다.
     async fn execute_sql(&self, sql: &str) -> anyhow::Result<()> {
-        println!("[DummyExecutor] SQL 실행: {sql}");
+        println!("{}", tf("executor.dummy.execute", &[("sql", &sql)]));
         Ok(())
     }
+
+    /// Dummy 구현으로 SQL을 stdout으로 출력하고 결과 없음을 반환한다.
+    async fn query_scalar(&self, sql: &str) -> anyhow::Result<Option<String>> {
+        println!("{}", tf("executor.dummy.query", &[("sql", &sql)]));
+        Ok(None)
+    }
+
+    /// Dummy 구현으로 SQL을 stdout으로 출력하고 빈 결과 표를 반환한다.
+    async fn query_rows(&self, sql: &str, max_rows: usize) -> anyhow::Result<QueryRows> {
+        println!(
+            "{}",
+            tf(
+                "executor.dummy.preview",
+                &[("max_rows", &max_rows), ("sql", &sql)],
+            )
+        );
+        Ok(QueryRows::default())
+    }
+
+    /// Dummy 구현으로 빈 테이블 목록을 반환한다.
+    async fn list_tables(&self) -> anyhow::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// Dummy 구현으로 빈 컬럼 목록을 반환한다.
+    async fn list_columns(&self, table: &str) -> anyhow::Result<Vec<ColumnInfo>> {
+        println!("{}", tf("executor.dummy.columns", &[("table", &table)]));
+        Ok(Vec::new())
+    }
 }
 
 /// DbExecutor를 공유하기 위한 Arc 타입 별칭이다.
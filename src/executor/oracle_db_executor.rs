@@ -1,7 +1,12 @@
-use super::{DbExecutor, SharedExecutor};
+use super::{
+    ColumnInfo, DbExecutor, QueryRows, SCHEMA_MAX_ROWS, SharedExecutor, SqlGuard, column_infos,
+    first_column, sql_literal,
+};
+use crate::i18n::{t, tf};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
@@ -37,43 +42,241 @@ impl OracleDbExecutor {
             password: password.into(),
         }
     }
-}
 
-#[async_trait]
-impl DbExecutor for OracleDbExecutor {
-    /// sqlplus 프로세스를 실행해 Oracle DB에 임의의 SQL을 전달한다.
+    /// sqlplus 프로세스를 실행해 스크립트를 stdin으로 전달하고 종료를 기다린다.
     ///
     /// # 매개변수
-    /// - `sql`: 실행할 SQL 문자열.
+    /// - `script`: sqlplus에 전달할 전체 스크립트.
+    /// - `timeout`: 지정되면 이 시간을 넘긴 sqlplus 프로세스를 종료한다.
     ///
     /// # 반환값
-    /// sqlplus 종료 코드에 따라 성공 또는 오류를 반환한다.
-    async fn execute_sql(&self, sql: &str) -> Result<()> {
+    /// 성공하면 sqlplus 표준 출력을, 실패하면 종료 코드와 출력을 담은 오류를 반환한다.
+    async fn run_script(&self, script: &str, timeout: Option<Duration>) -> Result<String> {
         let mut command = Command::new("sqlplus");
         command.arg("-S");
         command.arg(format!("{}/{}@{}", self.user, self.password, self.dsn));
         command.stdin(std::process::Stdio::piped());
         command.stdout(std::process::Stdio::piped());
         command.stderr(std::process::Stdio::piped());
+        command.kill_on_drop(true);
 
-        let mut child = command.spawn().context("sqlplus 실행 실패")?;
+        let mut child = command.spawn().context(t("executor.oracle.spawn"))?;
 
         if let Some(mut stdin) = child.stdin.take() {
-            let script = format!("SET HEADING OFF\nSET FEEDBACK OFF\n{sql}\n/\nEXIT\n");
             stdin
                 .write_all(script.as_bytes())
                 .await
-                .context("sqlplus stdin 전송 실패")?;
+                .context(t("executor.oracle.stdin"))?;
         }
 
-        let status = child.wait().await.context("sqlplus 종료 대기 실패")?;
+        let output = match timeout {
+            Some(limit) => tokio::time::timeout(limit, child.wait_with_output())
+                .await
+                .with_context(|| tf("executor.oracle.timeout", &[("secs", &limit.as_secs())]))?,
+            None => child.wait_with_output().await,
+        }
+        .context(t("executor.oracle.wait"))?;
 
-        if status.success() {
-            Ok(())
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
         } else {
-            Err(anyhow::anyhow!(format!("sqlplus 종료 코드: {status}")))
+            let mut detail = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if !stderr.trim().is_empty() {
+                detail.push('\n');
+                detail.push_str(stderr.trim());
+            }
+            Err(anyhow::anyhow!(tf(
+                "executor.oracle.exit",
+                &[("status", &output.status), ("detail", &detail)],
+            )))
+        }
+    }
+}
+
+#[async_trait]
+impl DbExecutor for OracleDbExecutor {
+    /// sqlplus 프로세스를 실행해 Oracle DB에 임의의 SQL을 전달한다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행할 SQL 문자열.
+    ///
+    /// # 반환값
+    /// sqlplus 종료 코드에 따라 성공 또는 오류를 반환한다.
+    async fn execute_sql(&self, sql: &str) -> Result<()> {
+        let script = format!(
+            "WHENEVER SQLERROR EXIT SQL.SQLCODE\nSET HEADING OFF\nSET FEEDBACK OFF\n{sql}\n/\nEXIT\n"
+        );
+        self.run_script(&script, None).await.map(|_| ())
+    }
+
+    /// 안전장치를 적용해 Oracle DB에 SQL을 전달한다.
+    ///
+    /// Oracle은 세션 단위 문장 제한 시간을 제공하지 않으므로 `statement_timeout`을 넘긴
+    /// sqlplus 프로세스를 종료해 세션을 끊는다. `max_affected_rows`가 지정되면 단일 DML을
+    /// PL/SQL 블록으로 감싸 `SQL%ROWCOUNT`를 검사하고, 한도를 넘으면 롤백 후
+    /// `ORA-20001`을 발생시킨다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행할 SQL 문자열.
+    /// - `guard`: 적용할 안전장치.
+    ///
+    /// # 반환값
+    /// sqlplus 종료 코드에 따라 성공 또는 오류를 반환한다. 변경 행 수는 출력으로 받지 않으므로
+    /// 항상 `None`이다.
+    async fn execute_sql_guarded(&self, sql: &str, guard: SqlGuard) -> Result<Option<u64>> {
+        let body = match guard.max_affected_rows {
+            Some(limit) => {
+                let statement = sql.trim().trim_end_matches(['/', ';']).trim_end();
+                // 변경 행 수는 PL/SQL에서 알 수 있으므로 문구 안에서 문자열 연결로 넣는다.
+                let message = tf(
+                    "executor.error.max_affected_rows",
+                    &[("affected", &"' || v_rows || '"), ("limit", &limit)],
+                );
+                format!(
+                    "DECLARE\n  v_rows NUMBER;\nBEGIN\n  {statement};\n  v_rows := SQL%ROWCOUNT;\n  IF v_rows > {limit} THEN\n    ROLLBACK;\n    RAISE_APPLICATION_ERROR(-20001, '{message}');\n  END IF;\n  COMMIT;\nEND;"
+                )
+            }
+            None => sql.to_string(),
+        };
+        let script = format!(
+            "WHENEVER SQLERROR EXIT SQL.SQLCODE ROLLBACK\nSET HEADING OFF\nSET FEEDBACK OFF\n{body}\n/\nEXIT\n"
+        );
+        self.run_script(&script, guard.statement_timeout)
+            .await
+            .map(|_| None)
+    }
+
+    /// 머리글과 페이지 구분 없이 조회를 실행해 출력의 첫 줄을 값으로 반환한다.
+    ///
+    /// sqlplus는 여러 열을 공백으로 구분해 출력하므로 첫 열만 필요하면 한 열만 조회한다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행할 조회 SQL 문자열.
+    ///
+    /// # 반환값
+    /// 출력의 첫 번째 비어 있지 않은 줄. 결과 행이 없으면 `None`이다.
+    async fn query_scalar(&self, sql: &str) -> Result<Option<String>> {
+        let statement = sql.trim().trim_end_matches(['/', ';']).trim_end();
+        let script = format!(
+            "WHENEVER SQLERROR EXIT SQL.SQLCODE\nSET HEADING OFF\nSET FEEDBACK OFF\nSET PAGESIZE 0\nSET LINESIZE 4000\nSET TRIMOUT ON\n{statement}\n/\nEXIT\n"
+        );
+        let stdout = self.run_script(&script, None).await?;
+        Ok(stdout
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string))
+    }
+
+    /// 읽기 전용 트랜잭션에서 `ROWNUM`으로 감싼 조회를 CSV 출력으로 실행하고 롤백한다.
+    ///
+    /// `SET MARKUP CSV`를 쓰므로 sqlplus 12.2 이상이 필요하다. 잘림 여부를 알기 위해 한 행을 더
+    /// 요청하며, 빈 값은 NULL로 본다. 조회가 `--` 주석으로 끝나도 인라인 뷰가 닫히도록 닫는 괄호는
+    /// 다음 줄에 둔다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행할 단일 조회 SQL 문자열.
+    /// - `max_rows`: 반환할 최대 행 수.
+    ///
+    /// # 반환값
+    /// 머리글 줄의 열 이름과 나머지 줄의 행 목록.
+    async fn query_rows(&self, sql: &str, max_rows: usize) -> Result<QueryRows> {
+        let statement = sql.trim().trim_end_matches(['/', ';']).trim_end();
+        let script = format!(
+            "WHENEVER SQLERROR EXIT SQL.SQLCODE ROLLBACK\nSET FEEDBACK OFF\nSET PAGESIZE 50000\nSET MARKUP CSV ON QUOTE ON\nSET TRANSACTION READ ONLY;\nSELECT * FROM ({statement}\n) WHERE ROWNUM <= {}\n/\nROLLBACK;\nEXIT\n",
+            max_rows + 1
+        );
+        let stdout = self.run_script(&script, None).await?;
+        let mut lines = stdout.lines().filter(|line| !line.trim().is_empty());
+        let mut result = QueryRows::default();
+        let Some(header) = lines.next() else {
+            return Ok(result);
+        };
+        result.columns = parse_csv_line(header)
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .collect();
+        for line in lines {
+            if result.rows.len() == max_rows {
+                result.truncated = true;
+                break;
+            }
+            result.rows.push(parse_csv_line(line));
+        }
+        Ok(result)
+    }
+
+    /// `EXPLAIN PLAN`으로 실행 계획을 `PLAN_TABLE`에 넣고 `DBMS_XPLAN.DISPLAY`로 읽은 뒤 롤백한다.
+    ///
+    /// 문장을 실행하지 않으므로 `analyze_timeout`과 관계없이 옵티마이저의 예상 계획이다. 접속 사용자가
+    /// `PLAN_TABLE`을 쓸 수 있어야 한다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행 계획을 받을 단일 SQL 문자열.
+    /// - `_analyze_timeout`: 쓰지 않는다.
+    ///
+    /// # 반환값
+    /// `DBMS_XPLAN.DISPLAY` 출력.
+    async fn explain_plan(&self, sql: &str, _analyze_timeout: Option<Duration>) -> Result<String> {
+        let statement = sql.trim().trim_end_matches(['/', ';']).trim_end();
+        let script = format!(
+            "WHENEVER SQLERROR EXIT SQL.SQLCODE ROLLBACK\nSET HEADING OFF\nSET FEEDBACK OFF\nSET PAGESIZE 0\nSET LINESIZE 4000\nSET TRIMOUT ON\nEXPLAIN PLAN FOR\n{statement}\n/\nSELECT plan_table_output FROM TABLE(DBMS_XPLAN.DISPLAY())\n/\nROLLBACK;\nEXIT\n"
+        );
+        let stdout = self.run_script(&script, None).await?;
+        Ok(stdout.trim_matches('\n').to_string())
+    }
+
+    /// 접속 사용자 스키마의 테이블과 뷰 이름을 반환한다.
+    async fn list_tables(&self) -> Result<Vec<String>> {
+        let rows = self
+            .query_rows(
+                "SELECT table_name FROM user_tables \
+                 UNION SELECT view_name FROM user_views ORDER BY 1",
+                SCHEMA_MAX_ROWS,
+            )
+            .await?;
+        Ok(first_column(rows))
+    }
+
+    /// `user_tab_columns`에서 컬럼 이름, 자료형, NULL 허용 여부를 읽는다.
+    async fn list_columns(&self, table: &str) -> Result<Vec<ColumnInfo>> {
+        let sql = format!(
+            "SELECT column_name, data_type, nullable FROM user_tab_columns \
+             WHERE table_name = {} ORDER BY column_id",
+            sql_literal(table)
+        );
+        Ok(column_infos(self.query_rows(&sql, SCHEMA_MAX_ROWS).await?))
+    }
+}
+
+/// sqlplus CSV 출력 한 줄을 필드 목록으로 나눈다. 따옴표로 감싸지 않은 빈 필드는 NULL(`None`)이다.
+fn parse_csv_line(line: &str) -> Vec<Option<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.is_empty() && !quoted => {
+                in_quotes = true;
+                quoted = true;
+            }
+            ',' if !in_quotes => {
+                fields.push((quoted || !field.is_empty()).then(|| std::mem::take(&mut field)));
+                quoted = false;
+            }
+            _ => field.push(ch),
         }
     }
+    fields.push((quoted || !field.is_empty()).then_some(field));
+    fields
 }
 
 /// OracleDbExecutor를 [`SharedExecutor`] 형태로 생성한다.
@@ -1,9 +1,17 @@
-use super::{DbExecutor, SharedExecutor};
+use super::{
+    ColumnInfo, DbExecutor, PoolStats, QueryRows, SCHEMA_MAX_ROWS, SharedExecutor, SqlGuard,
+    column_infos, first_column, sql_literal,
+};
+use crate::i18n::{t, tf};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use deadpool_postgres::{Config as PoolConfig, ManagerConfig, Pool, RecyclingMethod, Runtime};
+use deadpool_postgres::{
+    Config as PoolConfig, ManagerConfig, Object, Pool, RecyclingMethod, Runtime,
+};
 use std::sync::Arc;
-use tokio_postgres::NoTls;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio_postgres::{NoTls, SimpleQueryMessage};
 
 /// RealDbExecutor는 PostgreSQL 연결 풀을 통해 SQL을 실행하는 실제 구현체이다.
 #[derive(Clone)]
@@ -16,6 +24,10 @@ pub struct RealDbExecutor {
     password: Option<String>,
     /// deadpool 기반 연결 풀이다.
     pool: Pool,
+    /// 풀에서 커넥션을 얻은 횟수이다.
+    checkout_count: Arc<AtomicU64>,
+    /// 풀에서 커넥션을 얻는 데 걸린 시간의 합(마이크로초)이다.
+    checkout_micros: Arc<AtomicU64>,
 }
 
 impl RealDbExecutor {
@@ -44,15 +56,66 @@ impl RealDbExecutor {
 
         let pool = config
             .create_pool(Some(Runtime::Tokio1), NoTls)
-            .context("PostgreSQL 커넥션 풀 생성 실패")?;
+            .context(t("executor.pg.pool"))?;
 
         Ok(Self {
             dsn,
             user,
             password,
             pool,
+            checkout_count: Arc::new(AtomicU64::new(0)),
+            checkout_micros: Arc::new(AtomicU64::new(0)),
         })
     }
+
+    /// 풀에서 커넥션을 얻고, 걸린 시간을 평균 계산용으로 누적한다.
+    async fn checkout(&self) -> Result<Object> {
+        let started = Instant::now();
+        let client = self.pool.get().await.context(t("executor.pg.checkout"))?;
+        let micros = u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX);
+        self.checkout_micros.fetch_add(micros, Ordering::Relaxed);
+        self.checkout_count.fetch_add(1, Ordering::Relaxed);
+        Ok(client)
+    }
+
+    /// 읽기 전용 트랜잭션에서 `EXPLAIN` 문을 실행해 출력 줄을 잇고 롤백한다.
+    ///
+    /// `timeout`이 있으면 `SET LOCAL statement_timeout`으로 그 시간 안에 끝나지 않는 문을 중단한다.
+    async fn explain_read_only(&self, explain: &str, timeout: Option<Duration>) -> Result<String> {
+        let mut client = self.checkout().await?;
+        let transaction = client
+            .build_transaction()
+            .read_only(true)
+            .start()
+            .await
+            .context(t("executor.pg.begin_read_only"))?;
+        if let Some(timeout) = timeout {
+            transaction
+                .batch_execute(&format!(
+                    "SET LOCAL statement_timeout = {}",
+                    timeout.as_millis().max(1)
+                ))
+                .await
+                .context(t("executor.pg.statement_timeout"))?;
+        }
+        let messages = transaction
+            .simple_query(explain)
+            .await
+            .context(t("executor.pg.explain"))?;
+        let plan = messages
+            .iter()
+            .filter_map(|message| match message {
+                SimpleQueryMessage::Row(row) => row.get(0),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        transaction
+            .rollback()
+            .await
+            .context(t("executor.pg.rollback"))?;
+        Ok(plan)
+    }
 }
 
 #[async_trait]
@@ -65,17 +128,225 @@ impl DbExecutor for RealDbExecutor {
     /// # 반환값
     /// 실행 결과에 따라 성공 또는 오류를 반환한다.
     async fn execute_sql(&self, sql: &str) -> Result<()> {
-        let client = self
-            .pool
-            .get()
-            .await
-            .context("PostgreSQL 커넥션 획득 실패")?;
+        let client = self.checkout().await?;
         client
             .batch_execute(sql)
             .await
-            .context("PostgreSQL SQL 실행 실패")?;
+            .context(t("executor.pg.execute"))?;
         Ok(())
     }
+
+    /// 트랜잭션 안에서 `statement_timeout`을 설정한 뒤 SQL을 실행한다.
+    ///
+    /// `max_affected_rows`가 지정되면 단일 문장으로 실행해 변경 행 수를 확인하고,
+    /// 한도를 넘으면 커밋하지 않고 롤백한다. 변경 행 수는 이 경우에만 반환된다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행할 SQL 문자열.
+    /// - `guard`: 적용할 안전장치.
+    ///
+    /// # 반환값
+    /// 실행 및 한도 검사 결과에 따라 변경 행 수 또는 오류를 반환한다.
+    async fn execute_sql_guarded(&self, sql: &str, guard: SqlGuard) -> Result<Option<u64>> {
+        let mut client = self.checkout().await?;
+        let transaction = client.transaction().await.context(t("executor.pg.begin"))?;
+        if let Some(timeout) = guard.statement_timeout {
+            transaction
+                .batch_execute(&format!(
+                    "SET LOCAL statement_timeout = {}",
+                    timeout.as_millis()
+                ))
+                .await
+                .context(t("executor.pg.statement_timeout"))?;
+        }
+        let rows = match guard.max_affected_rows {
+            Some(limit) => {
+                let affected = transaction
+                    .execute(sql.trim().trim_end_matches(';'), &[])
+                    .await
+                    .context(t("executor.pg.execute_single"))?;
+                if affected > limit {
+                    transaction
+                        .rollback()
+                        .await
+                        .context(t("executor.pg.rollback"))?;
+                    anyhow::bail!(tf(
+                        "executor.error.max_affected_rows",
+                        &[("affected", &affected), ("limit", &limit)],
+                    ));
+                }
+                Some(affected)
+            }
+            None => {
+                transaction
+                    .batch_execute(sql)
+                    .await
+                    .context(t("executor.pg.execute"))?;
+                None
+            }
+        };
+        transaction
+            .commit()
+            .await
+            .context(t("executor.pg.commit"))?;
+        Ok(rows)
+    }
+
+    /// 단순 질의 프로토콜로 조회해 첫 행 첫 열 값을 텍스트로 반환한다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행할 조회 SQL 문자열.
+    ///
+    /// # 반환값
+    /// 첫 행 첫 열 값. 결과 행이 없거나 값이 NULL이면 `None`이다.
+    async fn query_scalar(&self, sql: &str) -> Result<Option<String>> {
+        let client = self.checkout().await?;
+        let messages = client
+            .simple_query(sql)
+            .await
+            .context(t("executor.pg.query"))?;
+        let value = messages.iter().find_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some(row.try_get(0).ok().flatten().map(str::to_string)),
+            _ => None,
+        });
+        Ok(value.flatten())
+    }
+
+    /// 읽기 전용 트랜잭션에서 `LIMIT`으로 감싼 조회를 실행하고 롤백한다.
+    ///
+    /// 잘림 여부를 알기 위해 한 행을 더 요청한다. 결과 행이 없으면 열 이름도 비어 있다. 조회 끝의
+    /// `--` 주석이 닫는 괄호를 삼키지 않도록 괄호 앞에서 줄을 바꾼다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행할 단일 조회 SQL 문자열.
+    /// - `max_rows`: 반환할 최대 행 수.
+    ///
+    /// # 반환값
+    /// 열 이름과 텍스트로 변환한 행 목록.
+    async fn query_rows(&self, sql: &str, max_rows: usize) -> Result<QueryRows> {
+        let mut client = self.checkout().await?;
+        let transaction = client
+            .build_transaction()
+            .read_only(true)
+            .start()
+            .await
+            .context(t("executor.pg.begin_read_only"))?;
+        let statement = sql.trim().trim_end_matches(';');
+        let messages = transaction
+            .simple_query(&format!(
+                "SELECT * FROM ({statement}\n) AS preview LIMIT {}",
+                max_rows + 1
+            ))
+            .await
+            .context(t("executor.pg.query"))?;
+        let mut result = QueryRows::default();
+        for message in &messages {
+            let SimpleQueryMessage::Row(row) = message else {
+                continue;
+            };
+            if result.columns.is_empty() {
+                result.columns = row
+                    .columns()
+                    .iter()
+                    .map(|column| column.name().to_string())
+                    .collect();
+            }
+            if result.rows.len() == max_rows {
+                result.truncated = true;
+                break;
+            }
+            result.rows.push(
+                (0..row.len())
+                    .map(|idx| row.get(idx).map(str::to_string))
+                    .collect(),
+            );
+        }
+        transaction
+            .rollback()
+            .await
+            .context(t("executor.pg.rollback"))?;
+        Ok(result)
+    }
+
+    /// 읽기 전용 트랜잭션에서 실행 계획을 받고 롤백한다.
+    ///
+    /// `analyze_timeout`이 있고 `SELECT`·`WITH` 조회이면 `EXPLAIN (ANALYZE, BUFFERS)`로 실제 실행
+    /// 통계까지 받되 그 시간을 `statement_timeout`으로 건다. 시간을 넘기거나 실패하면, 또는 그 밖의
+    /// 문장이면 다시 실행하지 않도록 `EXPLAIN`으로 예상 계획만 받는다.
+    ///
+    /// # 매개변수
+    /// - `sql`: 실행 계획을 받을 단일 SQL 문자열.
+    /// - `analyze_timeout`: 조회를 실제로 실행해 볼 최대 시간. `None`이면 실행하지 않는다.
+    ///
+    /// # 반환값
+    /// `EXPLAIN` 출력 줄을 이은 텍스트.
+    async fn explain_plan(&self, sql: &str, analyze_timeout: Option<Duration>) -> Result<String> {
+        let statement = sql.trim().trim_end_matches(';');
+        let keyword = statement
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        if let Some(timeout) = analyze_timeout
+            && matches!(keyword.as_str(), "SELECT" | "WITH")
+        {
+            let analyzed = self
+                .explain_read_only(
+                    &format!("EXPLAIN (ANALYZE, BUFFERS) {statement}"),
+                    Some(timeout),
+                )
+                .await;
+            match analyzed {
+                Ok(plan) => return Ok(plan),
+                Err(err) => tracing::warn!("EXPLAIN ANALYZE 실패, 예상 계획만 받습니다: {err:#}"),
+            }
+        }
+        self.explain_read_only(&format!("EXPLAIN {statement}"), None)
+            .await
+    }
+
+    /// 시스템 스키마를 뺀 테이블·뷰 이름을 반환한다. `public` 스키마가 아니면 `스키마.테이블`로 적는다.
+    async fn list_tables(&self) -> Result<Vec<String>> {
+        let rows = self
+            .query_rows(
+                "SELECT CASE WHEN table_schema = 'public' THEN table_name \
+                 ELSE table_schema || '.' || table_name END AS name \
+                 FROM information_schema.tables \
+                 WHERE table_schema NOT IN ('pg_catalog', 'information_schema') \
+                 ORDER BY 1",
+                SCHEMA_MAX_ROWS,
+            )
+            .await?;
+        Ok(first_column(rows))
+    }
+
+    /// `information_schema.columns`에서 컬럼 이름, 자료형, NULL 허용 여부를 읽는다.
+    async fn list_columns(&self, table: &str) -> Result<Vec<ColumnInfo>> {
+        let (schema, name) = table.split_once('.').unwrap_or(("public", table));
+        let sql = format!(
+            "SELECT column_name, data_type, is_nullable FROM information_schema.columns \
+             WHERE table_schema = {} AND table_name = {} ORDER BY ordinal_position",
+            sql_literal(schema),
+            sql_literal(name)
+        );
+        Ok(column_infos(self.query_rows(&sql, SCHEMA_MAX_ROWS).await?))
+    }
+
+    /// deadpool 풀 상태와 누적한 커넥션 획득 시간으로 세션 사용 현황을 만든다.
+    fn pool_stats(&self) -> Option<PoolStats> {
+        let status = self.pool.status();
+        let count = self.checkout_count.load(Ordering::Relaxed);
+        let micros = self.checkout_micros.load(Ordering::Relaxed);
+        Some(PoolStats {
+            max_size: status.max_size,
+            in_use: status.size.saturating_sub(status.available),
+            idle: status.available,
+            waiting: status.waiting,
+            checkouts: count,
+            checkout_time: Duration::from_micros(micros),
+            avg_checkout: None,
+        })
+    }
 }
 
 /// RealDbExecutor를 [`SharedExecutor`] 형태로 감싸 애플리케이션에서 쉽게 사용할 수 있게 한다.
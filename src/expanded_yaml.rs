@@ -0,0 +1,86 @@
+use crate::engine::{ExecutionContext, SECRET_MASK, log_dir};
+use crate::i18n::{t, tf};
+use crate::scenario::{Scenario, scenario_to_yaml};
+use anyhow::Context;
+use std::path::PathBuf;
+
+/// 시나리오의 `${...}` 플레이스홀더를 실행에 쓸 파라미터로 치환한 YAML을 만든다.
+///
+/// 엔진이 실행을 시작할 때처럼 시나리오 파라미터를 컨텍스트에 넣고 모든 문자열 값을 치환한 뒤,
+/// 비밀 파라미터와 `secret` Extract 변수 값은 [`SECRET_MASK`]로 가린다. `db`에 직접 적은 비밀번호와 DSN은
+/// 연결 문자열에 비밀번호가 들어 있을 수 있으므로 값 자체를 [`SECRET_MASK`]로 바꾼다.
+/// Step 결과(`${STEP.<id>.<field>}`), DB 연결 정보 변수, 작업 디렉터리, Extract 변수처럼 실행 중에
+/// 정해지는 값은 플레이스홀더 그대로 둔다. 빌더 전용 `metadata` 블록은 뺀다.
+///
+/// # 매개변수
+/// - `scenario`: 컨텍스트 가져오기와 실행 프로필 값을 이미 합친 실행할 시나리오.
+pub fn expand_scenario_yaml(scenario: &Scenario) -> anyhow::Result<String> {
+    let mut ctx = ExecutionContext::new();
+    for (key, value) in &scenario.params {
+        ctx.set_var(key.clone(), value.clone());
+    }
+    for key in scenario.secret_vars() {
+        ctx.mark_secret(key);
+    }
+    let mut masked = scenario.clone();
+    for config in masked.db.values_mut() {
+        for field in [&mut config.password, &mut config.dsn] {
+            if field.is_some() {
+                *field = Some(SECRET_MASK.to_string());
+            }
+        }
+    }
+    let mut value: serde_yaml::Value = serde_yaml::from_str(&scenario_to_yaml(&masked)?)?;
+    if let Some(root) = value.as_mapping_mut() {
+        root.remove("metadata");
+    }
+    expand_strings(&mut value, &ctx);
+    let yaml = serde_yaml::to_string(&value)?;
+    Ok(format!("{}\n{yaml}", t("expanded.header")))
+}
+
+/// YAML 값 안의 모든 문자열을 치환하고 비밀 값을 가린다.
+fn expand_strings(value: &mut serde_yaml::Value, ctx: &ExecutionContext) {
+    match value {
+        serde_yaml::Value::String(text) => *text = ctx.mask(&ctx.expand_lenient(text)),
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                expand_strings(item, ctx);
+            }
+        }
+        serde_yaml::Value::Mapping(fields) => {
+            for (_, item) in fields.iter_mut() {
+                expand_strings(item, ctx);
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => expand_strings(&mut tagged.value, ctx),
+        _ => {}
+    }
+}
+
+/// 치환한 YAML을 실행 로그 디렉터리에 `<시나리오명>-<run_id>.expanded.yaml`로 남긴다.
+///
+/// # 반환값
+/// 기록한 파일 경로.
+pub fn write_expanded_yaml(
+    scenario_name: &str,
+    run_id: &str,
+    yaml: &str,
+) -> anyhow::Result<PathBuf> {
+    let dir = log_dir();
+    let name: String = scenario_name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let path = dir.join(format!("{name}-{run_id}.expanded.yaml"));
+    std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(&path, yaml))
+        .with_context(|| tf("expanded_yaml.error.save", &[("path", &path.display())]))?;
+    Ok(path)
+}
@@ -0,0 +1,191 @@
+use crate::engine::ExecutionContext;
+use crate::i18n::tf;
+use crate::scenario::{Scenario, Step, StepKind, upstream_steps};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// 실행 전에 없거나 읽을 수 없는 것으로 확인된 참조 파일이다.
+#[derive(Debug, Clone)]
+pub struct MissingFile {
+    /// 파일을 참조하는 Step ID.
+    pub step_id: String,
+    /// 파일을 참조하는 필드 이름(`sql_file`, `control_file` 등).
+    pub field: &'static str,
+    /// 시나리오 파라미터로 치환한 경로.
+    pub path: String,
+    /// 파일은 있지만 열 수 없으면 그 오류 메시지이다.
+    pub error: Option<String>,
+}
+
+impl MissingFile {
+    /// Step ID를 뺀 문제 설명을 만든다.
+    pub fn message(&self) -> String {
+        match &self.error {
+            Some(error) => tf(
+                "file_check.unreadable",
+                &[
+                    ("field", &self.field),
+                    ("path", &self.path),
+                    ("error", error),
+                ],
+            ),
+            None => tf(
+                "file_check.missing",
+                &[("field", &self.field), ("path", &self.path)],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for MissingFile {
+    /// `[step_id] 메시지` 형태로 출력한다.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.step_id, self.message())
+    }
+}
+
+/// 시나리오가 참조하는 로컬 파일이 있고 읽을 수 있는지 검사한다.
+///
+/// `sql_file`, sqlldr `control_file`/`data_file`, Extract `file_path`, Verify `file`을 시나리오
+/// 파라미터(없으면 환경 변수)로 치환해 확인한다. 치환되지 않는 변수가 남은 경로(Loop 변수, `RUN_TMPDIR`, 상위 Step이 만드는
+/// 변수 등)와 원격 호스트에서 실행하는 sqlldr Step은 실행 전에 알 수 없으므로 건너뛴다. Extract·Verify
+/// 파일은 상위 Step이 만들 수 있으면(shell Step, 같은 경로를 log/bad/discard로 쓰는 sqlldr Step) 건너뛴다.
+/// Loop 내부와 `on_failure` Step도 함께 검사한다.
+///
+/// # 반환값
+/// 문제가 있는 파일 목록. 비어 있으면 모든 참조 파일을 확인했다.
+pub fn check_scenario_files(scenario: &Scenario) -> Vec<MissingFile> {
+    let mut ctx = ExecutionContext::new();
+    for (key, value) in &scenario.params {
+        ctx.set_var(key.clone(), value.clone());
+    }
+    let mut missing = Vec::new();
+    collect_missing_files(&scenario.steps, &ctx, false, &mut missing);
+    collect_missing_files(scenario.cleanup_steps(), &ctx, false, &mut missing);
+    missing
+}
+
+/// 문제가 있는 파일 목록을 실행 탭 오류로 보여 줄 여러 줄 문자열로 만든다. 목록이 비어 있으면 `None`이다.
+pub fn missing_files_summary(missing: &[MissingFile]) -> Option<String> {
+    if missing.is_empty() {
+        return None;
+    }
+    let mut text = tf("file_check.summary", &[("count", &missing.len())]);
+    for file in missing {
+        text.push('\n');
+        text.push_str(&file.to_string());
+    }
+    Some(text)
+}
+
+/// 같은 레벨의 Step 목록에서 문제가 있는 파일을 모은다.
+///
+/// `outer_producer`는 Loop 바깥의 상위 Step이 Extract 파일을 만들 수 있는지 여부이다.
+fn collect_missing_files(
+    steps: &[Step],
+    ctx: &ExecutionContext,
+    outer_producer: bool,
+    missing: &mut Vec<MissingFile>,
+) {
+    let by_id: HashMap<&str, &Step> = steps.iter().map(|s| (s.id.as_str(), s)).collect();
+    for step in steps {
+        let mut check = |field: &'static str, raw: &Path| {
+            if let Some(problem) = check_file(ctx, &step.id, field, raw) {
+                missing.push(problem);
+            }
+        };
+        match &step.kind {
+            StepKind::SqlFile { path, .. } => check("sql_file", path),
+            StepKind::SqlLoaderPar { config } if config.remote.is_none() => {
+                check("control_file", &config.control_file);
+                if let Some(data) = &config.data_file {
+                    check("data_file", data);
+                }
+            }
+            StepKind::Extract { config } => {
+                let path = PathBuf::from(ctx.expand_lenient(&config.file_path));
+                let produced = outer_producer
+                    || upstream_steps(step, &by_id)
+                        .iter()
+                        .any(|upstream| may_produce(upstream, &path, ctx));
+                if !produced {
+                    check("file_path", &path);
+                }
+            }
+            StepKind::Verify { config } => {
+                let path = PathBuf::from(ctx.expand_lenient(&config.file));
+                let produced = outer_producer
+                    || upstream_steps(step, &by_id)
+                        .iter()
+                        .any(|upstream| may_produce(upstream, &path, ctx));
+                if !produced {
+                    check("file", &path);
+                }
+            }
+            StepKind::Loop { config } => {
+                let producer = outer_producer
+                    || upstream_steps(step, &by_id)
+                        .iter()
+                        .any(|upstream| may_produce_any(upstream));
+                collect_missing_files(&config.steps, ctx, producer, missing);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 경로 하나를 치환해 열어 보고 문제가 있으면 반환한다.
+fn check_file(
+    ctx: &ExecutionContext,
+    step_id: &str,
+    field: &'static str,
+    raw: &Path,
+) -> Option<MissingFile> {
+    let path = ctx.expand_lenient(&raw.to_string_lossy());
+    if path.is_empty() || path.contains("${") {
+        return None;
+    }
+    let error = match std::fs::File::open(&path) {
+        Ok(_) => return None,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => Some(err.to_string()),
+    };
+    Some(MissingFile {
+        step_id: step_id.to_string(),
+        field,
+        path,
+        error,
+    })
+}
+
+/// 상위 Step이 `path` 파일을 만들 수 있는지 확인한다.
+fn may_produce(step: &Step, path: &Path, ctx: &ExecutionContext) -> bool {
+    match &step.kind {
+        StepKind::Shell { .. } => true,
+        StepKind::SqlLoaderPar { config } => {
+            [&config.log_file, &config.bad_file, &config.discard_file]
+                .into_iter()
+                .flatten()
+                .any(|output| Path::new(&ctx.expand_lenient(&output.to_string_lossy())) == path)
+        }
+        StepKind::Loop { config } => config
+            .steps
+            .iter()
+            .any(|inner| may_produce(inner, path, ctx)),
+        _ => false,
+    }
+}
+
+/// 상위 Step이 어떤 파일이든 만들 수 있는지 확인한다. Loop 내부 경로는 반복 변수에 따라 달라지므로
+/// 바깥 상위 Step은 출력 경로를 비교하지 않고 보수적으로 판단한다.
+fn may_produce_any(step: &Step) -> bool {
+    match &step.kind {
+        StepKind::Shell { .. } => true,
+        StepKind::SqlLoaderPar { config } => {
+            config.log_file.is_some() || config.bad_file.is_some() || config.discard_file.is_some()
+        }
+        StepKind::Loop { config } => config.steps.iter().any(may_produce_any),
+        _ => false,
+    }
+}
@@ -0,0 +1,96 @@
+use crate::engine::{
+    ConfirmMode, EngineEvent, EngineHandleCache, EngineRuntimeConfig, load_context_file,
+    run_scenario,
+};
+use crate::executor::{DummyExecutor, SharedExecutor};
+use crate::i18n::t;
+use crate::scenario::load_scenario_from_file;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// 창 없이 시나리오 파일을 한 번 실행하고 모든 Step이 성공했는지 반환한다.
+///
+/// cron이나 작업 스케줄러처럼 사람이 지켜보지 않는 실행을 위한 것이다. Step 로그는 표준 출력으로
+/// 내보내고, 컨펌은 묻지 않고 각 Step의 `default_answer`를 따른다. 참관 실행(`ConfirmMode::Attended`)이면
+/// 응답할 화면이 없으므로 컨펌 Step을 실패로 처리한다. 실행 저널은 남기지 않는다.
+///
+/// # 매개변수
+/// - `runtime_config`: 엔진 런타임 구성.
+/// - `path`: 실행할 시나리오 파일.
+/// - `profile`: 적용할 실행 프로필 이름. 프로필 파라미터는 `context_in`보다 우선하며, 승인을 요구하는
+///   프로필이면 실행하지 않고 오류를 반환한다.
+/// - `params`: 시나리오 `params`를 덮어쓸 `(이름, 값)` 목록. 프로필과 `context_in`보다 우선한다. 덮어쓴 뒤의 값이
+///   파라미터 검증 규칙을 어기면 실행하지 않고 오류를 반환한다. 변수 계약을 어긴 Step이 있어도 같다.
+/// - `context_in`: 이전 실행이 남긴 컨텍스트 파일. 변수를 시나리오 `params`로 넣는다.
+/// - `context_out`: 실행이 끝난 시점의 컨텍스트 변수를 남길 파일.
+/// - `confirm_mode`: 컨펌 Step에 응답하는 방식.
+pub fn run_headless(
+    runtime_config: EngineRuntimeConfig,
+    path: &Path,
+    profile: Option<&str>,
+    params: &[(String, String)],
+    context_in: Option<&Path>,
+    context_out: Option<PathBuf>,
+    confirm_mode: ConfirmMode,
+) -> anyhow::Result<bool> {
+    let mut scenario = load_scenario_from_file(path)?;
+    if let Some(context_in) = context_in {
+        scenario.params.extend(load_context_file(context_in)?);
+    }
+    if let Some(name) = profile {
+        let profile_params = scenario.unattended_profile(name)?.params.clone();
+        scenario.params.extend(profile_params);
+    }
+    for (key, value) in params {
+        scenario.params.insert(key.clone(), value.clone());
+    }
+    scenario.check_param_rules()?;
+    scenario.check_contracts()?;
+    let runtime = runtime_config.build()?;
+    let executor: SharedExecutor = Arc::new(DummyExecutor);
+    runtime.block_on(async move {
+        let step_ids: Vec<String> = scenario.steps.iter().map(|step| step.id.clone()).collect();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let run = tokio::spawn(run_scenario(
+            scenario,
+            executor,
+            EngineHandleCache::default(),
+            tx,
+            CancellationToken::new(),
+            None,
+            confirm_mode,
+            None,
+            None,
+            context_out,
+        ));
+        let mut succeeded: HashSet<String> = HashSet::new();
+        while let Some(event) = rx.recv().await {
+            match event {
+                EngineEvent::StepLog { step_id, line } => println!("[{step_id}] {line}"),
+                EngineEvent::StepFinished {
+                    step_id,
+                    success,
+                    error,
+                    ..
+                } => {
+                    if success {
+                        println!("[{step_id}] {}", t("status.success"));
+                        succeeded.insert(step_id);
+                    } else {
+                        let reason = error.map(|err| err.to_string()).unwrap_or_default();
+                        println!("[{step_id}] {} {reason}", t("status.failed"));
+                    }
+                }
+                EngineEvent::StepBlocked { step_id } => {
+                    println!("[{step_id}] {}", t("cli.headless_blocked"))
+                }
+                _ => {}
+            }
+        }
+        run.await??;
+        Ok(step_ids.iter().all(|id| succeeded.contains(id)))
+    })
+}
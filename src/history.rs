@@ -0,0 +1,402 @@
+use crate::engine::DiagnosticResult;
+use crate::i18n::tf;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use tokio::io::AsyncWriteExt;
+
+/// 실행 이력 파일 경로를 지정하는 환경 변수 이름이다.
+pub const HISTORY_FILE_ENV: &str = "RUST_AIRFLOW_HISTORY_FILE";
+
+/// 환경 변수가 없을 때 사용하는 실행 이력 파일 경로이다.
+pub const DEFAULT_HISTORY_FILE: &str = "run_history.jsonl";
+
+/// 통계에 반영할 Step별 최근 실행 수이다.
+const STATS_WINDOW: usize = 20;
+
+/// 이력이 참조하지 않아도 지우지 않는 최근 SQL 스냅숏의 기간이다. 진행 중인 실행의 스냅숏을 보호한다.
+const SQL_SNAPSHOT_GRACE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Step 한 번의 실행 결과를 기록하는 이력 레코드이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepRunRecord {
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// Step ID.
+    pub step_id: String,
+    /// 성공 여부.
+    pub success: bool,
+    /// 실행 소요 시간(밀리초).
+    pub duration_ms: u64,
+    /// 종료 시각(RFC 3339).
+    pub finished_at: String,
+    /// 같은 실행에서 기록한 레코드를 묶는 ID. 이 값이 생기기 전 레코드에는 없다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+    /// 실패했을 때 실행한 진단 명령 결과.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<DiagnosticResult>,
+    /// `sql_file` Step이 실행한 파일 내용(치환 전)의 SHA-256. 내용은 [`sql_snapshot_dir`]에 남긴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_sha256: Option<String>,
+    /// 이 레코드가 속한 실행 전체의 결과. 취소되지 않고 끝까지 실행되어 실패하거나 건너뛴 Step이
+    /// 없을 때만 `true`이다. 이 값이 생기기 전 레코드에는 없다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_success: Option<bool>,
+}
+
+/// 실행 하나에 붙인 메모와 태그이다. 나중에 왜 그 실행을 했는지 알 수 있도록 남긴다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunNote {
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 메모를 붙인 실행의 ID([`StepRunRecord::run_id`]).
+    pub run_id: String,
+    /// 자유 형식 메모.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+    /// 태그 목록.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl RunNote {
+    /// 메모와 태그가 모두 비었는지 여부를 반환한다.
+    pub fn is_empty(&self) -> bool {
+        self.note.trim().is_empty() && self.tags.is_empty()
+    }
+
+    /// 메모나 태그에 검색어가 들어 있는지 대소문자 구분 없이 확인한다. 검색어 앞의 `#`은 무시한다.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().trim_start_matches('#').to_lowercase();
+        self.note.to_lowercase().contains(&query)
+            || self
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&query))
+    }
+}
+
+/// 쉼표나 공백으로 구분한 태그 입력을 중복 없는 태그 목록으로 바꾼다. 태그 앞의 `#`은 뗀다.
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(|ch: char| ch == ',' || ch.is_whitespace()) {
+        let tag = tag.trim_start_matches('#');
+        if !tag.is_empty() && !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// 과거 실행 이력으로 계산한 Step별 소요 시간 통계이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepDurationStats {
+    /// 집계한 실행 수.
+    pub runs: u32,
+    /// 그중 성공한 실행 수.
+    pub successes: u32,
+    /// 최소 소요 시간.
+    pub min: Duration,
+    /// 평균 소요 시간.
+    pub avg: Duration,
+    /// 최대 소요 시간.
+    pub max: Duration,
+}
+
+impl StepDurationStats {
+    /// 레코드 목록으로 통계를 계산한다. 레코드가 없으면 `None`이다.
+    fn from_records(records: &[&StepRunRecord]) -> Option<Self> {
+        let durations: Vec<u64> = records.iter().map(|record| record.duration_ms).collect();
+        let min = *durations.iter().min()?;
+        let max = *durations.iter().max()?;
+        let avg = durations.iter().sum::<u64>() / durations.len() as u64;
+        Some(Self {
+            runs: records.len() as u32,
+            successes: records.iter().filter(|record| record.success).count() as u32,
+            min: Duration::from_millis(min),
+            avg: Duration::from_millis(avg),
+            max: Duration::from_millis(max),
+        })
+    }
+
+    /// 성공률(0.0~1.0)을 반환한다.
+    pub fn success_rate(&self) -> f32 {
+        if self.runs == 0 {
+            0.0
+        } else {
+            self.successes as f32 / self.runs as f32
+        }
+    }
+
+    /// `최소 1초 · 평균 3초 · 최대 9초 · 성공률 95% (20회)` 형태의 요약 문자열을 만든다.
+    pub fn summary(&self) -> String {
+        tf(
+            "history.stats_summary",
+            &[
+                ("min", &format_duration(self.min)),
+                ("avg", &format_duration(self.avg)),
+                ("max", &format_duration(self.max)),
+                ("rate", &format!("{:.0}", self.success_rate() * 100.0)),
+                ("runs", &self.runs),
+            ],
+        )
+    }
+}
+
+/// 소요 시간을 `1시간 2분`, `3분 4초`, `5초` 형태로 표시한다.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        tf(
+            "history.duration_hours",
+            &[("hours", &(secs / 3600)), ("minutes", &(secs % 3600 / 60))],
+        )
+    } else if secs >= 60 {
+        tf(
+            "history.duration_minutes",
+            &[("minutes", &(secs / 60)), ("seconds", &(secs % 60))],
+        )
+    } else {
+        tf("history.duration_seconds", &[("seconds", &secs)])
+    }
+}
+
+/// 실행 이력 파일 경로를 반환한다.
+pub fn history_path() -> PathBuf {
+    std::env::var(HISTORY_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_HISTORY_FILE))
+}
+
+/// SQL 파일 스냅숏 디렉터리 경로를 반환한다. 실행 이력 파일 옆에 `<이력 파일 이름>.sql_snapshots`로 둔다.
+pub fn sql_snapshot_dir() -> PathBuf {
+    history_path().with_extension("sql_snapshots")
+}
+
+/// SQL 파일 내용의 SHA-256을 소문자 16진수로 반환한다.
+pub fn sql_sha256(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// SQL 파일 내용을 해시 이름(`<sha256>.sql`)의 스냅숏으로 남기고 해시를 반환한다.
+///
+/// 같은 내용의 스냅숏이 이미 있어도 다시 써서 수정 시각을 갱신한다. 이력 정리는 최근에 쓴 스냅숏을
+/// 진행 중인 실행의 것으로 보고 남긴다.
+pub async fn save_sql_snapshot(content: &str) -> anyhow::Result<String> {
+    let hash = sql_sha256(content);
+    let dir = sql_snapshot_dir();
+    let path = dir.join(format!("{hash}.sql"));
+    tokio::fs::create_dir_all(&dir).await?;
+    tokio::fs::write(&path, content).await?;
+    Ok(hash)
+}
+
+/// 해시로 SQL 파일 스냅숏을 읽는다. 이력 정리로 지워졌거나 읽을 수 없으면 `None`이다.
+pub fn load_sql_snapshot(hash: &str) -> Option<String> {
+    std::fs::read_to_string(sql_snapshot_dir().join(format!("{hash}.sql"))).ok()
+}
+
+/// 실행 메모 파일 경로를 반환한다. 실행 이력 파일 옆에 `<이력 파일 이름>.notes.jsonl`로 둔다.
+pub fn run_notes_path() -> PathBuf {
+    history_path().with_extension("notes.jsonl")
+}
+
+/// 실행 메모를 메모 파일 끝에 JSON 한 줄로 추가한다.
+///
+/// 같은 실행의 메모는 나중 줄이 앞의 값을 대신하므로, 메모를 고치거나 지울(빈 메모) 때도 덧붙이기만 한다.
+pub fn save_run_note(note: &RunNote) -> anyhow::Result<()> {
+    use std::io::Write;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(run_notes_path())?;
+    writeln!(file, "{}", serde_json::to_string(note)?)?;
+    Ok(())
+}
+
+/// 실행별 최신 메모를 `(시나리오 이름, 실행 ID)`를 키로 읽는다.
+///
+/// 메모 파일이 없거나 읽을 수 없으면 빈 맵을 반환하고, 깨진 줄과 빈 메모로 지운 실행은 뺀다.
+pub fn load_run_notes() -> HashMap<(String, String), RunNote> {
+    let Ok(content) = std::fs::read_to_string(run_notes_path()) else {
+        return HashMap::new();
+    };
+    let mut notes = HashMap::new();
+    for note in content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RunNote>(line).ok())
+    {
+        notes.insert((note.scenario.clone(), note.run_id.clone()), note);
+    }
+    notes.retain(|_, note| !note.is_empty());
+    notes
+}
+
+/// 실행 이력 파일 끝에 레코드를 JSON 한 줄씩 추가한다.
+///
+/// # 매개변수
+/// - `records`: 추가할 Step 실행 레코드 목록.
+pub async fn append_records(records: &[StepRunRecord]) -> anyhow::Result<()> {
+    if records.is_empty() {
+        return Ok(());
+    }
+    let mut buf = String::new();
+    for record in records {
+        buf.push_str(&serde_json::to_string(record)?);
+        buf.push('\n');
+    }
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_path())
+        .await?;
+    file.write_all(buf.as_bytes()).await?;
+    Ok(())
+}
+
+/// 보존 기간이 지난 실행 이력을 지우고 지운 레코드 수를 반환한다.
+///
+/// 종료 시각을 해석할 수 없는 줄은 그대로 둔다. 지울 레코드가 없으면 파일을 다시 쓰지 않는다.
+///
+/// # 매개변수
+/// - `keep_days`: 종료 시각 기준으로 보존할 일수.
+pub async fn prune_history(keep_days: u32) -> anyhow::Result<usize> {
+    let path = history_path();
+    let content = match tokio::fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err.into()),
+    };
+    let cutoff = chrono::Local::now() - chrono::Duration::days(i64::from(keep_days));
+    let mut removed = 0;
+    let kept: String = content
+        .lines()
+        .filter(|line| {
+            let expired = serde_json::from_str::<StepRunRecord>(line)
+                .ok()
+                .and_then(|record| chrono::DateTime::parse_from_rfc3339(&record.finished_at).ok())
+                .is_some_and(|finished| finished < cutoff);
+            if expired {
+                removed += 1;
+            }
+            !expired
+        })
+        .map(|line| format!("{line}\n"))
+        .collect();
+    if removed > 0 {
+        prune_run_notes(&kept).await?;
+        prune_sql_snapshots(&kept, cutoff.into()).await?;
+        tokio::fs::write(&path, kept).await?;
+    }
+    Ok(removed)
+}
+
+/// 남은 이력에 없는 실행의 메모를 지우고, 실행마다 최신 메모 한 줄만 남겨 메모 파일을 다시 쓴다.
+///
+/// # 매개변수
+/// - `kept`: 정리 후 남길 실행 이력 파일 내용.
+async fn prune_run_notes(kept: &str) -> anyhow::Result<()> {
+    let path = run_notes_path();
+    if !tokio::fs::try_exists(&path).await? {
+        return Ok(());
+    }
+    let runs: HashSet<(String, String)> = kept
+        .lines()
+        .filter_map(|line| serde_json::from_str::<StepRunRecord>(line).ok())
+        .filter_map(|record| Some((record.scenario, record.run_id?)))
+        .collect();
+    let mut notes: Vec<RunNote> = load_run_notes()
+        .into_iter()
+        .filter(|(key, _)| runs.contains(key))
+        .map(|(_, note)| note)
+        .collect();
+    notes.sort_by(|a, b| (&a.scenario, &a.run_id).cmp(&(&b.scenario, &b.run_id)));
+    let mut buf = String::new();
+    for note in &notes {
+        buf.push_str(&serde_json::to_string(note)?);
+        buf.push('\n');
+    }
+    tokio::fs::write(&path, buf).await?;
+    Ok(())
+}
+
+/// 남은 이력에서 참조하지 않는 SQL 파일 스냅숏을 지운다.
+///
+/// 진행 중인 실행(다른 프로세스 포함)은 스냅숏을 먼저 쓰고 이력을 실행이 끝날 때 남기므로, `cutoff`와
+/// [`SQL_SNAPSHOT_GRACE`] 전 중 이른 시각 뒤에 쓴 스냅숏은 참조가 없어도 남긴다.
+///
+/// # 매개변수
+/// - `kept`: 정리 후 남길 실행 이력 파일 내용.
+/// - `cutoff`: 보존 기간의 시작 시각.
+async fn prune_sql_snapshots(kept: &str, cutoff: SystemTime) -> anyhow::Result<()> {
+    let dir = sql_snapshot_dir();
+    if !tokio::fs::try_exists(&dir).await? {
+        return Ok(());
+    }
+    let threshold = cutoff.min(SystemTime::now() - SQL_SNAPSHOT_GRACE);
+    let used: HashSet<String> = kept
+        .lines()
+        .filter_map(|line| serde_json::from_str::<StepRunRecord>(line).ok())
+        .filter_map(|record| record.sql_sha256)
+        .collect();
+    let mut entries = tokio::fs::read_dir(&dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let unused = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|hash| !used.contains(hash));
+        if !unused {
+            continue;
+        }
+        let stale = entry
+            .metadata()
+            .await
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| modified < threshold);
+        if stale {
+            tokio::fs::remove_file(&path).await?;
+        }
+    }
+    Ok(())
+}
+
+/// 실행 이력 파일의 레코드를 기록 순서대로 읽는다.
+///
+/// 이력 파일이 없거나 읽을 수 없으면 빈 목록을 반환하고, 깨진 줄은 건너뛴다.
+pub fn load_records() -> Vec<StepRunRecord> {
+    let Ok(content) = std::fs::read_to_string(history_path()) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// 시나리오의 Step별 최근 실행 통계를 계산한다.
+///
+/// 이력 파일이 없거나 읽을 수 없으면 빈 맵을 반환하고, 깨진 줄은 건너뛴다.
+///
+/// # 매개변수
+/// - `scenario`: 대상 시나리오 이름.
+pub fn load_step_stats(scenario: &str) -> HashMap<String, StepDurationStats> {
+    let records: Vec<StepRunRecord> = load_records()
+        .into_iter()
+        .filter(|record| record.scenario == scenario)
+        .collect();
+    let mut by_step: HashMap<&str, Vec<&StepRunRecord>> = HashMap::new();
+    for record in &records {
+        by_step.entry(&record.step_id).or_default().push(record);
+    }
+    by_step
+        .into_iter()
+        .filter_map(|(step_id, runs)| {
+            let recent = &runs[runs.len().saturating_sub(STATS_WINDOW)..];
+            StepDurationStats::from_records(recent).map(|stats| (step_id.to_string(), stats))
+        })
+        .collect()
+}
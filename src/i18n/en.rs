@@ -0,0 +1,2213 @@
+/// 영어 메시지 카탈로그이다. 없는 키는 한국어 문구로 대체된다.
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    ("status.pending", "Pending"),
+    ("status.waiting", "Waiting for window"),
+    ("status.running", "Running"),
+    ("status.retrying", "Retrying"),
+    ("status.success", "Succeeded"),
+    ("status.failed", "Failed"),
+    ("status.blocked", "Skipped"),
+    ("status.awaiting_approval", "Awaiting approval"),
+    ("status.retry_in", "in {secs}s"),
+    ("status.retry_progress", "Retry {attempt}/{max} · {phase}"),
+    ("toolbar.loaded", "{lock}Loaded · {path}"),
+    (
+        "toolbar.pick_scenario",
+        "Open a scenario file to get started.",
+    ),
+    ("toolbar.daemon_connected", "🔗 Daemon connected · {addr}"),
+    ("toolbar.daemon_waiting", "⛓ Waiting for daemon · {addr}"),
+    ("toolbar.open", "Open"),
+    ("toolbar.run", "Run"),
+    ("toolbar.stop", "Stop"),
+    ("toolbar.new", "New scenario"),
+    ("toolbar.open_ellipsis", "Open..."),
+    ("toolbar.import", "Import..."),
+    (
+        "toolbar.import_hint",
+        "Build a scenario skeleton from an Airflow DAG (.py) or Oozie workflow (.xml).",
+    ),
+    ("toolbar.save", "Save"),
+    ("toolbar.save_as", "Save as"),
+    ("toolbar.check_sql", "Check SQL"),
+    ("toolbar.modified_suffix", " (modified)"),
+    ("toolbar.file", "{lock}File · {path}{dirty}"),
+    ("toolbar.modified_tag", " · modified"),
+    ("toolbar.new_scenario", "New scenario{dirty}"),
+    ("confirm.window", "Confirm step"),
+    ("confirm.title", "Confirmation required"),
+    ("confirm.phase_before", "before run"),
+    ("confirm.phase_after", "after run"),
+    ("confirm.summary", "Summary"),
+    ("common.yes", "Yes"),
+    ("common.no", "No"),
+    ("confirm.accept", "Yes · continue"),
+    ("confirm.reject", "No · stop"),
+    ("passphrase.window", "Enter passphrase"),
+    ("passphrase.title", "🔒 Encrypted scenario"),
+    ("approval.window", "Run approval"),
+    ("approval.title", "🔒 Approval required"),
+    ("approval.scenario", "Scenario"),
+    ("approval.profile", "Profile"),
+    ("approval.requester", "Requested by"),
+    ("approval.requested_at", "Requested at"),
+    ("approval.id", "Approval ID"),
+    (
+        "approval.hint_code",
+        "An approver other than the requester must enter their name and own approval code to start the run.",
+    ),
+    ("approval.approver", "Approver"),
+    ("approval.code", "Approval code"),
+    ("approval.approve", "Approve and run"),
+    ("approval.reject", "Reject"),
+    ("approval.withdraw", "Withdraw request"),
+    ("exit.window", "Confirm exit"),
+    ("exit.title", "⚠ A scenario is still running"),
+    (
+        "exit.message",
+        "Choose what to do with the running scenario before closing the window.",
+    ),
+    ("exit.cancel_wait", "Cancel run and exit"),
+    (
+        "exit.cancel_wait_hint",
+        "Cancels the run, stops child processes, and exits once run history and log files are written.",
+    ),
+    ("exit.detach", "Leave it to the daemon and exit"),
+    (
+        "exit.detach_hint",
+        "The run continues in the daemon process. Reconnect with --attach to follow it.",
+    ),
+    (
+        "exit.detach_unavailable",
+        "Runs can only be handed over when attached to a daemon (--attach).",
+    ),
+    ("exit.force", "Force quit"),
+    (
+        "exit.force_hint",
+        "Exits immediately. Running processes are killed and this run may not be recorded in history.",
+    ),
+    ("exit.keep_running", "Keep running (don't close)"),
+    (
+        "exit.draining",
+        "Cancelling the run and finishing writes… {secs}s",
+    ),
+    ("parse_error.window", "Scenario format error"),
+    ("parse_error.title", "⚠ Could not read the scenario file"),
+    ("parse_error.file", "File"),
+    ("parse_error.location", "Location (line:col)"),
+    ("parse_error.step", "Step"),
+    ("parse_error.hint", "💡 Hint: {hint}"),
+    ("parse_error.reload", "Reload"),
+    ("reload.title", "📝 {file} changed on disk"),
+    (
+        "reload.message",
+        "The scenario file was modified elsewhere (e.g. git pull). Reloading keeps node positions for steps with the same ID.",
+    ),
+    (
+        "reload.dirty_warning",
+        "The builder has unsaved changes. Reloading discards them.",
+    ),
+    ("reload.reload", "Reload"),
+    ("reload.keep", "Keep current"),
+    (
+        "reload.running",
+        "Cannot reload while a run is in progress.",
+    ),
+    ("reload.failed", "Failed to reload scenario: {error}"),
+    ("common.ok", "OK"),
+    ("common.cancel", "Cancel"),
+    ("common.save", "Save"),
+    ("confirm.kind_phase", "Kind: {kind} · Phase: {phase}"),
+    ("confirm.default_answer", "Default answer: {answer}"),
+    ("tab.run", "Run"),
+    ("tab.dashboard", "Dashboard"),
+    ("dashboard.title", "📊 Run history statistics"),
+    ("dashboard.all_scenarios", "All scenarios"),
+    ("dashboard.period", "Last {days} days"),
+    ("dashboard.refresh", "🔄 Refresh"),
+    ("dashboard.copy", "📋 Copy daily table"),
+    (
+        "dashboard.copy_hint",
+        "Copies runs, successes, success rate and average duration per day as a table you can paste into a spreadsheet.",
+    ),
+    ("dashboard.empty", "No runs were recorded in this period."),
+    ("dashboard.total_runs", "Runs"),
+    ("dashboard.success_rate", "Success rate"),
+    ("dashboard.avg_duration", "Average duration"),
+    ("dashboard.runs_per_day", "Runs per day"),
+    ("dashboard.success_trend", "Success rate per day"),
+    ("dashboard.duration_trend", "Average duration per day"),
+    ("dashboard.no_runs", "No runs"),
+    ("dashboard.failing_steps", "❌ Most failing steps"),
+    ("dashboard.no_failures", "No step failed in this period."),
+    ("dashboard.longest_steps", "⏱ Longest steps"),
+    ("dashboard.step", "Scenario / Step"),
+    ("dashboard.failures", "Failed / Runs"),
+    ("dashboard.failure_rate", "Failure rate"),
+    ("dashboard.avg", "Average"),
+    ("dashboard.max", "Max"),
+    ("dashboard.col_date", "Date"),
+    ("dashboard.col_successes", "Successes"),
+    ("dashboard.col_avg_secs", "Avg duration (s)"),
+    ("dashboard.col_total", "Total"),
+    ("dashboard.runs", "🗒 Runs"),
+    ("dashboard.search_hint", "Search notes, tags, scenarios"),
+    ("dashboard.no_matching_runs", "No runs match the search."),
+    ("dashboard.col_finished", "Finished"),
+    ("dashboard.col_scenario", "Scenario"),
+    ("dashboard.col_result", "Result"),
+    ("dashboard.col_duration", "Duration"),
+    ("dashboard.col_note", "Tags · Note"),
+    ("dashboard.run_success", "Success"),
+    ("dashboard.run_failed", "Failed"),
+    ("dashboard.edit_note", "Edit note and tags"),
+    (
+        "dashboard.more_runs",
+        "{count} more runs not shown. Narrow down with a search or period.",
+    ),
+    ("dashboard.note_for", "{scenario} · run {run} note"),
+    ("settings.menu", "⚙ Settings"),
+    ("search.menu", "🔎 Find in all scenarios"),
+    ("search.window", "Find in all scenarios"),
+    ("search.dir", "Search folder: {dir} (change it in Settings)"),
+    ("search.hint", "Table, step name, path or variable name"),
+    ("search.run", "Find"),
+    (
+        "search.summary",
+        "Found {hits} matches in {files} scenarios.",
+    ),
+    ("search.open", "Open"),
+    (
+        "search.skipped",
+        "{count} files or folders could not be read",
+    ),
+    ("expanded.window", "Expanded YAML"),
+    (
+        "expanded.note",
+        "This is the scenario the engine will see when run. Secret values are masked as ****; step results, DB connection info and work directory variables decided during the run are left as placeholders.",
+    ),
+    ("expanded.copy", "📋 Copy"),
+    ("expanded.save", "💾 Save..."),
+    (
+        "expanded.save_failed",
+        "Failed to save expanded YAML: {error}",
+    ),
+    (
+        "expanded.header",
+        "# Scenario with placeholders replaced by run parameters. Secret values are masked as ****.",
+    ),
+    (
+        "search.dir_failed",
+        "Could not read scenario folder {dir}: {error}",
+    ),
+    ("search.field_step", "Step"),
+    ("search.field_sql", "SQL"),
+    ("search.field_script", "Script"),
+    ("search.field_path", "Path"),
+    ("search.field_variable", "Variable"),
+    ("settings.runtime_title", "Engine runtime"),
+    ("settings.workers", "Worker threads"),
+    ("settings.blocking_threads", "Blocking thread cap"),
+    ("settings.auto", "Auto"),
+    (
+        "settings.runtime_current",
+        "Active: {workers} workers, {blocking} blocking",
+    ),
+    ("settings.apply", "Apply"),
+    (
+        "settings.runtime_running",
+        "Cannot change while a run is in progress.",
+    ),
+    (
+        "settings.runtime_daemon",
+        "In daemon mode the daemon process options apply.",
+    ),
+    (
+        "settings.runtime_failed",
+        "Failed to create engine runtime: {error}",
+    ),
+    ("settings.window", "App settings"),
+    (
+        "settings.file_hint",
+        "Saved to {path} · command-line arguments and environment variables take precedence.",
+    ),
+    ("settings.language", "Language"),
+    ("settings.language_default", "Default (environment)"),
+    ("settings.theme", "Theme"),
+    ("settings.theme_light", "Light"),
+    ("settings.theme_dark", "Dark"),
+    ("settings.ui_scale", "UI scale"),
+    ("settings.log_dir", "Log directory"),
+    ("settings.scenario_dir", "Scenario folder"),
+    ("settings.history_keep_days", "Keep run history (days)"),
+    ("settings.history_keep_all", "Keep all"),
+    ("settings.notify", "Run completion notifications"),
+    (
+        "settings.notify_hint",
+        "http:// addresses that receive the run result as a JSON POST.",
+    ),
+    ("settings.notify_add", "➕ Add address"),
+    ("settings.save", "Save"),
+    (
+        "settings.save_failed",
+        "Failed to save app settings: {error}",
+    ),
+    ("progress.title", "📈 Overall progress"),
+    (
+        "progress.with_eta",
+        "Progress: {percent}% · about {eta} left",
+    ),
+    ("progress.percent", "Progress: {percent}%"),
+    ("progress.succeeded", "✅ Succeeded"),
+    ("progress.failed", "❌ Failed"),
+    ("progress.blocked", "⛔ Skipped"),
+    ("progress.remaining", "⏳ Remaining"),
+    ("progress.lanes", "⚙ Running now {count}"),
+    (
+        "progress.lanes_idle",
+        "No step executing — waiting on a time window, approval, or retry delay",
+    ),
+    ("panel.steps", "Steps"),
+    (
+        "panel.keyboard_hint",
+        "⌨ ↑↓ move · Enter details · F5 run · Shift+F5 stop · A approve",
+    ),
+    ("panel.slow_card", "Slow · over {avg} average"),
+    ("panel.overrun_card", "Over time limit · {elapsed} elapsed"),
+    ("panel.iterations", "Iteration {done}/{total}"),
+    ("panel.no_scenario", "Load a scenario first."),
+    ("panel.cleanup_steps", "On failure cleanup"),
+    ("panel.stage_header", "{stage} · {done}/{total}"),
+    ("panel.no_stage", "No stage"),
+    ("panel.gate_waiting", "✋ {step} is awaiting approval"),
+    ("panel.gate_approve", "Approve"),
+    ("panel.step_cancel_fail", "Cancel as failed"),
+    (
+        "panel.step_cancel_fail_hint",
+        "Stop only this step and mark it as failed. Steps that depend on it are skipped.",
+    ),
+    ("panel.step_cancel_skip", "Skip"),
+    (
+        "panel.step_cancel_skip_hint",
+        "Stop only this step and mark it as succeeded so the rest of the flow continues.",
+    ),
+    ("rehearsal.title", "Rehearsal"),
+    (
+        "rehearsal.hint",
+        "These settings are never saved to the scenario file, and rehearsal runs are not recorded in run history.",
+    ),
+    (
+        "rehearsal.simulate_others",
+        "Pretend the remaining steps succeed without running them",
+    ),
+    ("rehearsal.step", "Step"),
+    ("rehearsal.fail", "Fail"),
+    ("rehearsal.delay", "Delay"),
+    ("rehearsal.sec_suffix", " s"),
+    ("detail.title", "Step details"),
+    ("detail.status", "Status · {status}"),
+    ("detail.waiting_until", "Waiting until {until}"),
+    ("detail.slow", "⚠ Taking longer than the {avg} average"),
+    (
+        "detail.overrun",
+        "⏰ Past the time limit, still waiting ({elapsed} elapsed)",
+    ),
+    ("detail.approved", "Approved by {approver} at {at}"),
+    ("detail.parallel", "Parallel"),
+    ("detail.retry", "Retries"),
+    ("detail.retry_value", ": {count}"),
+    ("detail.retry_transient", "{value} · transient errors only"),
+    ("detail.retry_any", "{value} · any error"),
+    ("detail.timeout", "Timeout"),
+    ("detail.timeout_value", ": {secs}s"),
+    (
+        "detail.timeout_warn_continue",
+        "{value} · warn and keep waiting when exceeded",
+    ),
+    (
+        "detail.timeout_fail",
+        "{value} · fail without retry when exceeded",
+    ),
+    ("detail.past_duration", "Past duration"),
+    (
+        "detail.duration_value",
+        ": min {min} · avg {avg} · max {max}",
+    ),
+    ("detail.success_rate", "Success rate"),
+    (
+        "detail.success_rate_value",
+        ": {percent}% ({successes}/{runs} runs)",
+    ),
+    ("detail.time_window", "Time window"),
+    ("detail.skip_if", "Skip if"),
+    ("detail.skip_if_file", "file {path} exists"),
+    ("detail.skip_if_sql", "query returns rows"),
+    ("detail.sqlldr_title", "SQL*Loader row counts"),
+    ("detail.sqlldr_empty", "No row counts in the output yet."),
+    ("detail.sqlldr_read", "Records read"),
+    ("detail.sqlldr_rejected", "Records rejected"),
+    ("detail.sqlldr_discarded", "Records discarded"),
+    ("detail.sqlldr_loaded", "Rows loaded into {table}"),
+    ("detail.window_wait", "wait"),
+    ("detail.window_fail", "fail"),
+    ("detail.depends_on", "Depends on"),
+    ("detail.none_selected", "No step selected."),
+    ("detail.db_error", "DB error · {code}"),
+    ("detail.knowledge_base", "Knowledge base"),
+    ("detail.context", "Context at failure · {count}"),
+    ("detail.context_empty", "No variables captured."),
+    ("detail.set_vars", "Variables set by this step · {count}"),
+    ("detail.outputs", "Expected outputs · {count}"),
+    ("detail.output_missing", "✗ {path} (missing)"),
+    ("detail.output_empty", "✗ {path} (empty)"),
+    ("detail.diagnostics", "Failure diagnostics ({count})"),
+    ("detail.diagnostic_failed", "Diagnostic failed: {error}"),
+    ("detail.diagnostic_empty", "No output"),
+    ("detail.preview", "Resolved configuration"),
+    ("preview.target_db", "Target DB"),
+    ("preview.sql", "SQL"),
+    ("preview.sql_file", "SQL file"),
+    ("preview.remote", "Remote host"),
+    ("preview.control_file", "Control file"),
+    ("preview.data_file", "Data file"),
+    ("preview.log_file", "Log file"),
+    ("preview.bad_file", "Bad file"),
+    ("preview.discard_file", "Discard file"),
+    ("preview.command", "Command line"),
+    ("preview.script", "Script"),
+    ("preview.working_dir", "Working directory"),
+    ("preview.run_as", "Run as"),
+    ("preview.env", "Environment variables"),
+    ("preview.extract_file", "Source file"),
+    ("preview.extract_rule", "Extraction rule"),
+    (
+        "preview.extract_rule_value",
+        "line {line} · /{pattern}/ · group {group} → ${{var}}",
+    ),
+    ("preview.glob", "Glob pattern"),
+    ("preview.glob_matches", "Current matches"),
+    ("preview.loop_body", "Loop variable · child steps"),
+    ("preview.loop_body_value", "${{var}} · {count} child steps"),
+    ("preview.gate_message", "Approval message"),
+    ("preview.wait_seconds", "Wait time"),
+    ("preview.wait_seconds_value", "{secs}s"),
+    ("preview.wait_until", "Wait until"),
+    ("preview.wait_run_scenario", "Scenario to wait for"),
+    ("preview.wait_run_since", "Finished after"),
+    ("preview.verify_file", "File to verify"),
+    ("preview.verify_method", "Method"),
+    ("preview.verify_expected", "Expected value"),
+    ("preview.verify_expected_file", "Expected value file"),
+    ("preview.exists", "File exists."),
+    ("preview.missing", "File not found."),
+    ("panel.logs", "Logs"),
+    ("panel.log_filter_hint", "Filter logs (contains text)"),
+    ("failure.title", "🧯 Failure summary"),
+    ("failure.root_cause", "Root cause · {name} ({id})"),
+    (
+        "failure.blocked_chain",
+        "Skipped because of this failure · {chain}",
+    ),
+    ("failure.recent_logs", "Last {count} log lines"),
+    ("failure.retry", "🔁 Retry from failure"),
+    ("failure.open_logs", "📝 Open logs"),
+    ("summary.title", "📊 Run summary"),
+    ("summary.received_at", "Queried at {time}"),
+    ("summary.no_rows", "(no rows)"),
+    ("summary.failed", "Query failed · {error}"),
+    ("summary.report_header", "{name} run summary ({time})"),
+    ("pool.title", "🔌 DB session usage"),
+    ("pool.db_key", "DB"),
+    ("pool.in_use", "In use / max"),
+    ("pool.idle", "Idle"),
+    ("pool.waiting", "Waiting"),
+    ("pool.avg_checkout", "Avg checkout"),
+    ("journal.title", "⚠ The previous run did not finish"),
+    (
+        "journal.summary",
+        "{name} · started {started_at} · {count} steps succeeded",
+    ),
+    (
+        "journal.message",
+        "The app exited in the middle of a run. Restoring keeps the succeeded steps so you can retry from the failure point.",
+    ),
+    ("journal.restore", "♻ Restore run state"),
+    ("journal.dismiss", "Dismiss"),
+    ("journal.interrupted_step", "Interrupted by app exit"),
+    ("common.close", "Close"),
+    ("sql_check.all_passed", "✅ All SQL steps passed the check."),
+    ("cost_check.title", "💸 Cost check"),
+    (
+        "cost_check.hint",
+        "Advisory only. Acknowledged warnings are not asked again at run start once saved.",
+    ),
+    ("cost_check.acknowledge", "Acknowledge"),
+    ("cost_check.unacknowledge", "Unacknowledge"),
+    (
+        "cost_check.full_table_scan",
+        "Reads or changes large table {table} without WHERE.",
+    ),
+    (
+        "cost_check.conventional_load",
+        "Loads a {size}MB data file without direct path: {path}",
+    ),
+    (
+        "cost_check.large_glob",
+        "Loop pattern matches more than {limit} files: {pattern}",
+    ),
+    ("cost_check.window", "Run cost warning"),
+    ("cost_check.prompt_title", "💸 Some steps may be expensive"),
+    (
+        "cost_check.prompt_hint",
+        "Acknowledge the warnings in the builder check panel and save to stop being asked.",
+    ),
+    ("cost_check.run_anyway", "Run anyway"),
+    ("detail.no_code", "no code"),
+    ("common.copy", "Copy"),
+    ("common.none", "None"),
+    ("app.daemon_disconnected", "Daemon disconnected: {reason}"),
+    ("app.confirm_accepted", "Confirmation accepted"),
+    ("app.confirm_rejected", "Confirmation rejected"),
+    (
+        "app.gate_not_waiting",
+        "Not a gate awaiting approval: {step}",
+    ),
+    ("app.step_not_running", "Not a running step: {step}"),
+    ("app.no_scenario", "No scenario is loaded."),
+    (
+        "app.run_ended_unexpectedly",
+        "The engine stopped without reporting the end of the run. Check the logs.",
+    ),
+    ("app.encrypted_filter", "Encrypted YAML (*.yaml.enc)"),
+    ("app.import_filter", "Airflow DAG / Oozie workflow"),
+    ("app.import_done", "Imported {steps} steps."),
+    (
+        "app.import_done_todos",
+        "Imported {steps} steps. {todos} items to migrate by hand:",
+    ),
+    (
+        "app.contract_warning",
+        "Saved. Some steps have unsatisfied variable contracts, so the scenario cannot run yet:\n{violations}",
+    ),
+    ("toolbar.reload_db", "Apply DB definitions"),
+    ("toolbar.rehearsal", "🎭 Rehearsal mode"),
+    (
+        "toolbar.rehearsal_hint",
+        "Make chosen steps fail or stall on purpose to practice recovery procedures.",
+    ),
+    ("toolbar.confirm_prompt", "💬 Ask confirmations"),
+    ("toolbar.confirm_unattended", "🤖 Unattended"),
+    ("toolbar.confirm_attended", "👀 Attended"),
+    (
+        "toolbar.confirm_mode_hint",
+        "How confirm steps are answered. Unattended proceeds with each step's default answer without asking; attended always requires an explicit answer even when a default exists.",
+    ),
+    ("toolbar.context_in", "📥 Import context"),
+    (
+        "toolbar.context_in_hint",
+        "Start with the variables from a context JSON left by a previous run as scenario parameters.",
+    ),
+    ("toolbar.context_out", "📤 Export context"),
+    (
+        "toolbar.context_out_hint",
+        "Write the context variables at the end of the run to a JSON file. Not available while attached to a daemon.",
+    ),
+    ("toolbar.expanded_yaml", "📜 Expanded YAML"),
+    (
+        "toolbar.expanded_yaml_hint",
+        "View the scenario with placeholders replaced by the current parameter and profile values",
+    ),
+    ("toolbar.expanded_yaml_attach", "Attach to report"),
+    (
+        "toolbar.expanded_yaml_attach_hint",
+        "When a run starts, save the expanded YAML to the run log directory as <scenario>-<run id>.expanded.yaml",
+    ),
+    ("toolbar.run_note", "📝 Run note"),
+    (
+        "toolbar.run_note_hint",
+        "e.g. re-run after fixing FX rates file",
+    ),
+    ("toolbar.run_tags", "🏷 Tags"),
+    ("toolbar.run_tags_hint", "Separated by commas or spaces"),
+    ("toolbar.profile", "Profile: {name}"),
+    ("toolbar.profile_none", "(none)"),
+    (
+        "toolbar.profile_hint",
+        "Override scenario parameters with the execution profile. 🔒 profiles need another user's approval before running.",
+    ),
+    ("run_note.save_failed", "Failed to save run note: {error}"),
+    ("app.db_reloading", "Rebuilding DB connections..."),
+    (
+        "app.db_reloaded",
+        "DB definitions applied. New connections are used from the next run.",
+    ),
+    (
+        "app.db_reload_daemon",
+        "DB definitions applied. The daemon rebuilds connections when the next run starts.",
+    ),
+    (
+        "app.db_reload_failed",
+        "Failed to rebuild DB connections: {error}",
+    ),
+    (
+        "app.sql_preview_aborted",
+        "The SQL preview stopped before it finished.",
+    ),
+    (
+        "app.schema_aborted",
+        "The schema lookup stopped before it finished.",
+    ),
+    (
+        "app.shell_trial_aborted",
+        "The local test stopped before it finished.",
+    ),
+    (
+        "app.shell_trial_no_step",
+        "The shell step to test could not be found.",
+    ),
+    ("app.step_test_failed", "The step test failed."),
+    ("app.step_test_stopped", "The step test was stopped."),
+    (
+        "app.step_test_aborted",
+        "The step test ended without a result.",
+    ),
+    ("engine.error.db", "DB error{code}: {message}"),
+    ("engine.error.timeout", "Timed out"),
+    ("engine.error.cancelled", "Run was stopped by the user."),
+    ("engine.error.file_not_found", "File not found: {path}"),
+    (
+        "engine.error.outside_window",
+        "Outside the allowed time window: {reason}",
+    ),
+    ("engine.error.config", "Configuration error: {reason}"),
+    (
+        "editor.error.empty_scenario_name",
+        "Scenario name is empty.",
+    ),
+    ("editor.error.empty_step_id", "Step ID is empty."),
+    ("editor.error.duplicate_step_id", "Duplicate step ID: {id}"),
+    (
+        "editor.error.missing_node",
+        "Connection references a missing node: {from} -> {to}",
+    ),
+    (
+        "editor.error.cyclic",
+        "Cyclic dependency detected. Check the connections.",
+    ),
+    (
+        "editor.error.unsupported_db_kind",
+        "Unsupported DB kind: {kind} (key: {key})",
+    ),
+    ("editor.error.empty_db_key", "DB key name is empty."),
+    (
+        "editor.error.duplicate_db_key",
+        "Duplicate DB key name: {key}",
+    ),
+    (
+        "editor.error.empty_db_ref",
+        "Shared connection name for DB '{key}' is empty.",
+    ),
+    (
+        "editor.error.invalid_time_window",
+        "Invalid time window on step '{step}': {message}",
+    ),
+    (
+        "scenario.contract_violation",
+        "No upstream step provides variable {var} read by step '{step}'.",
+    ),
+    (
+        "scenario.param_not_allowed",
+        "Parameter {param} value '{value}' is not one of the allowed values ({allowed}).",
+    ),
+    (
+        "scenario.param_pattern",
+        "Parameter {param} value '{value}' does not match the pattern {pattern}.",
+    ),
+    (
+        "scenario.param_invalid_pattern",
+        "Validation pattern {pattern} of parameter {param} is invalid: {error}",
+    ),
+    (
+        "scenario.param_date_format",
+        "Parameter {param} value '{value}' does not match the date format {format}.",
+    ),
+    (
+        "scenario.param_not_number",
+        "Parameter {param} value '{value}' is not a number.",
+    ),
+    (
+        "scenario.param_below_min",
+        "Parameter {param} value '{value}' is less than the minimum {min}.",
+    ),
+    (
+        "scenario.param_above_max",
+        "Parameter {param} value '{value}' is greater than the maximum {max}.",
+    ),
+    (
+        "file_check.summary",
+        "{count} referenced files could not be verified. Fix the paths before running.",
+    ),
+    ("file_check.missing", "{field} file not found: {path}"),
+    (
+        "file_check.unreadable",
+        "{field} file is not readable: {path} ({error})",
+    ),
+    (
+        "engine.log.suppressed",
+        "… {count} log lines omitted (max {limit} lines/s per step)",
+    ),
+    (
+        "engine.log.truncated",
+        " …(truncated: {bytes} bytes in total, full line in the run log file)",
+    ),
+    (
+        "engine.log.blocked",
+        "Not run because an upstream step failed.",
+    ),
+    (
+        "engine.log.memo_hit",
+        "Reusing the result of step '{step}', which already ran with the same configuration; skipping execution.",
+    ),
+    (
+        "engine.log.skip_if_file",
+        "Skip condition: file {path} already exists.",
+    ),
+    (
+        "engine.log.skip_if_sql",
+        "Skip condition: the query returned rows.",
+    ),
+    (
+        "engine.log.skip_if_failed",
+        "Could not check the skip condition, running the step: {error}",
+    ),
+    (
+        "engine.log.output_missing",
+        "Expected output is missing: {path}",
+    ),
+    (
+        "engine.log.output_empty",
+        "Expected output is empty: {path}",
+    ),
+    (
+        "engine.log.output_remote_skipped",
+        "Skipping expected output check because the step ran on remote host {remote}.",
+    ),
+    (
+        "engine.log.remote_cancel",
+        "Cancelled and closed the local ssh connection, but the process on remote host {remote} may still be running.",
+    ),
+    ("engine.log.wait_start", "Waiting {secs}s (until {until})."),
+    ("engine.log.wait_remaining", "Time left: {secs}s"),
+    ("engine.log.wait_done", "Wait finished."),
+    (
+        "engine.log.wait_run_start",
+        "Waiting for a successful run of {scenario} finished after {since}.",
+    ),
+    (
+        "engine.log.wait_run_pending",
+        "No successful run of {scenario} yet. Checking again in {secs}s.",
+    ),
+    (
+        "engine.log.wait_run_done",
+        "Found a successful run of {scenario} finished at {finished}.",
+    ),
+    (
+        "engine.log.wait_run_timeout",
+        "No successful run of {scenario} finished after {since} within the maximum wait.",
+    ),
+    (
+        "engine.log.marker_reached",
+        "All upstream steps finished; marker passed.",
+    ),
+    (
+        "engine.log.step_cancel_fail",
+        "Cancelled by the user and marked as failed.",
+    ),
+    (
+        "engine.log.step_cancel_skip",
+        "Skipped by the user and marked as succeeded.",
+    ),
+    (
+        "engine.log.step_tmpdir_failed",
+        "Could not create the step work directory; running without ${STEP_TMPDIR}: {error}",
+    ),
+    (
+        "engine.log.diagnostics_start",
+        "Running failure diagnostics.",
+    ),
+    ("engine.log.diagnostic_failed", "Diagnostic failed: {error}"),
+    ("engine.log.diagnostic_empty", "(no output)"),
+    (
+        "engine.log.loop_no_match",
+        "No files match the loop pattern: {pattern}",
+    ),
+    (
+        "engine.log.loop_iteration",
+        "[iteration {index}] {var} = {value}",
+    ),
+    (
+        "engine.log.loop_failure_ignored",
+        "Ignoring loop iteration failure: {error}",
+    ),
+    (
+        "engine.confirm.rejected_before",
+        "Step '{step}' was rejected at the pre-run confirmation.",
+    ),
+    (
+        "engine.confirm.rejected_after",
+        "Step '{step}' was rejected at the post-run confirmation.",
+    ),
+    ("engine.confirm.error", "Confirmation error: {error}"),
+    (
+        "engine.confirm.unattended",
+        "Unattended run: confirmation resolved with the default answer '{answer}'.",
+    ),
+    (
+        "engine.confirm.attended_no_ui",
+        "Attended run, but no screen is available to answer the confirmation.",
+    ),
+    (
+        "engine.confirm.attended_no_answer",
+        "Attended run, but the connection closed before the confirmation was answered.",
+    ),
+    (
+        "engine.output_check_failed",
+        "{count} expected output(s) missing or empty.",
+    ),
+    (
+        "engine.gate.no_bridge",
+        "No UI is available to approve this manual gate.",
+    ),
+    (
+        "engine.rehearsal.injected_failure",
+        "Rehearsal: injected failure",
+    ),
+    (
+        "engine.log.retry_after_error",
+        "Error occurred, retrying in {secs}s",
+    ),
+    (
+        "engine.log.retry_skipped_permanent",
+        "Not a transient error, skipping retries (set retry_on: any to retry every error)",
+    ),
+    (
+        "engine.log.retry_after_timeout",
+        "Timed out, preparing retry",
+    ),
+    (
+        "engine.log.timeout_fail",
+        "Timed out, failing without retry per policy",
+    ),
+    (
+        "engine.log.timeout_warn",
+        "Exceeded the {secs}s limit ({elapsed}s elapsed), still waiting",
+    ),
+    ("engine.log.sql_start", "Running SQL"),
+    ("engine.log.sql_file", "Running SQL file: {path}"),
+    (
+        "engine.log.sql_plan_saved",
+        "Saved the execution plan of a slow SQL ({secs}s): {path}",
+    ),
+    (
+        "engine.log.sql_plan_failed",
+        "Could not get the execution plan of a slow SQL ({secs}s): {error}",
+    ),
+    (
+        "engine.log.sql_plan_multi",
+        "Slow SQL ({secs}s) has multiple statements, so no execution plan was taken.",
+    ),
+    ("engine.plan.elapsed", "Elapsed: {secs}s"),
+    (
+        "engine.log.shell_exit_ignored",
+        "Non-zero exit {status}, ignored by policy",
+    ),
+    (
+        "engine.log.shell_retry",
+        "Shell command failed, retrying in {secs}s ({attempt}/{max})",
+    ),
+    (
+        "engine.log.process_priority",
+        "Process priority applied: {detail}",
+    ),
+    (
+        "engine.log.window_wait",
+        "Waiting for time window until {until}",
+    ),
+    ("engine.log.window_open", "Time window opened, starting"),
+    ("engine.log.gate_waiting", "Waiting for approval"),
+    ("engine.log.gate_approved", "Approved by {approver} at {at}"),
+    ("engine.log.rehearsal_delay", "Rehearsal: delaying {secs}s"),
+    (
+        "engine.log.rehearsal_simulated",
+        "Rehearsal: marked successful without running",
+    ),
+    (
+        "engine.log.on_failure_timeout",
+        "Stopped after exceeding the cleanup time limit ({secs}s).",
+    ),
+    (
+        "engine.log.on_failure_expired",
+        "Not run because the cleanup time limit has passed.",
+    ),
+    (
+        "engine.error.schema_timeout",
+        "Schema lookup timed out after {secs}s",
+    ),
+    (
+        "engine.error.query_timeout",
+        "Query timed out after {secs}s",
+    ),
+    (
+        "engine.error.diagnostic_timeout",
+        "Diagnostic timed out after {secs}s",
+    ),
+    (
+        "engine.error.diagnostic_kind",
+        "Specify exactly one of shell and sql.",
+    ),
+    ("engine.diagnostic.exit_code", "(exit code: {code})"),
+    (
+        "engine.output.truncated",
+        "…(truncated: {bytes} bytes total)",
+    ),
+    (
+        "engine.error.sandbox_create",
+        "Cannot create the sandbox directory: {path}",
+    ),
+    (
+        "engine.error.shell_trial_timeout",
+        "Local test timed out after {secs}s",
+    ),
+    (
+        "engine.error.shell_spawn",
+        "Cannot start the shell: {program}",
+    ),
+    ("engine.error.step_not_found", "Step not found: {step}"),
+    (
+        "engine.error.run_tmpdir_create",
+        "Failed to create the run temp directory: {path}",
+    ),
+    (
+        "engine.error.step_tmpdir_create",
+        "Failed to create the step work directory: {path}",
+    ),
+    (
+        "engine.error.placeholder",
+        "Failed to resolve placeholders: {text}",
+    ),
+    (
+        "engine.error.placeholder_field",
+        "Cannot resolve the placeholders in the {field} field.",
+    ),
+    (
+        "engine.error.context_file_write",
+        "Cannot write the context file: {path}",
+    ),
+    (
+        "engine.error.context_file_read",
+        "Cannot read the context file: {path}",
+    ),
+    (
+        "engine.error.context_file_format",
+        "Invalid context file format: {path}",
+    ),
+    ("engine.error.unknown_db", "Undefined DB target: {name}"),
+    (
+        "engine.error.unknown_remote",
+        "Undefined remote host: {name}",
+    ),
+    (
+        "engine.error.db_executor",
+        "Failed to create the DB executor: {name}",
+    ),
+    (
+        "engine.error.remote_expand",
+        "Failed to resolve the remote host settings: {name}",
+    ),
+    ("engine.error.missing_field", "{field} is missing."),
+    ("engine.error.sqlldr_conn", "SQLLDR_CONN is not set."),
+    ("engine.error.sqlldr_spawn", "Failed to start sqlldr"),
+    ("engine.error.sqlldr_exit", "sqlldr exit status: {status}"),
+    (
+        "engine.error.run_as_remote",
+        "run_as is not supported for remote execution.",
+    ),
+    (
+        "engine.error.shell_spawn_script",
+        "Failed to run the shell command: {script}",
+    ),
+    (
+        "engine.error.shell_exit",
+        "Shell command exit status: {status}",
+    ),
+    (
+        "engine.error.shell_retry_exhausted",
+        "Retry limit exceeded: {status}",
+    ),
+    (
+        "engine.error.run_as_unsupported",
+        "run_as is not supported on this operating system.",
+    ),
+    ("engine.error.passwd_read", "Cannot read /etc/passwd."),
+    ("engine.error.uid_parse", "Failed to parse UID: {value}"),
+    ("engine.error.gid_parse", "Failed to parse GID: {value}"),
+    ("engine.error.user_not_found", "User {user} not found."),
+    (
+        "engine.error.sql_file_read",
+        "Failed to read the SQL file: {path}",
+    ),
+    (
+        "engine.error.verify_both",
+        "Specify only one of expected and expected_file in verify.",
+    ),
+    (
+        "engine.error.verify_lines_expected",
+        "Line count verification needs expected or expected_file.",
+    ),
+    (
+        "engine.error.verify_lines_nan",
+        "Expected line count is not a number: {expected} ({source})",
+    ),
+    ("engine.verify.lines", "Line count"),
+    ("engine.verify.checksum", "{method} checksum"),
+    (
+        "engine.error.verify_mismatch",
+        "{what} mismatch: {path}\n  expected: {expected} ({source})\n  actual: {actual}",
+    ),
+    ("engine.log.verify_passed", "Verification passed ({source})"),
+    (
+        "engine.error.verify_expected_read",
+        "Cannot read the expected value file: {path}",
+    ),
+    (
+        "engine.error.verify_expected_empty",
+        "The expected value file is empty: {path}",
+    ),
+    ("engine.error.file_open", "Cannot open the file: {path}"),
+    ("engine.log.pipe_read_error", "{tag} read error: {error}"),
+    (
+        "engine.error.priority_linux_only",
+        "priority io_class, io_level and cpu_affinity are supported only on Linux.",
+    ),
+    (
+        "engine.error.window_closed",
+        "Does not start after not_after {time}.",
+    ),
+    (
+        "engine.error.window_not_open",
+        "Does not start before {until}.",
+    ),
+    (
+        "engine.error.extract_line",
+        "Line {line} not found in {path}.",
+    ),
+    (
+        "engine.error.extract_regex",
+        "Failed to compile the regex: {pattern} ({error})",
+    ),
+    (
+        "engine.error.extract_no_match",
+        "The pattern did not match: {line}",
+    ),
+    (
+        "engine.error.extract_group",
+        "Capture group {group} not found.",
+    ),
+    ("engine.log.extract_var", "Variable {name} = {value}"),
+    ("engine.confirm.sql_file", "File: {path}"),
+    (
+        "engine.confirm.extract",
+        "File: {path} / group: {group} / variable: {var}",
+    ),
+    ("engine.confirm.wait_until", "Wait: until {until}"),
+    ("engine.confirm.wait_secs", "Wait: {secs}s"),
+    (
+        "engine.confirm.wait_for_run",
+        "Wait for run: {scenario} (since {since})",
+    ),
+    ("engine.confirm.since_midnight", "midnight today"),
+    ("engine.confirm.verify", "Verify: {file} ({method})"),
+    (
+        "engine.error.glob",
+        "Failed to parse the glob pattern: {error}",
+    ),
+    (
+        "engine.error.loop_order",
+        "Cannot determine the order of the loop's child steps. Check the dependencies.",
+    ),
+    (
+        "engine.error.loop_unknown_dependency",
+        "Loop child step '{step}' references unknown dependency '{dependency}'.",
+    ),
+    (
+        "engine.error.wait_for_run_scenario",
+        "Specify the scenario to wait for in wait_for_run.",
+    ),
+    (
+        "engine.error.preview_timeout",
+        "Preview timed out after {secs}s",
+    ),
+    (
+        "engine.error.preview_read_only",
+        "Preview can run only queries that start with SELECT or WITH.",
+    ),
+    (
+        "engine.error.preview_single",
+        "Preview can run only a single query.",
+    ),
+    (
+        "executor.error.no_query",
+        "This DB executor does not return query results.",
+    ),
+    (
+        "executor.error.no_plan",
+        "This DB executor does not support execution plans.",
+    ),
+    (
+        "executor.error.no_schema",
+        "This DB executor does not support schema lookup.",
+    ),
+    (
+        "executor.pg.pool",
+        "Failed to create the PostgreSQL connection pool",
+    ),
+    (
+        "executor.pg.checkout",
+        "Failed to get a PostgreSQL connection",
+    ),
+    (
+        "executor.pg.begin_read_only",
+        "Failed to start a PostgreSQL read-only transaction",
+    ),
+    (
+        "executor.pg.statement_timeout",
+        "Failed to set the PostgreSQL statement_timeout",
+    ),
+    (
+        "executor.pg.explain",
+        "Failed to get the PostgreSQL execution plan",
+    ),
+    ("executor.pg.rollback", "PostgreSQL rollback failed"),
+    ("executor.pg.execute", "PostgreSQL SQL execution failed"),
+    (
+        "executor.pg.begin",
+        "Failed to start a PostgreSQL transaction",
+    ),
+    (
+        "executor.pg.execute_single",
+        "PostgreSQL SQL execution failed (max_affected_rows supports a single statement only)",
+    ),
+    ("executor.pg.commit", "PostgreSQL commit failed"),
+    ("executor.pg.query", "PostgreSQL query failed"),
+    (
+        "executor.error.max_affected_rows",
+        "Rolled back because {affected} changed rows exceed the limit of {limit}.",
+    ),
+    ("executor.oracle.spawn", "Failed to start sqlplus"),
+    (
+        "executor.oracle.stdin",
+        "Failed to send the script to sqlplus",
+    ),
+    (
+        "executor.oracle.timeout",
+        "sqlplus statement timed out after {secs}s",
+    ),
+    ("executor.oracle.wait", "Failed to wait for sqlplus to exit"),
+    (
+        "executor.oracle.exit",
+        "sqlplus exit status: {status}\n{detail}",
+    ),
+    ("cli.service_installed", "Installed the service: {name}"),
+    ("cli.service_uninstalled", "Removed the service: {name}"),
+    ("cli.service_started", "Started the service: {name}"),
+    ("cli.service_failed", "Service command failed: {error}"),
+    ("cli.run_failed", "Scenario run failed: {path}"),
+    ("cli.run_unavailable", "Cannot run the scenario: {error}"),
+    ("cli.report_path", "Report: {path}"),
+    (
+        "cli.self_test_failed",
+        "Failed to prepare the self-test: {error}",
+    ),
+    ("cli.schedule_failed", "Schedule export failed: {error}"),
+    ("cli.load_failed", "Failed to read the scenario: {error}"),
+    (
+        "cli.lint_summary",
+        "{path}: {errors} errors, {warnings} warnings",
+    ),
+    ("cli.graph_failed", "Graph export failed: {error}"),
+    (
+        "cli.graph_blocked",
+        "Not exporting the graph because of {errors} errors.",
+    ),
+    ("cli.param_format", "--param must be KEY=VALUE: {param}"),
+    (
+        "cli.headless_blocked",
+        "Skipped because a dependency failed",
+    ),
+    ("lint.level_error", "error"),
+    ("lint.level_warning", "warning"),
+    ("lint.duplicate_id", "[{step}] Duplicate step ID."),
+    (
+        "lint.unknown_dependency",
+        "[{step}] depends_on step not found: {dependency}",
+    ),
+    (
+        "lint.cycle",
+        "Steps that cannot start because of a dependency cycle: {steps}",
+    ),
+    (
+        "cli.graph_format",
+        "Unknown graph format: {format} (dot or mermaid)",
+    ),
+    (
+        "sql_check.path_has_vars",
+        "Not checked because the path has variables: {path}",
+    ),
+    (
+        "sql_check.read_failed",
+        "Cannot read the SQL file: {path} ({error})",
+    ),
+    ("sql_check.syntax", "Syntax error: {error}"),
+    (
+        "sql_check.unclosed_comment",
+        "The block comment on line {line} is not closed.",
+    ),
+    (
+        "sql_check.unclosed_string",
+        "The string on line {line} is not closed.",
+    ),
+    (
+        "sql_check.unclosed_identifier",
+        "The quoted identifier on line {line} is not closed.",
+    ),
+    ("sql_check.unmatched_close", "Unmatched ')' on line {line}."),
+    ("sql_check.unclosed_paren", "{count} '(' not closed."),
+    (
+        "self_test.summary_passed",
+        "Self-test passed: {failed} of {total} checks failed",
+    ),
+    (
+        "self_test.summary_failed",
+        "Self-test failed: {failed} of {total} checks failed",
+    ),
+    (
+        "self_test.fixture_dir",
+        "Failed to create the self-test temp folder: {path}",
+    ),
+    ("self_test.engine_ok", "Engine finished without errors"),
+    (
+        "self_test.finished_once",
+        "Scenario finished event arrives once, last",
+    ),
+    ("self_test.finished_count", "{count} finished events"),
+    ("self_test.steps_once", "Every step finishes exactly once"),
+    (
+        "self_test.dependency_order",
+        "Steps start only after their dependencies succeed",
+    ),
+    (
+        "self_test.forced_failure",
+        "The forced failure is recorded as failed",
+    ),
+    ("self_test.failed_steps", "Failed steps: {steps}"),
+    (
+        "self_test.blocked_downstream",
+        "Downstream steps of a failed step are skipped without starting",
+    ),
+    ("self_test.blocked_steps", "Skipped steps: {steps}"),
+    (
+        "self_test.independent_success",
+        "Parallel branches and steps unrelated to the failure all succeed",
+    ),
+    (
+        "self_test.extract_value",
+        "Extract variable matches the input file",
+    ),
+    ("self_test.loop_files", "Loop iterates over every file"),
+    (
+        "schedule.unknown_format",
+        "Unknown schedule format: {format} (crontab or windows)",
+    ),
+    (
+        "schedule.cron_fields",
+        "A cron expression needs five fields `minute hour day month weekday`: {expr}",
+    ),
+    ("schedule.field_minute", "minute"),
+    ("schedule.field_hour", "hour"),
+    ("schedule.field_day", "day"),
+    ("schedule.field_month", "month"),
+    ("schedule.field_weekday", "weekday"),
+    ("schedule.field_error", "Invalid cron {field} field"),
+    (
+        "schedule.cron_required",
+        "Specify --cron \"<minute hour day month weekday>\".",
+    ),
+    (
+        "schedule.scenario_missing",
+        "Scenario file not found: {path}",
+    ),
+    (
+        "schedule.too_many_triggers",
+        "Task Scheduler would need more than {max} triggers. Use fewer minutes and hours: {expr}",
+    ),
+    (
+        "schedule.unsupported_cron",
+        "This cron expression cannot be converted for Task Scheduler. Day and weekday, or weekday and month, cannot be set together: {expr}",
+    ),
+    ("schedule.empty_command", "The command to run is empty."),
+    ("schedule.bad_step", "Invalid step: {part}"),
+    (
+        "schedule.bad_range",
+        "Range start is greater than its end: {part}",
+    ),
+    ("schedule.not_number", "Not a number: {value}"),
+    ("schedule.out_of_range", "Out of range {min}-{max}: {value}"),
+    (
+        "cli.confirm_mode_conflict",
+        "--unattended and --attended cannot be used together.",
+    ),
+    ("editor.default_scenario_name", "New scenario"),
+    (
+        "scenario.error.db_kind_required",
+        "A DB connection needs kind (dummy, postgres, oracle) or db_ref.",
+    ),
+    (
+        "scenario.error.priority_nice",
+        "priority.nice must be between -20 and 19: {value}",
+    ),
+    (
+        "scenario.error.priority_io_level",
+        "priority.io_level must be between 0 and 7: {value}",
+    ),
+    (
+        "scenario.error.priority_idle_level",
+        "io_level cannot be set when priority.io_class is idle.",
+    ),
+    (
+        "scenario.error.shell_error_policy",
+        "Unknown shell error policy: {policy}",
+    ),
+    (
+        "scenario.error.time_format",
+        "Invalid {field} time (HH:MM or HH:MM:SS): {value}",
+    ),
+    (
+        "scenario.error.wait_both",
+        "Specify only one of seconds and until_time in wait.",
+    ),
+    (
+        "scenario.error.wait_missing",
+        "Specify seconds or until_time in wait.",
+    ),
+    ("scenario.error.since_format", "Invalid since time: {value}"),
+    (
+        "scenario.error.local_time",
+        "The time does not exist in the local time zone: {time}",
+    ),
+    (
+        "scenario.error.include_format",
+        "Invalid include entry: {error}",
+    ),
+    (
+        "scenario.error.fragment_read",
+        "Failed to read the step fragment file ({path}): {error}",
+    ),
+    (
+        "scenario.error.fragment_parse",
+        "Failed to parse the step fragment file ({path}): {error}",
+    ),
+    (
+        "scenario.error.fragment_no_steps",
+        "The step fragment file has no steps list: {path}",
+    ),
+    (
+        "scenario.error.fragment_nested_include",
+        "include cannot be used inside a step fragment file: {path}",
+    ),
+    ("parse_error.hint_text", "Hint: {hint}"),
+    (
+        "parse_error.hint_tabs",
+        "Use spaces instead of tabs for indentation.",
+    ),
+    (
+        "parse_error.hint_quote_colon",
+        "If a value contains `: `, wrap the whole value in quotes.",
+    ),
+    (
+        "parse_error.hint_indent",
+        "Check that the indentation and the `-` list marker line up with the line above.",
+    ),
+    (
+        "parse_error.hint_duplicate",
+        "The same key appears twice in one mapping. Remove one.",
+    ),
+    (
+        "parse_error.hint_kind",
+        "Set `kind` on the step. Allowed values: {kinds}",
+    ),
+    (
+        "parse_error.hint_key_typo",
+        "Check the key name: `{typo}` → `{key}`",
+    ),
+    (
+        "parse_error.hint_other_kind",
+        "There is a `{key}` key. Did you mean `kind: {kind}`?",
+    ),
+    (
+        "parse_error.hint_kind_field",
+        "A `kind: {kind}` step needs `{field}`.",
+    ),
+    ("parse_error.hint_add_field", "Add `{field}`."),
+    ("parse_error.hint_similar", "Similar value: `{value}`"),
+    ("parse_error.hint_options", "Allowed values: {options}"),
+    ("builder.kind.sql_file", "SQL file"),
+    ("builder.kind.manual_gate", "Approval gate"),
+    ("builder.kind.wait", "Wait"),
+    ("builder.kind.wait_for_run", "Wait for run"),
+    ("builder.kind.verify", "Verify"),
+    ("builder.kind.marker", "Marker"),
+    ("builder.lost.target_db", "DB target (target_db)"),
+    ("builder.lost.guard", "SQL guard"),
+    ("builder.lost.sql_file_path", "SQL file path"),
+    ("builder.lost.control_file", "control file"),
+    ("builder.lost.sqlldr_files", "data/log/bad/discard files"),
+    ("builder.lost.conn", "Connection string"),
+    ("builder.lost.remote", "Remote host"),
+    (
+        "builder.lost.sqlldr_options",
+        "sqlldr options/extra arguments",
+    ),
+    ("builder.lost.script", "Script"),
+    ("builder.lost.shell_program", "Shell program/arguments"),
+    ("builder.lost.env", "Environment variables"),
+    ("builder.lost.working_dir", "Working directory/run-as user"),
+    (
+        "builder.lost.extract",
+        "Extract settings (file/regex/variable)",
+    ),
+    ("builder.lost.loop_steps", "All loop child steps"),
+    ("builder.lost.gate_message", "Approval message"),
+    ("builder.lost.wait", "Wait time"),
+    ("builder.lost.wait_for_run", "Scenario to wait for"),
+    (
+        "builder.lost.verify",
+        "Verify settings (file/expected value)",
+    ),
+    ("builder.new_step_name", "New step {id}"),
+    ("builder.copy_name", "{name} copy"),
+    (
+        "builder.paste.added_db",
+        "Also added DB connections: {names} (passwords are not copied; enter them in the DB settings)",
+    ),
+    (
+        "builder.paste.conflicting_db",
+        "Kept this scenario's definition for DB connections whose definitions differ: {names}",
+    ),
+    (
+        "builder.paste.missing_vars",
+        "⚠ Variables with no providing step or parameter: {names}",
+    ),
+    ("builder.none", "(none)"),
+    ("builder.notify.title", "Step notification webhook"),
+    (
+        "builder.notify.hint",
+        "URL to POST JSON to on start, success and failure (notify_url)",
+    ),
+    (
+        "builder.notify.placeholder",
+        "e.g. http://hooks.internal/customer-loaded",
+    ),
+    (
+        "builder.sql_change.changed",
+        "⚠ Changed · the SQL file changed since the last successful run ({changed_at}).",
+    ),
+    ("builder.sql_change.show", "🔍 Show changes"),
+    (
+        "builder.sql_change.show_hover",
+        "Compares the file content at the last successful run with the current content line by line.",
+    ),
+    ("builder.contracts.title", "Variable contract"),
+    ("builder.contracts.consumes", "Reads variables (consumes)"),
+    (
+        "builder.contracts.produces",
+        "Provides variables (produces)",
+    ),
+    (
+        "builder.contracts.placeholder",
+        "e.g. RUN_DATE, TARGET_FILE",
+    ),
+    (
+        "builder.included.title",
+        "🔗 Step included from a fragment file (read-only)",
+    ),
+    ("builder.included.copy_path", "📋 Copy path"),
+    (
+        "builder.included.copy_path_hover",
+        "Copies the fragment file path to the clipboard.",
+    ),
+    ("builder.sql_diff.title", "SQL changes · {step_id}"),
+    (
+        "builder.sql_diff.no_snapshot",
+        "The file content from the last successful run is no longer available, so it cannot be compared. It may have been removed by run history cleanup.",
+    ),
+    (
+        "builder.sql_diff.counts",
+        "−{removed} lines · +{added} lines",
+    ),
+    ("builder.skip_if.title", "Skip if results already exist"),
+    (
+        "builder.skip_if.file_exists",
+        "If this file exists (file_exists)",
+    ),
+    (
+        "builder.skip_if.sql_returns_rows",
+        "If this query returns rows (sql_returns_rows)",
+    ),
+    ("builder.skip_if.db", "Query DB (default if empty)"),
+    (
+        "builder.skip_if.hint",
+        "When all given conditions hold, the step is not run and is treated as successful.",
+    ),
+    ("builder.shell.vars", "Variables"),
+    (
+        "builder.included.prefix",
+        "ID prefix `{prefix}` · edit it in the fragment file.",
+    ),
+    (
+        "builder.sql_diff.since",
+        "{path} · since the last successful run {at}",
+    ),
+    (
+        "builder.perf.overlay",
+        "FPS {fps}\nCanvas build {build_ms}ms\nNodes {nodes}/{nodes_total} · Connections {connections}/{connections_total}",
+    ),
+    ("builder.list.chain", "🔗 Chain in order"),
+    ("builder.list.drag_hint", "☰ Drag the handle to reorder."),
+    ("builder.col.kind", "Kind"),
+    ("builder.col.name", "Name"),
+    ("builder.col.predecessors", "Predecessors"),
+    ("builder.outputs.title", "Expected outputs"),
+    (
+        "builder.outputs.files",
+        "Files that must exist after success (one per line, expects_outputs)",
+    ),
+    ("builder.outputs.fail", "Fail the step"),
+    ("builder.outputs.warn", "Warn only"),
+    ("builder.outputs.policy", "When missing or empty"),
+    (
+        "builder.outputs.hint",
+        "Even with exit code 0, a missing or zero-byte file is handled by this policy.",
+    ),
+    (
+        "builder.preview.button",
+        "🔎 Preview (up to {SQL_PREVIEW_MAX_ROWS} rows)",
+    ),
+    (
+        "builder.preview.hover",
+        "Runs a read-only query on the DB target and shows part of the result. Only SELECT/WITH queries are run.",
+    ),
+    ("builder.preview.running", "Querying..."),
+    ("builder.preview.no_rows", "No rows returned."),
+    (
+        "builder.convert.nothing_lost",
+        "Nothing is lost. Connection/retry/timeout/confirm settings are kept.",
+    ),
+    (
+        "builder.convert.kept",
+        "Connection/retry/timeout/confirm settings are kept.",
+    ),
+    ("builder.convert.apply", "Convert"),
+    ("builder.schema.title", "🗂 Schema"),
+    ("builder.schema.reload", "Reload"),
+    ("builder.schema.filter", "Filter table names"),
+    ("builder.schema.loading", "Loading tables…"),
+    ("builder.schema.failed", "Query failed: {err}"),
+    ("builder.delete.bridge", "Delete and bridge connections"),
+    ("builder.delete.apply", "Delete"),
+    ("builder.window.title", "Run time window"),
+    (
+        "builder.window.not_before",
+        "Earliest start (not_before, HH:MM)",
+    ),
+    (
+        "builder.window.not_after",
+        "Latest start (not_after, HH:MM)",
+    ),
+    ("builder.window.policy", "If reached before the window"),
+    ("builder.window.wait", "Wait"),
+    ("builder.window.fail", "Fail"),
+    (
+        "builder.list.chain_hint",
+        "Creates previous → next step dependencies in the current order. All existing connections are removed.",
+    ),
+    (
+        "builder.preview.truncated",
+        "{more}+ rows · showing the first {shown}",
+    ),
+    ("builder.preview.rows", "{rows} rows"),
+    ("builder.convert.title", "Convert {from} → {to}"),
+    (
+        "builder.convert.lost",
+        "These values will be removed: {fields}",
+    ),
+    (
+        "builder.schema.tables",
+        "{count} tables · drag a name onto the SQL editor to insert it.",
+    ),
+    ("builder.delete.title", "Delete '{step}'"),
+    ("builder.delete.predecessors", "Predecessors: {steps}"),
+    ("builder.delete.successors", "Successors: {steps}"),
+    (
+        "builder.delete.orphaned",
+        "Steps left without a predecessor unless connections are bridged: {steps}",
+    ),
+    ("builder.step_test.button", "🧪 Test this step only"),
+    (
+        "builder.step_test.hover",
+        "Runs only this step with the configured executor. No history or notifications are recorded.",
+    ),
+    ("builder.preparing", "Preparing..."),
+    ("builder.running", "Running..."),
+    ("builder.stop", "⏹ Stop"),
+    ("builder.stopping", "Stopping..."),
+    ("builder.step_test.passed", "✅ Test passed"),
+    ("builder.run", "▶ Run"),
+    ("builder.shell_trial.title", "🧪 Local test"),
+    ("builder.shell_trial.no_vars", "No variables to substitute."),
+    ("builder.shell_trial.dummy_values", "Dummy variable values"),
+    ("builder.shell_trial.run", "▶ Run locally"),
+    (
+        "builder.shell_trial.hover",
+        "Runs once with this machine's shell in a temporary sandbox directory. Remote host, run-as user and working directory are ignored.",
+    ),
+    ("builder.shell_trial.exit_code", "Exit code {code}"),
+    ("builder.shell_trial.signaled", "Terminated by signal"),
+    ("builder.shell_trial.no_output", "No output."),
+    ("builder.confirm.title", "Run confirmation"),
+    ("builder.confirm.before", "Confirm before run"),
+    ("builder.confirm.after", "Confirm after run"),
+    ("builder.confirm.message_before", "Message (before run)"),
+    ("builder.confirm.message_after", "Message (after run)"),
+    ("builder.confirm.default_answer", "Default answer"),
+    ("builder.palette.title", "🧱 Step palette"),
+    ("builder.palette.hint", "Choose the kind of step to add."),
+    ("builder.palette.extract", "Extract (value)"),
+    ("builder.palette.loop", "Loop"),
+    (
+        "builder.palette.wait_for_run",
+        "Wait for run (other scenario)",
+    ),
+    ("builder.palette.marker", "Marker (join point)"),
+    ("builder.trash.title", "🗑 Trash ({count})"),
+    ("builder.trash.empty", "No deleted steps."),
+    ("builder.trash.restore", "↩ Restore"),
+    (
+        "builder.trash.restore_hover",
+        "Restores the step where it was and reconnects links whose other step still exists.",
+    ),
+    (
+        "builder.trash.purge_hover",
+        "Removes it from the trash permanently.",
+    ),
+    ("builder.trash.clear", "Empty trash"),
+    ("builder.trash.no_connections", "No connections"),
+    ("builder.trash.connections", "Connections before deletion:"),
+    (
+        "builder.canvas.sql_changed_hover",
+        "The SQL file changed since the last successful run. See the changes in the properties panel.",
+    ),
+    ("builder.canvas.add_here", "Add step here"),
+    ("builder.critical.no_steps", "No steps to analyze."),
+    (
+        "builder.critical.top",
+        "Top {CRITICAL_PATH_TOP_STEPS} by savings:",
+    ),
+    (
+        "builder.critical.no_history",
+        "None (no run history for path steps)",
+    ),
+    (
+        "builder.critical.saving_hover",
+        "How much the whole run would shorten if this step took no time",
+    ),
+    ("builder.sim.pause", "Pause"),
+    ("builder.sim.play", "Play"),
+    ("builder.sim.rewind", "Back to start"),
+    ("builder.sim.tick", "Tick"),
+    (
+        "builder.sim.assume_success",
+        "✅ Assume selected step succeeds",
+    ),
+    (
+        "builder.sim.assume_failure",
+        "💥 Assume selected step fails",
+    ),
+    (
+        "builder.sim.failure_hover",
+        "Shows which downstream steps are skipped if the selected step fails.",
+    ),
+    ("builder.sim.clear_failures", "Clear assumptions"),
+    (
+        "builder.step_test.enter_vars",
+        "Enter values for the variables this step references.",
+    ),
+    (
+        "builder.trash.capacity",
+        "Keeps the last {capacity} steps and is emptied when a scenario is opened.",
+    ),
+    (
+        "builder.trash.open_flow",
+        "Open the flow the step was in ({flow}) on the canvas to restore it.",
+    ),
+    ("builder.trash.flow", "Flow: {flow}"),
+    ("builder.trash.top_level", "Top level"),
+    ("builder.canvas.history", "Past runs · {summary}"),
+    ("builder.canvas.no_var", "{label} → no variable"),
+    (
+        "builder.critical.cycle",
+        "⚠ The critical path cannot be computed because of a cycle.",
+    ),
+    (
+        "builder.critical.total",
+        "⏱ Estimated total duration {total}",
+    ),
+    (
+        "builder.critical.unmeasured",
+        "{count} steps without run history were counted as 0 seconds.",
+    ),
+    ("builder.canvas.changed", "Changed"),
+    ("builder.sim.total", "🔢 Estimated total duration {total}"),
+    (
+        "builder.sim.counts",
+        "{started} started · {parallel} overlapping other steps · {blocked} skipped",
+    ),
+    ("builder.sim.failures", "Assumed failures: {steps}"),
+    (
+        "builder.sim.unmeasured",
+        "{count} steps without run history were counted as {secs} seconds.",
+    ),
+    (
+        "builder.sim.unreached",
+        "⚠ Steps that cannot start (for example due to a cycle): {steps}",
+    ),
+    ("builder.props.title", "⚙️ Step properties"),
+    ("builder.props.duplicate", "📄 Duplicate"),
+    (
+        "builder.props.duplicate_hover",
+        "Makes a copy with a suffixed ID. (Ctrl+D)",
+    ),
+    ("builder.props.open_loop", "🔁 Open loop"),
+    (
+        "builder.props.open_loop_hover",
+        "Edit the child steps on the canvas. (Double-click the node)",
+    ),
+    ("builder.props.icon", "Display icon"),
+    ("builder.props.label", "Display label"),
+    ("builder.props.stage", "Stage"),
+    ("builder.allow_parallel", "Allow parallel"),
+    ("builder.retry", "Retries"),
+    ("builder.timeout_prefix", "Timeout "),
+    ("builder.seconds_suffix", "s"),
+    (
+        "builder.props.not_found",
+        "The selected step could not be found.",
+    ),
+    ("builder.props.no_selection", "No step selected."),
+    ("builder.deps.title", "Dependencies"),
+    ("builder.deps.add", "Add dependency"),
+    ("builder.deps.select_node", "Select node"),
+    ("builder.props.delete_step", "Delete this step"),
+    ("builder.props.scenario_name", "Scenario name"),
+    (
+        "builder.props.memoize_hover",
+        "Enable only for idempotent steps. Within one run, steps with the same kind and expanded config run once, and later iterations and branches reuse the result.",
+    ),
+    ("builder.props.retry_all", "Retry on all errors"),
+    (
+        "builder.props.retry_all_hover",
+        "When off, only transient errors such as disconnects and deadlocks are retried, and SQL errors fail immediately.",
+    ),
+    ("builder.timeout.fail", "Fail immediately"),
+    ("builder.timeout.warn_continue", "Warn and keep waiting"),
+    ("builder.timeout.kill_retry", "Abort and retry"),
+    ("builder.timeout.policy", "On timeout"),
+    (
+        "builder.timeout.warn_continue_hover",
+        "Warn and keep waiting only logs a warning on each timeout and does not stop the step.",
+    ),
+    ("builder.log_level.default", "Default"),
+    ("builder.log_level.debug", "debug (verbose)"),
+    ("builder.log_level.warn", "warn (warnings only)"),
+    ("builder.log_level.title", "Log level"),
+    (
+        "builder.log_level.hover",
+        "Controls how many progress messages the engine logs. debug also logs variable values for each loop iteration, and warn logs warnings only. Program output and failure messages are always logged.",
+    ),
+    (
+        "builder.props.inherited",
+        "Inherited from the scenario defaults.",
+    ),
+    ("builder.db.title", "🗄 DB settings"),
+    (
+        "builder.db.hint",
+        "Defines the DB connections used by SQL/SQL file steps.",
+    ),
+    (
+        "builder.db.no_default",
+        "Without a default key, steps without target_db fail.",
+    ),
+    (
+        "builder.db.empty",
+        "No DB connections. Use the 'Add DB connection' button to create one.",
+    ),
+    ("builder.db.key", "Key"),
+    (
+        "builder.db.default_hint",
+        "default is used when target_db is not set.",
+    ),
+    ("builder.db.shared", "Shared connection"),
+    ("builder.db.inline", "(enter directly)"),
+    (
+        "builder.db.uses_shared",
+        "Uses the '{name}' connection from connections.yaml.",
+    ),
+    (
+        "builder.db.shared_missing",
+        "The shared store has no '{name}' connection.",
+    ),
+    ("builder.db.kind", "Kind"),
+    ("builder.db.unsupported", "(unsupported)"),
+    ("builder.db.dsn", "DSN / connection string"),
+    ("builder.db.user", "User"),
+    ("builder.db.password", "Password"),
+    ("builder.db.export", "Export to shared store"),
+    ("builder.db.add", "Add DB connection"),
+    ("builder.db.store_title", "📚 Shared connection store"),
+    (
+        "builder.db.store_hint",
+        "Stored in connections.yaml and referenced by scenarios via db_ref.",
+    ),
+    (
+        "builder.db.store_empty",
+        "No shared connections. Export one from a DB entry above.",
+    ),
+    ("builder.db.import", "Import"),
+    ("builder.loop.title", "Loop settings"),
+    ("builder.loop.as_var", "as variable name"),
+    ("builder.loop.on_failure", "On failure"),
+    ("builder.loop.children", "Child steps"),
+    ("builder.loop.add", "Add"),
+    ("builder.loop.delete_selected", "Delete selected step"),
+    ("builder.loop.selected_child", "Selected child step"),
+    ("builder.layout.canvas", "🗺 Canvas"),
+    ("builder.layout.list", "📋 List"),
+    ("builder.layout.stage_colors", "🎨 Color by stage"),
+    ("builder.layout.critical_path", "⏱ Critical path"),
+    (
+        "builder.layout.critical_path_hover",
+        "Highlights the longest path using average durations from run history.",
+    ),
+    ("builder.layout.order_sim", "🔢 Run order simulation"),
+    (
+        "builder.layout.order_sim_hover",
+        "Replays on the canvas, without running anything, the order in which the engine starts steps according to allow_parallel and dependencies.",
+    ),
+    ("builder.layout.schema", "🗂 Schema browser"),
+    (
+        "builder.layout.schema_hover",
+        "Browse the tables and columns of the selected DB and drag names onto the SQL editor.",
+    ),
+    ("builder.layout.snap", "▦ Snap to grid"),
+    ("builder.layout.guides", "📐 Alignment guides"),
+    (
+        "builder.layout.guides_hover",
+        "Aligns the dragged node to the edges and centers of other nodes and shows guide lines.",
+    ),
+    (
+        "builder.layout.distribute_hint",
+        "Select three or more nodes with Shift+click to space them evenly between the outermost nodes.",
+    ),
+    ("builder.layout.distribute_h", "↔ Distribute horizontally"),
+    ("builder.layout.distribute_v", "↕ Distribute vertically"),
+    ("builder.layout.up", "⬅ Parent flow"),
+    (
+        "builder.layout.up_hover",
+        "Applies the child steps to the loop and returns to the parent flow.",
+    ),
+    ("builder.layout.editing_loop", "Editing loop child steps"),
+    ("builder.layout.close_notice", "Dismiss notice"),
+    ("builder.props.memoize", "Reuse results of identical config"),
+    ("builder.cfg.sql_file_path", "SQL file path"),
+    ("builder.cfg.gate_message", "Approval message"),
+    (
+        "builder.cfg.gate_message_hint",
+        "Describe what to check before approving.",
+    ),
+    ("builder.cfg.verify_method", "Method (method)"),
+    ("builder.cfg.expected_source", "Expected value source"),
+    ("builder.cfg.expected_value", "Value/variable"),
+    ("builder.cfg.expected_file", "Expected value file"),
+    ("builder.cfg.sidecar", "Sidecar file"),
+    (
+        "builder.cfg.expected_file_hint",
+        "e.g. /data/in/sales.dat.md5",
+    ),
+    (
+        "builder.cfg.sidecar_hint",
+        "Compares with the first word of {sidecar}.",
+    ),
+    (
+        "builder.cfg.wait_scenario",
+        "Scenario to wait for (scenario)",
+    ),
+    ("builder.cfg.wait_scenario_hint", "e.g. upstream_daily_load"),
+    (
+        "builder.cfg.wait_since",
+        "Since (since) — only successful runs finished after this time count",
+    ),
+    ("builder.cfg.poll_sec", "Poll interval (poll_sec)"),
+    ("builder.cfg.max_wait", "Limit wait time (max_wait_sec)"),
+    ("builder.cfg.wait_seconds_mode", "Wait for seconds"),
+    ("builder.cfg.wait_until_mode", "Until a time"),
+    ("builder.cfg.until_time", "Wait until (until_time)"),
+    ("builder.cfg.until_time_hint", "HH:MM or HH:MM:SS"),
+    ("builder.cfg.wait_seconds", "Wait time (seconds)"),
+    ("builder.cfg.target_db", "DB target (target_db)"),
+    ("builder.cfg.default_db", "(use default DB)"),
+    ("builder.cfg.guard", "Safeguards"),
+    ("builder.cfg.statement_timeout", "Statement timeout"),
+    ("builder.cfg.suffix_seconds", " s"),
+    ("builder.cfg.max_affected_rows", "Max affected rows"),
+    ("builder.cfg.suffix_count", ""),
+    (
+        "builder.cfg.max_affected_rows_hint",
+        "Max affected rows applies only to single DML statements; exceeding it rolls back and fails.",
+    ),
+    ("builder.cfg.control_file", "control file"),
+    ("builder.cfg.data_file", "data file"),
+    ("builder.cfg.log_file", "log file"),
+    ("builder.cfg.bad_file", "bad file"),
+    ("builder.cfg.discard_file", "discard file"),
+    ("builder.cfg.conn", "Connection string"),
+    (
+        "builder.cfg.conn_hint",
+        "Leave empty to use the SQLLDR_CONN environment variable.",
+    ),
+    ("builder.cfg.sqlldr_options", "sqlldr options"),
+    ("builder.cfg.suffix_rows", " rows"),
+    ("builder.cfg.suffix_bytes", " bytes"),
+    (
+        "builder.cfg.extra_args",
+        "Extra arguments (one per line, key=value)",
+    ),
+    ("builder.cfg.remote", "Remote host (remotes key)"),
+    ("builder.cfg.remote_hint", "Leave empty to run locally."),
+    ("builder.cfg.priority", "Process priority"),
+    (
+        "builder.cfg.nice_hover",
+        "Higher values yield more CPU to other processes. Negative values need administrator rights.",
+    ),
+    ("builder.cfg.io_class", "I/O class"),
+    ("builder.cfg.unset", "Not set"),
+    ("builder.cfg.io_level", "I/O priority"),
+    ("builder.cfg.io_level_hover", "0 is served first, 7 last."),
+    ("builder.cfg.cpus", "CPU numbers (comma-separated)"),
+    (
+        "builder.cfg.priority_hint",
+        "On Linux this uses nice, ionice and taskset; on Windows nice is applied as a priority class.",
+    ),
+    ("builder.cfg.script", "Script"),
+    ("builder.cfg.shell_program", "Shell program"),
+    ("builder.cfg.shell_args", "Arguments (comma-separated)"),
+    ("builder.cfg.working_dir", "Working directory"),
+    ("builder.cfg.run_as", "Run as user"),
+    (
+        "builder.cfg.inherit_env",
+        "Inherit the GUI process environment",
+    ),
+    (
+        "builder.cfg.inherit_env_hover",
+        "When off, runs in an empty environment with only the variables below.",
+    ),
+    (
+        "builder.cfg.env",
+        "Environment variables (one KEY=VALUE per line)",
+    ),
+    ("builder.cfg.line_prefix", "Line "),
+    ("builder.cfg.pattern", "Regex pattern"),
+    ("builder.cfg.group_prefix", "Capture group "),
+    ("builder.cfg.var_name", "Variable name to store"),
+    (
+        "builder.cfg.secret",
+        "Secret value (masked as **** in logs and on screen)",
+    ),
+    ("builder.cfg.verify_file", "File to verify (file)"),
+    (
+        "builder.cfg.expected_hint",
+        "Checksum/line count or ${VAR_NAME}",
+    ),
+    (
+        "builder.cfg.wait_since_hint",
+        "Empty means today 00:00 · e.g. ${RUN_DATE}, 2024-01-01 02:00, 06:00",
+    ),
+    (
+        "builder.cfg.extra_args_hint",
+        "e.g. parallel=true, readsize=${READSIZE}. Appended after the options as is.",
+    ),
+    ("builder.cfg.file_path", "File path"),
+    (
+        "crypto.error.passphrase_required",
+        "This scenario is encrypted. A passphrase is required.",
+    ),
+    (
+        "crypto.error.wrong_passphrase",
+        "The passphrase is wrong or the file is corrupted.",
+    ),
+    (
+        "crypto.error.invalid_format",
+        "Not an encrypted scenario file.",
+    ),
+    (
+        "crypto.error.encrypt_failed",
+        "Failed to encrypt the scenario",
+    ),
+    (
+        "history.stats_summary",
+        "min {min} · avg {avg} · max {max} · success {rate}% ({runs} runs)",
+    ),
+    ("history.duration_hours", "{hours}h {minutes}m"),
+    ("history.duration_minutes", "{minutes}m {seconds}s"),
+    ("history.duration_seconds", "{seconds}s"),
+    (
+        "import.todo.db_connection",
+        "[db.{key}] Fill in the connection details (dsn, user, password).",
+    ),
+    ("import.error.read", "Failed to read workflow file: {path}"),
+    (
+        "import.error.no_tasks",
+        "No tasks to import were found: {path}",
+    ),
+    (
+        "import.todo.not_literal",
+        "{arg} is not a string literal, so it was not imported.",
+    ),
+    (
+        "import.todo.unsupported_operator",
+        "Unsupported operator {operator}. Port it manually.",
+    ),
+    (
+        "import.todo.decision",
+        "[{name}] decision conditions were not imported; all branches are connected to run.",
+    ),
+    (
+        "import.todo.error_transition",
+        "[{name}] The error transition to {error} was not imported. Configure it with on_failure.",
+    ),
+    (
+        "import.todo.unsupported_action",
+        "Unsupported Oozie action {action}. Port it manually.",
+    ),
+    (
+        "import.todo.no_command",
+        "The {action} action has no command to run.",
+    ),
+    ("import.todo.placeholder", "port the {id} task."),
+    (
+        "connections.error.missing_shared",
+        "Shared connection '{name}' referenced by DB '{key}' does not exist.",
+    ),
+    (
+        "connections.error.nested_ref",
+        "Shared connection '{name}' cannot reference another connection.",
+    ),
+    (
+        "connections.error.read",
+        "Failed to read the shared connection store: {path}",
+    ),
+    (
+        "connections.error.parse",
+        "Failed to parse the shared connection store: {path}",
+    ),
+    (
+        "connections.error.save",
+        "Failed to save the shared connection store: {path}",
+    ),
+    ("approval.error.no_approver", "Enter the approver name."),
+    (
+        "approval.error.self_approval",
+        "The requester cannot approve their own request. Another user must approve it.",
+    ),
+    (
+        "approval.error.scenario_changed",
+        "The scenario changed after the approval request. Request the run again.",
+    ),
+    (
+        "approval.error.bad_code",
+        "The approver name or approval code is incorrect.",
+    ),
+    (
+        "approval.error.open_log",
+        "Cannot open the approval log file: {path}",
+    ),
+    (
+        "approval.error.read_approvers",
+        "Cannot read the approvers file: {path}",
+    ),
+    (
+        "approval.error.approvers_writable",
+        "The approvers file is writable by other users, so it is not used: {path}",
+    ),
+    ("settings.error.read", "Failed to read app settings: {path}"),
+    (
+        "settings.error.parse",
+        "Failed to parse app settings: {path}",
+    ),
+    ("settings.error.save", "Failed to save app settings: {path}"),
+    (
+        "expanded_yaml.error.save",
+        "Failed to save the expanded YAML: {path}",
+    ),
+    ("notify.error.http_only", "Only http:// URLs are supported."),
+    ("notify.error.connect", "Connection failed: {addr}"),
+    (
+        "notify.error.bad_response",
+        "Cannot parse the response: {line}",
+    ),
+    ("notify.error.status", "Response status {status}"),
+    (
+        "daemon.error.read_token",
+        "Cannot read the daemon token file ({path}): {error}",
+    ),
+    (
+        "daemon.error.empty_token",
+        "The daemon token file is empty: {path}",
+    ),
+    (
+        "daemon.error.resolve",
+        "Cannot resolve the daemon address: {addr}",
+    ),
+    (
+        "daemon.error.not_loopback",
+        "The daemon can only listen on loopback addresses (127.0.0.1, ::1): {addr} → {remote}",
+    ),
+    ("daemon.client.closed", "The daemon closed the connection."),
+    (
+        "daemon.client.connect_failed",
+        "Cannot connect to the daemon ({addr}): {error}",
+    ),
+    (
+        "daemon.client.not_connected",
+        "Not connected to the daemon, so the request was not sent.",
+    ),
+    ("daemon.error.bad_token", "The daemon token does not match."),
+    ("daemon.error.bad_request", "Invalid request: {error}"),
+    (
+        "daemon.error.already_running",
+        "A scenario is already running.",
+    ),
+    (
+        "daemon.error.unknown_confirm",
+        "The confirm request was already handled or does not exist: {id}",
+    ),
+    (
+        "trigger.error.no_trigger",
+        "The scenario has no trigger settings.",
+    ),
+    (
+        "trigger.error.bad_pattern",
+        "Invalid trigger.pattern regex: {pattern}",
+    ),
+    (
+        "service.error.unknown_command",
+        "Unknown service command: {command} (one of install, uninstall, start)",
+    ),
+    (
+        "service.error.privileged_account",
+        "The service cannot be registered with the {account} account. Pass a dedicated low-privilege account with --account.",
+    ),
+    (
+        "service.error.windows_only",
+        "Service mode is only available on Windows. On other systems, run --daemon under systemd or similar.",
+    ),
+    (
+        "service.error.manager",
+        "Failed to connect to the service manager (run as administrator)",
+    ),
+    ("service.error.create", "Failed to register the service"),
+    (
+        "service.installed",
+        "Service account: {account}, token file: {token_file}",
+    ),
+    (
+        "service.error.not_found",
+        "The registered service was not found",
+    ),
+    ("service.error.stop", "Failed to stop the service"),
+    ("service.error.delete", "Failed to delete the service"),
+    ("service.error.start", "Failed to start the service"),
+    (
+        "service.error.dispatcher",
+        "Failed to connect to the service control manager (--service run only works as a service)",
+    ),
+    (
+        "executor.dummy.execute",
+        "[DummyExecutor] Execute SQL: {sql}",
+    ),
+    ("executor.dummy.query", "[DummyExecutor] Query SQL: {sql}"),
+    (
+        "executor.dummy.preview",
+        "[DummyExecutor] Preview SQL (up to {max_rows} rows): {sql}",
+    ),
+    (
+        "executor.dummy.columns",
+        "[DummyExecutor] List columns: {table}",
+    ),
+    ("search.running", "Searching…"),
+    ("search.aborted", "The search task was aborted."),
+    (
+        "builder.included.remove",
+        "🗑 Remove include ({count} steps)",
+    ),
+    (
+        "builder.included.remove_hover",
+        "Removes every step expanded from this fragment so the include entry is dropped on save. Removed steps can be restored from the trash as regular steps.",
+    ),
+    (
+        "builder.paste.skipped_db",
+        "Could not bring over DB connections the builder cannot edit. Add them to the scenario YAML directly: {reasons}",
+    ),
+    (
+        "engine.error.priority_tool_missing",
+        "Cannot find the `{tool}` command on PATH to apply priority. Install util-linux (taskset, ionice) or coreutils (nice), or remove the priority setting.",
+    ),
+    (
+        "engine.error.sqlldr_stdin",
+        "Failed to pass the connect string to sqlldr",
+    ),
+    (
+        "dashboard.aborted",
+        "Run history statistics calculation was aborted.",
+    ),
+    ("dashboard.loading", "Reading run history..."),
+    ("service.display_name", "Rust Airflow engine daemon"),
+    (
+        "service.description",
+        "Engine daemon that watches triggers and runs batches even when no user is logged on.",
+    ),
+    (
+        "service.error.no_launch",
+        "Service configuration is missing",
+    ),
+    (
+        "service.error.runtime",
+        "Failed to create the Tokio runtime",
+    ),
+    (
+        "scenario.error.profile_missing",
+        "The scenario has no `{name}` execution profile",
+    ),
+    (
+        "scenario.error.profile_requires_approval",
+        "The `{name}` execution profile requires approval, so it cannot run headless. Run it from the GUI with approval",
+    ),
+];
@@ -0,0 +1,2111 @@
+/// 한국어 메시지 카탈로그이다. 모든 키의 기준 문구이다.
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    ("status.pending", "대기 중"),
+    ("status.waiting", "시간 대기"),
+    ("status.running", "실행 중"),
+    ("status.retrying", "재시도"),
+    ("status.success", "성공"),
+    ("status.failed", "실패"),
+    ("status.blocked", "건너뜀"),
+    ("status.awaiting_approval", "승인 대기"),
+    ("status.retry_in", "{secs}초 후"),
+    ("status.retry_progress", "재시도 {attempt}/{max} · {phase}"),
+    ("toolbar.loaded", "{lock}로드됨 · {path}"),
+    (
+        "toolbar.pick_scenario",
+        "시나리오 파일을 선택해 시작하세요.",
+    ),
+    ("toolbar.daemon_connected", "🔗 데몬 연결됨 · {addr}"),
+    ("toolbar.daemon_waiting", "⛓ 데몬 접속 대기 · {addr}"),
+    ("toolbar.open", "열기"),
+    ("toolbar.run", "실행"),
+    ("toolbar.stop", "정지"),
+    ("toolbar.new", "새 시나리오"),
+    ("toolbar.open_ellipsis", "열기..."),
+    ("toolbar.import", "가져오기..."),
+    (
+        "toolbar.import_hint",
+        "Airflow DAG(.py)나 Oozie 워크플로(.xml)에서 시나리오 골격을 만듭니다.",
+    ),
+    ("toolbar.save", "저장"),
+    ("toolbar.save_as", "다른 이름으로"),
+    ("toolbar.check_sql", "SQL 검사"),
+    ("toolbar.modified_suffix", " (수정됨)"),
+    ("toolbar.file", "{lock}파일 · {path}{dirty}"),
+    ("toolbar.modified_tag", " · 수정됨"),
+    ("toolbar.new_scenario", "새 시나리오{dirty}"),
+    ("confirm.window", "Step 실행 확인"),
+    ("confirm.title", "사용자 컨펌이 필요합니다"),
+    ("confirm.phase_before", "실행 전"),
+    ("confirm.phase_after", "실행 후"),
+    ("confirm.summary", "요약"),
+    ("common.yes", "예"),
+    ("common.no", "아니오"),
+    ("confirm.accept", "예 · 계속"),
+    ("confirm.reject", "아니오 · 중단"),
+    ("passphrase.window", "암호 문구 입력"),
+    ("passphrase.title", "🔒 암호화된 시나리오"),
+    ("approval.window", "실행 승인"),
+    ("approval.title", "🔒 실행 승인 필요"),
+    ("approval.scenario", "시나리오"),
+    ("approval.profile", "프로필"),
+    ("approval.requester", "요청자"),
+    ("approval.requested_at", "요청 시각"),
+    ("approval.id", "승인 ID"),
+    (
+        "approval.hint_code",
+        "요청자가 아닌 승인자가 이름과 자신의 승인 코드를 입력해야 실행이 시작됩니다.",
+    ),
+    ("approval.approver", "승인자"),
+    ("approval.code", "승인 코드"),
+    ("approval.approve", "승인 후 실행"),
+    ("approval.reject", "거절"),
+    ("approval.withdraw", "요청 취소"),
+    ("exit.window", "종료 확인"),
+    ("exit.title", "⚠ 실행 중인 시나리오가 있습니다"),
+    (
+        "exit.message",
+        "창을 닫기 전에 실행 중인 시나리오를 어떻게 할지 고르세요.",
+    ),
+    ("exit.cancel_wait", "실행 취소 후 종료"),
+    (
+        "exit.cancel_wait_hint",
+        "실행을 취소하고 자식 프로세스를 멈춘 뒤, 실행 이력과 로그 파일 기록이 끝나면 종료합니다.",
+    ),
+    ("exit.detach", "데몬에 맡기고 종료"),
+    (
+        "exit.detach_hint",
+        "실행은 데몬 프로세스에서 계속됩니다. 다시 --attach로 접속해 이어서 볼 수 있습니다.",
+    ),
+    (
+        "exit.detach_unavailable",
+        "데몬 모드(--attach)로 실행한 경우에만 실행을 맡길 수 있습니다.",
+    ),
+    ("exit.force", "강제 종료"),
+    (
+        "exit.force_hint",
+        "기다리지 않고 바로 종료합니다. 실행 중인 프로세스는 종료되며 이번 실행의 이력이 남지 않을 수 있습니다.",
+    ),
+    ("exit.keep_running", "계속 실행 (닫지 않음)"),
+    (
+        "exit.draining",
+        "실행을 취소하고 기록을 마치는 중입니다… {secs}초",
+    ),
+    ("parse_error.window", "시나리오 형식 오류"),
+    ("parse_error.title", "⚠ 시나리오 파일을 읽을 수 없습니다"),
+    ("parse_error.file", "파일"),
+    ("parse_error.location", "위치(줄:열)"),
+    ("parse_error.step", "Step"),
+    ("parse_error.hint", "💡 힌트: {hint}"),
+    ("parse_error.reload", "다시 불러오기"),
+    ("reload.title", "📝 {file} 파일이 변경되었습니다"),
+    (
+        "reload.message",
+        "다른 곳(git pull 등)에서 시나리오 파일이 수정되었습니다. 다시 불러오면 노드 위치는 ID가 같은 Step끼리 유지됩니다.",
+    ),
+    (
+        "reload.dirty_warning",
+        "빌더에 저장하지 않은 변경이 있습니다. 다시 불러오면 사라집니다.",
+    ),
+    ("reload.reload", "다시 불러오기"),
+    ("reload.keep", "현재 내용 유지"),
+    ("reload.running", "실행 중에는 다시 불러올 수 없습니다."),
+    ("reload.failed", "시나리오 다시 불러오기 실패: {error}"),
+    ("common.ok", "확인"),
+    ("common.cancel", "취소"),
+    ("common.save", "저장"),
+    ("confirm.kind_phase", "종류: {kind} · 단계: {phase}"),
+    ("confirm.default_answer", "기본 응답: {answer}"),
+    ("tab.run", "실행"),
+    ("tab.dashboard", "대시보드"),
+    ("dashboard.title", "📊 실행 이력 통계"),
+    ("dashboard.all_scenarios", "전체 시나리오"),
+    ("dashboard.period", "최근 {days}일"),
+    ("dashboard.refresh", "🔄 새로고침"),
+    ("dashboard.copy", "📋 일별 표 복사"),
+    (
+        "dashboard.copy_hint",
+        "날짜별 실행 수·성공 수·성공률·평균 소요 시간을 스프레드시트에 붙여 넣을 수 있는 표로 복사합니다.",
+    ),
+    ("dashboard.empty", "이 기간에 기록된 실행 이력이 없습니다."),
+    ("dashboard.total_runs", "실행 수"),
+    ("dashboard.success_rate", "성공률"),
+    ("dashboard.avg_duration", "평균 소요 시간"),
+    ("dashboard.runs_per_day", "일별 실행 수"),
+    ("dashboard.success_trend", "일별 성공률"),
+    ("dashboard.duration_trend", "일별 평균 소요 시간"),
+    ("dashboard.no_runs", "실행 없음"),
+    ("dashboard.failing_steps", "❌ 실패가 많은 Step"),
+    ("dashboard.no_failures", "이 기간에 실패한 Step이 없습니다."),
+    ("dashboard.longest_steps", "⏱ 오래 걸리는 Step"),
+    ("dashboard.step", "시나리오 / Step"),
+    ("dashboard.failures", "실패 / 실행"),
+    ("dashboard.failure_rate", "실패율"),
+    ("dashboard.avg", "평균"),
+    ("dashboard.max", "최대"),
+    ("dashboard.col_date", "날짜"),
+    ("dashboard.col_successes", "성공 수"),
+    ("dashboard.col_avg_secs", "평균 소요(초)"),
+    ("dashboard.col_total", "합계"),
+    ("dashboard.runs", "🗒 실행 목록"),
+    ("dashboard.search_hint", "메모·태그·시나리오 검색"),
+    (
+        "dashboard.no_matching_runs",
+        "검색어와 맞는 실행이 없습니다.",
+    ),
+    ("dashboard.col_finished", "종료 시각"),
+    ("dashboard.col_scenario", "시나리오"),
+    ("dashboard.col_result", "결과"),
+    ("dashboard.col_duration", "소요 시간"),
+    ("dashboard.col_note", "태그 · 메모"),
+    ("dashboard.run_success", "성공"),
+    ("dashboard.run_failed", "실패"),
+    ("dashboard.edit_note", "메모·태그 편집"),
+    (
+        "dashboard.more_runs",
+        "외 {count}건은 표시하지 않았습니다. 검색어나 기간으로 좁혀 보세요.",
+    ),
+    ("dashboard.note_for", "{scenario} · 실행 {run} 메모"),
+    ("settings.menu", "⚙ 설정"),
+    ("search.menu", "🔎 모든 시나리오에서 찾기"),
+    ("search.window", "모든 시나리오에서 찾기"),
+    ("search.dir", "검색 폴더: {dir} (설정에서 바꿀 수 있습니다)"),
+    ("search.hint", "테이블 이름, Step 이름, 경로, 변수 이름"),
+    ("search.run", "찾기"),
+    (
+        "search.summary",
+        "시나리오 {files}개에서 {hits}건을 찾았습니다.",
+    ),
+    ("search.open", "열기"),
+    ("search.skipped", "읽지 못한 파일·폴더 {count}개"),
+    ("expanded.window", "펼친 YAML"),
+    (
+        "expanded.note",
+        "실행하면 엔진이 볼 시나리오입니다. 비밀 값은 ****로 가렸고, 실행 중에 정해지는 Step 결과·DB 연결 정보·작업 디렉터리 변수는 그대로 둡니다.",
+    ),
+    ("expanded.copy", "📋 복사"),
+    ("expanded.save", "💾 저장..."),
+    ("expanded.save_failed", "펼친 YAML 저장 실패: {error}"),
+    (
+        "expanded.header",
+        "# 플레이스홀더를 실행 파라미터로 치환한 시나리오입니다. 비밀 값은 ****로 가렸습니다.",
+    ),
+    (
+        "search.dir_failed",
+        "시나리오 폴더 {dir}을(를) 읽지 못했습니다: {error}",
+    ),
+    ("search.field_step", "Step"),
+    ("search.field_sql", "SQL"),
+    ("search.field_script", "스크립트"),
+    ("search.field_path", "경로"),
+    ("search.field_variable", "변수"),
+    ("settings.runtime_title", "엔진 런타임"),
+    ("settings.workers", "워커 스레드"),
+    ("settings.blocking_threads", "블로킹 스레드 상한"),
+    ("settings.auto", "자동"),
+    (
+        "settings.runtime_current",
+        "적용됨: 워커 {workers}, 블로킹 {blocking}",
+    ),
+    ("settings.apply", "적용"),
+    ("settings.runtime_running", "실행 중에는 바꿀 수 없습니다."),
+    (
+        "settings.runtime_daemon",
+        "데몬 모드에서는 데몬 프로세스의 실행 옵션을 따릅니다.",
+    ),
+    ("settings.runtime_failed", "엔진 런타임 생성 실패: {error}"),
+    ("settings.window", "앱 설정"),
+    (
+        "settings.file_hint",
+        "저장 위치: {path} · 명령줄 인자와 환경 변수가 우선합니다.",
+    ),
+    ("settings.language", "언어"),
+    ("settings.language_default", "기본값 (환경 변수)"),
+    ("settings.theme", "테마"),
+    ("settings.theme_light", "라이트"),
+    ("settings.theme_dark", "다크"),
+    ("settings.ui_scale", "UI 배율"),
+    ("settings.log_dir", "로그 디렉터리"),
+    ("settings.scenario_dir", "시나리오 폴더"),
+    ("settings.history_keep_days", "실행 이력 보존 일수"),
+    ("settings.history_keep_all", "모두 보존"),
+    ("settings.notify", "실행 종료 알림"),
+    (
+        "settings.notify_hint",
+        "실행이 끝나면 결과를 JSON으로 POST할 http:// 주소입니다.",
+    ),
+    ("settings.notify_add", "➕ 주소 추가"),
+    ("settings.save", "저장"),
+    ("settings.save_failed", "앱 설정 저장 실패: {error}"),
+    ("progress.title", "📈 전체 진행률"),
+    (
+        "progress.with_eta",
+        "진행률: {percent}% · 예상 남은 시간 {eta}",
+    ),
+    ("progress.percent", "진행률: {percent}%"),
+    ("progress.succeeded", "✅ 성공"),
+    ("progress.failed", "❌ 실패"),
+    ("progress.blocked", "⛔ 건너뜀"),
+    ("progress.remaining", "⏳ 남음"),
+    ("progress.lanes", "⚙ 동시 실행 {count}"),
+    (
+        "progress.lanes_idle",
+        "실행 중인 Step 없음 — 시간 창, 승인, 재시도 간격을 기다리는 중",
+    ),
+    ("panel.steps", "작업 단계"),
+    (
+        "panel.keyboard_hint",
+        "⌨ ↑↓ 이동 · Enter 상세 · F5 실행 · Shift+F5 정지 · A 승인",
+    ),
+    ("panel.slow_card", "느림 · 평균 {avg} 초과"),
+    ("panel.overrun_card", "제한 시간 초과 · {elapsed} 경과"),
+    ("panel.iterations", "반복 {done}/{total}"),
+    ("panel.no_scenario", "시나리오를 먼저 불러오세요."),
+    ("panel.cleanup_steps", "실패 시 정리"),
+    ("panel.stage_header", "{stage} · {done}/{total}"),
+    ("panel.no_stage", "스테이지 없음"),
+    ("panel.gate_waiting", "✋ {step} 승인 대기 중"),
+    ("panel.gate_approve", "승인"),
+    ("panel.step_cancel_fail", "실패로 취소"),
+    (
+        "panel.step_cancel_fail_hint",
+        "이 Step만 중단하고 실패로 처리합니다. 이 Step에 의존하는 Step은 건너뜁니다.",
+    ),
+    ("panel.step_cancel_skip", "건너뛰기"),
+    (
+        "panel.step_cancel_skip_hint",
+        "이 Step만 중단하고 성공으로 처리해 나머지 흐름을 계속 실행합니다.",
+    ),
+    ("rehearsal.title", "리허설"),
+    (
+        "rehearsal.hint",
+        "이 설정은 시나리오 파일에 저장되지 않으며, 리허설 실행은 실행 이력에 남지 않습니다.",
+    ),
+    (
+        "rehearsal.simulate_others",
+        "나머지 Step은 실제로 실행하지 않고 성공 처리",
+    ),
+    ("rehearsal.step", "Step"),
+    ("rehearsal.fail", "실패"),
+    ("rehearsal.delay", "지연"),
+    ("rehearsal.sec_suffix", "초"),
+    ("detail.title", "Step 정보"),
+    ("detail.status", "상태 · {status}"),
+    ("detail.waiting_until", "{until}까지 대기"),
+    ("detail.slow", "⚠ 평균 {avg}보다 오래 걸리는 중"),
+    (
+        "detail.overrun",
+        "⏰ 제한 시간을 넘겨 계속 대기 중 ({elapsed} 경과)",
+    ),
+    ("detail.approved", "{approver}님이 {at}에 승인"),
+    ("detail.parallel", "병렬 허용"),
+    ("detail.retry", "재시도"),
+    ("detail.retry_value", ": {count}회"),
+    ("detail.retry_transient", "{value} · 일시적 오류만"),
+    ("detail.retry_any", "{value} · 모든 오류"),
+    ("detail.timeout", "타임아웃"),
+    ("detail.timeout_value", ": {secs}초"),
+    (
+        "detail.timeout_warn_continue",
+        "{value} · 초과 시 경고 후 계속 대기",
+    ),
+    ("detail.timeout_fail", "{value} · 초과 시 재시도 없이 실패"),
+    ("detail.past_duration", "과거 소요 시간"),
+    (
+        "detail.duration_value",
+        ": 최소 {min} · 평균 {avg} · 최대 {max}",
+    ),
+    ("detail.success_rate", "성공률"),
+    (
+        "detail.success_rate_value",
+        ": {percent}% ({successes}/{runs}회)",
+    ),
+    ("detail.time_window", "실행 시간 창"),
+    ("detail.skip_if", "건너뛰기 조건"),
+    ("detail.skip_if_file", "파일 {path} 있음"),
+    ("detail.skip_if_sql", "조회 결과 행 있음"),
+    ("detail.sqlldr_title", "SQL*Loader 행 수"),
+    (
+        "detail.sqlldr_empty",
+        "아직 출력에서 읽은 행 수가 없습니다.",
+    ),
+    ("detail.sqlldr_read", "읽은 레코드"),
+    ("detail.sqlldr_rejected", "거부된 레코드"),
+    ("detail.sqlldr_discarded", "버린 레코드"),
+    ("detail.sqlldr_loaded", "{table} 적재 행"),
+    ("detail.window_wait", "대기"),
+    ("detail.window_fail", "실패"),
+    ("detail.depends_on", "의존성"),
+    ("detail.none_selected", "선택된 Step이 없습니다."),
+    ("detail.db_error", "DB 오류 · {code}"),
+    ("detail.knowledge_base", "지식베이스"),
+    ("detail.context", "실패 시점 컨텍스트 · {count}개"),
+    ("detail.context_empty", "저장된 변수가 없습니다."),
+    ("detail.set_vars", "이 Step이 설정한 변수 · {count}개"),
+    ("detail.outputs", "기대 산출물 · {count}개"),
+    ("detail.output_missing", "✗ {path} (없음)"),
+    ("detail.output_empty", "✗ {path} (빈 파일)"),
+    ("detail.diagnostics", "실패 진단 ({count})"),
+    ("detail.diagnostic_failed", "진단 실패: {error}"),
+    ("detail.diagnostic_empty", "출력 없음"),
+    ("detail.preview", "실행 구성 미리보기"),
+    ("preview.target_db", "대상 DB"),
+    ("preview.sql", "SQL"),
+    ("preview.sql_file", "SQL 파일"),
+    ("preview.remote", "원격 호스트"),
+    ("preview.control_file", "컨트롤 파일"),
+    ("preview.data_file", "데이터 파일"),
+    ("preview.log_file", "로그 파일"),
+    ("preview.bad_file", "BAD 파일"),
+    ("preview.discard_file", "DISCARD 파일"),
+    ("preview.command", "명령줄"),
+    ("preview.script", "스크립트"),
+    ("preview.working_dir", "작업 디렉터리"),
+    ("preview.run_as", "실행 사용자"),
+    ("preview.env", "환경 변수"),
+    ("preview.extract_file", "대상 파일"),
+    ("preview.extract_rule", "추출 규칙"),
+    (
+        "preview.extract_rule_value",
+        "{line}번째 줄 · /{pattern}/ · 그룹 {group} → ${{var}}",
+    ),
+    ("preview.glob", "glob 패턴"),
+    ("preview.glob_matches", "현재 일치 항목 수"),
+    ("preview.loop_body", "반복 변수 · 하위 Step"),
+    ("preview.loop_body_value", "${{var}} · 하위 Step {count}개"),
+    ("preview.gate_message", "승인 안내 메시지"),
+    ("preview.wait_seconds", "대기 시간"),
+    ("preview.wait_seconds_value", "{secs}초"),
+    ("preview.wait_until", "대기 종료 시각"),
+    ("preview.wait_run_scenario", "기다릴 시나리오"),
+    ("preview.wait_run_since", "기준 시각"),
+    ("preview.verify_file", "검증 파일"),
+    ("preview.verify_method", "계산 방식"),
+    ("preview.verify_expected", "기대값"),
+    ("preview.verify_expected_file", "기대값 파일"),
+    ("preview.exists", "파일이 있습니다."),
+    ("preview.missing", "파일을 찾을 수 없습니다."),
+    ("panel.logs", "로그"),
+    ("panel.log_filter_hint", "로그 필터 (포함할 글자)"),
+    ("failure.title", "🧯 실패 원인 요약"),
+    ("failure.root_cause", "근본 원인 · {name} ({id})"),
+    ("failure.blocked_chain", "이 실패로 건너뛴 Step · {chain}"),
+    ("failure.recent_logs", "최근 로그 {count}줄"),
+    ("failure.retry", "🔁 실패 지점부터 재시도"),
+    ("failure.open_logs", "📝 로그 열기"),
+    ("summary.title", "📊 결과 요약"),
+    ("summary.received_at", "{time} 조회"),
+    ("summary.no_rows", "(결과 없음)"),
+    ("summary.failed", "조회 실패 · {error}"),
+    ("summary.report_header", "{name} 결과 요약 ({time})"),
+    ("pool.title", "🔌 DB 세션 사용 현황"),
+    ("pool.db_key", "DB"),
+    ("pool.in_use", "사용 중 / 최대"),
+    ("pool.idle", "유휴"),
+    ("pool.waiting", "대기"),
+    ("pool.avg_checkout", "평균 획득 시간"),
+    ("journal.title", "⚠ 이전 실행이 끝나지 못했습니다"),
+    (
+        "journal.summary",
+        "{name} · {started_at} 시작 · 성공한 Step {count}개",
+    ),
+    (
+        "journal.message",
+        "앱이 실행 도중 종료되었습니다. 복원하면 성공한 Step을 유지한 채 실패 지점부터 재시도할 수 있습니다.",
+    ),
+    ("journal.restore", "♻ 실행 상태 복원"),
+    ("journal.dismiss", "무시"),
+    ("journal.interrupted_step", "앱 종료로 중단됨"),
+    ("common.close", "닫기"),
+    (
+        "sql_check.all_passed",
+        "✅ 모든 SQL Step이 검사를 통과했습니다.",
+    ),
+    ("cost_check.title", "💸 비용 점검"),
+    (
+        "cost_check.hint",
+        "실행을 막지 않는 권고입니다. 확인한 경고는 저장 후 실행 시작 때 다시 묻지 않습니다.",
+    ),
+    ("cost_check.acknowledge", "확인"),
+    ("cost_check.unacknowledge", "확인 취소"),
+    (
+        "cost_check.full_table_scan",
+        "큰 테이블 {table}을(를) WHERE 없이 읽거나 바꿉니다.",
+    ),
+    (
+        "cost_check.conventional_load",
+        "{size}MB 데이터 파일을 direct path 없이 적재합니다: {path}",
+    ),
+    (
+        "cost_check.large_glob",
+        "Loop 패턴이 {limit}개보다 많은 파일과 일치합니다: {pattern}",
+    ),
+    ("cost_check.window", "실행 비용 경고"),
+    ("cost_check.prompt_title", "💸 비용이 큰 작업이 있습니다"),
+    (
+        "cost_check.prompt_hint",
+        "빌더의 검사 패널에서 경고를 확인하고 저장하면 다음부터 묻지 않습니다.",
+    ),
+    ("cost_check.run_anyway", "그래도 실행"),
+    ("detail.no_code", "코드 없음"),
+    ("common.copy", "복사"),
+    ("common.none", "없음"),
+    ("app.daemon_disconnected", "데몬 연결 끊김: {reason}"),
+    ("app.confirm_accepted", "컨펌 승인"),
+    ("app.confirm_rejected", "컨펌 거부"),
+    (
+        "app.gate_not_waiting",
+        "승인을 기다리는 게이트가 아닙니다: {step}",
+    ),
+    ("app.step_not_running", "실행 중인 Step이 아닙니다: {step}"),
+    ("app.no_scenario", "시나리오가 로드되지 않았습니다."),
+    (
+        "app.run_ended_unexpectedly",
+        "엔진이 실행 종료 알림 없이 멈췄습니다. 로그를 확인하세요.",
+    ),
+    ("app.encrypted_filter", "암호화 YAML (*.yaml.enc)"),
+    ("app.import_filter", "Airflow DAG / Oozie 워크플로"),
+    ("app.import_done", "Step {steps}개를 가져왔습니다."),
+    (
+        "app.import_done_todos",
+        "Step {steps}개를 가져왔습니다. 직접 옮길 항목 {todos}개:",
+    ),
+    (
+        "app.contract_warning",
+        "저장했습니다. 변수 계약을 만족하지 않는 Step이 있어 이대로는 실행할 수 없습니다:\n{violations}",
+    ),
+    ("toolbar.reload_db", "DB 정의 적용"),
+    ("toolbar.rehearsal", "🎭 리허설 모드"),
+    (
+        "toolbar.rehearsal_hint",
+        "지정한 Step을 일부러 실패시키거나 지연시켜 복구 절차를 연습합니다.",
+    ),
+    ("toolbar.confirm_prompt", "💬 컨펌 묻기"),
+    ("toolbar.confirm_unattended", "🤖 무인 실행"),
+    ("toolbar.confirm_attended", "👀 참관 실행"),
+    (
+        "toolbar.confirm_mode_hint",
+        "컨펌 Step 응답 방식입니다. 무인 실행은 묻지 않고 각 Step의 기본 응답으로 진행하고, 참관 실행은 기본 응답이 있어도 반드시 응답을 받습니다.",
+    ),
+    ("toolbar.context_in", "📥 컨텍스트 가져오기"),
+    (
+        "toolbar.context_in_hint",
+        "이전 실행이 남긴 컨텍스트 JSON의 변수를 시나리오 파라미터로 넣고 시작합니다.",
+    ),
+    ("toolbar.context_out", "📤 컨텍스트 내보내기"),
+    (
+        "toolbar.context_out_hint",
+        "실행이 끝난 시점의 컨텍스트 변수를 JSON 파일로 남깁니다. 데몬 접속 중에는 쓸 수 없습니다.",
+    ),
+    ("toolbar.expanded_yaml", "📜 펼친 YAML"),
+    (
+        "toolbar.expanded_yaml_hint",
+        "플레이스홀더를 현재 파라미터·프로필 값으로 치환한 시나리오를 봅니다",
+    ),
+    ("toolbar.expanded_yaml_attach", "보고서에 첨부"),
+    (
+        "toolbar.expanded_yaml_attach_hint",
+        "실행을 시작하면 펼친 YAML을 실행 로그 디렉터리에 <시나리오>-<실행 ID>.expanded.yaml로 남깁니다",
+    ),
+    ("toolbar.run_note", "📝 실행 메모"),
+    ("toolbar.run_note_hint", "예: FX 환율 파일 수정 후 재실행"),
+    ("toolbar.run_tags", "🏷 태그"),
+    ("toolbar.run_tags_hint", "쉼표나 공백으로 구분"),
+    ("toolbar.profile", "프로필: {name}"),
+    ("toolbar.profile_none", "(없음)"),
+    (
+        "toolbar.profile_hint",
+        "실행 프로필의 파라미터로 시나리오 파라미터를 덮어씁니다. 🔒 프로필은 다른 사용자의 승인을 받아야 실행됩니다.",
+    ),
+    ("run_note.save_failed", "실행 메모 저장 실패: {error}"),
+    ("app.db_reloading", "DB 연결을 다시 구성하는 중입니다..."),
+    (
+        "app.db_reloaded",
+        "DB 정의를 적용했습니다. 다음 실행부터 새 연결을 사용합니다.",
+    ),
+    (
+        "app.db_reload_daemon",
+        "DB 정의를 적용했습니다. 데몬은 다음 실행을 시작할 때 연결을 다시 구성합니다.",
+    ),
+    ("app.db_reload_failed", "DB 연결 재구성 실패: {error}"),
+    (
+        "app.sql_preview_aborted",
+        "SQL 미리보기가 완료되지 않고 중단되었습니다.",
+    ),
+    (
+        "app.schema_aborted",
+        "스키마 조회가 완료되지 않고 중단되었습니다.",
+    ),
+    (
+        "app.shell_trial_aborted",
+        "로컬 테스트가 완료되지 않고 중단되었습니다.",
+    ),
+    (
+        "app.shell_trial_no_step",
+        "테스트할 Shell Step을 찾을 수 없습니다.",
+    ),
+    ("app.step_test_failed", "Step 테스트가 실패했습니다."),
+    ("app.step_test_stopped", "Step 테스트를 중지했습니다."),
+    (
+        "app.step_test_aborted",
+        "Step 테스트가 결과 없이 끝났습니다.",
+    ),
+    ("engine.error.db", "DB 오류{code}: {message}"),
+    ("engine.error.timeout", "시간 초과"),
+    (
+        "engine.error.cancelled",
+        "사용자에 의해 실행이 중단되었습니다.",
+    ),
+    (
+        "engine.error.file_not_found",
+        "파일을 찾을 수 없습니다: {path}",
+    ),
+    (
+        "engine.error.outside_window",
+        "실행 허용 시간 밖입니다: {reason}",
+    ),
+    ("engine.error.config", "설정 오류: {reason}"),
+    (
+        "editor.error.empty_scenario_name",
+        "시나리오 이름이 비어 있습니다.",
+    ),
+    ("editor.error.empty_step_id", "Step ID가 비어 있습니다."),
+    (
+        "editor.error.duplicate_step_id",
+        "중복된 Step ID가 존재합니다: {id}",
+    ),
+    (
+        "editor.error.missing_node",
+        "존재하지 않는 노드를 참조하는 연결입니다: {from} -> {to}",
+    ),
+    (
+        "editor.error.cyclic",
+        "순환 의존성이 감지되었습니다. 연결 구성을 확인하세요.",
+    ),
+    (
+        "editor.error.unsupported_db_kind",
+        "지원하지 않는 DB 종류입니다: {kind} (키: {key})",
+    ),
+    ("editor.error.empty_db_key", "DB 키 이름이 비어 있습니다."),
+    (
+        "editor.error.duplicate_db_key",
+        "DB 키 이름이 중복되었습니다: {key}",
+    ),
+    (
+        "editor.error.empty_db_ref",
+        "DB '{key}'의 공유 연결 이름이 비어 있습니다.",
+    ),
+    (
+        "editor.error.invalid_time_window",
+        "Step '{step}'의 실행 시간 창이 잘못되었습니다: {message}",
+    ),
+    (
+        "scenario.contract_violation",
+        "Step '{step}'이(가) 읽는 변수 {var}을(를) 제공하는 상위 Step이 없습니다.",
+    ),
+    (
+        "scenario.param_not_allowed",
+        "파라미터 {param} 값 '{value}'은(는) 허용 값({allowed}) 중 하나가 아닙니다.",
+    ),
+    (
+        "scenario.param_pattern",
+        "파라미터 {param} 값 '{value}'이(가) 패턴 {pattern}에 맞지 않습니다.",
+    ),
+    (
+        "scenario.param_invalid_pattern",
+        "파라미터 {param}의 검증 패턴 {pattern}이(가) 잘못되었습니다: {error}",
+    ),
+    (
+        "scenario.param_date_format",
+        "파라미터 {param} 값 '{value}'이(가) 날짜 형식 {format}에 맞지 않습니다.",
+    ),
+    (
+        "scenario.param_not_number",
+        "파라미터 {param} 값 '{value}'은(는) 숫자가 아닙니다.",
+    ),
+    (
+        "scenario.param_below_min",
+        "파라미터 {param} 값 '{value}'이(가) 최솟값 {min}보다 작습니다.",
+    ),
+    (
+        "scenario.param_above_max",
+        "파라미터 {param} 값 '{value}'이(가) 최댓값 {max}보다 큽니다.",
+    ),
+    (
+        "file_check.summary",
+        "참조 파일 {count}개를 확인할 수 없습니다. 경로를 고치기 전에는 실행할 수 없습니다.",
+    ),
+    ("file_check.missing", "{field} 파일이 없습니다: {path}"),
+    (
+        "file_check.unreadable",
+        "{field} 파일을 읽을 수 없습니다: {path} ({error})",
+    ),
+    (
+        "engine.log.suppressed",
+        "… 로그 {count}줄 생략됨 (Step당 초당 최대 {limit}줄)",
+    ),
+    (
+        "engine.log.truncated",
+        " …(잘림: 전체 {bytes}바이트, 원문은 실행 로그 파일에 있음)",
+    ),
+    (
+        "engine.log.blocked",
+        "선행 Step 실패로 인해 실행하지 않습니다.",
+    ),
+    (
+        "engine.log.memo_hit",
+        "같은 구성으로 이미 실행한 Step '{step}'의 결과를 재사용하고 실행을 생략합니다.",
+    ),
+    (
+        "engine.log.skip_if_file",
+        "건너뛰기 조건: 파일 {path}이(가) 이미 있습니다.",
+    ),
+    (
+        "engine.log.skip_if_sql",
+        "건너뛰기 조건: 조회가 행을 반환했습니다.",
+    ),
+    (
+        "engine.log.skip_if_failed",
+        "건너뛰기 조건을 확인하지 못해 Step을 실행합니다: {error}",
+    ),
+    (
+        "engine.log.output_missing",
+        "기대 산출물이 없습니다: {path}",
+    ),
+    (
+        "engine.log.output_empty",
+        "기대 산출물이 비어 있습니다: {path}",
+    ),
+    (
+        "engine.log.output_remote_skipped",
+        "원격 호스트({remote})에서 실행한 Step이라 기대 산출물을 확인하지 않습니다.",
+    ),
+    (
+        "engine.log.remote_cancel",
+        "취소해 로컬 ssh 연결을 끊었지만 원격 호스트({remote})의 프로세스는 계속 실행 중일 수 있습니다.",
+    ),
+    ("engine.log.wait_start", "{secs}초 대기합니다({until}까지)."),
+    ("engine.log.wait_remaining", "남은 대기 시간: {secs}초"),
+    ("engine.log.wait_done", "대기를 마쳤습니다."),
+    (
+        "engine.log.wait_run_start",
+        "{scenario} 시나리오가 {since} 뒤에 성공한 실행을 기다립니다.",
+    ),
+    (
+        "engine.log.wait_run_pending",
+        "{scenario} 시나리오의 성공한 실행이 아직 없습니다. {secs}초 뒤 다시 확인합니다.",
+    ),
+    (
+        "engine.log.wait_run_done",
+        "{scenario} 시나리오가 {finished}에 성공한 실행을 찾았습니다.",
+    ),
+    (
+        "engine.log.wait_run_timeout",
+        "최대 대기 시간 안에 {scenario} 시나리오가 {since} 뒤에 성공한 실행이 없습니다.",
+    ),
+    (
+        "engine.log.marker_reached",
+        "선행 Step이 모두 끝나 표식을 통과했습니다.",
+    ),
+    (
+        "engine.log.step_cancel_fail",
+        "사용자가 이 Step을 취소해 실패로 처리했습니다.",
+    ),
+    (
+        "engine.log.step_cancel_skip",
+        "사용자가 이 Step을 건너뛰어 성공으로 처리했습니다.",
+    ),
+    (
+        "engine.log.step_tmpdir_failed",
+        "Step 작업 디렉터리를 만들지 못해 ${STEP_TMPDIR} 없이 실행합니다: {error}",
+    ),
+    (
+        "engine.log.diagnostics_start",
+        "실패 진단 명령을 실행합니다.",
+    ),
+    ("engine.log.diagnostic_failed", "진단 실패: {error}"),
+    ("engine.log.diagnostic_empty", "(출력 없음)"),
+    (
+        "engine.log.loop_no_match",
+        "Loop 패턴에 해당하는 파일이 없습니다: {pattern}",
+    ),
+    (
+        "engine.log.loop_iteration",
+        "[반복 {index}] {var} = {value}",
+    ),
+    (
+        "engine.log.loop_failure_ignored",
+        "Loop 반복 실패 무시: {error}",
+    ),
+    (
+        "engine.confirm.rejected_before",
+        "사전 컨펌에서 Step '{step}' 실행이 거부되었습니다.",
+    ),
+    (
+        "engine.confirm.rejected_after",
+        "사후 컨펌에서 Step '{step}' 실행이 거부되었습니다.",
+    ),
+    ("engine.confirm.error", "컨펌 처리 오류: {error}"),
+    (
+        "engine.confirm.unattended",
+        "무인 실행: 기본 응답 '{answer}'(으)로 컨펌을 처리했습니다.",
+    ),
+    (
+        "engine.confirm.attended_no_ui",
+        "참관 실행이지만 컨펌에 응답할 화면이 없습니다.",
+    ),
+    (
+        "engine.confirm.attended_no_answer",
+        "참관 실행이지만 컨펌 응답을 받기 전에 연결이 끊겼습니다.",
+    ),
+    (
+        "engine.output_check_failed",
+        "기대 산출물 {count}개가 없거나 비어 있습니다.",
+    ),
+    (
+        "engine.gate.no_bridge",
+        "승인을 받을 UI가 없어 승인 게이트를 통과할 수 없습니다.",
+    ),
+    (
+        "engine.rehearsal.injected_failure",
+        "리허설: 주입한 강제 실패",
+    ),
+    (
+        "engine.log.retry_after_error",
+        "오류 발생, {secs}초 후 재시도",
+    ),
+    (
+        "engine.log.retry_skipped_permanent",
+        "일시적 오류가 아니므로 재시도하지 않음 (retry_on: any로 바꾸면 모든 오류를 재시도)",
+    ),
+    (
+        "engine.log.retry_after_timeout",
+        "시간 초과 발생, 재시도 준비",
+    ),
+    (
+        "engine.log.timeout_fail",
+        "시간 초과 발생, 정책에 따라 재시도 없이 실패",
+    ),
+    (
+        "engine.log.timeout_warn",
+        "제한 시간 {secs}초 초과({elapsed}초 경과), 중단하지 않고 계속 대기",
+    ),
+    ("engine.log.sql_start", "SQL 실행 시작"),
+    ("engine.log.sql_file", "SQL 파일 실행: {path}"),
+    (
+        "engine.log.sql_plan_saved",
+        "느린 SQL({secs}초)의 실행 계획을 남겼습니다: {path}",
+    ),
+    (
+        "engine.log.sql_plan_failed",
+        "느린 SQL({secs}초)의 실행 계획을 받지 못했습니다: {error}",
+    ),
+    (
+        "engine.log.sql_plan_multi",
+        "느린 SQL({secs}초)이지만 여러 문장이라 실행 계획을 받지 않았습니다.",
+    ),
+    ("engine.plan.elapsed", "소요 시간: {secs}초"),
+    (
+        "engine.log.shell_exit_ignored",
+        "비정상 종료 코드 {status}, 정책에 따라 무시",
+    ),
+    (
+        "engine.log.shell_retry",
+        "쉘 명령 실패, {secs}초 후 재시도 ({attempt}/{max})",
+    ),
+    (
+        "engine.log.process_priority",
+        "프로세스 우선순위 적용: {detail}",
+    ),
+    ("engine.log.window_wait", "실행 시간 창 대기: {until}까지"),
+    ("engine.log.window_open", "실행 시간 창 열림, 실행 시작"),
+    ("engine.log.gate_waiting", "승인 대기 중"),
+    ("engine.log.gate_approved", "{approver}님이 {at}에 승인"),
+    ("engine.log.rehearsal_delay", "리허설: {secs}초 지연"),
+    (
+        "engine.log.rehearsal_simulated",
+        "리허설: 실제 실행 없이 성공 처리",
+    ),
+    (
+        "engine.log.on_failure_timeout",
+        "정리 Step 제한 시간({secs}초)을 넘겨 중단했습니다.",
+    ),
+    (
+        "engine.log.on_failure_expired",
+        "정리 Step 제한 시간이 지나 실행하지 않습니다.",
+    ),
+    (
+        "engine.error.schema_timeout",
+        "스키마 조회 제한 시간 {secs}초 초과",
+    ),
+    ("engine.error.query_timeout", "조회 제한 시간 {secs}초 초과"),
+    (
+        "engine.error.diagnostic_timeout",
+        "진단 제한 시간 {secs}초 초과",
+    ),
+    (
+        "engine.error.diagnostic_kind",
+        "shell과 sql 중 하나만 지정해야 합니다.",
+    ),
+    ("engine.diagnostic.exit_code", "(종료 코드: {code})"),
+    ("engine.output.truncated", "…(잘림: 전체 {bytes}바이트)"),
+    (
+        "engine.error.sandbox_create",
+        "샌드박스 디렉터리를 만들 수 없습니다: {path}",
+    ),
+    (
+        "engine.error.shell_trial_timeout",
+        "로컬 테스트 제한 시간 {secs}초 초과",
+    ),
+    (
+        "engine.error.shell_spawn",
+        "셸을 실행할 수 없습니다: {program}",
+    ),
+    (
+        "engine.error.step_not_found",
+        "Step을 찾을 수 없습니다: {step}",
+    ),
+    (
+        "engine.error.run_tmpdir_create",
+        "실행 임시 디렉터리 생성 실패: {path}",
+    ),
+    (
+        "engine.error.step_tmpdir_create",
+        "Step 작업 디렉터리 생성 실패: {path}",
+    ),
+    ("engine.error.placeholder", "플레이스홀더 치환 실패: {text}"),
+    (
+        "engine.error.placeholder_field",
+        "{field} 필드의 플레이스홀더를 치환할 수 없습니다.",
+    ),
+    (
+        "engine.error.context_file_write",
+        "컨텍스트 파일을 쓸 수 없습니다: {path}",
+    ),
+    (
+        "engine.error.context_file_read",
+        "컨텍스트 파일을 읽을 수 없습니다: {path}",
+    ),
+    (
+        "engine.error.context_file_format",
+        "컨텍스트 파일 형식 오류: {path}",
+    ),
+    ("engine.error.unknown_db", "정의되지 않은 DB 타겟: {name}"),
+    (
+        "engine.error.unknown_remote",
+        "정의되지 않은 원격 호스트: {name}",
+    ),
+    ("engine.error.db_executor", "DB 실행기 생성 실패: {name}"),
+    (
+        "engine.error.remote_expand",
+        "원격 호스트 설정 치환 실패: {name}",
+    ),
+    ("engine.error.missing_field", "{field} 값이 누락되었습니다."),
+    (
+        "engine.error.sqlldr_conn",
+        "SQLLDR_CONN 값을 찾을 수 없습니다.",
+    ),
+    ("engine.error.sqlldr_spawn", "sqlldr 실행 실패"),
+    ("engine.error.sqlldr_exit", "sqlldr 종료 코드: {status}"),
+    (
+        "engine.error.run_as_remote",
+        "run_as는 원격 실행에서 지원되지 않습니다.",
+    ),
+    (
+        "engine.error.shell_spawn_script",
+        "쉘 명령 실행 실패: {script}",
+    ),
+    ("engine.error.shell_exit", "쉘 명령 종료 코드: {status}"),
+    (
+        "engine.error.shell_retry_exhausted",
+        "재시도 한도를 초과했습니다: {status}",
+    ),
+    (
+        "engine.error.run_as_unsupported",
+        "run_as는 현재 운영체제에서 지원되지 않습니다.",
+    ),
+    (
+        "engine.error.passwd_read",
+        "/etc/passwd 파일을 읽을 수 없습니다.",
+    ),
+    ("engine.error.uid_parse", "UID 파싱 실패: {value}"),
+    ("engine.error.gid_parse", "GID 파싱 실패: {value}"),
+    (
+        "engine.error.user_not_found",
+        "사용자 {user} 정보를 찾을 수 없습니다.",
+    ),
+    ("engine.error.sql_file_read", "SQL 파일 읽기 실패: {path}"),
+    (
+        "engine.error.verify_both",
+        "verify에는 expected와 expected_file 중 하나만 지정하세요.",
+    ),
+    (
+        "engine.error.verify_lines_expected",
+        "줄 수 검증에는 expected 또는 expected_file을 지정하세요.",
+    ),
+    (
+        "engine.error.verify_lines_nan",
+        "기대 줄 수가 숫자가 아닙니다: {expected} ({source})",
+    ),
+    ("engine.verify.lines", "줄 수"),
+    ("engine.verify.checksum", "{method} 체크섬"),
+    (
+        "engine.error.verify_mismatch",
+        "{what} 불일치: {path}\n  기대: {expected} ({source})\n  실제: {actual}",
+    ),
+    ("engine.log.verify_passed", "검증 통과 ({source})"),
+    (
+        "engine.error.verify_expected_read",
+        "기대값 파일을 읽을 수 없습니다: {path}",
+    ),
+    (
+        "engine.error.verify_expected_empty",
+        "기대값 파일이 비었습니다: {path}",
+    ),
+    ("engine.error.file_open", "파일을 열 수 없습니다: {path}"),
+    ("engine.log.pipe_read_error", "{tag} 읽기 오류: {error}"),
+    (
+        "engine.error.priority_linux_only",
+        "priority의 io_class·io_level·cpu_affinity는 Linux에서만 지원됩니다.",
+    ),
+    (
+        "engine.error.window_closed",
+        "not_after {time} 이후에는 시작하지 않습니다.",
+    ),
+    (
+        "engine.error.window_not_open",
+        "{until} 이전에는 시작하지 않습니다.",
+    ),
+    (
+        "engine.error.extract_line",
+        "{path}에서 {line}번째 줄을 찾을 수 없습니다.",
+    ),
+    (
+        "engine.error.extract_regex",
+        "정규식 컴파일 실패: {pattern} ({error})",
+    ),
+    (
+        "engine.error.extract_no_match",
+        "패턴이 매칭되지 않았습니다: {line}",
+    ),
+    (
+        "engine.error.extract_group",
+        "캡처 그룹 {group}을 찾을 수 없습니다.",
+    ),
+    ("engine.log.extract_var", "변수 {name} = {value}"),
+    ("engine.confirm.sql_file", "파일: {path}"),
+    (
+        "engine.confirm.extract",
+        "파일: {path} / 그룹: {group} / 변수: {var}",
+    ),
+    ("engine.confirm.wait_until", "대기: {until}까지"),
+    ("engine.confirm.wait_secs", "대기: {secs}초"),
+    (
+        "engine.confirm.wait_for_run",
+        "실행 대기: {scenario} ({since} 이후)",
+    ),
+    ("engine.confirm.since_midnight", "오늘 0시"),
+    ("engine.confirm.verify", "검증: {file} ({method})"),
+    ("engine.error.glob", "glob 패턴 파싱 실패: {error}"),
+    (
+        "engine.error.loop_order",
+        "Loop 하위 Step 실행 순서를 결정할 수 없습니다. 의존성을 확인하세요.",
+    ),
+    (
+        "engine.error.loop_unknown_dependency",
+        "Loop 하위 Step '{step}'에서 알 수 없는 의존성 '{dependency}'을 참조했습니다.",
+    ),
+    (
+        "engine.error.wait_for_run_scenario",
+        "wait_for_run에는 기다릴 scenario를 지정하세요.",
+    ),
+    (
+        "engine.error.preview_timeout",
+        "미리보기 제한 시간 {secs}초 초과",
+    ),
+    (
+        "engine.error.preview_read_only",
+        "미리보기는 SELECT 또는 WITH로 시작하는 조회만 실행할 수 있습니다.",
+    ),
+    (
+        "engine.error.preview_single",
+        "미리보기는 단일 조회 문장만 실행할 수 있습니다.",
+    ),
+    (
+        "executor.error.no_query",
+        "이 DB 실행기는 조회 결과를 지원하지 않습니다.",
+    ),
+    (
+        "executor.error.no_plan",
+        "이 DB 실행기는 실행 계획 조회를 지원하지 않습니다.",
+    ),
+    (
+        "executor.error.no_schema",
+        "이 DB 실행기는 스키마 조회를 지원하지 않습니다.",
+    ),
+    ("executor.pg.pool", "PostgreSQL 커넥션 풀 생성 실패"),
+    ("executor.pg.checkout", "PostgreSQL 커넥션 획득 실패"),
+    (
+        "executor.pg.begin_read_only",
+        "PostgreSQL 읽기 전용 트랜잭션 시작 실패",
+    ),
+    (
+        "executor.pg.statement_timeout",
+        "PostgreSQL statement_timeout 설정 실패",
+    ),
+    ("executor.pg.explain", "PostgreSQL 실행 계획 조회 실패"),
+    ("executor.pg.rollback", "PostgreSQL 롤백 실패"),
+    ("executor.pg.execute", "PostgreSQL SQL 실행 실패"),
+    ("executor.pg.begin", "PostgreSQL 트랜잭션 시작 실패"),
+    (
+        "executor.pg.execute_single",
+        "PostgreSQL SQL 실행 실패 (max_affected_rows는 단일 문장만 지원)",
+    ),
+    ("executor.pg.commit", "PostgreSQL 커밋 실패"),
+    ("executor.pg.query", "PostgreSQL 조회 실패"),
+    (
+        "executor.error.max_affected_rows",
+        "변경 행 수 {affected}건이 허용 한도 {limit}건을 초과해 롤백했습니다.",
+    ),
+    ("executor.oracle.spawn", "sqlplus 실행 실패"),
+    ("executor.oracle.stdin", "sqlplus stdin 전송 실패"),
+    (
+        "executor.oracle.timeout",
+        "sqlplus 문장 제한 시간 {secs}초 초과",
+    ),
+    ("executor.oracle.wait", "sqlplus 종료 대기 실패"),
+    (
+        "executor.oracle.exit",
+        "sqlplus 종료 코드: {status}\n{detail}",
+    ),
+    ("cli.service_installed", "서비스를 등록했습니다: {name}"),
+    ("cli.service_uninstalled", "서비스를 삭제했습니다: {name}"),
+    ("cli.service_started", "서비스를 시작했습니다: {name}"),
+    ("cli.service_failed", "서비스 명령 실패: {error}"),
+    ("cli.run_failed", "시나리오 실행 실패: {path}"),
+    ("cli.run_unavailable", "시나리오 실행 불가: {error}"),
+    ("cli.report_path", "보고서: {path}"),
+    ("cli.self_test_failed", "자가 점검 준비 실패: {error}"),
+    ("cli.schedule_failed", "스케줄 내보내기 실패: {error}"),
+    ("cli.load_failed", "시나리오 읽기 실패: {error}"),
+    (
+        "cli.lint_summary",
+        "{path}: 오류 {errors}개, 경고 {warnings}개",
+    ),
+    ("cli.graph_failed", "그래프 내보내기 실패: {error}"),
+    (
+        "cli.graph_blocked",
+        "오류 {errors}개가 있어 그래프를 내보내지 않습니다.",
+    ),
+    (
+        "cli.param_format",
+        "--param은 KEY=VALUE 형식이어야 합니다: {param}",
+    ),
+    ("cli.headless_blocked", "선행 실패로 건너뜀"),
+    ("lint.level_error", "오류"),
+    ("lint.level_warning", "경고"),
+    ("lint.duplicate_id", "[{step}] Step ID가 중복됩니다."),
+    (
+        "lint.unknown_dependency",
+        "[{step}] depends_on의 Step을 찾을 수 없습니다: {dependency}",
+    ),
+    (
+        "lint.cycle",
+        "순환 의존이 있어 시작할 수 없는 Step: {steps}",
+    ),
+    (
+        "cli.graph_format",
+        "알 수 없는 그래프 형식입니다: {format} (dot 또는 mermaid)",
+    ),
+    (
+        "sql_check.path_has_vars",
+        "경로에 변수가 있어 검사하지 않았습니다: {path}",
+    ),
+    (
+        "sql_check.read_failed",
+        "SQL 파일을 읽을 수 없습니다: {path} ({error})",
+    ),
+    ("sql_check.syntax", "문법 오류: {error}"),
+    (
+        "sql_check.unclosed_comment",
+        "{line}행의 블록 주석이 닫히지 않았습니다.",
+    ),
+    (
+        "sql_check.unclosed_string",
+        "{line}행의 문자열이 닫히지 않았습니다.",
+    ),
+    (
+        "sql_check.unclosed_identifier",
+        "{line}행의 따옴표 식별자가 닫히지 않았습니다.",
+    ),
+    (
+        "sql_check.unmatched_close",
+        "{line}행에 짝이 없는 ')'가 있습니다.",
+    ),
+    (
+        "sql_check.unclosed_paren",
+        "닫히지 않은 '('가 {count}개 있습니다.",
+    ),
+    (
+        "self_test.summary_passed",
+        "자가 점검 통과: {total}개 항목 중 {failed}개 실패",
+    ),
+    (
+        "self_test.summary_failed",
+        "자가 점검 실패: {total}개 항목 중 {failed}개 실패",
+    ),
+    (
+        "self_test.fixture_dir",
+        "자가 점검 임시 폴더 생성 실패: {path}",
+    ),
+    ("self_test.engine_ok", "엔진이 오류 없이 끝남"),
+    (
+        "self_test.finished_once",
+        "시나리오 종료 이벤트가 한 번, 마지막에 옴",
+    ),
+    ("self_test.finished_count", "종료 이벤트 {count}개"),
+    ("self_test.steps_once", "모든 Step이 정확히 한 번 끝남"),
+    (
+        "self_test.dependency_order",
+        "선행 Step이 성공한 뒤에만 시작함",
+    ),
+    (
+        "self_test.forced_failure",
+        "일부러 만든 실패가 실패로 기록됨",
+    ),
+    ("self_test.failed_steps", "실패한 Step: {steps}"),
+    (
+        "self_test.blocked_downstream",
+        "실패한 Step의 하위 Step은 시작하지 않고 건너뜀",
+    ),
+    ("self_test.blocked_steps", "건너뛴 Step: {steps}"),
+    (
+        "self_test.independent_success",
+        "병렬 분기와 실패와 무관한 Step이 모두 성공함",
+    ),
+    (
+        "self_test.extract_value",
+        "Extract 변수 값이 입력 파일과 같음",
+    ),
+    ("self_test.loop_files", "Loop가 모든 파일을 반복함"),
+    (
+        "schedule.unknown_format",
+        "알 수 없는 스케줄 형식입니다: {format} (crontab 또는 windows)",
+    ),
+    (
+        "schedule.cron_fields",
+        "cron 식은 `분 시 일 월 요일` 다섯 필드여야 합니다: {expr}",
+    ),
+    ("schedule.field_minute", "분"),
+    ("schedule.field_hour", "시"),
+    ("schedule.field_day", "일"),
+    ("schedule.field_month", "월"),
+    ("schedule.field_weekday", "요일"),
+    ("schedule.field_error", "cron {field} 필드 오류"),
+    (
+        "schedule.cron_required",
+        "--cron \"<분 시 일 월 요일>\"을 지정해 주세요.",
+    ),
+    (
+        "schedule.scenario_missing",
+        "시나리오 파일을 찾을 수 없습니다: {path}",
+    ),
+    (
+        "schedule.too_many_triggers",
+        "작업 스케줄러로 옮기면 트리거가 {max}개를 넘습니다. 분과 시를 줄여 주세요: {expr}",
+    ),
+    (
+        "schedule.unsupported_cron",
+        "작업 스케줄러로 옮길 수 없는 cron 식입니다. 일과 요일을 함께 지정하거나 요일과 월을 함께 지정할 수 없습니다: {expr}",
+    ),
+    ("schedule.empty_command", "실행할 명령이 비었습니다."),
+    ("schedule.bad_step", "잘못된 간격입니다: {part}"),
+    ("schedule.bad_range", "범위의 시작이 끝보다 큽니다: {part}"),
+    ("schedule.not_number", "숫자가 아닙니다: {value}"),
+    (
+        "schedule.out_of_range",
+        "{min}~{max} 범위를 벗어났습니다: {value}",
+    ),
+    (
+        "cli.confirm_mode_conflict",
+        "--unattended와 --attended는 함께 쓸 수 없습니다.",
+    ),
+    ("editor.default_scenario_name", "새 시나리오"),
+    (
+        "scenario.error.db_kind_required",
+        "DB 연결에 kind(dummy, postgres, oracle) 또는 db_ref를 지정해야 합니다.",
+    ),
+    (
+        "scenario.error.priority_nice",
+        "priority.nice는 -20~19 사이여야 합니다: {value}",
+    ),
+    (
+        "scenario.error.priority_io_level",
+        "priority.io_level은 0~7 사이여야 합니다: {value}",
+    ),
+    (
+        "scenario.error.priority_idle_level",
+        "priority.io_class가 idle이면 io_level을 지정할 수 없습니다.",
+    ),
+    (
+        "scenario.error.shell_error_policy",
+        "알 수 없는 shell error policy: {policy}",
+    ),
+    (
+        "scenario.error.time_format",
+        "{field} 시각 형식이 잘못되었습니다(HH:MM 또는 HH:MM:SS): {value}",
+    ),
+    (
+        "scenario.error.wait_both",
+        "wait에는 seconds와 until_time 중 하나만 지정하세요.",
+    ),
+    (
+        "scenario.error.wait_missing",
+        "wait에는 seconds 또는 until_time을 지정하세요.",
+    ),
+    (
+        "scenario.error.since_format",
+        "since 시각 형식이 잘못되었습니다: {value}",
+    ),
+    (
+        "scenario.error.local_time",
+        "로컬 시간대에 없는 시각입니다: {time}",
+    ),
+    (
+        "scenario.error.include_format",
+        "include 항목 형식 오류: {error}",
+    ),
+    (
+        "scenario.error.fragment_read",
+        "Step 조각 파일 읽기 실패({path}): {error}",
+    ),
+    (
+        "scenario.error.fragment_parse",
+        "Step 조각 파일 파싱 실패({path}): {error}",
+    ),
+    (
+        "scenario.error.fragment_no_steps",
+        "Step 조각 파일에 steps 목록이 없습니다: {path}",
+    ),
+    (
+        "scenario.error.fragment_nested_include",
+        "Step 조각 파일 안에서는 include를 쓸 수 없습니다: {path}",
+    ),
+    ("parse_error.hint_text", "힌트: {hint}"),
+    (
+        "parse_error.hint_tabs",
+        "들여쓰기에는 탭 대신 공백을 쓰세요.",
+    ),
+    (
+        "parse_error.hint_quote_colon",
+        "값에 `: `가 들어 있으면 값 전체를 따옴표로 감싸세요.",
+    ),
+    (
+        "parse_error.hint_indent",
+        "윗줄과 들여쓰기, 목록 기호 `-` 위치가 맞는지 확인하세요.",
+    ),
+    (
+        "parse_error.hint_duplicate",
+        "같은 매핑 안에 같은 키가 두 번 있습니다. 하나를 지우세요.",
+    ),
+    (
+        "parse_error.hint_kind",
+        "Step에 `kind`를 지정하세요. 사용할 수 있는 값: {kinds}",
+    ),
+    (
+        "parse_error.hint_key_typo",
+        "키 이름을 확인하세요: `{typo}` → `{key}`",
+    ),
+    (
+        "parse_error.hint_other_kind",
+        "`{key}` 키가 있습니다. `kind: {kind}`로 바꾸려던 것인가요?",
+    ),
+    (
+        "parse_error.hint_kind_field",
+        "`kind: {kind}` Step에는 `{field}` 항목이 필요합니다.",
+    ),
+    ("parse_error.hint_add_field", "`{field}` 항목을 추가하세요."),
+    ("parse_error.hint_similar", "비슷한 값: `{value}`"),
+    ("parse_error.hint_options", "사용할 수 있는 값: {options}"),
+    ("builder.kind.sql_file", "SQL 파일"),
+    ("builder.kind.manual_gate", "승인 게이트"),
+    ("builder.kind.wait", "대기"),
+    ("builder.kind.wait_for_run", "실행 대기"),
+    ("builder.kind.verify", "검증"),
+    ("builder.kind.marker", "표식"),
+    ("builder.lost.target_db", "DB 타겟(target_db)"),
+    ("builder.lost.guard", "SQL 안전장치"),
+    ("builder.lost.sql_file_path", "SQL 파일 경로"),
+    ("builder.lost.control_file", "control 파일"),
+    ("builder.lost.sqlldr_files", "data/log/bad/discard 파일"),
+    ("builder.lost.conn", "접속 문자열"),
+    ("builder.lost.remote", "원격 호스트"),
+    ("builder.lost.sqlldr_options", "sqlldr 옵션/추가 인자"),
+    ("builder.lost.script", "스크립트"),
+    ("builder.lost.shell_program", "셸 프로그램/인자"),
+    ("builder.lost.env", "환경 변수"),
+    ("builder.lost.working_dir", "작업 디렉터리/실행 사용자"),
+    ("builder.lost.extract", "추출 설정(파일/정규식/변수명)"),
+    ("builder.lost.loop_steps", "Loop 하위 Step 전체"),
+    ("builder.lost.gate_message", "승인 안내 문구"),
+    ("builder.lost.wait", "대기 시간"),
+    ("builder.lost.wait_for_run", "기다릴 시나리오"),
+    ("builder.lost.verify", "검증 설정(파일/기대값)"),
+    ("builder.new_step_name", "새 Step {id}"),
+    ("builder.copy_name", "{name} 사본"),
+    (
+        "builder.paste.added_db",
+        "DB 연결을 함께 추가했습니다: {names} (비밀번호는 옮기지 않으므로 DB 설정에서 입력하세요)",
+    ),
+    (
+        "builder.paste.conflicting_db",
+        "같은 이름의 DB 연결이 달라 현재 시나리오의 정의를 유지했습니다: {names}",
+    ),
+    (
+        "builder.paste.missing_vars",
+        "⚠ 제공하는 Step이나 파라미터가 없는 변수: {names}",
+    ),
+    ("builder.none", "(없음)"),
+    ("builder.notify.title", "Step 알림 웹훅"),
+    (
+        "builder.notify.hint",
+        "시작·성공·실패 시 JSON을 POST할 주소 (notify_url)",
+    ),
+    (
+        "builder.notify.placeholder",
+        "예: http://hooks.internal/customer-loaded",
+    ),
+    (
+        "builder.sql_change.changed",
+        "⚠ 변경됨 · 마지막 성공 실행({changed_at}) 이후 SQL 파일이 바뀌었습니다.",
+    ),
+    ("builder.sql_change.show", "🔍 변경 내용 보기"),
+    (
+        "builder.sql_change.show_hover",
+        "마지막 성공 실행 때 파일 내용과 지금 내용을 줄 단위로 비교합니다.",
+    ),
+    ("builder.contracts.title", "변수 계약"),
+    ("builder.contracts.consumes", "읽는 변수 (consumes)"),
+    ("builder.contracts.produces", "제공하는 변수 (produces)"),
+    ("builder.contracts.placeholder", "예: RUN_DATE, TARGET_FILE"),
+    (
+        "builder.included.title",
+        "🔗 조각 파일에서 포함된 Step (읽기 전용)",
+    ),
+    ("builder.included.copy_path", "📋 경로 복사"),
+    (
+        "builder.included.copy_path_hover",
+        "조각 파일 경로를 클립보드에 복사합니다.",
+    ),
+    ("builder.sql_diff.title", "SQL 변경 내용 · {step_id}"),
+    (
+        "builder.sql_diff.no_snapshot",
+        "마지막 성공 실행 때 파일 내용이 남아 있지 않아 비교할 수 없습니다. 실행 이력 정리로 지워졌을 수 있습니다.",
+    ),
+    ("builder.sql_diff.counts", "−{removed}줄 · +{added}줄"),
+    ("builder.skip_if.title", "이미 결과가 있으면 건너뛰기"),
+    (
+        "builder.skip_if.file_exists",
+        "이 파일이 있으면 (file_exists)",
+    ),
+    (
+        "builder.skip_if.sql_returns_rows",
+        "이 조회가 행을 반환하면 (sql_returns_rows)",
+    ),
+    ("builder.skip_if.db", "조회 대상 DB (비우면 default)"),
+    (
+        "builder.skip_if.hint",
+        "지정한 조건을 모두 충족하면 실행하지 않고 성공으로 처리합니다.",
+    ),
+    ("builder.shell.vars", "변수"),
+    (
+        "builder.included.prefix",
+        "ID 접두사 `{prefix}` · 조각 파일에서 편집하세요.",
+    ),
+    (
+        "builder.sql_diff.since",
+        "{path} · 마지막 성공 실행 {at} 이후",
+    ),
+    (
+        "builder.perf.overlay",
+        "FPS {fps}\n캔버스 구성 {build_ms}ms\n노드 {nodes}/{nodes_total} · 연결 {connections}/{connections_total}",
+    ),
+    ("builder.list.chain", "🔗 체인으로 연결"),
+    ("builder.list.drag_hint", "☰ 손잡이를 끌어 순서를 바꿉니다."),
+    ("builder.col.kind", "유형"),
+    ("builder.col.name", "이름"),
+    ("builder.col.predecessors", "선행 Step"),
+    ("builder.outputs.title", "기대 산출물"),
+    (
+        "builder.outputs.files",
+        "성공 뒤 있어야 하는 파일 (한 줄에 하나, expects_outputs)",
+    ),
+    ("builder.outputs.fail", "Step 실패"),
+    ("builder.outputs.warn", "경고만 남김"),
+    ("builder.outputs.policy", "없거나 비었을 때"),
+    (
+        "builder.outputs.hint",
+        "종료 코드가 0이어도 파일이 없거나 0바이트면 정책대로 처리합니다.",
+    ),
+    (
+        "builder.preview.button",
+        "🔎 미리보기 (최대 {SQL_PREVIEW_MAX_ROWS}행)",
+    ),
+    (
+        "builder.preview.hover",
+        "DB 타겟에서 읽기 전용으로 조회해 결과 일부를 보여 줍니다. SELECT/WITH 조회만 실행합니다.",
+    ),
+    ("builder.preview.running", "조회 중..."),
+    ("builder.preview.no_rows", "결과 행이 없습니다."),
+    (
+        "builder.convert.nothing_lost",
+        "잃는 값이 없습니다. 연결/재시도/타임아웃/컨펌 설정은 유지됩니다.",
+    ),
+    (
+        "builder.convert.kept",
+        "연결/재시도/타임아웃/컨펌 설정은 유지됩니다.",
+    ),
+    ("builder.convert.apply", "변환"),
+    ("builder.schema.title", "🗂 스키마"),
+    ("builder.schema.reload", "다시 읽기"),
+    ("builder.schema.filter", "테이블 이름 필터"),
+    ("builder.schema.loading", "테이블 목록을 읽는 중…"),
+    ("builder.schema.failed", "조회 실패: {err}"),
+    ("builder.delete.bridge", "연결 이어서 삭제"),
+    ("builder.delete.apply", "삭제"),
+    ("builder.window.title", "실행 시간 창"),
+    (
+        "builder.window.not_before",
+        "시작 가능 시각 (not_before, HH:MM)",
+    ),
+    (
+        "builder.window.not_after",
+        "시작 마감 시각 (not_after, HH:MM)",
+    ),
+    ("builder.window.policy", "시작 전 도달 시"),
+    ("builder.window.wait", "대기"),
+    ("builder.window.fail", "실패"),
+    (
+        "builder.list.chain_hint",
+        "현재 순서대로 앞 Step → 다음 Step 의존성을 만듭니다. 기존 연결은 모두 지워집니다.",
+    ),
+    (
+        "builder.preview.truncated",
+        "{more}행 이상 · 앞의 {shown}행만 표시",
+    ),
+    ("builder.preview.rows", "{rows}행"),
+    ("builder.convert.title", "{from} → {to} 변환"),
+    ("builder.convert.lost", "다음 값이 삭제됩니다: {fields}"),
+    (
+        "builder.schema.tables",
+        "테이블 {count}개 · 이름을 SQL 편집기로 끌어다 놓으면 삽입됩니다.",
+    ),
+    ("builder.delete.title", "'{step}' 삭제"),
+    ("builder.delete.predecessors", "선행: {steps}"),
+    ("builder.delete.successors", "후행: {steps}"),
+    (
+        "builder.delete.orphaned",
+        "연결을 잇지 않으면 선행 Step이 없어지는 Step: {steps}",
+    ),
+    ("builder.step_test.button", "🧪 이 Step만 테스트"),
+    (
+        "builder.step_test.hover",
+        "다른 Step 없이 이 Step 하나만 설정된 실행기로 실행합니다. 이력과 알림은 남기지 않습니다.",
+    ),
+    ("builder.preparing", "준비 중..."),
+    ("builder.running", "실행 중..."),
+    ("builder.stop", "⏹ 중지"),
+    ("builder.stopping", "중지하는 중..."),
+    ("builder.step_test.passed", "✅ 테스트 성공"),
+    ("builder.run", "▶ 실행"),
+    ("builder.shell_trial.title", "🧪 로컬 테스트"),
+    ("builder.shell_trial.no_vars", "치환할 변수가 없습니다."),
+    ("builder.shell_trial.dummy_values", "변수 더미 값"),
+    ("builder.shell_trial.run", "▶ 로컬에서 테스트 실행"),
+    (
+        "builder.shell_trial.hover",
+        "임시 샌드박스 디렉터리에서 이 PC의 셸로 한 번 실행합니다. 원격 호스트·실행 사용자·작업 디렉터리는 무시합니다.",
+    ),
+    ("builder.shell_trial.exit_code", "종료 코드 {code}"),
+    ("builder.shell_trial.signaled", "시그널로 종료됨"),
+    ("builder.shell_trial.no_output", "출력이 없습니다."),
+    ("builder.confirm.title", "실행 컨펌"),
+    ("builder.confirm.before", "실행 전 확인"),
+    ("builder.confirm.after", "실행 후 확인"),
+    ("builder.confirm.message_before", "메시지 (실행 전)"),
+    ("builder.confirm.message_after", "메시지 (실행 후)"),
+    ("builder.confirm.default_answer", "기본 응답"),
+    ("builder.palette.title", "🧱 Step 팔레트"),
+    ("builder.palette.hint", "추가할 Step 유형을 선택하세요."),
+    ("builder.palette.extract", "Extract (값 추출)"),
+    ("builder.palette.loop", "Loop (반복)"),
+    ("builder.palette.wait_for_run", "실행 대기 (다른 시나리오)"),
+    ("builder.palette.marker", "표식 (합류 지점)"),
+    ("builder.trash.title", "🗑 휴지통 ({count})"),
+    ("builder.trash.empty", "삭제한 Step이 없습니다."),
+    ("builder.trash.restore", "↩ 복원"),
+    (
+        "builder.trash.restore_hover",
+        "삭제 전 위치에 되살리고, 상대 Step이 남아 있는 연결을 다시 잇습니다.",
+    ),
+    ("builder.trash.purge_hover", "휴지통에서 영구히 지웁니다."),
+    ("builder.trash.clear", "휴지통 비우기"),
+    ("builder.trash.no_connections", "연결 없음"),
+    ("builder.trash.connections", "삭제 전 연결:"),
+    (
+        "builder.canvas.sql_changed_hover",
+        "마지막 성공 실행 이후 SQL 파일이 바뀌었습니다. 속성 패널에서 변경 내용을 볼 수 있습니다.",
+    ),
+    ("builder.canvas.add_here", "여기에 Step 추가"),
+    ("builder.critical.no_steps", "분석할 Step이 없습니다."),
+    (
+        "builder.critical.top",
+        "단축 효과 상위 {CRITICAL_PATH_TOP_STEPS}:",
+    ),
+    (
+        "builder.critical.no_history",
+        "없음 (경로 Step의 실행 이력이 없습니다)",
+    ),
+    (
+        "builder.critical.saving_hover",
+        "이 Step의 소요 시간을 0으로 줄였을 때 전체 실행이 짧아지는 시간",
+    ),
+    ("builder.sim.pause", "일시 정지"),
+    ("builder.sim.play", "재생"),
+    ("builder.sim.rewind", "처음으로"),
+    ("builder.sim.tick", "시점"),
+    ("builder.sim.assume_success", "✅ 선택 Step 성공 가정"),
+    ("builder.sim.assume_failure", "💥 선택 Step 실패 가정"),
+    (
+        "builder.sim.failure_hover",
+        "선택한 Step이 실패하면 어떤 하위 Step이 건너뛰어지는지 봅니다.",
+    ),
+    ("builder.sim.clear_failures", "가정 지우기"),
+    (
+        "builder.step_test.enter_vars",
+        "이 Step이 참조하는 변수 값을 입력하세요.",
+    ),
+    (
+        "builder.trash.capacity",
+        "최근 {capacity}개까지 보관하며 시나리오를 새로 열면 비워집니다.",
+    ),
+    (
+        "builder.trash.open_flow",
+        "Step이 있던 흐름({flow})을 캔버스로 열어야 복원할 수 있습니다.",
+    ),
+    ("builder.trash.flow", "흐름: {flow}"),
+    ("builder.trash.top_level", "최상위"),
+    ("builder.canvas.history", "과거 실행 · {summary}"),
+    ("builder.canvas.no_var", "{label} → 변수 미지정"),
+    (
+        "builder.critical.cycle",
+        "⚠ 순환 연결이 있어 크리티컬 패스를 계산할 수 없습니다.",
+    ),
+    ("builder.critical.total", "⏱ 예상 총 소요 시간 {total}"),
+    (
+        "builder.critical.unmeasured",
+        "실행 이력이 없는 Step {count}개는 0초로 계산했습니다.",
+    ),
+    ("builder.canvas.changed", "변경됨"),
+    ("builder.sim.total", "🔢 예상 총 소요 시간 {total}"),
+    (
+        "builder.sim.counts",
+        "시작 {started}개 · 다른 Step과 겹침 {parallel}개 · 건너뜀 {blocked}개",
+    ),
+    ("builder.sim.failures", "실패 가정: {steps}"),
+    (
+        "builder.sim.unmeasured",
+        "실행 이력이 없는 Step {count}개는 {secs}초로 계산했습니다.",
+    ),
+    (
+        "builder.sim.unreached",
+        "⚠ 순환 연결 등으로 시작할 수 없는 Step: {steps}",
+    ),
+    ("builder.props.title", "⚙️ Step 속성"),
+    ("builder.props.duplicate", "📄 복제"),
+    (
+        "builder.props.duplicate_hover",
+        "ID에 접미사를 붙여 사본을 만듭니다. (Ctrl+D)",
+    ),
+    ("builder.props.open_loop", "🔁 Loop 열기"),
+    (
+        "builder.props.open_loop_hover",
+        "하위 Step을 캔버스에서 편집합니다. (노드 더블 클릭)",
+    ),
+    ("builder.props.icon", "표시 아이콘"),
+    ("builder.props.label", "표시 라벨"),
+    ("builder.props.stage", "스테이지"),
+    ("builder.allow_parallel", "병렬 허용"),
+    ("builder.retry", "재시도"),
+    ("builder.timeout_prefix", "타임아웃 "),
+    ("builder.seconds_suffix", "초"),
+    (
+        "builder.props.not_found",
+        "선택된 Step 정보를 찾을 수 없습니다.",
+    ),
+    ("builder.props.no_selection", "선택된 Step이 없습니다."),
+    ("builder.deps.title", "의존성"),
+    ("builder.deps.add", "의존성 추가"),
+    ("builder.deps.select_node", "노드 선택"),
+    ("builder.props.delete_step", "이 Step 삭제"),
+    ("builder.props.scenario_name", "시나리오 이름"),
+    (
+        "builder.props.memoize_hover",
+        "멱등 Step에만 켜세요. 한 실행 안에서 유형과 치환된 구성이 같은 Step은 한 번만 실행하고, 이후 반복·분기는 그 결과를 재사용합니다.",
+    ),
+    ("builder.props.retry_all", "모든 오류 재시도"),
+    (
+        "builder.props.retry_all_hover",
+        "끄면 접속 끊김·교착 상태 같은 일시적 오류만 재시도하고 SQL 오류는 바로 실패합니다.",
+    ),
+    ("builder.timeout.fail", "즉시 실패"),
+    ("builder.timeout.warn_continue", "경고 후 계속 대기"),
+    ("builder.timeout.kill_retry", "중단 후 재시도"),
+    ("builder.timeout.policy", "타임아웃 시"),
+    (
+        "builder.timeout.warn_continue_hover",
+        "경고 후 계속 대기는 타임아웃마다 경고만 남기고 Step을 중단하지 않습니다.",
+    ),
+    ("builder.log_level.default", "기본"),
+    ("builder.log_level.debug", "debug (자세히)"),
+    ("builder.log_level.warn", "warn (경고만)"),
+    ("builder.log_level.title", "로그 수준"),
+    (
+        "builder.log_level.hover",
+        "엔진이 남기는 진행 메시지의 양을 정합니다. debug는 Loop 반복마다의 변수 값까지 남기고, warn은 경고만 남깁니다. 프로그램 출력과 실패 메시지는 항상 남습니다.",
+    ),
+    (
+        "builder.props.inherited",
+        "시나리오 defaults에서 상속된 값입니다.",
+    ),
+    ("builder.db.title", "🗄 DB 설정"),
+    (
+        "builder.db.hint",
+        "SQL/SQL 파일 Step에서 사용할 DB 접속 정보를 정의합니다.",
+    ),
+    (
+        "builder.db.no_default",
+        "default 키가 없으면 target_db 미지정 Step이 실패합니다.",
+    ),
+    (
+        "builder.db.empty",
+        "등록된 DB 연결이 없습니다. 'DB 연결 추가' 버튼으로 새 항목을 만드세요.",
+    ),
+    ("builder.db.key", "키"),
+    (
+        "builder.db.default_hint",
+        "default는 target_db 미지정 시 사용됩니다.",
+    ),
+    ("builder.db.shared", "공유 연결"),
+    ("builder.db.inline", "(직접 입력)"),
+    (
+        "builder.db.uses_shared",
+        "connections.yaml의 '{name}' 연결을 사용합니다.",
+    ),
+    (
+        "builder.db.shared_missing",
+        "공유 저장소에 '{name}' 연결이 없습니다.",
+    ),
+    ("builder.db.kind", "종류"),
+    ("builder.db.unsupported", "(미지원)"),
+    ("builder.db.dsn", "DSN / 접속 문자열"),
+    ("builder.db.user", "사용자"),
+    ("builder.db.password", "비밀번호"),
+    ("builder.db.export", "공유 저장소로 내보내기"),
+    ("builder.db.add", "DB 연결 추가"),
+    ("builder.db.store_title", "📚 공유 연결 저장소"),
+    (
+        "builder.db.store_hint",
+        "connections.yaml에 저장되며 여러 시나리오가 db_ref로 참조합니다.",
+    ),
+    (
+        "builder.db.store_empty",
+        "공유 연결이 없습니다. 위 DB 항목에서 내보내기로 추가하세요.",
+    ),
+    ("builder.db.import", "가져오기"),
+    ("builder.loop.title", "Loop 설정"),
+    ("builder.loop.as_var", "as 변수명"),
+    ("builder.loop.on_failure", "실패 시 동작"),
+    ("builder.loop.children", "하위 Step"),
+    ("builder.loop.add", "추가"),
+    ("builder.loop.delete_selected", "선택 Step 삭제"),
+    ("builder.loop.selected_child", "선택된 하위 Step"),
+    ("builder.layout.canvas", "🗺 캔버스"),
+    ("builder.layout.list", "📋 목록"),
+    ("builder.layout.stage_colors", "🎨 스테이지별 색상"),
+    ("builder.layout.critical_path", "⏱ 크리티컬 패스"),
+    (
+        "builder.layout.critical_path_hover",
+        "실행 이력의 평균 소요 시간으로 가장 오래 걸리는 경로를 강조합니다.",
+    ),
+    ("builder.layout.order_sim", "🔢 실행 순서 시뮬레이션"),
+    (
+        "builder.layout.order_sim_hover",
+        "실제로 실행하지 않고, 엔진이 allow_parallel과 의존성에 따라 Step을 시작하는 순서를 캔버스에 재생합니다.",
+    ),
+    ("builder.layout.schema", "🗂 스키마 브라우저"),
+    (
+        "builder.layout.schema_hover",
+        "선택한 DB의 테이블과 컬럼을 보고, 이름을 SQL 편집기로 끌어다 놓습니다.",
+    ),
+    ("builder.layout.snap", "▦ 그리드 맞춤"),
+    ("builder.layout.guides", "📐 정렬 가이드"),
+    (
+        "builder.layout.guides_hover",
+        "끄는 노드를 다른 노드의 가장자리·중심에 맞추고 가이드 선을 보여 줍니다.",
+    ),
+    (
+        "builder.layout.distribute_hint",
+        "Shift+클릭으로 노드를 3개 이상 고르면 양 끝 노드 사이 간격을 고르게 맞춥니다.",
+    ),
+    ("builder.layout.distribute_h", "↔ 가로 간격 맞춤"),
+    ("builder.layout.distribute_v", "↕ 세로 간격 맞춤"),
+    ("builder.layout.up", "⬅ 상위 흐름"),
+    (
+        "builder.layout.up_hover",
+        "하위 Step을 Loop에 반영하고 바로 위 흐름으로 돌아갑니다.",
+    ),
+    ("builder.layout.editing_loop", "Loop 하위 Step 편집 중"),
+    ("builder.layout.close_notice", "안내 닫기"),
+    ("builder.props.memoize", "같은 구성 결과 재사용"),
+    ("builder.cfg.sql_file_path", "SQL 파일 경로"),
+    ("builder.cfg.gate_message", "승인 안내 문구"),
+    (
+        "builder.cfg.gate_message_hint",
+        "승인 전에 확인할 사항을 적어 주세요.",
+    ),
+    ("builder.cfg.verify_method", "계산 방식(method)"),
+    ("builder.cfg.expected_source", "기대값 출처"),
+    ("builder.cfg.expected_value", "값/변수"),
+    ("builder.cfg.expected_file", "기대값 파일"),
+    ("builder.cfg.sidecar", "사이드카 파일"),
+    (
+        "builder.cfg.expected_file_hint",
+        "예: /data/in/sales.dat.md5",
+    ),
+    (
+        "builder.cfg.sidecar_hint",
+        "{sidecar}의 첫 단어와 비교합니다.",
+    ),
+    (
+        "builder.cfg.wait_scenario",
+        "기다릴 시나리오 이름(scenario)",
+    ),
+    ("builder.cfg.wait_scenario_hint", "예: upstream_daily_load"),
+    (
+        "builder.cfg.wait_since",
+        "기준 시각(since) — 이 시각 뒤에 끝난 성공 실행만 인정",
+    ),
+    ("builder.cfg.poll_sec", "확인 간격(poll_sec)"),
+    ("builder.cfg.max_wait", "최대 대기 시간 지정(max_wait_sec)"),
+    ("builder.cfg.wait_seconds_mode", "초 단위 대기"),
+    ("builder.cfg.wait_until_mode", "지정 시각까지"),
+    ("builder.cfg.until_time", "대기 종료 시각(until_time)"),
+    ("builder.cfg.until_time_hint", "HH:MM 또는 HH:MM:SS"),
+    ("builder.cfg.wait_seconds", "대기 시간(seconds)"),
+    ("builder.cfg.target_db", "DB 타겟(target_db)"),
+    ("builder.cfg.default_db", "(기본 DB 사용)"),
+    ("builder.cfg.guard", "안전장치"),
+    ("builder.cfg.statement_timeout", "문장 제한 시간"),
+    ("builder.cfg.suffix_seconds", " 초"),
+    ("builder.cfg.max_affected_rows", "최대 변경 행 수"),
+    ("builder.cfg.suffix_count", " 건"),
+    (
+        "builder.cfg.max_affected_rows_hint",
+        "최대 변경 행 수는 단일 DML 문장에만 적용되며, 초과 시 롤백 후 실패합니다.",
+    ),
+    ("builder.cfg.control_file", "control 파일"),
+    ("builder.cfg.data_file", "data 파일"),
+    ("builder.cfg.log_file", "log 파일"),
+    ("builder.cfg.bad_file", "bad 파일"),
+    ("builder.cfg.discard_file", "discard 파일"),
+    ("builder.cfg.conn", "접속 문자열"),
+    (
+        "builder.cfg.conn_hint",
+        "비워두면 SQLLDR_CONN 환경 변수를 사용합니다.",
+    ),
+    ("builder.cfg.sqlldr_options", "sqlldr 옵션"),
+    ("builder.cfg.suffix_rows", " 행"),
+    ("builder.cfg.suffix_bytes", " 바이트"),
+    (
+        "builder.cfg.extra_args",
+        "추가 인자(한 줄에 하나, key=value)",
+    ),
+    ("builder.cfg.remote", "원격 호스트(remotes 키)"),
+    ("builder.cfg.remote_hint", "비워두면 로컬에서 실행합니다."),
+    ("builder.cfg.priority", "프로세스 우선순위"),
+    (
+        "builder.cfg.nice_hover",
+        "클수록 다른 프로세스에 CPU를 양보합니다. 음수는 관리자 권한이 필요합니다.",
+    ),
+    ("builder.cfg.io_class", "I/O 클래스"),
+    ("builder.cfg.unset", "지정 안 함"),
+    ("builder.cfg.io_level", "I/O 우선순위"),
+    (
+        "builder.cfg.io_level_hover",
+        "0이 가장 먼저, 7이 가장 나중에 처리됩니다.",
+    ),
+    ("builder.cfg.cpus", "CPU 번호(쉼표 구분)"),
+    (
+        "builder.cfg.priority_hint",
+        "Linux는 nice·ionice·taskset으로, Windows는 nice를 우선순위 클래스로 적용합니다.",
+    ),
+    ("builder.cfg.script", "스크립트"),
+    ("builder.cfg.shell_program", "셸 프로그램"),
+    ("builder.cfg.shell_args", "인자 목록(쉼표 구분)"),
+    ("builder.cfg.working_dir", "작업 디렉터리"),
+    ("builder.cfg.run_as", "실행 사용자"),
+    ("builder.cfg.inherit_env", "GUI 프로세스 환경 변수 상속"),
+    (
+        "builder.cfg.inherit_env_hover",
+        "끄면 빈 환경에서 아래 환경 변수만 지정해 실행합니다.",
+    ),
+    ("builder.cfg.env", "환경 변수 (KEY=VALUE 한 줄씩)"),
+    ("builder.cfg.line_prefix", "라인 "),
+    ("builder.cfg.pattern", "정규식 패턴"),
+    ("builder.cfg.group_prefix", "캡처 그룹 "),
+    ("builder.cfg.var_name", "저장할 변수명"),
+    ("builder.cfg.secret", "비밀 값(로그·화면에서 **** 로 가림)"),
+    ("builder.cfg.verify_file", "검증할 파일(file)"),
+    ("builder.cfg.expected_hint", "체크섬/줄 수 또는 ${VAR_NAME}"),
+    (
+        "builder.cfg.wait_since_hint",
+        "비우면 오늘 0시 · 예: ${RUN_DATE}, 2024-01-01 02:00, 06:00",
+    ),
+    (
+        "builder.cfg.extra_args_hint",
+        "예: parallel=true, readsize=${READSIZE}. 옵션 뒤에 그대로 붙습니다.",
+    ),
+    ("builder.cfg.file_path", "파일 경로"),
+    (
+        "crypto.error.passphrase_required",
+        "암호화된 시나리오입니다. 암호 문구가 필요합니다.",
+    ),
+    (
+        "crypto.error.wrong_passphrase",
+        "암호 문구가 올바르지 않거나 파일이 손상되었습니다.",
+    ),
+    (
+        "crypto.error.invalid_format",
+        "암호화된 시나리오 형식이 아닙니다.",
+    ),
+    ("crypto.error.encrypt_failed", "시나리오 암호화 실패"),
+    (
+        "history.stats_summary",
+        "최소 {min} · 평균 {avg} · 최대 {max} · 성공률 {rate}% ({runs}회)",
+    ),
+    ("history.duration_hours", "{hours}시간 {minutes}분"),
+    ("history.duration_minutes", "{minutes}분 {seconds}초"),
+    ("history.duration_seconds", "{seconds}초"),
+    (
+        "import.todo.db_connection",
+        "[db.{key}] 연결 정보(dsn, user, password)를 입력해 주세요.",
+    ),
+    ("import.error.read", "워크플로 파일 읽기 실패: {path}"),
+    (
+        "import.error.no_tasks",
+        "가져올 작업을 찾지 못했습니다: {path}",
+    ),
+    (
+        "import.todo.not_literal",
+        "{arg}이(가) 문자열 리터럴이 아니어서 옮기지 못했습니다.",
+    ),
+    (
+        "import.todo.unsupported_operator",
+        "지원하지 않는 연산자 {operator}입니다. 직접 옮겨 주세요.",
+    ),
+    (
+        "import.todo.decision",
+        "[{name}] decision 분기 조건은 옮기지 않고 모든 분기를 실행하도록 연결했습니다.",
+    ),
+    (
+        "import.todo.error_transition",
+        "[{name}] 실패 시 {error}(으)로 가는 전이는 옮기지 않았습니다. on_failure로 구성해 주세요.",
+    ),
+    (
+        "import.todo.unsupported_action",
+        "지원하지 않는 Oozie 액션 {action}입니다. 직접 옮겨 주세요.",
+    ),
+    (
+        "import.todo.no_command",
+        "{action} 액션에 실행할 명령이 없습니다.",
+    ),
+    ("import.todo.placeholder", "{id} 작업을 옮겨 주세요."),
+    (
+        "connections.error.missing_shared",
+        "DB '{key}'가 참조한 공유 연결 '{name}'이 없습니다.",
+    ),
+    (
+        "connections.error.nested_ref",
+        "공유 연결 '{name}'은 다른 연결을 참조할 수 없습니다.",
+    ),
+    (
+        "connections.error.read",
+        "공유 연결 저장소 읽기 실패: {path}",
+    ),
+    (
+        "connections.error.parse",
+        "공유 연결 저장소 파싱 실패: {path}",
+    ),
+    (
+        "connections.error.save",
+        "공유 연결 저장소 저장 실패: {path}",
+    ),
+    ("approval.error.no_approver", "승인자 이름을 입력하세요."),
+    (
+        "approval.error.self_approval",
+        "요청자 본인은 승인할 수 없습니다. 다른 사용자가 승인해야 합니다.",
+    ),
+    (
+        "approval.error.scenario_changed",
+        "승인 요청 뒤 시나리오가 바뀌었습니다. 실행을 다시 요청하세요.",
+    ),
+    (
+        "approval.error.bad_code",
+        "승인자 이름 또는 승인 코드가 올바르지 않습니다.",
+    ),
+    (
+        "approval.error.open_log",
+        "승인 기록 파일을 열 수 없습니다: {path}",
+    ),
+    (
+        "approval.error.read_approvers",
+        "승인자 목록 파일을 읽을 수 없습니다: {path}",
+    ),
+    (
+        "approval.error.approvers_writable",
+        "승인자 목록 파일을 다른 사용자가 수정할 수 있어 사용하지 않습니다: {path}",
+    ),
+    ("settings.error.read", "앱 설정 읽기 실패: {path}"),
+    ("settings.error.parse", "앱 설정 파싱 실패: {path}"),
+    ("settings.error.save", "앱 설정 저장 실패: {path}"),
+    ("expanded_yaml.error.save", "펼친 YAML 저장 실패: {path}"),
+    ("notify.error.http_only", "http:// 주소만 지원합니다."),
+    ("notify.error.connect", "접속 실패: {addr}"),
+    (
+        "notify.error.bad_response",
+        "응답을 해석할 수 없습니다: {line}",
+    ),
+    ("notify.error.status", "응답 상태 {status}"),
+    (
+        "daemon.error.read_token",
+        "데몬 토큰 파일({path})을 읽을 수 없습니다: {error}",
+    ),
+    (
+        "daemon.error.empty_token",
+        "데몬 토큰 파일이 비어 있습니다: {path}",
+    ),
+    (
+        "daemon.error.resolve",
+        "데몬 주소를 해석할 수 없습니다: {addr}",
+    ),
+    (
+        "daemon.error.not_loopback",
+        "데몬은 루프백 주소(127.0.0.1, ::1)에서만 대기할 수 있습니다: {addr} → {remote}",
+    ),
+    ("daemon.client.closed", "데몬이 연결을 종료했습니다."),
+    (
+        "daemon.client.connect_failed",
+        "데몬({addr})에 접속할 수 없습니다: {error}",
+    ),
+    (
+        "daemon.client.not_connected",
+        "데몬에 연결되어 있지 않아 요청을 보내지 못했습니다.",
+    ),
+    ("daemon.error.bad_token", "데몬 토큰이 맞지 않습니다."),
+    ("daemon.error.bad_request", "잘못된 요청: {error}"),
+    (
+        "daemon.error.already_running",
+        "이미 실행 중인 시나리오가 있습니다.",
+    ),
+    (
+        "daemon.error.unknown_confirm",
+        "이미 처리되었거나 존재하지 않는 컨펌 요청입니다: {id}",
+    ),
+    (
+        "trigger.error.no_trigger",
+        "시나리오에 trigger 설정이 없습니다.",
+    ),
+    (
+        "trigger.error.bad_pattern",
+        "trigger.pattern 정규식 오류: {pattern}",
+    ),
+    (
+        "service.error.unknown_command",
+        "알 수 없는 서비스 명령입니다: {command} (install, uninstall, start 중 하나)",
+    ),
+    (
+        "service.error.privileged_account",
+        "서비스를 {account} 계정으로 등록할 수 없습니다. 전용 저권한 계정을 --account로 지정하세요.",
+    ),
+    (
+        "service.error.windows_only",
+        "서비스 모드는 Windows에서만 쓸 수 있습니다. 다른 운영체제에서는 --daemon을 systemd 등으로 띄우세요.",
+    ),
+    (
+        "service.error.manager",
+        "서비스 관리자 접속 실패(관리자 권한으로 실행하세요)",
+    ),
+    ("service.error.create", "서비스 등록 실패"),
+    (
+        "service.installed",
+        "서비스 실행 계정: {account}, 접속 토큰 파일: {token_file}",
+    ),
+    ("service.error.not_found", "등록된 서비스를 찾지 못했습니다"),
+    ("service.error.stop", "서비스 중지 실패"),
+    ("service.error.delete", "서비스 삭제 실패"),
+    ("service.error.start", "서비스 시작 실패"),
+    (
+        "service.error.dispatcher",
+        "서비스 제어 관리자 연결 실패(--service run은 서비스로만 실행됩니다)",
+    ),
+    ("executor.dummy.execute", "[DummyExecutor] SQL 실행: {sql}"),
+    ("executor.dummy.query", "[DummyExecutor] SQL 조회: {sql}"),
+    (
+        "executor.dummy.preview",
+        "[DummyExecutor] SQL 미리보기(최대 {max_rows}행): {sql}",
+    ),
+    (
+        "executor.dummy.columns",
+        "[DummyExecutor] 컬럼 조회: {table}",
+    ),
+    ("search.running", "검색 중…"),
+    ("search.aborted", "검색 작업이 중단되었습니다."),
+    (
+        "builder.included.remove",
+        "🗑 include 항목 삭제 (Step {count}개)",
+    ),
+    (
+        "builder.included.remove_hover",
+        "이 조각 파일에서 펼친 Step을 모두 지워 저장할 때 include 항목이 빠지게 합니다. 지운 Step은 휴지통에서 일반 Step으로 되살릴 수 있습니다.",
+    ),
+    (
+        "builder.paste.skipped_db",
+        "빌더가 편집할 수 없는 DB 연결이라 옮기지 못했습니다. 시나리오 YAML에 직접 추가하세요: {reasons}",
+    ),
+    (
+        "engine.error.priority_tool_missing",
+        "priority를 적용할 `{tool}` 명령을 PATH에서 찾을 수 없습니다. util-linux(taskset·ionice)나 coreutils(nice)를 설치하거나 priority 설정을 빼세요.",
+    ),
+    (
+        "engine.error.sqlldr_stdin",
+        "sqlldr에 접속 문자열 전달 실패",
+    ),
+    ("dashboard.aborted", "실행 이력 통계 계산이 중단되었습니다."),
+    ("dashboard.loading", "실행 이력을 읽는 중..."),
+    ("service.display_name", "Rust Airflow 엔진 데몬"),
+    (
+        "service.description",
+        "로그온한 사용자가 없어도 트리거 감시와 배치 실행을 맡는 엔진 데몬입니다.",
+    ),
+    ("service.error.no_launch", "서비스 구성이 없습니다"),
+    ("service.error.runtime", "Tokio 런타임 생성 실패"),
+    (
+        "scenario.error.profile_missing",
+        "시나리오에 `{name}` 실행 프로필이 없습니다",
+    ),
+    (
+        "scenario.error.profile_requires_approval",
+        "`{name}` 실행 프로필은 승인이 필요하므로 창 없이 실행할 수 없습니다. GUI에서 승인을 받아 실행하세요",
+    ),
+];
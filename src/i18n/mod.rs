@@ -0,0 +1,139 @@
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+mod en;
+mod ko;
+
+/// 시작 언어를 지정하는 환경 변수 이름이다. (`ko`/`en`)
+pub const LANG_ENV: &str = "RUST_AIRFLOW_LANG";
+
+/// 현재 언어 값이다. [`Language::index`]로 저장한다.
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// 한국어 카탈로그 조회 맵이다.
+static KO: Lazy<HashMap<&'static str, &'static str>> =
+    Lazy::new(|| ko::MESSAGES.iter().copied().collect());
+
+/// 영어 카탈로그 조회 맵이다.
+static EN: Lazy<HashMap<&'static str, &'static str>> =
+    Lazy::new(|| en::MESSAGES.iter().copied().collect());
+
+/// UI, 엔진 로그, 검증 오류에 사용할 언어이다. 설정 파일에는 언어 코드(`ko`/`en`)로 저장한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    /// 한국어.
+    #[serde(rename = "ko")]
+    Korean,
+    /// 영어.
+    #[serde(rename = "en")]
+    English,
+}
+
+impl Default for Language {
+    /// 기본 언어는 한국어이다.
+    fn default() -> Self {
+        Language::Korean
+    }
+}
+
+impl Language {
+    /// 선택 가능한 모든 언어 목록이다.
+    pub const ALL: [Language; 2] = [Language::Korean, Language::English];
+
+    /// 언어 선택 UI에 표시할 이름을 반환한다.
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::Korean => "한국어",
+            Language::English => "English",
+        }
+    }
+
+    /// `ko`, `en` 같은 언어 코드로 언어를 찾는다.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_ascii_lowercase().as_str() {
+            "ko" | "ko-kr" | "korean" => Some(Language::Korean),
+            "en" | "en-us" | "english" => Some(Language::English),
+            _ => None,
+        }
+    }
+
+    /// 전역 상태 저장용 번호를 반환한다.
+    fn index(self) -> u8 {
+        match self {
+            Language::Korean => 0,
+            Language::English => 1,
+        }
+    }
+}
+
+/// `RUST_AIRFLOW_LANG` 환경 변수가 있으면 시작 언어로 적용한다.
+pub fn init_from_env() {
+    if let Some(language) = std::env::var(LANG_ENV)
+        .ok()
+        .and_then(|code| Language::from_code(&code))
+    {
+        set_language(language);
+    }
+}
+
+/// 현재 언어를 바꾼다. 다음 프레임부터 모든 문자열에 반영된다.
+pub fn set_language(language: Language) {
+    CURRENT.store(language.index(), Ordering::Relaxed);
+}
+
+/// 현재 언어를 반환한다.
+pub fn language() -> Language {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Language::English,
+        _ => Language::Korean,
+    }
+}
+
+/// 키에 해당하는 현재 언어 문자열을 반환한다.
+///
+/// 영어 카탈로그에 없으면 한국어로, 한국어에도 없으면 키를 그대로 반환한다.
+pub fn t(key: &'static str) -> &'static str {
+    let found = match language() {
+        Language::English => EN.get(key).or_else(|| KO.get(key)),
+        Language::Korean => KO.get(key),
+    };
+    found.copied().unwrap_or(key)
+}
+
+/// 키에 해당하는 문자열의 `{이름}` 자리표시자를 인자로 치환한다.
+///
+/// 템플릿을 한 번만 훑으므로 인자 값에 `{이름}` 같은 글자가 있어도 다시 치환하지 않는다.
+/// 인자에 없는 자리표시자는 그대로 남긴다.
+///
+/// # 매개변수
+/// - `key`: 카탈로그 키.
+/// - `args`: `(이름, 값)` 목록.
+pub fn tf(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    let mut rest = t(key);
+    let mut text = String::with_capacity(rest.len());
+    while let Some(open) = rest.find('{') {
+        text.push_str(&rest[..open]);
+        let tail = &rest[open + 1..];
+        let value = tail.find('}').and_then(|close| {
+            let name = &tail[..close];
+            args.iter()
+                .find(|(arg, _)| *arg == name)
+                .map(|(_, value)| (close, value))
+        });
+        match value {
+            Some((close, value)) => {
+                text.push_str(&value.to_string());
+                rest = &tail[close + 1..];
+            }
+            None => {
+                text.push('{');
+                rest = tail;
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
@@ -1,19 +1,146 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod app;
+mod connections;
+mod cost_check;
+mod daemon;
 mod editor;
 mod engine;
 mod executor;
+mod expanded_yaml;
+mod file_check;
+mod headless;
+mod history;
+mod i18n;
+mod notify;
+mod run_approval;
+mod run_dashboard;
 mod scenario;
+mod scenario_crypto;
+mod scenario_error;
+mod scenario_graph;
+mod scenario_lint;
+mod scenario_search;
+mod schedule_export;
+mod self_test;
+mod service;
+mod settings;
+mod sql_changes;
+mod sql_check;
 mod theme;
+mod workflow_import;
 
 use app::BatchOrchestratorApp;
 use eframe::{egui, egui_wgpu::wgpu};
 use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// egui 애플리케이션을 초기화하고 실행하는 진입점입니다.
+///
+/// `--daemon`으로 실행하면 창 없이 엔진 데몬만 띄우고, `--attach`로 실행하면 GUI가 데몬에 접속해
+/// 실행을 위임합니다. 데몬 주소는 `RUST_AIRFLOW_DAEMON_ADDR`로, 접속 토큰 파일은
+/// `RUST_AIRFLOW_DAEMON_TOKEN_FILE`로, 시작 언어는 `RUST_AIRFLOW_LANG`으로 바꿀 수 있으며
+/// `--daemon-addr <주소>`와 `--daemon-token-file <경로>`가 환경 변수보다 우선합니다.
+/// `--service install|uninstall|start`는 데몬(`--trigger`, 엔진 런타임 인자 포함)을 Windows 서비스로
+/// 등록·삭제·시작하며, `install`은 `--account <계정>`(기본: 가상 서비스 계정)으로 실행 계정을 정합니다.
+/// 서비스는 로그를 실행 로그 디렉터리의 파일에 남깁니다. 엔진 전용 런타임의 워커 수와 블로킹 스레드 상한은 `--engine-workers <N>`,
+/// `--engine-blocking-threads <N>`으로 지정합니다. 데몬은 `--trigger <시나리오 파일>`(여러 번 지정 가능)로
+/// 받은 시나리오의 트리거 파일을 감시합니다. `--run <시나리오 파일>`은 창 없이 시나리오를 한 번 실행하고
+/// 결과를 종료 코드로 알리며, `--export-schedule <시나리오 파일> --cron "<식>"`은 그 실행을 부르는 crontab
+/// 항목(`--format windows`면 작업 스케줄러 XML)을 출력합니다. `--self-test`는 합성 시나리오를 Dummy 실행기로
+/// 돌려 엔진 불변 조건을 점검하고 통과 여부를 종료 코드로 알립니다. `--lint <시나리오 파일>`은 시나리오를
+/// 실행하지 않고 점검하며, `--graph <시나리오 파일> --format dot|mermaid`는 같은 점검을 통과한 시나리오의 DAG를
+/// Graphviz DOT 또는 Mermaid로 출력합니다. `--run`은 `--param KEY=VALUE`로 시나리오
+/// 파라미터를 덮어쓰고, `--profile <이름>`으로 실행 프로필의 파라미터를 적용하며(승인이 필요한 프로필은
+/// 실행하지 않습니다), `--context-in <JSON>`으로 이전 실행의 컨텍스트 변수를 받아 시작하며
+/// `--context-out <JSON>`으로 끝난 시점의 컨텍스트 변수를 남깁니다. `--unattended`는 모든 컨펌을 각 Step의
+/// 기본 응답으로 바로 처리하고, `--attended`는 기본 응답이 있어도 반드시 응답을 받게 합니다(`--run`에서는
+/// 응답할 화면이 없으므로 컨펌 Step이 실패합니다). GUI에서는 이 값이 실행 툴바의 컨펌 방식 시작 값이 됩니다. `--export-schedule`은 이 인자들을
+/// 내보낸 실행 명령에 그대로 옮깁니다. 인자와 환경 변수로 지정하지 않은 언어와 엔진 런타임 구성은
+/// 앱 설정 파일(`settings.yaml`)의 값을 따릅니다.
 fn main() -> eframe::Result<()> {
-    tracing_subscriber::fmt::init();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let service_command = flag_values(&args, "--service").pop();
+    if service_command.as_deref() == Some("run") {
+        let dir = flag_values(&args, "--service-dir").pop().map(PathBuf::from);
+        service::init_service_process(dir.as_deref());
+    } else {
+        tracing_subscriber::fmt::init();
+    }
+    let app_settings = settings::load_app_settings().unwrap_or_else(|err| {
+        tracing::warn!("앱 설정 읽기 실패: {err:#}");
+        settings::AppSettings::default()
+    });
+    if let Some(language) = app_settings.language {
+        i18n::set_language(language);
+    }
+    i18n::init_from_env();
+
+    let runtime_config = engine::EngineRuntimeConfig::from_args(&args).or_defaults(
+        app_settings.engine_workers,
+        app_settings.engine_blocking_threads,
+    );
+    let daemon_token_file = flag_values(&args, "--daemon-token-file")
+        .pop()
+        .map(PathBuf::from);
+    let daemon_endpoint = daemon::DaemonEndpoint {
+        addr: flag_values(&args, "--daemon-addr")
+            .pop()
+            .unwrap_or_else(daemon::daemon_addr),
+        token_file: daemon_token_file
+            .clone()
+            .unwrap_or_else(daemon::daemon_token_path),
+    };
+    let triggers: Vec<PathBuf> = flag_values(&args, "--trigger")
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+    if let Some(command) = service_command {
+        let launch = service::ServiceLaunch {
+            addr: daemon_endpoint.addr,
+            token_file: daemon_token_file,
+            account: flag_values(&args, "--account").pop(),
+            triggers,
+            runtime_config,
+        };
+        std::process::exit(run_service_process(&command, launch));
+    }
+    if args.iter().any(|arg| arg == "--daemon") {
+        run_daemon_process(runtime_config, &daemon_endpoint, triggers);
+        return Ok(());
+    }
+    if let Some(path) = flag_values(&args, "--export-schedule").pop() {
+        std::process::exit(export_schedule_process(&args, Path::new(&path)));
+    }
+    if let Some(path) = flag_values(&args, "--lint").pop() {
+        std::process::exit(lint_process(Path::new(&path)));
+    }
+    if let Some(path) = flag_values(&args, "--graph").pop() {
+        std::process::exit(graph_process(&args, Path::new(&path)));
+    }
+    if args.iter().any(|arg| arg == "--self-test") {
+        std::process::exit(run_self_test_process(runtime_config));
+    }
+    let confirm_mode = match engine::ConfirmMode::from_args(&args) {
+        Ok(mode) => mode,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(2);
+        }
+    };
+    if let Some(path) = flag_values(&args, "--run").pop() {
+        std::process::exit(run_headless_process(
+            runtime_config,
+            confirm_mode,
+            &args,
+            Path::new(&path),
+        ));
+    }
+    let daemon_endpoint = args
+        .iter()
+        .any(|arg| arg == "--attach")
+        .then_some(daemon_endpoint);
 
     let native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -31,10 +158,278 @@ fn main() -> eframe::Result<()> {
     eframe::run_native(
         "Rust Batch Orchestrator",
         native_options,
-        Box::new(|cc| Box::new(BatchOrchestratorApp::new(cc))),
+        Box::new(move |cc| {
+            Box::new(BatchOrchestratorApp::new(
+                cc,
+                daemon_endpoint,
+                runtime_config,
+                app_settings,
+                confirm_mode,
+            ))
+        }),
     )
 }
 
+/// GUI 없이 엔진 데몬을 실행합니다. 바인딩에 실패하면 오류를 출력하고 종료합니다.
+///
+/// 데몬은 화면이 없으므로 접속 처리와 시나리오 실행 모두 엔진 런타임 구성을 따릅니다.
+fn run_daemon_process(
+    runtime_config: engine::EngineRuntimeConfig,
+    endpoint: &daemon::DaemonEndpoint,
+    triggers: Vec<PathBuf>,
+) {
+    let runtime = runtime_config.build().expect("Tokio 런타임 생성 실패");
+    let executor: executor::SharedExecutor = Arc::new(executor::DummyExecutor);
+    if let Err(err) = runtime.block_on(daemon::run_daemon(endpoint, executor, triggers)) {
+        tracing::error!("데몬 실행 실패: {err}");
+        std::process::exit(1);
+    }
+}
+
+/// Windows 서비스 하위 명령을 실행하고 종료 코드를 반환합니다.
+///
+/// 성공하면 0, 서비스 관리자 작업이 실패하면 1, 하위 명령이 잘못되었으면 2입니다.
+fn run_service_process(command: &str, launch: service::ServiceLaunch) -> i32 {
+    let command = match service::ServiceCommand::parse(command) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{err}");
+            return 2;
+        }
+    };
+    match service::run_service_command(command, launch) {
+        Ok(()) => {
+            match command {
+                service::ServiceCommand::Install => println!(
+                    "{}",
+                    i18n::tf("cli.service_installed", &[("name", &service::SERVICE_NAME)])
+                ),
+                service::ServiceCommand::Uninstall => println!(
+                    "{}",
+                    i18n::tf(
+                        "cli.service_uninstalled",
+                        &[("name", &service::SERVICE_NAME)]
+                    )
+                ),
+                service::ServiceCommand::Start => println!(
+                    "{}",
+                    i18n::tf("cli.service_started", &[("name", &service::SERVICE_NAME)])
+                ),
+                service::ServiceCommand::Run => {}
+            }
+            0
+        }
+        Err(err) => {
+            tracing::error!(
+                "{}",
+                i18n::tf("cli.service_failed", &[("error", &format!("{err:#}"))])
+            );
+            1
+        }
+    }
+}
+
+/// 시나리오를 창 없이 한 번 실행하고 종료 코드를 반환합니다.
+///
+/// 모든 Step이 성공하면 0, 실패한 Step이 있으면 1, 인자나 시나리오 파일이 잘못되었으면 2입니다.
+fn run_headless_process(
+    runtime_config: engine::EngineRuntimeConfig,
+    confirm_mode: engine::ConfirmMode,
+    args: &[String],
+    path: &Path,
+) -> i32 {
+    let context_in = flag_values(args, "--context-in").pop().map(PathBuf::from);
+    let context_out = flag_values(args, "--context-out").pop().map(PathBuf::from);
+    let profile = flag_values(args, "--profile").pop();
+    let result = param_args(args).and_then(|params| {
+        headless::run_headless(
+            runtime_config,
+            path,
+            profile.as_deref(),
+            &params,
+            context_in.as_deref(),
+            context_out,
+            confirm_mode,
+        )
+    });
+    match result {
+        Ok(true) => 0,
+        Ok(false) => {
+            tracing::error!(
+                "{}",
+                i18n::tf("cli.run_failed", &[("path", &path.display())])
+            );
+            1
+        }
+        Err(err) => {
+            tracing::error!(
+                "{}",
+                i18n::tf("cli.run_unavailable", &[("error", &format!("{err:#}"))])
+            );
+            2
+        }
+    }
+}
+
+/// 합성 시나리오로 엔진을 자가 점검하고 보고서를 표준 출력으로 내보낸 뒤 종료 코드를 반환합니다.
+///
+/// 모든 점검 항목이 통과하면 0, 하나라도 실패하면 1, 점검을 준비하지 못했으면 2입니다.
+fn run_self_test_process(runtime_config: engine::EngineRuntimeConfig) -> i32 {
+    match self_test::run_self_test(runtime_config) {
+        Ok((report, path)) => {
+            print!("{}", report.to_text());
+            if let Some(path) = path {
+                println!(
+                    "{}",
+                    i18n::tf("cli.report_path", &[("path", &path.display())])
+                );
+            }
+            if report.passed() { 0 } else { 1 }
+        }
+        Err(err) => {
+            eprintln!(
+                "{}",
+                i18n::tf("cli.self_test_failed", &[("error", &format!("{err:#}"))])
+            );
+            2
+        }
+    }
+}
+
+/// `--run` 실행을 부르는 스케줄 항목을 표준 출력으로 내보내고 종료 코드를 반환합니다.
+fn export_schedule_process(args: &[String], path: &Path) -> i32 {
+    let cron = flag_values(args, "--cron").pop();
+    let format = flag_values(args, "--format").pop();
+    let context_in = flag_values(args, "--context-in").pop().map(PathBuf::from);
+    let context_out = flag_values(args, "--context-out").pop().map(PathBuf::from);
+    let profile = flag_values(args, "--profile").pop();
+    let result = param_args(args).and_then(|params| {
+        schedule_export::export_scenario_schedule(
+            path,
+            cron.as_deref(),
+            format.as_deref(),
+            profile.as_deref(),
+            &params,
+            [("--context-in", context_in), ("--context-out", context_out)],
+        )
+    });
+    match result {
+        Ok(entry) => {
+            print!("{entry}");
+            0
+        }
+        Err(err) => {
+            eprintln!(
+                "{}",
+                i18n::tf("cli.schedule_failed", &[("error", &format!("{err:#}"))])
+            );
+            2
+        }
+    }
+}
+
+/// 시나리오 파일을 점검해 문제를 출력하고 종료 코드를 반환합니다.
+///
+/// 오류가 없으면 0, 오류가 있으면 1, 시나리오를 읽지 못하면 2입니다. 경고만 있으면 0입니다.
+fn lint_process(path: &Path) -> i32 {
+    let scenario = match scenario::load_scenario_from_file(path) {
+        Ok(scenario) => scenario,
+        Err(err) => {
+            eprintln!(
+                "{}",
+                i18n::tf("cli.load_failed", &[("error", &format!("{err:#}"))])
+            );
+            return 2;
+        }
+    };
+    let report = scenario_lint::lint_scenario(&scenario);
+    for issue in &report.issues {
+        println!("{issue}");
+    }
+    println!(
+        "{}",
+        i18n::tf(
+            "cli.lint_summary",
+            &[
+                ("path", &path.display()),
+                ("errors", &report.error_count()),
+                ("warnings", &report.warning_count()),
+            ],
+        )
+    );
+    if report.error_count() > 0 { 1 } else { 0 }
+}
+
+/// 시나리오 DAG를 표준 출력으로 내보내고 종료 코드를 반환합니다.
+///
+/// `--lint`와 같은 점검을 먼저 돌려 문제는 표준 오류로 알리고, 오류가 있으면 그래프를 내보내지 않고 1을
+/// 반환합니다. 시나리오를 읽지 못하거나 `--format` 값이 잘못되면 2입니다.
+fn graph_process(args: &[String], path: &Path) -> i32 {
+    let format =
+        match scenario_graph::GraphFormat::parse(flag_values(args, "--format").pop().as_deref()) {
+            Ok(format) => format,
+            Err(err) => {
+                eprintln!(
+                    "{}",
+                    i18n::tf("cli.graph_failed", &[("error", &format!("{err:#}"))])
+                );
+                return 2;
+            }
+        };
+    let scenario = match scenario::load_scenario_from_file(path) {
+        Ok(scenario) => scenario,
+        Err(err) => {
+            eprintln!(
+                "{}",
+                i18n::tf("cli.load_failed", &[("error", &format!("{err:#}"))])
+            );
+            return 2;
+        }
+    };
+    let report = scenario_lint::lint_scenario(&scenario);
+    for issue in &report.issues {
+        eprintln!("{issue}");
+    }
+    if report.error_count() > 0 {
+        eprintln!(
+            "{}",
+            i18n::tf("cli.graph_blocked", &[("errors", &report.error_count())])
+        );
+        return 1;
+    }
+    print!(
+        "{}",
+        scenario_graph::render_scenario_graph(&scenario, format)
+    );
+    0
+}
+
+/// `--param KEY=VALUE` 인자를 모두 `(이름, 값)`으로 나눕니다.
+fn param_args(args: &[String]) -> anyhow::Result<Vec<(String, String)>> {
+    flag_values(args, "--param")
+        .iter()
+        .map(|param| {
+            param
+                .split_once('=')
+                .filter(|(key, _)| !key.trim().is_empty())
+                .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+                .ok_or_else(|| anyhow::anyhow!(i18n::tf("cli.param_format", &[("param", param)])))
+        })
+        .collect()
+}
+
+/// `<flag> <값>` 인자(`<flag>=<값>` 형식도 허용)의 값을 순서대로 모두 모읍니다.
+fn flag_values(args: &[String], flag: &str) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter_map(|(idx, arg)| match arg.strip_prefix(flag) {
+            Some("") => args.get(idx + 1).cloned(),
+            Some(rest) => rest.strip_prefix('=').map(str::to_string),
+            None => None,
+        })
+        .collect()
+}
+
 /// 애플리케이션 아이콘을 ICO 파일에서 읽어 egui가 요구하는 포맷으로 변환합니다.
 fn load_icon_from_ico() -> egui::IconData {
     // 가장 큰 엔트리를 골라 RGBA로 변환
@@ -0,0 +1,137 @@
+use crate::i18n::{t, tf};
+use anyhow::Context;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// 웹훅 주소 하나에 알림을 보낼 때의 제한 시간이다.
+const NOTIFY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 실행이 끝났을 때 웹훅으로 보내는 알림 본문이다.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunNotification {
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 모든 Step이 성공했고 중지되지 않았는지 여부.
+    pub success: bool,
+    /// 중지되었는지 여부.
+    pub cancelled: bool,
+    /// 실패했거나 건너뛴 Step ID 목록.
+    pub failed_steps: Vec<String>,
+    /// 종료 시각(RFC 3339).
+    pub finished_at: String,
+}
+
+/// Step 하나의 `notify_url`로 보내는 알림 시점이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepNotifyEvent {
+    /// Step이 시작되었다.
+    Started,
+    /// Step이 성공했다.
+    Success,
+    /// Step이 실패했다.
+    Failure,
+}
+
+/// Step의 `notify_url`로 보내는 알림 본문이다.
+#[derive(Debug, Clone, Serialize)]
+pub struct StepNotification {
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 실행 ID.
+    pub run_id: String,
+    /// Step ID.
+    pub step_id: String,
+    /// Step 이름.
+    pub step_name: String,
+    /// 알림 시점.
+    pub event: StepNotifyEvent,
+    /// 실패했을 때의 오류 메시지. 비밀 값은 가린다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Step 결과 변수(`STEP.<id>.*`)와 Step이 읽거나 남기는 변수 중 알림 시점에 있는 값. 비밀 값은 가린다.
+    pub context: BTreeMap<String, String>,
+    /// 알림 시각(RFC 3339).
+    pub at: String,
+}
+
+/// Step 알림을 웹훅 주소 하나로 JSON POST한다. 보내지 못하면 경고 로그만 남긴다.
+pub async fn send_step_notification(endpoint: &str, notification: &StepNotification) {
+    let body = match serde_json::to_string(notification) {
+        Ok(body) => body,
+        Err(err) => {
+            tracing::warn!("Step 알림 직렬화 실패: {err}");
+            return;
+        }
+    };
+    match tokio::time::timeout(NOTIFY_TIMEOUT, post_json(endpoint, &body)).await {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => tracing::warn!("Step 알림 전송 실패({endpoint}): {err:#}"),
+        Err(_) => tracing::warn!("Step 알림 전송 시간 초과({endpoint})"),
+    }
+}
+
+/// 알림을 모든 웹훅 주소로 JSON POST한다. 보내지 못한 주소는 경고 로그만 남긴다.
+pub async fn send_run_notification(endpoints: &[String], notification: &RunNotification) {
+    let body = match serde_json::to_string(notification) {
+        Ok(body) => body,
+        Err(err) => {
+            tracing::warn!("실행 알림 직렬화 실패: {err}");
+            return;
+        }
+    };
+    for endpoint in endpoints {
+        let sent = tokio::time::timeout(NOTIFY_TIMEOUT, post_json(endpoint, &body)).await;
+        match sent {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => tracing::warn!("실행 알림 전송 실패({endpoint}): {err:#}"),
+            Err(_) => tracing::warn!("실행 알림 전송 시간 초과({endpoint})"),
+        }
+    }
+}
+
+/// `http://host[:port]/path` 주소로 JSON 본문을 POST하고 2xx 응답인지 확인한다.
+///
+/// TLS를 지원하지 않으므로 `https://` 주소는 사내 프록시나 중계기를 거쳐 `http://`로 지정해야 한다.
+async fn post_json(endpoint: &str, body: &str) -> anyhow::Result<()> {
+    let rest = endpoint
+        .trim()
+        .strip_prefix("http://")
+        .context(t("notify.error.http_only"))?;
+    let (authority, path) = match rest.find('/') {
+        Some(idx) => (&rest[..idx], &rest[idx..]),
+        None => (rest, "/"),
+    };
+    let addr = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+    let mut stream = TcpStream::connect(&addr)
+        .await
+        .with_context(|| tf("notify.error.connect", &[("addr", &addr)]))?;
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: {authority}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(request.as_bytes()).await?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).await?;
+    let status_line = String::from_utf8_lossy(&response)
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let status: u16 = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .with_context(|| tf("notify.error.bad_response", &[("line", &status_line)]))?;
+    if !(200..300).contains(&status) {
+        anyhow::bail!(tf("notify.error.status", &[("status", &status)]));
+    }
+    Ok(())
+}
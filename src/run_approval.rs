@@ -0,0 +1,257 @@
+use crate::daemon::token_matches;
+use crate::i18n::{t, tf};
+use crate::settings::user_data_dir;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+use std::path::PathBuf;
+
+/// 실행 승인 감사 기록 파일 경로를 지정하는 환경 변수 이름이다.
+pub const APPROVAL_FILE_ENV: &str = "RUST_AIRFLOW_APPROVAL_FILE";
+
+/// 환경 변수가 없을 때 사용자 데이터 디렉터리 아래에 두는 실행 승인 감사 기록 파일 이름이다.
+pub const DEFAULT_APPROVAL_FILE: &str = "run_approvals.jsonl";
+
+/// 승인자 목록 파일 경로를 지정하는 환경 변수 이름이다.
+pub const APPROVERS_FILE_ENV: &str = "RUST_AIRFLOW_APPROVERS_FILE";
+
+/// 승인된 실행의 승인 ID가 저장되는 변수 이름이다.
+pub const RUN_APPROVAL_ID_VAR: &str = "RUN_APPROVAL_ID";
+
+/// 승인된 실행의 승인자가 저장되는 변수 이름이다.
+pub const RUN_APPROVED_BY_VAR: &str = "RUN_APPROVED_BY";
+
+/// 승인된 실행의 승인 시각이 저장되는 변수 이름이다.
+pub const RUN_APPROVED_AT_VAR: &str = "RUN_APPROVED_AT";
+
+/// 실행 승인 감사 기록 한 줄이 나타내는 동작이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalAction {
+    /// 실행을 요청해 승인 대기 항목이 만들어졌다.
+    Requested,
+    /// 승인되어 실행을 시작했다.
+    Approved,
+    /// 승인자가 거절했다.
+    Rejected,
+    /// 요청자가 승인을 받기 전에 요청을 거뒀다.
+    Withdrawn,
+    /// 승인된 요청으로 실행이 시작되었다. `run_id`에 그 실행 ID가 남는다.
+    Started,
+}
+
+/// 실행 승인 감사 기록 파일의 한 줄이다. 요청과 그 결과가 같은 `id`로 이어진다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalRecord {
+    /// 승인 요청 ID.
+    pub id: String,
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 실행 프로필 이름.
+    pub profile: String,
+    /// 요청 시점 시나리오 YAML의 SHA-256(16진수). 승인은 이 내용의 시나리오에만 유효하다.
+    #[serde(default)]
+    pub scenario_sha256: String,
+    /// 이 동작.
+    pub action: ApprovalAction,
+    /// 동작을 한 사용자. 요청이면 요청자, 승인·거절이면 승인자이다.
+    pub user: String,
+    /// 기록 시각(RFC 3339).
+    pub at: String,
+    /// 승인 코드로 승인했는지 여부.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub with_code: bool,
+    /// 승인으로 시작한 실행 ID. `Started` 기록에만 있다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+}
+
+impl ApprovalRecord {
+    /// 이 승인으로 시작한 실행 ID를 감사 기록에 남긴다.
+    pub fn record_started(&self, run_id: &str) -> anyhow::Result<()> {
+        append_approval_record(&ApprovalRecord {
+            action: ApprovalAction::Started,
+            at: chrono::Local::now().to_rfc3339(),
+            run_id: Some(run_id.to_string()),
+            ..self.clone()
+        })
+    }
+}
+
+/// 승인을 기다리는 실행 요청이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalRequest {
+    /// 승인 요청 ID.
+    pub id: String,
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 실행 프로필 이름.
+    pub profile: String,
+    /// 요청 시점 시나리오 YAML의 SHA-256(16진수).
+    pub scenario_sha256: String,
+    /// 실행을 요청한 사용자.
+    pub requester: String,
+    /// 요청 시각(로컬 시간, `YYYY-MM-DD HH:MM:SS`).
+    pub requested_at: String,
+}
+
+impl ApprovalRequest {
+    /// 승인 요청을 만들고 감사 기록에 남긴다.
+    ///
+    /// `scenario_yaml`은 실행할 시나리오의 YAML이며, 승인은 이 내용 그대로일 때만 유효하다.
+    pub fn create(
+        scenario: &str,
+        scenario_yaml: &str,
+        profile: &str,
+        requester: &str,
+    ) -> anyhow::Result<Self> {
+        let now = chrono::Local::now();
+        let request = Self {
+            id: now.format("%Y%m%d%H%M%S%3f").to_string(),
+            scenario: scenario.to_string(),
+            profile: profile.to_string(),
+            scenario_sha256: sha256_hex(scenario_yaml),
+            requester: requester.to_string(),
+            requested_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        };
+        append_approval_record(&request.record(ApprovalAction::Requested, requester, false))?;
+        Ok(request)
+    }
+
+    /// 승인자와 승인 코드를 확인해 승인하고 감사 기록에 남긴다.
+    ///
+    /// 승인자는 요청자와 달라야 하고, 승인자 목록 파일([`approvers_path`])에 있는 그 승인자의 코드를
+    /// 입력해야 한다. 요청 뒤 시나리오 내용(`scenario_yaml`)이 바뀌었으면 승인하지 않는다.
+    ///
+    /// # 반환값
+    /// 남긴 승인 기록. 확인에 실패하면 아무것도 기록하지 않고 오류를 반환한다.
+    pub fn approve(
+        &self,
+        approver: &str,
+        code: &str,
+        scenario_yaml: &str,
+    ) -> anyhow::Result<ApprovalRecord> {
+        let approver = approver.trim();
+        if approver.is_empty() {
+            anyhow::bail!(t("approval.error.no_approver"));
+        }
+        if approver.eq_ignore_ascii_case(self.requester.trim()) {
+            anyhow::bail!(t("approval.error.self_approval"));
+        }
+        if sha256_hex(scenario_yaml) != self.scenario_sha256 {
+            anyhow::bail!(t("approval.error.scenario_changed"));
+        }
+        if !approver_code_matches(approver, code)? {
+            anyhow::bail!(t("approval.error.bad_code"));
+        }
+        let record = self.record(ApprovalAction::Approved, approver, true);
+        append_approval_record(&record)?;
+        Ok(record)
+    }
+
+    /// 요청을 거절하거나(`Rejected`) 거둔(`Withdrawn`) 것을 감사 기록에 남긴다.
+    pub fn close(&self, action: ApprovalAction, user: &str) -> anyhow::Result<()> {
+        append_approval_record(&self.record(action, user, false))
+    }
+
+    /// 이 요청에 대한 감사 기록 한 줄을 만든다.
+    fn record(&self, action: ApprovalAction, user: &str, with_code: bool) -> ApprovalRecord {
+        ApprovalRecord {
+            id: self.id.clone(),
+            scenario: self.scenario.clone(),
+            profile: self.profile.clone(),
+            scenario_sha256: self.scenario_sha256.clone(),
+            action,
+            user: user.to_string(),
+            at: chrono::Local::now().to_rfc3339(),
+            with_code,
+            run_id: None,
+        }
+    }
+}
+
+/// 실행 승인 감사 기록 파일 경로를 반환한다. 기본값은 사용자 데이터 디렉터리 아래이다.
+pub fn approval_path() -> PathBuf {
+    std::env::var(APPROVAL_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| user_data_dir().join(DEFAULT_APPROVAL_FILE))
+}
+
+/// 승인자 목록 파일 경로를 반환한다.
+///
+/// 요청자가 승인자를 만들어 넣을 수 없도록 기본값은 관리자만 쓸 수 있는 시스템 경로이다.
+/// Unix는 `/etc/rust-airflow/approvers`, Windows는 `%ProgramData%\rust-airflow\approvers`이다.
+pub fn approvers_path() -> PathBuf {
+    if let Ok(path) = std::env::var(APPROVERS_FILE_ENV) {
+        return PathBuf::from(path);
+    }
+    #[cfg(windows)]
+    let base = std::env::var_os("ProgramData")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"));
+    #[cfg(not(windows))]
+    let base = PathBuf::from("/etc");
+    base.join("rust-airflow").join("approvers")
+}
+
+/// 감사 기록을 파일 끝에 JSON 한 줄로 추가한다. 기존 줄은 고치거나 지우지 않는다.
+fn append_approval_record(record: &ApprovalRecord) -> anyhow::Result<()> {
+    use std::io::Write;
+    let path = approval_path();
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| tf("approval.error.open_log", &[("path", &path.display())]))?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// 승인자 목록 파일에서 승인자의 항목을 찾아 입력한 코드가 맞는지 확인한다.
+///
+/// 파일은 한 줄에 `<승인자> <솔트> <SHA-256(솔트 + 코드) 16진수>`이며 `#`으로 시작하는 줄은 주석이다.
+/// Unix에서는 그룹이나 다른 사용자가 쓸 수 있는 파일을 믿지 않는다.
+fn approver_code_matches(approver: &str, code: &str) -> anyhow::Result<bool> {
+    let path = approvers_path();
+    let content = std::fs::read_to_string(&path).with_context(|| {
+        tf(
+            "approval.error.read_approvers",
+            &[("path", &path.display())],
+        )
+    })?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if std::fs::metadata(&path)?.permissions().mode() & 0o022 != 0 {
+            anyhow::bail!(tf(
+                "approval.error.approvers_writable",
+                &[("path", &path.display())],
+            ));
+        }
+    }
+    let entry = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some((fields.next()?, fields.next()?, fields.next()?))
+        })
+        .find(|(user, _, _)| user.eq_ignore_ascii_case(approver));
+    let Some((_, salt, expected)) = entry else {
+        return Ok(false);
+    };
+    let actual = sha256_hex(&format!("{salt}{}", code.trim()));
+    Ok(token_matches(&expected.to_ascii_lowercase(), &actual))
+}
+
+/// 텍스트의 SHA-256 해시를 16진수 소문자로 반환한다.
+fn sha256_hex(text: &str) -> String {
+    format!("{:x}", sha2::Sha256::digest(text.as_bytes()))
+}
@@ -0,0 +1,343 @@
+use crate::history::{RunNote, StepRunRecord, load_records, load_run_notes};
+use chrono::{DateTime, Local, NaiveDate};
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+
+/// 실패가 많은 Step과 오래 걸리는 Step 순위에 표시할 최대 개수이다.
+pub const DASHBOARD_TOP_STEPS: usize = 10;
+
+/// 대시보드에서 고를 수 있는 집계 기간(일)이다.
+pub const DASHBOARD_PERIODS: [u32; 3] = [7, 30, 90];
+
+/// 실행 이력 전체를 실행 단위로 묶어 일별 추이와 Step 순위를 계산한 대시보드 데이터이다.
+#[derive(Debug, Clone, Default)]
+pub struct RunDashboard {
+    /// 기간 안의 날짜별 집계. 오래된 날부터 오늘까지 실행이 없는 날도 포함한다.
+    pub days: Vec<DailyRunStats>,
+    /// 실패한 적이 있는 Step을 실패 횟수가 많은 순으로 나열한 목록.
+    pub failing_steps: Vec<StepRanking>,
+    /// 평균 소요 시간이 긴 순으로 나열한 Step 목록.
+    pub longest_steps: Vec<StepRanking>,
+    /// 이력에 기록된 모든 시나리오 이름. 필터 목록에 쓴다.
+    pub scenarios: Vec<String>,
+    /// 기간 안의 실행 목록. 최근 실행부터.
+    pub runs: Vec<RunEntry>,
+}
+
+/// 하루 동안의 실행 집계이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyRunStats {
+    /// 날짜(로컬 시간 기준 실행 종료일).
+    pub date: NaiveDate,
+    /// 실행 수.
+    pub runs: u32,
+    /// 모든 Step이 성공한 실행 수.
+    pub successes: u32,
+    /// 실행 소요 시간 합계.
+    pub total_duration: Duration,
+}
+
+impl DailyRunStats {
+    /// 성공률(0.0~1.0)을 반환한다. 실행이 없으면 `None`이다.
+    pub fn success_rate(&self) -> Option<f32> {
+        (self.runs > 0).then(|| self.successes as f32 / self.runs as f32)
+    }
+
+    /// 실행 평균 소요 시간을 반환한다. 실행이 없으면 `None`이다.
+    pub fn avg_duration(&self) -> Option<Duration> {
+        (self.runs > 0).then(|| self.total_duration / self.runs)
+    }
+}
+
+/// 실행 목록 한 줄이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunEntry {
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// 실행 ID. 실행 ID가 없던 예전 레코드이면 `None`이며 메모를 붙일 수 없다.
+    pub run_id: Option<String>,
+    /// 실행 종료 시각(로컬 시간).
+    pub finished_at: DateTime<Local>,
+    /// 모든 Step이 성공했는지 여부.
+    pub success: bool,
+    /// 실행 소요 시간.
+    pub duration: Duration,
+    /// 실행에 붙인 메모와 태그.
+    pub note: Option<RunNote>,
+}
+
+impl RunEntry {
+    /// 시나리오 이름이나 메모·태그에 검색어가 들어 있는지 확인한다. 빈 검색어는 모든 실행과 맞는다.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim();
+        query.is_empty()
+            || self.scenario.to_lowercase().contains(&query.to_lowercase())
+            || self.note.as_ref().is_some_and(|note| note.matches(query))
+    }
+}
+
+/// Step 순위 한 줄이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepRanking {
+    /// 시나리오 이름.
+    pub scenario: String,
+    /// Step ID.
+    pub step_id: String,
+    /// 기간 안의 실행 수.
+    pub runs: u32,
+    /// 그중 실패한 실행 수.
+    pub failures: u32,
+    /// 평균 소요 시간.
+    pub avg: Duration,
+    /// 최대 소요 시간.
+    pub max: Duration,
+}
+
+impl RunDashboard {
+    /// 실행 이력 파일을 읽어 대시보드 데이터를 계산한다.
+    ///
+    /// # 매개변수
+    /// - `scenario`: 집계할 시나리오 이름. `None`이면 모든 시나리오를 합친다.
+    /// - `days`: 오늘을 포함해 집계할 일수.
+    pub fn load(scenario: Option<&str>, days: u32) -> Self {
+        let records = load_records();
+        let today = Local::now().date_naive();
+        let first_day = today - chrono::Duration::days(i64::from(days.max(1)) - 1);
+        let scenarios: BTreeSet<&str> = records
+            .iter()
+            .map(|record| record.scenario.as_str())
+            .collect();
+        let mut daily: Vec<DailyRunStats> = first_day
+            .iter_days()
+            .take_while(|date| *date <= today)
+            .map(|date| DailyRunStats {
+                date,
+                runs: 0,
+                successes: 0,
+                total_duration: Duration::ZERO,
+            })
+            .collect();
+        let mut notes = load_run_notes();
+        let mut entries: Vec<RunEntry> = Vec::new();
+        let mut steps: HashMap<(&str, &str), StepAccumulator> = HashMap::new();
+        let runs = group_runs(
+            records
+                .iter()
+                .filter(|record| scenario.is_none_or(|name| record.scenario == name)),
+        );
+        for run in runs {
+            let Some(summary) = RunSummary::new(&run) else {
+                continue;
+            };
+            let Some(day) = daily.iter_mut().find(|day| day.date == summary.date) else {
+                continue;
+            };
+            day.runs += 1;
+            if summary.success {
+                day.successes += 1;
+            }
+            day.total_duration += summary.duration;
+            let first = run[0];
+            entries.push(RunEntry {
+                scenario: first.scenario.clone(),
+                run_id: first.run_id.clone(),
+                finished_at: summary.end,
+                success: summary.success,
+                duration: summary.duration,
+                note: first
+                    .run_id
+                    .as_ref()
+                    .and_then(|run_id| notes.remove(&(first.scenario.clone(), run_id.clone()))),
+            });
+            for record in run {
+                steps
+                    .entry((record.scenario.as_str(), record.step_id.as_str()))
+                    .or_default()
+                    .add(record);
+            }
+        }
+        let rankings: Vec<StepRanking> = steps
+            .into_iter()
+            .map(|((scenario, step_id), acc)| acc.ranking(scenario, step_id))
+            .collect();
+        let mut failing_steps: Vec<StepRanking> = rankings
+            .iter()
+            .filter(|ranking| ranking.failures > 0)
+            .cloned()
+            .collect();
+        failing_steps.sort_by(|a, b| {
+            b.failures
+                .cmp(&a.failures)
+                .then(a.runs.cmp(&b.runs))
+                .then_with(|| (&a.scenario, &a.step_id).cmp(&(&b.scenario, &b.step_id)))
+        });
+        failing_steps.truncate(DASHBOARD_TOP_STEPS);
+        let mut longest_steps = rankings;
+        longest_steps.sort_by(|a, b| {
+            b.avg
+                .cmp(&a.avg)
+                .then_with(|| (&a.scenario, &a.step_id).cmp(&(&b.scenario, &b.step_id)))
+        });
+        longest_steps.truncate(DASHBOARD_TOP_STEPS);
+        entries.sort_by(|a, b| b.finished_at.cmp(&a.finished_at));
+        Self {
+            days: daily,
+            failing_steps,
+            longest_steps,
+            scenarios: scenarios.into_iter().map(str::to_string).collect(),
+            runs: entries,
+        }
+    }
+
+    /// 기간 전체의 실행 수를 반환한다.
+    pub fn total_runs(&self) -> u32 {
+        self.days.iter().map(|day| day.runs).sum()
+    }
+
+    /// 기간 전체를 하루로 합친 집계를 반환한다. 날짜는 기간의 마지막 날이다.
+    pub fn total(&self) -> Option<DailyRunStats> {
+        let last = self.days.last()?;
+        Some(DailyRunStats {
+            date: last.date,
+            runs: self.total_runs(),
+            successes: self.days.iter().map(|day| day.successes).sum(),
+            total_duration: self.days.iter().map(|day| day.total_duration).sum(),
+        })
+    }
+}
+
+/// 시나리오의 실행 중 실행 전체가 성공한 가장 최근 실행의 종료 시각과 실행 ID를 찾는다.
+///
+/// 취소되었거나 실패·건너뜀 Step이 있는 실행은 끝난 Step이 모두 성공했어도 성공으로 보지 않는다.
+///
+/// 이력 파일이 없거나 성공한 실행이 없으면 `None`이다.
+pub fn last_successful_run(scenario: &str) -> Option<(DateTime<Local>, Option<String>)> {
+    let records = load_records();
+    group_runs(records.iter().filter(|record| record.scenario == scenario))
+        .into_iter()
+        .filter_map(|run| {
+            let summary = RunSummary::new(&run)?;
+            summary
+                .success
+                .then(|| (summary.end, run[0].run_id.clone()))
+        })
+        .max_by_key(|(end, _)| *end)
+}
+
+/// 이력 레코드를 실행 단위로 묶는다.
+///
+/// `run_id`가 있으면 시나리오와 ID가 같은 레코드끼리 묶는다. `run_id`가 없는 예전 레코드는 실행마다
+/// 한 번에 이어 쓰였으므로, 같은 시나리오의 연속된 줄을 같은 Step ID가 다시 나올 때까지 한 실행으로 본다.
+fn group_runs<'a>(records: impl Iterator<Item = &'a StepRunRecord>) -> Vec<Vec<&'a StepRunRecord>> {
+    let mut runs: Vec<Vec<&StepRunRecord>> = Vec::new();
+    let mut by_id: HashMap<(&str, &str), usize> = HashMap::new();
+    let mut legacy: Option<usize> = None;
+    for record in records {
+        match &record.run_id {
+            Some(run_id) => {
+                legacy = None;
+                let idx = *by_id
+                    .entry((record.scenario.as_str(), run_id.as_str()))
+                    .or_insert_with(|| {
+                        runs.push(Vec::new());
+                        runs.len() - 1
+                    });
+                runs[idx].push(record);
+            }
+            None => {
+                let joins = legacy.is_some_and(|idx| {
+                    runs[idx].iter().all(|prev| {
+                        prev.scenario == record.scenario && prev.step_id != record.step_id
+                    })
+                });
+                match legacy {
+                    Some(idx) if joins => runs[idx].push(record),
+                    _ => {
+                        runs.push(vec![record]);
+                        legacy = Some(runs.len() - 1);
+                    }
+                }
+            }
+        }
+    }
+    runs
+}
+
+/// 실행 하나의 결과 요약이다.
+struct RunSummary {
+    /// 실행 종료일(로컬 시간).
+    date: NaiveDate,
+    /// 마지막 Step 종료 시각(로컬 시간).
+    end: DateTime<Local>,
+    /// 실행 전체가 성공했는지 여부.
+    success: bool,
+    /// 첫 Step 시작부터 마지막 Step 종료까지 걸린 시간.
+    duration: Duration,
+}
+
+impl RunSummary {
+    /// 실행의 레코드로 요약을 만든다. 종료 시각을 해석할 수 있는 레코드가 없으면 `None`이다.
+    ///
+    /// 성공 여부는 기록된 실행 결과(`run_success`)를 따른다. 그 값이 없는 예전 레코드는 모든 Step이
+    /// 성공했는지로 판단한다.
+    fn new(records: &[&StepRunRecord]) -> Option<Self> {
+        let mut start: Option<DateTime<Local>> = None;
+        let mut end: Option<DateTime<Local>> = None;
+        for record in records {
+            let Ok(finished) = DateTime::parse_from_rfc3339(&record.finished_at) else {
+                continue;
+            };
+            let finished = finished.with_timezone(&Local);
+            let started = finished - chrono::Duration::milliseconds(record.duration_ms as i64);
+            start = Some(start.map_or(started, |current| current.min(started)));
+            end = Some(end.map_or(finished, |current| current.max(finished)));
+        }
+        let (start, end) = (start?, end?);
+        Some(Self {
+            date: end.date_naive(),
+            end,
+            success: records
+                .iter()
+                .find_map(|record| record.run_success)
+                .unwrap_or_else(|| records.iter().all(|record| record.success)),
+            duration: (end - start).to_std().unwrap_or_default(),
+        })
+    }
+}
+
+/// Step 순위 계산을 위해 실행 결과를 누적한다.
+#[derive(Default)]
+struct StepAccumulator {
+    /// 실행 수.
+    runs: u32,
+    /// 실패 수.
+    failures: u32,
+    /// 소요 시간 합계(밀리초).
+    total_ms: u64,
+    /// 최대 소요 시간(밀리초).
+    max_ms: u64,
+}
+
+impl StepAccumulator {
+    /// 레코드 하나를 누적한다.
+    fn add(&mut self, record: &StepRunRecord) {
+        self.runs += 1;
+        if !record.success {
+            self.failures += 1;
+        }
+        self.total_ms += record.duration_ms;
+        self.max_ms = self.max_ms.max(record.duration_ms);
+    }
+
+    /// 누적 결과로 순위 줄을 만든다.
+    fn ranking(&self, scenario: &str, step_id: &str) -> StepRanking {
+        StepRanking {
+            scenario: scenario.to_string(),
+            step_id: step_id.to_string(),
+            runs: self.runs,
+            failures: self.failures,
+            avg: Duration::from_millis(self.total_ms / u64::from(self.runs.max(1))),
+            max: Duration::from_millis(self.max_ms),
+        }
+    }
+}
@@ -1,21 +1,99 @@
+use crate::i18n::{t, tf};
+use crate::scenario_crypto::{decrypt, is_encrypted_path, resolve_passphrase};
+use crate::scenario_error::ScenarioParseError;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime};
 use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::File;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
 /// 시나리오에서 사용 가능한 DB 연결 정의이다.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `db_ref`를 지정하면 나머지 필드 대신 공유 연결 저장소(`connections.yaml`)의 같은 이름 항목을 사용한다.
+/// `db_ref`가 없으면 `kind`를 반드시 적어야 하며, 빠뜨린 정의가 조용히 더미 실행기로 바뀌지 않도록
+/// 역직렬화에서 거부한다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "DbConnectionConfigRepr", into = "DbConnectionConfigRepr")]
 pub struct DbConnectionConfig {
-    /// 연결 종류이다.
+    /// 연결 종류이다. `db_ref`를 지정한 정의에서는 쓰지 않는다.
     pub kind: DbKind,
     /// 연결 문자열 또는 DSN이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dsn: Option<String>,
     /// 사용자명이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
     /// 비밀번호이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+    /// 참조할 공유 연결 이름이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub db_ref: Option<String>,
+}
+
+impl DbConnectionConfig {
+    /// 공유 연결을 참조하는 정의를 만든다.
+    pub fn reference(name: impl Into<String>) -> Self {
+        Self {
+            kind: DbKind::default(),
+            dsn: None,
+            user: None,
+            password: None,
+            db_ref: Some(name.into()),
+        }
+    }
+}
+
+/// [`DbConnectionConfig`]의 YAML 표현이다. `kind` 누락을 구분하려고 선택 필드로 둔다.
+#[derive(Serialize, Deserialize)]
+struct DbConnectionConfigRepr {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kind: Option<DbKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dsn: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    db_ref: Option<String>,
+}
+
+impl TryFrom<DbConnectionConfigRepr> for DbConnectionConfig {
+    type Error = String;
+
+    /// `db_ref`도 `kind`도 없는 정의를 거부한다.
+    fn try_from(repr: DbConnectionConfigRepr) -> Result<Self, Self::Error> {
+        let kind = match (repr.kind, &repr.db_ref) {
+            (Some(kind), _) => kind,
+            (None, Some(_)) => DbKind::default(),
+            (None, None) => {
+                return Err(t("scenario.error.db_kind_required").to_string());
+            }
+        };
+        Ok(Self {
+            kind,
+            dsn: repr.dsn,
+            user: repr.user,
+            password: repr.password,
+            db_ref: repr.db_ref,
+        })
+    }
+}
+
+impl From<DbConnectionConfig> for DbConnectionConfigRepr {
+    /// 공유 연결 참조는 `kind` 없이, 직접 정의는 더미여도 `kind`를 적어 다시 불러올 수 있게 한다.
+    fn from(config: DbConnectionConfig) -> Self {
+        Self {
+            kind: config.db_ref.is_none().then_some(config.kind),
+            dsn: config.dsn,
+            user: config.user,
+            password: config.password,
+            db_ref: config.db_ref,
+        }
+    }
 }
 
 /// 지원하는 DB 종류를 표현한다.
@@ -30,6 +108,241 @@ pub enum DbKind {
     Oracle,
 }
 
+impl DbKind {
+    /// 더미 실행기인지 확인한다.
+    pub fn is_dummy(&self) -> bool {
+        matches!(self, DbKind::Dummy)
+    }
+
+    /// YAML에 쓰는 이름을 반환한다.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DbKind::Dummy => "dummy",
+            DbKind::Postgres => "postgres",
+            DbKind::Oracle => "oracle",
+        }
+    }
+}
+
+impl Default for DbKind {
+    /// 기본값은 더미 실행기이다.
+    fn default() -> Self {
+        DbKind::Dummy
+    }
+}
+
+/// 원격 실행에 사용할 SSH 호스트 정의이다.
+///
+/// 시스템 `ssh` 클라이언트를 `BatchMode`로 호출하므로 키 기반 인증이 준비되어 있어야 한다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteHostConfig {
+    /// 접속할 호스트명 또는 IP.
+    pub host: String,
+    /// 접속 사용자명.
+    #[serde(default)]
+    pub user: Option<String>,
+    /// SSH 포트.
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// 개인 키 파일 경로.
+    #[serde(default)]
+    pub identity_file: Option<PathBuf>,
+    /// 원격 명령을 실행할 기본 디렉터리.
+    #[serde(default)]
+    pub base_dir: Option<String>,
+    /// `ssh`에 그대로 전달할 추가 인자 목록.
+    #[serde(default)]
+    pub ssh_args: Vec<String>,
+}
+
+/// 실행마다 만드는 임시 디렉터리 경로가 저장되는 변수 이름이다.
+pub const RUN_TMPDIR_VAR: &str = "RUN_TMPDIR";
+
+/// Step 실행(Loop 반복 포함)마다 따로 만드는 작업 디렉터리 경로가 저장되는 변수 이름이다.
+pub const STEP_TMPDIR_VAR: &str = "STEP_TMPDIR";
+
+/// DB 연결 정보 변수 이름의 접두사이다. `${DB.<DB 키>.<필드>}` 형태로 쓴다.
+pub const DB_VAR_PREFIX: &str = "DB.";
+
+/// DB 연결 정보 변수로 제공하는 필드이다.
+pub const DB_VAR_FIELDS: [&str; 5] = ["kind", "dsn", "user", "password", "conn_string"];
+
+/// DB 연결 정보 변수 중 로그와 화면에서 값을 가리는 필드이다.
+pub const DB_SECRET_FIELDS: [&str; 2] = ["password", "conn_string"];
+
+/// DB 키와 필드로 DB 연결 정보 변수 이름(`DB.<키>.<필드>`)을 만든다.
+pub fn db_var(key: &str, field: &str) -> String {
+    format!("{DB_VAR_PREFIX}{key}.{field}")
+}
+
+/// 실행별 임시 디렉터리의 위치와 보존 정책이다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RunTmpdirConfig {
+    /// 임시 디렉터리를 만들 상위 경로. 없으면 OS 임시 디렉터리 아래 `rust-airflow`를 쓴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_dir: Option<PathBuf>,
+    /// 실행 종료 후 보존 정책.
+    #[serde(default)]
+    pub retention: TmpdirRetention,
+    /// 보존된 디렉터리를 시나리오별로 최근 몇 개까지 남길지 여부.
+    #[serde(default = "default_tmpdir_keep_last")]
+    pub keep_last: usize,
+}
+
+impl Default for RunTmpdirConfig {
+    /// 기본 위치에 만들고 종료 후 삭제하는 설정이다.
+    fn default() -> Self {
+        Self {
+            base_dir: None,
+            retention: TmpdirRetention::default(),
+            keep_last: default_tmpdir_keep_last(),
+        }
+    }
+}
+
+/// 실행별 임시 디렉터리 보존 정책이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TmpdirRetention {
+    /// 결과와 관계없이 실행 종료 후 삭제한다.
+    Delete,
+    /// 실패하거나 중지된 실행만 남긴다.
+    KeepOnFailure,
+    /// 항상 남긴다.
+    Keep,
+}
+
+impl Default for TmpdirRetention {
+    /// 기본 정책은 실행 종료 후 삭제한다.
+    fn default() -> Self {
+        TmpdirRetention::Delete
+    }
+}
+
+/// 트리거 파일 경로가 저장되는 변수 이름이다.
+pub const TRIGGER_FILE_VAR: &str = "TRIGGER_FILE";
+
+/// 외부 시스템이 놓는 트리거(센티널) 파일을 감시해 실행을 시작하는 설정이다.
+///
+/// 데몬을 `--trigger <시나리오 파일>`로 띄웠을 때만 쓴다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TriggerConfig {
+    /// 트리거 파일을 찾을 디렉터리.
+    pub watch_dir: PathBuf,
+    /// 파일 이름 전체가 일치해야 하는 정규식. 이름 있는 그룹(`(?P<LOAD_DT>\d{8})`)은 같은 이름의
+    /// 시나리오 파라미터로 실행에 넘긴다.
+    pub pattern: String,
+    /// 성공한 실행의 트리거 파일 처리 방식.
+    #[serde(default)]
+    pub after: TriggerCleanup,
+    /// 보관 디렉터리. 없으면 `watch_dir/archive`를 쓴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_dir: Option<PathBuf>,
+    /// 디렉터리를 확인하는 간격(초). `notify` 방식에서는 알림을 놓쳤을 때를 대비한 재확인 간격이다.
+    #[serde(default = "default_trigger_poll_sec")]
+    pub poll_sec: u64,
+    /// 트리거 파일을 알아채는 방식.
+    #[serde(default, skip_serializing_if = "TriggerMode::is_default")]
+    pub mode: TriggerMode,
+    /// `notify` 방식에서 일치하는 파일 알림이 이 시간(밀리초) 동안 더 없을 때 확인한다. 파일이 아직
+    /// 쓰이는 중에 실행하지 않게 한다.
+    #[serde(default = "default_trigger_debounce_ms")]
+    pub debounce_ms: u64,
+}
+
+/// 트리거 파일을 알아채는 방식이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerMode {
+    /// `poll_sec`마다 디렉터리를 확인한다.
+    Poll,
+    /// 운영체제 파일 알림을 받으면 바로 확인한다.
+    Notify,
+}
+
+impl TriggerMode {
+    /// 기본 방식인지 확인한다.
+    pub fn is_default(&self) -> bool {
+        *self == TriggerMode::default()
+    }
+}
+
+impl Default for TriggerMode {
+    /// 기본 방식은 주기 확인이다.
+    fn default() -> Self {
+        TriggerMode::Poll
+    }
+}
+
+/// 실행이 성공한 뒤 트리거 파일을 처리하는 방식이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerCleanup {
+    /// 보관 디렉터리로 옮긴다.
+    Archive,
+    /// 지운다.
+    Delete,
+}
+
+impl Default for TriggerCleanup {
+    /// 기본 처리 방식은 보관이다.
+    fn default() -> Self {
+        TriggerCleanup::Archive
+    }
+}
+
+/// 메인 DAG가 실패하거나 취소된 뒤 실행하는 정리 Step 구성이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnFailureConfig {
+    /// 정리 Step 전체에 허용하는 최대 실행 시간(초).
+    #[serde(default = "default_on_failure_timeout_sec")]
+    pub timeout_sec: u64,
+    /// 의존성 순서대로 실행할 정리 Step 목록. 의존성은 이 목록 안에서만 참조한다.
+    pub steps: Vec<Step>,
+}
+
+/// 메인 DAG가 끝난 뒤 실행해 결과 요약 패널에 보여 줄 조회이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryQuery {
+    /// 요약 항목 이름(예: `TB_SALES 적재 건수`).
+    pub label: String,
+    /// 첫 행 첫 열 값을 결과로 쓰는 조회 SQL. `${VAR}`는 실행 컨텍스트로 치환한다.
+    pub sql: String,
+    /// 조회할 DB 키. 없으면 `default`를 사용한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_db: Option<String>,
+}
+
+/// 실행 환경(개발, 운영 등)마다 바꿔 쓸 파라미터와 실행 조건을 묶은 실행 프로필이다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExecutionProfile {
+    /// 프로필을 골라 실행하면 시나리오 파라미터를 덮어쓰는 값.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, String>,
+    /// 실행 전에 요청자가 아닌 사용자의 승인을 받아야 하는지 여부.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub requires_approval: bool,
+}
+
+/// Step이 실패했을 때 원인 분석을 위해 실행해 출력을 남기는 진단 명령이다.
+///
+/// 디스크 부족이나 세션 초과처럼 아침이면 사라지는 환경 문제를 실패 시점에 기록하는 용도이다.
+/// `shell`과 `sql` 중 하나만 지정한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailureDiagnostic {
+    /// 진단 항목 이름(예: `디스크 사용량`).
+    pub label: String,
+    /// 로컬에서 실행할 셸 명령(예: `df -h`). 표준 출력과 표준 오류를 함께 남긴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// 첫 행 첫 열 값을 결과로 쓰는 조회 SQL(예: 세션 수 조회).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql: Option<String>,
+    /// `sql`을 실행할 DB 키. 없으면 `default`를 사용한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_db: Option<String>,
+}
+
 /// sqlldr Step 구성을 표현한다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SqlLoaderParConfig {
@@ -45,6 +358,64 @@ pub struct SqlLoaderParConfig {
     pub discard_file: Option<PathBuf>,
     /// SQL*Loader 접속 문자열.
     pub conn: Option<String>,
+    /// 실행할 원격 호스트 키. 비어 있으면 로컬에서 실행한다.
+    #[serde(default)]
+    pub remote: Option<String>,
+    /// 자주 쓰는 sqlldr 옵션.
+    #[serde(default, skip_serializing_if = "SqlLoaderOptions::is_empty")]
+    pub options: SqlLoaderOptions,
+    /// 그 밖의 sqlldr 인자 목록(`key=value`). 플레이스홀더를 치환해 옵션 뒤에 붙인다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_args: Vec<String>,
+    /// sqlldr 프로세스의 OS 우선순위와 CPU 지정.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<ProcessPriority>,
+}
+
+/// sqlldr 명령줄 옵션이다. 지정하지 않은 옵션은 sqlldr 기본값을 따른다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SqlLoaderOptions {
+    /// 커밋 간격 행 수(`rows`). direct path에서는 데이터 저장 간격이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rows: Option<u64>,
+    /// conventional path 바인드 배열 크기(바이트, `bindsize`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bindsize: Option<u64>,
+    /// direct path 적재 여부(`direct=true`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub direct: bool,
+    /// 적재를 중단하기 전까지 허용할 오류 행 수(`errors`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<u64>,
+    /// 건너뛸 선두 논리 레코드 수(`skip`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip: Option<u64>,
+}
+
+impl SqlLoaderOptions {
+    /// 지정한 옵션이 하나도 없는지 확인한다.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 지정한 옵션을 `key=value` 형식의 sqlldr 인자로 만든다.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        for (key, value) in [
+            ("rows", self.rows),
+            ("bindsize", self.bindsize),
+            ("errors", self.errors),
+            ("skip", self.skip),
+        ] {
+            if let Some(value) = value {
+                args.push(format!("{key}={value}"));
+            }
+        }
+        if self.direct {
+            args.push("direct=true".to_string());
+        }
+        args
+    }
 }
 
 /// Shell Step 실행 설정이다.
@@ -61,6 +432,12 @@ pub struct ShellConfig {
     /// 스크립트 실행 시 적용할 환경 변수.
     #[serde(default)]
     pub env: HashMap<String, String>,
+    /// GUI 프로세스의 환경 변수를 물려받을지 여부. `false`면 빈 환경에 `env`만 적용한다.
+    #[serde(
+        default = "default_inherit_env",
+        skip_serializing_if = "is_inherit_env"
+    )]
+    pub inherit_env: bool,
     /// 실행 전 변경할 작업 디렉터리.
     pub working_dir: Option<PathBuf>,
     /// 명령을 실행할 사용자 계정.
@@ -68,6 +445,140 @@ pub struct ShellConfig {
     /// 비정상 종료 시 처리 정책.
     #[serde(default)]
     pub error_policy: ShellErrorPolicy,
+    /// 실행할 원격 호스트 키. 비어 있으면 로컬에서 실행한다.
+    #[serde(default)]
+    pub remote: Option<String>,
+    /// 셸 프로세스의 OS 우선순위와 CPU 지정.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<ProcessPriority>,
+}
+
+/// Shell·sqlldr 자식 프로세스의 OS 우선순위와 CPU 지정이다.
+///
+/// 무거운 변환 작업이 같은 배치 호스트의 대화형 DB 세션을 굶기지 않도록 낮춰 실행할 때 쓴다.
+/// Unix에서는 `nice`·`ionice`·`taskset`으로, Windows에서는 프로세스 우선순위 클래스로 적용한다.
+/// 값 범위는 시나리오를 불러올 때 검사한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "ProcessPriorityRepr")]
+pub struct ProcessPriority {
+    /// nice 값(-20~19). 클수록 다른 프로세스에 양보하며, 음수는 관리자 권한이 필요하다.
+    /// Windows에서는 가까운 우선순위 클래스(`idle`~`high`)로 바꾼다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i8>,
+    /// I/O 스케줄링 클래스(Linux `ionice -c`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_class: Option<IoPriorityClass>,
+    /// I/O 우선순위(0~7, 작을수록 우선). `io_class`가 없으면 `best_effort`로 본다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_level: Option<u8>,
+    /// 프로세스를 묶어 둘 CPU 번호 목록(Linux `taskset -c`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpu_affinity: Vec<usize>,
+}
+
+/// 범위 검사 전 YAML의 `priority` 정의이다.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProcessPriorityRepr {
+    #[serde(default)]
+    nice: Option<i8>,
+    #[serde(default)]
+    io_class: Option<IoPriorityClass>,
+    #[serde(default)]
+    io_level: Option<u8>,
+    #[serde(default)]
+    cpu_affinity: Vec<usize>,
+}
+
+impl TryFrom<ProcessPriorityRepr> for ProcessPriority {
+    type Error = String;
+
+    /// 범위를 벗어난 값을 실행 전에 거부한다.
+    fn try_from(repr: ProcessPriorityRepr) -> Result<Self, Self::Error> {
+        let priority = Self {
+            nice: repr.nice,
+            io_class: repr.io_class,
+            io_level: repr.io_level,
+            cpu_affinity: repr.cpu_affinity,
+        };
+        priority.validate().map_err(|err| err.to_string())?;
+        Ok(priority)
+    }
+}
+
+/// Linux I/O 스케줄링 클래스이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IoPriorityClass {
+    /// 다른 I/O보다 먼저 처리한다. 관리자 권한이 필요하다.
+    Realtime,
+    /// 일반 I/O. `io_level`로 순서를 조정한다.
+    BestEffort,
+    /// 다른 프로세스가 디스크를 쓰지 않을 때만 I/O를 처리한다.
+    Idle,
+}
+
+impl IoPriorityClass {
+    /// 모든 클래스 목록이다.
+    pub const ALL: [IoPriorityClass; 3] = [
+        IoPriorityClass::Realtime,
+        IoPriorityClass::BestEffort,
+        IoPriorityClass::Idle,
+    ];
+
+    /// 시나리오 YAML에 쓰는 이름을 반환한다.
+    pub fn name(self) -> &'static str {
+        match self {
+            IoPriorityClass::Realtime => "realtime",
+            IoPriorityClass::BestEffort => "best_effort",
+            IoPriorityClass::Idle => "idle",
+        }
+    }
+}
+
+impl ProcessPriority {
+    /// 지정한 설정이 하나도 없는지 확인한다.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 값 범위를 검사한다.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if let Some(nice) = self.nice
+            && !(-20..=19).contains(&nice)
+        {
+            anyhow::bail!(tf("scenario.error.priority_nice", &[("value", &nice)]));
+        }
+        if let Some(level) = self.io_level {
+            if level > 7 {
+                anyhow::bail!(tf("scenario.error.priority_io_level", &[("value", &level)]));
+            }
+            if self.io_class == Some(IoPriorityClass::Idle) {
+                anyhow::bail!(t("scenario.error.priority_idle_level"));
+            }
+        }
+        Ok(())
+    }
+
+    /// 로그에 남길 설정 요약(`nice=10, ionice=idle, cpu=0,1`)을 만든다.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(nice) = self.nice {
+            parts.push(format!("nice={nice}"));
+        }
+        if self.io_class.is_some() || self.io_level.is_some() {
+            let class = self.io_class.unwrap_or(IoPriorityClass::BestEffort).name();
+            match self.io_level {
+                Some(level) => parts.push(format!("ionice={class}/{level}")),
+                None => parts.push(format!("ionice={class}")),
+            }
+        }
+        if !self.cpu_affinity.is_empty() {
+            let cpus: Vec<String> = self.cpu_affinity.iter().map(usize::to_string).collect();
+            parts.push(format!("cpu={}", cpus.join(",")));
+        }
+        parts.join(", ")
+    }
 }
 
 /// Shell Step 실패 처리 정책이다.
@@ -114,8 +625,9 @@ impl<'de> Deserialize<'de> for ShellErrorPolicy {
                     max_retries: 3,
                     delay_secs: 5,
                 }),
-                other => Err(de::Error::custom(format!(
-                    "알 수 없는 shell error policy: {other}"
+                other => Err(de::Error::custom(tf(
+                    "scenario.error.shell_error_policy",
+                    &[("policy", &other)],
                 ))),
             },
             Helper::Detailed {
@@ -129,14 +641,243 @@ impl<'de> Deserialize<'de> for ShellErrorPolicy {
                     max_retries: max_retries.unwrap_or(3),
                     delay_secs: delay_secs.unwrap_or(5),
                 }),
-                other => Err(de::Error::custom(format!(
-                    "알 수 없는 shell error policy: {other}"
+                other => Err(de::Error::custom(tf(
+                    "scenario.error.shell_error_policy",
+                    &[("policy", &other)],
                 ))),
             },
         }
     }
 }
 
+/// SQL Step 안전장치 설정이다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SqlGuardConfig {
+    /// DB 세션에 적용할 문장 제한 시간(초 단위).
+    #[serde(default)]
+    pub statement_timeout_sec: Option<u64>,
+    /// 한 번의 실행에서 변경을 허용할 최대 행 수.
+    #[serde(default)]
+    pub max_affected_rows: Option<u64>,
+}
+
+impl SqlGuardConfig {
+    /// 설정된 안전장치가 하나도 없는지 확인한다.
+    pub fn is_empty(&self) -> bool {
+        self.statement_timeout_sec.is_none() && self.max_affected_rows.is_none()
+    }
+}
+
+/// Step 실행을 허용하는 하루 중 시간 창이다.
+///
+/// 시각은 로컬 시간 `HH:MM` 또는 `HH:MM:SS` 형식이다. `not_before`가 `not_after`보다 늦으면
+/// 자정을 넘는 창(예: 22:00~06:00)으로 해석한다. 어느 날의 창인지는 실행을 시작한 시각 뒤에 처음
+/// 닫히는 창으로 정한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StepTimeWindow {
+    /// 이 시각 이전에는 Step을 시작하지 않는다.
+    #[serde(default)]
+    pub not_before: Option<String>,
+    /// 이 시각 이후에는 Step을 시작하지 않는다.
+    #[serde(default)]
+    pub not_after: Option<String>,
+    /// 시작 가능 시각 전에 도달했을 때의 처리 정책.
+    #[serde(default)]
+    pub policy: TimeWindowPolicy,
+}
+
+impl StepTimeWindow {
+    /// `not_before`/`not_after` 문자열을 시각으로 파싱한다.
+    ///
+    /// # 반환값
+    /// `(not_before, not_after)` 순서의 시각을 반환하고, 형식이 잘못되면 오류를 반환한다.
+    pub fn parse(&self) -> anyhow::Result<(Option<NaiveTime>, Option<NaiveTime>)> {
+        Ok((
+            parse_window_time(self.not_before.as_deref(), "not_before")?,
+            parse_window_time(self.not_after.as_deref(), "not_after")?,
+        ))
+    }
+
+    /// 두 시각이 모두 비어 있는지 확인한다.
+    pub fn is_empty(&self) -> bool {
+        self.not_before.is_none() && self.not_after.is_none()
+    }
+}
+
+/// Step 실행 전에 확인해, 지정한 조건을 모두 충족하면 실행하지 않고 성공으로 처리하는 조건이다.
+///
+/// 일부만 끝난 날짜를 다시 돌릴 때 이미 결과가 있는 Step을 손으로 끄지 않아도 되게 한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StepSkipIf {
+    /// 이 경로의 파일이 있으면 건너뛴다. `${VAR}`는 실행 컨텍스트로 치환한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_exists: Option<String>,
+    /// 이 조회가 한 행 이상을 반환하면 건너뛴다. `${VAR}`는 실행 컨텍스트로 치환한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sql_returns_rows: Option<String>,
+    /// 조회를 실행할 DB 키. 없으면 `default`를 쓴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_db: Option<String>,
+}
+
+impl StepSkipIf {
+    /// 확인할 조건이 하나도 없는지 확인한다.
+    pub fn is_empty(&self) -> bool {
+        self.file_exists.is_none() && self.sql_returns_rows.is_none()
+    }
+}
+
+/// `HH:MM` 또는 `HH:MM:SS` 문자열을 시각으로 변환한다.
+fn parse_window_time(value: Option<&str>, field: &str) -> anyhow::Result<Option<NaiveTime>> {
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .map(Some)
+        .map_err(|_| {
+            anyhow::anyhow!(tf(
+                "scenario.error.time_format",
+                &[("field", &field), ("value", &value)]
+            ))
+        })
+}
+
+/// 시작 가능 시각 전에 Step에 도달했을 때의 처리 정책이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeWindowPolicy {
+    /// 시간 창이 열릴 때까지 대기한다.
+    Wait,
+    /// 즉시 실패 처리한다.
+    Fail,
+}
+
+impl Default for TimeWindowPolicy {
+    /// 기본값은 대기이다.
+    fn default() -> Self {
+        TimeWindowPolicy::Wait
+    }
+}
+
+/// Step이 `timeout_sec`을 넘겼을 때의 처리 정책이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeoutPolicy {
+    /// 재시도 없이 즉시 실패 처리한다.
+    Fail,
+    /// 경고 이벤트만 보내고 중단하지 않은 채 계속 기다린다. `timeout_sec`마다 다시 경고한다.
+    WarnContinue,
+    /// 실행을 중단하고 남은 재시도 횟수만큼 다시 실행한다.
+    KillRetry,
+}
+
+impl TimeoutPolicy {
+    /// 모든 정책 목록이다.
+    pub const ALL: [TimeoutPolicy; 3] = [
+        TimeoutPolicy::Fail,
+        TimeoutPolicy::WarnContinue,
+        TimeoutPolicy::KillRetry,
+    ];
+
+    /// 기본 정책인지 확인한다.
+    pub fn is_default(&self) -> bool {
+        *self == TimeoutPolicy::default()
+    }
+}
+
+impl Default for TimeoutPolicy {
+    /// 기본값은 중단 후 재시도이다.
+    fn default() -> Self {
+        TimeoutPolicy::KillRetry
+    }
+}
+
+/// `expects_outputs` 산출물이 없거나 비어 있을 때의 처리 정책이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingOutputPolicy {
+    /// Step을 실패로 처리한다.
+    Fail,
+    /// 경고 로그만 남기고 성공으로 둔다.
+    Warn,
+}
+
+impl MissingOutputPolicy {
+    /// 기본 정책인지 확인한다.
+    pub fn is_default(&self) -> bool {
+        *self == MissingOutputPolicy::default()
+    }
+}
+
+impl Default for MissingOutputPolicy {
+    /// 기본값은 실패 처리이다.
+    fn default() -> Self {
+        MissingOutputPolicy::Fail
+    }
+}
+
+/// Step 실행 중 엔진이 남기는 로그 메시지의 최소 수준이다.
+///
+/// 수준이 낮을수록 자세히 남긴다. Step 프로그램(shell·sqlldr)의 출력과 실패·건너뜀·재시도처럼 결과를 알리는
+/// 메시지는 수준과 관계없이 남긴다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum StepLogLevel {
+    /// Loop 반복마다의 변수 값, 추출·검증 값처럼 자세한 진행 메시지까지 남긴다.
+    Debug,
+    /// 시작·완료 같은 주요 진행 메시지를 남긴다.
+    Info,
+    /// 무시한 실패나 없는 산출물 같은 경고만 남긴다.
+    Warn,
+}
+
+impl StepLogLevel {
+    /// 모든 수준 목록이다.
+    pub const ALL: [StepLogLevel; 3] =
+        [StepLogLevel::Debug, StepLogLevel::Info, StepLogLevel::Warn];
+
+    /// 시나리오 YAML에 쓰는 이름을 반환한다.
+    pub fn name(self) -> &'static str {
+        match self {
+            StepLogLevel::Debug => "debug",
+            StepLogLevel::Info => "info",
+            StepLogLevel::Warn => "warn",
+        }
+    }
+}
+
+impl Default for StepLogLevel {
+    /// 기본값은 주요 진행 메시지까지 남기는 `info`이다.
+    fn default() -> Self {
+        StepLogLevel::Info
+    }
+}
+
+/// 실패한 Step을 재시도할 오류 범위이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryOn {
+    /// 접속 끊김·교착 상태처럼 일시적인 오류만 재시도하고 SQL 오류 등은 바로 실패한다.
+    Transient,
+    /// 오류 종류와 관계없이 재시도한다.
+    Any,
+}
+
+impl RetryOn {
+    /// 기본 범위인지 확인한다.
+    pub fn is_default(&self) -> bool {
+        *self == RetryOn::default()
+    }
+}
+
+impl Default for RetryOn {
+    /// 기본값은 일시적 오류만 재시도이다.
+    fn default() -> Self {
+        RetryOn::Transient
+    }
+}
+
 /// StepKind는 배치 엔진이 수행할 개별 작업 유형을 표현한다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractVarFromFileConfig {
@@ -150,6 +891,9 @@ pub struct ExtractVarFromFileConfig {
     pub group: usize,
     /// 저장할 변수명.
     pub var_name: String,
+    /// 추출한 값을 로그와 화면에서 가릴지 여부.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub secret: bool,
 }
 
 /// Loop Step 구성을 표현한다.
@@ -194,6 +938,9 @@ pub enum StepKind {
         /// 사용할 DB 타겟.
         #[serde(default)]
         target_db: Option<String>,
+        /// 문장 제한 시간/변경 행 수 안전장치.
+        #[serde(default, skip_serializing_if = "SqlGuardConfig::is_empty")]
+        guard: SqlGuardConfig,
     },
     /// SQL 파일을 읽어 실행한다.
     SqlFile {
@@ -203,6 +950,9 @@ pub enum StepKind {
         /// 사용할 DB 타겟.
         #[serde(default)]
         target_db: Option<String>,
+        /// 문장 제한 시간/변경 행 수 안전장치.
+        #[serde(default, skip_serializing_if = "SqlGuardConfig::is_empty")]
+        guard: SqlGuardConfig,
     },
     /// sqlldr par 파일을 실행한다.
     SqlLoaderPar {
@@ -229,6 +979,61 @@ pub enum StepKind {
         #[serde(rename = "loop")]
         config: LoopStepConfig,
     },
+    /// 사용자가 승인할 때까지 DAG 진행을 멈추는 수동 승인 게이트이다.
+    ManualGate {
+        /// 승인 게이트 설정.
+        #[serde(rename = "gate", default)]
+        config: ManualGateConfig,
+    },
+    /// 지정한 시간만큼 또는 지정 시각까지 기다린다.
+    Wait {
+        /// 대기 설정.
+        #[serde(rename = "wait")]
+        config: WaitConfig,
+    },
+    /// 다른 시나리오가 기준 시각 뒤에 성공한 실행 이력이 생길 때까지 기다린다.
+    WaitForRun {
+        /// 실행 대기 설정.
+        #[serde(rename = "wait_for_run")]
+        config: WaitForRunConfig,
+    },
+    /// 파일의 체크섬이나 줄 수를 기대값과 비교한다.
+    Verify {
+        /// 검증 설정.
+        #[serde(rename = "verify")]
+        config: VerifyConfig,
+    },
+    /// 아무 작업 없이 바로 성공하는 표식 Step이다. 여러 Step을 한데 모으는 합류 지점으로 쓴다.
+    Marker,
+}
+
+impl StepKind {
+    /// 시나리오 YAML의 `kind` 값을 반환한다.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StepKind::Sql { .. } => "sql",
+            StepKind::SqlFile { .. } => "sql_file",
+            StepKind::SqlLoaderPar { .. } => "sql_loader_par",
+            StepKind::Shell { .. } => "shell",
+            StepKind::Extract { .. } => "extract",
+            StepKind::Loop { .. } => "loop",
+            StepKind::ManualGate { .. } => "manual_gate",
+            StepKind::Wait { .. } => "wait",
+            StepKind::WaitForRun { .. } => "wait_for_run",
+            StepKind::Verify { .. } => "verify",
+            StepKind::Marker => "marker",
+        }
+    }
+
+    /// SQL·SQL 파일 Step의 대상 DB 키를 반환한다. 그 밖의 Step이면 `None`이다.
+    pub fn target_db(&self) -> Option<&str> {
+        match self {
+            StepKind::Sql { target_db, .. } | StepKind::SqlFile { target_db, .. } => {
+                target_db.as_deref()
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Step은 Scenario 내 최소 실행 단위를 표현한다.
@@ -238,6 +1043,15 @@ pub struct Step {
     pub id: String,
     /// 사용자 친화적인 Step 이름.
     pub name: String,
+    /// 빌더 노드와 실행 카드에 표시할 아이콘. 없으면 유형별 기본 아이콘을 쓴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// 유형 이름 대신 표시할 짧은 라벨. 없으면 유형 이름을 쓴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// 실행에는 영향이 없는 구성용 스테이지 이름(`extract`, `load` 등).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
     /// Step에서 실행할 Kind 정보.
     #[serde(flatten)]
     pub kind: StepKind,
@@ -247,15 +1061,173 @@ pub struct Step {
     /// 같은 레벨에서 병렬 실행 가능한지 여부.
     #[serde(default)]
     pub allow_parallel: bool,
+    /// 멱등 Step이면 한 실행 안에서 유형과 치환된 구성이 같은 Step을 한 번만 실행하고 결과를 재사용한다.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub memoize: bool,
     /// 실패 시 재시도 횟수.
     #[serde(default = "default_retry")]
     pub retry: u8,
+    /// 재시도할 오류 범위.
+    #[serde(default, skip_serializing_if = "RetryOn::is_default")]
+    pub retry_on: RetryOn,
     /// 실행 제한 시간(초 단위).
     #[serde(default = "default_timeout")]
     pub timeout_sec: u64,
+    /// 실행 제한 시간을 넘겼을 때의 처리 정책.
+    #[serde(default, skip_serializing_if = "TimeoutPolicy::is_default")]
+    pub on_timeout: TimeoutPolicy,
+    /// 엔진 로그 메시지의 최소 수준. 없으면 감싼 Loop Step의 수준을, 최상위면 `info`를 따른다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<StepLogLevel>,
     /// Step 실행 컨펌 설정.
     #[serde(default)]
     pub confirm: Option<StepConfirmConfig>,
+    /// Step 시작을 허용하는 시간 창.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_window: Option<StepTimeWindow>,
+    /// 실행 전에 확인해 결과가 이미 있으면 Step을 건너뛰는 조건.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_if: Option<StepSkipIf>,
+    /// 성공한 뒤 있어야 하는 산출물 경로 목록. `${VAR}`는 실행 컨텍스트로 치환한다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expects_outputs: Vec<String>,
+    /// 기대 산출물이 없거나 비어 있을 때의 처리 정책.
+    #[serde(default, skip_serializing_if = "MissingOutputPolicy::is_default")]
+    pub on_missing_output: MissingOutputPolicy,
+    /// Step이 읽는 컨텍스트 변수 목록.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumes: Vec<String>,
+    /// Step이 컨텍스트에 남기는 변수 목록.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub produces: Vec<String>,
+    /// 이 Step이 시작하고 끝날 때 JSON을 POST할 웹훅 주소(`http://`). `${VAR}`는 실행 컨텍스트로 치환한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_url: Option<String>,
+    /// 최상위 `steps`의 `include` 항목으로 조각 파일에서 펼친 Step이면 그 항목. 파일에는 쓰지 않는다.
+    #[serde(skip)]
+    pub included_from: Option<StepInclude>,
+}
+
+/// 최상위 `steps`에 `- include: <파일>`로 적는 Step 조각 포함 항목이다.
+///
+/// 불러올 때 조각 파일의 Step을 그 자리에 펼치고, 저장할 때 펼친 Step을 다시 이 항목 하나로 되돌린다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepInclude {
+    /// 조각 파일 경로. 상대 경로는 시나리오 파일이 있는 디렉터리 기준이다.
+    pub include: PathBuf,
+    /// 조각 Step ID 앞에 붙일 접두사. 없으면 `<파일 이름>_`이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// 조각 안에서 선행 Step이 없는 Step에 더할 선행 Step ID 목록.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+}
+
+impl StepInclude {
+    /// 조각 Step ID에 붙일 접두사를 반환한다.
+    pub fn id_prefix(&self) -> String {
+        self.prefix.clone().unwrap_or_else(|| {
+            let stem = self
+                .include
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            format!("{stem}_")
+        })
+    }
+}
+
+impl Step {
+    /// Step이 실행 후 제공하는 변수 목록을 반환한다.
+    ///
+    /// 선언된 `produces` 외에 Extract Step의 `var_name`도 포함한다.
+    pub fn produced_vars(&self) -> Vec<&str> {
+        let mut vars: Vec<&str> = self.produces.iter().map(String::as_str).collect();
+        if let StepKind::Extract { config } = &self.kind
+            && !config.var_name.is_empty()
+            && !vars.contains(&config.var_name.as_str())
+        {
+            vars.push(config.var_name.as_str());
+        }
+        vars
+    }
+
+    /// Step이 실행되는 원격 호스트 키를 반환한다. 로컬에서 실행하면 `None`이다.
+    pub fn remote_host(&self) -> Option<&str> {
+        match &self.kind {
+            StepKind::Shell { config } => config.remote.as_deref(),
+            StepKind::SqlLoaderPar { config } => config.remote.as_deref(),
+            _ => None,
+        }
+        .filter(|remote| !remote.is_empty())
+    }
+}
+
+/// 실행에는 쓰지 않고 시나리오 빌더만 읽는 편집 상태이다.
+///
+/// 파일을 열고 저장해도 손으로 배치한 노드 위치, 열어 둔 Loop와 선택이 유지되도록 시나리오의
+/// `metadata` 블록에 저장한다. 없는 Step을 가리키는 항목은 불러올 때 무시한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EditorMetadata {
+    /// Step ID별 캔버스 위치 `[x, y]`. Loop 하위 Step도 포함한다.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub positions: BTreeMap<String, [f32; 2]>,
+    /// 캔버스로 열어 둔 Loop 경로(바깥부터).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_loops: Vec<String>,
+    /// 최상위 흐름에서 선택한 Step ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected: Option<String>,
+    /// Loop ID별로 하위 흐름에서 선택한 Step ID.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub loop_selected: BTreeMap<String, String>,
+    /// 열려 있던 흐름의 캔버스 오프셋 `[x, y]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canvas_offset: Option<[f32; 2]>,
+    /// 열려 있던 흐름의 캔버스 줌 비율.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canvas_zoom: Option<f32>,
+    /// 목록 보기로 열지 여부.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub list_view: bool,
+    /// 캔버스 노드를 스테이지별 색으로 칠할지 여부.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub color_by_stage: bool,
+    /// 끄는 노드를 그리드에 맞출지 여부.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub snap_to_grid: bool,
+    /// 실행 탭에서 고른 실행 프로필 이름.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+/// 실행 전 비용 점검 설정이다. 경고는 실행을 막지 않고 확인을 요청한다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CostCheckConfig {
+    /// `WHERE` 없는 SQL을 경고할 큰 테이블 이름 목록(대소문자 구분 없음).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub large_tables: Vec<String>,
+    /// direct path 없이 적재하면 경고할 sqlldr 데이터 파일 크기(MB).
+    #[serde(default = "default_cost_large_file_mb")]
+    pub large_file_mb: u64,
+    /// Loop glob이 이 개수보다 많은 파일과 일치하면 경고한다.
+    #[serde(default = "default_cost_max_glob_files")]
+    pub max_glob_files: usize,
+    /// 확인하고 넘긴 경고 키(`<step_id>/<규칙>`) 목록. 같은 경고는 다시 묻지 않는다.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub acknowledged: BTreeSet<String>,
+}
+
+impl Default for CostCheckConfig {
+    /// 큰 테이블 없이 기본 파일 크기·glob 기준만 둔 설정을 만든다.
+    fn default() -> Self {
+        Self {
+            large_tables: Vec::new(),
+            large_file_mb: default_cost_large_file_mb(),
+            max_glob_files: default_cost_max_glob_files(),
+            acknowledged: BTreeSet::new(),
+        }
+    }
 }
 
 /// Scenario는 여러 Step으로 구성된 전체 배치 정의다.
@@ -266,8 +1238,64 @@ pub struct Scenario {
     /// DB 실행기 정의 맵.
     #[serde(default)]
     pub db: HashMap<String, DbConnectionConfig>,
+    /// 원격 실행 호스트 정의 맵.
+    #[serde(default)]
+    pub remotes: HashMap<String, RemoteHostConfig>,
+    /// 실행 시작 시 컨텍스트에 미리 설정되는 시나리오 파라미터.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, String>,
+    /// 값을 로그와 화면에서 가릴 시나리오 파라미터 이름.
+    ///
+    /// YAML에서는 `params` 항목을 `{ value: ..., secret: true }` 형식으로 적으며, 불러오고 저장할 때
+    /// 이 목록과 서로 변환한다.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub secret_params: BTreeSet<String>,
+    /// 파라미터 이름별 값 검증 규칙.
+    ///
+    /// YAML에서는 `params` 항목을 `{ value: ..., date_format: "%Y%m%d" }`처럼 적으며, 불러오고 저장할 때
+    /// 이 맵과 서로 변환한다.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub param_rules: BTreeMap<String, ParamRule>,
+    /// 값을 지정하지 않은 Step에 적용할 공통 기본값.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<StepDefaults>,
+    /// Step별 초당 최대 로그 라인 수. 없으면 엔진 기본값을 쓰고 `0`이면 제한하지 않는다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_lines_per_sec: Option<u32>,
+    /// UI로 전달할 Step 로그 한 줄의 최대 바이트 수. 없으면 엔진 기본값을 쓰고 `0`이면 자르지 않는다.
+    /// 잘린 줄의 원문은 실행 로그 파일에 남는다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_line_max_bytes: Option<usize>,
+    /// SQL·SQL 파일 Step의 문장 실행이 이 시간(초)을 넘기면 그 문장의 실행 계획을 떠서 로그 디렉터리에
+    /// 남긴다. 없으면 남기지 않는다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explain_slow_sql_sec: Option<u64>,
+    /// 실행별 임시 디렉터리(`${RUN_TMPDIR}`) 설정. 없으면 기본 위치에 만들고 종료 후 삭제한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_tmpdir: Option<RunTmpdirConfig>,
+    /// 메인 DAG가 실패하거나 취소되었을 때 실행할 정리 Step. 취소된 뒤에도 실행된다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<OnFailureConfig>,
+    /// 메인 DAG가 끝난 뒤 실행할 결과 요약 조회 목록. 중지한 실행에서는 건너뛴다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub summary: Vec<SummaryQuery>,
+    /// Step이 실패할 때마다 실행해 출력을 실패 기록에 붙이는 진단 명령 목록.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failure_diagnostics: Vec<FailureDiagnostic>,
+    /// 이름별 실행 프로필. 실행 탭이나 `--run --profile <이름>`으로 하나를 골라 실행한다.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, ExecutionProfile>,
+    /// 데몬이 감시할 외부 트리거 파일 설정.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<TriggerConfig>,
+    /// 실행 전 비용 점검 설정. 없으면 기본 기준으로 sqlldr와 Loop만 점검한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_check: Option<CostCheckConfig>,
     /// Step 목록.
     pub steps: Vec<Step>,
+    /// 시나리오 빌더 편집 상태. 실행에는 영향이 없다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<EditorMetadata>,
 }
 
 impl Scenario {
@@ -289,6 +1317,343 @@ impl Scenario {
     pub fn is_empty(&self) -> bool {
         self.steps.is_empty()
     }
+
+    /// Step을 `stage`별로 묶는다.
+    ///
+    /// 스테이지는 처음 등장한 순서를 따르며, 스테이지가 없는 Step은 `None` 묶음에 모인다.
+    pub fn stage_groups(&self) -> Vec<(Option<&str>, Vec<&Step>)> {
+        let mut groups: Vec<(Option<&str>, Vec<&Step>)> = Vec::new();
+        for step in &self.steps {
+            let stage = step.stage.as_deref();
+            match groups.iter_mut().find(|(name, _)| *name == stage) {
+                Some((_, steps)) => steps.push(step),
+                None => groups.push((stage, vec![step])),
+            }
+        }
+        groups
+    }
+
+    /// `on_failure` 정리 Step 목록을 반환한다. 정의가 없으면 빈 슬라이스이다.
+    pub fn cleanup_steps(&self) -> &[Step] {
+        self.on_failure
+            .as_ref()
+            .map(|config| config.steps.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 값을 가려야 하는 변수 이름을 모은다.
+    ///
+    /// 비밀 시나리오 파라미터, `secret: true`인 Extract Step(Loop 내부, `on_failure` 포함)의 변수, DB마다의
+    /// `DB.<키>.password`·`DB.<키>.conn_string`이다.
+    pub fn secret_vars(&self) -> HashSet<String> {
+        fn collect(steps: &[Step], secrets: &mut HashSet<String>) {
+            for step in steps {
+                match &step.kind {
+                    StepKind::Extract { config } if config.secret => {
+                        secrets.insert(config.var_name.clone());
+                    }
+                    StepKind::Loop { config } => collect(&config.steps, secrets),
+                    _ => {}
+                }
+            }
+        }
+        let mut secrets: HashSet<String> = self.secret_params.iter().cloned().collect();
+        collect(&self.steps, &mut secrets);
+        collect(self.cleanup_steps(), &mut secrets);
+        for key in self.db.keys() {
+            secrets.extend(DB_SECRET_FIELDS.iter().map(|field| db_var(key, field)));
+        }
+        secrets
+    }
+
+    /// 창 없는 실행(`--run`, 내보낸 스케줄)에 쓸 실행 프로필을 찾는다.
+    ///
+    /// 창 없는 실행에는 승인할 사람이 없으므로 승인을 요구하는 프로필은 승인 게이트를 건너뛰지 않도록
+    /// 거부한다.
+    ///
+    /// # 반환값
+    /// 시나리오에 없는 프로필이거나 `requires_approval` 프로필이면 오류를 반환한다.
+    pub fn unattended_profile(&self, name: &str) -> anyhow::Result<&ExecutionProfile> {
+        let profile = self.profiles.get(name).ok_or_else(|| {
+            anyhow::anyhow!(tf("scenario.error.profile_missing", &[("name", &name)]))
+        })?;
+        if profile.requires_approval {
+            anyhow::bail!(tf(
+                "scenario.error.profile_requires_approval",
+                &[("name", &name)]
+            ));
+        }
+        Ok(profile)
+    }
+
+    /// 각 Step의 `consumes`가 상위 Step, 시나리오 파라미터 또는 DB 연결 정보 변수에서 제공되는지 검사한다.
+    ///
+    /// # 반환값
+    /// 제공되지 않는 변수마다 하나씩 위반 항목을 반환한다. 비어 있으면 계약이 모두 충족된다.
+    pub fn contract_violations(&self) -> Vec<ContractViolation> {
+        let db_vars: Vec<String> = self
+            .db
+            .keys()
+            .flat_map(|key| DB_VAR_FIELDS.iter().map(move |field| db_var(key, field)))
+            .collect();
+        let mut available: HashSet<&str> = self.params.keys().map(String::as_str).collect();
+        available.insert(RUN_TMPDIR_VAR);
+        available.insert(STEP_TMPDIR_VAR);
+        available.extend(db_vars.iter().map(String::as_str));
+        let mut violations = Vec::new();
+        collect_contract_violations(&self.steps, &available, &mut violations);
+        violations
+    }
+
+    /// 파라미터 값이 `param_rules`를 어기는 항목을 파라미터 이름순으로 모은다.
+    ///
+    /// 실행 프로필, `--param`, 컨텍스트 가져오기로 값을 덮어쓴 뒤의 실행용 사본에 대해 부른다. 규칙이 있는데
+    /// 값이 없는 파라미터는 빈 문자열로 검사한다. 비밀 파라미터의 값은 메시지에서 가린다.
+    pub fn param_violations(&self) -> Vec<ParamViolation> {
+        self.param_rules
+            .iter()
+            .filter_map(|(param, rule)| {
+                let value = self.params.get(param).map(String::as_str).unwrap_or("");
+                let error = rule.check(value)?;
+                let value = if self.secret_params.contains(param) {
+                    "****".to_string()
+                } else {
+                    value.to_string()
+                };
+                Some(ParamViolation {
+                    param: param.clone(),
+                    value,
+                    error,
+                })
+            })
+            .collect()
+    }
+
+    /// 파라미터 값이 `param_rules`를 어기면 어긴 항목을 한 줄씩 이은 오류를 반환한다.
+    ///
+    /// GUI·데몬·트리거·헤드리스 실행이 모두 엔진 시작 전에 이 검사를 거친다.
+    pub fn check_param_rules(&self) -> anyhow::Result<()> {
+        let violations = self.param_violations();
+        if violations.is_empty() {
+            return Ok(());
+        }
+        let lines: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        anyhow::bail!("{}", lines.join("\n"))
+    }
+
+    /// 변수 계약을 어긴 Step이 있으면 위반 항목을 한 줄씩 이은 오류를 반환한다.
+    ///
+    /// 빌더에서는 경고로만 알리고, GUI·데몬·트리거·헤드리스 실행을 시작할 때 이 검사로 막는다.
+    pub fn check_contracts(&self) -> anyhow::Result<()> {
+        let violations = self.contract_violations();
+        if violations.is_empty() {
+            return Ok(());
+        }
+        let lines: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        anyhow::bail!("{}", lines.join("\n"))
+    }
+}
+
+/// 시나리오 파라미터 값 검증 규칙이다. 지정한 조건을 모두 만족해야 한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ParamRule {
+    /// 값 전체가 맞아야 하는 정규식.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// 숫자 값의 최솟값. 지정하면 값이 유한한 숫자여야 한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    /// 숫자 값의 최댓값. 지정하면 값이 유한한 숫자여야 한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// 값이 맞아야 하는 chrono 날짜(또는 날짜·시각) 형식. 예: `%Y%m%d`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+    /// 허용하는 값 목록. 비어 있으면 제한하지 않는다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed: Vec<String>,
+}
+
+impl ParamRule {
+    /// 값이 규칙을 어기면 그 이유를 반환한다. 여러 조건을 어기면 처음 어긴 조건 하나만 알린다.
+    pub fn check(&self, value: &str) -> Option<ParamRuleError> {
+        if !self.allowed.is_empty() && !self.allowed.iter().any(|allowed| allowed == value) {
+            return Some(ParamRuleError::NotAllowed(self.allowed.join(", ")));
+        }
+        if let Some(pattern) = &self.pattern {
+            match regex::Regex::new(&format!("^(?:{pattern})$")) {
+                Ok(re) if re.is_match(value) => {}
+                Ok(_) => return Some(ParamRuleError::Pattern(pattern.clone())),
+                Err(err) => {
+                    return Some(ParamRuleError::InvalidPattern(
+                        pattern.clone(),
+                        err.to_string(),
+                    ));
+                }
+            }
+        }
+        if let Some(format) = &self.date_format
+            && NaiveDate::parse_from_str(value, format).is_err()
+            && NaiveDateTime::parse_from_str(value, format).is_err()
+        {
+            return Some(ParamRuleError::DateFormat(format.clone()));
+        }
+        if self.min.is_some() || self.max.is_some() {
+            // `NaN`·`inf`도 f64로 읽히지만 어떤 범위 비교도 통과시키면 안 되므로 숫자로 보지 않는다.
+            let Some(number) = value
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+            else {
+                return Some(ParamRuleError::NotNumber);
+            };
+            if let Some(min) = self.min
+                && number < min
+            {
+                return Some(ParamRuleError::BelowMin(min));
+            }
+            if let Some(max) = self.max
+                && number > max
+            {
+                return Some(ParamRuleError::AboveMax(max));
+            }
+        }
+        None
+    }
+}
+
+/// 파라미터 값이 어긴 검증 조건이다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamRuleError {
+    /// 허용 값 목록(쉼표로 이은 문자열)에 없다.
+    NotAllowed(String),
+    /// 정규식에 맞지 않는다.
+    Pattern(String),
+    /// 규칙의 정규식 자체가 잘못되었다. 정규식과 오류 내용이다.
+    InvalidPattern(String, String),
+    /// 날짜 형식에 맞지 않는다.
+    DateFormat(String),
+    /// `min`/`max`가 있는데 유한한 숫자가 아니다.
+    NotNumber,
+    /// 최솟값보다 작다.
+    BelowMin(f64),
+    /// 최댓값보다 크다.
+    AboveMax(f64),
+}
+
+/// 검증 규칙을 어긴 시나리오 파라미터이다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamViolation {
+    /// 파라미터 이름.
+    pub param: String,
+    /// 표시할 값. 비밀 파라미터면 가린 값이다.
+    pub value: String,
+    /// 어긴 조건.
+    pub error: ParamRuleError,
+}
+
+impl std::fmt::Display for ParamViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use crate::i18n::tf;
+        let (param, value) = (&self.param, &self.value);
+        let message = match &self.error {
+            ParamRuleError::NotAllowed(allowed) => tf(
+                "scenario.param_not_allowed",
+                &[("param", param), ("value", value), ("allowed", allowed)],
+            ),
+            ParamRuleError::Pattern(pattern) => tf(
+                "scenario.param_pattern",
+                &[("param", param), ("value", value), ("pattern", pattern)],
+            ),
+            ParamRuleError::InvalidPattern(pattern, error) => tf(
+                "scenario.param_invalid_pattern",
+                &[("param", param), ("pattern", pattern), ("error", error)],
+            ),
+            ParamRuleError::DateFormat(format) => tf(
+                "scenario.param_date_format",
+                &[("param", param), ("value", value), ("format", format)],
+            ),
+            ParamRuleError::NotNumber => tf(
+                "scenario.param_not_number",
+                &[("param", param), ("value", value)],
+            ),
+            ParamRuleError::BelowMin(min) => tf(
+                "scenario.param_below_min",
+                &[("param", param), ("value", value), ("min", min)],
+            ),
+            ParamRuleError::AboveMax(max) => tf(
+                "scenario.param_above_max",
+                &[("param", param), ("value", value), ("max", max)],
+            ),
+        };
+        f.write_str(&message)
+    }
+}
+
+/// 변수 계약 위반 항목이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractViolation {
+    /// 변수를 읽는 Step ID.
+    pub step_id: String,
+    /// 어느 상위 Step도 제공하지 않는 변수명.
+    pub var: String,
+}
+
+impl std::fmt::Display for ContractViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&crate::i18n::tf(
+            "scenario.contract_violation",
+            &[("step", &self.step_id), ("var", &self.var)],
+        ))
+    }
+}
+
+/// 같은 레벨의 Step 목록에서 계약 위반을 수집한다.
+///
+/// `available`은 이 레벨 바깥(시나리오 파라미터, Loop 변수, Loop의 상위 Step)에서 이미 제공되는 변수이다.
+fn collect_contract_violations<'a>(
+    steps: &'a [Step],
+    available: &HashSet<&'a str>,
+    violations: &mut Vec<ContractViolation>,
+) {
+    let by_id: HashMap<&str, &Step> = steps.iter().map(|s| (s.id.as_str(), s)).collect();
+    for step in steps {
+        let mut provided = available.clone();
+        for upstream in upstream_steps(step, &by_id) {
+            provided.extend(upstream.produced_vars());
+        }
+        for var in &step.consumes {
+            if !provided.contains(var.as_str()) {
+                violations.push(ContractViolation {
+                    step_id: step.id.clone(),
+                    var: var.clone(),
+                });
+            }
+        }
+        if let StepKind::Loop { config } = &step.kind {
+            provided.insert(config.as_var.as_str());
+            collect_contract_violations(&config.steps, &provided, violations);
+        }
+    }
+}
+
+/// `depends_on`을 따라 직간접 상위 Step을 모은다.
+pub(crate) fn upstream_steps<'a>(step: &Step, by_id: &HashMap<&str, &'a Step>) -> Vec<&'a Step> {
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut stack: Vec<&str> = step.depends_on.iter().map(String::as_str).collect();
+    let mut result = Vec::new();
+    while let Some(id) = stack.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        if let Some(upstream) = by_id.get(id) {
+            result.push(*upstream);
+            stack.extend(upstream.depends_on.iter().map(String::as_str));
+        }
+    }
+    result
 }
 
 /// Step 실행 컨펌 구성을 표현한다.
@@ -309,6 +1674,226 @@ pub struct StepConfirmConfig {
     pub default_answer: ConfirmDefault,
 }
 
+/// Step이 값을 지정하지 않았을 때 적용할 시나리오 공통 기본값이다.
+///
+/// 적재할 때 해당 키가 없는 Step(Loop 하위와 `on_failure` 포함)에 채워 넣고, 저장할 때는
+/// 기본값과 같은 Step 값을 생략하므로 기본값과 같은 값은 상속된 것으로 본다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StepDefaults {
+    /// 기본 재시도 횟수.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<u8>,
+    /// 기본 실행 제한 시간(초 단위).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_sec: Option<u64>,
+    /// Shell Step의 기본 실패 처리 정책.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_policy: Option<ShellErrorPolicy>,
+    /// SQL/SQL 파일 Step의 기본 DB 타겟.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_db: Option<String>,
+    /// 기본 컨펌 설정.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<StepConfirmConfig>,
+}
+
+/// 수동 승인 게이트 구성을 표현한다.
+///
+/// 컨펌과 달리 기본 응답이나 제한 시간이 없으며, 승인될 때까지 무기한 대기한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ManualGateConfig {
+    /// 승인 요청과 함께 보여 줄 안내 문구.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// 대기 Step 구성을 표현한다. `seconds`와 `until_time` 중 하나를 지정한다.
+///
+/// 설정은 시나리오를 불러올 때 검사한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "WaitConfigRepr")]
+pub struct WaitConfig {
+    /// 대기할 초.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seconds: Option<u64>,
+    /// 이 로컬 시각(`HH:MM` 또는 `HH:MM:SS`)까지 대기한다. 이미 지났으면 바로 끝난다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until_time: Option<String>,
+}
+
+impl WaitConfig {
+    /// 현재 시각 `now`부터 기다려야 할 시간을 계산한다.
+    ///
+    /// `seconds`와 `until_time`을 함께 지정했거나 둘 다 비었으면 오류를 반환한다.
+    pub fn remaining(&self, now: NaiveTime) -> anyhow::Result<std::time::Duration> {
+        match (
+            self.seconds,
+            parse_window_time(self.until_time.as_deref(), "until_time")?,
+        ) {
+            (Some(_), Some(_)) => {
+                anyhow::bail!(t("scenario.error.wait_both"))
+            }
+            (Some(secs), None) => Ok(std::time::Duration::from_secs(secs)),
+            (None, Some(until)) => Ok((until - now).to_std().unwrap_or_default()),
+            (None, None) => anyhow::bail!(t("scenario.error.wait_missing")),
+        }
+    }
+
+    /// `seconds`와 `until_time` 중 하나만 지정했고 `until_time`을 시각으로 해석할 수 있는지 검사한다.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.remaining(NaiveTime::MIN).map(|_| ())
+    }
+}
+
+/// 검사 전 YAML의 대기 Step 구성이다.
+#[derive(Deserialize)]
+struct WaitConfigRepr {
+    #[serde(default)]
+    seconds: Option<u64>,
+    #[serde(default)]
+    until_time: Option<String>,
+}
+
+impl TryFrom<WaitConfigRepr> for WaitConfig {
+    type Error = String;
+
+    /// 실행 때에야 실패할 대기 설정을 미리 거부한다.
+    fn try_from(repr: WaitConfigRepr) -> Result<Self, Self::Error> {
+        let config = Self {
+            seconds: repr.seconds,
+            until_time: repr.until_time,
+        };
+        config.validate().map_err(|err| err.to_string())?;
+        Ok(config)
+    }
+}
+
+/// 다른 시나리오의 실행 완료 대기 Step 구성을 표현한다.
+///
+/// 실행 이력 파일에서 `scenario`의 실행 중 모든 Step이 성공하고 `since`보다 뒤에 끝난 실행을 찾는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitForRunConfig {
+    /// 기다릴 시나리오 이름(시나리오 파일의 `name`).
+    pub scenario: String,
+    /// 이 시각보다 뒤에 끝난 실행만 인정한다. 플레이스홀더를 쓸 수 있으며, 비우면 오늘 0시이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// 실행 이력을 다시 확인하는 간격(초).
+    #[serde(default = "default_wait_for_run_poll_sec")]
+    pub poll_sec: u64,
+    /// 이 시간(초) 안에 실행이 없으면 실패한다. 비우면 무기한 기다린다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_wait_sec: Option<u64>,
+}
+
+impl Default for WaitForRunConfig {
+    fn default() -> Self {
+        Self {
+            scenario: String::new(),
+            since: None,
+            poll_sec: default_wait_for_run_poll_sec(),
+            max_wait_sec: None,
+        }
+    }
+}
+
+impl WaitForRunConfig {
+    /// 치환한 `since` 값을 기준 시각으로 해석한다.
+    ///
+    /// RFC 3339, `YYYY-MM-DD HH:MM[:SS]`, `YYYY-MM-DD`, `YYYYMMDD`(그날 0시), 오늘의 `HH:MM[:SS]`를
+    /// 받으며, 비었으면 `now`가 속한 날의 0시이다.
+    pub fn since_time(
+        since: Option<&str>,
+        now: DateTime<Local>,
+    ) -> anyhow::Result<DateTime<Local>> {
+        let Some(value) = since.map(str::trim).filter(|v| !v.is_empty()) else {
+            return local_time(now.date_naive().and_time(NaiveTime::MIN));
+        };
+        if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+            return Ok(time.with_timezone(&Local));
+        }
+        let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M"))
+            .or_else(|_| {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .or_else(|_| NaiveDate::parse_from_str(value, "%Y%m%d"))
+                    .map(|date| date.and_time(NaiveTime::MIN))
+            })
+            .or_else(|_| {
+                parse_window_time(Some(value), "since")
+                    .ok()
+                    .flatten()
+                    .map(|time| now.date_naive().and_time(time))
+                    .ok_or(())
+            })
+            .map_err(|_| {
+                anyhow::anyhow!(tf("scenario.error.since_format", &[("value", &value)]))
+            })?;
+        local_time(naive)
+    }
+}
+
+/// 로컬 날짜·시각을 시간대가 있는 시각으로 바꾼다. 일광 절약 시간으로 겹치면 앞선 시각을 쓴다.
+fn local_time(naive: NaiveDateTime) -> anyhow::Result<DateTime<Local>> {
+    naive
+        .and_local_timezone(Local)
+        .earliest()
+        .ok_or_else(|| anyhow::anyhow!(tf("scenario.error.local_time", &[("time", &naive)])))
+}
+
+/// 검증 Step 구성을 표현한다.
+///
+/// 기대값은 `expected`(플레이스홀더로 컨텍스트 변수를 쓸 수 있다)와 `expected_file` 중 하나로 주며,
+/// 둘 다 비우면 체크섬 방식은 `<file>.md5`/`<file>.sha256` 사이드카 파일을 읽는다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VerifyConfig {
+    /// 검증할 파일 경로.
+    pub file: String,
+    /// 계산 방식.
+    #[serde(default)]
+    pub method: VerifyMethod,
+    /// 기대값.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// 기대값을 읽을 파일. 첫 줄의 첫 단어를 쓰므로 `md5sum` 출력 형식도 읽는다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_file: Option<String>,
+}
+
+/// 검증 Step의 계산 방식이다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerifyMethod {
+    /// MD5 체크섬(16진수).
+    Md5,
+    /// SHA-256 체크섬(16진수).
+    Sha256,
+    /// 줄 수. 마지막 줄에 줄바꿈이 없어도 한 줄로 센다.
+    Lines,
+}
+
+impl VerifyMethod {
+    /// 선택 가능한 전체 방식 목록이다.
+    pub const ALL: [VerifyMethod; 3] =
+        [VerifyMethod::Md5, VerifyMethod::Sha256, VerifyMethod::Lines];
+
+    /// YAML에 쓰는 방식 이름을 반환한다.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VerifyMethod::Md5 => "md5",
+            VerifyMethod::Sha256 => "sha256",
+            VerifyMethod::Lines => "lines",
+        }
+    }
+}
+
+impl Default for VerifyMethod {
+    /// 기본 방식은 SHA-256이다.
+    fn default() -> Self {
+        VerifyMethod::Sha256
+    }
+}
+
 /// 컨펌 기본 응답 값을 표현한다.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -334,16 +1919,433 @@ fn default_timeout() -> u64 {
     60
 }
 
+fn default_on_failure_timeout_sec() -> u64 {
+    300
+}
+
+fn default_tmpdir_keep_last() -> usize {
+    5
+}
+
+fn default_wait_for_run_poll_sec() -> u64 {
+    60
+}
+
+fn default_trigger_poll_sec() -> u64 {
+    10
+}
+
+fn default_trigger_debounce_ms() -> u64 {
+    2000
+}
+
+fn default_cost_large_file_mb() -> u64 {
+    1024
+}
+
+fn default_cost_max_glob_files() -> usize {
+    1000
+}
+
+fn default_inherit_env() -> bool {
+    true
+}
+
+fn is_inherit_env(value: &bool) -> bool {
+    *value
+}
+
 /// YAML 파일을 읽어 Scenario로 역직렬화한다.
+///
+/// `*.enc` 파일은 [`resolve_passphrase`]로 찾은 암호 문구로 복호화한 뒤 파싱한다. 형식 오류에는
+/// 파일 경로를 붙인다.
 pub fn load_scenario_from_file(path: &Path) -> anyhow::Result<Scenario> {
-    let mut file = File::open(path)?;
-    load_scenario_from_reader(&mut file)
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    let result = if is_encrypted_path(path) {
+        let data = std::fs::read(path)?;
+        let plaintext = decrypt(&data, &resolve_passphrase()?)?;
+        load_scenario_in_dir(&mut plaintext.as_slice(), base_dir)
+    } else {
+        let mut file = File::open(path)?;
+        load_scenario_in_dir(&mut file, base_dir)
+    };
+    result.map_err(|err| match err.downcast::<ScenarioParseError>() {
+        Ok(parse_err) => parse_err.with_file(path).into(),
+        Err(err) => err,
+    })
 }
 
 /// Reader에서 YAML을 읽어 Scenario 구조체로 파싱한다.
+///
+/// YAML 문법이나 구조가 잘못되면 위치·Step ID·힌트를 담은 [`ScenarioParseError`]를 반환한다.
+/// `include` 조각 파일의 상대 경로는 현재 디렉터리 기준이다.
 pub fn load_scenario_from_reader<R: Read>(reader: &mut R) -> anyhow::Result<Scenario> {
+    load_scenario_in_dir(reader, Path::new(""))
+}
+
+/// Reader에서 YAML을 읽어 `include` 조각을 `base_dir` 기준으로 펼친 뒤 Scenario로 파싱한다.
+fn load_scenario_in_dir<R: Read>(reader: &mut R, base_dir: &Path) -> anyhow::Result<Scenario> {
     let mut buf = String::new();
     reader.read_to_string(&mut buf)?;
-    let scenario: Scenario = serde_yaml::from_str(&buf)?;
+    let mut value: serde_yaml::Value =
+        serde_yaml::from_str(&buf).map_err(|err| ScenarioParseError::from_syntax(&err, &buf))?;
+    let origins = resolve_step_includes(&mut value, base_dir)?;
+    unwrap_param_entries(&mut value);
+    let defaults = value.get("defaults").cloned();
+    if let Some(serde_yaml::Value::Mapping(defaults)) = &defaults {
+        visit_step_lists(&mut value, &mut |step| apply_step_defaults(step, defaults));
+    }
+    let mut scenario = Scenario::deserialize(&value)
+        .map_err(|err| ScenarioParseError::from_structure(&err, &value, &buf))?;
+    for step in &mut scenario.steps {
+        step.included_from = origins.get(&step.id).cloned();
+    }
     Ok(scenario)
 }
+
+/// Scenario를 YAML 문자열로 직렬화한다.
+///
+/// `defaults`가 있으면 기본값과 같은 Step 값을 생략해 상속 관계를 유지한다.
+pub fn scenario_to_yaml(scenario: &Scenario) -> anyhow::Result<String> {
+    let mut value = serde_yaml::to_value(scenario)?;
+    let defaults = value.get("defaults").cloned();
+    if let Some(serde_yaml::Value::Mapping(defaults)) = &defaults {
+        visit_step_lists(&mut value, &mut |step| strip_step_defaults(step, defaults));
+    }
+    collapse_step_includes(&mut value, &scenario.steps);
+    wrap_param_entries(&mut value);
+    Ok(serde_yaml::to_string(&value)?)
+}
+
+/// 최상위 `steps`의 `include` 항목을 조각 파일의 Step으로 펼친다.
+///
+/// 조각 파일은 `steps:` 목록을 가진 매핑이거나 Step 목록 자체이다. 조각의 Step ID(Loop 하위 포함)에
+/// 접두사를 붙이고, 조각 안 Step끼리의 `depends_on`과 `${STEP.<id>.` 참조도 바꾼 ID로 옮긴다. 조각
+/// 안에서 `depends_on`이 없는 Step에는 `include` 항목의 `depends_on`을 넣는다. 조각 파일 안의
+/// `include`는 지원하지 않는다.
+///
+/// # 반환값
+/// 펼친 최상위 Step ID별 출처 `include` 항목.
+fn resolve_step_includes(
+    scenario: &mut serde_yaml::Value,
+    base_dir: &Path,
+) -> anyhow::Result<HashMap<String, StepInclude>> {
+    let mut origins = HashMap::new();
+    let Some(serde_yaml::Value::Sequence(steps)) = scenario.get_mut("steps") else {
+        return Ok(origins);
+    };
+    if !steps.iter().any(|entry| entry.get("include").is_some()) {
+        return Ok(origins);
+    }
+    let mut resolved = Vec::with_capacity(steps.len());
+    for entry in std::mem::take(steps) {
+        if entry.get("include").is_none() {
+            resolved.push(entry);
+            continue;
+        }
+        let include: StepInclude = serde_yaml::from_value(entry).map_err(|err| {
+            anyhow::anyhow!(tf("scenario.error.include_format", &[("error", &err)]))
+        })?;
+        let path = base_dir.join(&include.include);
+        let content = std::fs::read_to_string(&path).map_err(|err| {
+            anyhow::anyhow!(tf(
+                "scenario.error.fragment_read",
+                &[("path", &path.display()), ("error", &err)]
+            ))
+        })?;
+        let fragment: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|err| {
+            anyhow::anyhow!(tf(
+                "scenario.error.fragment_parse",
+                &[("path", &path.display()), ("error", &err)]
+            ))
+        })?;
+        let fragment_steps = match fragment {
+            serde_yaml::Value::Sequence(list) => list,
+            serde_yaml::Value::Mapping(mut root) => match root.remove("steps") {
+                Some(serde_yaml::Value::Sequence(list)) => list,
+                _ => anyhow::bail!(tf(
+                    "scenario.error.fragment_no_steps",
+                    &[("path", &path.display())]
+                )),
+            },
+            _ => anyhow::bail!(tf(
+                "scenario.error.fragment_no_steps",
+                &[("path", &path.display())]
+            )),
+        };
+        if fragment_steps
+            .iter()
+            .any(|step| step.get("include").is_some())
+        {
+            anyhow::bail!(tf(
+                "scenario.error.fragment_nested_include",
+                &[("path", &path.display())]
+            ));
+        }
+        let prefix = include.id_prefix();
+        let mut ids = HashSet::new();
+        collect_fragment_ids(&fragment_steps, &mut ids);
+        for mut step in fragment_steps {
+            prefix_fragment_ids(&mut step, &prefix, &ids);
+            rename_step_refs(&mut step, &prefix, &ids);
+            if let serde_yaml::Value::Mapping(fields) = &mut step {
+                let rooted = fields
+                    .get("depends_on")
+                    .and_then(serde_yaml::Value::as_sequence)
+                    .is_none_or(|deps| deps.is_empty());
+                if rooted && !include.depends_on.is_empty() {
+                    fields.insert(
+                        "depends_on".into(),
+                        serde_yaml::to_value(&include.depends_on)?,
+                    );
+                }
+                if let Some(id) = fields.get("id").and_then(serde_yaml::Value::as_str) {
+                    origins.insert(id.to_string(), include.clone());
+                }
+            }
+            resolved.push(step);
+        }
+    }
+    *steps = resolved;
+    Ok(origins)
+}
+
+/// 조각 Step 목록의 모든 Step ID(Loop 하위 포함)를 모은다.
+fn collect_fragment_ids(steps: &[serde_yaml::Value], ids: &mut HashSet<String>) {
+    for step in steps {
+        if let Some(id) = step.get("id").and_then(serde_yaml::Value::as_str) {
+            ids.insert(id.to_string());
+        }
+        if let Some(serde_yaml::Value::Sequence(children)) =
+            step.get("loop").and_then(|config| config.get("steps"))
+        {
+            collect_fragment_ids(children, ids);
+        }
+    }
+}
+
+/// 조각 Step(Loop 하위 포함)의 ID와 조각 안 Step을 가리키는 `depends_on`에 접두사를 붙인다.
+fn prefix_fragment_ids(step: &mut serde_yaml::Value, prefix: &str, ids: &HashSet<String>) {
+    let serde_yaml::Value::Mapping(fields) = step else {
+        return;
+    };
+    if let Some(serde_yaml::Value::String(id)) = fields.get_mut("id") {
+        *id = format!("{prefix}{id}");
+    }
+    if let Some(serde_yaml::Value::Sequence(deps)) = fields.get_mut("depends_on") {
+        for dep in deps {
+            if let serde_yaml::Value::String(dep) = dep
+                && ids.contains(dep.as_str())
+            {
+                *dep = format!("{prefix}{dep}");
+            }
+        }
+    }
+    if let Some(serde_yaml::Value::Sequence(children)) = fields
+        .get_mut("loop")
+        .and_then(|config| config.get_mut("steps"))
+    {
+        for child in children {
+            prefix_fragment_ids(child, prefix, ids);
+        }
+    }
+}
+
+/// 조각 Step 안 문자열의 `${STEP.<id>.` 참조를 접두사를 붙인 ID로 바꾼다.
+fn rename_step_refs(value: &mut serde_yaml::Value, prefix: &str, ids: &HashSet<String>) {
+    match value {
+        serde_yaml::Value::String(text) if text.contains("${STEP.") => {
+            for id in ids {
+                let from = format!("${{STEP.{id}.");
+                if text.contains(&from) {
+                    *text = text.replace(&from, &format!("${{STEP.{prefix}{id}."));
+                }
+            }
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                rename_step_refs(item, prefix, ids);
+            }
+        }
+        serde_yaml::Value::Mapping(fields) => {
+            for (_, item) in fields.iter_mut() {
+                rename_step_refs(item, prefix, ids);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 조각 파일에서 펼친 최상위 Step을 처음 나온 자리의 `include` 항목 하나로 되돌린다.
+fn collapse_step_includes(scenario: &mut serde_yaml::Value, steps: &[Step]) {
+    if steps.iter().all(|step| step.included_from.is_none()) {
+        return;
+    }
+    let Some(serde_yaml::Value::Sequence(list)) = scenario.get_mut("steps") else {
+        return;
+    };
+    let mut emitted: Vec<&StepInclude> = Vec::new();
+    let collapsed = std::mem::take(list)
+        .into_iter()
+        .zip(steps)
+        .filter_map(|(entry, step)| {
+            let Some(include) = &step.included_from else {
+                return Some(entry);
+            };
+            if emitted.contains(&include) {
+                return None;
+            }
+            emitted.push(include);
+            serde_yaml::to_value(include).ok()
+        })
+        .collect();
+    *list = collapsed;
+}
+
+/// `params`의 `{ value, secret, ...규칙 }` 항목을 문자열 값으로 풀고, 비밀 항목 이름은 `secret_params`에,
+/// 나머지 필드는 검증 규칙으로 `param_rules`에 모은다.
+fn unwrap_param_entries(scenario: &mut serde_yaml::Value) {
+    let mut secrets = Vec::new();
+    let mut rules = serde_yaml::Mapping::new();
+    if let Some(serde_yaml::Value::Mapping(params)) = scenario.get_mut("params") {
+        for (key, entry) in params.iter_mut() {
+            let serde_yaml::Value::Mapping(fields) = entry else {
+                continue;
+            };
+            if fields.get("secret") == Some(&serde_yaml::Value::Bool(true)) {
+                secrets.push(key.clone());
+            }
+            let value = fields.remove("value").unwrap_or(serde_yaml::Value::Null);
+            fields.remove("secret");
+            if !fields.is_empty() {
+                rules.insert(key.clone(), serde_yaml::Value::Mapping(fields.clone()));
+            }
+            *entry = value;
+        }
+    }
+    let Some(root) = scenario.as_mapping_mut() else {
+        return;
+    };
+    if !secrets.is_empty() {
+        if let Some(serde_yaml::Value::Sequence(list)) = root.get_mut("secret_params") {
+            list.extend(secrets);
+        } else {
+            root.insert("secret_params".into(), serde_yaml::Value::Sequence(secrets));
+        }
+    }
+    if !rules.is_empty() {
+        if let Some(serde_yaml::Value::Mapping(existing)) = root.get_mut("param_rules") {
+            existing.extend(rules);
+        } else {
+            root.insert("param_rules".into(), serde_yaml::Value::Mapping(rules));
+        }
+    }
+}
+
+/// `secret_params`와 `param_rules`에 있는 파라미터를 `params`의 `{ value, secret: true, ...규칙 }` 형식으로
+/// 되돌린다.
+fn wrap_param_entries(scenario: &mut serde_yaml::Value) {
+    let Some(root) = scenario.as_mapping_mut() else {
+        return;
+    };
+    let secrets = match root.remove("secret_params") {
+        Some(serde_yaml::Value::Sequence(secrets)) => secrets,
+        _ => Vec::new(),
+    };
+    let rules = match root.remove("param_rules") {
+        Some(serde_yaml::Value::Mapping(rules)) => rules,
+        _ => serde_yaml::Mapping::new(),
+    };
+    let Some(serde_yaml::Value::Mapping(params)) = root.get_mut("params") else {
+        return;
+    };
+    for (key, entry) in params.iter_mut() {
+        let secret = secrets.contains(key);
+        let rule = rules.get(key);
+        if !secret && rule.is_none() {
+            continue;
+        }
+        let mut fields = serde_yaml::Mapping::new();
+        fields.insert("value".into(), entry.clone());
+        if secret {
+            fields.insert("secret".into(), serde_yaml::Value::Bool(true));
+        }
+        if let Some(serde_yaml::Value::Mapping(rule)) = rule {
+            fields.extend(rule.clone());
+        }
+        *entry = serde_yaml::Value::Mapping(fields);
+    }
+}
+
+/// 시나리오 YAML 값 안의 모든 Step 매핑(Loop 하위, `on_failure` 포함)에 `visit`을 적용한다.
+fn visit_step_lists(
+    scenario: &mut serde_yaml::Value,
+    visit: &mut dyn FnMut(&mut serde_yaml::Mapping),
+) {
+    fn visit_steps(
+        steps: Option<&mut serde_yaml::Value>,
+        visit: &mut dyn FnMut(&mut serde_yaml::Mapping),
+    ) {
+        let Some(serde_yaml::Value::Sequence(steps)) = steps else {
+            return;
+        };
+        for step in steps {
+            let serde_yaml::Value::Mapping(step) = step else {
+                continue;
+            };
+            visit(step);
+            let children = step
+                .get_mut("loop")
+                .and_then(|config| config.get_mut("steps"));
+            visit_steps(children, visit);
+        }
+    }
+    visit_steps(scenario.get_mut("steps"), visit);
+    let cleanup = scenario
+        .get_mut("on_failure")
+        .and_then(|config| config.get_mut("steps"));
+    visit_steps(cleanup, visit);
+}
+
+/// `defaults`에 있는 키 중 Step에 없는 값을 채운다.
+fn apply_step_defaults(step: &mut serde_yaml::Mapping, defaults: &serde_yaml::Mapping) {
+    for key in ["retry", "timeout_sec", "confirm"] {
+        if let Some(value) = defaults.get(key)
+            && !step.contains_key(key)
+        {
+            step.insert(key.into(), value.clone());
+        }
+    }
+    let kind = step.get("kind").and_then(serde_yaml::Value::as_str);
+    if let Some(value) = defaults.get("target_db")
+        && matches!(kind, Some("sql" | "sql_file"))
+        && !step.contains_key("target_db")
+    {
+        step.insert("target_db".into(), value.clone());
+    }
+    if let Some(value) = defaults.get("error_policy")
+        && kind == Some("shell")
+        && let Some(serde_yaml::Value::Mapping(shell)) = step.get_mut("shell")
+        && !shell.contains_key("error_policy")
+    {
+        shell.insert("error_policy".into(), value.clone());
+    }
+}
+
+/// Step 값 중 `defaults`와 같은 값을 지운다.
+fn strip_step_defaults(step: &mut serde_yaml::Mapping, defaults: &serde_yaml::Mapping) {
+    for key in ["retry", "timeout_sec", "confirm", "target_db"] {
+        if defaults
+            .get(key)
+            .is_some_and(|value| step.get(key) == Some(value))
+        {
+            step.remove(key);
+        }
+    }
+    if let Some(value) = defaults.get("error_policy")
+        && let Some(serde_yaml::Value::Mapping(shell)) = step.get_mut("shell")
+        && shell.get("error_policy") == Some(value)
+    {
+        shell.remove("error_policy");
+    }
+}
@@ -0,0 +1,124 @@
+use crate::i18n::t;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use once_cell::sync::Lazy;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// 암호화된 시나리오 파일의 확장자이다. (`*.yaml.enc`)
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// 암호 문구를 지정하는 환경 변수 이름이다.
+pub const PASSPHRASE_ENV: &str = "RUST_AIRFLOW_SCENARIO_PASSPHRASE";
+
+/// OS 키링에서 암호 문구를 찾을 서비스 이름이다.
+pub const KEYRING_SERVICE: &str = "rust-airflow";
+
+/// OS 키링에서 암호 문구를 찾을 계정 이름이다.
+pub const KEYRING_USER: &str = "scenario-passphrase";
+
+/// 파일 머리에 기록하는 형식 식별자이다.
+const MAGIC: &[u8; 6] = b"RAENC1";
+
+/// 키 유도 솔트 길이이다.
+const SALT_LEN: usize = 16;
+
+/// AES-GCM 논스 길이이다.
+const NONCE_LEN: usize = 12;
+
+/// PBKDF2 반복 횟수이다.
+const KDF_ROUNDS: u32 = 210_000;
+
+/// 이번 실행 동안 다이얼로그에서 입력한 암호 문구이다.
+static SESSION_PASSPHRASE: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+/// 암호화 시나리오 처리 중 발생하는 오류이다.
+#[derive(Debug, thiserror::Error)]
+pub enum ScenarioCryptoError {
+    /// 환경 변수, OS 키링, 입력한 암호 문구가 모두 없는 경우이다.
+    #[error("{}", t("crypto.error.passphrase_required"))]
+    PassphraseRequired,
+    /// 암호 문구가 틀렸거나 파일이 변조된 경우이다.
+    #[error("{}", t("crypto.error.wrong_passphrase"))]
+    WrongPassphrase,
+    /// 암호화 시나리오 형식이 아닌 경우이다.
+    #[error("{}", t("crypto.error.invalid_format"))]
+    InvalidFormat,
+}
+
+/// 경로가 암호화된 시나리오(`*.enc`)인지 확인한다.
+pub fn is_encrypted_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(ENCRYPTED_EXTENSION))
+}
+
+/// 이번 실행 동안 사용할 암호 문구를 기억한다.
+pub fn remember_passphrase(passphrase: String) {
+    if let Ok(mut slot) = SESSION_PASSPHRASE.lock() {
+        *slot = Some(passphrase);
+    }
+}
+
+/// 사용할 암호 문구를 찾는다.
+///
+/// 다이얼로그에서 입력한 값, `RUST_AIRFLOW_SCENARIO_PASSPHRASE`, OS 키링 순으로 확인한다.
+pub fn resolve_passphrase() -> Result<String, ScenarioCryptoError> {
+    if let Some(passphrase) = SESSION_PASSPHRASE.lock().ok().and_then(|slot| slot.clone()) {
+        return Ok(passphrase);
+    }
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV)
+        && !passphrase.is_empty()
+    {
+        return Ok(passphrase);
+    }
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)
+        .and_then(|entry| entry.get_password())
+        .map_err(|_| ScenarioCryptoError::PassphraseRequired)
+}
+
+/// 암호 문구와 솔트로 AES-256 키를 유도한다.
+fn derive_key(passphrase: &str, salt: &[u8]) -> Key<Aes256Gcm> {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<sha2::Sha256>(passphrase.as_bytes(), salt, KDF_ROUNDS, &mut key);
+    key.into()
+}
+
+/// 평문을 암호화해 `매직 + 솔트 + 논스 + 암호문` 형식으로 반환한다.
+///
+/// # 매개변수
+/// - `plaintext`: 암호화할 YAML 바이트.
+/// - `passphrase`: 암호 문구.
+pub fn encrypt(plaintext: &[u8], passphrase: &str) -> anyhow::Result<Vec<u8>> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, &salt));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| anyhow::anyhow!(t("crypto.error.encrypt_failed")))?;
+    let mut out = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// [`encrypt`]로 만든 바이트를 복호화한다.
+///
+/// # 매개변수
+/// - `data`: 암호화된 파일 내용.
+/// - `passphrase`: 암호 문구.
+pub fn decrypt(data: &[u8], passphrase: &str) -> Result<Vec<u8>, ScenarioCryptoError> {
+    let header = MAGIC.len() + SALT_LEN + NONCE_LEN;
+    if data.len() < header || !data.starts_with(MAGIC) {
+        return Err(ScenarioCryptoError::InvalidFormat);
+    }
+    let salt = &data[MAGIC.len()..MAGIC.len() + SALT_LEN];
+    let nonce = Nonce::from_slice(&data[MAGIC.len() + SALT_LEN..header]);
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, salt));
+    cipher
+        .decrypt(nonce, &data[header..])
+        .map_err(|_| ScenarioCryptoError::WrongPassphrase)
+}
@@ -0,0 +1,413 @@
+use crate::i18n::{t, tf};
+use crate::scenario::Step;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Step 유형 이름과 그 유형의 설정을 담는 키 목록이다.
+const KIND_CONFIG_KEYS: [(&str, &str); 9] = [
+    ("sql", "sql"),
+    ("sql_file", "sql_file"),
+    ("sql_loader_par", "sqlldr"),
+    ("shell", "shell"),
+    ("extract", "extract"),
+    ("loop", "loop"),
+    ("manual_gate", "gate"),
+    ("wait", "wait"),
+    ("verify", "verify"),
+];
+
+/// 오타로 볼 최대 편집 거리이다.
+const TYPO_DISTANCE: usize = 2;
+
+/// serde 메시지 끝의 `at line X column Y` 위치 표기이다.
+static LOCATION_SUFFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r",? at line \d+ column \d+$").expect("위치 정규식"));
+
+/// serde_yaml이 메시지 앞에 붙이는 값 경로(`steps[2].loop: `)이다.
+static PATH_PREFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[A-Za-z0-9_\[\]\.]+: ").expect("경로 정규식"));
+
+/// 메시지 안의 역따옴표로 감싼 이름이다.
+static BACKTICKED: Lazy<Regex> = Lazy::new(|| Regex::new(r"`([^`]+)`").expect("역따옴표 정규식"));
+
+/// 시나리오 YAML을 읽다가 난 오류를 작성자가 고칠 수 있도록 위치와 힌트를 붙여 표현한다.
+///
+/// serde_yaml 오류는 파싱 단계에 따라 위치가 없거나(`at line 0`) 어느 Step인지 알 수 없으므로,
+/// 원문과 YAML 값을 다시 살펴 파일 안의 줄·열, 문제가 된 Step ID, 고칠 방법을 찾아 둔다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioParseError {
+    /// 시나리오 파일 경로. 파일이 아닌 입력에서 읽었으면 `None`이다.
+    pub file: Option<PathBuf>,
+    /// 오류 위치의 줄 번호(1부터).
+    pub line: Option<usize>,
+    /// 오류 위치의 열 번호(1부터).
+    pub column: Option<usize>,
+    /// 오류가 난 Step ID.
+    pub step_id: Option<String>,
+    /// 위치 표기를 뗀 serde_yaml 오류 메시지.
+    pub message: String,
+    /// 고칠 방법에 대한 힌트.
+    pub hint: Option<String>,
+    /// 오류 위치의 원문 한 줄.
+    pub source_line: Option<String>,
+}
+
+impl ScenarioParseError {
+    /// YAML 문법 오류를 변환한다. 문법 오류에는 serde_yaml이 위치를 알려 준다.
+    pub fn from_syntax(err: &serde_yaml::Error, source: &str) -> Self {
+        let message = clean_message(&err.to_string());
+        let mut error = Self::new(message);
+        if let Some(location) = err.location() {
+            error.set_location(source, location.line(), Some(location.column()));
+            error.step_id = enclosing_step_id(source, location.line());
+        }
+        error.hint = syntax_hint(&error.message, error.source_line.as_deref());
+        error
+    }
+
+    /// 값 구조 오류(필드 누락, 잘못된 유형 등)를 변환한다.
+    ///
+    /// YAML 값에서 Step을 하나씩 다시 역직렬화해 가장 안쪽의 실패한 Step을 찾고, 그 Step 블록에서
+    /// 메시지에 나온 키나 값을 찾아 위치를 정한다.
+    ///
+    /// # 매개변수
+    /// - `err`: 시나리오 전체를 역직렬화하다 난 오류.
+    /// - `value`: 기본값 적용까지 마친 시나리오 YAML 값.
+    /// - `source`: 시나리오 원문.
+    pub fn from_structure(
+        err: &serde_yaml::Error,
+        value: &serde_yaml::Value,
+        source: &str,
+    ) -> Self {
+        let failing = find_failing_step(value);
+        let message = match &failing {
+            Some((_, step_err)) => clean_message(&step_err.to_string()),
+            None => clean_message(&err.to_string()),
+        };
+        let mut error = Self::new(message);
+        let mapping = match &failing {
+            Some((step, _)) => step.as_mapping(),
+            None => value.as_mapping(),
+        };
+        let step_id = failing.as_ref().and_then(|(step, _)| {
+            step.get("id")
+                .and_then(serde_yaml::Value::as_str)
+                .map(str::to_string)
+        });
+        let (hint, focus) = mapping
+            .map(|mapping| structure_hint(&error.message, mapping))
+            .unwrap_or_default();
+        error.hint = hint;
+        let block = match &step_id {
+            Some(id) => step_block(source, id),
+            None => Some((0, source.lines().count())),
+        };
+        if let Some((start, end)) = block {
+            let line = focus
+                .and_then(|token| find_token_line(source, start, end, &token))
+                .or_else(|| step_id.is_some().then_some(start));
+            if let Some(line) = line {
+                error.set_location(source, line + 1, None);
+            }
+        }
+        error.step_id = step_id;
+        error
+    }
+
+    /// 오류가 난 파일 경로를 붙인다.
+    pub fn with_file(mut self, path: &Path) -> Self {
+        self.file = Some(path.to_path_buf());
+        self
+    }
+
+    /// `line:column` 형식의 위치 표기를 반환한다. 줄을 모르면 `None`이다.
+    pub fn location_label(&self) -> Option<String> {
+        let line = self.line?;
+        Some(match self.column {
+            Some(column) => format!("{line}:{column}"),
+            None => line.to_string(),
+        })
+    }
+
+    /// 메시지만 담은 오류를 만든다.
+    fn new(message: String) -> Self {
+        Self {
+            file: None,
+            line: None,
+            column: None,
+            step_id: None,
+            message,
+            hint: None,
+            source_line: None,
+        }
+    }
+
+    /// 1부터 세는 줄·열 위치와 그 줄의 원문을 기록한다.
+    fn set_location(&mut self, source: &str, line: usize, column: Option<usize>) {
+        self.line = Some(line);
+        self.column = column;
+        self.source_line = source
+            .lines()
+            .nth(line.saturating_sub(1))
+            .map(|text| text.trim_end().to_string());
+    }
+}
+
+impl fmt::Display for ScenarioParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut prefix = String::new();
+        if let Some(file) = &self.file {
+            prefix.push_str(&file.display().to_string());
+        }
+        if let Some(location) = self.location_label() {
+            if !prefix.is_empty() {
+                prefix.push(':');
+            }
+            prefix.push_str(&location);
+        }
+        let title = t("parse_error.window");
+        if prefix.is_empty() {
+            write!(f, "{title}: {}", self.message)?;
+        } else {
+            write!(f, "{prefix}: {title}: {}", self.message)?;
+        }
+        if let Some(step_id) = &self.step_id {
+            write!(f, "\n  Step: {step_id}")?;
+        }
+        if let (Some(line), Some(text)) = (self.line, &self.source_line) {
+            write!(f, "\n  {line} | {text}")?;
+            if let Some(column) = self.column {
+                let gutter = line.to_string().len() + 3;
+                write!(f, "\n  {}^", " ".repeat(gutter + column.saturating_sub(1)))?;
+            }
+        }
+        if let Some(hint) = &self.hint {
+            write!(f, "\n  {}", tf("parse_error.hint_text", &[("hint", hint)]))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ScenarioParseError {}
+
+/// serde_yaml 메시지에서 값 경로와 위치 표기를 뗀다.
+fn clean_message(message: &str) -> String {
+    let message = LOCATION_SUFFIX.replace(message, "");
+    PATH_PREFIX.replace(&message, "").into_owned()
+}
+
+/// 문법 오류 메시지에 맞는 힌트를 고른다.
+fn syntax_hint(message: &str, source_line: Option<&str>) -> Option<String> {
+    if source_line.is_some_and(|line| line.starts_with(|ch: char| ch.is_whitespace() && ch != ' '))
+        || message.contains("cannot start any token")
+    {
+        return Some(t("parse_error.hint_tabs").to_string());
+    }
+    if message.contains("mapping values are not allowed") {
+        return Some(t("parse_error.hint_quote_colon").to_string());
+    }
+    if message.contains("did not find expected") || message.contains("could not find expected") {
+        return Some(t("parse_error.hint_indent").to_string());
+    }
+    if message.contains("duplicate") {
+        return Some(t("parse_error.hint_duplicate").to_string());
+    }
+    None
+}
+
+/// 구조 오류 메시지와 문제가 된 매핑으로 힌트와 위치를 찾을 키(또는 값)를 고른다.
+fn structure_hint(
+    message: &str,
+    mapping: &serde_yaml::Mapping,
+) -> (Option<String>, Option<String>) {
+    let names: Vec<&str> = BACKTICKED
+        .captures_iter(message)
+        .filter_map(|caps| caps.get(1).map(|name| name.as_str()))
+        .collect();
+    let keys: Vec<&str> = mapping
+        .keys()
+        .filter_map(serde_yaml::Value::as_str)
+        .collect();
+    if message.starts_with("missing field")
+        && let Some(field) = names.first().copied()
+    {
+        if field == "kind" {
+            let kinds = KIND_CONFIG_KEYS
+                .map(|(kind, _)| format!("`{kind}`"))
+                .join(", ");
+            return (
+                Some(tf("parse_error.hint_kind", &[("kinds", &kinds)])),
+                Some("id".to_string()),
+            );
+        }
+        if let Some(typo) = closest(field, keys.iter().copied()) {
+            return (
+                Some(tf(
+                    "parse_error.hint_key_typo",
+                    &[("typo", &typo), ("key", &field)],
+                )),
+                Some(typo.to_string()),
+            );
+        }
+        let kind = mapping.get("kind").and_then(serde_yaml::Value::as_str);
+        if let Some((other, key)) = KIND_CONFIG_KEYS
+            .iter()
+            .find(|(other, key)| Some(*other) != kind && keys.contains(key))
+        {
+            return (
+                Some(tf(
+                    "parse_error.hint_other_kind",
+                    &[("key", key), ("kind", other)],
+                )),
+                Some("kind".to_string()),
+            );
+        }
+        let hint = match kind {
+            Some(kind) => tf(
+                "parse_error.hint_kind_field",
+                &[("kind", &kind), ("field", &field)],
+            ),
+            None => tf("parse_error.hint_add_field", &[("field", &field)]),
+        };
+        return (Some(hint), kind.map(|_| "kind".to_string()));
+    }
+    if message.starts_with("unknown variant")
+        && let Some((value, expected)) = names.split_first()
+    {
+        let hint = match closest(value, expected.iter().copied()) {
+            Some(similar) => tf("parse_error.hint_similar", &[("value", &similar)]),
+            None => {
+                let options: Vec<String> =
+                    expected.iter().map(|name| format!("`{name}`")).collect();
+                let options = options.join(", ");
+                tf("parse_error.hint_options", &[("options", &options)])
+            }
+        };
+        return (Some(hint), Some(value.to_string()));
+    }
+    if message.starts_with("unknown field")
+        && let Some((field, expected)) = names.split_first()
+    {
+        let hint = closest(field, expected.iter().copied()).map(|similar| {
+            tf(
+                "parse_error.hint_key_typo",
+                &[("typo", field), ("key", &similar)],
+            )
+        });
+        return (hint, Some(field.to_string()));
+    }
+    (None, None)
+}
+
+/// `candidates` 중 `target`과 편집 거리가 가장 가까운 이름을 고른다. 같은 이름이나 너무 먼 이름은 뺀다.
+fn closest<'a>(target: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .filter(|candidate| *candidate != target)
+        .map(|candidate| (edit_distance(target, candidate), candidate))
+        .filter(|(distance, _)| *distance <= TYPO_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// 두 문자열의 레벤슈타인 편집 거리를 계산한다.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            current[j + 1] = substitute.min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+        prev = current;
+    }
+    prev[b.len()]
+}
+
+/// 역직렬화에 실패하는 Step 중 가장 안쪽의 것을 찾는다.
+///
+/// Loop 하위 Step이 잘못되면 부모 Loop도 실패하므로 하위 Step을 먼저 확인한다.
+fn find_failing_step(value: &serde_yaml::Value) -> Option<(&serde_yaml::Value, serde_yaml::Error)> {
+    fn search(
+        steps: Option<&serde_yaml::Value>,
+    ) -> Option<(&serde_yaml::Value, serde_yaml::Error)> {
+        for step in steps?.as_sequence()? {
+            let children = step.get("loop").and_then(|config| config.get("steps"));
+            if let Some(found) = search(children) {
+                return Some(found);
+            }
+            if let Err(err) = Step::deserialize(step) {
+                return Some((step, err));
+            }
+        }
+        None
+    }
+    search(value.get("steps")).or_else(|| {
+        search(
+            value
+                .get("on_failure")
+                .and_then(|config| config.get("steps")),
+        )
+    })
+}
+
+/// 원문에서 Step ID가 `id`인 Step 블록의 줄 범위(0부터, 끝 제외)를 찾는다.
+///
+/// 블록은 `id:` 줄에서 시작해 다음 `- id:` 줄이나 같은 깊이 이하로 내어 쓴 목록 항목 앞에서 끝난다.
+fn step_block(source: &str, id: &str) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let start = lines.iter().position(|line| {
+        let body = line.trim_start().trim_start_matches("- ").trim_start();
+        body.strip_prefix("id:")
+            .is_some_and(|value| value.trim().trim_matches(['"', '\'']) == id)
+    })?;
+    let indent = indent_of(lines[start]);
+    let end = lines
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, line)| {
+            let body = line.trim_start();
+            body.starts_with("- id:") || (body.starts_with("- ") && indent_of(line) < indent)
+        })
+        .map_or(lines.len(), |(idx, _)| idx);
+    Some((start, end))
+}
+
+/// 줄 범위 안에서 `token`이 키(`token:`)나 값으로 처음 나오는 줄을 찾는다.
+fn find_token_line(source: &str, start: usize, end: usize, token: &str) -> Option<usize> {
+    let key = format!("{token}:");
+    let lines = || source.lines().enumerate().skip(start).take(end - start);
+    lines()
+        .find(|(_, line)| line.trim_start().trim_start_matches("- ").starts_with(&key))
+        .or_else(|| lines().find(|(_, line)| line.contains(token)))
+        .map(|(idx, _)| idx)
+}
+
+/// 1부터 세는 `line` 위쪽에서 가장 가까운 Step ID를 찾는다.
+fn enclosing_step_id(source: &str, line: usize) -> Option<String> {
+    source
+        .lines()
+        .take(line)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .find_map(|text| {
+            let body = text.trim_start().strip_prefix("- ")?.trim_start();
+            let value = body.strip_prefix("id:")?.trim().trim_matches(['"', '\'']);
+            (!value.is_empty()).then(|| value.to_string())
+        })
+}
+
+/// 줄 앞 공백 수를 센다. 목록 기호 `- `도 들여쓰기로 센다.
+fn indent_of(line: &str) -> usize {
+    line.len()
+        - line
+            .trim_start()
+            .trim_start_matches("- ")
+            .trim_start()
+            .len()
+}
@@ -0,0 +1,185 @@
+use crate::i18n::tf;
+use crate::scenario::{Scenario, Step, StepKind};
+use std::fmt::Write;
+
+/// DAG 출력 형식이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// `--format` 값을 해석한다. 비우면 DOT이다.
+    pub fn parse(value: Option<&str>) -> anyhow::Result<Self> {
+        match value.map(str::trim) {
+            None | Some("") | Some("dot") => Ok(GraphFormat::Dot),
+            Some("mermaid") => Ok(GraphFormat::Mermaid),
+            Some(other) => {
+                anyhow::bail!(tf("cli.graph_format", &[("format", &other)]))
+            }
+        }
+    }
+}
+
+/// 시나리오의 DAG를 문서나 CI에서 그릴 수 있는 텍스트로 만든다.
+///
+/// Step마다 ID, 이름, `kind`, `target_db`, `stage`를 노드 속성(DOT)이나 라벨(Mermaid)로 남기고,
+/// `depends_on`을 선행 Step에서 후행 Step으로 가는 간선으로 그린다. Loop 내부 Step은 Loop 이름의 묶음으로,
+/// `on_failure` Step은 별도 묶음으로 그린다.
+pub fn render_scenario_graph(scenario: &Scenario, format: GraphFormat) -> String {
+    let mut out = String::new();
+    match format {
+        GraphFormat::Dot => {
+            let _ = writeln!(out, "digraph \"{}\" {{", dot_escape(&scenario.name));
+            let _ = writeln!(out, "  rankdir=LR;");
+            let _ = writeln!(out, "  node [shape=box, style=rounded];");
+            write_dot_steps(&mut out, &scenario.steps, 1);
+            if !scenario.cleanup_steps().is_empty() {
+                let _ = writeln!(out, "  subgraph \"cluster_on_failure\" {{");
+                let _ = writeln!(out, "    label=\"on_failure\";");
+                let _ = writeln!(out, "    style=dashed;");
+                write_dot_steps(&mut out, scenario.cleanup_steps(), 2);
+                let _ = writeln!(out, "  }}");
+            }
+            let _ = writeln!(out, "}}");
+        }
+        GraphFormat::Mermaid => {
+            let _ = writeln!(out, "flowchart LR");
+            write_mermaid_steps(&mut out, &scenario.steps, 1);
+            if !scenario.cleanup_steps().is_empty() {
+                let _ = writeln!(out, "  subgraph on_failure__ [\"on_failure\"]");
+                write_mermaid_steps(&mut out, scenario.cleanup_steps(), 2);
+                let _ = writeln!(out, "  end");
+            }
+        }
+    }
+    out
+}
+
+/// Step 목록을 DOT 노드와 간선으로 쓴다. Loop는 내부 Step을 담은 클러스터도 함께 쓴다.
+fn write_dot_steps(out: &mut String, steps: &[Step], depth: usize) {
+    let indent = "  ".repeat(depth);
+    for step in steps {
+        let mut attrs = format!(
+            "label=\"{}\\n({})\", kind=\"{}\"",
+            dot_escape(&step.name),
+            dot_escape(&step.id),
+            step.kind.name()
+        );
+        if let Some(target_db) = step.kind.target_db() {
+            let _ = write!(attrs, ", target_db=\"{}\"", dot_escape(target_db));
+        }
+        if let Some(stage) = &step.stage {
+            let _ = write!(attrs, ", stage=\"{}\"", dot_escape(stage));
+        }
+        if matches!(step.kind, StepKind::Marker) {
+            attrs.push_str(", shape=diamond, style=solid");
+        }
+        let _ = writeln!(out, "{indent}\"{}\" [{attrs}];", dot_escape(&step.id));
+        if let StepKind::Loop { config } = &step.kind {
+            let _ = writeln!(
+                out,
+                "{indent}subgraph \"cluster_{}\" {{",
+                dot_escape(&step.id)
+            );
+            let _ = writeln!(
+                out,
+                "{indent}  label=\"{} ({} ∈ {})\";",
+                dot_escape(&step.name),
+                dot_escape(&config.as_var),
+                dot_escape(&config.for_each_glob)
+            );
+            write_dot_steps(out, &config.steps, depth + 1);
+            let _ = writeln!(out, "{indent}}}");
+        }
+    }
+    for step in steps {
+        for dep in &step.depends_on {
+            let _ = writeln!(
+                out,
+                "{indent}\"{}\" -> \"{}\";",
+                dot_escape(dep),
+                dot_escape(&step.id)
+            );
+        }
+    }
+}
+
+/// Step 목록을 Mermaid 노드와 간선으로 쓴다. Loop는 내부 Step을 담은 subgraph로 쓴다.
+fn write_mermaid_steps(out: &mut String, steps: &[Step], depth: usize) {
+    let indent = "  ".repeat(depth);
+    for step in steps {
+        let mut details = vec![step.kind.name().to_string()];
+        if let Some(target_db) = step.kind.target_db() {
+            details.push(format!("db: {target_db}"));
+        }
+        if let Some(stage) = &step.stage {
+            details.push(format!("stage: {stage}"));
+        }
+        let label = mermaid_escape(&format!(
+            "{}<br/>{}<br/>{}",
+            step.name,
+            step.id,
+            details.join(" · ")
+        ));
+        let id = mermaid_id(&step.id);
+        match &step.kind {
+            StepKind::Loop { config } => {
+                let _ = writeln!(out, "{indent}subgraph {id} [\"{label}\"]");
+                write_mermaid_steps(out, &config.steps, depth + 1);
+                let _ = writeln!(out, "{indent}end");
+            }
+            StepKind::Marker => {
+                let _ = writeln!(out, "{indent}{id}{{\"{label}\"}}");
+            }
+            _ => {
+                let _ = writeln!(out, "{indent}{id}[\"{label}\"]");
+            }
+        }
+    }
+    for step in steps {
+        for dep in &step.depends_on {
+            let _ = writeln!(
+                out,
+                "{indent}{} --> {}",
+                mermaid_id(dep),
+                mermaid_id(&step.id)
+            );
+        }
+    }
+}
+
+/// DOT 큰따옴표 문자열 안에 넣을 수 있도록 역슬래시와 큰따옴표를 이스케이프한다.
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Mermaid 라벨 안에 넣을 수 있도록 큰따옴표를 엔티티로 바꾼다.
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+/// Step ID를 Mermaid 노드 ID로 바꾼다.
+///
+/// 영문자·숫자는 그대로 두고 `_`는 `__`로, 그 밖의 문자는 `_<16진수 코드>_`로 바꿔 서로 다른 Step ID가
+/// 같은 노드 ID가 되지 않게 한다(`a-b`는 `a_2d_b`, `a_b`는 `a__b`). 예약어 `end`와 겹치지 않도록 대소문자
+/// 구분 없이 `end`이면 끝에 `_`를 붙인다.
+fn mermaid_id(id: &str) -> String {
+    let mut safe = String::with_capacity(id.len());
+    for c in id.chars() {
+        if c.is_ascii_alphanumeric() {
+            safe.push(c);
+        } else if c == '_' {
+            safe.push_str("__");
+        } else {
+            let _ = write!(safe, "_{:x}_", u32::from(c));
+        }
+    }
+    if safe.eq_ignore_ascii_case("end") {
+        safe.push('_');
+    }
+    safe
+}
@@ -0,0 +1,204 @@
+use crate::cost_check::check_scenario_cost;
+use crate::engine::DagScheduler;
+use crate::file_check::check_scenario_files;
+use crate::i18n::{t, tf};
+use crate::scenario::{Scenario, Step, StepKind};
+use crate::sql_check::{SqlIssueLevel, check_scenario_sql};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// 점검 결과의 심각도이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    /// 실행하면 실패하거나 DAG를 그릴 수 없는 문제이다.
+    Error,
+    /// 실행 환경에 따라 다르거나 실행을 막지 않는 문제이다.
+    Warning,
+}
+
+impl LintLevel {
+    /// 출력에 붙일 심각도 이름을 반환한다.
+    pub fn label(self) -> &'static str {
+        match self {
+            LintLevel::Error => t("lint.level_error"),
+            LintLevel::Warning => t("lint.level_warning"),
+        }
+    }
+}
+
+/// 시나리오 점검에서 발견한 문제 하나이다.
+#[derive(Debug, Clone)]
+pub struct LintIssue {
+    /// 심각도.
+    pub level: LintLevel,
+    /// 문제 설명. Step에 속한 문제면 `[step_id]`로 시작한다.
+    pub message: String,
+}
+
+impl fmt::Display for LintIssue {
+    /// `[오류] 메시지` 형태로 출력한다.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.level.label(), self.message)
+    }
+}
+
+/// 시나리오 점검 결과이다.
+#[derive(Debug, Clone, Default)]
+pub struct LintReport {
+    /// 발견한 문제. 구조 문제, 변수 계약, 파라미터, SQL, 파일, 비용 순이다.
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    /// 오류 수를 반환한다.
+    pub fn error_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.level == LintLevel::Error)
+            .count()
+    }
+
+    /// 경고 수를 반환한다.
+    pub fn warning_count(&self) -> usize {
+        self.issues.len() - self.error_count()
+    }
+}
+
+/// 시나리오를 실행하지 않고 점검한다. `--lint`와 `--graph`가 같은 점검을 쓴다.
+///
+/// 중복 Step ID, 없는 Step을 가리키는 `depends_on`, 순환 의존, 범위를 벗어난 프로세스 우선순위, 변수
+/// 계약 위반과 SQL 문법 오류는 오류로, 검증 규칙을 어긴 파라미터 기본값, 찾지 못한 참조 파일과 확인하지 않은 비용 경고, 검사하지
+/// 못한 SQL은 경고로 모은다.
+/// 파일은 점검하는 곳과 실행하는 곳이 다를 수 있어 경고로만 알린다. Loop 내부와 `on_failure` Step도 점검한다.
+pub fn lint_scenario(scenario: &Scenario) -> LintReport {
+    let mut issues = Vec::new();
+    let mut error = |message: String| {
+        issues.push(LintIssue {
+            level: LintLevel::Error,
+            message,
+        });
+    };
+    let mut seen: HashSet<&str> = HashSet::new();
+    for id in all_step_ids(&scenario.steps)
+        .into_iter()
+        .chain(all_step_ids(scenario.cleanup_steps()))
+    {
+        if !seen.insert(id) {
+            error(tf("lint.duplicate_id", &[("step", &id)]));
+        }
+    }
+    check_dependencies(&scenario.steps, &mut error);
+    check_dependencies(scenario.cleanup_steps(), &mut error);
+    check_priorities(&scenario.steps, &mut error);
+    check_priorities(scenario.cleanup_steps(), &mut error);
+    for violation in scenario.contract_violations() {
+        error(violation.to_string());
+    }
+    // 기본값은 실행할 때 `--param`이나 실행 프로필로 덮어쓸 수 있으므로 경고로만 알린다.
+    for violation in scenario.param_violations() {
+        issues.push(LintIssue {
+            level: LintLevel::Warning,
+            message: violation.to_string(),
+        });
+    }
+    for issue in check_scenario_sql(scenario) {
+        issues.push(LintIssue {
+            level: match issue.level {
+                SqlIssueLevel::Error => LintLevel::Error,
+                SqlIssueLevel::Warning => LintLevel::Warning,
+            },
+            message: issue.to_string(),
+        });
+    }
+    for missing in check_scenario_files(scenario) {
+        issues.push(LintIssue {
+            level: LintLevel::Warning,
+            message: missing.to_string(),
+        });
+    }
+    for warning in check_scenario_cost(scenario) {
+        if !warning.acknowledged {
+            issues.push(LintIssue {
+                level: LintLevel::Warning,
+                message: warning.to_string(),
+            });
+        }
+    }
+    LintReport { issues }
+}
+
+/// Loop 내부까지 모든 Step ID를 선언 순서대로 모은다.
+fn all_step_ids(steps: &[Step]) -> Vec<&str> {
+    let mut ids = Vec::new();
+    for step in steps {
+        ids.push(step.id.as_str());
+        if let StepKind::Loop { config } = &step.kind {
+            ids.extend(all_step_ids(&config.steps));
+        }
+    }
+    ids
+}
+
+/// Shell·sqlldr Step(Loop 내부 포함)의 `priority` 값 범위를 점검한다.
+fn check_priorities(steps: &[Step], error: &mut impl FnMut(String)) {
+    for step in steps {
+        let priority = match &step.kind {
+            StepKind::Shell { config } => config.priority.as_ref(),
+            StepKind::SqlLoaderPar { config } => config.priority.as_ref(),
+            StepKind::Loop { config } => {
+                check_priorities(&config.steps, error);
+                None
+            }
+            _ => None,
+        };
+        if let Some(Err(err)) = priority.map(|priority| priority.validate()) {
+            error(format!("[{}] {err}", step.id));
+        }
+    }
+}
+
+/// 같은 레벨의 Step 목록에서 없는 Step을 가리키는 `depends_on`과 순환 의존을 찾는다.
+///
+/// 의존은 같은 목록 안에서만 참조하므로 Loop 내부는 따로 점검한다. 순환에 걸린 Step은 한 번에 알린다.
+fn check_dependencies(steps: &[Step], error: &mut impl FnMut(String)) {
+    let ids: HashSet<&str> = steps.iter().map(|step| step.id.as_str()).collect();
+    // 중복 ID는 따로 알리므로 처음 선언한 Step에 의존을 모아 한 번씩만 센다.
+    let mut order: Vec<(String, Vec<String>)> = Vec::new();
+    let mut position: HashMap<&str, usize> = HashMap::new();
+    for step in steps {
+        let pos = *position.entry(step.id.as_str()).or_insert_with(|| {
+            order.push((step.id.clone(), Vec::new()));
+            order.len() - 1
+        });
+        for dep in &step.depends_on {
+            if ids.contains(dep.as_str()) {
+                order[pos].1.push(dep.clone());
+            } else {
+                error(tf(
+                    "lint.unknown_dependency",
+                    &[("step", &step.id), ("dependency", dep)],
+                ));
+            }
+        }
+    }
+    // 실행과 같은 스케줄러로 모두 성공했다고 가정하고 진행해 끝까지 준비되지 않는 Step을 찾는다.
+    let mut scheduler = DagScheduler::from_dependencies(order.iter().cloned());
+    loop {
+        let ready = scheduler.take_ready();
+        if ready.is_empty() {
+            break;
+        }
+        for idx in ready {
+            scheduler.mark_succeeded(&order[idx].0);
+        }
+    }
+    let cyclic = scheduler.take_unreachable();
+    if !cyclic.is_empty() {
+        error(tf("lint.cycle", &[("steps", &cyclic.join(", "))]));
+    }
+    for step in steps {
+        if let StepKind::Loop { config } = &step.kind {
+            check_dependencies(&config.steps, error);
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use crate::i18n::t;
+use crate::scenario::{Scenario, Step, StepKind, load_scenario_from_file};
+use crate::scenario_crypto::is_encrypted_path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// 검색 결과 한 줄에 보여 줄 주변 글자 수이다.
+const SNIPPET_CHARS: usize = 120;
+
+/// 시나리오 파일 하나에서 검색어가 나온 위치이다.
+#[derive(Debug, Clone)]
+pub struct ScenarioSearchHit {
+    /// 시나리오 파일 경로.
+    pub path: PathBuf,
+    /// 검색어가 나온 Step ID. 시나리오 파라미터에서 나왔으면 `None`이다.
+    pub step_id: Option<String>,
+    /// 열었을 때 선택할 최상위 Step ID. Loop 내부 Step이면 그 Loop Step이다.
+    pub focus_id: Option<String>,
+    /// 검색어가 나온 항목 종류.
+    pub field: ScenarioSearchField,
+    /// 검색어가 나온 줄(길면 주변만 자른다).
+    pub snippet: String,
+}
+
+/// 검색 대상 항목 종류이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioSearchField {
+    /// Step ID와 이름.
+    StepName,
+    /// SQL 문자열과 읽을 수 있는 `sql_file` 내용.
+    Sql,
+    /// shell 스크립트.
+    Script,
+    /// Step이 참조하는 파일 경로.
+    FilePath,
+    /// 시나리오 파라미터와 Step이 쓰거나 남기는 변수 이름.
+    Variable,
+}
+
+impl ScenarioSearchField {
+    /// 결과 목록에 표시할 항목 이름을 반환한다.
+    pub fn label(self) -> &'static str {
+        match self {
+            ScenarioSearchField::StepName => t("search.field_step"),
+            ScenarioSearchField::Sql => t("search.field_sql"),
+            ScenarioSearchField::Script => t("search.field_script"),
+            ScenarioSearchField::FilePath => t("search.field_path"),
+            ScenarioSearchField::Variable => t("search.field_variable"),
+        }
+    }
+}
+
+/// 시나리오 폴더 전체 검색 결과이다.
+#[derive(Debug, Clone, Default)]
+pub struct ScenarioSearchReport {
+    /// 파일 경로와 Step 순서로 정렬한 결과.
+    pub hits: Vec<ScenarioSearchHit>,
+    /// 읽은 시나리오 파일 수.
+    pub scanned: usize,
+    /// 읽지 못한 파일·폴더와 그 이유(암호 문구가 필요한 파일, 형식 오류, 권한 없는 폴더 등).
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+/// 폴더 아래(하위 폴더 포함)의 모든 시나리오 파일에서 검색어를 찾는다.
+///
+/// Step ID·이름, SQL 문자열과 `sql_file` 내용, shell 스크립트, 참조 파일 경로, 시나리오 파라미터와
+/// Step 변수 이름을 대소문자 구분 없이 비교한다. Loop 내부와 `on_failure` Step도 포함한다. 숨김
+/// 폴더는 건너뛰고, 심볼릭 링크로 이미 들른 폴더에 다시 들어가면 건너뛴다. 읽지 못한 하위 폴더와
+/// 파일은 [`ScenarioSearchReport::skipped`]에 남기고 나머지를 계속 검색한다. 파일을 모두 읽으므로
+/// UI 스레드가 아닌 블로킹 작업에서 호출한다.
+///
+/// # 오류
+/// 검색 폴더 자체를 읽지 못하면 오류를 반환한다.
+pub fn search_scenarios(dir: &Path, query: &str) -> anyhow::Result<ScenarioSearchReport> {
+    let needle = query.trim().to_lowercase();
+    let mut report = ScenarioSearchReport::default();
+    if needle.is_empty() {
+        return Ok(report);
+    }
+    let entries = std::fs::read_dir(dir)?;
+    let mut visited = HashSet::new();
+    if let Ok(canonical) = dir.canonicalize() {
+        visited.insert(canonical);
+    }
+    let mut files = Vec::new();
+    collect_scenario_files(entries, &mut visited, &mut files, &mut report.skipped);
+    files.sort();
+    for path in files {
+        match load_scenario_from_file(&path) {
+            Ok(scenario) => {
+                report.scanned += 1;
+                search_scenario(&scenario, &path, &needle, &mut report.hits);
+            }
+            Err(err) => report.skipped.push((path, err.to_string())),
+        }
+    }
+    Ok(report)
+}
+
+/// 폴더 항목 아래 YAML·암호화 시나리오 파일 경로를 모은다.
+///
+/// 하위 폴더는 실제 경로로 `visited`에 남겨, 심볼릭 링크가 상위 폴더를 가리켜도 같은 폴더를 다시
+/// 훑지 않는다. 읽지 못한 항목과 하위 폴더는 경고를 남기고 `skipped`에 더한 뒤 건너뛴다.
+fn collect_scenario_files(
+    entries: std::fs::ReadDir,
+    visited: &mut HashSet<PathBuf>,
+    files: &mut Vec<PathBuf>,
+    skipped: &mut Vec<(PathBuf, String)>,
+) {
+    for entry in entries {
+        let path = match entry {
+            Ok(entry) => entry.path(),
+            Err(err) => {
+                tracing::warn!("시나리오 검색 중 폴더 항목 읽기 실패: {err}");
+                continue;
+            }
+        };
+        if path.is_dir() {
+            let hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if hidden {
+                continue;
+            }
+            let read = path
+                .canonicalize()
+                .and_then(|canonical| Ok((canonical, std::fs::read_dir(&path)?)));
+            match read {
+                Ok((canonical, entries)) => {
+                    if visited.insert(canonical) {
+                        collect_scenario_files(entries, visited, files, skipped);
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("시나리오 검색 중 폴더 읽기 실패({}): {err}", path.display());
+                    skipped.push((path, err.to_string()));
+                }
+            }
+        } else if is_encrypted_path(&path)
+            || path.extension().is_some_and(|ext| {
+                ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml")
+            })
+        {
+            files.push(path);
+        }
+    }
+}
+
+/// 시나리오 하나에서 검색어가 나온 위치를 모은다.
+fn search_scenario(
+    scenario: &Scenario,
+    path: &Path,
+    needle: &str,
+    hits: &mut Vec<ScenarioSearchHit>,
+) {
+    let mut params: Vec<&String> = scenario.params.keys().collect();
+    params.sort();
+    for name in params {
+        if let Some(snippet) = find_snippet(name, needle) {
+            hits.push(ScenarioSearchHit {
+                path: path.to_path_buf(),
+                step_id: None,
+                focus_id: None,
+                field: ScenarioSearchField::Variable,
+                snippet,
+            });
+        }
+    }
+    for step in scenario.steps.iter().chain(scenario.cleanup_steps()) {
+        search_step(step, &step.id, path, needle, hits);
+    }
+}
+
+/// Step 하나(Loop면 내부 Step 포함)에서 검색어가 나온 위치를 모은다.
+///
+/// `focus_id`는 결과를 열 때 선택할 최상위 Step ID이다.
+fn search_step(
+    step: &Step,
+    focus_id: &str,
+    path: &Path,
+    needle: &str,
+    hits: &mut Vec<ScenarioSearchHit>,
+) {
+    let mut texts: Vec<(ScenarioSearchField, String)> = vec![
+        (ScenarioSearchField::StepName, step.id.clone()),
+        (ScenarioSearchField::StepName, step.name.clone()),
+    ];
+    let vars = step
+        .produced_vars()
+        .into_iter()
+        .chain(step.consumes.iter().map(String::as_str));
+    texts.extend(vars.map(|var| (ScenarioSearchField::Variable, var.to_string())));
+    match &step.kind {
+        StepKind::Sql { sql, .. } => texts.push((ScenarioSearchField::Sql, sql.clone())),
+        StepKind::SqlFile { path: sql_path, .. } => {
+            texts.push((
+                ScenarioSearchField::FilePath,
+                sql_path.display().to_string(),
+            ));
+            if let Ok(sql) = std::fs::read_to_string(sql_path) {
+                texts.push((ScenarioSearchField::Sql, sql));
+            }
+        }
+        StepKind::SqlLoaderPar { config } => {
+            let paths = [
+                Some(&config.control_file),
+                config.data_file.as_ref(),
+                config.log_file.as_ref(),
+                config.bad_file.as_ref(),
+                config.discard_file.as_ref(),
+            ];
+            texts.extend(
+                paths
+                    .into_iter()
+                    .flatten()
+                    .map(|p| (ScenarioSearchField::FilePath, p.display().to_string())),
+            );
+        }
+        StepKind::Shell { config } => {
+            texts.push((ScenarioSearchField::Script, config.script.clone()));
+            if let Some(dir) = &config.working_dir {
+                texts.push((ScenarioSearchField::FilePath, dir.display().to_string()));
+            }
+        }
+        StepKind::Extract { config } => {
+            texts.push((ScenarioSearchField::FilePath, config.file_path.clone()));
+        }
+        StepKind::Verify { config } => {
+            texts.push((ScenarioSearchField::FilePath, config.file.clone()));
+            if let Some(expected) = &config.expected_file {
+                texts.push((ScenarioSearchField::FilePath, expected.clone()));
+            }
+        }
+        StepKind::Loop { config } => {
+            texts.push((ScenarioSearchField::FilePath, config.for_each_glob.clone()));
+            texts.push((ScenarioSearchField::Variable, config.as_var.clone()));
+        }
+        StepKind::WaitForRun { config } => {
+            texts.push((ScenarioSearchField::StepName, config.scenario.clone()));
+        }
+        StepKind::ManualGate { .. } | StepKind::Wait { .. } | StepKind::Marker => {}
+    }
+    for (field, text) in texts {
+        if let Some(snippet) = find_snippet(&text, needle) {
+            hits.push(ScenarioSearchHit {
+                path: path.to_path_buf(),
+                step_id: Some(step.id.clone()),
+                focus_id: Some(focus_id.to_string()),
+                field,
+                snippet,
+            });
+        }
+    }
+    if let StepKind::Loop { config } = &step.kind {
+        for inner in &config.steps {
+            search_step(inner, focus_id, path, needle, hits);
+        }
+    }
+}
+
+/// 검색어가 처음 나온 줄을 반환한다. 줄이 길면 검색어 주변 [`SNIPPET_CHARS`]자만 남긴다.
+///
+/// `needle`은 소문자로 바꾼 검색어이다.
+fn find_snippet(text: &str, needle: &str) -> Option<String> {
+    let line = text
+        .lines()
+        .find(|line| line.to_lowercase().contains(needle))?
+        .trim();
+    let chars: Vec<char> = line.chars().collect();
+    if chars.len() <= SNIPPET_CHARS {
+        return Some(line.to_string());
+    }
+    let lower: Vec<char> = line.to_lowercase().chars().collect();
+    let needle: Vec<char> = needle.chars().collect();
+    let at = lower
+        .windows(needle.len())
+        .position(|window| window == needle.as_slice())
+        .filter(|_| lower.len() == chars.len())
+        .unwrap_or(0);
+    let start = at.saturating_sub(SNIPPET_CHARS / 3);
+    let end = (start + SNIPPET_CHARS).min(chars.len());
+    let mut snippet: String = chars[start..end].iter().collect();
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    Some(snippet)
+}
@@ -0,0 +1,392 @@
+use crate::i18n::{t, tf};
+use crate::scenario::load_scenario_from_file;
+use anyhow::{Context, bail};
+use std::path::{Path, PathBuf};
+
+/// 분·시 조합으로 만들 수 있는 작업 스케줄러 트리거 수 상한이다.
+const MAX_TRIGGERS: usize = 48;
+
+/// 작업 스케줄러 XML의 월 요소 이름이다.
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// 작업 스케줄러 XML의 요일 요소 이름이다. cron처럼 0이 일요일이다.
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// 내보낼 스케줄 항목의 형식이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScheduleFormat {
+    /// 한 줄짜리 crontab 항목.
+    Crontab,
+    /// Windows 작업 스케줄러로 가져올 작업 XML.
+    TaskScheduler,
+}
+
+impl ScheduleFormat {
+    /// `--format` 인자 값을 해석한다.
+    fn parse(value: &str) -> anyhow::Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "crontab" | "cron" => Ok(Self::Crontab),
+            "windows" | "task-scheduler" | "xml" => Ok(Self::TaskScheduler),
+            other => bail!(tf("schedule.unknown_format", &[("format", &other)])),
+        }
+    }
+}
+
+/// 다섯 필드(`분 시 일 월 요일`) cron 식이다.
+///
+/// crontab 항목에는 그대로 쓰고, 작업 스케줄러 XML은 같은 식을 달력 트리거로 옮겨 두 형식이
+/// 같은 시각에 실행되도록 한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CronSchedule {
+    /// 분 필드.
+    minute: String,
+    /// 시 필드.
+    hour: String,
+    /// 일 필드.
+    day_of_month: String,
+    /// 월 필드.
+    month: String,
+    /// 요일 필드. 0과 7이 일요일이다.
+    day_of_week: String,
+}
+
+impl CronSchedule {
+    /// 공백으로 나눈 다섯 필드 cron 식을 해석한다. 각 필드의 값 범위도 검사한다.
+    fn parse(expr: &str) -> anyhow::Result<Self> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            bail!(tf("schedule.cron_fields", &[("expr", &expr)]));
+        };
+        for (field, name, min, max) in [
+            (minute, "schedule.field_minute", 0, 59),
+            (hour, "schedule.field_hour", 0, 23),
+            (day_of_month, "schedule.field_day", 1, 31),
+            (month, "schedule.field_month", 1, 12),
+            (day_of_week, "schedule.field_weekday", 0, 7),
+        ] {
+            expand_field(field, min, max)
+                .with_context(|| tf("schedule.field_error", &[("field", &t(name))]))?;
+        }
+        Ok(Self {
+            minute: minute.to_string(),
+            hour: hour.to_string(),
+            day_of_month: day_of_month.to_string(),
+            month: month.to_string(),
+            day_of_week: day_of_week.to_string(),
+        })
+    }
+
+    /// cron 식 문자열로 되돌린다.
+    fn expr(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.minute, self.hour, self.day_of_month, self.month, self.day_of_week
+        )
+    }
+}
+
+/// 시나리오 파일을 `--run`으로 실행하는 스케줄 항목을 만든다.
+///
+/// # 매개변수
+/// - `path`: 스케줄로 실행할 시나리오 파일.
+/// - `cron`: 다섯 필드 cron 식.
+/// - `format`: 출력 형식(`crontab` 또는 `windows`). `None`이면 crontab이다.
+/// - `profile`: 실행 때 적용할 실행 프로필 이름. 승인을 요구하는 프로필이면 창 없이 실행할 수 없으므로
+///   오류를 반환한다.
+/// - `params`: 실행 때 덮어쓸 시나리오 파라미터. 파라미터 검증 규칙을 어기면 오류를 반환한다.
+/// - `context_files`: 실행에 함께 넘길 `--context-in`/`--context-out` 인자와 파일.
+pub fn export_scenario_schedule(
+    path: &Path,
+    cron: Option<&str>,
+    format: Option<&str>,
+    profile: Option<&str>,
+    params: &[(String, String)],
+    context_files: [(&str, Option<PathBuf>); 2],
+) -> anyhow::Result<String> {
+    let cron = cron.context(t("schedule.cron_required"))?;
+    let schedule = CronSchedule::parse(cron)?;
+    let format = format.map_or(Ok(ScheduleFormat::Crontab), ScheduleFormat::parse)?;
+    let mut scenario = load_scenario_from_file(path)?;
+    let profile_params = match profile {
+        Some(name) => scenario.unattended_profile(name)?.params.clone(),
+        None => Default::default(),
+    };
+    scenario.params.extend(profile_params.clone());
+    scenario.params.extend(params.iter().cloned());
+    // 기본값은 실행 때 컨텍스트 파일로 바뀔 수 있으므로 프로필과 넘겨받은 파라미터만 미리 검증한다.
+    let violations: Vec<String> = scenario
+        .param_violations()
+        .into_iter()
+        .filter(|violation| {
+            profile_params.contains_key(&violation.param)
+                || params.iter().any(|(key, _)| *key == violation.param)
+        })
+        .map(|violation| violation.to_string())
+        .collect();
+    if !violations.is_empty() {
+        anyhow::bail!("{}", violations.join("\n"));
+    }
+    let mut command = headless_command(&std::env::current_exe()?, path, params)?;
+    if let Some(name) = profile {
+        command.push("--profile".to_string());
+        command.push(name.to_string());
+    }
+    for (flag, file) in context_files {
+        if let Some(file) = file {
+            command.push(flag.to_string());
+            command.push(std::path::absolute(file)?.to_string_lossy().to_string());
+        }
+    }
+    match format {
+        ScheduleFormat::Crontab => Ok(crontab_line(&schedule, &scenario.name, &command)),
+        ScheduleFormat::TaskScheduler => task_scheduler_xml(&schedule, &scenario.name, &command),
+    }
+}
+
+/// 시나리오를 창 없이 한 번 실행하는 명령줄을 만든다. 첫 요소가 실행 파일이다.
+///
+/// 스케줄러는 작업 디렉터리가 정해져 있지 않으므로 실행 파일과 시나리오는 절대 경로로 적는다.
+fn headless_command(
+    exe: &Path,
+    scenario_path: &Path,
+    params: &[(String, String)],
+) -> anyhow::Result<Vec<String>> {
+    if !scenario_path.is_file() {
+        bail!(tf(
+            "schedule.scenario_missing",
+            &[("path", &scenario_path.display())]
+        ));
+    }
+    let scenario_path = std::path::absolute(scenario_path)?;
+    let mut command = vec![
+        exe.to_string_lossy().to_string(),
+        "--run".to_string(),
+        scenario_path.to_string_lossy().to_string(),
+    ];
+    for (key, value) in params {
+        command.push("--param".to_string());
+        command.push(format!("{key}={value}"));
+    }
+    Ok(command)
+}
+
+/// 시나리오 이름 주석과 crontab 한 줄을 만든다. 인자는 작은따옴표로 감싼다.
+fn crontab_line(schedule: &CronSchedule, scenario_name: &str, command: &[String]) -> String {
+    let command = command
+        .iter()
+        .map(|arg| format!("'{}'", arg.replace('\'', r"'\''")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    // crontab에서 `%`는 줄바꿈으로 해석되므로 이스케이프한다.
+    let command = command.replace('%', r"\%");
+    format!("# {scenario_name}\n{} {command}\n", schedule.expr())
+}
+
+/// cron 식을 달력 트리거로 옮긴 작업 스케줄러 XML을 만든다.
+///
+/// 분과 시는 값 목록만 쓸 수 있고 조합마다 트리거를 하나씩 만든다. 일과 요일은 둘 중 하나만 지정할 수
+/// 있다. 이보다 복잡한 식은 작업 스케줄러로 그대로 옮길 수 없어 오류를 반환한다.
+fn task_scheduler_xml(
+    schedule: &CronSchedule,
+    scenario_name: &str,
+    command: &[String],
+) -> anyhow::Result<String> {
+    let minutes = expand_field(&schedule.minute, 0, 59)?;
+    let hours = expand_field(&schedule.hour, 0, 23)?;
+    if minutes.len() * hours.len() > MAX_TRIGGERS {
+        bail!(tf(
+            "schedule.too_many_triggers",
+            &[("max", &MAX_TRIGGERS), ("expr", &schedule.expr())]
+        ));
+    }
+    let months = expand_field(&schedule.month, 1, 12)?;
+    let months_xml = months
+        .iter()
+        .map(|month| format!("<{}/>", MONTH_NAMES[*month as usize - 1]))
+        .collect::<String>();
+    let body = match (
+        schedule.day_of_month.as_str(),
+        schedule.day_of_week.as_str(),
+    ) {
+        ("*", "*") if schedule.month == "*" => {
+            "<ScheduleByDay><DaysInterval>1</DaysInterval></ScheduleByDay>".to_string()
+        }
+        ("*", "*") => {
+            let days = (1..=31)
+                .map(|day| format!("<Day>{day}</Day>"))
+                .collect::<String>();
+            format!(
+                "<ScheduleByMonth><DaysOfMonth>{days}</DaysOfMonth><Months>{months_xml}</Months></ScheduleByMonth>"
+            )
+        }
+        ("*", day_of_week) if schedule.month == "*" => {
+            let mut weekdays = expand_field(day_of_week, 0, 7)?;
+            for day in &mut weekdays {
+                *day %= 7;
+            }
+            weekdays.sort_unstable();
+            weekdays.dedup();
+            let days = weekdays
+                .iter()
+                .map(|day| format!("<{}/>", WEEKDAY_NAMES[*day as usize]))
+                .collect::<String>();
+            format!(
+                "<ScheduleByWeek><DaysOfWeek>{days}</DaysOfWeek><WeeksInterval>1</WeeksInterval></ScheduleByWeek>"
+            )
+        }
+        (day_of_month, "*") => {
+            let days = expand_field(day_of_month, 1, 31)?
+                .iter()
+                .map(|day| format!("<Day>{day}</Day>"))
+                .collect::<String>();
+            format!(
+                "<ScheduleByMonth><DaysOfMonth>{days}</DaysOfMonth><Months>{months_xml}</Months></ScheduleByMonth>"
+            )
+        }
+        _ => bail!(tf(
+            "schedule.unsupported_cron",
+            &[("expr", &schedule.expr())]
+        )),
+    };
+    let today = chrono::Local::now().format("%Y-%m-%d");
+    let mut triggers = String::new();
+    for hour in &hours {
+        for minute in &minutes {
+            triggers.push_str(&format!(
+                "    <CalendarTrigger>\n      <StartBoundary>{today}T{hour:02}:{minute:02}:00</StartBoundary>\n      <Enabled>true</Enabled>\n      {body}\n    </CalendarTrigger>\n"
+            ));
+        }
+    }
+    let (exe, args) = command.split_first().context(t("schedule.empty_command"))?;
+    let arguments = args
+        .iter()
+        .map(|arg| windows_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+    Ok(format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<Task version="1.2" xmlns="http://schemas.microsoft.com/windows/2004/02/mit/task">
+  <RegistrationInfo>
+    <Description>{description}</Description>
+  </RegistrationInfo>
+  <Triggers>
+{triggers}  </Triggers>
+  <Settings>
+    <MultipleInstancesPolicy>IgnoreNew</MultipleInstancesPolicy>
+    <ExecutionTimeLimit>PT0S</ExecutionTimeLimit>
+    <Enabled>true</Enabled>
+  </Settings>
+  <Actions Context="Author">
+    <Exec>
+      <Command>{exe}</Command>
+      <Arguments>{arguments}</Arguments>
+    </Exec>
+  </Actions>
+</Task>
+"#,
+        description = xml_escape(&format!("{scenario_name} ({})", schedule.expr())),
+        exe = xml_escape(exe),
+        arguments = xml_escape(&arguments),
+    ))
+}
+
+/// cron 필드(`*`, 값, `a-b`, `*/n`, `a-b/n`, 쉼표 목록)를 값 목록으로 펼친다.
+fn expand_field(field: &str, min: u32, max: u32) -> anyhow::Result<Vec<u32>> {
+    let mut values = Vec::new();
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|step| *step > 0)),
+            None => (part, Some(1)),
+        };
+        let Some(step) = step else {
+            bail!(tf("schedule.bad_step", &[("part", &part)]));
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (parse_value(start, min, max)?, parse_value(end, min, max)?),
+                None => {
+                    let value = parse_value(range, min, max)?;
+                    (value, if part.contains('/') { max } else { value })
+                }
+            },
+        };
+        if start > end {
+            bail!(tf("schedule.bad_range", &[("part", &part)]));
+        }
+        values.extend((start..=end).step_by(step as usize));
+    }
+    values.sort_unstable();
+    values.dedup();
+    Ok(values)
+}
+
+/// cron 필드의 숫자 값 하나를 범위와 함께 확인한다.
+fn parse_value(value: &str, min: u32, max: u32) -> anyhow::Result<u32> {
+    let parsed: u32 = value
+        .parse()
+        .with_context(|| tf("schedule.not_number", &[("value", &value)]))?;
+    if !(min..=max).contains(&parsed) {
+        bail!(tf(
+            "schedule.out_of_range",
+            &[("min", &min), ("max", &max), ("value", &parsed)]
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Windows 명령줄 규칙에 맞춰 공백이나 큰따옴표가 있는 인자를 감싼다.
+fn windows_quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        return arg.to_string();
+    }
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for ch in arg.chars() {
+        match ch {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(ch);
+                backslashes = 0;
+            }
+        }
+    }
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// XML 텍스트에 쓸 수 있도록 특수 문자를 바꾼다.
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,402 @@
+use crate::engine::{
+    ConfirmMode, EngineEvent, EngineHandleCache, EngineRuntimeConfig, RehearsalPlan, log_dir,
+    run_scenario,
+};
+use crate::executor::{DummyExecutor, SharedExecutor};
+use crate::i18n::{t, tf};
+use crate::scenario::{Scenario, StepKind, load_scenario_from_file};
+use anyhow::Context;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// 자가 점검 시나리오의 Loop가 반복할 파일 수이다.
+const LOOP_FILES: usize = 3;
+
+/// Extract Step이 읽어야 하는 값이다.
+const EXTRACT_VALUE: &str = "42";
+
+/// Extract Step이 값을 넣는 변수 이름이다.
+const EXTRACT_VAR: &str = "SELF_TEST_VALUE";
+
+/// 일부러 실패하게 만든 Step ID이다.
+const FAILING_STEP: &str = "forced_failure";
+
+/// 실패한 Step 뒤에 있어 건너뛰어야 하는 Step ID이다.
+const BLOCKED_STEP: &str = "after_failure";
+
+/// 자가 점검 시나리오이다. `{dir}`은 임시 폴더 경로로 바뀐다.
+///
+/// 병렬 SQL 분기, Shell, Extract, Loop, 합류 표식, 실패하는 검증 Step과 그 하위 Step, 실패와 무관한
+/// 분기를 고루 담는다.
+const SCENARIO_TEMPLATE: &str = r#"name: self-test
+steps:
+  - id: prepare
+    name: 준비
+    kind: shell
+    shell: { script: "echo self-test" }
+    retry: 0
+    timeout_sec: 30
+  - id: sql_a
+    name: 병렬 SQL A
+    kind: sql
+    sql: "SELECT 1"
+    depends_on: [prepare]
+    allow_parallel: true
+    retry: 0
+    timeout_sec: 30
+  - id: sql_b
+    name: 병렬 SQL B
+    kind: sql
+    sql: "SELECT 2"
+    depends_on: [prepare]
+    allow_parallel: true
+    retry: 0
+    timeout_sec: 30
+  - id: extract_value
+    name: 값 추출
+    kind: extract
+    extract: { file_path: '{dir}/value.txt', line: 1, pattern: "value=(\\d+)", group: 1, var_name: SELF_TEST_VALUE }
+    depends_on: [prepare]
+    retry: 0
+    timeout_sec: 30
+  - id: loop_files
+    name: 파일 반복
+    kind: loop
+    loop:
+      for_each_glob: '{dir}/loop/*.txt'
+      as_var: LOOP_FILE
+      steps:
+        - id: loop_sql
+          name: 반복 SQL
+          kind: sql
+          sql: "INSERT INTO self_test VALUES ('${LOOP_FILE}')"
+          retry: 0
+          timeout_sec: 30
+    depends_on: [prepare]
+    retry: 0
+    timeout_sec: 60
+  - id: join
+    name: 합류
+    kind: marker
+    depends_on: [sql_a, sql_b, extract_value, loop_files]
+  - id: forced_failure
+    name: 일부러 실패
+    kind: verify
+    verify: { file: '{dir}/value.txt', method: lines, expected: "999" }
+    depends_on: [join]
+    retry: 0
+    timeout_sec: 30
+  - id: after_failure
+    name: 실패 뒤 Step
+    kind: marker
+    depends_on: [forced_failure]
+  - id: independent
+    name: 무관한 분기
+    kind: sql
+    sql: "SELECT '${SELF_TEST_VALUE}'"
+    depends_on: [join]
+    retry: 0
+    timeout_sec: 30
+"#;
+
+/// 자가 점검 항목 하나의 결과이다.
+#[derive(Debug, Clone)]
+pub struct SelfTestCheck {
+    /// 점검 항목 이름.
+    pub name: &'static str,
+    /// 통과 여부.
+    pub passed: bool,
+    /// 실패했을 때의 설명. 통과하면 비어 있다.
+    pub detail: String,
+}
+
+/// 자가 점검 결과 보고서이다.
+#[derive(Debug, Clone, Default)]
+pub struct SelfTestReport {
+    /// 점검 순서대로의 항목 결과.
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestReport {
+    /// 모든 항목이 통과했는지 확인한다.
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    /// 항목별 `PASS`/`FAIL` 줄과 마지막 요약 줄로 된 텍스트 보고서를 만든다.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for check in &self.checks {
+            let mark = if check.passed { "PASS" } else { "FAIL" };
+            let _ = write!(text, "[{mark}] {}", check.name);
+            if !check.detail.is_empty() {
+                let _ = write!(text, " — {}", check.detail);
+            }
+            text.push('\n');
+        }
+        let failed = self.checks.iter().filter(|check| !check.passed).count();
+        let key = if failed == 0 {
+            "self_test.summary_passed"
+        } else {
+            "self_test.summary_failed"
+        };
+        let _ = writeln!(
+            text,
+            "{}",
+            tf(key, &[("total", &self.checks.len()), ("failed", &failed)])
+        );
+        text
+    }
+
+    /// 점검 결과를 추가한다.
+    fn check(&mut self, name: &'static str, passed: bool, detail: impl FnOnce() -> String) {
+        let detail = if passed { String::new() } else { detail() };
+        self.checks.push(SelfTestCheck {
+            name,
+            passed,
+            detail,
+        });
+    }
+}
+
+/// 합성 시나리오를 만들어 창 없이 실행하고 엔진 불변 조건을 점검한다.
+///
+/// 배치 서버에 도구를 배포한 뒤 돌리는 스모크 테스트이다. 임시 폴더에 입력 파일과 시나리오를 만들고
+/// Dummy 실행기로 실행하므로 실제 DB에는 접속하지 않는다. 리허설 실행으로 돌려 실행 이력과 웹훅
+/// 알림을 남기지 않는다. 보고서는 실행 로그 디렉터리에 `self-test-<시각>.txt`로도 남기며, 임시 폴더는
+/// 끝나면 지운다.
+///
+/// # 반환값
+/// 점검 보고서와 기록한 보고서 파일 경로. 보고서를 남기지 못했으면 경로는 `None`이다.
+pub fn run_self_test(
+    runtime_config: EngineRuntimeConfig,
+) -> anyhow::Result<(SelfTestReport, Option<PathBuf>)> {
+    let dir = std::env::temp_dir().join(format!(
+        "rust-airflow-self-test-{}-{}",
+        std::process::id(),
+        chrono::Local::now().format("%Y%m%d%H%M%S")
+    ));
+    let result = prepare_fixture(&dir).and_then(|scenario| {
+        let runtime = runtime_config.build()?;
+        Ok(runtime.block_on(run_and_check(scenario)))
+    });
+    if let Err(err) = std::fs::remove_dir_all(&dir) {
+        tracing::warn!("자가 점검 임시 폴더 삭제 실패: {err}");
+    }
+    let report = result?;
+    let path = log_dir().join(format!(
+        "self-test-{}.txt",
+        chrono::Local::now().format("%Y%m%d%H%M%S")
+    ));
+    let written =
+        std::fs::create_dir_all(log_dir()).and_then(|_| std::fs::write(&path, report.to_text()));
+    match written {
+        Ok(()) => Ok((report, Some(path))),
+        Err(err) => {
+            tracing::warn!("자가 점검 보고서 기록 실패: {err}");
+            Ok((report, None))
+        }
+    }
+}
+
+/// 임시 폴더에 입력 파일과 시나리오 파일을 만들고 시나리오를 읽는다.
+fn prepare_fixture(dir: &Path) -> anyhow::Result<Scenario> {
+    let loop_dir = dir.join("loop");
+    std::fs::create_dir_all(&loop_dir)
+        .with_context(|| tf("self_test.fixture_dir", &[("path", &dir.display())]))?;
+    std::fs::write(dir.join("value.txt"), format!("value={EXTRACT_VALUE}\n"))?;
+    for idx in 1..=LOOP_FILES {
+        std::fs::write(loop_dir.join(format!("file{idx}.txt")), format!("{idx}\n"))?;
+    }
+    let path = dir.join("self-test.yaml");
+    let yaml = SCENARIO_TEMPLATE.replace("{dir}", &dir.to_string_lossy());
+    std::fs::write(&path, yaml)?;
+    load_scenario_from_file(&path)
+}
+
+/// 시나리오를 실행하며 이벤트를 모은 뒤 불변 조건을 점검한다.
+async fn run_and_check(scenario: Scenario) -> SelfTestReport {
+    let deps: HashMap<String, Vec<String>> = scenario
+        .steps
+        .iter()
+        .map(|step| (step.id.clone(), step.depends_on.clone()))
+        .collect();
+    let loop_ids: Vec<String> = scenario
+        .steps
+        .iter()
+        .filter(|step| matches!(step.kind, StepKind::Loop { .. }))
+        .map(|step| step.id.clone())
+        .collect();
+    let executor: SharedExecutor = Arc::new(DummyExecutor);
+    let plan = RehearsalPlan {
+        simulate_others: false,
+        faults: HashMap::new(),
+    };
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let run = tokio::spawn(run_scenario(
+        scenario,
+        executor,
+        EngineHandleCache::default(),
+        tx,
+        CancellationToken::new(),
+        None,
+        ConfirmMode::Prompt,
+        Some(plan),
+        None,
+        None,
+    ));
+    let mut events = Vec::new();
+    while let Some(event) = rx.recv().await {
+        events.push(event);
+    }
+    let mut report = SelfTestReport::default();
+    let run_result = match run.await {
+        Ok(result) => result.map_err(|err| format!("{err:#}")),
+        Err(err) => Err(err.to_string()),
+    };
+    report.check(t("self_test.engine_ok"), run_result.is_ok(), || {
+        run_result.clone().err().unwrap_or_default()
+    });
+    check_events(&mut report, &events, &deps, &loop_ids);
+    report
+}
+
+/// 모은 이벤트로 실행 순서와 결과에 대한 불변 조건을 점검한다.
+fn check_events(
+    report: &mut SelfTestReport,
+    events: &[EngineEvent],
+    deps: &HashMap<String, Vec<String>>,
+    loop_ids: &[String],
+) {
+    let finished_count = events
+        .iter()
+        .filter(|event| matches!(event, EngineEvent::ScenarioFinished))
+        .count();
+    report.check(
+        t("self_test.finished_once"),
+        finished_count == 1 && matches!(events.last(), Some(EngineEvent::ScenarioFinished)),
+        || tf("self_test.finished_count", &[("count", &finished_count)]),
+    );
+
+    let mut succeeded: HashSet<&str> = HashSet::new();
+    let mut failed: HashSet<&str> = HashSet::new();
+    let mut blocked: HashSet<&str> = HashSet::new();
+    let mut started: HashSet<&str> = HashSet::new();
+    let mut outcomes: HashMap<&str, usize> = HashMap::new();
+    let mut early_starts: Vec<String> = Vec::new();
+    let mut extracted: Option<String> = None;
+    let mut loop_progress: HashMap<&str, (usize, usize)> = HashMap::new();
+    for event in events {
+        match event {
+            EngineEvent::StepStarted { step_id } => {
+                let Some(upstream) = deps.get(step_id) else {
+                    continue;
+                };
+                started.insert(step_id.as_str());
+                let missing: Vec<&str> = upstream
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|id| !succeeded.contains(id))
+                    .collect();
+                if !missing.is_empty() {
+                    early_starts.push(format!("{step_id}({})", missing.join(", ")));
+                }
+            }
+            EngineEvent::StepFinished {
+                step_id, success, ..
+            } if deps.contains_key(step_id) => {
+                *outcomes.entry(step_id.as_str()).or_default() += 1;
+                if *success {
+                    succeeded.insert(step_id.as_str());
+                } else {
+                    failed.insert(step_id.as_str());
+                }
+            }
+            EngineEvent::StepBlocked { step_id } if deps.contains_key(step_id) => {
+                *outcomes.entry(step_id.as_str()).or_default() += 1;
+                blocked.insert(step_id.as_str());
+            }
+            EngineEvent::StepVariables { vars, .. } => {
+                if let Some(var) = vars.iter().rev().find(|var| var.name == EXTRACT_VAR) {
+                    extracted = Some(var.value.clone());
+                }
+            }
+            EngineEvent::LoopProgress {
+                step_id,
+                done,
+                total,
+            } => {
+                loop_progress.insert(step_id.as_str(), (*done, *total));
+            }
+            _ => {}
+        }
+    }
+
+    let mut ids: Vec<&str> = deps.keys().map(String::as_str).collect();
+    ids.sort_unstable();
+    let uneven: Vec<String> = ids
+        .iter()
+        .filter(|id| outcomes.get(*id).copied().unwrap_or_default() != 1)
+        .map(|id| format!("{id}={}", outcomes.get(id).copied().unwrap_or_default()))
+        .collect();
+    report.check(t("self_test.steps_once"), uneven.is_empty(), || {
+        uneven.join(", ")
+    });
+    report.check(
+        t("self_test.dependency_order"),
+        early_starts.is_empty(),
+        || early_starts.join(", "),
+    );
+    report.check(
+        t("self_test.forced_failure"),
+        failed.contains(FAILING_STEP) && failed.len() == 1,
+        || {
+            tf(
+                "self_test.failed_steps",
+                &[("steps", &format!("{failed:?}"))],
+            )
+        },
+    );
+    report.check(
+        t("self_test.blocked_downstream"),
+        blocked.contains(BLOCKED_STEP) && !started.contains(BLOCKED_STEP) && blocked.len() == 1,
+        || {
+            tf(
+                "self_test.blocked_steps",
+                &[("steps", &format!("{blocked:?}"))],
+            )
+        },
+    );
+    let expected_success: Vec<&str> = ids
+        .iter()
+        .copied()
+        .filter(|id| *id != FAILING_STEP && *id != BLOCKED_STEP)
+        .collect();
+    let not_succeeded: Vec<&str> = expected_success
+        .iter()
+        .copied()
+        .filter(|id| !succeeded.contains(id))
+        .collect();
+    report.check(
+        t("self_test.independent_success"),
+        not_succeeded.is_empty(),
+        || not_succeeded.join(", "),
+    );
+    report.check(
+        t("self_test.extract_value"),
+        extracted.as_deref() == Some(EXTRACT_VALUE),
+        || format!("{EXTRACT_VAR}={extracted:?}"),
+    );
+    for id in loop_ids {
+        let progress = loop_progress.get(id.as_str()).copied();
+        report.check(
+            t("self_test.loop_files"),
+            progress == Some((LOOP_FILES, LOOP_FILES)),
+            || format!("{id}: {progress:?}"),
+        );
+    }
+}
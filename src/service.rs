@@ -0,0 +1,344 @@
+use crate::engine::{EngineRuntimeConfig, log_dir};
+use crate::i18n::tf;
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+
+/// 서비스 제어 관리자에 등록하는 서비스 이름이다.
+pub const SERVICE_NAME: &str = "RustAirflowDaemon";
+
+/// 서비스 로그 파일 이름이다. 실행 로그 디렉터리 아래에 남긴다.
+const SERVICE_LOG_FILE: &str = "daemon-service.log";
+
+/// `--daemon-token-file`을 주지 않았을 때 서비스 작업 디렉터리에 두는 접속 토큰 파일 이름이다.
+#[cfg_attr(not(windows), allow(dead_code))]
+const SERVICE_TOKEN_FILE: &str = "daemon.token";
+
+/// `--account`를 주지 않았을 때 쓰는 서비스 전용 가상 계정이다. 암호가 없고 관리자 권한도 없다.
+#[cfg_attr(not(windows), allow(dead_code))]
+const DEFAULT_SERVICE_ACCOUNT: &str = "NT SERVICE\\RustAirflowDaemon";
+
+/// 서비스 실행 계정의 암호를 읽는 환경 변수 이름이다. 가상 계정과 gMSA는 암호가 필요 없다.
+#[cfg_attr(not(windows), allow(dead_code))]
+pub const SERVICE_PASSWORD_ENV: &str = "RUST_AIRFLOW_SERVICE_PASSWORD";
+
+/// 서비스 실행 계정으로 쓸 수 없는 고권한 계정 이름(소문자)이다.
+#[cfg_attr(not(windows), allow(dead_code))]
+const PRIVILEGED_ACCOUNTS: &[&str] = &[
+    "localsystem",
+    ".\\localsystem",
+    "system",
+    "nt authority\\system",
+];
+
+/// `--service` 하위 명령이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceCommand {
+    /// 현재 실행 파일과 데몬 인자로 서비스를 등록한다.
+    Install,
+    /// 서비스를 멈추고 등록을 지운다.
+    Uninstall,
+    /// 등록한 서비스를 시작한다.
+    Start,
+    /// 서비스 제어 관리자가 부르는 서비스 본체이다. 직접 쓰지 않는다.
+    Run,
+}
+
+impl ServiceCommand {
+    /// 하위 명령 이름을 해석한다.
+    pub fn parse(name: &str) -> anyhow::Result<Self> {
+        match name.trim() {
+            "install" => Ok(ServiceCommand::Install),
+            "uninstall" => Ok(ServiceCommand::Uninstall),
+            "start" => Ok(ServiceCommand::Start),
+            "run" => Ok(ServiceCommand::Run),
+            other => anyhow::bail!(tf("service.error.unknown_command", &[("command", &other)])),
+        }
+    }
+}
+
+/// 서비스로 띄울 데몬 구성이다.
+///
+/// 등록할 때 서비스 실행 인자로 옮기고, 서비스가 시작되면 그 인자에서 다시 읽는다.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(windows), allow(dead_code))]
+pub struct ServiceLaunch {
+    /// 데몬이 대기할 루프백 주소.
+    pub addr: String,
+    /// 데몬 접속 토큰 파일. 없으면 등록할 때 서비스 작업 디렉터리의 `daemon.token`을 쓴다.
+    pub token_file: Option<PathBuf>,
+    /// 서비스 실행 계정. 없으면 가상 서비스 계정이다. 등록할 때만 쓴다.
+    pub account: Option<String>,
+    /// 트리거 파일을 감시할 시나리오 파일 목록.
+    pub triggers: Vec<PathBuf>,
+    /// 엔진 런타임 구성.
+    pub runtime_config: EngineRuntimeConfig,
+}
+
+impl ServiceLaunch {
+    /// 서비스 실행 계정을 정한다. LocalSystem 같은 고권한 계정은 거부한다.
+    ///
+    /// 데몬은 접속한 사용자의 shell Step을 서비스 계정으로 실행하므로, 서비스 계정이 고권한이면 토큰을
+    /// 읽을 수 있는 사용자가 그 권한을 얻게 된다.
+    #[cfg_attr(not(windows), allow(dead_code))]
+    fn service_account(&self) -> anyhow::Result<String> {
+        let account = self
+            .account
+            .as_deref()
+            .map(str::trim)
+            .filter(|account| !account.is_empty())
+            .unwrap_or(DEFAULT_SERVICE_ACCOUNT);
+        if PRIVILEGED_ACCOUNTS.contains(&account.to_lowercase().as_str()) {
+            anyhow::bail!(tf(
+                "service.error.privileged_account",
+                &[("account", &account)],
+            ));
+        }
+        Ok(account.to_string())
+    }
+}
+
+/// 서비스 프로세스의 작업 디렉터리를 등록한 디렉터리로 옮기고 로그를 파일로 보낸다.
+///
+/// 서비스는 `System32`에서 콘솔 없이 시작하므로, 상대 경로로 적은 앱 설정과 실행 로그 디렉터리가
+/// 등록할 때와 같은 곳을 가리키도록 먼저 옮긴 뒤 `<로그 디렉터리>/daemon-service.log`에 로그를
+/// 덧붙인다. 파일을 열지 못하면 기본 로그 설정을 쓴다.
+pub fn init_service_process(dir: Option<&Path>) {
+    let moved = dir.map(std::env::set_current_dir);
+    let dir = log_dir();
+    let file = std::fs::create_dir_all(&dir).and_then(|_| {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(SERVICE_LOG_FILE))
+    });
+    match file {
+        Ok(file) => tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_writer(std::sync::Mutex::new(file))
+            .init(),
+        Err(_) => tracing_subscriber::fmt::init(),
+    }
+    if let Some(Err(err)) = moved {
+        tracing::warn!("서비스 작업 디렉터리 이동 실패: {err}");
+    }
+}
+
+/// 서비스 하위 명령을 실행한다.
+///
+/// `Run`은 서비스가 멈출 때까지 반환하지 않는다. Windows가 아니면 오류를 반환한다.
+pub fn run_service_command(command: ServiceCommand, launch: ServiceLaunch) -> anyhow::Result<()> {
+    #[cfg(windows)]
+    {
+        match command {
+            ServiceCommand::Install => windows::install(&launch),
+            ServiceCommand::Uninstall => windows::uninstall(),
+            ServiceCommand::Start => windows::start(),
+            ServiceCommand::Run => windows::run(launch),
+        }
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = (command, launch);
+        anyhow::bail!(crate::i18n::t("service.error.windows_only"))
+    }
+}
+
+#[cfg(windows)]
+mod windows {
+    use super::{SERVICE_NAME, SERVICE_PASSWORD_ENV, SERVICE_TOKEN_FILE, ServiceLaunch};
+    use crate::daemon::{DaemonEndpoint, daemon_token_path, run_daemon};
+    use crate::executor::{DummyExecutor, SharedExecutor};
+    use crate::i18n::{t, tf};
+    use anyhow::Context;
+    use once_cell::sync::OnceCell;
+    use std::ffi::{OsStr, OsString};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio_util::sync::CancellationToken;
+    use windows_service::service::{
+        ServiceAccess, ServiceControl, ServiceControlAccept, ServiceErrorControl, ServiceExitCode,
+        ServiceInfo, ServiceStartType, ServiceState, ServiceStatus, ServiceType,
+    };
+    use windows_service::service_control_handler::{self, ServiceControlHandlerResult};
+    use windows_service::service_manager::{ServiceManager, ServiceManagerAccess};
+    use windows_service::{define_windows_service, service_dispatcher};
+
+    /// 서비스 중지 요청 뒤 실행 중인 작업이 정리되기를 기다리는 최대 시간이다.
+    const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// 서비스 본체에 넘길 데몬 구성이다. 서비스 제어 관리자가 별도 스레드에서 본체를 부르므로 여기에 둔다.
+    static LAUNCH: OnceCell<ServiceLaunch> = OnceCell::new();
+
+    define_windows_service!(ffi_service_main, service_main);
+
+    /// 현재 실행 파일을 자동 시작 서비스로 등록한다.
+    ///
+    /// 서비스는 `System32`에서 시작하므로 지금 작업 디렉터리를 `--service-dir`로 넘기고 트리거
+    /// 시나리오와 접속 토큰 파일은 절대 경로로 적는다. 실행 계정은 [`ServiceLaunch::service_account`]로
+    /// 정하며, 암호가 필요한 계정이면 `RUST_AIRFLOW_SERVICE_PASSWORD`에서 읽는다.
+    pub(super) fn install(launch: &ServiceLaunch) -> anyhow::Result<()> {
+        let account = launch.service_account()?;
+        let dir = std::env::current_dir()?;
+        let token_file = match &launch.token_file {
+            Some(path) => std::path::absolute(path)?,
+            None => dir.join(SERVICE_TOKEN_FILE),
+        };
+        let mut args = vec![
+            "--service".to_string(),
+            "run".to_string(),
+            format!("--service-dir={}", dir.display()),
+            format!("--daemon-addr={}", launch.addr),
+            format!("--daemon-token-file={}", token_file.display()),
+        ];
+        for path in &launch.triggers {
+            args.push(format!(
+                "--trigger={}",
+                std::path::absolute(path)?.display()
+            ));
+        }
+        if let Some(workers) = launch.runtime_config.worker_threads {
+            args.push(format!("--engine-workers={workers}"));
+        }
+        if let Some(blocking) = launch.runtime_config.max_blocking_threads {
+            args.push(format!("--engine-blocking-threads={blocking}"));
+        }
+        let manager = ServiceManager::local_computer(
+            None::<&str>,
+            ServiceManagerAccess::CONNECT | ServiceManagerAccess::CREATE_SERVICE,
+        )
+        .context(t("service.error.manager"))?;
+        let info = ServiceInfo {
+            name: OsString::from(SERVICE_NAME),
+            display_name: OsString::from(t("service.display_name")),
+            service_type: ServiceType::OWN_PROCESS,
+            start_type: ServiceStartType::AutoStart,
+            error_control: ServiceErrorControl::Normal,
+            executable_path: std::env::current_exe()?,
+            launch_arguments: args.into_iter().map(OsString::from).collect(),
+            dependencies: Vec::new(),
+            account_name: Some(OsString::from(&account)),
+            account_password: std::env::var_os(SERVICE_PASSWORD_ENV),
+        };
+        let service = manager
+            .create_service(&info, ServiceAccess::CHANGE_CONFIG)
+            .context(t("service.error.create"))?;
+        service.set_description(t("service.description"))?;
+        println!(
+            "{}",
+            tf(
+                "service.installed",
+                &[("account", &account), ("token_file", &token_file.display())],
+            )
+        );
+        Ok(())
+    }
+
+    /// 서비스가 실행 중이면 멈추고 등록을 지운다.
+    pub(super) fn uninstall() -> anyhow::Result<()> {
+        let manager = ServiceManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)
+            .context(t("service.error.manager"))?;
+        let service = manager
+            .open_service(
+                SERVICE_NAME,
+                ServiceAccess::QUERY_STATUS | ServiceAccess::STOP | ServiceAccess::DELETE,
+            )
+            .context(t("service.error.not_found"))?;
+        if service.query_status()?.current_state != ServiceState::Stopped {
+            service.stop().context(t("service.error.stop"))?;
+        }
+        service.delete().context(t("service.error.delete"))?;
+        Ok(())
+    }
+
+    /// 등록한 서비스를 시작한다.
+    pub(super) fn start() -> anyhow::Result<()> {
+        let manager = ServiceManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)
+            .context(t("service.error.manager"))?;
+        let service = manager
+            .open_service(SERVICE_NAME, ServiceAccess::START)
+            .context(t("service.error.not_found"))?;
+        service
+            .start::<&OsStr>(&[])
+            .context(t("service.error.start"))?;
+        Ok(())
+    }
+
+    /// 서비스 제어 관리자에 본체를 넘기고 서비스가 멈출 때까지 기다린다.
+    pub(super) fn run(launch: ServiceLaunch) -> anyhow::Result<()> {
+        let _ = LAUNCH.set(launch);
+        service_dispatcher::start(SERVICE_NAME, ffi_service_main)
+            .context(t("service.error.dispatcher"))?;
+        Ok(())
+    }
+
+    /// 서비스 제어 관리자가 부르는 본체이다.
+    fn service_main(_arguments: Vec<OsString>) {
+        if let Err(err) = serve() {
+            tracing::error!("서비스 실행 실패: {err:#}");
+        }
+    }
+
+    /// 중지 요청을 받을 때까지 데몬을 실행하고 서비스 상태를 알린다.
+    fn serve() -> anyhow::Result<()> {
+        let launch = LAUNCH.get().context(t("service.error.no_launch"))?;
+        let stop = CancellationToken::new();
+        let handler_stop = stop.clone();
+        let status =
+            service_control_handler::register(SERVICE_NAME, move |control| match control {
+                ServiceControl::Stop | ServiceControl::Shutdown => {
+                    handler_stop.cancel();
+                    ServiceControlHandlerResult::NoError
+                }
+                ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+                _ => ServiceControlHandlerResult::NotImplemented,
+            })?;
+        status.set_service_status(service_status(ServiceState::Running, 0))?;
+        let result = run_until_stopped(launch, stop);
+        let code = if result.is_ok() { 0 } else { 1 };
+        status.set_service_status(service_status(ServiceState::Stopped, code))?;
+        result
+    }
+
+    /// 엔진 런타임에서 데몬을 띄우고 중지 요청이 오면 실행 중인 작업을 정리한다.
+    fn run_until_stopped(launch: &ServiceLaunch, stop: CancellationToken) -> anyhow::Result<()> {
+        let runtime = launch
+            .runtime_config
+            .build()
+            .context(t("service.error.runtime"))?;
+        let executor: SharedExecutor = Arc::new(DummyExecutor);
+        let endpoint = DaemonEndpoint {
+            addr: launch.addr.clone(),
+            token_file: launch.token_file.clone().unwrap_or_else(daemon_token_path),
+        };
+        tracing::info!("서비스 시작: {SERVICE_NAME}");
+        let result = runtime.block_on(async {
+            tokio::select! {
+                result = run_daemon(&endpoint, executor, launch.triggers.clone()) => result,
+                _ = stop.cancelled() => {
+                    tracing::info!("서비스 중지 요청");
+                    Ok(())
+                }
+            }
+        });
+        runtime.shutdown_timeout(SHUTDOWN_TIMEOUT);
+        result
+    }
+
+    /// 서비스 제어 관리자에 알릴 상태를 만든다.
+    fn service_status(state: ServiceState, exit_code: u32) -> ServiceStatus {
+        let controls_accepted = if state == ServiceState::Running {
+            ServiceControlAccept::STOP | ServiceControlAccept::SHUTDOWN
+        } else {
+            ServiceControlAccept::empty()
+        };
+        ServiceStatus {
+            service_type: ServiceType::OWN_PROCESS,
+            current_state: state,
+            controls_accepted,
+            exit_code: ServiceExitCode::Win32(exit_code),
+            checkpoint: 0,
+            wait_hint: Duration::default(),
+            process_id: None,
+        }
+    }
+}
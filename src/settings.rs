@@ -0,0 +1,185 @@
+use crate::i18n::{Language, tf};
+use crate::theme::ThemeVariant;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// 앱 설정 파일 경로를 지정하는 환경 변수 이름이다.
+pub const SETTINGS_FILE_ENV: &str = "RUST_AIRFLOW_SETTINGS_FILE";
+
+/// 환경 변수가 없을 때 사용하는 앱 설정 파일 경로이다.
+pub const DEFAULT_SETTINGS_FILE: &str = "settings.yaml";
+
+/// 앱 설정에 화면 상태를 기억하는 최대 시나리오 파일 수이다. 넘으면 가장 오래전에 바뀐 것부터 버린다.
+pub const SCENARIO_VIEWS_MAX: usize = 200;
+
+/// 설정 대화상자에서 편집해 앱 설정 파일에 저장하는 앱 전역 설정이다.
+///
+/// 비어 있는 값은 각 기능의 기본값을 따르며, 같은 값을 명령줄 인자나 환경 변수로 지정하면 그쪽이 우선한다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// 실행 로그 파일 디렉터리.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_dir: Option<PathBuf>,
+    /// 실행 이력 보존 일수. 지난 이력은 실행이 끝날 때 지운다. 없으면 모두 보존한다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_keep_days: Option<u32>,
+    /// UI 테마.
+    pub theme: ThemeVariant,
+    /// 시작 언어.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+    /// UI 배율. `1.0`이 기본 크기이다.
+    pub ui_scale: f32,
+    /// 엔진 워커 스레드 수 기본값.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engine_workers: Option<usize>,
+    /// 엔진 블로킹 스레드 풀 상한 기본값.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engine_blocking_threads: Option<usize>,
+    /// 실행이 끝나면 결과를 JSON으로 POST할 웹훅 주소(`http://`) 목록.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notify_endpoints: Vec<String>,
+    /// `모든 시나리오에서 찾기`로 검색할 시나리오 폴더. 없으면 현재 디렉터리를 쓴다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scenario_dir: Option<PathBuf>,
+    /// 시나리오 파일 경로 해시([`scenario_view_key`])별로 기억한 화면 상태. 최대
+    /// [`SCENARIO_VIEWS_MAX`]개이다.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub scenario_views: BTreeMap<String, ScenarioViewPrefs>,
+}
+
+/// 시나리오 파일 하나를 다시 열 때 되살리는 화면 상태이다.
+///
+/// 운영자 개인의 보기 상태이므로 시나리오 파일이 아니라 앱 설정 파일에 둔다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScenarioViewPrefs {
+    /// 실행 탭에서 선택한 Step ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_step: Option<String>,
+    /// 실행 탭에서 접어 둔 스테이지 이름. 스테이지가 없는 Step 묶음은 빈 문자열이다.
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub collapsed_stages: BTreeSet<String>,
+    /// 로그 영역의 필터 검색어.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub log_filter: String,
+    /// 빌더 최상위 흐름의 캔버스 오프셋 `[x, y]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canvas_offset: Option<[f32; 2]>,
+    /// 빌더 최상위 흐름의 캔버스 줌 비율.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canvas_zoom: Option<f32>,
+    /// 마지막으로 바뀐 시각(유닉스 초). 기억한 화면 상태가 너무 많을 때 버릴 순서를 정한다.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub updated_at: i64,
+}
+
+/// 직렬화하지 않을 기본값(0)인지 확인한다.
+fn is_zero(value: &i64) -> bool {
+    *value == 0
+}
+
+impl AppSettings {
+    /// 시나리오 파일 하나의 화면 상태를 기억한다.
+    ///
+    /// [`SCENARIO_VIEWS_MAX`]개를 넘으면 가장 오래전에 바뀐 것부터 버린다.
+    pub fn remember_scenario_view(&mut self, key: String, mut prefs: ScenarioViewPrefs) {
+        prefs.updated_at = chrono::Local::now().timestamp();
+        self.scenario_views.insert(key, prefs);
+        while self.scenario_views.len() > SCENARIO_VIEWS_MAX {
+            let Some(oldest) = self
+                .scenario_views
+                .iter()
+                .min_by_key(|(_, prefs)| prefs.updated_at)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.scenario_views.remove(&oldest);
+        }
+    }
+}
+
+impl Default for AppSettings {
+    /// 모든 기능의 기본값을 따르는 설정을 만든다.
+    fn default() -> Self {
+        Self {
+            log_dir: None,
+            history_keep_days: None,
+            theme: ThemeVariant::Light,
+            language: None,
+            ui_scale: 1.0,
+            engine_workers: None,
+            engine_blocking_threads: None,
+            notify_endpoints: Vec::new(),
+            scenario_dir: None,
+            scenario_views: BTreeMap::new(),
+        }
+    }
+}
+
+/// 앱 설정 파일 경로를 반환한다.
+pub fn settings_path() -> PathBuf {
+    std::env::var(SETTINGS_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_SETTINGS_FILE))
+}
+
+/// 현재 사용자의 데이터 디렉터리(`rust-airflow`)를 반환한다.
+///
+/// Windows는 `%LOCALAPPDATA%`, 그 밖에는 `$XDG_DATA_HOME`(없으면 `~/.local/share`) 아래이다. 모두
+/// 없으면 현재 디렉터리를 쓴다.
+pub fn user_data_dir() -> PathBuf {
+    #[cfg(windows)]
+    let base = std::env::var_os("LOCALAPPDATA").map(PathBuf::from);
+    #[cfg(not(windows))]
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")));
+    base.map(|base| base.join("rust-airflow"))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// 승인 기록에 남길 현재 프로세스의 OS 사용자 이름을 반환한다. 알 수 없으면 `unknown`이다.
+pub fn local_user_name() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// 시나리오 파일 경로로 [`AppSettings::scenario_views`]의 키를 만든다.
+///
+/// 경로를 정규화할 수 있으면 정규화한 경로의 SHA-256 앞 16자리이므로, 상대 경로로 열어도 같은
+/// 파일이면 같은 키가 된다.
+pub fn scenario_view_key(path: &Path) -> String {
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let digest = Sha256::digest(path.to_string_lossy().as_bytes());
+    format!("{digest:x}")[..16].to_string()
+}
+
+/// 앱 설정을 읽는다. 파일이 없으면 기본 설정을 반환한다.
+pub fn load_app_settings() -> anyhow::Result<AppSettings> {
+    let path = settings_path();
+    if !path.exists() {
+        return Ok(AppSettings::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| tf("settings.error.read", &[("path", &path.display())]))?;
+    serde_yaml::from_str(&content)
+        .with_context(|| tf("settings.error.parse", &[("path", &path.display())]))
+}
+
+/// 앱 설정을 파일에 기록한다.
+pub fn save_app_settings(settings: &AppSettings) -> anyhow::Result<()> {
+    let path = settings_path();
+    let yaml = serde_yaml::to_string(settings)?;
+    std::fs::write(&path, yaml)
+        .with_context(|| tf("settings.error.save", &[("path", &path.display())]))
+}
@@ -0,0 +1,289 @@
+use crate::engine::ExecutionContext;
+use crate::history::{StepRunRecord, load_records, load_sql_snapshot, sql_sha256};
+use crate::scenario::{Scenario, StepKind};
+use chrono::{DateTime, Local};
+use std::collections::HashMap;
+
+/// 줄 비교로 차이를 계산할 최대 표 크기(바뀐 구간의 이전 줄 수 × 현재 줄 수)이다.
+///
+/// 넘으면 바뀐 구간 전체를 지운 줄과 추가한 줄로 보여 준다.
+const DIFF_MAX_CELLS: usize = 4_000_000;
+
+/// 마지막 성공 실행 이후 내용이 바뀐 `sql_file`이다.
+#[derive(Debug, Clone)]
+pub struct SqlFileChange {
+    /// 파일을 읽는 Step ID.
+    pub step_id: String,
+    /// 시나리오 파라미터로 치환한 파일 경로.
+    pub path: String,
+    /// 비교한 마지막 성공 실행의 종료 시각(RFC 3339).
+    pub last_success_at: String,
+    /// 마지막 성공 실행 때의 파일 내용. 이력 정리로 스냅숏이 지워졌으면 `None`이다.
+    pub previous: Option<String>,
+    /// 현재 파일 내용.
+    pub current: String,
+}
+
+impl SqlFileChange {
+    /// 비교한 마지막 성공 실행 시각을 로컬 시간 `YYYY-MM-DD HH:MM`으로 반환한다.
+    pub fn last_success_label(&self) -> String {
+        DateTime::parse_from_rfc3339(&self.last_success_at)
+            .map(|time| {
+                time.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_else(|_| self.last_success_at.clone())
+    }
+
+    /// 마지막 성공 실행 때 내용과 현재 내용의 줄 단위 차이를 계산한다. 이전 내용이 없으면 비어 있다.
+    pub fn diff(&self) -> Vec<DiffLine> {
+        self.previous
+            .as_deref()
+            .map(|previous| diff_lines(previous, &self.current))
+            .unwrap_or_default()
+    }
+}
+
+/// 줄 단위 차이 한 줄이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    /// 양쪽에 같은 줄.
+    Same(String),
+    /// 마지막 성공 실행 때만 있던 줄.
+    Removed(String),
+    /// 현재 파일에만 있는 줄.
+    Added(String),
+}
+
+/// 시나리오의 `sql_file` Step 중 마지막 성공 실행 이후 파일 내용이 바뀐 것을 찾는다.
+///
+/// 실행 이력에 남은 Step별 마지막 성공 실행의 SQL 해시와 지금 파일 내용(치환 전)의 해시를 비교한다.
+/// Step이 성공했더라도 실행 전체가 실패하거나 취소된 실행은 기준으로 삼지 않는다. 실행 결과가 없는
+/// 예전 이력은 같은 실행의 Step이 모두 성공했을 때만 성공한 실행으로 본다. 이력은 최상위 Step만
+/// 남기므로 Loop 내부와 `on_failure` Step은 비교하지 않는다. 경로는 시나리오 파라미터로 치환하며,
+/// 치환되지 않는 변수가 남거나 읽을 수 없는 파일, 해시가 없는 예전 이력은 건너뛴다.
+///
+/// # 반환값
+/// Step ID별 변경 내용.
+pub fn changed_sql_files(scenario: &Scenario) -> HashMap<String, SqlFileChange> {
+    let records: Vec<StepRunRecord> = load_records()
+        .into_iter()
+        .filter(|record| record.scenario == scenario.name)
+        .collect();
+    let mut run_ok: HashMap<&str, bool> = HashMap::new();
+    for record in &records {
+        let Some(run_id) = record.run_id.as_deref() else {
+            continue;
+        };
+        let ok = record.run_success.unwrap_or(record.success);
+        run_ok
+            .entry(run_id)
+            .and_modify(|all| *all &= ok)
+            .or_insert(ok);
+    }
+    let mut last_success: HashMap<String, &StepRunRecord> = HashMap::new();
+    for record in &records {
+        let run_succeeded = record
+            .run_id
+            .as_deref()
+            .and_then(|run_id| run_ok.get(run_id).copied())
+            .unwrap_or(record.success);
+        if record.success && run_succeeded && record.sql_sha256.is_some() {
+            last_success.insert(record.step_id.clone(), record);
+        }
+    }
+    if last_success.is_empty() {
+        return HashMap::new();
+    }
+    let mut ctx = ExecutionContext::new();
+    for (key, value) in &scenario.params {
+        ctx.set_var(key.clone(), value.clone());
+    }
+    let mut changes = HashMap::new();
+    for step in &scenario.steps {
+        let StepKind::SqlFile { path, .. } = &step.kind else {
+            continue;
+        };
+        let Some(record) = last_success.get(&step.id) else {
+            continue;
+        };
+        let path = ctx.expand_lenient(&path.to_string_lossy());
+        if path.is_empty() || path.contains("${") {
+            continue;
+        }
+        let Ok(current) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let Some(hash) = record.sql_sha256.as_deref() else {
+            continue;
+        };
+        if sql_sha256(&current) == hash {
+            continue;
+        }
+        changes.insert(
+            step.id.clone(),
+            SqlFileChange {
+                step_id: step.id.clone(),
+                path,
+                last_success_at: record.finished_at.clone(),
+                previous: load_sql_snapshot(hash),
+                current,
+            },
+        );
+    }
+    changes
+}
+
+/// 두 텍스트의 줄 단위 차이를 계산한다.
+///
+/// 앞뒤의 같은 줄을 먼저 떼고, 남은 구간은 최장 공통 부분열로 맞춘다. 남은 구간이
+/// [`DIFF_MAX_CELLS`]보다 크면 구간 전체를 지운 줄과 추가한 줄로 본다.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+    let mut lines: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|line| DiffLine::Same(line.to_string()))
+        .collect();
+    if old_mid.len().saturating_mul(new_mid.len()) > DIFF_MAX_CELLS {
+        lines.extend(
+            old_mid
+                .iter()
+                .map(|line| DiffLine::Removed(line.to_string())),
+        );
+        lines.extend(new_mid.iter().map(|line| DiffLine::Added(line.to_string())));
+    } else {
+        lines.extend(lcs_diff(old_mid, new_mid));
+    }
+    lines.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| DiffLine::Same(line.to_string())),
+    );
+    lines
+}
+
+/// 최장 공통 부분열 표로 두 줄 목록의 차이를 계산한다. 같은 위치에서는 지운 줄을 먼저 둔다.
+fn lcs_diff(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    let width = new.len() + 1;
+    // table[i * width + j]는 old[i..]와 new[j..]의 최장 공통 부분열 길이이다.
+    let mut table = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            table[i * width + j] = if old[i] == new[j] {
+                table[(i + 1) * width + j + 1] + 1
+            } else {
+                table[(i + 1) * width + j].max(table[i * width + j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::with_capacity(old.len() + new.len());
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * width + j] >= table[i * width + j + 1] {
+            lines.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    lines.extend(
+        old[i..]
+            .iter()
+            .map(|line| DiffLine::Removed(line.to_string())),
+    );
+    lines.extend(
+        new[j..]
+            .iter()
+            .map(|line| DiffLine::Added(line.to_string())),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn same(line: &str) -> DiffLine {
+        DiffLine::Same(line.to_string())
+    }
+
+    fn removed(line: &str) -> DiffLine {
+        DiffLine::Removed(line.to_string())
+    }
+
+    fn added(line: &str) -> DiffLine {
+        DiffLine::Added(line.to_string())
+    }
+
+    #[test]
+    fn identical_text_has_only_same_lines() {
+        assert_eq!(diff_lines("a\nb", "a\nb"), vec![same("a"), same("b")]);
+    }
+
+    #[test]
+    fn keeps_common_prefix_and_suffix() {
+        assert_eq!(
+            diff_lines(
+                "select 1\nfrom a\nwhere x = 1\n;",
+                "select 1\nfrom b\nwhere x = 1\n;"
+            ),
+            vec![
+                same("select 1"),
+                removed("from a"),
+                added("from b"),
+                same("where x = 1"),
+                same(";"),
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_empty_sides() {
+        assert_eq!(diff_lines("", "a\nb"), vec![added("a"), added("b")]);
+        assert_eq!(diff_lines("a\nb", ""), vec![removed("a"), removed("b")]);
+        assert!(diff_lines("", "").is_empty());
+    }
+
+    #[test]
+    fn lcs_keeps_longest_common_lines() {
+        assert_eq!(
+            lcs_diff(&["a", "b", "c", "d"], &["b", "x", "d", "e"]),
+            vec![
+                removed("a"),
+                same("b"),
+                removed("c"),
+                added("x"),
+                same("d"),
+                added("e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn prefix_and_suffix_do_not_overlap_on_repeated_lines() {
+        assert_eq!(
+            diff_lines("a\na", "a\na\na"),
+            vec![same("a"), same("a"), added("a")]
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use crate::connections::load_connection_store;
+use crate::i18n::tf;
+use crate::scenario::{DbConnectionConfig, DbKind, Scenario, Step, StepKind};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sqlparser::dialect::{Dialect, GenericDialect, PostgreSqlDialect};
+use sqlparser::parser::Parser;
+use std::collections::HashMap;
+use std::fmt;
+
+/// `${VAR}` 플레이스홀더를 찾는 정규식이다.
+static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\$\{[^}]*\}").expect("정규식 오류"));
+
+/// 파서에 넘기기 전에 플레이스홀더를 대체할 식별자이다.
+const PLACEHOLDER_STUB: &str = "__var__";
+
+/// 검사 결과의 심각도이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlIssueLevel {
+    /// 실행하면 실패할 가능성이 높은 문법 오류이다.
+    Error,
+    /// 검사를 수행하지 못한 항목이다.
+    Warning,
+}
+
+/// SQL Step 하나에서 발견한 문제이다.
+#[derive(Debug, Clone)]
+pub struct SqlIssue {
+    /// 대상 Step ID.
+    pub step_id: String,
+    /// 심각도.
+    pub level: SqlIssueLevel,
+    /// 문제 설명.
+    pub message: String,
+}
+
+impl fmt::Display for SqlIssue {
+    /// `[step_id] 메시지` 형태로 출력한다.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.step_id, self.message)
+    }
+}
+
+/// 시나리오의 SQL/SQL 파일 Step을 대상 DB 종류에 맞는 문법으로 오프라인 검사한다.
+///
+/// `target_db`가 가리키는 연결의 종류로 방언을 고르며, `db_ref`는 공유 연결 저장소로 해석한다.
+/// Loop 내부 Step도 함께 검사한다.
+///
+/// # 매개변수
+/// - `scenario`: 검사할 시나리오.
+///
+/// # 반환값
+/// 발견한 문제 목록. 비어 있으면 모든 SQL이 검사를 통과했다.
+pub fn check_scenario_sql(scenario: &Scenario) -> Vec<SqlIssue> {
+    let db = load_connection_store()
+        .and_then(|store| store.resolve(&scenario.db))
+        .unwrap_or_else(|_| scenario.db.clone());
+    let mut issues = Vec::new();
+    collect_step_issues(&scenario.steps, &db, &mut issues);
+    issues
+}
+
+/// Step 목록을 재귀적으로 검사해 문제를 모은다.
+fn collect_step_issues(
+    steps: &[Step],
+    db: &HashMap<String, DbConnectionConfig>,
+    issues: &mut Vec<SqlIssue>,
+) {
+    for step in steps {
+        let (sql, target_db) = match &step.kind {
+            StepKind::Sql { sql, target_db, .. } => (sql.clone(), target_db),
+            StepKind::SqlFile {
+                path, target_db, ..
+            } => {
+                let raw = path.to_string_lossy();
+                if raw.contains("${") {
+                    issues.push(SqlIssue {
+                        step_id: step.id.clone(),
+                        level: SqlIssueLevel::Warning,
+                        message: tf("sql_check.path_has_vars", &[("path", &raw)]),
+                    });
+                    continue;
+                }
+                match std::fs::read_to_string(path) {
+                    Ok(content) => (content, target_db),
+                    Err(err) => {
+                        issues.push(SqlIssue {
+                            step_id: step.id.clone(),
+                            level: SqlIssueLevel::Error,
+                            message: tf(
+                                "sql_check.read_failed",
+                                &[("path", &raw), ("error", &err)],
+                            ),
+                        });
+                        continue;
+                    }
+                }
+            }
+            StepKind::Loop { config } => {
+                collect_step_issues(&config.steps, db, issues);
+                continue;
+            }
+            _ => continue,
+        };
+        let kind = db
+            .get(target_db.as_deref().unwrap_or("default"))
+            .map(|config| config.kind.clone())
+            .unwrap_or_default();
+        issues.extend(check_sql(&sql, &kind).into_iter().map(|message| SqlIssue {
+            step_id: step.id.clone(),
+            level: SqlIssueLevel::Error,
+            message,
+        }));
+    }
+}
+
+/// SQL 문자열 하나를 DB 종류에 맞춰 검사하고 문제 설명 목록을 반환한다.
+///
+/// 닫히지 않은 문자열/주석/괄호를 먼저 확인한 뒤 파서로 문법을 검사한다. Oracle은 sqlplus
+/// 명령 줄을 제외하고 일반 방언으로 파싱하며, PL/SQL 블록은 파서가 지원하지 않으므로 앞의
+/// 검사만 수행한다.
+///
+/// # 매개변수
+/// - `sql`: 검사할 SQL 문자열.
+/// - `kind`: 대상 DB 종류.
+pub fn check_sql(sql: &str, kind: &DbKind) -> Vec<String> {
+    let lexical = check_unterminated(sql);
+    if !lexical.is_empty() {
+        return lexical;
+    }
+    let body = match kind {
+        DbKind::Oracle => strip_sqlplus_commands(sql),
+        _ => sql.to_string(),
+    };
+    if body.trim().is_empty() || (*kind == DbKind::Oracle && is_plsql_block(&body)) {
+        return Vec::new();
+    }
+    let body = PLACEHOLDER.replace_all(&body, PLACEHOLDER_STUB);
+    let dialect: Box<dyn Dialect> = match kind {
+        DbKind::Postgres => Box::new(PostgreSqlDialect {}),
+        DbKind::Oracle | DbKind::Dummy => Box::new(GenericDialect {}),
+    };
+    match Parser::parse_sql(dialect.as_ref(), &body) {
+        Ok(_) => Vec::new(),
+        Err(err) => vec![tf("sql_check.syntax", &[("error", &err)])],
+    }
+}
+
+/// 닫히지 않은 문자열, 따옴표 식별자, 블록 주석과 짝이 맞지 않는 괄호를 찾는다.
+fn check_unterminated(sql: &str) -> Vec<String> {
+    let mut issues = Vec::new();
+    let mut chars = sql.chars().peekable();
+    let mut line = 1;
+    let mut depth: i32 = 0;
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\n' => line += 1,
+            '-' if chars.peek() == Some(&'-') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        line += 1;
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let start = line;
+                let mut closed = false;
+                while let Some(next) = chars.next() {
+                    if next == '\n' {
+                        line += 1;
+                    } else if next == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    issues.push(tf("sql_check.unclosed_comment", &[("line", &start)]));
+                }
+            }
+            '\'' | '"' => {
+                let start = line;
+                let mut closed = false;
+                while let Some(next) = chars.next() {
+                    if next == '\n' {
+                        line += 1;
+                    } else if next == ch {
+                        if chars.peek() == Some(&ch) {
+                            chars.next();
+                            continue;
+                        }
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    let key = if ch == '\'' {
+                        "sql_check.unclosed_string"
+                    } else {
+                        "sql_check.unclosed_identifier"
+                    };
+                    issues.push(tf(key, &[("line", &start)]));
+                }
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth < 0 {
+                    issues.push(tf("sql_check.unmatched_close", &[("line", &line)]));
+                    depth = 0;
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth > 0 {
+        issues.push(tf("sql_check.unclosed_paren", &[("count", &depth)]));
+    }
+    issues
+}
+
+/// sqlplus 전용 명령 줄(`SET`, `SPOOL`, `/` 등)을 빈 줄로 바꾼다.
+///
+/// 명령은 문장 사이(앞 문장이 `;`나 `/`로 끝난 뒤)에서 줄 첫 단어로 올 때만 알아보므로
+/// `UPDATE ... SET` 같은 여러 줄 문장 안의 `SET` 줄은 남긴다. 문장 첫머리의 `SET`도
+/// `SET TRANSACTION`·`SET ROLE`·`SET CONSTRAINTS`는 SQL 문장이라 남긴다.
+fn strip_sqlplus_commands(sql: &str) -> String {
+    const COMMANDS: [&str; 9] = [
+        "SET", "SPOOL", "PROMPT", "WHENEVER", "EXIT", "QUIT", "DEFINE", "UNDEFINE", "COLUMN",
+    ];
+    const SQL_SET: [&str; 4] = ["TRANSACTION", "ROLE", "CONSTRAINT", "CONSTRAINTS"];
+    let mut in_statement = false;
+    sql.lines()
+        .map(|line| {
+            let trimmed = line.trim();
+            if trimmed == "/" {
+                in_statement = false;
+                return "";
+            }
+            if trimmed.is_empty() || trimmed.starts_with("--") {
+                return line;
+            }
+            if !in_statement {
+                let upper = trimmed.to_uppercase();
+                let mut words = upper
+                    .split(|ch: char| ch.is_whitespace() || ch == ';')
+                    .filter(|word| !word.is_empty());
+                let first = words.next().unwrap_or_default();
+                let is_command = upper.starts_with('@')
+                    || (COMMANDS.contains(&first)
+                        && !(first == "SET"
+                            && words.next().is_some_and(|word| SQL_SET.contains(&word))));
+                if is_command {
+                    return "";
+                }
+            }
+            let code = trimmed
+                .split_once("--")
+                .map_or(trimmed, |(code, _)| code)
+                .trim_end();
+            in_statement = !code.ends_with(';');
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// PL/SQL 블록이나 저장 프로시저 정의가 포함되어 있는지 확인한다.
+fn is_plsql_block(sql: &str) -> bool {
+    let upper = sql.to_uppercase();
+    let head = upper.trim_start();
+    head.starts_with("BEGIN")
+        || head.starts_with("DECLARE")
+        || ["PROCEDURE", "FUNCTION", "PACKAGE", "TRIGGER"]
+            .iter()
+            .any(|object| upper.contains(&format!("CREATE OR REPLACE {object}")))
+}
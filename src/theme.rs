@@ -1,5 +1,8 @@
 use crate::engine::StepStatus;
+use crate::i18n::t;
+use crate::scenario::StepKind;
 use eframe::egui::{self, Color32};
+use serde::{Deserialize, Serialize};
 
 include!(concat!(env!("OUT_DIR"), "/custom_font.rs"));
 
@@ -55,11 +58,19 @@ impl ThemeDecorations {
     }
 }
 
-/// 테마 종류를 정의한다. 현재는 라이트 테마만 구현되어 있으며 추후 다크 테마를 추가할 수 있다.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// 테마 종류를 정의한다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ThemeVariant {
     /// 부드러운 파스텔 라이트 테마.
     Light,
+    /// 어두운 배경의 다크 테마.
+    Dark,
+}
+
+impl ThemeVariant {
+    /// 선택 가능한 모든 테마 목록이다.
+    pub const ALL: [ThemeVariant; 2] = [ThemeVariant::Light, ThemeVariant::Dark];
 }
 
 /// 시나리오 빌더 전용 색상 묶음이다.
@@ -81,21 +92,31 @@ pub struct BuilderColors {
     pub text_secondary: Color32,
     /// 핸들 색상.
     pub handle_fill: Color32,
+    /// 영향 미리보기에서 상위 의존 노드 강조 색상.
+    pub upstream_highlight: Color32,
+    /// 영향 미리보기에서 하위 의존 노드 강조 색상.
+    pub downstream_highlight: Color32,
+    /// 상위 노드에서 오는 변수 흐름 힌트 색상.
+    pub data_flow_hint: Color32,
+    /// 상위 노드가 아닌 곳에서 오는 변수 흐름 힌트 색상.
+    pub data_flow_conflict: Color32,
+    /// 크리티컬 패스 분석에서 경로 노드와 연결 강조 색상.
+    pub critical_path: Color32,
 }
 
 /// Step 시각 스타일을 정의한다.
 #[derive(Clone, Copy, Debug)]
-pub struct StepVisualStyle {
+pub struct StepVisualStyle<'a> {
     /// Step 유형 아이콘이다.
-    pub icon: &'static str,
+    pub icon: &'a str,
     /// Step 라벨이다.
-    pub label: &'static str,
+    pub label: &'a str,
     /// 색상이다.
     pub color: Color32,
 }
 
 /// Step 시각 분류이다.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum StepVisualKind {
     /// SQL 직접 입력.
     Sql,
@@ -109,6 +130,35 @@ pub enum StepVisualKind {
     Extract,
     /// Loop.
     Loop,
+    /// 수동 승인 게이트.
+    ManualGate,
+    /// 대기.
+    Wait,
+    /// 다른 시나리오 실행 대기.
+    WaitForRun,
+    /// 검증.
+    Verify,
+    /// 합류 표식.
+    Marker,
+}
+
+impl StepVisualKind {
+    /// 시나리오 Step 종류를 시각 분류로 매핑한다.
+    pub fn from_step_kind(kind: &StepKind) -> Self {
+        match kind {
+            StepKind::Sql { .. } => StepVisualKind::Sql,
+            StepKind::SqlFile { .. } => StepVisualKind::SqlFile,
+            StepKind::SqlLoaderPar { .. } => StepVisualKind::SqlLoader,
+            StepKind::Shell { .. } => StepVisualKind::Shell,
+            StepKind::Extract { .. } => StepVisualKind::Extract,
+            StepKind::Loop { .. } => StepVisualKind::Loop,
+            StepKind::ManualGate { .. } => StepVisualKind::ManualGate,
+            StepKind::Wait { .. } => StepVisualKind::Wait,
+            StepKind::WaitForRun { .. } => StepVisualKind::WaitForRun,
+            StepKind::Verify { .. } => StepVisualKind::Verify,
+            StepKind::Marker => StepVisualKind::Marker,
+        }
+    }
 }
 
 /// 라이트/다크 모드 공통으로 사용할 색상 팔레트를 정의한다.
@@ -182,6 +232,32 @@ impl ThemePalette {
             builder_handle: Color32::from_rgb(76, 128, 255),
         }
     }
+
+    /// 다크 테마용 팔레트를 반환한다. 강조색은 라이트 테마와 같은 계열을 쓴다.
+    pub const fn dark() -> Self {
+        Self {
+            bg_main: Color32::from_rgb(30, 31, 34),
+            bg_panel: Color32::from_rgb(43, 45, 48),
+            bg_toolbar: Color32::from_rgb(38, 40, 43),
+            bg_sidebar: Color32::from_rgb(36, 38, 41),
+            bg_log: Color32::from_rgb(25, 26, 28),
+            fg_text_primary: Color32::from_rgb(223, 225, 229),
+            fg_text_secondary: Color32::from_rgb(150, 154, 160),
+            accent_primary: Color32::from_rgb(92, 142, 255),
+            accent_success: Color32::from_rgb(102, 187, 106),
+            accent_warning: Color32::from_rgb(255, 202, 40),
+            accent_error: Color32::from_rgb(239, 83, 80),
+            border_soft: Color32::from_rgb(66, 69, 73),
+            accent_pending: Color32::from_rgb(117, 117, 117),
+            icon_emphasis: Color32::from_rgb(92, 142, 255),
+            builder_canvas: Color32::from_rgb(33, 35, 39),
+            builder_node: Color32::from_rgb(50, 53, 57),
+            builder_node_selected: Color32::from_rgb(42, 62, 102),
+            builder_connection: Color32::from_rgb(144, 164, 174),
+            builder_node_border: Color32::from_rgb(90, 96, 103),
+            builder_handle: Color32::from_rgb(92, 142, 255),
+        }
+    }
 }
 
 /// UI 전체에서 참조할 공통 테마 정보.
@@ -205,6 +281,23 @@ impl Theme {
         }
     }
 
+    /// 다크 테마 인스턴스를 생성한다.
+    pub fn dark() -> Self {
+        Self {
+            variant: ThemeVariant::Dark,
+            palette: ThemePalette::dark(),
+            decorations: ThemeDecorations::light(),
+        }
+    }
+
+    /// 테마 종류에 맞는 인스턴스를 생성한다.
+    pub fn from_variant(variant: ThemeVariant) -> Self {
+        match variant {
+            ThemeVariant::Light => Self::light(),
+            ThemeVariant::Dark => Self::dark(),
+        }
+    }
+
     /// 현재 테마 팔레트를 반환한다.
     pub fn palette(&self) -> &ThemePalette {
         &self.palette
@@ -217,7 +310,10 @@ impl Theme {
 
     /// egui Context에 테마 기반 스타일을 적용한다.
     pub fn apply(&self, ctx: &egui::Context) {
-        let mut visuals = egui::Visuals::light();
+        let mut visuals = match self.variant {
+            ThemeVariant::Light => egui::Visuals::light(),
+            ThemeVariant::Dark => egui::Visuals::dark(),
+        };
         let palette = self.palette;
         let decorations = self.decorations;
         visuals.override_text_color = Some(palette.fg_text_primary);
@@ -257,9 +353,13 @@ impl Theme {
     pub fn status_color(&self, status: &StepStatus) -> Color32 {
         match status {
             StepStatus::Pending => self.palette.accent_pending,
+            StepStatus::Waiting(_) => self.palette.accent_pending,
             StepStatus::Running => self.palette.accent_warning,
+            StepStatus::Retrying { .. } => self.palette.accent_warning,
             StepStatus::Success => self.palette.accent_success,
             StepStatus::Failed(_) => self.palette.accent_error,
+            StepStatus::Blocked => self.palette.fg_text_secondary,
+            StepStatus::AwaitingApproval => self.palette.accent_primary,
         }
     }
 
@@ -280,11 +380,57 @@ impl Theme {
             text_primary: palette.fg_text_primary,
             text_secondary: palette.fg_text_secondary,
             handle_fill: palette.builder_handle,
+            upstream_highlight: palette.accent_primary,
+            downstream_highlight: palette.accent_warning,
+            data_flow_hint: palette.accent_success,
+            data_flow_conflict: palette.accent_error,
+            critical_path: palette.accent_error,
         }
     }
 
+    /// Step에 지정한 아이콘·표시 라벨로 기본 시각 스타일을 덮어써 반환한다.
+    ///
+    /// 비어 있거나 지정하지 않은 항목은 유형별 기본값을 쓰며, 색상은 항상 유형을 따른다.
+    pub fn step_visual_with<'a>(
+        &self,
+        kind: StepVisualKind,
+        icon: Option<&'a str>,
+        label: Option<&'a str>,
+    ) -> StepVisualStyle<'a> {
+        let base = self.step_visual(kind);
+        StepVisualStyle {
+            icon: icon
+                .filter(|value| !value.trim().is_empty())
+                .unwrap_or(base.icon),
+            label: label
+                .filter(|value| !value.trim().is_empty())
+                .unwrap_or(base.label),
+            color: base.color,
+        }
+    }
+
+    /// 스테이지 이름에 대응하는 색상을 반환한다.
+    ///
+    /// 이름 해시로 고정 팔레트에서 고르므로 같은 이름은 항상 같은 색이다.
+    pub fn stage_color(&self, stage: &str) -> Color32 {
+        const STAGE_COLORS: [Color32; 8] = [
+            Color32::from_rgb(33, 150, 243),
+            Color32::from_rgb(76, 175, 80),
+            Color32::from_rgb(255, 152, 0),
+            Color32::from_rgb(156, 39, 176),
+            Color32::from_rgb(0, 150, 136),
+            Color32::from_rgb(233, 30, 99),
+            Color32::from_rgb(121, 85, 72),
+            Color32::from_rgb(96, 125, 139),
+        ];
+        let hash = stage.bytes().fold(0u32, |acc, byte| {
+            acc.wrapping_mul(31).wrapping_add(byte as u32)
+        });
+        STAGE_COLORS[hash as usize % STAGE_COLORS.len()]
+    }
+
     /// Step 시각 스타일을 반환한다.
-    pub fn step_visual(&self, kind: StepVisualKind) -> StepVisualStyle {
+    pub fn step_visual(&self, kind: StepVisualKind) -> StepVisualStyle<'static> {
         match kind {
             StepVisualKind::Sql => StepVisualStyle {
                 icon: "🧮",
@@ -293,7 +439,7 @@ impl Theme {
             },
             StepVisualKind::SqlFile => StepVisualStyle {
                 icon: "📄",
-                label: "SQL 파일",
+                label: t("builder.kind.sql_file"),
                 color: Color32::from_rgb(0, 150, 136),
             },
             StepVisualKind::SqlLoader => StepVisualStyle {
@@ -316,6 +462,31 @@ impl Theme {
                 label: "Loop",
                 color: Color32::from_rgb(233, 30, 99),
             },
+            StepVisualKind::ManualGate => StepVisualStyle {
+                icon: "✋",
+                label: t("builder.kind.manual_gate"),
+                color: Color32::from_rgb(255, 152, 0),
+            },
+            StepVisualKind::Wait => StepVisualStyle {
+                icon: "⏳",
+                label: t("builder.kind.wait"),
+                color: Color32::from_rgb(96, 125, 139),
+            },
+            StepVisualKind::WaitForRun => StepVisualStyle {
+                icon: "📬",
+                label: t("builder.kind.wait_for_run"),
+                color: Color32::from_rgb(84, 110, 122),
+            },
+            StepVisualKind::Verify => StepVisualStyle {
+                icon: "🔐",
+                label: t("builder.kind.verify"),
+                color: Color32::from_rgb(0, 150, 136),
+            },
+            StepVisualKind::Marker => StepVisualStyle {
+                icon: "◆",
+                label: t("builder.kind.marker"),
+                color: Color32::from_rgb(121, 85, 72),
+            },
         }
     }
 }
@@ -0,0 +1,700 @@
+use crate::i18n::tf;
+use crate::scenario::{
+    DbConnectionConfig, DbKind, Scenario, ShellConfig, SqlGuardConfig, Step, StepKind,
+    WaitForRunConfig,
+};
+use anyhow::Context;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// 가져온 Step에 적용하는 기본 타임아웃(초)이다. 빌더에서 새 노드를 만들 때와 같다.
+const DEFAULT_TIMEOUT_SEC: u64 = 60;
+
+/// 변수에 대입한 Airflow 연산자 호출(`task = BashOperator(`)을 찾는다.
+static AIRFLOW_TASK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^[ \t]*(\w+)\s*=\s*(?:\w+\.)*(\w+(?:Operator|Sensor))\s*\(")
+        .expect("정규식 컴파일 실패")
+});
+
+/// `DAG("id"` 또는 `DAG(dag_id="id"`에서 DAG ID를 찾는다.
+static AIRFLOW_DAG_ID: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"DAG\s*\(\s*(?:dag_id\s*=\s*)?[rRuU]?["']([^"']+)["']"#)
+        .expect("정규식 컴파일 실패")
+});
+
+/// `a >> b`, `a << b` 연결의 연산자를 찾는다.
+static AIRFLOW_SHIFT: Lazy<Regex> = Lazy::new(|| Regex::new(r">>|<<").expect("정규식 컴파일 실패"));
+
+/// `a.set_downstream(b)`, `a.set_upstream(b)` 호출을 찾는다.
+static AIRFLOW_SET_STREAM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\w+)\.set_(downstream|upstream)\(\s*([^)]*)\)").expect("정규식 컴파일 실패")
+});
+
+/// `chain(a, b, [c, d])` 호출을 찾는다.
+static AIRFLOW_CHAIN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bchain\(([^()]*)\)").expect("정규식 컴파일 실패"));
+
+/// `execution_timeout=timedelta(...)` 인자를 찾는다.
+static AIRFLOW_TIMEOUT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"execution_timeout\s*=\s*(?:\w+\.)?timedelta\(([^)]*)\)")
+        .expect("정규식 컴파일 실패")
+});
+
+/// Oozie `<action name="...">...</action>` 요소를 찾는다.
+static OOZIE_ACTION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?s)<action\s+name="([^"]+)"[^>]*>(.*?)</action>"#).expect("정규식 컴파일 실패")
+});
+
+/// Oozie 제어 노드(`start`, `fork`, `join`, `decision`)를 찾는다.
+static OOZIE_CONTROL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?s)<(start|fork|join|decision)\b([^>]*?)(?:/>|>(.*?)</(?:fork|decision)>)"#)
+        .expect("정규식 컴파일 실패")
+});
+
+/// Oozie 요소의 전이 대상(`to="..."`, `start="..."`) 속성을 찾는다.
+static OOZIE_TARGET: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\b(?:to|start)\s*=\s*"([^"]+)""#).expect("정규식 컴파일 실패"));
+
+/// 워크플로 정의에서 만든 시나리오 골격과 직접 옮겨야 할 항목이다.
+#[derive(Debug, Clone)]
+pub struct ImportedWorkflow {
+    /// 가져온 시나리오. 옮기지 못한 작업은 실행하면 실패하는 TODO Shell Step으로 들어 있다.
+    pub scenario: Scenario,
+    /// 직접 확인하거나 옮겨야 할 항목 설명 목록.
+    pub todos: Vec<String>,
+}
+
+/// 가져오는 중인 시나리오 골격이다.
+#[derive(Default)]
+struct SkeletonBuilder {
+    /// 추가한 Step 목록.
+    steps: Vec<Step>,
+    /// SQL Step이 참조한 연결 이름과 종류.
+    db: BTreeMap<String, DbKind>,
+    /// 직접 옮겨야 할 항목 설명 목록.
+    todos: Vec<String>,
+}
+
+impl SkeletonBuilder {
+    /// Step을 추가한다. `kind`가 오류이면 실행하면 실패하는 TODO Shell Step으로 바꾸고 사유를 남긴다.
+    fn push(&mut self, id: &str, kind: Result<StepKind, String>) -> &mut Step {
+        let (kind, label) = match kind {
+            Ok(kind) => (kind, None),
+            Err(reason) => {
+                self.todos.push(format!("[{id}] {reason}"));
+                (todo_shell_kind(id), Some("TODO".to_string()))
+            }
+        };
+        self.steps.push(Step {
+            id: id.to_string(),
+            name: id.to_string(),
+            icon: None,
+            label,
+            stage: None,
+            kind,
+            depends_on: Vec::new(),
+            allow_parallel: false,
+            memoize: false,
+            retry: 0,
+            retry_on: Default::default(),
+            timeout_sec: DEFAULT_TIMEOUT_SEC,
+            on_timeout: Default::default(),
+            log_level: None,
+            confirm: None,
+            time_window: None,
+            skip_if: None,
+            expects_outputs: Vec::new(),
+            on_missing_output: Default::default(),
+            consumes: Vec::new(),
+            produces: Vec::new(),
+            notify_url: None,
+            included_from: None,
+        });
+        self.steps.last_mut().expect("방금 추가한 Step")
+    }
+
+    /// `from`이 끝나야 `to`를 시작하도록 의존성을 추가한다. 없는 Step이나 중복 연결은 무시한다.
+    fn connect(&mut self, from: &str, to: &str) {
+        if from == to || !self.steps.iter().any(|step| step.id == from) {
+            return;
+        }
+        if let Some(step) = self.steps.iter_mut().find(|step| step.id == to)
+            && !step.depends_on.iter().any(|dep| dep == from)
+        {
+            step.depends_on.push(from.to_string());
+        }
+    }
+
+    /// 모은 Step으로 시나리오를 만든다. 참조한 연결은 접속 정보가 빈 정의로 추가하고 TODO로 남긴다.
+    fn finish(mut self, name: String) -> ImportedWorkflow {
+        let mut db = HashMap::new();
+        for (key, kind) in self.db {
+            self.todos
+                .push(tf("import.todo.db_connection", &[("key", &key)]));
+            db.insert(
+                key,
+                DbConnectionConfig {
+                    kind,
+                    dsn: None,
+                    user: None,
+                    password: None,
+                    db_ref: None,
+                },
+            );
+        }
+        let scenario = Scenario {
+            name,
+            db,
+            remotes: HashMap::new(),
+            params: HashMap::new(),
+            secret_params: Default::default(),
+            param_rules: Default::default(),
+            defaults: None,
+            log_lines_per_sec: None,
+            log_line_max_bytes: None,
+            explain_slow_sql_sec: None,
+            run_tmpdir: None,
+            on_failure: None,
+            summary: Vec::new(),
+            failure_diagnostics: Vec::new(),
+            profiles: BTreeMap::new(),
+            trigger: None,
+            cost_check: None,
+            steps: self.steps,
+            metadata: None,
+        };
+        ImportedWorkflow {
+            scenario,
+            todos: self.todos,
+        }
+    }
+}
+
+/// Airflow DAG(`*.py`) 또는 Oozie 워크플로(`*.xml`) 파일에서 시나리오 골격을 만든다.
+///
+/// 확장자가 `xml`이거나 내용이 `<`로 시작하면 Oozie, 그 밖에는 Airflow로 읽는다.
+pub fn import_workflow_file(path: &Path) -> anyhow::Result<ImportedWorkflow> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| tf("import.error.read", &[("path", &path.display())]))?;
+    let fallback_name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "imported".to_string());
+    let is_xml = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("xml"))
+        || source.trim_start().starts_with('<');
+    let imported = if is_xml {
+        import_oozie_workflow(&source, &fallback_name)
+    } else {
+        import_airflow_dag(&source, &fallback_name)
+    };
+    if imported.scenario.steps.is_empty() {
+        anyhow::bail!(tf("import.error.no_tasks", &[("path", &path.display())]));
+    }
+    Ok(imported)
+}
+
+/// Airflow DAG 파이썬 코드를 정규식으로 훑어 시나리오 골격을 만든다.
+///
+/// 변수에 대입한 연산자 호출만 작업으로 보며, `BashOperator`는 Shell Step, SQL 연산자는
+/// SQL(파일) Step, `EmptyOperator`/`DummyOperator`는 표식 Step으로 옮긴다. `retries`와
+/// `execution_timeout`도 옮기고, 의존성은 `>>`/`<<`, `set_downstream`/`set_upstream`, `chain`에서
+/// 읽는다. 인자가 문자열 리터럴이 아니거나 그 밖의 연산자는 TODO Step으로 남긴다.
+pub fn import_airflow_dag(source: &str, fallback_name: &str) -> ImportedWorkflow {
+    let mut builder = SkeletonBuilder::default();
+    let mut task_ids: HashMap<String, String> = HashMap::new();
+    for caps in AIRFLOW_TASK.captures_iter(source) {
+        let (Some(whole), Some(var), Some(operator)) = (caps.get(0), caps.get(1), caps.get(2))
+        else {
+            continue;
+        };
+        let args = call_args(source, whole.end() - 1);
+        let task_id = string_kwarg(args, "task_id").unwrap_or_else(|| var.as_str().to_string());
+        if task_ids.values().any(|id| *id == task_id) {
+            continue;
+        }
+        let kind = airflow_task_kind(operator.as_str(), args, &mut builder.db);
+        let step = builder.push(&task_id, kind);
+        if let Some(retries) = int_kwarg(args, "retries") {
+            step.retry = retries.min(u64::from(u8::MAX)) as u8;
+        }
+        if let Some(timeout) = AIRFLOW_TIMEOUT
+            .captures(args)
+            .and_then(|caps| timedelta_seconds(caps.get(1)?.as_str()))
+        {
+            step.timeout_sec = timeout.max(1);
+        }
+        task_ids.insert(var.as_str().to_string(), task_id);
+    }
+    let resolve = |names: &[String]| -> Vec<String> {
+        names
+            .iter()
+            .filter_map(|name| task_ids.get(name).cloned())
+            .collect()
+    };
+    let mut edges: Vec<(String, String)> = Vec::new();
+    let mut push_edges = |from: &[String], to: &[String]| {
+        for upstream in resolve(from) {
+            for downstream in resolve(to) {
+                edges.push((upstream.clone(), downstream));
+            }
+        }
+    };
+    for line in source.lines() {
+        let code = line.split('#').next().unwrap_or_default();
+        if !AIRFLOW_SHIFT.is_match(code) {
+            continue;
+        }
+        let ops: Vec<&str> = AIRFLOW_SHIFT.find_iter(code).map(|m| m.as_str()).collect();
+        let Some(groups) = AIRFLOW_SHIFT
+            .split(code)
+            .map(task_group)
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        for (pair, op) in groups.windows(2).zip(ops) {
+            if op == ">>" {
+                push_edges(&pair[0], &pair[1]);
+            } else {
+                push_edges(&pair[1], &pair[0]);
+            }
+        }
+    }
+    for caps in AIRFLOW_SET_STREAM.captures_iter(source) {
+        let this = vec![caps[1].to_string()];
+        let Some(other) = task_group(&caps[3]) else {
+            continue;
+        };
+        if &caps[2] == "downstream" {
+            push_edges(&this, &other);
+        } else {
+            push_edges(&other, &this);
+        }
+    }
+    for caps in AIRFLOW_CHAIN.captures_iter(source) {
+        let items: Option<Vec<Vec<String>>> = split_top_level(&caps[1])
+            .into_iter()
+            .map(task_group)
+            .collect();
+        for pair in items.unwrap_or_default().windows(2) {
+            push_edges(&pair[0], &pair[1]);
+        }
+    }
+    for (from, to) in edges {
+        builder.connect(&from, &to);
+    }
+    let name = AIRFLOW_DAG_ID
+        .captures(source)
+        .map(|caps| caps[1].to_string())
+        .unwrap_or_else(|| fallback_name.to_string());
+    builder.finish(name)
+}
+
+/// Airflow 연산자와 인자를 Step 유형으로 옮긴다. 옮길 수 없으면 TODO 사유를 반환한다.
+fn airflow_task_kind(
+    operator: &str,
+    args: &str,
+    db: &mut BTreeMap<String, DbKind>,
+) -> Result<StepKind, String> {
+    match operator {
+        "BashOperator" => {
+            let script = string_kwarg(args, "bash_command")
+                .ok_or_else(|| tf("import.todo.not_literal", &[("arg", &"bash_command")]))?;
+            Ok(shell_kind(script.trim()))
+        }
+        "EmptyOperator" | "DummyOperator" => Ok(StepKind::Marker),
+        "ExternalTaskSensor" => {
+            let dag_id = string_kwarg(args, "external_dag_id")
+                .ok_or_else(|| tf("import.todo.not_literal", &[("arg", &"external_dag_id")]))?;
+            Ok(StepKind::WaitForRun {
+                config: WaitForRunConfig {
+                    scenario: dag_id,
+                    ..WaitForRunConfig::default()
+                },
+            })
+        }
+        _ if is_sql_operator(operator) => {
+            let sql = string_kwarg(args, "sql").ok_or_else(|| {
+                tf(
+                    "import.todo.not_literal",
+                    &[("arg", &format!("{operator}.sql"))],
+                )
+            })?;
+            let target_db = ["postgres_conn_id", "oracle_conn_id", "conn_id"]
+                .iter()
+                .find_map(|name| string_kwarg(args, name));
+            if let Some(key) = &target_db {
+                let kind = if operator.contains("Oracle") {
+                    DbKind::Oracle
+                } else {
+                    DbKind::Postgres
+                };
+                db.entry(key.clone()).or_insert(kind);
+            }
+            let sql = sql.trim();
+            if sql.ends_with(".sql") && !sql.contains(char::is_whitespace) {
+                Ok(StepKind::SqlFile {
+                    path: PathBuf::from(sql),
+                    target_db,
+                    guard: SqlGuardConfig::default(),
+                })
+            } else {
+                Ok(StepKind::Sql {
+                    sql: sql.to_string(),
+                    target_db,
+                    guard: SqlGuardConfig::default(),
+                })
+            }
+        }
+        _ => Err(tf(
+            "import.todo.unsupported_operator",
+            &[("operator", &operator)],
+        )),
+    }
+}
+
+/// SQL을 실행하는 Airflow 연산자인지 확인한다.
+fn is_sql_operator(operator: &str) -> bool {
+    operator.ends_with("Operator")
+        && (operator.contains("Sql")
+            || operator.contains("SQL")
+            || operator.starts_with("Postgres")
+            || operator.starts_with("Oracle"))
+}
+
+/// Oozie 워크플로 XML을 정규식으로 훑어 시나리오 골격을 만든다.
+///
+/// `shell`/`ssh` 액션은 Shell Step으로 옮기고, `ok` 전이를 따라 `fork`/`join`/`decision`을 건너뛰며
+/// 액션 사이 의존성을 만든다. 그 밖의 액션, `decision` 조건, `kill`이 아닌 `error` 전이는 TODO로 남긴다.
+pub fn import_oozie_workflow(source: &str, fallback_name: &str) -> ImportedWorkflow {
+    let mut builder = SkeletonBuilder::default();
+    let mut transitions: HashMap<String, Vec<String>> = HashMap::new();
+    let mut actions: Vec<String> = Vec::new();
+    let kill_nodes: HashSet<String> = Regex::new(r#"<kill\s+name="([^"]+)""#)
+        .expect("정규식 컴파일 실패")
+        .captures_iter(source)
+        .map(|caps| caps[1].to_string())
+        .collect();
+    for caps in OOZIE_CONTROL.captures_iter(source) {
+        let name = attr(&caps[2], "name").unwrap_or_else(|| caps[1].to_string());
+        let targets = OOZIE_TARGET
+            .captures_iter(&caps[0])
+            .map(|target| target[1].to_string())
+            .collect();
+        if &caps[1] == "decision" {
+            builder
+                .todos
+                .push(tf("import.todo.decision", &[("name", &name)]));
+        }
+        transitions.insert(name, targets);
+    }
+    for caps in OOZIE_ACTION.captures_iter(source) {
+        let name = caps[1].to_string();
+        let body = &caps[2];
+        let ok = attr_of(body, "ok", "to");
+        if let Some(error) = attr_of(body, "error", "to")
+            && !kill_nodes.contains(&error)
+        {
+            builder.todos.push(tf(
+                "import.todo.error_transition",
+                &[("name", &name), ("error", &error)],
+            ));
+        }
+        builder.push(&name, oozie_action_kind(body));
+        transitions.insert(name.clone(), ok.into_iter().collect());
+        actions.push(name);
+    }
+    let action_set: HashSet<&str> = actions.iter().map(String::as_str).collect();
+    for action in &actions {
+        let mut visited = HashSet::new();
+        let mut pending: Vec<&str> = transitions
+            .get(action)
+            .map(|targets| targets.iter().map(String::as_str).collect())
+            .unwrap_or_default();
+        let mut downstream = Vec::new();
+        while let Some(node) = pending.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            if action_set.contains(node) {
+                downstream.push(node.to_string());
+            } else if let Some(targets) = transitions.get(node) {
+                pending.extend(targets.iter().map(String::as_str));
+            }
+        }
+        for target in downstream {
+            builder.connect(action, &target);
+        }
+    }
+    let name = Regex::new(r#"<workflow-app\b[^>]*\bname="([^"]+)""#)
+        .expect("정규식 컴파일 실패")
+        .captures(source)
+        .map(|caps| xml_unescape(&caps[1]))
+        .unwrap_or_else(|| fallback_name.to_string());
+    builder.finish(name)
+}
+
+/// Oozie 액션 본문을 Step 유형으로 옮긴다. 옮길 수 없으면 TODO 사유를 반환한다.
+fn oozie_action_kind(body: &str) -> Result<StepKind, String> {
+    let action_type = Regex::new(r"<([\w:-]+)")
+        .expect("정규식 컴파일 실패")
+        .captures_iter(body)
+        .map(|caps| caps[1].to_string())
+        .find(|tag| tag != "ok" && tag != "error")
+        .unwrap_or_default();
+    let (command, arg_tag) = match action_type.split(':').next_back().unwrap_or_default() {
+        "shell" => (element_text(body, "exec"), "argument"),
+        "ssh" => (element_text(body, "command"), "args"),
+        other => {
+            return Err(tf("import.todo.unsupported_action", &[("action", &other)]));
+        }
+    };
+    let Some(command) = command else {
+        return Err(tf("import.todo.no_command", &[("action", &action_type)]));
+    };
+    let mut script = command;
+    for arg in element_texts(body, arg_tag) {
+        script.push(' ');
+        script.push_str(&arg);
+    }
+    Ok(shell_kind(&script))
+}
+
+/// 스크립트를 실행하는 Shell Step 유형을 만든다.
+fn shell_kind(script: &str) -> StepKind {
+    StepKind::Shell {
+        config: ShellConfig {
+            script: script.to_string(),
+            shell_program: None,
+            shell_args: Vec::new(),
+            env: HashMap::new(),
+            inherit_env: true,
+            working_dir: None,
+            run_as: None,
+            error_policy: Default::default(),
+            remote: None,
+            priority: None,
+        },
+    }
+}
+
+/// 옮기지 못한 작업 자리에 두는, 실행하면 실패하는 Shell Step 유형을 만든다.
+fn todo_shell_kind(id: &str) -> StepKind {
+    shell_kind(&format!(
+        "echo TODO: {} && exit 1",
+        tf("import.todo.placeholder", &[("id", &id)])
+    ))
+}
+
+/// `open` 위치의 여는 괄호에 짝이 맞는 닫는 괄호까지의 인자 문자열을 반환한다.
+///
+/// 문자열 리터럴과 주석 안의 괄호는 무시한다. 짝을 찾지 못하면 끝까지 반환한다.
+fn call_args(source: &str, open: usize) -> &str {
+    let bytes = source.as_bytes();
+    let mut depth = 0usize;
+    let mut idx = open;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return &source[open + 1..idx];
+                }
+            }
+            b'\'' | b'"' => {
+                idx = string_end(source, idx);
+                continue;
+            }
+            b'#' => {
+                idx = source[idx..]
+                    .find('\n')
+                    .map_or(bytes.len(), |pos| idx + pos);
+                continue;
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    &source[open + 1..]
+}
+
+/// `start` 위치의 따옴표로 시작하는 문자열 리터럴 바로 뒤 위치를 반환한다. 삼중 따옴표도 처리한다.
+///
+/// 리터럴 안의 한글처럼 여러 바이트 문자 중간에서 문자열을 자르지 않도록 바이트 단위로 비교한다.
+fn string_end(source: &str, start: usize) -> usize {
+    let delimiter = string_delimiter(&source[start..]);
+    let bytes = source.as_bytes();
+    let mut idx = start + delimiter.len();
+    while idx < bytes.len() {
+        if bytes[idx] == b'\\' {
+            idx += 2;
+            continue;
+        }
+        if bytes[idx..].starts_with(delimiter.as_bytes()) {
+            return idx + delimiter.len();
+        }
+        idx += 1;
+    }
+    bytes.len()
+}
+
+/// 문자열 리터럴을 여는 구분자(`'`, `"`, `'''`, `"""`)를 반환한다.
+fn string_delimiter(literal: &str) -> &'static str {
+    for delimiter in ["\"\"\"", "'''", "\"", "'"] {
+        if literal.starts_with(delimiter) {
+            return delimiter;
+        }
+    }
+    ""
+}
+
+/// 호출 인자에서 `name=` 키워드 인자의 문자열 리터럴 값을 읽는다. 리터럴이 아니면 `None`이다.
+///
+/// `r`/`f` 같은 접두어를 허용하며, raw 문자열이 아니면 `\n`, `\t`, `\\`, 따옴표 이스케이프를 푼다.
+fn string_kwarg(args: &str, name: &str) -> Option<String> {
+    let pattern = Regex::new(&format!(r"(?:^|[\s,(]){name}\s*=\s*([rRfFuUbB]{{0,2}})")).ok()?;
+    let caps = pattern.captures(args)?;
+    let prefix = caps.get(1)?;
+    let start = prefix.end();
+    let delimiter = string_delimiter(&args[start..]);
+    if delimiter.is_empty() {
+        return None;
+    }
+    let end = string_end(args, start);
+    let body = args.get(start + delimiter.len()..end.checked_sub(delimiter.len())?)?;
+    if prefix.as_str().contains(['r', 'R']) {
+        return Some(body.to_string());
+    }
+    let mut value = String::with_capacity(body.len());
+    let mut chars = body.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            value.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('t') => value.push('\t'),
+            Some('\n') => {}
+            Some(other @ ('\\' | '\'' | '"')) => value.push(other),
+            Some(other) => {
+                value.push('\\');
+                value.push(other);
+            }
+            None => value.push('\\'),
+        }
+    }
+    Some(value)
+}
+
+/// 호출 인자에서 `name=` 키워드 인자의 정수 리터럴 값을 읽는다.
+fn int_kwarg(args: &str, name: &str) -> Option<u64> {
+    Regex::new(&format!(r"(?:^|[\s,(]){name}\s*=\s*(\d+)"))
+        .ok()?
+        .captures(args)?
+        .get(1)?
+        .as_str()
+        .parse()
+        .ok()
+}
+
+/// `timedelta(hours=1, minutes=30)` 인자를 초로 바꾼다. 알아볼 수 있는 인자가 없으면 `None`이다.
+fn timedelta_seconds(args: &str) -> Option<u64> {
+    let total: Option<u64> = [
+        ("days", 86_400),
+        ("hours", 3_600),
+        ("minutes", 60),
+        ("seconds", 1),
+    ]
+    .iter()
+    .filter_map(|(name, unit)| int_kwarg(args, name).map(|value| value * unit))
+    .reduce(|a, b| a + b);
+    total.filter(|seconds| *seconds > 0)
+}
+
+/// `a`, `[a, b]` 형태의 작업 묶음을 변수 이름 목록으로 읽는다. 식별자가 아니면 `None`이다.
+fn task_group(text: &str) -> Option<Vec<String>> {
+    let text = text.trim();
+    let inner = text
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(text);
+    let names: Vec<String> = inner
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    let valid = !names.is_empty()
+        && names
+            .iter()
+            .all(|name| name.chars().all(|ch| ch.is_alphanumeric() || ch == '_'));
+    valid.then_some(names)
+}
+
+/// 괄호 밖의 쉼표로 나눈다.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (idx, ch) in text.char_indices() {
+        match ch {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&text[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts.retain(|part| !part.trim().is_empty());
+    parts
+}
+
+/// 여는 태그 속성 문자열에서 `name="..."` 값을 읽는다.
+fn attr(attrs: &str, name: &str) -> Option<String> {
+    Regex::new(&format!(r#"\b{name}\s*=\s*"([^"]*)""#))
+        .ok()?
+        .captures(attrs)
+        .map(|caps| xml_unescape(&caps[1]))
+}
+
+/// 본문에서 첫 `<tag ...>` 요소의 속성 값을 읽는다.
+fn attr_of(body: &str, tag: &str, name: &str) -> Option<String> {
+    let caps = Regex::new(&format!(r"<{tag}\b([^>]*)>"))
+        .ok()?
+        .captures(body)?;
+    attr(&caps[1], name)
+}
+
+/// 본문에서 첫 `<tag>...</tag>` 요소의 텍스트를 읽는다.
+fn element_text(body: &str, tag: &str) -> Option<String> {
+    element_texts(body, tag).into_iter().next()
+}
+
+/// 본문에서 모든 `<tag>...</tag>` 요소의 텍스트를 순서대로 읽는다.
+fn element_texts(body: &str, tag: &str) -> Vec<String> {
+    let Ok(pattern) = Regex::new(&format!(r"(?s)<{tag}>(.*?)</{tag}>")) else {
+        return Vec::new();
+    };
+    pattern
+        .captures_iter(body)
+        .map(|caps| xml_unescape(caps[1].trim()))
+        .collect()
+}
+
+/// XML 기본 엔티티를 푼다.
+fn xml_unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}